@@ -0,0 +1,141 @@
+//! Directory mode: convert every matching file under `--input-dir` into `--output-dir`.
+
+use crate::decompress::{self, Compression};
+use crate::paths;
+use glob::{MatchOptions, Pattern};
+use html_to_markdown_rs::boilerplate::{self, BoilerplateConfig, BoilerplateReport};
+use rayon::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Settings for one directory conversion run.
+pub struct BatchJob<'a> {
+    pub input_dir: &'a Path,
+    pub output_dir: &'a Path,
+    pub pattern: &'a str,
+    pub jobs: Option<usize>,
+    /// Extension of the written files, without the dot.
+    pub extension: &'a str,
+    /// Remove blocks repeated across most of the converted files before writing them.
+    pub boilerplate: Option<&'a BoilerplateConfig>,
+}
+
+/// Outcome of a directory conversion run.
+pub struct BatchSummary {
+    pub converted: usize,
+    pub failures: Vec<(PathBuf, String)>,
+    /// Blocks removed under [`BatchJob::boilerplate`].
+    pub boilerplate: BoilerplateReport,
+}
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Relative paths of the files under `input_dir` that match `pattern`, sorted.
+fn collect_inputs(input_dir: &Path, pattern: &Pattern) -> Result<Vec<PathBuf>, String> {
+    let mut inputs = Vec::new();
+    for entry in WalkDir::new(input_dir).follow_links(true) {
+        let entry = entry.map_err(|e| format!("Error walking '{}': {e}", paths::display(input_dir)))?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(relative) = entry.path().strip_prefix(input_dir) else {
+            continue;
+        };
+        // Match on a lossy copy so files with non-UTF-8 names are still considered.
+        if pattern.matches_with(&relative.to_string_lossy(), MATCH_OPTIONS) {
+            inputs.push(relative.to_path_buf());
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Convert every matching file, mirroring the directory layout under `output_dir`.
+///
+/// `convert` receives the raw file bytes and returns the text to write. Failures are collected
+/// per file rather than aborting the run. With [`BatchJob::boilerplate`] set, every file is
+/// converted before any is written, so the whole batch can be compared.
+pub fn run<F>(job: &BatchJob<'_>, convert: F) -> Result<BatchSummary, String>
+where
+    F: Fn(&[u8]) -> Result<String, String> + Sync,
+{
+    if !job.input_dir.is_dir() {
+        return Err(format!(
+            "Input directory '{}' does not exist",
+            paths::display(job.input_dir)
+        ));
+    }
+    let pattern = Pattern::new(job.pattern).map_err(|e| format!("Invalid --glob pattern '{}': {e}", job.pattern))?;
+    let inputs = collect_inputs(job.input_dir, &pattern)?;
+
+    let convert_one = |relative: &PathBuf| -> Result<String, String> {
+        let source = job.input_dir.join(relative);
+        let bytes = fs::read(&source).map_err(|e| format!("read failed: {e}"))?;
+        let bytes = decompress::maybe_decompress(bytes, Compression::from_path(relative))?;
+        convert(&bytes)
+    };
+    let write_one = |relative: &PathBuf, output: &str| -> Result<(), String> {
+        let target = decompress::strip_extension(&job.output_dir.join(relative)).with_extension(job.extension);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("cannot create '{}': {e}", paths::display(parent)))?;
+        }
+        fs::write(&target, output).map_err(|e| format!("write to '{}' failed: {e}", paths::display(&target)))
+    };
+
+    let mut builder = rayon::ThreadPoolBuilder::new();
+    if let Some(jobs) = job.jobs {
+        builder = builder.num_threads(jobs);
+    }
+    let pool = builder
+        .build()
+        .map_err(|e| format!("Error starting worker threads: {e}"))?;
+
+    let mut report = BoilerplateReport::default();
+    let results: Vec<Result<(), String>> = if let Some(config) = job.boilerplate {
+        let mut converted: Vec<Result<String, String>> = pool.install(|| inputs.par_iter().map(convert_one).collect());
+        let mut outputs: Vec<String> = converted
+            .iter_mut()
+            .filter_map(|result| result.as_mut().ok().map(std::mem::take))
+            .collect();
+        report = boilerplate::strip_boilerplate(&mut outputs, config);
+        for (result, output) in converted
+            .iter_mut()
+            .filter_map(|result| result.as_mut().ok())
+            .zip(outputs)
+        {
+            *result = output;
+        }
+        pool.install(|| {
+            inputs
+                .par_iter()
+                .zip(converted)
+                .map(|(relative, result)| write_one(relative, &result?))
+                .collect()
+        })
+    } else {
+        pool.install(|| {
+            inputs
+                .par_iter()
+                .map(|relative| write_one(relative, &convert_one(relative)?))
+                .collect()
+        })
+    };
+
+    let mut summary = BatchSummary {
+        converted: 0,
+        failures: Vec::new(),
+        boilerplate: report,
+    };
+    for (relative, result) in inputs.into_iter().zip(results) {
+        match result {
+            Ok(()) => summary.converted += 1,
+            Err(err) => summary.failures.push((relative, err)),
+        }
+    }
+    Ok(summary)
+}
@@ -0,0 +1,84 @@
+//! TOML configuration files mapping onto `ConversionOptionsUpdate`.
+//!
+//! Keys may be written in snake_case (`heading_style`), kebab-case (`heading-style`) or the
+//! camelCase used by the JSON options; nested tables such as `[preprocessing]` follow the same
+//! rules. Values use the same spellings as the JSON options (`heading_style = "atx"`). Keys are
+//! checked against the options JSON Schema, so a misspelled option is reported instead of ignored.
+
+use html_to_markdown_rs::{
+    ConversionOptionsUpdate, conversion_options_json_schema, conversion_options_update_from_json,
+};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::paths;
+
+/// File name looked up in the current directory and its ancestors when `--config` is not given.
+pub const DISCOVERED_NAME: &str = ".html2md.toml";
+
+/// Find the nearest `.html2md.toml`, starting at `start` and walking up to the filesystem root.
+pub fn discover(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(DISCOVERED_NAME))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Read a config file into a partial options update.
+pub fn load(path: &Path) -> Result<ConversionOptionsUpdate, String> {
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("Error reading config file '{}': {e}", paths::display(path)))?;
+    parse(&text).map_err(|e| format!("Invalid config file '{}': {e}", paths::display(path)))
+}
+
+fn parse(text: &str) -> Result<ConversionOptionsUpdate, String> {
+    let table: toml::Table = toml::from_str(text).map_err(|e| e.to_string())?;
+    let value = camel_case_keys(serde_json::to_value(table).map_err(|e| e.to_string())?);
+    check_known_keys(&value, &conversion_options_json_schema(), "")?;
+    conversion_options_update_from_json(&value.to_string()).map_err(|e| e.to_string())
+}
+
+/// Reject keys the schema does not list, recursing into nested tables.
+fn check_known_keys(value: &Value, schema: &Value, prefix: &str) -> Result<(), String> {
+    let Value::Object(map) = value else {
+        return Ok(());
+    };
+    for (key, value) in map {
+        let Some(property) = schema["properties"].get(key) else {
+            return Err(format!("unknown option '{prefix}{key}'"));
+        };
+        if property["type"] == "object" {
+            check_known_keys(value, property, &format!("{prefix}{key}."))?;
+        }
+    }
+    Ok(())
+}
+
+/// Rewrite every object key to camelCase, recursing into nested tables.
+fn camel_case_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (camel_case(&key), camel_case_keys(value)))
+                .collect::<Map<_, _>>(),
+        ),
+        other => other,
+    }
+}
+
+fn camel_case(key: &str) -> String {
+    let mut out = String::with_capacity(key.len());
+    let mut upper = false;
+    for ch in key.chars() {
+        if ch == '_' || ch == '-' {
+            upper = !out.is_empty();
+        } else if upper {
+            out.extend(ch.to_uppercase());
+            upper = false;
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
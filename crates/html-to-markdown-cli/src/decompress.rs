@@ -0,0 +1,84 @@
+//! Transparent gzip/Brotli decompression for input files and fetched documents.
+//!
+//! Gzip is recognised by its magic bytes as well as by a `.gz` extension; Brotli has no magic
+//! number, so it is only recognised by a `.br` extension. HTTP `Content-Encoding` is already
+//! handled by the HTTP client, which leaves bodies served as compressed files (for example a
+//! `.html.gz` with `Content-Type: application/gzip`).
+
+use flate2::read::MultiGzDecoder;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::paths;
+
+/// Upper bound on decompressed size, so a small compressed bomb cannot exhaust memory.
+const MAX_DECOMPRESSED_SIZE: u64 = 1024 * 1024 * 1024;
+
+const GZIP_MAGIC: &[u8] = b"\x1F\x8B";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Gzip,
+    Brotli,
+}
+
+impl Compression {
+    /// Compression implied by a file name's last extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(OsStr::to_str) {
+            Some(ext) if ext.eq_ignore_ascii_case("gz") => Some(Self::Gzip),
+            Some(ext) if ext.eq_ignore_ascii_case("br") => Some(Self::Brotli),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Brotli => "brotli",
+        }
+    }
+}
+
+/// Decompress `bytes` if `hint` or the gzip magic says they are compressed.
+pub fn maybe_decompress(bytes: Vec<u8>, hint: Option<Compression>) -> Result<Vec<u8>, String> {
+    let compression = hint.or_else(|| bytes.starts_with(GZIP_MAGIC).then_some(Compression::Gzip));
+    let Some(compression) = compression else {
+        return Ok(bytes);
+    };
+
+    let reader: Box<dyn Read + '_> = match compression {
+        Compression::Gzip => Box::new(MultiGzDecoder::new(bytes.as_slice())),
+        Compression::Brotli => Box::new(brotli_decompressor::Decompressor::new(bytes.as_slice(), 4096)),
+    };
+    let mut decompressed = Vec::with_capacity(bytes.len().saturating_mul(4));
+    reader
+        .take(MAX_DECOMPRESSED_SIZE + 1)
+        .read_to_end(&mut decompressed)
+        .map_err(|e| format!("Error decompressing {} data: {e}", compression.name()))?;
+    if decompressed.len() as u64 > MAX_DECOMPRESSED_SIZE {
+        return Err(format!(
+            "Decompressed {} data exceeds {} MiB",
+            compression.name(),
+            MAX_DECOMPRESSED_SIZE / (1024 * 1024)
+        ));
+    }
+    Ok(decompressed)
+}
+
+/// Read a file, decompressing `.gz`/`.br` (or gzip-magic) contents.
+pub fn read_file(path: &Path) -> Result<Vec<u8>, String> {
+    let bytes = fs::read(path).map_err(|e| format!("Error reading file '{}': {e}", paths::display(path)))?;
+    maybe_decompress(bytes, Compression::from_path(path)).map_err(|e| format!("{e} in '{}'", paths::display(path)))
+}
+
+/// `page.html.gz` -> `page.html`; paths without a compression extension are returned as-is.
+pub fn strip_extension(path: &Path) -> PathBuf {
+    if Compression::from_path(path).is_some() {
+        path.with_extension("")
+    } else {
+        path.to_path_buf()
+    }
+}
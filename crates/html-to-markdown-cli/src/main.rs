@@ -1,18 +1,31 @@
 #![allow(clippy::all, clippy::pedantic, clippy::nursery, missing_docs)]
-use clap::{Parser, ValueEnum};
+mod batch;
+mod config;
+mod decompress;
+mod ndjson;
+mod paths;
+mod progress;
+mod serve;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use encoding_rs::Encoding;
 use html_to_markdown_rs::{
-    CodeBlockStyle, ConversionOptions, HeadingStyle, HighlightStyle, ListIndentType, MetadataConfig, NewlineStyle,
-    PreprocessingOptions, PreprocessingPreset, WhitespaceMode, convert, convert_with_metadata,
+    AbbrStyle, BidiMode, BoilerplateConfig, BuiltinPostProcessor, CodeBlockStyle, ContactLinkStyle, ConversionOptions,
+    ConversionPreset, DetailsStyle, DfnStyle, FigureStyle, FrontMatterFormat, HeaderBreakStyle, HeadingStyle,
+    HighlightStyle, ListIndentType, ListSpacing, MathStyle, MetadataConfig, NbspMode, NewlineStyle, PostProcessorChain,
+    PreprocessingOptions, PreprocessingPreset, SrcsetPolicy, SvgTextMode, TimeStyle, TitleSource,
+    UnrepresentablePolicy, WhitespaceMode, conversion_options_json_schema, convert, convert_with_diagnostics,
+    convert_with_metadata, convert_with_progress, detect_input_format,
+    fetch::{FetchConfig, Fetcher},
     metadata::DEFAULT_MAX_STRUCTURED_DATA_SIZE,
+    repro::ReproBundle,
+    warc::WarcReader,
 };
-use reqwest::blocking::Client;
-use reqwest::header::{CONTENT_TYPE, USER_AGENT};
 use serde_json::json;
 use std::fs;
 use std::io::{self, Read, Write as IoWrite};
-use std::path::PathBuf;
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::thread;
 
 const DEFAULT_USER_AGENT: &str =
     "Mozilla/5.0 (compatible; html-to-markdown-cli/2.10; +https://github.com/kreuzberg-dev/html-to-markdown)";
@@ -60,6 +73,27 @@ const DEFAULT_USER_AGENT: &str =
     # Fetch remote HTML and convert
     html-to-markdown --url https://example.com > output.md
 
+    # Serve conversions over HTTP (POST /convert), with setext headings by default
+    html-to-markdown --heading-style underlined serve --port 8080
+
+    # Start from the options recommended for LLM prompts
+    html-to-markdown page.html --profile llm
+
+    # Share a conversion profile (also picked up automatically as .html2md.toml)
+    html-to-markdown input.html --config team.toml
+
+    # Convert a whole directory tree using 8 worker threads
+    html-to-markdown --input-dir site/ --output-dir docs/ --glob '**/*.htm*' --jobs 8
+
+    # Long-lived pipeline: one {\"id\", \"html\"} JSON object per stdin line
+    printf '{\"id\": 1, \"html\": \"<b>hi</b>\"}\\n' | html-to-markdown --ndjson
+
+    # Keep embedded media as raw HTML
+    html-to-markdown page.html --preserve-tags video,iframe
+
+    # Convert every HTML page in a web archive to JSON lines
+    html-to-markdown crawl.warc.gz --warc > pages.ndjson
+
     # Discord/Slack-friendly (2-space indents)
     html-to-markdown input.html --list-indent-width 2
 
@@ -72,9 +106,12 @@ const DEFAULT_USER_AGENT: &str =
 For more information: https://github.com/kreuzberg-dev/html-to-markdown
 ")]
 struct Cli {
-    /// Input HTML file (use \"-\" or omit for stdin)
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Input HTML file (use \"-\" or omit for stdin); .gz and .br files are decompressed
     #[arg(value_name = "FILE")]
-    input: Option<String>,
+    input: Option<PathBuf>,
 
     /// Fetch HTML from a URL (alternative to file/stdin)
     #[arg(long, value_name = "URL", conflicts_with = "input")]
@@ -88,6 +125,96 @@ struct Cli {
     #[arg(short = 'o', long = "output", value_name = "FILE")]
     output: Option<PathBuf>,
 
+    /// Convert every matching file under DIR
+    ///
+    /// Files are converted in parallel and written to --output-dir with the
+    /// same relative path and a .md extension (.json with --with-metadata).
+    /// Compressed inputs are decompressed, so page.html.gz becomes page.md.
+    /// A summary of failed files is printed to stderr.
+    #[arg(long, value_name = "DIR", requires = "output_dir")]
+    #[arg(conflicts_with_all = ["input", "url", "output"])]
+    #[arg(help_heading = "Batch Mode")]
+    input_dir: Option<PathBuf>,
+
+    /// Directory receiving the files converted from --input-dir
+    #[arg(long, value_name = "DIR", requires = "input_dir")]
+    #[arg(help_heading = "Batch Mode")]
+    output_dir: Option<PathBuf>,
+
+    /// Files to convert inside --input-dir, relative to it (default: "**/*.html")
+    #[arg(long = "glob", value_name = "PATTERN", requires = "input_dir")]
+    #[arg(help_heading = "Batch Mode")]
+    glob: Option<String>,
+
+    /// Number of worker threads for --input-dir (default: one per CPU)
+    #[arg(short = 'j', long, value_name = "N", requires = "input_dir")]
+    #[arg(value_parser = clap::value_parser!(u16).range(1..))]
+    #[arg(help_heading = "Batch Mode")]
+    jobs: Option<u16>,
+
+    /// Remove blocks repeated across most files of --input-dir
+    ///
+    /// A paragraph, list or code block that appears verbatim in at least 60% of
+    /// the converted files (site footers, newsletter signups, cookie notices) is
+    /// removed from all of them. The removed blocks are listed on stderr.
+    /// Needs at least three files.
+    #[arg(long, requires = "input_dir", conflicts_with = "with_metadata")]
+    #[arg(help_heading = "Batch Mode")]
+    strip_boilerplate: bool,
+
+    /// Read NDJSON requests from stdin and write one JSON response per line
+    ///
+    /// Each input line is an object {"id": ..., "html": "..."}; each output line is
+    /// {"id": ..., "markdown": ..., "error": ...} with exactly one of markdown or error
+    /// set. The id is echoed back unchanged, so callers can keep the process alive and
+    /// match responses to requests. With --with-metadata, responses also carry "metadata".
+    #[arg(long, conflicts_with_all = ["input", "url", "output", "input_dir"])]
+    #[arg(help_heading = "Batch Mode")]
+    ndjson: bool,
+
+    /// Treat the input as a WARC archive (optionally gzip-compressed)
+    ///
+    /// Every HTML response in the archive is converted and written as one JSON
+    /// line: {"target_uri": ..., "date": ..., "record_id": ..., "markdown": ...}.
+    /// Records that cannot be decoded produce {"error": ...} instead.
+    #[arg(long, conflicts_with_all = ["url", "input_dir", "ndjson", "with_metadata"])]
+    #[arg(help_heading = "Batch Mode")]
+    warc: bool,
+
+    /// Start from a bundle of recommended options
+    ///
+    /// - 'chat': fenced code, `$` math and tight lists for chat interfaces
+    /// - 'docs': navigation removed, fenced code and heading ids kept for documentation sites
+    /// - 'scraping': main content only, tracking parameters and empty elements removed
+    /// - 'llm': scraping cleanup plus plain tables, no escaping and lines wrapped at 100 columns
+    ///
+    /// The config file and command-line flags take precedence over the profile.
+    #[arg(long, value_name = "NAME")]
+    #[arg(help_heading = "Configuration")]
+    profile: Option<CliProfile>,
+
+    /// Read conversion options from a TOML file
+    ///
+    /// Keys mirror the JSON conversion options and may be snake_case, e.g.
+    /// `heading_style = "atx"`, `preserve_tags = ["svg"]`, or a `[preprocessing]` table.
+    /// Without this flag the nearest .html2md.toml in the current directory or one of its
+    /// parents is used. Command-line flags take precedence over the file.
+    #[arg(long, value_name = "FILE")]
+    #[arg(help_heading = "Configuration")]
+    config: Option<PathBuf>,
+
+    /// Do not look for a .html2md.toml file
+    #[arg(long, conflicts_with = "config")]
+    #[arg(help_heading = "Configuration")]
+    no_config: bool,
+
+    /// Print the JSON Schema for conversion options and exit
+    ///
+    /// Property names are camelCase; config files may also spell them in snake_case.
+    #[arg(long)]
+    #[arg(help_heading = "Configuration")]
+    print_config_schema: bool,
+
     /// Generate shell completion script
     #[arg(long = "generate-completion", value_name = "SHELL", value_enum)]
     generate_completion: Option<Shell>,
@@ -96,6 +223,13 @@ struct Cli {
     #[arg(long = "generate-man")]
     generate_man: bool,
 
+    /// Print the detected input format and exit
+    ///
+    /// Prints one of html, hocr, alto, mhtml, warc, xml or text, so scripts can pick
+    /// options such as --no-hocr-spatial-tables before converting.
+    #[arg(long, conflicts_with_all = ["input_dir", "ndjson", "warc"])]
+    detect_format: bool,
+
     /// Heading style
     ///
     /// Controls how headings are formatted in the output:
@@ -127,6 +261,33 @@ struct Cli {
     #[arg(value_parser = validate_bullets)]
     bullets: Option<String>,
 
+    /// Keep the first bullet character at every nesting level
+    ///
+    /// Some renderers treat "*" and "-" lists differently when mixed, so this
+    /// disables cycling through --bullets by depth.
+    #[arg(long)]
+    #[arg(help_heading = "List Options")]
+    same_bullet: bool,
+
+    /// Ordered list delimiters per nesting level
+    ///
+    /// Characters cycle through nesting levels like --bullets. Default "."
+    /// renders "1.", ".)" renders "1." at level 1 and "1)" at level 2.
+    #[arg(long, value_name = "CHARS")]
+    #[arg(help_heading = "List Options")]
+    #[arg(value_parser = validate_ordered_markers)]
+    ordered_markers: Option<String>,
+
+    /// Spacing between list items
+    ///
+    /// Options:
+    /// - 'auto': Follow the source document (default)
+    /// - 'tight': No blank lines between items
+    /// - 'loose': Blank line between every item
+    #[arg(long, value_name = "SPACING")]
+    #[arg(help_heading = "List Options")]
+    list_spacing: Option<CliListSpacing>,
+
     /// Symbol for bold and italic
     ///
     /// Choose '*' (default) or '_' for **bold** and *italic* text
@@ -199,6 +360,20 @@ struct Cli {
     #[arg(help_heading = "Code Blocks")]
     code_language: Option<String>,
 
+    /// Infer code block languages
+    ///
+    /// Emit the fence language of code blocks, inferring it from highlighter
+    /// classes (brush: js, highlight-source-python), data-lang attributes or
+    /// the code itself when there is no language-* class
+    #[arg(long)]
+    #[arg(help_heading = "Code Blocks")]
+    infer_code_language: bool,
+
+    /// Keep blank lines at the start and end of code blocks
+    #[arg(long = "no-code-block-trim")]
+    #[arg(help_heading = "Code Blocks")]
+    no_code_block_trim: bool,
+
     /// Convert URLs to autolinks
     ///
     /// When link text equals href, use <url> instead of [url](url)
@@ -213,6 +388,50 @@ struct Cli {
     #[arg(help_heading = "Links")]
     default_title: bool,
 
+    /// Emit Pandoc attributes
+    ///
+    /// Keep element ids and classes as Pandoc attribute blocks, e.g.
+    /// `# Intro {#intro .lead}`, on headings, fenced code blocks, links and images
+    #[arg(long)]
+    #[arg(help_heading = "Element Handling")]
+    pandoc_attributes: bool,
+
+    /// Preserve heading ids
+    ///
+    /// Keep heading ids so #fragment links still work, as an <a id="..."></a> anchor
+    /// before the heading text (or {#id} with --pandoc-attributes)
+    #[arg(long)]
+    #[arg(help_heading = "Element Handling")]
+    preserve_heading_ids: bool,
+
+    /// Fall back to other sources for missing titles
+    ///
+    /// Comma-separated sources tried in order when a link has no title attribute or the
+    /// document has no <title> (front matter):
+    /// - 'og-title': The document's og:title (front matter only)
+    /// - 'first-heading': The first heading inside the link or document
+    /// - 'url-basename': The last path segment of the URL
+    #[arg(long, value_name = "SOURCES", value_delimiter = ',', value_parser = validate_title_source)]
+    #[arg(help_heading = "Links")]
+    title_fallback: Option<Vec<String>>,
+
+    /// Link embedded objects
+    ///
+    /// Append a link to the data/src URL of <object> and <embed> elements after
+    /// their fallback content, unless the fallback already links to it
+    #[arg(long)]
+    #[arg(help_heading = "Links")]
+    object_links: bool,
+
+    /// Write title attributes as footnotes
+    ///
+    /// The title of links, <abbr> and <span> elements becomes a [^n] footnote
+    /// reference, with the definitions written before the next heading and at
+    /// the end of the document
+    #[arg(long)]
+    #[arg(help_heading = "Links")]
+    title_footnotes: bool,
+
     /// Keep inline images in specific elements
     ///
     /// Comma-separated list of HTML elements where images should remain
@@ -221,6 +440,98 @@ struct Cli {
     #[arg(help_heading = "Images")]
     keep_inline_images_in: Option<Vec<String>>,
 
+    /// Surface the text of inline SVGs
+    ///
+    /// What to do with <title>, <desc> and <text> labels of inline SVGs:
+    /// - 'off': Use only the <title> as alt text (default)
+    /// - 'alt': Use all SVG text as alt text
+    /// - 'caption': Add SVG text as a caption below the image
+    /// - 'replace': Emit SVG text instead of the image
+    #[arg(long, value_name = "MODE")]
+    #[arg(help_heading = "Images")]
+    svg_text_mode: Option<CliSvgTextMode>,
+
+    /// Pick a srcset candidate for images without a src
+    ///
+    /// Which candidate of an <img> srcset, or of the <source> elements of its
+    /// <picture>, is used when the image has no plain src:
+    /// - 'first': First candidate in document order (default)
+    /// - 'largest': Candidate with the largest width or density
+    /// - 'smallest': Candidate with the smallest width or density
+    #[arg(long, value_name = "POLICY")]
+    #[arg(help_heading = "Images")]
+    srcset_policy: Option<CliSrcsetPolicy>,
+
+    /// How figure captions are written
+    ///
+    /// What to do with <figure> elements and their <figcaption>:
+    /// - 'italic': Caption as an italic paragraph after the figure (default)
+    /// - 'blockquote': Caption as a blockquote after the figure
+    /// - 'html': Keep the whole figure as HTML
+    #[arg(long, value_name = "STYLE")]
+    #[arg(help_heading = "Element Handling")]
+    figure_style: Option<CliFigureStyle>,
+
+    /// How details sections are written
+    ///
+    /// What to do with <details> and its <summary>:
+    /// - 'markdown': Summary as a bold line followed by the body (default)
+    /// - 'html': Keep the details/summary wrapper as HTML around the converted body
+    #[arg(long, value_name = "STYLE")]
+    #[arg(help_heading = "Element Handling")]
+    details_style: Option<CliDetailsStyle>,
+
+    /// How abbreviations are written
+    ///
+    /// What to do with <abbr title="...">:
+    /// - 'expand': Abbreviation followed by its title in parentheses (default)
+    /// - 'text': Abbreviation only
+    /// - 'html': Keep the abbr element as inline HTML
+    #[arg(long, value_name = "STYLE")]
+    #[arg(help_heading = "Element Handling")]
+    abbr_style: Option<CliAbbrStyle>,
+
+    /// How time elements are written
+    ///
+    /// What to do with <time datetime="...">:
+    /// - 'text': Human-readable text only (default)
+    /// - 'datetime': Text followed by the datetime value in parentheses
+    /// - 'html': Keep the time element as inline HTML
+    #[arg(long, value_name = "STYLE")]
+    #[arg(help_heading = "Element Handling")]
+    time_style: Option<CliTimeStyle>,
+
+    /// How definition terms are written
+    ///
+    /// What to do with <dfn>:
+    /// - 'emphasis': Emphasis with the strong/em symbol (default)
+    /// - 'text': Plain text
+    /// - 'html': Keep the dfn element as inline HTML
+    #[arg(long, value_name = "STYLE")]
+    #[arg(help_heading = "Element Handling")]
+    dfn_style: Option<CliDfnStyle>,
+
+    /// How mailto: and tel: links are written
+    ///
+    /// What to do with <a href="mailto:..."> and <a href="tel:...">:
+    /// - 'link': Regular link keeping the scheme (default)
+    /// - 'autolink': <address> when the link text is the address
+    /// - 'text': Plain text, with the address in parentheses when it differs
+    #[arg(long, value_name = "STYLE")]
+    #[arg(help_heading = "Element Handling")]
+    contact_link_style: Option<CliContactLinkStyle>,
+
+    /// Write MathML and TeX as Markdown math
+    ///
+    /// How <math> elements and MathJax TeX (script type="math/tex", \(...\)
+    /// and \[...\] in text) are written:
+    /// - 'comment': Keep MathML as an HTML comment followed by its text (default)
+    /// - 'dollar': $...$ inline and $$...$$ display math
+    /// - 'fenced': $`...`$ inline math and ```math display blocks
+    #[arg(long, value_name = "STYLE")]
+    #[arg(help_heading = "Element Handling")]
+    math_style: Option<CliMathStyle>,
+
     /// Use <br> in table cells
     ///
     /// Preserve line breaks in table cells using <br> tags instead of
@@ -229,6 +540,18 @@ struct Cli {
     #[arg(help_heading = "Tables")]
     br_in_tables: bool,
 
+    /// What <br> becomes inside headings and <th> header cells
+    ///
+    /// A line break would end a heading or a table row, so these contexts
+    /// need a one-line replacement:
+    /// - 'inherit': Two spaces in headings, --br-in-tables in header cells (default)
+    /// - 'space': A single space
+    /// - 'slash': " / " between the lines
+    /// - 'html': An inline <br> tag
+    #[arg(long, value_name = "STYLE")]
+    #[arg(help_heading = "Tables")]
+    header_break_style: Option<CliHeaderBreakStyle>,
+
     /// Disable spatial table reconstruction for hOCR documents
     #[arg(long = "no-hocr-spatial-tables")]
     #[arg(help_heading = "Tables")]
@@ -247,11 +570,21 @@ struct Cli {
 
     /// Extract metadata from HTML
     ///
-    /// Extract title and meta tags as HTML comment header
+    /// Extract title and meta tags as a front matter header
     #[arg(long)]
     #[arg(help_heading = "Metadata")]
     extract_metadata: bool,
 
+    /// Emit extracted metadata as front matter in the given format
+    ///
+    /// Implies --extract-metadata. Formats:
+    /// - 'yaml': --- fenced YAML (default)
+    /// - 'toml': +++ fenced TOML
+    /// - 'json': JSON object
+    #[arg(long, value_name = "FORMAT")]
+    #[arg(help_heading = "Metadata")]
+    front_matter: Option<CliFrontMatterFormat>,
+
     /// Extract comprehensive metadata and output as JSON
     ///
     /// When enabled, output will be JSON with "markdown" and "metadata" keys.
@@ -300,6 +633,24 @@ struct Cli {
     #[arg(requires = "with_metadata")]
     extract_structured_data: bool,
 
+    /// Extract external resources
+    ///
+    /// Requires --with-metadata. Lists the scripts, stylesheets, fonts, images and
+    /// media the page loads, as a fetch list for archiving.
+    #[arg(long)]
+    #[arg(help_heading = "Metadata")]
+    #[arg(requires = "with_metadata")]
+    extract_resources: bool,
+
+    /// Extract a source map
+    ///
+    /// Requires --with-metadata. Records the HTML and Markdown byte ranges of every
+    /// emitted block, for tracing output back to the input.
+    #[arg(long)]
+    #[arg(help_heading = "Metadata")]
+    #[arg(requires = "with_metadata")]
+    extract_source_map: bool,
+
     /// Whitespace handling mode
     ///
     /// How to handle whitespace in HTML:
@@ -309,6 +660,22 @@ struct Cli {
     #[arg(help_heading = "Whitespace")]
     whitespace_mode: Option<CliWhitespaceMode>,
 
+    /// Non-breaking space handling in prose
+    ///
+    /// How U+00A0 is emitted outside tables and code:
+    /// - 'space': Convert to a regular space (default)
+    /// - 'preserve': Keep the non-breaking space
+    #[arg(long, value_name = "MODE")]
+    #[arg(help_heading = "Whitespace")]
+    nbsp_in_prose: Option<CliNbspMode>,
+
+    /// Non-breaking space handling in table cells
+    ///
+    /// Use 'preserve' to keep alignment padding built from U+00A0 intact.
+    #[arg(long, value_name = "MODE")]
+    #[arg(help_heading = "Whitespace")]
+    nbsp_in_tables: Option<CliNbspMode>,
+
     /// Strip newlines from input
     ///
     /// Remove all newlines from HTML before processing (useful for
@@ -317,6 +684,52 @@ struct Cli {
     #[arg(help_heading = "Whitespace")]
     strip_newlines: bool,
 
+    /// Remove spaces before punctuation after inline elements
+    ///
+    /// Clean up artifacts like 'word **bold** .' produced by span-heavy
+    /// editors, emitting 'word **bold**.' instead
+    #[arg(long)]
+    #[arg(help_heading = "Whitespace")]
+    remove_space_before_punctuation: bool,
+
+    /// Keep soft hyphens and word joiners
+    ///
+    /// By default invisible U+00AD soft hyphens and U+2060/U+FEFF word
+    /// joiners are removed from text so they don't break search and diffs
+    #[arg(long)]
+    #[arg(help_heading = "Whitespace")]
+    keep_soft_hyphens: bool,
+
+    /// Right-to-left section handling
+    ///
+    /// How elements with a dir attribute that changes the direction are emitted:
+    /// - 'ignore': Drop the direction (default)
+    /// - 'marks': Prefix lines with RLM/LRM marks
+    /// - 'embedding': Wrap lines in RLE/LRE and PDF controls
+    /// - 'html': Wrap content in a <div dir="..."> passthrough
+    #[arg(long, value_name = "MODE")]
+    #[arg(help_heading = "Text Formatting")]
+    bidi: Option<CliBidiMode>,
+
+    /// Content without a Markdown equivalent
+    ///
+    /// Applies to <bdo>, <bdi>, <wbr> and removed soft hyphens:
+    /// - 'drop': Drop it silently (default)
+    /// - 'passthrough': Keep it as inline HTML or raw characters
+    /// - 'diagnose': Drop it and report each occurrence on stderr
+    #[arg(long, value_name = "POLICY")]
+    #[arg(help_heading = "Text Formatting")]
+    unrepresentable: Option<CliUnrepresentablePolicy>,
+
+    /// Localize generated punctuation
+    ///
+    /// Use the quotation marks and parentheses of the element's lang attribute
+    /// (e.g. „…“ for German, « … » for French) for <q> quotes and <abbr>
+    /// expansions instead of ASCII
+    #[arg(long)]
+    #[arg(help_heading = "Text Formatting")]
+    localized_punctuation: bool,
+
     /// Enable text wrapping
     ///
     /// Wrap output lines at --wrap-width columns
@@ -331,6 +744,49 @@ struct Cli {
     #[arg(help_heading = "Wrapping")]
     wrap_width: Option<u16>,
 
+    /// Cap the output at N characters
+    ///
+    /// Cut between top-level blocks so paragraphs, list items, tables and code
+    /// blocks are kept whole or dropped, and end with a '<!-- truncated -->' line
+    #[arg(long, value_name = "N")]
+    #[arg(help_heading = "Wrapping")]
+    max_output_chars: Option<usize>,
+
+    /// Cap the output at N tokens
+    ///
+    /// Like --max-output-chars, counting tokens as one per four characters
+    #[arg(long, value_name = "N")]
+    #[arg(help_heading = "Wrapping")]
+    max_output_tokens: Option<usize>,
+
+    /// Post-process the Markdown output
+    ///
+    /// Run a built-in post-processor after conversion; repeat to chain them in order:
+    /// - 'compact-blank-lines': Collapse runs of blank lines
+    /// - 'normalize-urls': Drop tracking query parameters and trailing slashes from URLs
+    /// - 'prune-empty-elements': Drop empty headings, list items and links
+    /// - 'reference-links': Turn inline links into numbered reference links
+    /// - 'toc': Insert a table of contents built from the headings
+    #[arg(long, value_name = "NAME", value_parser = validate_post_processor)]
+    #[arg(help_heading = "Post-processing")]
+    post_process: Option<Vec<String>>,
+
+    /// Query parameters normalize-urls keeps
+    ///
+    /// Comma-separated parameter names; when given, every other query parameter
+    /// is dropped from link and image URLs. A trailing '*' matches any suffix.
+    #[arg(long, value_name = "PARAMS", value_delimiter = ',')]
+    #[arg(help_heading = "Post-processing")]
+    url_param_allow: Option<Vec<String>>,
+
+    /// Query parameters normalize-urls drops
+    ///
+    /// Comma-separated parameter names, replacing the default list of tracking
+    /// parameters (utm_*, fbclid, gclid, ...). A trailing '*' matches any suffix.
+    #[arg(long, value_name = "PARAMS", value_delimiter = ',')]
+    #[arg(help_heading = "Post-processing")]
+    url_param_deny: Option<Vec<String>>,
+
     /// Treat block elements as inline
     ///
     /// Convert block-level elements without adding paragraph breaks
@@ -346,6 +802,43 @@ struct Cli {
     #[arg(help_heading = "Element Handling")]
     strip_tags: Option<Vec<String>>,
 
+    /// HTML tags to remove with their content
+    ///
+    /// Comma-separated list of HTML tags dropped together with everything
+    /// inside them. Example: "aside,figure"
+    #[arg(long, value_name = "TAGS", value_delimiter = ',')]
+    #[arg(help_heading = "Element Handling")]
+    remove_tags: Option<Vec<String>>,
+
+    /// HTML tags to keep as raw HTML
+    ///
+    /// Comma-separated list of HTML tags emitted verbatim, including their
+    /// attributes and children, for content Markdown cannot express.
+    /// Example: "video,iframe,table"
+    #[arg(long, value_name = "TAGS", value_delimiter = ',')]
+    #[arg(help_heading = "Element Handling")]
+    preserve_tags: Option<Vec<String>>,
+
+    /// Attributes kept on HTML written to the output
+    ///
+    /// Comma-separated attribute names, e.g. "colspan,rowspan,align". Elements kept
+    /// by --preserve-tags or --fidelity-threshold lose every other attribute. An entry
+    /// like "style:text-align" keeps only that declaration of the style attribute.
+    #[arg(long, value_name = "ATTRS", value_delimiter = ',')]
+    #[arg(help_heading = "Element Handling")]
+    preserved_attributes: Option<Vec<String>>,
+
+    /// Keep lossy tables, forms and MathML as HTML
+    ///
+    /// When more than this fraction (0.0-1.0) of the structural elements in a
+    /// <table>, <form> or <math> subtree would be lost in Markdown (nested
+    /// tables, merged cells, form controls, MathML with --math-style comment),
+    /// the subtree is kept as sanitized HTML and a warning is printed on stderr.
+    /// Default 1.0 disables the guard.
+    #[arg(long, value_name = "FRACTION")]
+    #[arg(help_heading = "Element Handling")]
+    fidelity_threshold: Option<f64>,
+
     /// Enable HTML preprocessing
     ///
     /// Clean up HTML before conversion (removes navigation, ads, forms, etc.)
@@ -359,6 +852,7 @@ struct Cli {
     /// - 'minimal': Basic cleanup only
     /// - 'standard': Balanced cleaning (default)
     /// - 'aggressive': Maximum cleaning for web scraping
+    /// - 'confluence': Balanced cleaning plus Confluence panels, code macros and images
     #[arg(long, value_name = "LEVEL")]
     #[arg(help_heading = "Preprocessing")]
     #[arg(requires = "preprocess")]
@@ -380,6 +874,69 @@ struct Cli {
     #[arg(requires = "preprocess")]
     keep_forms: bool,
 
+    /// Apply print stylesheet hints
+    ///
+    /// Drop elements the page hides when printed (@media print rules,
+    /// classes such as 'no-print' or 'd-print-none')
+    #[arg(long)]
+    #[arg(help_heading = "Preprocessing")]
+    #[arg(requires = "preprocess")]
+    apply_print_styles: bool,
+
+    /// Convert only the main content
+    ///
+    /// Score blocks by text and link density (like Readability) and
+    /// convert only the main article
+    #[arg(long)]
+    #[arg(help_heading = "Preprocessing")]
+    #[arg(requires = "preprocess")]
+    extract_main_content: bool,
+
+    /// Flatten declarative shadow DOM
+    ///
+    /// Replace web component hosts' children with their
+    /// <template shadowrootmode> content, filling its slots
+    #[arg(long)]
+    #[arg(help_heading = "Preprocessing")]
+    #[arg(requires = "preprocess")]
+    flatten_shadow_dom: bool,
+
+    /// Drop elements matching a CSS selector
+    ///
+    /// Elements matching the selector are removed together with their
+    /// content. Repeatable. Example: '.ads, #sidebar'
+    #[arg(long, value_name = "SELECTOR")]
+    #[arg(help_heading = "Preprocessing")]
+    #[arg(requires = "preprocess")]
+    exclude_selector: Vec<String>,
+
+    /// Convert only elements matching a CSS selector
+    ///
+    /// Everything outside the matching elements is ignored.
+    /// Example: 'article.main'
+    #[arg(long, value_name = "SELECTOR")]
+    #[arg(help_heading = "Preprocessing")]
+    #[arg(requires = "preprocess")]
+    include_selector: Option<String>,
+
+    /// Report how much text was removed
+    ///
+    /// Prints, per removal rule (navigation, forms, --remove-tags, selectors,
+    /// print styles, main content extraction), how many text characters it
+    /// dropped and what share of the page that was
+    #[arg(long)]
+    #[arg(help_heading = "Preprocessing")]
+    report_dropped_content: bool,
+
+    /// Convert concatenated documents one at a time
+    ///
+    /// For input made of several complete HTML documents glued together, such
+    /// as log exports or email digests: each document is converted on its own
+    /// and the results are separated by '---'
+    #[arg(long)]
+    #[arg(help_heading = "Parsing")]
+    split_documents: bool,
+
     /// Input character encoding
     ///
     /// Encoding to use when reading input files (e.g., 'utf-8', 'latin-1')
@@ -393,6 +950,59 @@ struct Cli {
     #[arg(long)]
     #[arg(help_heading = "Debugging")]
     debug: bool,
+
+    /// Show a progress bar on stderr while converting
+    ///
+    /// Useful for very large documents. Only applies to plain single-document conversions,
+    /// not to --with-metadata, diagnostics or batch mode.
+    #[arg(long)]
+    #[arg(help_heading = "Debugging")]
+    progress: bool,
+
+    /// Write a replay bundle for a bug report
+    ///
+    /// Packages the input bytes (after decompression), the effective options, the
+    /// version and the Markdown it converts to into a zstd-compressed tar archive,
+    /// e.g. bundle.tar.zst, which maintainers replay with --replay-repro. The normal
+    /// output is written as usual. With --url the decoded page is stored.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["input_dir", "ndjson", "warc", "detect_format"])]
+    #[arg(help_heading = "Debugging")]
+    capture_repro: Option<PathBuf>,
+
+    /// Replay a bundle written by --capture-repro and exit
+    ///
+    /// Converts the captured input with the captured options and prints the result.
+    /// Exits with an error when it differs from the captured output.
+    #[arg(long, value_name = "FILE", conflicts_with = "capture_repro")]
+    #[arg(help_heading = "Debugging")]
+    replay_repro: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run an HTTP server exposing POST /convert
+    ///
+    /// The request body is the HTML to convert and the response is the Markdown. Options
+    /// can be passed as query parameters (?headingStyle=underlined&wrap=true), or send
+    /// Content-Type: application/json with {"html": "...", "options": {...}} to get
+    /// {"markdown": "..."} back. Conversion options given before `serve` are the defaults.
+    Serve(ServeArgs),
+}
+
+#[derive(Args)]
+struct ServeArgs {
+    /// Port to listen on (0 picks a free port)
+    #[arg(short, long, default_value_t = 8080)]
+    port: u16,
+
+    /// Address to bind to
+    #[arg(long, value_name = "ADDR", default_value = "127.0.0.1")]
+    host: String,
+
+    /// Number of worker threads (default: one per CPU)
+    #[arg(short = 'j', long, value_name = "N")]
+    #[arg(value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
@@ -502,6 +1112,292 @@ impl From<CliHighlightStyle> for HighlightStyle {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliFrontMatterFormat {
+    /// YAML between --- fences (default)
+    Yaml,
+    /// TOML between +++ fences
+    Toml,
+    /// JSON object
+    Json,
+}
+
+impl From<CliFrontMatterFormat> for FrontMatterFormat {
+    fn from(value: CliFrontMatterFormat) -> Self {
+        match value {
+            CliFrontMatterFormat::Yaml => Self::Yaml,
+            CliFrontMatterFormat::Toml => Self::Toml,
+            CliFrontMatterFormat::Json => Self::Json,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliBidiMode {
+    /// Ignore dir attributes (default)
+    Ignore,
+    /// Prefix lines with RLM/LRM marks
+    Marks,
+    /// Wrap lines in RLE/LRE ... PDF controls
+    Embedding,
+    /// Wrap content in a <div dir="..."> passthrough
+    Html,
+}
+
+impl From<CliBidiMode> for BidiMode {
+    fn from(value: CliBidiMode) -> Self {
+        match value {
+            CliBidiMode::Ignore => Self::Ignore,
+            CliBidiMode::Marks => Self::Marks,
+            CliBidiMode::Embedding => Self::Embedding,
+            CliBidiMode::Html => Self::Html,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliListSpacing {
+    /// Follow the source document (default)
+    Auto,
+    /// No blank lines between list items
+    Tight,
+    /// Blank line between every list item
+    Loose,
+}
+
+impl From<CliListSpacing> for ListSpacing {
+    fn from(value: CliListSpacing) -> Self {
+        match value {
+            CliListSpacing::Auto => Self::Auto,
+            CliListSpacing::Tight => Self::Tight,
+            CliListSpacing::Loose => Self::Loose,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliUnrepresentablePolicy {
+    /// Drop it silently (default)
+    Drop,
+    /// Keep it as inline HTML or raw characters
+    Passthrough,
+    /// Drop it and record a diagnostic
+    Diagnose,
+}
+
+impl From<CliUnrepresentablePolicy> for UnrepresentablePolicy {
+    fn from(value: CliUnrepresentablePolicy) -> Self {
+        match value {
+            CliUnrepresentablePolicy::Drop => Self::Drop,
+            CliUnrepresentablePolicy::Passthrough => Self::Passthrough,
+            CliUnrepresentablePolicy::Diagnose => Self::Diagnose,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliSvgTextMode {
+    /// Use only the <title> as alt text (default)
+    Off,
+    /// Use all SVG text as alt text
+    Alt,
+    /// Add SVG text as a caption below the image
+    Caption,
+    /// Emit SVG text instead of the image
+    Replace,
+}
+
+impl From<CliSvgTextMode> for SvgTextMode {
+    fn from(value: CliSvgTextMode) -> Self {
+        match value {
+            CliSvgTextMode::Off => Self::Off,
+            CliSvgTextMode::Alt => Self::Alt,
+            CliSvgTextMode::Caption => Self::Caption,
+            CliSvgTextMode::Replace => Self::Replace,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliMathStyle {
+    /// Keep MathML as an HTML comment followed by its text (default)
+    Comment,
+    /// $...$ inline and $$...$$ display math
+    Dollar,
+    /// $`...`$ inline math and ```math display blocks
+    Fenced,
+}
+
+impl From<CliMathStyle> for MathStyle {
+    fn from(value: CliMathStyle) -> Self {
+        match value {
+            CliMathStyle::Comment => Self::Comment,
+            CliMathStyle::Dollar => Self::Dollar,
+            CliMathStyle::Fenced => Self::Fenced,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliSrcsetPolicy {
+    /// First candidate in document order (default)
+    First,
+    /// Candidate with the largest width or density
+    Largest,
+    /// Candidate with the smallest width or density
+    Smallest,
+}
+
+impl From<CliSrcsetPolicy> for SrcsetPolicy {
+    fn from(value: CliSrcsetPolicy) -> Self {
+        match value {
+            CliSrcsetPolicy::First => Self::First,
+            CliSrcsetPolicy::Largest => Self::Largest,
+            CliSrcsetPolicy::Smallest => Self::Smallest,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliFigureStyle {
+    /// Caption as an italic paragraph after the figure (default)
+    Italic,
+    /// Caption as a blockquote after the figure
+    Blockquote,
+    /// Keep the whole figure as HTML
+    Html,
+}
+
+impl From<CliFigureStyle> for FigureStyle {
+    fn from(value: CliFigureStyle) -> Self {
+        match value {
+            CliFigureStyle::Italic => Self::Italic,
+            CliFigureStyle::Blockquote => Self::Blockquote,
+            CliFigureStyle::Html => Self::Html,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliDetailsStyle {
+    /// Bold summary line followed by the body (default)
+    Markdown,
+    /// Keep the details/summary wrapper as HTML
+    Html,
+}
+
+impl From<CliDetailsStyle> for DetailsStyle {
+    fn from(value: CliDetailsStyle) -> Self {
+        match value {
+            CliDetailsStyle::Markdown => Self::Markdown,
+            CliDetailsStyle::Html => Self::Html,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliAbbrStyle {
+    /// Abbreviation followed by its title in parentheses (default)
+    Expand,
+    /// Abbreviation only
+    Text,
+    /// Keep the abbr element as HTML
+    Html,
+}
+
+impl From<CliAbbrStyle> for AbbrStyle {
+    fn from(value: CliAbbrStyle) -> Self {
+        match value {
+            CliAbbrStyle::Expand => Self::Expand,
+            CliAbbrStyle::Text => Self::Text,
+            CliAbbrStyle::Html => Self::Html,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliTimeStyle {
+    /// Human-readable text only (default)
+    Text,
+    /// Text followed by the datetime value in parentheses
+    Datetime,
+    /// Keep the time element as HTML
+    Html,
+}
+
+impl From<CliTimeStyle> for TimeStyle {
+    fn from(value: CliTimeStyle) -> Self {
+        match value {
+            CliTimeStyle::Text => Self::Text,
+            CliTimeStyle::Datetime => Self::Datetime,
+            CliTimeStyle::Html => Self::Html,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliDfnStyle {
+    /// Emphasis with strong_em_symbol (default)
+    Emphasis,
+    /// Plain text
+    Text,
+    /// Keep the dfn element as HTML
+    Html,
+}
+
+impl From<CliDfnStyle> for DfnStyle {
+    fn from(value: CliDfnStyle) -> Self {
+        match value {
+            CliDfnStyle::Emphasis => Self::Emphasis,
+            CliDfnStyle::Text => Self::Text,
+            CliDfnStyle::Html => Self::Html,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliContactLinkStyle {
+    /// Regular link keeping the scheme (default)
+    Link,
+    /// Autolink when the text is the address
+    Autolink,
+    /// Plain text with the address in parentheses
+    Text,
+}
+
+impl From<CliContactLinkStyle> for ContactLinkStyle {
+    fn from(value: CliContactLinkStyle) -> Self {
+        match value {
+            CliContactLinkStyle::Link => Self::Link,
+            CliContactLinkStyle::Autolink => Self::Autolink,
+            CliContactLinkStyle::Text => Self::Text,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliHeaderBreakStyle {
+    /// Two spaces in headings, --br-in-tables in header cells (default)
+    Inherit,
+    /// A single space
+    Space,
+    /// " / " between the lines
+    Slash,
+    /// An inline <br> tag
+    Html,
+}
+
+impl From<CliHeaderBreakStyle> for HeaderBreakStyle {
+    fn from(value: CliHeaderBreakStyle) -> Self {
+        match value {
+            CliHeaderBreakStyle::Inherit => Self::Inherit,
+            CliHeaderBreakStyle::Space => Self::Space,
+            CliHeaderBreakStyle::Slash => Self::Slash,
+            CliHeaderBreakStyle::Html => Self::Html,
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
 enum CliWhitespaceMode {
     /// Normalize whitespace (default)
@@ -519,6 +1415,46 @@ impl From<CliWhitespaceMode> for WhitespaceMode {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliNbspMode {
+    /// Convert to a regular space (default)
+    Space,
+    /// Keep U+00A0 as-is
+    Preserve,
+}
+
+impl From<CliNbspMode> for NbspMode {
+    fn from(mode: CliNbspMode) -> Self {
+        match mode {
+            CliNbspMode::Space => Self::Space,
+            CliNbspMode::Preserve => Self::Preserve,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum CliProfile {
+    /// Replies and snippets for chat interfaces
+    Chat,
+    /// Documentation sites
+    Docs,
+    /// Arbitrary web pages
+    Scraping,
+    /// Text for language model prompts
+    Llm,
+}
+
+impl From<CliProfile> for ConversionPreset {
+    fn from(profile: CliProfile) -> Self {
+        match profile {
+            CliProfile::Chat => Self::Chat,
+            CliProfile::Docs => Self::Docs,
+            CliProfile::Scraping => Self::Scraping,
+            CliProfile::Llm => Self::Llm,
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
 enum CliPreprocessingPreset {
     /// Basic cleanup
@@ -527,6 +1463,8 @@ enum CliPreprocessingPreset {
     Standard,
     /// Maximum cleaning
     Aggressive,
+    /// Balanced cleaning plus Confluence storage-format macros
+    Confluence,
 }
 
 impl From<CliPreprocessingPreset> for PreprocessingPreset {
@@ -535,6 +1473,7 @@ impl From<CliPreprocessingPreset> for PreprocessingPreset {
             CliPreprocessingPreset::Minimal => Self::Minimal,
             CliPreprocessingPreset::Standard => Self::Standard,
             CliPreprocessingPreset::Aggressive => Self::Aggressive,
+            CliPreprocessingPreset::Confluence => Self::Confluence,
         }
     }
 }
@@ -549,6 +1488,34 @@ fn validate_bullets(s: &str) -> Result<String, String> {
     Ok(s.to_string())
 }
 
+fn validate_ordered_markers(s: &str) -> Result<String, String> {
+    if s.is_empty() {
+        return Err("ordered markers cannot be empty".to_string());
+    }
+    if s.len() > 10 {
+        return Err("ordered markers string too long (max 10 characters)".to_string());
+    }
+    if !s.chars().all(|c| c == '.' || c == ')') {
+        return Err("ordered markers must only contain '.' or ')'".to_string());
+    }
+    Ok(s.to_string())
+}
+
+fn validate_post_processor(s: &str) -> Result<String, String> {
+    BuiltinPostProcessor::from_name(s)
+        .map(|builtin| builtin.name().to_string())
+        .ok_or_else(|| {
+            let known: Vec<&str> = BuiltinPostProcessor::ALL.iter().map(|builtin| builtin.name()).collect();
+            format!("unknown post-processor '{s}' (expected one of: {})", known.join(", "))
+        })
+}
+
+fn validate_title_source(s: &str) -> Result<String, String> {
+    TitleSource::parse(s)
+        .map(|_| s.to_string())
+        .ok_or_else(|| format!("unknown title source '{s}' (expected one of: og-title, first-heading, url-basename)"))
+}
+
 fn validate_strong_em_symbol(s: &str) -> Result<char, String> {
     if s.len() != 1 {
         return Err("strong_em_symbol must be exactly one character".to_string());
@@ -560,9 +1527,9 @@ fn validate_strong_em_symbol(s: &str) -> Result<char, String> {
     Ok(c)
 }
 
-fn decode_bytes(bytes: &[u8], encoding_name: &str) -> Result<String, String> {
-    let lowercase = encoding_name.to_lowercase();
-    let normalized = match lowercase.as_str() {
+/// Map the `latin-N` spellings accepted by `--encoding` to WHATWG encoding labels.
+fn encoding_label(encoding_name: &str) -> &str {
+    match encoding_name.to_lowercase().as_str() {
         "latin-1" | "latin1" => "iso-8859-1",
         "latin-2" | "latin2" => "iso-8859-2",
         "latin-3" | "latin3" => "iso-8859-3",
@@ -574,8 +1541,11 @@ fn decode_bytes(bytes: &[u8], encoding_name: &str) -> Result<String, String> {
         "latin-9" | "latin9" => "iso-8859-9",
         "latin-10" | "latin10" => "iso-8859-10",
         _ => encoding_name,
-    };
+    }
+}
 
+fn decode_bytes(bytes: &[u8], encoding_name: &str) -> Result<String, String> {
+    let normalized = encoding_label(encoding_name);
     let encoding =
         Encoding::for_label(normalized.as_bytes()).ok_or_else(|| format!("Unknown encoding '{encoding_name}'"))?;
 
@@ -586,43 +1556,58 @@ fn decode_bytes(bytes: &[u8], encoding_name: &str) -> Result<String, String> {
     Ok(decoded.into_owned())
 }
 
-fn extract_charset(content_type: &str) -> Option<String> {
-    content_type
-        .split(';')
-        .map(str::trim)
-        .find_map(|part| part.strip_prefix("charset=").map(|v| v.trim_matches('"').to_string()))
+fn fetch_url(url: &str, user_agent: &str, default_encoding: &str) -> Result<String, String> {
+    let fetcher = Fetcher::new(FetchConfig {
+        user_agent: user_agent.to_string(),
+        ..FetchConfig::default()
+    })
+    .map_err(|e| e.to_string())?;
+    let response = fetcher.fetch(url).map_err(|e| e.to_string())?;
+
+    let hint = decompress::Compression::from_path(Path::new(response.url.split(['?', '#']).next().unwrap_or_default()));
+    let encoding_name = response.charset().unwrap_or(default_encoding).to_string();
+    let bytes = decompress::maybe_decompress(response.body, hint)?;
+    decode_bytes(&bytes, &encoding_name)
 }
 
-fn fetch_url(url: &str, user_agent: &str, default_encoding: &str) -> Result<String, String> {
-    let client = Client::builder()
-        .timeout(Duration::from_secs(15))
-        .redirect(reqwest::redirect::Policy::limited(5))
-        .build()
-        .map_err(|e| format!("Failed to build HTTP client: {e}"))?;
+fn read_stdin() -> Result<Vec<u8>, String> {
+    let mut buffer = Vec::new();
+    io::stdin()
+        .read_to_end(&mut buffer)
+        .map_err(|e| format!("Error reading from stdin: {e}"))?;
+    decompress::maybe_decompress(buffer, None)
+}
 
-    let response = client
-        .get(url)
-        .header(USER_AGENT, user_agent)
-        .send()
-        .map_err(|e| format!("Failed to fetch '{url}': {e}"))?;
+fn write_repro_bundle(path: &Path, input: &[u8], encoding: &str, options: &ConversionOptions) -> Result<(), String> {
+    let bundle = ReproBundle::capture(input, Some(encoding), options);
+    let file = fs::File::create(path).map_err(|e| format!("Error writing to file '{}': {e}", paths::display(path)))?;
+    bundle
+        .write_to(io::BufWriter::new(file))
+        .map_err(|e| format!("Error writing repro bundle '{}': {e}", paths::display(path)))
+}
 
-    let status = response.status();
-    if !status.is_success() {
-        return Err(format!("Request failed for '{url}': HTTP {status}"));
+fn replay_repro_bundle(path: &Path) -> Result<(), String> {
+    let file = fs::File::open(path).map_err(|e| format!("Error reading file '{}': {e}", paths::display(path)))?;
+    let bundle = ReproBundle::read_from(io::BufReader::new(file))
+        .map_err(|e| format!("Error reading repro bundle '{}': {e}", paths::display(path)))?;
+
+    let replay = bundle.replay();
+    if !replay.same_version {
+        eprintln!(
+            "warning: bundle was captured with version {}, replaying with {}",
+            bundle.version,
+            env!("CARGO_PKG_VERSION")
+        );
+    }
+    match &replay.outcome {
+        Ok(markdown) => print!("{markdown}"),
+        Err(message) => eprintln!("Error converting HTML: {message}"),
+    }
+    if replay.matches {
+        Ok(())
+    } else {
+        Err("replayed output differs from the captured output".to_string())
     }
-
-    let charset = response
-        .headers()
-        .get(CONTENT_TYPE)
-        .and_then(|value| value.to_str().ok())
-        .and_then(extract_charset);
-
-    let bytes = response
-        .bytes()
-        .map_err(|e| format!("Failed to read response body from '{url}': {e}"))?;
-
-    let encoding_name = charset.as_deref().unwrap_or(default_encoding);
-    decode_bytes(&bytes, encoding_name)
 }
 
 fn generate_completions(shell: Shell) {
@@ -657,6 +1642,151 @@ fn generate_man_page() -> Result<(), String> {
     Ok(())
 }
 
+fn convert_document(
+    html: &str,
+    options: ConversionOptions,
+    metadata_config: Option<MetadataConfig>,
+    debug: bool,
+    show_progress: bool,
+) -> Result<String, String> {
+    if let Some(metadata_config) = metadata_config {
+        let (markdown, metadata) = convert_with_metadata(html, Some(options), metadata_config, None)
+            .map_err(|e| format!("Error converting HTML with metadata: {e}"))?;
+
+        if debug {
+            eprintln!("Generated {} bytes of markdown with metadata", markdown.len());
+        }
+
+        let output = json!({
+            "markdown": markdown,
+            "metadata": metadata
+        });
+
+        serde_json::to_string_pretty(&output).map_err(|e| format!("Error serializing JSON: {e}"))
+    } else {
+        let markdown = if options.unrepresentable_policy == UnrepresentablePolicy::Diagnose
+            || options.fidelity_threshold < 1.0
+            || options.report_dropped_content
+        {
+            let (markdown, diagnostics) =
+                convert_with_diagnostics(html, Some(options)).map_err(|e| format!("Error converting HTML: {e}"))?;
+            for diagnostic in &diagnostics {
+                eprintln!("warning: {}", diagnostic.message);
+            }
+            markdown
+        } else if show_progress {
+            convert_with_progress(
+                html,
+                Some(options),
+                progress::interval(html.len()),
+                progress::reporter(),
+            )
+            .map_err(|e| format!("Error converting HTML: {e}"))?
+        } else {
+            convert(html, Some(options)).map_err(|e| format!("Error converting HTML: {e}"))?
+        };
+
+        if debug {
+            eprintln!("Generated {} bytes of markdown", markdown.len());
+        }
+
+        Ok(markdown)
+    }
+}
+
+fn convert_ndjson_request(
+    html: &str,
+    options: &ConversionOptions,
+    metadata_config: Option<&MetadataConfig>,
+) -> Result<ndjson::Converted, String> {
+    let Some(metadata_config) = metadata_config else {
+        return convert(html, Some(options.clone()))
+            .map(|markdown| (markdown, None))
+            .map_err(|e| e.to_string());
+    };
+    let (markdown, metadata) =
+        convert_with_metadata(html, Some(options.clone()), metadata_config.clone(), None).map_err(|e| e.to_string())?;
+    let metadata = serde_json::to_value(metadata).map_err(|e| format!("Error serializing metadata: {e}"))?;
+    Ok((markdown, Some(metadata)))
+}
+
+fn convert_warc_archive(
+    input: Box<dyn Read>,
+    mut output: Box<dyn IoWrite>,
+    options: &ConversionOptions,
+) -> Result<(), String> {
+    let write_error = |e: io::Error| format!("Error writing output: {e}");
+    let mut failed = 0usize;
+    for record in WarcReader::new(input) {
+        let line = match record {
+            Ok(record) => match convert(&record.html, Some(options.clone())) {
+                Ok(markdown) => json!({
+                    "target_uri": record.target_uri,
+                    "date": record.date,
+                    "record_id": record.record_id,
+                    "markdown": markdown,
+                }),
+                Err(e) => {
+                    failed += 1;
+                    json!({
+                        "target_uri": record.target_uri,
+                        "date": record.date,
+                        "record_id": record.record_id,
+                        "error": e.to_string(),
+                    })
+                }
+            },
+            Err(e) => {
+                failed += 1;
+                json!({ "error": e.to_string() })
+            }
+        };
+        serde_json::to_writer(&mut output, &line).map_err(|e| format!("Error writing output: {e}"))?;
+        output.write_all(b"\n").map_err(write_error)?;
+    }
+    output.flush().map_err(write_error)?;
+    if failed > 0 {
+        return Err(format!("{failed} WARC record(s) could not be converted"));
+    }
+    Ok(())
+}
+
+fn convert_directory(
+    job: &batch::BatchJob<'_>,
+    encoding: &str,
+    options: &ConversionOptions,
+    metadata_config: Option<&MetadataConfig>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let summary = batch::run(job, |bytes| {
+        let html = decode_bytes(bytes, encoding)?;
+        convert_document(&html, options.clone(), metadata_config.cloned(), false, false)
+    })?;
+
+    let total = summary.converted + summary.failures.len();
+    eprintln!(
+        "Converted {} of {} file(s) into '{}'",
+        summary.converted,
+        total,
+        paths::display(job.output_dir)
+    );
+    if !summary.boilerplate.is_empty() {
+        eprintln!("Removed {} boilerplate block(s):", summary.boilerplate.blocks.len());
+        for block in &summary.boilerplate.blocks {
+            let first_line = block.text.lines().next().unwrap_or_default();
+            eprintln!("  [{} file(s)] {first_line}", block.documents);
+        }
+    }
+    if summary.failures.is_empty() {
+        return Ok(());
+    }
+
+    eprintln!("{} file(s) failed:", summary.failures.len());
+    for (path, err) in &summary.failures {
+        eprintln!("  {}: {err}", paths::display(path));
+    }
+    Err(format!("{} of {total} file(s) failed to convert", summary.failures.len()).into())
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
@@ -670,44 +1800,45 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
-    let html = match cli.input.as_deref() {
-        _ if cli.url.is_some() => {
-            let user_agent = cli.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
-            let fetched = fetch_url(cli.url.as_deref().unwrap(), user_agent, &cli.encoding)?;
-            if cli.debug {
-                eprintln!("Fetched {} bytes from URL", fetched.len());
-            }
-            fetched
-        }
-        None | Some("-") => {
-            let mut buffer = Vec::new();
-            io::stdin()
-                .read_to_end(&mut buffer)
-                .map_err(|e| format!("Error reading from stdin: {e}"))?;
-            let decoded = decode_bytes(&buffer, &cli.encoding)?;
-            if cli.debug {
-                eprintln!("Read {} bytes from stdin", decoded.len());
-            }
-            decoded
-        }
-        Some(path) => {
-            let path = PathBuf::from(path);
-            let bytes = fs::read(&path).map_err(|e| format!("Error reading file '{}': {}", path.display(), e))?;
-            let decoded = decode_bytes(&bytes, &cli.encoding)?;
-            if cli.debug {
-                eprintln!("Read {} bytes from file '{}'", decoded.len(), path.display());
-            }
-            decoded
-        }
-    };
+    if cli.print_config_schema {
+        let schema = serde_json::to_string_pretty(&conversion_options_json_schema())?;
+        println!("{schema}");
+        return Ok(());
+    }
 
-    let defaults = ConversionOptions::default();
+    if let Some(path) = cli.replay_repro.map(paths::normalize) {
+        return replay_repro_bundle(&path).map_err(Into::into);
+    }
+
+    let config_path = match cli.config.clone() {
+        Some(path) => Some(paths::normalize(path)),
+        None if !cli.no_config => std::env::current_dir().ok().and_then(|dir| config::discover(&dir)),
+        None => None,
+    };
+    let mut defaults = cli.profile.map_or_else(ConversionOptions::default, |profile| {
+        ConversionPreset::from(profile).options()
+    });
+    if let Some(path) = config_path {
+        if cli.debug {
+            eprintln!("Using config file '{}'", paths::display(&path));
+        }
+        defaults.apply_update(config::load(&path)?);
+    }
 
     let preprocessing = PreprocessingOptions {
-        enabled: cli.preprocess,
-        preset: cli.preset.map(Into::into).unwrap_or_default(),
-        remove_navigation: !cli.keep_navigation,
-        remove_forms: !cli.keep_forms,
+        enabled: cli.preprocess || defaults.preprocessing.enabled,
+        preset: cli.preset.map_or(defaults.preprocessing.preset, Into::into),
+        remove_navigation: defaults.preprocessing.remove_navigation && !cli.keep_navigation,
+        remove_forms: defaults.preprocessing.remove_forms && !cli.keep_forms,
+        apply_print_styles: cli.apply_print_styles || defaults.preprocessing.apply_print_styles,
+        extract_main_content: cli.extract_main_content || defaults.preprocessing.extract_main_content,
+        flatten_shadow_dom: cli.flatten_shadow_dom || defaults.preprocessing.flatten_shadow_dom,
+        exclude_selectors: if cli.exclude_selector.is_empty() {
+            defaults.preprocessing.exclude_selectors
+        } else {
+            cli.exclude_selector
+        },
+        include_selector: cli.include_selector.or(defaults.preprocessing.include_selector),
     };
 
     let options = ConversionOptions {
@@ -716,76 +1847,201 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         list_indent_width: cli.list_indent_width.map_or(defaults.list_indent_width, |w| w as usize),
         bullets: cli.bullets.unwrap_or(defaults.bullets),
         strong_em_symbol: cli.strong_em_symbol.unwrap_or(defaults.strong_em_symbol),
-        escape_asterisks: cli.escape_asterisks,
-        escape_underscores: cli.escape_underscores,
-        escape_misc: cli.escape_misc,
-        escape_ascii: cli.escape_ascii,
+        escape_asterisks: cli.escape_asterisks || defaults.escape_asterisks,
+        escape_underscores: cli.escape_underscores || defaults.escape_underscores,
+        escape_misc: cli.escape_misc || defaults.escape_misc,
+        escape_ascii: cli.escape_ascii || defaults.escape_ascii,
         code_language: cli.code_language.unwrap_or(defaults.code_language),
-        autolinks: cli.autolinks,
-        default_title: cli.default_title,
-        br_in_tables: cli.br_in_tables,
-        hocr_spatial_tables: if cli.no_hocr_spatial_tables {
-            false
-        } else {
-            defaults.hocr_spatial_tables
-        },
+        autolinks: cli.autolinks || defaults.autolinks,
+        default_title: cli.default_title || defaults.default_title,
+        br_in_tables: cli.br_in_tables || defaults.br_in_tables,
+        hocr_spatial_tables: defaults.hocr_spatial_tables && !cli.no_hocr_spatial_tables,
         highlight_style: cli.highlight_style.map_or(defaults.highlight_style, Into::into),
-        extract_metadata: cli.extract_metadata,
+        extract_metadata: cli.extract_metadata || cli.front_matter.is_some() || defaults.extract_metadata,
         whitespace_mode: cli.whitespace_mode.map_or(defaults.whitespace_mode, Into::into),
-        strip_newlines: cli.strip_newlines,
-        wrap: cli.wrap,
+        strip_newlines: cli.strip_newlines || defaults.strip_newlines,
+        wrap: cli.wrap || defaults.wrap,
         wrap_width: cli.wrap_width.map_or(defaults.wrap_width, |w| w as usize),
-        convert_as_inline: cli.convert_as_inline,
+        convert_as_inline: cli.convert_as_inline || defaults.convert_as_inline,
         sub_symbol: cli.sub_symbol.unwrap_or(defaults.sub_symbol),
         sup_symbol: cli.sup_symbol.unwrap_or(defaults.sup_symbol),
         newline_style: cli.newline_style.map_or(defaults.newline_style, Into::into),
         code_block_style: cli.code_block_style.map_or(defaults.code_block_style, Into::into),
         keep_inline_images_in: cli.keep_inline_images_in.unwrap_or(defaults.keep_inline_images_in),
-        skip_images: false,
+        skip_images: defaults.skip_images,
         preprocessing,
         encoding: cli.encoding.clone(),
-        debug: cli.debug,
+        debug: cli.debug || defaults.debug,
         strip_tags: cli.strip_tags.unwrap_or(defaults.strip_tags),
-        preserve_tags: Vec::new(),
+        remove_tags: cli.remove_tags.unwrap_or(defaults.remove_tags),
+        preserve_tags: cli.preserve_tags.unwrap_or(defaults.preserve_tags),
+        remove_space_before_punctuation: cli.remove_space_before_punctuation
+            || defaults.remove_space_before_punctuation,
+        nbsp_in_prose: cli.nbsp_in_prose.map_or(defaults.nbsp_in_prose, Into::into),
+        nbsp_in_tables: cli.nbsp_in_tables.map_or(defaults.nbsp_in_tables, Into::into),
+        front_matter_format: cli.front_matter.map_or(defaults.front_matter_format, Into::into),
+        remove_soft_hyphens: defaults.remove_soft_hyphens && !cli.keep_soft_hyphens,
+        bidi_mode: cli.bidi.map_or(defaults.bidi_mode, Into::into),
+        localized_punctuation: cli.localized_punctuation || defaults.localized_punctuation,
+        ordered_markers: cli.ordered_markers.unwrap_or(defaults.ordered_markers),
+        cycle_bullets: defaults.cycle_bullets && !cli.same_bullet,
+        list_spacing: cli.list_spacing.map_or(defaults.list_spacing, Into::into),
+        unrepresentable_policy: cli.unrepresentable.map_or(defaults.unrepresentable_policy, Into::into),
+        post_processors: cli
+            .post_process
+            .as_deref()
+            .map_or(defaults.post_processors, PostProcessorChain::from_names),
+        collect_stats: defaults.collect_stats,
+        title_fallback: cli
+            .title_fallback
+            .as_deref()
+            .map_or(defaults.title_fallback, TitleSource::from_names),
+        pandoc_attributes: cli.pandoc_attributes || defaults.pandoc_attributes,
+        svg_text_mode: cli.svg_text_mode.map_or(defaults.svg_text_mode, Into::into),
+        object_links: cli.object_links || defaults.object_links,
+        preserve_heading_ids: cli.preserve_heading_ids || defaults.preserve_heading_ids,
+        content_fingerprint: defaults.content_fingerprint,
+        math_style: cli.math_style.map_or(defaults.math_style, Into::into),
+        infer_code_language: cli.infer_code_language || defaults.infer_code_language,
+        code_block_trim: defaults.code_block_trim && !cli.no_code_block_trim,
+        srcset_policy: cli.srcset_policy.map_or(defaults.srcset_policy, Into::into),
+        figure_style: cli.figure_style.map_or(defaults.figure_style, Into::into),
+        details_style: cli.details_style.map_or(defaults.details_style, Into::into),
+        abbr_style: cli.abbr_style.map_or(defaults.abbr_style, Into::into),
+        time_style: cli.time_style.map_or(defaults.time_style, Into::into),
+        dfn_style: cli.dfn_style.map_or(defaults.dfn_style, Into::into),
+        url_param_allowlist: cli.url_param_allow.unwrap_or(defaults.url_param_allowlist),
+        url_param_denylist: cli.url_param_deny.unwrap_or(defaults.url_param_denylist),
+        contact_link_style: cli.contact_link_style.map_or(defaults.contact_link_style, Into::into),
+        fidelity_threshold: cli.fidelity_threshold.unwrap_or(defaults.fidelity_threshold),
+        title_footnotes: cli.title_footnotes || defaults.title_footnotes,
+        header_break_style: cli.header_break_style.map_or(defaults.header_break_style, Into::into),
+        report_dropped_content: cli.report_dropped_content || defaults.report_dropped_content,
+        split_documents: cli.split_documents || defaults.split_documents,
+        preserved_attributes: cli.preserved_attributes.unwrap_or(defaults.preserved_attributes),
+        max_output_chars: cli
+            .max_output_chars
+            .or(defaults.max_output_chars)
+            .filter(|&max| max > 0),
+        max_output_tokens: cli
+            .max_output_tokens
+            .or(defaults.max_output_tokens)
+            .filter(|&max| max > 0),
+        token_estimator: defaults.token_estimator,
     };
 
-    let output_content = if cli.with_metadata {
-        let metadata_config = MetadataConfig {
-            extract_document: cli.extract_document,
-            extract_headers: cli.extract_headers,
-            extract_links: cli.extract_links,
-            extract_images: cli.extract_images,
-            extract_structured_data: cli.extract_structured_data,
-            max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
+    let metadata_config = cli.with_metadata.then(|| MetadataConfig {
+        extract_document: cli.extract_document,
+        extract_headers: cli.extract_headers,
+        extract_links: cli.extract_links,
+        extract_images: cli.extract_images,
+        extract_structured_data: cli.extract_structured_data,
+        extract_resources: cli.extract_resources,
+        extract_source_map: cli.extract_source_map,
+        max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
+    });
+
+    if let Some(Command::Serve(args)) = &cli.command {
+        let workers = args
+            .jobs
+            .map_or_else(|| thread::available_parallelism().map_or(1, usize::from), usize::from);
+        let addr = if args.host.contains(':') {
+            format!("[{}]:{}", args.host, args.port)
+        } else {
+            format!("{}:{}", args.host, args.port)
         };
+        return serve::run(&addr, workers, options).map_err(Into::into);
+    }
 
-        let (markdown, metadata) = convert_with_metadata(&html, Some(options), metadata_config, None)
-            .map_err(|e| format!("Error converting HTML with metadata: {e}"))?;
-
-        if cli.debug {
-            eprintln!("Generated {} bytes of markdown with metadata", markdown.len());
-        }
+    if let Some(input_dir) = cli.input_dir.map(paths::normalize) {
+        let boilerplate_config = BoilerplateConfig::default();
+        let output_dir = cli.output_dir.map_or_else(|| PathBuf::from("."), paths::normalize);
+        let job = batch::BatchJob {
+            input_dir: &input_dir,
+            output_dir: &output_dir,
+            pattern: cli.glob.as_deref().unwrap_or("**/*.html"),
+            jobs: cli.jobs.map(usize::from),
+            extension: if metadata_config.is_some() { "json" } else { "md" },
+            boilerplate: cli.strip_boilerplate.then_some(&boilerplate_config),
+        };
+        return convert_directory(&job, &cli.encoding, &options, metadata_config.as_ref());
+    }
 
-        let output = json!({
-            "markdown": markdown,
-            "metadata": metadata
-        });
+    if cli.ndjson {
+        let stdin = io::stdin().lock();
+        let stdout = io::stdout().lock();
+        ndjson::run(stdin, stdout, |html| {
+            convert_ndjson_request(html, &options, metadata_config.as_ref())
+        })
+        .map_err(|e| format!("Error in NDJSON stream: {e}"))?;
+        return Ok(());
+    }
 
-        serde_json::to_string_pretty(&output).map_err(|e| format!("Error serializing JSON: {e}"))?
-    } else {
-        let markdown = convert(&html, Some(options)).map_err(|e| format!("Error converting HTML: {e}"))?;
+    let input = cli.input.map(paths::normalize);
 
-        if cli.debug {
-            eprintln!("Generated {} bytes of markdown", markdown.len());
+    if cli.warc {
+        let reader: Box<dyn Read> = match input.as_deref() {
+            Some(path) if path != Path::new("-") => Box::new(
+                fs::File::open(path).map_err(|e| format!("Error reading file '{}': {e}", paths::display(path)))?,
+            ),
+            _ => Box::new(io::stdin().lock()),
+        };
+        let writer: Box<dyn IoWrite> = match cli.output.map(paths::normalize) {
+            Some(path) => {
+                Box::new(io::BufWriter::new(fs::File::create(&path).map_err(|e| {
+                    format!("Error writing to file '{}': {e}", paths::display(&path))
+                })?))
+            }
+            None => Box::new(io::BufWriter::new(io::stdout().lock())),
+        };
+        return convert_warc_archive(reader, writer, &options).map_err(Into::into);
+    }
+    let (html, input_bytes) = match input.as_deref() {
+        _ if cli.url.is_some() => {
+            let user_agent = cli.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
+            let fetched = fetch_url(cli.url.as_deref().unwrap(), user_agent, &cli.encoding)?;
+            if cli.debug {
+                eprintln!("Fetched {} bytes from URL", fetched.len());
+            }
+            (fetched, None)
+        }
+        Some(path) if path != Path::new("-") => {
+            let bytes = decompress::read_file(path)?;
+            let decoded = decode_bytes(&bytes, &cli.encoding)?;
+            if cli.debug {
+                eprintln!("Read {} bytes from file '{}'", decoded.len(), paths::display(path));
+            }
+            (decoded, Some(bytes))
+        }
+        _ => {
+            let bytes = read_stdin()?;
+            let decoded = decode_bytes(&bytes, &cli.encoding)?;
+            if cli.debug {
+                eprintln!("Read {} bytes from stdin", decoded.len());
+            }
+            (decoded, Some(bytes))
         }
-
-        markdown
     };
 
-    match cli.output {
+    if cli.detect_format {
+        println!("{}", detect_input_format(&html));
+        return Ok(());
+    }
+
+    if let Some(path) = cli.capture_repro.map(paths::normalize) {
+        let (bytes, encoding) = input_bytes.as_deref().map_or((html.as_bytes(), "utf-8"), |bytes| {
+            (bytes, encoding_label(&cli.encoding))
+        });
+        write_repro_bundle(&path, bytes, encoding, &options)?;
+    }
+    drop(input_bytes);
+
+    let output_content = convert_document(&html, options, metadata_config, cli.debug, cli.progress)?;
+
+    match cli.output.map(paths::normalize) {
         Some(path) => {
             fs::write(&path, output_content.as_bytes())
-                .map_err(|e| format!("Error writing to file '{}': {}", path.display(), e))?;
+                .map_err(|e| format!("Error writing to file '{}': {}", paths::display(&path), e))?;
         }
         None => {
             print!("{output_content}");
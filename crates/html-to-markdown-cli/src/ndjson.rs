@@ -0,0 +1,72 @@
+//! NDJSON pipeline mode: one `{"id", "html"}` request per stdin line, one response per stdout line.
+//!
+//! Responses are flushed as soon as they are written so the CLI can be driven as a long-lived
+//! subprocess. A malformed request produces an error response instead of ending the stream.
+
+use serde_json::{Map, Value, json};
+use std::io::{self, BufRead, Write};
+
+/// Result of converting one request: the Markdown and, with `--with-metadata`, the metadata.
+pub type Converted = (String, Option<Value>);
+
+/// Read requests from `input` until EOF and write one response line to `output` per request.
+///
+/// Blank lines are ignored. The request `id` is echoed back unchanged and may be any JSON value;
+/// it is `null` when the line could not be parsed.
+pub fn run<R, W, F>(input: R, mut output: W, convert: F) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+    F: Fn(&str) -> Result<Converted, String>,
+{
+    for line in input.split(b'\n') {
+        let line = line?;
+        let line = String::from_utf8_lossy(&line);
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let response = match parse_request(line) {
+            Ok((id, html)) => respond(id, convert(&html)),
+            Err((id, err)) => respond(id, Err(err)),
+        };
+        serde_json::to_writer(&mut output, &response)?;
+        output.write_all(b"\n")?;
+        output.flush()?;
+    }
+    Ok(())
+}
+
+/// Split a request line into its id and HTML, keeping the id for the error response if possible.
+fn parse_request(line: &str) -> Result<(Value, String), (Value, String)> {
+    let request: Value = serde_json::from_str(line).map_err(|e| (Value::Null, format!("Invalid JSON: {e}")))?;
+    let Value::Object(mut request) = request else {
+        return Err((Value::Null, "Request must be a JSON object".to_string()));
+    };
+    let id = request.remove("id").unwrap_or(Value::Null);
+    match request.remove("html") {
+        Some(Value::String(html)) => Ok((id, html)),
+        Some(_) => Err((id, "Field 'html' must be a string".to_string())),
+        None => Err((id, "Missing field 'html'".to_string())),
+    }
+}
+
+fn respond(id: Value, result: Result<Converted, String>) -> Value {
+    let mut response = Map::new();
+    response.insert("id".to_string(), id);
+    match result {
+        Ok((markdown, metadata)) => {
+            response.insert("markdown".to_string(), Value::String(markdown));
+            if let Some(metadata) = metadata {
+                response.insert("metadata".to_string(), metadata);
+            }
+            response.insert("error".to_string(), Value::Null);
+        }
+        Err(err) => {
+            response.insert("markdown".to_string(), Value::Null);
+            response.insert("error".to_string(), json!(err));
+        }
+    }
+    Value::Object(response)
+}
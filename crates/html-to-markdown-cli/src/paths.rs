@@ -0,0 +1,69 @@
+//! Path handling for user-supplied files and directories.
+//!
+//! Paths are kept as `OsStr` end to end so non-UTF-8 file names survive. On Windows, verbatim
+//! paths (`\\?\C:\...`, `\\?\UNC\server\share\...`) bypass the Win32 normalisation that would
+//! otherwise turn `/` into `\`, so they are normalised here before use; plain long paths and UNC
+//! shares (`\\server\share`) are handled by the standard library.
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+/// Normalise a path given on the command line.
+///
+/// Verbatim Windows paths get their `/` separators turned into `\` and `.` components removed,
+/// since the OS treats both literally for such paths. Everything else is returned unchanged.
+#[cfg(windows)]
+pub fn normalize(path: PathBuf) -> PathBuf {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::{OsStrExt, OsStringExt};
+
+    const VERBATIM: [u16; 4] = [b'\\' as u16, b'\\' as u16, b'?' as u16, b'\\' as u16];
+    let wide: Vec<u16> = path.as_os_str().encode_wide().collect();
+    if !wide.starts_with(&VERBATIM) {
+        return path;
+    }
+
+    let rest: Vec<u16> = wide[VERBATIM.len()..]
+        .iter()
+        .map(|&unit| {
+            if unit == u16::from(b'/') {
+                u16::from(b'\\')
+            } else {
+                unit
+            }
+        })
+        .collect();
+    let mut normalized = VERBATIM.to_vec();
+    let mut first = true;
+    for component in rest.split(|&unit| unit == u16::from(b'\\')) {
+        if component == [u16::from(b'.')] || (component.is_empty() && !first) {
+            continue;
+        }
+        if !first {
+            normalized.push(u16::from(b'\\'));
+        }
+        normalized.extend_from_slice(component);
+        first = false;
+    }
+    PathBuf::from(OsString::from_wide(&normalized))
+}
+
+/// Normalise a path given on the command line (no-op outside Windows).
+#[cfg(not(windows))]
+pub const fn normalize(path: PathBuf) -> PathBuf {
+    path
+}
+
+/// Render a path for messages, hiding the verbatim prefix Windows APIs may add.
+pub fn display(path: &Path) -> Cow<'_, str> {
+    let text = path.to_string_lossy();
+    if cfg!(windows) {
+        if let Some(share) = text.strip_prefix(r"\\?\UNC\") {
+            return Cow::Owned(format!(r"\\{share}"));
+        }
+        if let Some(local) = text.strip_prefix(r"\\?\") {
+            return Cow::Owned(local.to_string());
+        }
+    }
+    text
+}
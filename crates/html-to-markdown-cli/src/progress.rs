@@ -0,0 +1,43 @@
+//! Progress bar for `--progress`.
+//!
+//! The bar is drawn on stderr, so it never mixes with Markdown written to stdout, and only
+//! redrawn when the whole-percent value changes.
+
+use std::cell::Cell;
+use std::io::{self, Write};
+
+use html_to_markdown_rs::{ProgressEvent, ProgressInterval};
+
+const WIDTH: usize = 30;
+
+/// Report roughly every whole percent of an input of `len` bytes.
+pub const fn interval(len: usize) -> ProgressInterval {
+    ProgressInterval::Bytes(len / 100)
+}
+
+/// Build the progress callback for one conversion.
+pub fn reporter() -> impl Fn(ProgressEvent) + 'static {
+    let last_percent = Cell::new(None);
+    move |event: ProgressEvent| {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let percent = (event.fraction() * 100.0).floor() as usize;
+        if last_percent.replace(Some(percent)) == Some(percent) && !event.is_done() {
+            return;
+        }
+
+        let filled = WIDTH * percent / 100;
+        let mut stderr = io::stderr().lock();
+        let _ = write!(
+            stderr,
+            "\r[{}{}] {percent:>3}% ({}/{} nodes)",
+            "#".repeat(filled),
+            "-".repeat(WIDTH - filled),
+            event.nodes_processed,
+            event.total_nodes
+        );
+        if event.is_done() {
+            let _ = writeln!(stderr);
+        }
+        let _ = stderr.flush();
+    }
+}
@@ -0,0 +1,179 @@
+//! `serve` subcommand: a small HTTP server exposing the converter over REST.
+//!
+//! Endpoints:
+//! - `POST /convert` with an HTML body returns `text/markdown`. Options come from the query
+//!   string using the same camelCase names as the JSON options (`?headingStyle=underlined&wrap=true`).
+//! - `POST /convert` with `Content-Type: application/json` takes `{"html": "...", "options": {...}}`
+//!   and returns `{"markdown": "..."}`.
+//! - `GET /health` returns `ok`.
+//!
+//! Options given on the command line are the defaults every request starts from.
+
+use html_to_markdown_rs::{ConversionOptions, conversion_options_update_from_json, convert};
+use serde_json::{Map, Value, json};
+use std::io::Read;
+use std::sync::Arc;
+use std::thread;
+use tiny_http::{Header, Request, Response, Server};
+
+/// Largest request body accepted, in bytes.
+const MAX_BODY_SIZE: u64 = 64 * 1024 * 1024;
+
+/// A response before it is handed to the HTTP layer.
+#[derive(Debug)]
+pub struct Reply {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Reply {
+    fn text(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body: body.into(),
+        }
+    }
+
+    fn json(status: u16, body: &Value) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: body.to_string(),
+        }
+    }
+
+    /// Errors are reported in the same format as the request: JSON for JSON requests.
+    fn error(status: u16, message: String, as_json: bool) -> Self {
+        if as_json {
+            Self::json(status, &json!({ "error": message }))
+        } else {
+            Self::text(status, message)
+        }
+    }
+}
+
+/// Bind to `addr` and serve requests on `workers` threads until the process is stopped.
+pub fn run(addr: &str, workers: usize, defaults: ConversionOptions) -> Result<(), String> {
+    let server = Server::http(addr).map_err(|e| format!("Cannot listen on {addr}: {e}"))?;
+    if let Some(local) = server.server_addr().to_ip() {
+        eprintln!("Listening on http://{local}");
+    }
+
+    let server = Arc::new(server);
+    let defaults = Arc::new(defaults);
+    let handles: Vec<_> = (0..workers.max(1))
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let defaults = Arc::clone(&defaults);
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    serve_request(request, &defaults);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        let _ = handle.join();
+    }
+    Ok(())
+}
+
+fn serve_request(mut request: Request, defaults: &ConversionOptions) {
+    let content_type = request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv("Content-Type"))
+        .map(|header| header.value.as_str().to_string());
+
+    let mut body = String::new();
+    let reply = match request.as_reader().take(MAX_BODY_SIZE + 1).read_to_string(&mut body) {
+        Ok(read) if read as u64 > MAX_BODY_SIZE => Reply::text(413, "Request body too large"),
+        Ok(_) => handle(
+            request.method().as_str(),
+            request.url(),
+            content_type.as_deref(),
+            &body,
+            defaults,
+        ),
+        Err(e) => Reply::text(400, format!("Cannot read request body as UTF-8: {e}")),
+    };
+
+    let header = Header::from_bytes("Content-Type", reply.content_type).expect("static header is valid");
+    let response = Response::from_string(reply.body)
+        .with_status_code(reply.status)
+        .with_header(header);
+    let _ = request.respond(response);
+}
+
+/// Route one request and produce its reply.
+pub fn handle(method: &str, url: &str, content_type: Option<&str>, body: &str, defaults: &ConversionOptions) -> Reply {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    match (path, method) {
+        ("/health", "GET") => Reply::text(200, "ok"),
+        ("/convert", "POST") => {
+            let as_json = content_type.is_some_and(|value| value.starts_with("application/json"));
+            convert_request(query, as_json, body, defaults)
+                .unwrap_or_else(|(status, message)| Reply::error(status, message, as_json))
+        }
+        ("/health" | "/convert", _) => Reply::text(405, "Method not allowed"),
+        _ => Reply::text(404, "Not found"),
+    }
+}
+
+fn convert_request(
+    query: &str,
+    as_json: bool,
+    body: &str,
+    defaults: &ConversionOptions,
+) -> Result<Reply, (u16, String)> {
+    let mut options = defaults.clone();
+    let query_options = query_to_options(query);
+    if !query_options.is_empty() {
+        apply_options(&mut options, &Value::Object(query_options))?;
+    }
+
+    let html = if as_json {
+        let mut request: Value = serde_json::from_str(body).map_err(|e| (400, format!("Invalid JSON: {e}")))?;
+        if let Some(request_options) = request.get("options") {
+            apply_options(&mut options, request_options)?;
+        }
+        match request.get_mut("html").map(Value::take) {
+            Some(Value::String(html)) => html,
+            Some(_) => return Err((400, "Field 'html' must be a string".to_string())),
+            None => return Err((400, "Missing field 'html'".to_string())),
+        }
+    } else {
+        body.to_string()
+    };
+
+    let markdown = convert(&html, Some(options)).map_err(|e| (422, e.to_string()))?;
+    Ok(if as_json {
+        Reply::json(200, &json!({ "markdown": markdown }))
+    } else {
+        Reply {
+            status: 200,
+            content_type: "text/markdown; charset=utf-8",
+            body: markdown,
+        }
+    })
+}
+
+fn apply_options(options: &mut ConversionOptions, update: &Value) -> Result<(), (u16, String)> {
+    let update =
+        conversion_options_update_from_json(&update.to_string()).map_err(|e| (400, format!("Invalid options: {e}")))?;
+    options.apply_update(update);
+    Ok(())
+}
+
+/// Turn `key=value` query pairs into a JSON object. Values that parse as JSON (`true`, `80`,
+/// `["toc"]`) keep their type; anything else is taken as a string.
+fn query_to_options(query: &str) -> Map<String, Value> {
+    form_urlencoded::parse(query.as_bytes())
+        .map(|(key, value)| {
+            let value = serde_json::from_str(&value).unwrap_or_else(|_| Value::String(value.into_owned()));
+            (key.into_owned(), value)
+        })
+        .collect()
+}
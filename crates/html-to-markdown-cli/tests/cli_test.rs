@@ -474,6 +474,85 @@ fn test_extract_metadata() {
         .stdout(predicate::str::contains("Page Title"));
 }
 
+#[test]
+fn test_keep_soft_hyphens() {
+    cli()
+        .arg("--keep-soft-hyphens")
+        .write_stdin("<p>soft&shy;hyphen</p>")
+        .assert()
+        .success()
+        .stdout("soft\u{ad}hyphen\n");
+}
+
+#[test]
+fn test_front_matter_toml() {
+    cli()
+        .arg("--front-matter")
+        .arg("toml")
+        .write_stdin("<html><head><title>Page Title</title></head><body><p>Content</p></body></html>")
+        .assert()
+        .success()
+        .stdout("+++\ntitle = \"Page Title\"\n+++\n\nContent\n");
+}
+
+#[test]
+fn test_bidi_html() {
+    cli()
+        .arg("--bidi")
+        .arg("html")
+        .write_stdin("<p dir=\"rtl\">שלום</p>")
+        .assert()
+        .success()
+        .stdout("<div dir=\"rtl\">\n\nשלום\n\n</div>\n");
+}
+
+#[test]
+fn test_list_spacing_tight() {
+    cli()
+        .arg("--list-spacing")
+        .arg("tight")
+        .write_stdin("<ul><li><p>One</p></li><li><p>Two</p></li></ul>")
+        .assert()
+        .success()
+        .stdout("- One\n- Two\n");
+}
+
+#[test]
+fn test_unrepresentable_diagnose_reports_on_stderr() {
+    cli()
+        .arg("--unrepresentable")
+        .arg("diagnose")
+        .write_stdin("<p>a<wbr>b</p>")
+        .assert()
+        .success()
+        .stdout("ab\n")
+        .stderr(predicate::str::contains("warning: dropped <wbr>"));
+}
+
+#[test]
+fn test_post_process_chain() {
+    cli()
+        .arg("--post-process")
+        .arg("reference-links")
+        .arg("--post-process")
+        .arg("toc")
+        .write_stdin("<h2>Intro</h2><p><a href=\"https://example.com\">Docs</a></p>")
+        .assert()
+        .success()
+        .stdout("- [Intro](#intro)\n\n## Intro\n\n[Docs][1]\n\n[1]: https://example.com\n");
+}
+
+#[test]
+fn test_post_process_rejects_unknown_name() {
+    cli()
+        .arg("--post-process")
+        .arg("spellcheck")
+        .write_stdin("<p>x</p>")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown post-processor 'spellcheck'"));
+}
+
 #[test]
 fn test_whitespace_mode_normalized() {
     cli()
@@ -537,6 +616,50 @@ fn test_strip_tags() {
         .stdout(predicate::str::contains("div content"));
 }
 
+#[test]
+fn test_remove_tags() {
+    cli()
+        .arg("--strip-tags")
+        .arg("span")
+        .arg("--remove-tags")
+        .arg("aside,figure")
+        .write_stdin("<p>Keep <span>this</span></p><aside>Sidebar</aside><figure>Chart</figure>")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Keep this"))
+        .stdout(predicate::str::contains("Sidebar").not())
+        .stdout(predicate::str::contains("Chart").not());
+}
+
+#[test]
+fn test_preserve_tags() {
+    cli()
+        .arg("--preserve-tags")
+        .arg("video,iframe")
+        .write_stdin("<p>Intro</p><video src=\"v.mp4\" controls></video><iframe src=\"https://x.test/embed\"></iframe>")
+        .assert()
+        .success()
+        .stdout("Intro\n\n<video src=\"v.mp4\" controls></video><iframe src=\"https://x.test/embed\"></iframe>\n");
+}
+
+#[test]
+fn test_preserve_tags_overrides_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("html2md.toml");
+    fs::write(&config_path, "preserve_tags = [\"video\"]\n").unwrap();
+
+    cli()
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--preserve-tags")
+        .arg("mark")
+        .write_stdin("<p><mark>hi</mark></p><video src=\"v.mp4\"></video>")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("<mark>hi</mark>"))
+        .stdout(predicate::str::contains("<video").not());
+}
+
 #[test]
 fn test_preprocess() {
     cli()
@@ -580,6 +703,18 @@ fn test_preprocess_with_preset_aggressive() {
         .success();
 }
 
+#[test]
+fn test_preprocess_with_preset_confluence() {
+    cli()
+        .arg("--preprocess")
+        .arg("--preset")
+        .arg("confluence")
+        .write_stdin(r#"<ac:structured-macro ac:name="tip"><ac:rich-text-body><p>Use it</p></ac:rich-text-body></ac:structured-macro>"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("> \u{1f4a1} **Tip**"));
+}
+
 #[test]
 fn test_keep_navigation() {
     cli()
@@ -600,11 +735,152 @@ fn test_keep_forms() {
         .success();
 }
 
+#[test]
+fn test_apply_print_styles() {
+    cli()
+        .arg("--preprocess")
+        .arg("--apply-print-styles")
+        .write_stdin(r#"<p>Article</p><div class="share d-print-none">Share this</div>"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Article"))
+        .stdout(predicate::str::contains("Share this").not());
+}
+
+#[test]
+fn test_extract_main_content() {
+    let article =
+        "<p>The council voted on Monday, after a long debate, to fund the new library, the pool, and the park.</p>";
+    cli()
+        .arg("--preprocess")
+        .arg("--extract-main-content")
+        .write_stdin(format!(
+            r#"<div class="links"><a href="/">Home</a></div><div class="article">{article}{article}</div>"#
+        ))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("The council voted"))
+        .stdout(predicate::str::contains("Home").not());
+}
+
+#[test]
+fn test_flatten_shadow_dom() {
+    cli()
+        .arg("--preprocess")
+        .arg("--flatten-shadow-dom")
+        .write_stdin(
+            r#"<x-note><template shadowrootmode="open"><strong><slot name="label">Note</slot></strong></template><span slot="label">Tip</span></x-note>"#,
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("**Tip**"))
+        .stdout(predicate::str::contains("Note").not());
+}
+
+#[test]
+fn test_exclude_and_include_selectors() {
+    cli()
+        .arg("--preprocess")
+        .arg("--include-selector")
+        .arg("article.main")
+        .arg("--exclude-selector")
+        .arg(".ads, article > .share")
+        .write_stdin(
+            r#"<p>Header</p><article class="main"><p>Body</p><div class="ads">Buy</div><p class="share">Share</p></article>"#,
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Body"))
+        .stdout(predicate::str::contains("Header").not())
+        .stdout(predicate::str::contains("Buy").not())
+        .stdout(predicate::str::contains("Share").not());
+}
+
+#[test]
+fn test_pandoc_attributes() {
+    cli()
+        .arg("--pandoc-attributes")
+        .write_stdin(r#"<h2 id="usage" class="docs">Usage</h2>"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("## Usage {#usage .docs}"));
+}
+
+#[test]
+fn test_svg_text_mode_replace() {
+    cli()
+        .arg("--svg-text-mode")
+        .arg("replace")
+        .write_stdin("<svg><title>Flow</title><text>Start</text><text>End</text></svg>")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Flow; Start; End"))
+        .stdout(predicate::str::contains("data:image/svg+xml").not());
+}
+
+#[test]
+fn test_object_links() {
+    cli()
+        .arg("--object-links")
+        .write_stdin(r#"<object data="/files/report.pdf"><p>Quarterly report</p></object>"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Quarterly report\n\n[report.pdf](/files/report.pdf)",
+        ));
+}
+
+#[test]
+fn test_preserve_heading_ids() {
+    cli()
+        .arg("--preserve-heading-ids")
+        .write_stdin(r#"<h2 id="setup">Setup</h2>"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("## <a id=\"setup\"></a>Setup"));
+}
+
 #[test]
 fn test_debug_flag() {
     cli().arg("--debug").write_stdin("<p>Test</p>").assert().success();
 }
 
+#[test]
+fn test_progress_flag_reports_on_stderr() {
+    cli()
+        .arg("--progress")
+        .write_stdin("<h1>Title</h1><p>Content</p>")
+        .assert()
+        .success()
+        .stdout("# Title\n\nContent\n")
+        .stderr(predicate::str::contains("100%"));
+}
+
+#[test]
+fn test_split_documents_with_progress() {
+    cli()
+        .arg("--split-documents")
+        .arg("--progress")
+        .write_stdin("<html><body><p>One</p></body></html><html><body><p>Two</p></body></html>")
+        .assert()
+        .success()
+        .stdout("One\n\n---\n\nTwo\n")
+        .stderr(predicate::str::contains("100%"));
+}
+
+#[test]
+fn test_split_documents_with_diagnostics() {
+    cli()
+        .arg("--split-documents")
+        .arg("--unrepresentable")
+        .arg("diagnose")
+        .write_stdin("<html><body><p>a<wbr>b</p></body></html><html><body><p>c</p></body></html>")
+        .assert()
+        .success()
+        .stdout("ab\n\n---\n\nc\n")
+        .stderr(predicate::str::contains("warning: dropped <wbr>"));
+}
+
 #[test]
 fn test_encoding_utf8() {
     let temp_dir = TempDir::new().unwrap();
@@ -869,6 +1145,30 @@ fn test_extract_structured_data_flag() {
         .stdout(predicate::str::contains("\"structured_data\""));
 }
 
+#[test]
+fn test_extract_resources_flag() {
+    cli()
+        .arg("--with-metadata")
+        .arg("--extract-resources")
+        .write_stdin(r#"<html><head><link rel="stylesheet" href="/site.css"></head><body><p>Hi</p></body></html>"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"resource_type\": \"stylesheet\""))
+        .stdout(predicate::str::contains("/site.css"));
+}
+
+#[test]
+fn test_extract_source_map_flag() {
+    cli()
+        .arg("--with-metadata")
+        .arg("--extract-source-map")
+        .write_stdin("<h1>Title</h1><p>Body</p>")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"source_map\""))
+        .stdout(predicate::str::contains("\"html_end\": 14"));
+}
+
 #[test]
 fn test_combining_multiple_extract_flags() {
     cli()
@@ -960,3 +1260,668 @@ fn serve_once_bytes(
 
     (format!("http://{}", addr), handle, rx)
 }
+
+#[test]
+fn test_input_dir_mirrors_tree() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("site");
+    let output_dir = temp_dir.path().join("docs");
+    fs::create_dir_all(input_dir.join("guide/deep")).unwrap();
+    fs::write(input_dir.join("index.html"), "<h1>Home</h1>").unwrap();
+    fs::write(input_dir.join("guide/deep/page.html"), "<p>Deep <em>page</em></p>").unwrap();
+    fs::write(input_dir.join("guide/notes.txt"), "not html").unwrap();
+
+    cli()
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .arg("--jobs")
+        .arg("2")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Converted 2 of 2 file(s)"));
+
+    assert_eq!(fs::read_to_string(output_dir.join("index.md")).unwrap(), "# Home\n");
+    assert_eq!(
+        fs::read_to_string(output_dir.join("guide/deep/page.md")).unwrap(),
+        "Deep *page*\n"
+    );
+    assert!(!output_dir.join("guide/notes.md").exists());
+}
+
+#[test]
+fn test_input_dir_strip_boilerplate() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("site");
+    let output_dir = temp_dir.path().join("docs");
+    fs::create_dir_all(&input_dir).unwrap();
+    for name in ["a", "b", "c"] {
+        fs::write(
+            input_dir.join(format!("{name}.html")),
+            format!("<h1>Page {name}</h1><footer><p>Subscribe to our newsletter</p></footer>"),
+        )
+        .unwrap();
+    }
+
+    cli()
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .arg("--strip-boilerplate")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed 1 boilerplate block(s)"))
+        .stderr(predicate::str::contains("[3 file(s)] Subscribe to our newsletter"));
+
+    assert_eq!(fs::read_to_string(output_dir.join("b.md")).unwrap(), "# Page b\n");
+}
+
+#[test]
+fn test_input_dir_glob_and_failure_summary() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("in");
+    let output_dir = temp_dir.path().join("out");
+    fs::create_dir_all(&input_dir).unwrap();
+    fs::write(input_dir.join("good.htm"), "<p>good</p>").unwrap();
+    fs::write(input_dir.join("broken.htm"), "%PDF-1.7").unwrap();
+    fs::write(input_dir.join("other.html"), "<p>not matched</p>").unwrap();
+
+    cli()
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .arg("--glob")
+        .arg("*.htm")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Converted 1 of 2 file(s)"))
+        .stderr(predicate::str::contains("broken.htm: Error converting HTML"));
+
+    assert_eq!(fs::read_to_string(output_dir.join("good.md")).unwrap(), "good\n");
+    assert!(!output_dir.join("other.md").exists());
+}
+
+#[test]
+fn test_input_dir_requires_output_dir() {
+    cli()
+        .arg("--input-dir")
+        .arg(".")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--output-dir"));
+}
+
+#[test]
+fn test_ndjson_mode_echoes_ids() {
+    let input = concat!(
+        "{\"id\": 1, \"html\": \"<h1>One</h1>\"}\n",
+        "\n",
+        "{\"id\": \"two\", \"html\": \"<p><em>Two</em></p>\"}\n",
+        "{\"id\": 3}\n",
+        "not json\n",
+    );
+    let output = cli().arg("--ndjson").write_stdin(input).output().unwrap();
+    assert!(output.status.success());
+
+    let responses: Vec<serde_json::Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(responses.len(), 4);
+    assert_eq!(
+        responses[0],
+        serde_json::json!({"id": 1, "markdown": "# One\n", "error": null})
+    );
+    assert_eq!(
+        responses[1],
+        serde_json::json!({"id": "two", "markdown": "*Two*\n", "error": null})
+    );
+    assert_eq!(
+        responses[2],
+        serde_json::json!({"id": 3, "markdown": null, "error": "Missing field 'html'"})
+    );
+    assert_eq!(responses[3]["id"], serde_json::Value::Null);
+    assert!(responses[3]["error"].as_str().unwrap().starts_with("Invalid JSON"));
+}
+
+#[test]
+fn test_ndjson_mode_with_metadata() {
+    let output = cli()
+        .arg("--ndjson")
+        .arg("--with-metadata")
+        .arg("--extract-document")
+        .write_stdin("{\"id\": 7, \"html\": \"<html><head><title>T</title></head><body><p>x</p></body></html>\"}\n")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let response: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(response["id"], 7);
+    assert_eq!(response["error"], serde_json::Value::Null);
+    assert_eq!(response["metadata"]["document"]["title"], "T");
+}
+
+#[test]
+fn test_ndjson_conflicts_with_input_file() {
+    cli()
+        .arg("--ndjson")
+        .arg("input.html")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+/// A running `serve` process, killed when dropped so a failing test does not leak it.
+struct Server(std::process::Child);
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Start `serve` on a free port and return the process with its address.
+fn spawn_server(args: &[&str]) -> (Server, String) {
+    use std::io::BufRead;
+    use std::process::{Command as StdCommand, Stdio};
+
+    let mut child = StdCommand::new(env!("CARGO_BIN_EXE_html-to-markdown"))
+        .args(args)
+        .args(["serve", "--port", "0", "--jobs", "2"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut line = String::new();
+    std::io::BufReader::new(child.stderr.take().unwrap())
+        .read_line(&mut line)
+        .unwrap();
+    let addr = line.trim().strip_prefix("Listening on http://").unwrap().to_string();
+    (Server(child), addr)
+}
+
+fn http_request(addr: &str, request: &str) -> String {
+    let mut stream = std::net::TcpStream::connect(addr).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+fn post(addr: &str, path: &str, content_type: &str, body: &str) -> String {
+    http_request(
+        addr,
+        &format!(
+            "POST {path} HTTP/1.1\r\nHost: {addr}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        ),
+    )
+}
+
+#[test]
+fn test_serve_convert_endpoint() {
+    let (_server, addr) = spawn_server(&["--heading-style", "underlined"]);
+
+    let response = post(&addr, "/convert", "text/html", "<h1>Title</h1>");
+    assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+    assert!(response.contains("text/markdown"));
+    assert!(response.ends_with("Title\n=====\n"), "{response}");
+
+    let response = post(
+        &addr,
+        "/convert?headingStyle=atx&bullets=*",
+        "text/html",
+        "<h1>T</h1><ul><li>x</li></ul>",
+    );
+    assert!(response.ends_with("# T\n\n* x\n"), "{response}");
+
+    let response = post(
+        &addr,
+        "/convert",
+        "application/json",
+        r#"{"html": "<h2>J</h2>", "options": {"headingStyle": "atx"}}"#,
+    );
+    assert!(response.ends_with(r###"{"markdown":"## J\n"}"###), "{response}");
+
+    let response = post(&addr, "/convert", "application/json", r#"{"options": {}}"#);
+    assert!(response.starts_with("HTTP/1.1 400"), "{response}");
+    assert!(response.ends_with(r#"{"error":"Missing field 'html'"}"#), "{response}");
+
+    let response = post(&addr, "/convert?wrap=sometimes", "text/html", "<p>x</p>");
+    assert!(response.starts_with("HTTP/1.1 400"), "{response}");
+
+    let response = http_request(&addr, "GET /health HTTP/1.1\r\nConnection: close\r\n\r\n");
+    assert!(
+        response.starts_with("HTTP/1.1 200") && response.ends_with("ok"),
+        "{response}"
+    );
+
+    let response = http_request(&addr, "GET /convert HTTP/1.1\r\nConnection: close\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 405"), "{response}");
+
+    let response = http_request(&addr, "GET /missing HTTP/1.1\r\nConnection: close\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 404"), "{response}");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_file_names() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("in");
+    let output_dir = temp_dir.path().join("out");
+    fs::create_dir_all(&input_dir).unwrap();
+    let name = OsStr::from_bytes(b"caf\xe9.html");
+    fs::write(input_dir.join(name), "<p>latin-1 name</p>").unwrap();
+
+    cli()
+        .arg(input_dir.join(name))
+        .assert()
+        .success()
+        .stdout("latin-1 name\n");
+
+    cli()
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Converted 1 of 1 file(s)"));
+    let converted = output_dir.join(OsStr::from_bytes(b"caf\xe9.md"));
+    assert_eq!(fs::read_to_string(converted).unwrap(), "latin-1 name\n");
+}
+
+#[test]
+fn test_missing_input_file_reports_path() {
+    cli()
+        .arg("no/such/file.html")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Error reading file 'no/such/file.html'"));
+}
+
+#[cfg(windows)]
+#[test]
+fn test_windows_long_and_verbatim_paths() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut deep = temp_dir.path().to_path_buf();
+    while deep.as_os_str().len() < 300 {
+        deep.push("a-rather-long-directory-name");
+    }
+    let input_dir = deep.join("in");
+    fs::create_dir_all(&input_dir).unwrap();
+    fs::write(input_dir.join("page.html"), "<p>deep</p>").unwrap();
+
+    cli()
+        .arg(input_dir.join("page.html"))
+        .assert()
+        .success()
+        .stdout("deep\n");
+
+    // `canonicalize` yields a verbatim `\\?\` path, which skips Win32 normalisation, so the
+    // forward slash and `.` component must be fixed up by the CLI.
+    let verbatim = fs::canonicalize(&input_dir).unwrap();
+    cli()
+        .arg(format!("{}/./page.html", verbatim.display()))
+        .assert()
+        .success()
+        .stdout("deep\n");
+
+    let output_dir = deep.join("out");
+    cli()
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .assert()
+        .success();
+    assert_eq!(fs::read_to_string(output_dir.join("page.md")).unwrap(), "deep\n");
+}
+
+#[test]
+fn test_config_file_options() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("team.toml");
+    fs::write(
+        &config_path,
+        r#"
+heading_style = "underlined"
+bullets = "+"
+preserve-tags = ["mark"]
+
+[preprocessing]
+enabled = true
+remove_forms = false
+"#,
+    )
+    .unwrap();
+
+    cli()
+        .arg("--config")
+        .arg(&config_path)
+        .write_stdin("<h1>Title</h1><ul><li><mark>x</mark></li></ul>")
+        .assert()
+        .success()
+        .stdout("Title\n=====\n\n+ <mark>x</mark>\n");
+
+    cli()
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--heading-style")
+        .arg("atx")
+        .write_stdin("<h1>Title</h1>")
+        .assert()
+        .success()
+        .stdout("# Title\n");
+}
+
+#[test]
+fn test_config_file_discovery() {
+    let temp_dir = TempDir::new().unwrap();
+    let nested = temp_dir.path().join("docs/pages");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(temp_dir.path().join(".html2md.toml"), "headingStyle = \"atx_closed\"\n").unwrap();
+
+    cli()
+        .current_dir(&nested)
+        .write_stdin("<h2>Found</h2>")
+        .assert()
+        .success()
+        .stdout("## Found ##\n");
+
+    cli()
+        .current_dir(&nested)
+        .arg("--no-config")
+        .write_stdin("<h2>Found</h2>")
+        .assert()
+        .success()
+        .stdout("## Found\n");
+}
+
+#[test]
+fn test_profile_options() {
+    let html = format!("<p>{}</p>", "word ".repeat(30).trim_end());
+
+    let output = cli()
+        .arg("--profile")
+        .arg("llm")
+        .write_stdin(html.clone())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let markdown = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(markdown.lines().count(), 2, "{markdown}");
+    assert!(markdown.lines().all(|line| line.len() <= 100), "{markdown}");
+
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("team.toml");
+    fs::write(&config_path, "wrap = false\n").unwrap();
+    cli()
+        .arg("--profile")
+        .arg("llm")
+        .arg("--config")
+        .arg(&config_path)
+        .write_stdin(html)
+        .assert()
+        .success()
+        .stdout(predicate::function(|out: &str| out.lines().count() == 1));
+
+    cli()
+        .arg("--profile")
+        .arg("blog")
+        .write_stdin("<p>x</p>")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'blog'"));
+}
+
+#[test]
+fn test_invalid_config_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("broken.toml");
+    fs::write(&config_path, "wrap = \"yes\"\n").unwrap();
+
+    cli()
+        .arg("--config")
+        .arg(&config_path)
+        .write_stdin("<p>x</p>")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid config file"))
+        .stderr(predicate::str::contains("expected a boolean"));
+}
+
+#[test]
+fn test_config_file_unknown_option() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("typo.toml");
+    fs::write(
+        &config_path,
+        "heading_styel = \"atx\"\n\n[preprocessing]\nremove_nav = true\n",
+    )
+    .unwrap();
+
+    cli()
+        .arg("--config")
+        .arg(&config_path)
+        .write_stdin("<p>x</p>")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown option 'headingStyel'"));
+
+    fs::write(&config_path, "[preprocessing]\nremove_nav = true\n").unwrap();
+    cli()
+        .arg("--config")
+        .arg(&config_path)
+        .write_stdin("<p>x</p>")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown option 'preprocessing.removeNav'"));
+}
+
+#[test]
+fn test_print_config_schema() {
+    let output = cli().arg("--print-config-schema").output().unwrap();
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["headingStyle"]["default"], "atx");
+    assert_eq!(
+        schema["properties"]["preprocessing"]["properties"]["preset"]["default"],
+        "standard"
+    );
+}
+
+#[test]
+fn test_detect_format() {
+    cli()
+        .arg("--detect-format")
+        .write_stdin(r#"<div class="ocr_page"><span class="ocrx_word">Hi</span></div>"#)
+        .assert()
+        .success()
+        .stdout("hocr\n");
+    cli()
+        .arg("--detect-format")
+        .write_stdin("<p>Hello</p>")
+        .assert()
+        .success()
+        .stdout("html\n");
+}
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn brotli_compress(data: &[u8]) -> Vec<u8> {
+    let mut compressed = Vec::new();
+    brotli::BrotliCompress(
+        &mut &data[..],
+        &mut compressed,
+        &brotli::enc::BrotliEncoderParams::default(),
+    )
+    .unwrap();
+    compressed
+}
+
+#[test]
+fn test_compressed_input_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let gz_path = temp_dir.path().join("page.html.gz");
+    let br_path = temp_dir.path().join("page.html.br");
+    fs::write(&gz_path, gzip(b"<h1>Gzip</h1>")).unwrap();
+    fs::write(&br_path, brotli_compress(b"<h1>Brotli</h1>")).unwrap();
+
+    cli().arg(&gz_path).assert().success().stdout("# Gzip\n");
+    cli().arg(&br_path).assert().success().stdout("# Brotli\n");
+    cli()
+        .write_stdin(gzip(b"<p>piped</p>"))
+        .assert()
+        .success()
+        .stdout("piped\n");
+}
+
+#[test]
+fn test_input_dir_decompresses_and_strips_extension() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("in");
+    let output_dir = temp_dir.path().join("out");
+    fs::create_dir_all(&input_dir).unwrap();
+    fs::write(input_dir.join("a.html.gz"), gzip(b"<p>a</p>")).unwrap();
+    fs::write(input_dir.join("b.html.br"), brotli_compress(b"<p>b</p>")).unwrap();
+    fs::write(input_dir.join("c.html.gz"), b"not gzip").unwrap();
+
+    cli()
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .arg("--glob")
+        .arg("*.html.*")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Converted 2 of 3 file(s)"))
+        .stderr(predicate::str::contains("c.html.gz: Error decompressing gzip data"));
+
+    assert_eq!(fs::read_to_string(output_dir.join("a.md")).unwrap(), "a\n");
+    assert_eq!(fs::read_to_string(output_dir.join("b.md")).unwrap(), "b\n");
+}
+
+#[test]
+fn test_url_decompresses_gzip_file_body() {
+    let (url, handle, _) = serve_once_bytes(gzip(b"<p>Archived</p>"), Some("application/gzip"));
+
+    cli()
+        .arg("--url")
+        .arg(format!("{url}/page.html.gz"))
+        .assert()
+        .success()
+        .stdout("Archived\n");
+
+    handle.join().unwrap();
+}
+
+fn warc_response(uri: &str, content_type: &str, body: &str) -> Vec<u8> {
+    let http = format!("HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\n\r\n{body}");
+    format!(
+        "WARC/1.1\r\nWARC-Type: response\r\nWARC-Target-URI: {uri}\r\nWARC-Date: 2024-05-01T12:00:00Z\r\n\
+         Content-Type: application/http; msgtype=response\r\nContent-Length: {}\r\n\r\n{http}\r\n\r\n",
+        http.len()
+    )
+    .into_bytes()
+}
+
+#[test]
+fn test_warc_input() {
+    let temp_dir = TempDir::new().unwrap();
+    let archive_path = temp_dir.path().join("crawl.warc.gz");
+    let archive = [
+        gzip(&warc_response("https://a.test/", "text/html", "<h1>A</h1>")),
+        gzip(&warc_response("https://a.test/style.css", "text/css", "h1 {}")),
+        gzip(&warc_response("https://a.test/b", "text/html", "<p>B</p>")),
+    ]
+    .concat();
+    fs::write(&archive_path, archive).unwrap();
+
+    let output = cli().arg(&archive_path).arg("--warc").output().unwrap();
+    assert!(output.status.success());
+    let lines: Vec<serde_json::Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            serde_json::json!({"target_uri": "https://a.test/", "date": "2024-05-01T12:00:00Z", "record_id": null, "markdown": "# A\n"}),
+            serde_json::json!({"target_uri": "https://a.test/b", "date": "2024-05-01T12:00:00Z", "record_id": null, "markdown": "B\n"}),
+        ]
+    );
+}
+
+#[test]
+fn test_warc_malformed_archive_fails() {
+    let mut archive = warc_response("https://a.test/", "text/html", "<p>ok</p>");
+    archive.extend_from_slice(b"garbage\r\n");
+
+    cli()
+        .arg("--warc")
+        .write_stdin(archive)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("\"markdown\":\"ok\\n\""))
+        .stdout(predicate::str::contains("malformed WARC record"))
+        .stderr(predicate::str::contains("1 WARC record(s) could not be converted"));
+}
+
+#[test]
+fn test_capture_and_replay_repro_bundle() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("page.html");
+    let bundle_path = temp_dir.path().join("bundle.tar.zst");
+    fs::write(&input_path, b"<h1>Caf\xe9</h1>").unwrap();
+
+    cli()
+        .arg(&input_path)
+        .args([
+            "--encoding",
+            "latin-1",
+            "--heading-style",
+            "atx-closed",
+            "--capture-repro",
+        ])
+        .arg(&bundle_path)
+        .assert()
+        .success()
+        .stdout("# Café #\n");
+
+    cli()
+        .arg("--replay-repro")
+        .arg(&bundle_path)
+        .assert()
+        .success()
+        .stdout("# Café #\n");
+}
+
+#[test]
+fn test_replay_repro_rejects_invalid_bundle() {
+    let temp_dir = TempDir::new().unwrap();
+    let bundle_path = temp_dir.path().join("bundle.tar.zst");
+    fs::write(&bundle_path, "not a bundle").unwrap();
+
+    cli()
+        .arg("--replay-repro")
+        .arg(&bundle_path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Error reading repro bundle"));
+}
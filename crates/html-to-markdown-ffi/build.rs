@@ -3,6 +3,10 @@
 //! Build script for generating C bindings.
 //!
 //! Uses cbindgen to generate C header files from Rust FFI definitions.
+//!
+//! The header is written next to the manifest and shipped with the crate, so hosts can
+//! include it instead of declaring the API by hand. cbindgen only rewrites it when the
+//! contents change, which keeps builds of a packaged crate from touching its sources.
 
 use std::env;
 use std::path::PathBuf;
@@ -15,6 +19,6 @@ fn main() {
         .expect("Unable to generate C bindings")
         .write_to_file(&output_file);
 
-    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=src");
     println!("cargo:rerun-if-changed=cbindgen.toml");
 }
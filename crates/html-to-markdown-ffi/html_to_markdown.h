@@ -9,9 +9,70 @@
 
 #include <stdarg.h>
 #include <stdbool.h>
+#include <stddef.h>
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * Category of the last error, returned by `html_to_markdown_error_code()`.
+ *
+ * The values are stable: new categories are only ever added at the end.
+ */
+typedef enum HtmlToMarkdownErrorCode {
+  /**
+   * The last call succeeded
+   */
+  HtmlToMarkdownErrorCode_Ok = 0,
+  /**
+   * A required pointer argument was NULL
+   */
+  HtmlToMarkdownErrorCode_NullPointer = 1,
+  /**
+   * A string argument was not valid UTF-8
+   */
+  HtmlToMarkdownErrorCode_InvalidUtf8 = 2,
+  /**
+   * The input was rejected before conversion, e.g. because it looks like binary data
+   */
+  HtmlToMarkdownErrorCode_InvalidInput = 3,
+  /**
+   * The options or another configuration value were invalid
+   */
+  HtmlToMarkdownErrorCode_ConfigError = 4,
+  /**
+   * The HTML could not be parsed
+   */
+  HtmlToMarkdownErrorCode_ParseError = 5,
+  /**
+   * Sanitizing the HTML failed
+   */
+  HtmlToMarkdownErrorCode_SanitizationError = 6,
+  /**
+   * Reading or writing failed
+   */
+  HtmlToMarkdownErrorCode_IoError = 7,
+  /**
+   * A visitor callback stopped the conversion
+   */
+  HtmlToMarkdownErrorCode_VisitorError = 8,
+  /**
+   * The converter panicked; the panic was caught at the boundary
+   */
+  HtmlToMarkdownErrorCode_Panic = 9,
+  /**
+   * The result could not be handed back, e.g. because it contained a NUL byte
+   */
+  HtmlToMarkdownErrorCode_InvalidOutput = 10,
+  /**
+   * Any other failure
+   */
+  HtmlToMarkdownErrorCode_Other = 11,
+  /**
+   * A callback asked to stop the conversion
+   */
+  HtmlToMarkdownErrorCode_Cancelled = 12,
+} HtmlToMarkdownErrorCode;
+
 /**
  * Result type enumeration for visitor callbacks.
  *
@@ -21,113 +82,1139 @@ typedef enum HtmlToMarkdownVisitResultType {
   /**
    * Continue with default conversion behavior
    */
-  Continue = 0,
+  HtmlToMarkdownVisitResultType_Continue = 0,
   /**
    * Replace default output with custom markdown (requires `custom_output` set)
    */
-  Custom = 1,
+  HtmlToMarkdownVisitResultType_Custom = 1,
   /**
    * Skip this element entirely
    */
-  Skip = 2,
+  HtmlToMarkdownVisitResultType_Skip = 2,
   /**
    * Preserve original HTML verbatim
    */
-  PreserveHtml = 3,
+  HtmlToMarkdownVisitResultType_PreserveHtml = 3,
   /**
    * Stop conversion with error (requires `error_message` set)
    */
-  Error = 4,
+  HtmlToMarkdownVisitResultType_Error = 4,
 } HtmlToMarkdownVisitResultType;
 
-typedef struct Option_HtmlToMarkdownVisitAudioCallback Option_HtmlToMarkdownVisitAudioCallback;
+/**
+ * Node type enumeration (mirrors Rust `NodeType`).
+ *
+ * All HTML element types recognized by the converter are represented here.
+ * Used in `NodeContext` to classify elements during visitor callbacks.
+ */
+typedef enum HtmlToMarkdownNodeType {
+  /**
+   * Text node (character data).
+   */
+  HtmlToMarkdownNodeType_Text = 0,
+  /**
+   * Generic element node.
+   */
+  HtmlToMarkdownNodeType_Element = 1,
+  /**
+   * Heading element (h1-h6).
+   */
+  HtmlToMarkdownNodeType_Heading = 2,
+  /**
+   * Paragraph element.
+   */
+  HtmlToMarkdownNodeType_Paragraph = 3,
+  /**
+   * Division element.
+   */
+  HtmlToMarkdownNodeType_Div = 4,
+  /**
+   * Blockquote element.
+   */
+  HtmlToMarkdownNodeType_Blockquote = 5,
+  /**
+   * Preformatted text element.
+   */
+  HtmlToMarkdownNodeType_Pre = 6,
+  /**
+   * Horizontal rule element.
+   */
+  HtmlToMarkdownNodeType_Hr = 7,
+  /**
+   * Unordered or ordered list element.
+   */
+  HtmlToMarkdownNodeType_List = 8,
+  /**
+   * List item element.
+   */
+  HtmlToMarkdownNodeType_ListItem = 9,
+  /**
+   * Table element.
+   */
+  HtmlToMarkdownNodeType_Table = 10,
+  /**
+   * Table row element.
+   */
+  HtmlToMarkdownNodeType_TableRow = 11,
+  /**
+   * Table cell element.
+   */
+  HtmlToMarkdownNodeType_TableCell = 12,
+  /**
+   * Anchor/hyperlink element.
+   */
+  HtmlToMarkdownNodeType_Link = 13,
+  /**
+   * Image element.
+   */
+  HtmlToMarkdownNodeType_Image = 14,
+  /**
+   * Inline code element.
+   */
+  HtmlToMarkdownNodeType_Code = 15,
+  /**
+   * Strong/bold element.
+   */
+  HtmlToMarkdownNodeType_Strong = 16,
+  /**
+   * Emphasis/italic element.
+   */
+  HtmlToMarkdownNodeType_Em = 17,
+  /**
+   * Custom or unknown element type.
+   */
+  HtmlToMarkdownNodeType_Custom = 255,
+} HtmlToMarkdownNodeType;
+
+/**
+ * Opaque conversion options created by `html_to_markdown_options_new_from_json`.
+ */
+typedef struct HtmlToMarkdownOptions HtmlToMarkdownOptions;
+
+/**
+ * An image extracted from the HTML.
+ *
+ * All pointers stay valid until the owning extraction is freed.
+ */
+typedef struct HtmlToMarkdownInlineImage {
+  /**
+   * Raw image bytes, encoded in their original format (PNG, JPEG, SVG markup, ...)
+   */
+  const uint8_t *data;
+  /**
+   * Number of bytes at `data`
+   */
+  size_t data_len;
+  /**
+   * Image format, e.g. "png", "jpeg" or "svg"
+   */
+  const char *format;
+  /**
+   * Generated filename, or NULL
+   */
+  const char *filename;
+  /**
+   * Alt text or title from the source element, or NULL
+   */
+  const char *description;
+  /**
+   * Width in pixels, or 0 when the dimensions were not inferred
+   */
+  uint32_t width;
+  /**
+   * Height in pixels, or 0 when the dimensions were not inferred
+   */
+  uint32_t height;
+  /**
+   * Where the image came from: "img_data_uri", "svg_element" or "img_remote"
+   */
+  const char *source;
+  /**
+   * Other attributes of the source element as a JSON object
+   */
+  const char *attributes_json;
+} HtmlToMarkdownInlineImage;
+
+/**
+ * A non-fatal problem met while extracting an image.
+ */
+typedef struct HtmlToMarkdownInlineImageWarning {
+  /**
+   * 1-based index of the image the warning is about
+   */
+  size_t index;
+  /**
+   * Description of the problem
+   */
+  const char *message;
+} HtmlToMarkdownInlineImageWarning;
+
+/**
+ * Markdown and the images extracted while producing it.
+ *
+ * Returned by `html_to_markdown_convert_with_inline_images()` and released, together with
+ * everything it points to, by `html_to_markdown_inline_image_extraction_free()`.
+ */
+typedef struct HtmlToMarkdownInlineImageExtraction {
+  /**
+   * Converted Markdown, NULL-terminated
+   */
+  const char *markdown;
+  /**
+   * Length of `markdown` in bytes, without the terminator
+   */
+  size_t markdown_len;
+  /**
+   * Array of `image_count` images, or NULL when there are none
+   */
+  const struct HtmlToMarkdownInlineImage *images;
+  /**
+   * Number of entries in `images`
+   */
+  size_t image_count;
+  /**
+   * Array of `warning_count` warnings, or NULL when there are none
+   */
+  const struct HtmlToMarkdownInlineImageWarning *warnings;
+  /**
+   * Number of entries in `warnings`
+   */
+  size_t warning_count;
+} HtmlToMarkdownInlineImageExtraction;
+
+/**
+ * Callback receiving one chunk of Markdown from `html_to_markdown_convert_streaming()`.
+ *
+ * # Parameters
+ *
+ * - `user_data`: The pointer passed to `html_to_markdown_convert_streaming()`
+ * - `chunk`: `len` bytes of UTF-8 Markdown; not NULL-terminated, and never split inside a
+ *   character
+ * - `len`: Number of bytes at `chunk`
+ *
+ * # Returns
+ *
+ * `true` to continue, `false` to stop the conversion.
+ *
+ * # Safety
+ *
+ * - `chunk` is valid only during the callback; copy it if needed
+ */
+typedef bool (*HtmlToMarkdownChunkCallback)(void *user_data, const char *chunk, size_t len);
+
+/**
+ * Opaque handle to a visitor instance.
+ *
+ * Returned by `html_to_markdown_visitor_create()` and passed to
+ * `html_to_markdown_convert_with_visitor()`. Contains ownership of the
+ * underlying `CVisitorWrapper`.
+ */
+typedef void *HtmlToMarkdownVisitor;
+
+/**
+ * Result of a visitor callback (C-compatible).
+ *
+ * Returned by all visitor callback functions. Only certain fields are meaningful
+ * depending on `result_type`:
+ *
+ * - **Continue**: No additional fields needed
+ * - **Custom**: `custom_output` must point to a malloc'd string (NULL-terminated)
+ * - **Skip**: No additional fields needed
+ * - **`PreserveHtml`**: No additional fields needed
+ * - **Error**: `error_message` must point to a malloc'd string (NULL-terminated)
+ *
+ * # Memory Ownership
+ *
+ * - `custom_output` and `error_message` are OWNED BY THE CALLBACK
+ * - The FFI layer will free these strings after processing
+ * - MUST be allocated with malloc/calloc; will be freed with `free()`
+ *
+ * # Safety
+ *
+ * The conversion engine guarantees:
+ * - This struct is only valid during the callback and for the return statement
+ * - Allocated strings are freed immediately after callback returns
+ * - No references to callback data persist after return
+ */
+typedef struct HtmlToMarkdownVisitResult {
+  /**
+   * The action to take (Continue, Custom, Skip, etc.)
+   */
+  enum HtmlToMarkdownVisitResultType result_type;
+  /**
+   * Custom markdown output (only if `result_type` == Custom)
+   * Must be malloc'd NULL-terminated string; ownership transfers to FFI layer
+   */
+  char *custom_output;
+  /**
+   * Error message (only if `result_type` == Error)
+   * Must be malloc'd NULL-terminated string; ownership transfers to FFI layer
+   */
+  char *error_message;
+} HtmlToMarkdownVisitResult;
+
+/**
+ * Key-value attribute pair (C-compatible).
+ *
+ * Used in attribute arrays passed to callbacks. Both key and value
+ * are pointers to NULL-terminated C strings (valid for callback duration only).
+ */
+typedef struct HtmlToMarkdownAttribute {
+  /**
+   * Attribute name (e.g., "href", "class", "id")
+   */
+  const char *key;
+  /**
+   * Attribute value (e.g., "<https://example.com>", "container", "header-1")
+   */
+  const char *value;
+} HtmlToMarkdownAttribute;
+
+/**
+ * Node context for visitor callbacks (C-compatible).
+ *
+ * Provides comprehensive metadata about the current HTML node being visited.
+ * All string pointers are valid only for the duration of the callback.
+ *
+ * # Memory Safety
+ *
+ * This struct is allocated by the conversion engine and remains valid only
+ * during the callback. DO NOT store or dereference pointers after return.
+ *
+ * # String Ownership
+ *
+ * All string fields (`tag_name`, `parent_tag`, attribute keys/values)
+ * are owned by the conversion engine and freed after callback returns.
+ * Make copies if you need to preserve them.
+ */
+typedef struct HtmlToMarkdownNodeContext {
+  /**
+   * Node type classification (coarse-grained)
+   */
+  enum HtmlToMarkdownNodeType node_type;
+  /**
+   * Raw HTML tag name (e.g., "div", "h1", "custom-element")
+   * NULL-terminated C string
+   */
+  const char *tag_name;
+  /**
+   * HTML attributes array, NULL-terminated
+   * Access: attributes[0], attributes[1], ..., until key == NULL
+   */
+  const struct HtmlToMarkdownAttribute *attributes;
+  /**
+   * Depth in DOM tree (0 = root)
+   */
+  size_t depth;
+  /**
+   * Index among siblings (0-based)
+   */
+  size_t index_in_parent;
+  /**
+   * Parent element's tag name, or NULL if root
+   */
+  const char *parent_tag;
+  /**
+   * Whether element is inline vs block (true = inline)
+   */
+  bool is_inline;
+  /**
+   * RESERVED for future expansion; set to 0
+   */
+  size_t reserved;
+} HtmlToMarkdownNodeContext;
 
-typedef struct Option_HtmlToMarkdownVisitBlockquoteCallback Option_HtmlToMarkdownVisitBlockquoteCallback;
+/**
+ * Visitor callback function type for text nodes.
+ *
+ * Called for each text node in the HTML document.
+ * This is the most frequently called callback (100+ times per document).
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer passed to `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context (valid only for callback duration)
+ * - `text`: Text content (NULL-terminated, valid for callback duration)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` with `result_type` and optional custom output.
+ * If custom, allocate the output string with `malloc()`.
+ *
+ * # Safety
+ *
+ * - `ctx` is valid only during callback; don't store or dereference after
+ * - `text` is valid only during callback; make a copy if needed
+ * - Returned `custom_output` MUST be malloc'd; will be freed with `free()`
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitTextCallback)(void *user_data,
+                                                                            const struct HtmlToMarkdownNodeContext *ctx,
+                                                                            const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitButtonCallback Option_HtmlToMarkdownVisitButtonCallback;
+/**
+ * Visitor callback function type for start of elements.
+ *
+ * Called before entering any HTML element (pre-order traversal).
+ * Generic hook for all element types.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context (valid only for callback duration)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action to take.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitElementStartCallback)(void *user_data,
+                                                                                    const struct HtmlToMarkdownNodeContext *ctx);
 
-typedef struct Option_HtmlToMarkdownVisitCodeBlockCallback Option_HtmlToMarkdownVisitCodeBlockCallback;
+/**
+ * Visitor callback function type for end of elements.
+ *
+ * Called after exiting any HTML element (post-order traversal).
+ * Receives the default markdown output for the element.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context (valid only for callback duration)
+ * - `output`: Default markdown output (NULL-terminated, valid for callback duration)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` with optional custom replacement output.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitElementEndCallback)(void *user_data,
+                                                                                  const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                  const char *output);
 
-typedef struct Option_HtmlToMarkdownVisitCodeInlineCallback Option_HtmlToMarkdownVisitCodeInlineCallback;
+/**
+ * Visitor callback function type for links.
+ *
+ * Called for anchor links `<a href="...">`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with link metadata
+ * - `href`: Link URL (NULL-terminated, valid for callback duration)
+ * - `text`: Link text (already converted to markdown, NULL-terminated)
+ * - `title`: Title attribute, or NULL if not present
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitLinkCallback)(void *user_data,
+                                                                            const struct HtmlToMarkdownNodeContext *ctx,
+                                                                            const char *href,
+                                                                            const char *text,
+                                                                            const char *title);
 
-typedef struct Option_HtmlToMarkdownVisitCustomElementCallback Option_HtmlToMarkdownVisitCustomElementCallback;
+/**
+ * Visitor callback function type for images.
+ *
+ * Called for image elements `<img src="..." alt="...">`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with image metadata
+ * - `src`: Image source URL (NULL-terminated)
+ * - `alt`: Alt text (NULL-terminated)
+ * - `title`: Title attribute, or NULL if not present
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitImageCallback)(void *user_data,
+                                                                             const struct HtmlToMarkdownNodeContext *ctx,
+                                                                             const char *src,
+                                                                             const char *alt,
+                                                                             const char *title);
 
-typedef struct Option_HtmlToMarkdownVisitDefinitionDescriptionCallback Option_HtmlToMarkdownVisitDefinitionDescriptionCallback;
+/**
+ * Visitor callback function type for headings.
+ *
+ * Called for heading elements `<h1>` through `<h6>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with heading metadata
+ * - `level`: Heading level (1-6)
+ * - `text`: Heading text content (NULL-terminated)
+ * - `id`: ID attribute, or NULL if not present
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitHeadingCallback)(void *user_data,
+                                                                               const struct HtmlToMarkdownNodeContext *ctx,
+                                                                               uint32_t level,
+                                                                               const char *text,
+                                                                               const char *id);
 
-typedef struct Option_HtmlToMarkdownVisitDefinitionListEndCallback Option_HtmlToMarkdownVisitDefinitionListEndCallback;
+/**
+ * Visitor callback function type for code blocks.
+ *
+ * Called for code block elements `<pre><code>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with code block metadata
+ * - `lang`: Optional language specifier (NULL-terminated, or NULL if not present)
+ * - `code`: Code content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitCodeBlockCallback)(void *user_data,
+                                                                                 const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                 const char *lang,
+                                                                                 const char *code);
 
-typedef struct Option_HtmlToMarkdownVisitDefinitionListStartCallback Option_HtmlToMarkdownVisitDefinitionListStartCallback;
+/**
+ * Visitor callback function type for inline code.
+ *
+ * Called for inline code elements `<code>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with code metadata
+ * - `code`: Code content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitCodeInlineCallback)(void *user_data,
+                                                                                  const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                  const char *code);
 
-typedef struct Option_HtmlToMarkdownVisitDefinitionTermCallback Option_HtmlToMarkdownVisitDefinitionTermCallback;
+/**
+ * Visitor callback function type for list items.
+ *
+ * Called for list item elements `<li>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with list item metadata
+ * - `ordered`: Whether this is in an ordered list
+ * - `marker`: The list marker string (e.g., "-", "1.", "a)") (NULL-terminated)
+ * - `text`: The list item content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitListItemCallback)(void *user_data,
+                                                                                const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                bool ordered,
+                                                                                const char *marker,
+                                                                                const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitDetailsCallback Option_HtmlToMarkdownVisitDetailsCallback;
+/**
+ * Visitor callback function type for list start.
+ *
+ * Called before processing a list element `<ul>` or `<ol>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with list metadata
+ * - `ordered`: Whether this is an ordered list (true) or unordered (false)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitListStartCallback)(void *user_data,
+                                                                                 const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                 bool ordered);
 
-typedef struct Option_HtmlToMarkdownVisitElementEndCallback Option_HtmlToMarkdownVisitElementEndCallback;
+/**
+ * Visitor callback function type for list end.
+ *
+ * Called after processing a list element `</ul>` or `</ol>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with list metadata
+ * - `ordered`: Whether this is an ordered list
+ * - `output`: The default markdown output for the list (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitListEndCallback)(void *user_data,
+                                                                               const struct HtmlToMarkdownNodeContext *ctx,
+                                                                               bool ordered,
+                                                                               const char *output);
 
-typedef struct Option_HtmlToMarkdownVisitElementStartCallback Option_HtmlToMarkdownVisitElementStartCallback;
+/**
+ * Visitor callback function type for table start.
+ *
+ * Called before processing a table element `<table>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with table metadata
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitTableStartCallback)(void *user_data,
+                                                                                  const struct HtmlToMarkdownNodeContext *ctx);
 
-typedef struct Option_HtmlToMarkdownVisitEmphasisCallback Option_HtmlToMarkdownVisitEmphasisCallback;
+/**
+ * Visitor callback function type for table rows.
+ *
+ * Called for table row elements `<tr>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with row metadata
+ * - `cells`: Array of cell contents (NULL-terminated array of NULL-terminated strings)
+ * - `cell_count`: Number of cells in the row
+ * - `is_header`: Whether this row is in a header section (true) or body (false)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitTableRowCallback)(void *user_data,
+                                                                                const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                const char *const *cells,
+                                                                                size_t cell_count,
+                                                                                bool is_header);
 
-typedef struct Option_HtmlToMarkdownVisitFigcaptionCallback Option_HtmlToMarkdownVisitFigcaptionCallback;
+/**
+ * Visitor callback function type for table end.
+ *
+ * Called after processing a table element `</table>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with table metadata
+ * - `output`: The default markdown output for the table (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitTableEndCallback)(void *user_data,
+                                                                                const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                const char *output);
 
-typedef struct Option_HtmlToMarkdownVisitFigureEndCallback Option_HtmlToMarkdownVisitFigureEndCallback;
+/**
+ * Visitor callback function type for blockquotes.
+ *
+ * Called for blockquote elements `<blockquote>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with blockquote metadata
+ * - `content`: The blockquote content (NULL-terminated)
+ * - `depth`: Nesting depth for nested blockquotes
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitBlockquoteCallback)(void *user_data,
+                                                                                  const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                  const char *content,
+                                                                                  size_t depth);
 
-typedef struct Option_HtmlToMarkdownVisitFigureStartCallback Option_HtmlToMarkdownVisitFigureStartCallback;
+/**
+ * Visitor callback function type for strong/bold elements.
+ *
+ * Called for `<strong>` and `<b>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with strong element metadata
+ * - `text`: The text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitStrongCallback)(void *user_data,
+                                                                              const struct HtmlToMarkdownNodeContext *ctx,
+                                                                              const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitFormCallback Option_HtmlToMarkdownVisitFormCallback;
+/**
+ * Visitor callback function type for emphasis/italic elements.
+ *
+ * Called for `<em>` and `<i>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with emphasis element metadata
+ * - `text`: The text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitEmphasisCallback)(void *user_data,
+                                                                                const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitHeadingCallback Option_HtmlToMarkdownVisitHeadingCallback;
+/**
+ * Visitor callback function type for strikethrough elements.
+ *
+ * Called for `<s>`, `<del>`, and `<strike>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with strikethrough element metadata
+ * - `text`: The text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitStrikethroughCallback)(void *user_data,
+                                                                                     const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                     const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitHorizontalRuleCallback Option_HtmlToMarkdownVisitHorizontalRuleCallback;
+/**
+ * Visitor callback function type for underline elements.
+ *
+ * Called for `<u>` and `<ins>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with underline element metadata
+ * - `text`: The text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitUnderlineCallback)(void *user_data,
+                                                                                 const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                 const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitIframeCallback Option_HtmlToMarkdownVisitIframeCallback;
+/**
+ * Visitor callback function type for subscript elements.
+ *
+ * Called for `<sub>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with subscript element metadata
+ * - `text`: The text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitSubscriptCallback)(void *user_data,
+                                                                                 const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                 const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitImageCallback Option_HtmlToMarkdownVisitImageCallback;
+/**
+ * Visitor callback function type for superscript elements.
+ *
+ * Called for `<sup>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with superscript element metadata
+ * - `text`: The text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitSuperscriptCallback)(void *user_data,
+                                                                                   const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                   const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitInputCallback Option_HtmlToMarkdownVisitInputCallback;
+/**
+ * Visitor callback function type for mark/highlight elements.
+ *
+ * Called for `<mark>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with mark element metadata
+ * - `text`: The text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitMarkCallback)(void *user_data,
+                                                                            const struct HtmlToMarkdownNodeContext *ctx,
+                                                                            const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitLineBreakCallback Option_HtmlToMarkdownVisitLineBreakCallback;
+/**
+ * Visitor callback function type for line breaks.
+ *
+ * Called for `<br>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with line break metadata
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitLineBreakCallback)(void *user_data,
+                                                                                 const struct HtmlToMarkdownNodeContext *ctx);
 
-typedef struct Option_HtmlToMarkdownVisitLinkCallback Option_HtmlToMarkdownVisitLinkCallback;
+/**
+ * Visitor callback function type for horizontal rules.
+ *
+ * Called for `<hr>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with horizontal rule metadata
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitHorizontalRuleCallback)(void *user_data,
+                                                                                      const struct HtmlToMarkdownNodeContext *ctx);
 
-typedef struct Option_HtmlToMarkdownVisitListEndCallback Option_HtmlToMarkdownVisitListEndCallback;
+/**
+ * Visitor callback function type for custom/unknown elements.
+ *
+ * Called for custom elements (web components) or unknown tags.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with custom element metadata
+ * - `tag_name`: The custom element's tag name (NULL-terminated)
+ * - `html`: The raw HTML of this element (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitCustomElementCallback)(void *user_data,
+                                                                                     const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                     const char *tag_name,
+                                                                                     const char *html);
 
-typedef struct Option_HtmlToMarkdownVisitListItemCallback Option_HtmlToMarkdownVisitListItemCallback;
+/**
+ * Visitor callback function type for definition list start.
+ *
+ * Called before processing a definition list `<dl>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with definition list metadata
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitDefinitionListStartCallback)(void *user_data,
+                                                                                           const struct HtmlToMarkdownNodeContext *ctx);
 
-typedef struct Option_HtmlToMarkdownVisitListStartCallback Option_HtmlToMarkdownVisitListStartCallback;
+/**
+ * Visitor callback function type for definition terms.
+ *
+ * Called for definition term elements `<dt>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with definition term metadata
+ * - `text`: The term text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitDefinitionTermCallback)(void *user_data,
+                                                                                      const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                      const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitMarkCallback Option_HtmlToMarkdownVisitMarkCallback;
+/**
+ * Visitor callback function type for definition descriptions.
+ *
+ * Called for definition description elements `<dd>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with definition description metadata
+ * - `text`: The description text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitDefinitionDescriptionCallback)(void *user_data,
+                                                                                             const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                             const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitStrikethroughCallback Option_HtmlToMarkdownVisitStrikethroughCallback;
+/**
+ * Visitor callback function type for definition list end.
+ *
+ * Called after processing a definition list `</dl>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with definition list metadata
+ * - `output`: The default markdown output for the definition list (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitDefinitionListEndCallback)(void *user_data,
+                                                                                         const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                         const char *output);
 
-typedef struct Option_HtmlToMarkdownVisitStrongCallback Option_HtmlToMarkdownVisitStrongCallback;
+/**
+ * Visitor callback function type for form elements.
+ *
+ * Called for `<form>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with form metadata
+ * - `action`: Form action attribute (NULL-terminated, or NULL if not present)
+ * - `method`: Form method attribute (NULL-terminated, or NULL if not present)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitFormCallback)(void *user_data,
+                                                                            const struct HtmlToMarkdownNodeContext *ctx,
+                                                                            const char *action,
+                                                                            const char *method);
 
-typedef struct Option_HtmlToMarkdownVisitSubscriptCallback Option_HtmlToMarkdownVisitSubscriptCallback;
+/**
+ * Visitor callback function type for input elements.
+ *
+ * Called for `<input>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with input metadata
+ * - `input_type`: Input type attribute (NULL-terminated)
+ * - `name`: Name attribute (NULL-terminated, or NULL if not present)
+ * - `value`: Value attribute (NULL-terminated, or NULL if not present)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitInputCallback)(void *user_data,
+                                                                             const struct HtmlToMarkdownNodeContext *ctx,
+                                                                             const char *input_type,
+                                                                             const char *name,
+                                                                             const char *value);
 
-typedef struct Option_HtmlToMarkdownVisitSummaryCallback Option_HtmlToMarkdownVisitSummaryCallback;
+/**
+ * Visitor callback function type for button elements.
+ *
+ * Called for `<button>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with button metadata
+ * - `text`: The button text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitButtonCallback)(void *user_data,
+                                                                              const struct HtmlToMarkdownNodeContext *ctx,
+                                                                              const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitSuperscriptCallback Option_HtmlToMarkdownVisitSuperscriptCallback;
+/**
+ * Visitor callback function type for audio elements.
+ *
+ * Called for `<audio>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with audio metadata
+ * - `src`: Source URL (NULL-terminated, or NULL if not present)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitAudioCallback)(void *user_data,
+                                                                             const struct HtmlToMarkdownNodeContext *ctx,
+                                                                             const char *src);
 
-typedef struct Option_HtmlToMarkdownVisitTableEndCallback Option_HtmlToMarkdownVisitTableEndCallback;
+/**
+ * Visitor callback function type for video elements.
+ *
+ * Called for `<video>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with video metadata
+ * - `src`: Source URL (NULL-terminated, or NULL if not present)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitVideoCallback)(void *user_data,
+                                                                             const struct HtmlToMarkdownNodeContext *ctx,
+                                                                             const char *src);
 
-typedef struct Option_HtmlToMarkdownVisitTableRowCallback Option_HtmlToMarkdownVisitTableRowCallback;
+/**
+ * Visitor callback function type for iframe elements.
+ *
+ * Called for `<iframe>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with iframe metadata
+ * - `src`: Source URL (NULL-terminated, or NULL if not present)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitIframeCallback)(void *user_data,
+                                                                              const struct HtmlToMarkdownNodeContext *ctx,
+                                                                              const char *src);
 
-typedef struct Option_HtmlToMarkdownVisitTableStartCallback Option_HtmlToMarkdownVisitTableStartCallback;
+/**
+ * Visitor callback function type for details elements.
+ *
+ * Called for `<details>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with details metadata
+ * - `open`: Whether the details element is open (true) or closed (false)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitDetailsCallback)(void *user_data,
+                                                                               const struct HtmlToMarkdownNodeContext *ctx,
+                                                                               bool open);
 
-typedef struct Option_HtmlToMarkdownVisitTextCallback Option_HtmlToMarkdownVisitTextCallback;
+/**
+ * Visitor callback function type for summary elements.
+ *
+ * Called for `<summary>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with summary metadata
+ * - `text`: The summary text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitSummaryCallback)(void *user_data,
+                                                                               const struct HtmlToMarkdownNodeContext *ctx,
+                                                                               const char *text);
 
-typedef struct Option_HtmlToMarkdownVisitUnderlineCallback Option_HtmlToMarkdownVisitUnderlineCallback;
+/**
+ * Visitor callback function type for figure start.
+ *
+ * Called before processing a figure element `<figure>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with figure metadata
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitFigureStartCallback)(void *user_data,
+                                                                                   const struct HtmlToMarkdownNodeContext *ctx);
 
-typedef struct Option_HtmlToMarkdownVisitVideoCallback Option_HtmlToMarkdownVisitVideoCallback;
+/**
+ * Visitor callback function type for figcaption elements.
+ *
+ * Called for `<figcaption>` elements.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with figcaption metadata
+ * - `text`: The caption text content (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
+ */
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitFigcaptionCallback)(void *user_data,
+                                                                                  const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                  const char *text);
 
 /**
- * Opaque handle to a visitor instance.
+ * Visitor callback function type for figure end.
  *
- * Returned by `html_to_markdown_visitor_create()` and passed to
- * `html_to_markdown_convert_with_visitor()`. Contains ownership of the
- * underlying `CVisitorWrapper`.
+ * Called after processing a figure element `</figure>`.
+ *
+ * # Arguments
+ *
+ * - `user_data`: Context pointer from `html_to_markdown_visitor_create()`
+ * - `ctx`: Node context with figure metadata
+ * - `output`: The default markdown output for the figure (NULL-terminated)
+ *
+ * # Returns
+ *
+ * `HtmlToMarkdownVisitResult` indicating action.
  */
-typedef void *HtmlToMarkdownVisitor;
+typedef struct HtmlToMarkdownVisitResult (*HtmlToMarkdownVisitFigureEndCallback)(void *user_data,
+                                                                                 const struct HtmlToMarkdownNodeContext *ctx,
+                                                                                 const char *output);
 
 /**
  * Complete callback table for visitor (C-compatible).
@@ -156,206 +1243,168 @@ typedef struct HtmlToMarkdownVisitorCallbacks {
   /**
    * Called for text nodes (most frequent)
    */
-  struct Option_HtmlToMarkdownVisitTextCallback visit_text;
+  HtmlToMarkdownVisitTextCallback visit_text;
   /**
    * Called before entering any element
    */
-  struct Option_HtmlToMarkdownVisitElementStartCallback visit_element_start;
+  HtmlToMarkdownVisitElementStartCallback visit_element_start;
   /**
    * Called after exiting any element
    */
-  struct Option_HtmlToMarkdownVisitElementEndCallback visit_element_end;
+  HtmlToMarkdownVisitElementEndCallback visit_element_end;
   /**
    * Called for anchor links
    */
-  struct Option_HtmlToMarkdownVisitLinkCallback visit_link;
+  HtmlToMarkdownVisitLinkCallback visit_link;
   /**
    * Called for images
    */
-  struct Option_HtmlToMarkdownVisitImageCallback visit_image;
+  HtmlToMarkdownVisitImageCallback visit_image;
   /**
    * Called for headings
    */
-  struct Option_HtmlToMarkdownVisitHeadingCallback visit_heading;
+  HtmlToMarkdownVisitHeadingCallback visit_heading;
   /**
    * Called for code blocks
    */
-  struct Option_HtmlToMarkdownVisitCodeBlockCallback visit_code_block;
+  HtmlToMarkdownVisitCodeBlockCallback visit_code_block;
   /**
    * Called for inline code
    */
-  struct Option_HtmlToMarkdownVisitCodeInlineCallback visit_code_inline;
+  HtmlToMarkdownVisitCodeInlineCallback visit_code_inline;
   /**
    * Called for list items
    */
-  struct Option_HtmlToMarkdownVisitListItemCallback visit_list_item;
+  HtmlToMarkdownVisitListItemCallback visit_list_item;
   /**
    * Called before processing a list
    */
-  struct Option_HtmlToMarkdownVisitListStartCallback visit_list_start;
+  HtmlToMarkdownVisitListStartCallback visit_list_start;
   /**
    * Called after processing a list
    */
-  struct Option_HtmlToMarkdownVisitListEndCallback visit_list_end;
+  HtmlToMarkdownVisitListEndCallback visit_list_end;
   /**
    * Called before processing a table
    */
-  struct Option_HtmlToMarkdownVisitTableStartCallback visit_table_start;
+  HtmlToMarkdownVisitTableStartCallback visit_table_start;
   /**
    * Called for table rows
    */
-  struct Option_HtmlToMarkdownVisitTableRowCallback visit_table_row;
+  HtmlToMarkdownVisitTableRowCallback visit_table_row;
   /**
    * Called after processing a table
    */
-  struct Option_HtmlToMarkdownVisitTableEndCallback visit_table_end;
+  HtmlToMarkdownVisitTableEndCallback visit_table_end;
   /**
    * Called for blockquotes
    */
-  struct Option_HtmlToMarkdownVisitBlockquoteCallback visit_blockquote;
+  HtmlToMarkdownVisitBlockquoteCallback visit_blockquote;
   /**
    * Called for strong text
    */
-  struct Option_HtmlToMarkdownVisitStrongCallback visit_strong;
+  HtmlToMarkdownVisitStrongCallback visit_strong;
   /**
    * Called for emphasis text
    */
-  struct Option_HtmlToMarkdownVisitEmphasisCallback visit_emphasis;
+  HtmlToMarkdownVisitEmphasisCallback visit_emphasis;
   /**
    * Called for strikethrough text
    */
-  struct Option_HtmlToMarkdownVisitStrikethroughCallback visit_strikethrough;
+  HtmlToMarkdownVisitStrikethroughCallback visit_strikethrough;
   /**
    * Called for underline text
    */
-  struct Option_HtmlToMarkdownVisitUnderlineCallback visit_underline;
+  HtmlToMarkdownVisitUnderlineCallback visit_underline;
   /**
    * Called for subscript text
    */
-  struct Option_HtmlToMarkdownVisitSubscriptCallback visit_subscript;
+  HtmlToMarkdownVisitSubscriptCallback visit_subscript;
   /**
    * Called for superscript text
    */
-  struct Option_HtmlToMarkdownVisitSuperscriptCallback visit_superscript;
+  HtmlToMarkdownVisitSuperscriptCallback visit_superscript;
   /**
    * Called for mark text
    */
-  struct Option_HtmlToMarkdownVisitMarkCallback visit_mark;
+  HtmlToMarkdownVisitMarkCallback visit_mark;
   /**
    * Called for line breaks
    */
-  struct Option_HtmlToMarkdownVisitLineBreakCallback visit_line_break;
+  HtmlToMarkdownVisitLineBreakCallback visit_line_break;
   /**
    * Called for horizontal rules
    */
-  struct Option_HtmlToMarkdownVisitHorizontalRuleCallback visit_horizontal_rule;
+  HtmlToMarkdownVisitHorizontalRuleCallback visit_horizontal_rule;
   /**
    * Called for custom elements
    */
-  struct Option_HtmlToMarkdownVisitCustomElementCallback visit_custom_element;
+  HtmlToMarkdownVisitCustomElementCallback visit_custom_element;
   /**
    * Called before processing a definition list
    */
-  struct Option_HtmlToMarkdownVisitDefinitionListStartCallback visit_definition_list_start;
+  HtmlToMarkdownVisitDefinitionListStartCallback visit_definition_list_start;
   /**
    * Called for definition terms
    */
-  struct Option_HtmlToMarkdownVisitDefinitionTermCallback visit_definition_term;
+  HtmlToMarkdownVisitDefinitionTermCallback visit_definition_term;
   /**
    * Called for definition descriptions
    */
-  struct Option_HtmlToMarkdownVisitDefinitionDescriptionCallback visit_definition_description;
+  HtmlToMarkdownVisitDefinitionDescriptionCallback visit_definition_description;
   /**
    * Called after processing a definition list
    */
-  struct Option_HtmlToMarkdownVisitDefinitionListEndCallback visit_definition_list_end;
+  HtmlToMarkdownVisitDefinitionListEndCallback visit_definition_list_end;
   /**
    * Called for form elements
    */
-  struct Option_HtmlToMarkdownVisitFormCallback visit_form;
+  HtmlToMarkdownVisitFormCallback visit_form;
   /**
    * Called for input elements
    */
-  struct Option_HtmlToMarkdownVisitInputCallback visit_input;
+  HtmlToMarkdownVisitInputCallback visit_input;
   /**
    * Called for button elements
    */
-  struct Option_HtmlToMarkdownVisitButtonCallback visit_button;
+  HtmlToMarkdownVisitButtonCallback visit_button;
   /**
    * Called for audio elements
    */
-  struct Option_HtmlToMarkdownVisitAudioCallback visit_audio;
+  HtmlToMarkdownVisitAudioCallback visit_audio;
   /**
    * Called for video elements
    */
-  struct Option_HtmlToMarkdownVisitVideoCallback visit_video;
+  HtmlToMarkdownVisitVideoCallback visit_video;
   /**
    * Called for iframe elements
    */
-  struct Option_HtmlToMarkdownVisitIframeCallback visit_iframe;
+  HtmlToMarkdownVisitIframeCallback visit_iframe;
   /**
    * Called for details elements
    */
-  struct Option_HtmlToMarkdownVisitDetailsCallback visit_details;
+  HtmlToMarkdownVisitDetailsCallback visit_details;
   /**
    * Called for summary elements
    */
-  struct Option_HtmlToMarkdownVisitSummaryCallback visit_summary;
+  HtmlToMarkdownVisitSummaryCallback visit_summary;
   /**
    * Called before processing a figure
    */
-  struct Option_HtmlToMarkdownVisitFigureStartCallback visit_figure_start;
+  HtmlToMarkdownVisitFigureStartCallback visit_figure_start;
   /**
    * Called for figcaption elements
    */
-  struct Option_HtmlToMarkdownVisitFigcaptionCallback visit_figcaption;
+  HtmlToMarkdownVisitFigcaptionCallback visit_figcaption;
   /**
    * Called after processing a figure
    */
-  struct Option_HtmlToMarkdownVisitFigureEndCallback visit_figure_end;
+  HtmlToMarkdownVisitFigureEndCallback visit_figure_end;
 } HtmlToMarkdownVisitorCallbacks;
 
-/**
- * Result of a visitor callback (C-compatible).
- *
- * Returned by all visitor callback functions. Only certain fields are meaningful
- * depending on `result_type`:
- *
- * - **Continue**: No additional fields needed
- * - **Custom**: `custom_output` must point to a malloc'd string (NULL-terminated)
- * - **Skip**: No additional fields needed
- * - **`PreserveHtml`**: No additional fields needed
- * - **Error**: `error_message` must point to a malloc'd string (NULL-terminated)
- *
- * # Memory Ownership
- *
- * - `custom_output` and `error_message` are OWNED BY THE CALLBACK
- * - The FFI layer will free these strings after processing
- * - MUST be allocated with malloc/calloc; will be freed with `free()`
- *
- * # Safety
- *
- * The conversion engine guarantees:
- * - This struct is only valid during the callback and for the return statement
- * - Allocated strings are freed immediately after callback returns
- * - No references to callback data persist after return
- */
-typedef struct HtmlToMarkdownVisitResult {
-  /**
-   * The action to take (Continue, Custom, Skip, etc.)
-   */
-  enum HtmlToMarkdownVisitResultType result_type;
-  /**
-   * Custom markdown output (only if `result_type` == Custom)
-   * Must be malloc'd NULL-terminated string; ownership transfers to FFI layer
-   */
-  char *custom_output;
-  /**
-   * Error message (only if `result_type` == Error)
-   * Must be malloc'd NULL-terminated string; ownership transfers to FFI layer
-   */
-  char *error_message;
-} HtmlToMarkdownVisitResult;
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
 
 /**
  * Start Rust-side profiling and write a flamegraph to the specified path.
@@ -379,6 +1428,20 @@ bool html_to_markdown_profile_start(const char *output, int32_t frequency);
  */
 bool html_to_markdown_profile_stop(void);
 
+/**
+ * Check conversion options given as a JSON object, in the camelCase form the other bindings accept.
+ *
+ * Returns 1 when the options parse and are valid, 0 otherwise. Unknown fields count as errors
+ * unless the object sets `"strictOptions": false`. On failure `html_to_markdown_last_error`
+ * holds the parse error or every problem found, separated by `"; "`, with the same wording as
+ * the other bindings use.
+ *
+ * # Safety
+ *
+ * - `options_json` must be a valid, null-terminated UTF-8 C string for the duration of the call.
+ */
+bool html_to_markdown_validate_options(const char *options_json);
+
 /**
  * Convert HTML to Markdown using default options.
  *
@@ -411,7 +1474,7 @@ char *html_to_markdown_convert(const char *html);
  * - The returned string must be freed with `html_to_markdown_free_string`
  * - Returns NULL on error
  */
-char *html_to_markdown_convert_with_len(const char *html, uintptr_t *len_out);
+char *html_to_markdown_convert_with_len(const char *html, size_t *len_out);
 
 /**
  * Convert UTF-8 HTML bytes to Markdown and return the output length.
@@ -423,9 +1486,7 @@ char *html_to_markdown_convert_with_len(const char *html, uintptr_t *len_out);
  * - The returned string must be freed with `html_to_markdown_free_string`
  * - Returns NULL on error
  */
-char *html_to_markdown_convert_bytes_with_len(const uint8_t *html,
-                                              uintptr_t len,
-                                              uintptr_t *len_out);
+char *html_to_markdown_convert_bytes_with_len(const uint8_t *html, size_t len, size_t *len_out);
 
 /**
  * Free a string returned by `html_to_markdown_convert`.
@@ -446,6 +1507,47 @@ char *html_to_markdown_convert_bytes_with_len(const uint8_t *html,
  */
 void html_to_markdown_free_string(char *s);
 
+/**
+ * Convert an array of HTML documents to Markdown using default options.
+ *
+ * Returns an array of `count` Markdown strings in input order, converting every document in
+ * one call to avoid per-document FFI overhead. Entries for documents that could not be
+ * converted are NULL, and `html_to_markdown_last_error` and `html_to_markdown_error_code`
+ * describe the first such failure.
+ *
+ * # Safety
+ *
+ * - `htmls` must point to `count` pointers, each NULL or a valid null-terminated C string
+ * - The returned array must be freed with `html_to_markdown_free_string_array` using the same `count`
+ * - Returns NULL if `htmls` is NULL
+ *
+ * # Example (C)
+ *
+ * ```c
+ * const char* docs[] = {"<h1>One</h1>", "<p>Two</p>"};
+ * char** results = html_to_markdown_convert_batch(docs, 2);
+ * if (results != NULL) {
+ *     for (size_t i = 0; i < 2; i++) {
+ *         printf("%s\n", results[i] ? results[i] : html_to_markdown_last_error());
+ *     }
+ *     html_to_markdown_free_string_array(results, 2);
+ * }
+ * ```
+ */
+char **html_to_markdown_convert_batch(const char *const *htmls,
+                                      size_t count);
+
+/**
+ * Free an array returned by `html_to_markdown_convert_batch`, including every string in it.
+ *
+ * # Safety
+ *
+ * - `strings` must be an array previously returned by `html_to_markdown_convert_batch`, or NULL
+ * - `count` must match the `count` passed to `html_to_markdown_convert_batch`
+ * - Neither the array nor its strings may be used after this call
+ */
+void html_to_markdown_free_string_array(char **strings, size_t count);
+
 /**
  * Get the library version string.
  *
@@ -497,8 +1599,8 @@ char *html_to_markdown_convert_with_metadata(const char *html,
  */
 char *html_to_markdown_convert_with_metadata_with_len(const char *html,
                                                       char **metadata_json_out,
-                                                      uintptr_t *markdown_len_out,
-                                                      uintptr_t *metadata_len_out);
+                                                      size_t *markdown_len_out,
+                                                      size_t *metadata_len_out);
 
 /**
  * Convert UTF-8 HTML bytes to Markdown with metadata extraction and return output lengths.
@@ -513,14 +1615,16 @@ char *html_to_markdown_convert_with_metadata_with_len(const char *html,
  * - Returns NULL on error (check error with `html_to_markdown_last_error`)
  */
 char *html_to_markdown_convert_with_metadata_bytes_with_len(const uint8_t *html,
-                                                            uintptr_t len,
+                                                            size_t len,
                                                             char **metadata_json_out,
-                                                            uintptr_t *markdown_len_out,
-                                                            uintptr_t *metadata_len_out);
+                                                            size_t *markdown_len_out,
+                                                            size_t *metadata_len_out);
 
 /**
  * Get the last error message from a failed conversion.
  *
+ * Only reports errors from calls made on the calling thread.
+ *
  * # Safety
  *
  * - Returns a pointer to a thread-local buffer; copy it immediately if needed
@@ -529,6 +1633,235 @@ char *html_to_markdown_convert_with_metadata_bytes_with_len(const uint8_t *html,
  */
 const char *html_to_markdown_last_error(void);
 
+/**
+ * Get a copy of the calling thread's last error message.
+ *
+ * Unlike `html_to_markdown_last_error()`, the returned string belongs to the caller and stays
+ * valid across later calls, which suits hosts that hand the message to another thread or
+ * keep it beyond the next conversion. The error itself is left in place.
+ *
+ * # Returns
+ *
+ * - Non-NULL: The message, to be freed with `html_to_markdown_free_string()`
+ * - NULL: The last call on this thread succeeded, or `html_to_markdown_clear_error()` was
+ *   called since
+ *
+ * # Safety
+ *
+ * - The returned string must be freed with `html_to_markdown_free_string()`
+ */
+char *html_to_markdown_last_error_for_thread(void);
+
+/**
+ * Clear the calling thread's last error.
+ *
+ * Afterwards `html_to_markdown_last_error()` returns NULL and `html_to_markdown_error_code()`
+ * returns `HtmlToMarkdownErrorCode_Ok` on this thread, until the next failing call. Other
+ * threads are not affected.
+ *
+ * # Safety
+ *
+ * Always safe to call; invalidates pointers previously returned by
+ * `html_to_markdown_last_error()` on this thread.
+ */
+void html_to_markdown_clear_error(void);
+
+/**
+ * Get the code of the last error from a failed call.
+ *
+ * Lets callers branch on the kind of failure without matching on
+ * `html_to_markdown_last_error()` messages. Like the message, the code is kept per thread and
+ * reset to `Ok` by the next successful call.
+ *
+ * # Safety
+ *
+ * Always safe to call; marked `unsafe` for consistency with the rest of the API.
+ *
+ * # Example (C)
+ *
+ * ```c
+ * char *markdown = html_to_markdown_convert(html);
+ * if (markdown == NULL) {
+ *     switch (html_to_markdown_error_code()) {
+ *     case HtmlToMarkdownErrorCode_InvalidInput:
+ *         skip_document(html);
+ *         break;
+ *     default:
+ *         fprintf(stderr, "%s\n", html_to_markdown_last_error());
+ *     }
+ * }
+ * ```
+ */
+enum HtmlToMarkdownErrorCode html_to_markdown_error_code(void);
+
+/**
+ * Convert HTML to Markdown and extract its inline images.
+ *
+ * Collects `data:` URI images and inline `<svg>` elements, with their raw bytes, alongside
+ * the Markdown.
+ *
+ * # Arguments
+ *
+ * - `html`: NULL-terminated HTML string
+ * - `options`: Options handle from `html_to_markdown_options_new_from_json()`, or NULL for
+ *   the default options
+ * - `image_config_json`: Extraction settings as a JSON object (`maxDecodedSizeBytes`,
+ *   `filenamePrefix`, `captureSvg`, `inferDimensions`), or NULL for the defaults
+ *
+ * # Returns
+ *
+ * - Non-NULL: Extraction, to be released with `html_to_markdown_inline_image_extraction_free()`
+ * - NULL: Conversion failed; call `html_to_markdown_last_error()`
+ *
+ * # Safety
+ *
+ * - `html` must be a valid null-terminated C string
+ * - `options` must be NULL or a handle that has not been freed
+ * - `image_config_json` must be NULL or a valid null-terminated UTF-8 C string
+ *
+ * # Example (C)
+ *
+ * ```c
+ * HtmlToMarkdownInlineImageExtraction *result = html_to_markdown_convert_with_inline_images(
+ *     "<img src=\"data:image/png;base64,iVBORw0KGgo=\" alt=\"Logo\">", NULL,
+ *     "{\"inferDimensions\":true}");
+ * if (result != NULL) {
+ *     for (size_t i = 0; i < result->image_count; i++) {
+ *         const HtmlToMarkdownInlineImage *image = &result->images[i];
+ *         write_file(image->filename, image->data, image->data_len);
+ *     }
+ *     html_to_markdown_inline_image_extraction_free(result);
+ * }
+ * ```
+ */
+struct HtmlToMarkdownInlineImageExtraction *html_to_markdown_convert_with_inline_images(const char *html,
+                                                                                        const struct HtmlToMarkdownOptions *options,
+                                                                                        const char *image_config_json);
+
+/**
+ * Free an extraction returned by `html_to_markdown_convert_with_inline_images()`.
+ *
+ * Releases the Markdown, the image and warning arrays, and every string and byte buffer they
+ * point to.
+ *
+ * # Safety
+ *
+ * - `extraction` must come from `html_to_markdown_convert_with_inline_images()`, or be NULL
+ *   (a no-op)
+ * - Nothing reached through `extraction` may be used after this call
+ */
+void html_to_markdown_inline_image_extraction_free(struct HtmlToMarkdownInlineImageExtraction *extraction);
+
+/**
+ * Create an options handle from a JSON object, in the camelCase form the other bindings accept.
+ *
+ * Fields left out keep their defaults. Unknown fields are rejected unless the object sets
+ * `"strictOptions": false`, and the options are validated the same way as by
+ * `html_to_markdown_validate_options`.
+ *
+ * # Returns
+ *
+ * - Non-NULL: Options handle, to be released with `html_to_markdown_options_free()`
+ * - NULL: The JSON did not parse or held invalid values; call `html_to_markdown_last_error()`
+ *
+ * # Safety
+ *
+ * - `options_json` must be a valid, null-terminated UTF-8 C string for the duration of the call
+ * - The handle may be used from any thread, but not from two threads while one frees it
+ *
+ * # Example (C)
+ *
+ * ```c
+ * HtmlToMarkdownOptions *options =
+ *     html_to_markdown_options_new_from_json("{\"headingStyle\":\"atx\",\"stripTags\":[\"span\"]}");
+ * if (options == NULL) {
+ *     fprintf(stderr, "Invalid options: %s\n", html_to_markdown_last_error());
+ *     return 1;
+ * }
+ * ```
+ */
+struct HtmlToMarkdownOptions *html_to_markdown_options_new_from_json(const char *options_json);
+
+/**
+ * Convert HTML to Markdown with an options handle.
+ *
+ * The handle is only read, so it can be reused for any number of conversions.
+ *
+ * # Safety
+ *
+ * - `options` must be a handle from `html_to_markdown_options_new_from_json()` that has not
+ *   been freed
+ * - `html` must be a valid null-terminated C string
+ * - The returned string must be freed with `html_to_markdown_free_string`
+ * - Returns NULL on error
+ *
+ * # Example (C)
+ *
+ * ```c
+ * char *markdown = html_to_markdown_convert_with_options(options, "<h1>Hello</h1>");
+ * if (markdown != NULL) {
+ *     printf("%s\n", markdown);
+ *     html_to_markdown_free_string(markdown);
+ * }
+ * html_to_markdown_options_free(options);
+ * ```
+ */
+char *html_to_markdown_convert_with_options(const struct HtmlToMarkdownOptions *options,
+                                            const char *html);
+
+/**
+ * Free an options handle created by `html_to_markdown_options_new_from_json()`.
+ *
+ * # Safety
+ *
+ * - `options` must be a handle from `html_to_markdown_options_new_from_json()`, or NULL
+ *   (a no-op)
+ * - The handle must not be used after this call
+ */
+void html_to_markdown_options_free(struct HtmlToMarkdownOptions *options);
+
+/**
+ * Convert UTF-8 HTML bytes to Markdown, passing the output to a callback in chunks.
+ *
+ * Produces the same Markdown as `html_to_markdown_convert`, delivered as the conversion
+ * finishes each top-level block. Concatenating the chunks gives the complete output.
+ *
+ * # Arguments
+ *
+ * - `html`: Pointer to `len` bytes of UTF-8 HTML
+ * - `len`: Number of bytes at `html`
+ * - `chunk_cb`: Called with each chunk of Markdown, in order
+ * - `user_data`: Passed unchanged to `chunk_cb`
+ *
+ * # Returns
+ *
+ * - `true`: Every chunk was delivered
+ * - `false`: The conversion failed or `chunk_cb` returned `false`
+ *   (`html_to_markdown_error_code()` is then `HtmlToMarkdownErrorCode_Cancelled`); chunks
+ *   delivered before the failure are not taken back
+ *
+ * # Safety
+ *
+ * - `html` must point to `len` bytes of UTF-8 data
+ * - `chunk_cb` must be safe to call with `user_data` on the calling thread
+ *
+ * # Example (C)
+ *
+ * ```c
+ * bool write_chunk(void *user_data, const char *chunk, size_t len) {
+ *     return fwrite(chunk, 1, len, (FILE *)user_data) == len;
+ * }
+ *
+ * if (!html_to_markdown_convert_streaming(html, html_len, write_chunk, out)) {
+ *     fprintf(stderr, "Conversion failed: %s\n", html_to_markdown_last_error());
+ * }
+ * ```
+ */
+bool html_to_markdown_convert_streaming(const uint8_t *html,
+                                        size_t len,
+                                        HtmlToMarkdownChunkCallback chunk_cb,
+                                        void *user_data);
+
 /**
  * Create a new visitor instance from a callback table.
  *
@@ -649,7 +1982,7 @@ void html_to_markdown_visitor_free(HtmlToMarkdownVisitor visitor);
  */
 char *html_to_markdown_convert_with_visitor(const char *html,
                                             HtmlToMarkdownVisitor visitor,
-                                            uintptr_t *len_out);
+                                            size_t *len_out);
 
 /**
  * Convert UTF-8 HTML bytes to Markdown using a custom visitor.
@@ -695,9 +2028,9 @@ char *html_to_markdown_convert_with_visitor(const char *html,
  * ```
  */
 char *html_to_markdown_convert_bytes_with_visitor(const uint8_t *html,
-                                                  uintptr_t len,
+                                                  size_t len,
                                                   HtmlToMarkdownVisitor visitor,
-                                                  uintptr_t *len_out);
+                                                  size_t *len_out);
 
 /**
  * Create a `VisitResult` with Continue action.
@@ -814,4 +2147,8 @@ struct HtmlToMarkdownVisitResult html_to_markdown_visit_result_preserve_html(voi
  */
 struct HtmlToMarkdownVisitResult html_to_markdown_visit_result_error(char *message);
 
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
 #endif  /* HTML_TO_MARKDOWN_H */
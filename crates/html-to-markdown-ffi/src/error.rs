@@ -2,6 +2,13 @@
 //!
 //! This module provides thread-local error storage and utilities for capturing
 //! and reporting errors across the FFI boundary.
+//!
+//! Errors are kept per OS thread. Every exported function that can fail either records an
+//! error or clears the previous one on the thread it was called from, and the error accessors
+//! only ever read that thread's state, so a failure on one thread is never visible on another.
+//! Hosts that move work between threads (goroutines, .NET tasks) must read the error on the
+//! thread that made the failing call: in Go, with `runtime.LockOSThread()` held around the call
+//! and the error lookup.
 
 use std::cell::RefCell;
 use std::ffi::CString;
@@ -10,19 +17,103 @@ use std::ptr;
 
 use html_to_markdown_rs::ConversionError;
 
+/// The calling thread's last error. The message and code are always replaced together.
+struct LastError {
+    code: HtmlToMarkdownErrorCode,
+    message: Option<CString>,
+}
+
 thread_local! {
-    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+    static LAST_ERROR: RefCell<LastError> = const {
+        RefCell::new(LastError {
+            code: HtmlToMarkdownErrorCode::Ok,
+            message: None,
+        })
+    };
+}
+
+/// Category of the last error, returned by `html_to_markdown_error_code()`.
+///
+/// The values are stable: new categories are only ever added at the end.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtmlToMarkdownErrorCode {
+    /// The last call succeeded
+    Ok = 0,
+    /// A required pointer argument was NULL
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8
+    InvalidUtf8 = 2,
+    /// The input was rejected before conversion, e.g. because it looks like binary data
+    InvalidInput = 3,
+    /// The options or another configuration value were invalid
+    ConfigError = 4,
+    /// The HTML could not be parsed
+    ParseError = 5,
+    /// Sanitizing the HTML failed
+    SanitizationError = 6,
+    /// Reading or writing failed
+    IoError = 7,
+    /// A visitor callback stopped the conversion
+    VisitorError = 8,
+    /// The converter panicked; the panic was caught at the boundary
+    Panic = 9,
+    /// The result could not be handed back, e.g. because it contained a NUL byte
+    InvalidOutput = 10,
+    /// Any other failure
+    Other = 11,
+    /// A callback asked to stop the conversion
+    Cancelled = 12,
+}
+
+impl From<&ConversionError> for HtmlToMarkdownErrorCode {
+    fn from(err: &ConversionError) -> Self {
+        match err {
+            ConversionError::ParseError(_) => Self::ParseError,
+            ConversionError::SanitizationError(_) => Self::SanitizationError,
+            ConversionError::ConfigError(_) => Self::ConfigError,
+            ConversionError::IoError(_) => Self::IoError,
+            ConversionError::Panic(_) => Self::Panic,
+            ConversionError::InvalidInput(_) => Self::InvalidInput,
+            ConversionError::Visitor(_) => Self::VisitorError,
+            ConversionError::Other(_) => Self::Other,
+        }
+    }
 }
 
 /// Set the thread-local last error message.
 ///
 /// # Arguments
 ///
-/// * `message` - Optional error message. If `None`, clears the error.
+/// * `message` - Optional error message. If `None`, clears the error; otherwise the error code
+///   becomes `Other`.
 pub fn set_last_error(message: Option<String>) {
+    match message {
+        Some(message) => set_error(HtmlToMarkdownErrorCode::Other, message),
+        None => LAST_ERROR.with(|cell| {
+            *cell.borrow_mut() = LastError {
+                code: HtmlToMarkdownErrorCode::Ok,
+                message: None,
+            };
+        }),
+    }
+}
+
+/// Set the thread-local last error message together with its code.
+///
+/// # Arguments
+///
+/// * `code` - Category of the error
+/// * `message` - Error message; NUL bytes in it are replaced so it can be returned as a C string
+pub fn set_error(code: HtmlToMarkdownErrorCode, message: String) {
+    let message = CString::new(message)
+        .or_else(|err| CString::new(String::from_utf8_lossy(&err.into_vec()).replace('\0', "\u{fffd}")))
+        .unwrap_or_default();
     LAST_ERROR.with(|cell| {
-        let mut slot = cell.borrow_mut();
-        *slot = message.and_then(|msg| CString::new(msg).ok());
+        *cell.borrow_mut() = LastError {
+            code,
+            message: Some(message),
+        };
     });
 }
 
@@ -35,6 +126,7 @@ pub fn set_last_error(message: Option<String>) {
 pub fn last_error_ptr() -> *const c_char {
     LAST_ERROR.with(|cell| {
         cell.borrow()
+            .message
             .as_ref()
             .map_or(ptr::null(), |cstr| cstr.as_ptr().cast::<c_char>())
     })
@@ -46,11 +138,13 @@ pub fn last_error_ptr() -> *const c_char {
 ///
 /// * `err` - The conversion error to capture
 pub fn capture_error(err: ConversionError) {
-    set_last_error(Some(err.to_string()));
+    set_error(HtmlToMarkdownErrorCode::from(&err), err.to_string());
 }
 
 /// Get the last error message from a failed conversion.
 ///
+/// Only reports errors from calls made on the calling thread.
+///
 /// # Safety
 ///
 /// - Returns a pointer to a thread-local buffer; copy it immediately if needed
@@ -60,3 +154,67 @@ pub fn capture_error(err: ConversionError) {
 pub unsafe extern "C" fn html_to_markdown_last_error() -> *const c_char {
     last_error_ptr()
 }
+
+/// Get a copy of the calling thread's last error message.
+///
+/// Unlike `html_to_markdown_last_error()`, the returned string belongs to the caller and stays
+/// valid across later calls, which suits hosts that hand the message to another thread or
+/// keep it beyond the next conversion. The error itself is left in place.
+///
+/// # Returns
+///
+/// - Non-NULL: The message, to be freed with `html_to_markdown_free_string()`
+/// - NULL: The last call on this thread succeeded, or `html_to_markdown_clear_error()` was
+///   called since
+///
+/// # Safety
+///
+/// - The returned string must be freed with `html_to_markdown_free_string()`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_last_error_for_thread() -> *mut c_char {
+    LAST_ERROR.with(|cell| cell.borrow().message.clone().map_or(ptr::null_mut(), CString::into_raw))
+}
+
+/// Clear the calling thread's last error.
+///
+/// Afterwards `html_to_markdown_last_error()` returns NULL and `html_to_markdown_error_code()`
+/// returns `HtmlToMarkdownErrorCode_Ok` on this thread, until the next failing call. Other
+/// threads are not affected.
+///
+/// # Safety
+///
+/// Always safe to call; invalidates pointers previously returned by
+/// `html_to_markdown_last_error()` on this thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_clear_error() {
+    set_last_error(None);
+}
+
+/// Get the code of the last error from a failed call.
+///
+/// Lets callers branch on the kind of failure without matching on
+/// `html_to_markdown_last_error()` messages. Like the message, the code is kept per thread and
+/// reset to `Ok` by the next successful call.
+///
+/// # Safety
+///
+/// Always safe to call; marked `unsafe` for consistency with the rest of the API.
+///
+/// # Example (C)
+///
+/// ```c
+/// char *markdown = html_to_markdown_convert(html);
+/// if (markdown == NULL) {
+///     switch (html_to_markdown_error_code()) {
+///     case HtmlToMarkdownErrorCode_InvalidInput:
+///         skip_document(html);
+///         break;
+///     default:
+///         fprintf(stderr, "%s\n", html_to_markdown_last_error());
+///     }
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_error_code() -> HtmlToMarkdownErrorCode {
+    LAST_ERROR.with(|cell| cell.borrow().code)
+}
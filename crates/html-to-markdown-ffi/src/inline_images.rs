@@ -0,0 +1,287 @@
+//! Inline image extraction for C FFI.
+//!
+//! The extraction is returned as one allocation: the C-visible
+//! [`HtmlToMarkdownInlineImageExtraction`] sits at the start of a private struct that also owns
+//! every string and byte buffer it points into, so a single free call releases all of it.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+
+use html_to_markdown_rs::safety::guard_panic;
+use html_to_markdown_rs::{
+    HtmlExtraction, InlineImageConfig, convert_with_inline_images, inline_image_config_from_json,
+};
+
+use crate::error::{HtmlToMarkdownErrorCode, capture_error, set_error, set_last_error};
+use crate::options::HtmlToMarkdownOptions;
+use crate::{profiling, string_to_c_string};
+
+/// An image extracted from the HTML.
+///
+/// All pointers stay valid until the owning extraction is freed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct HtmlToMarkdownInlineImage {
+    /// Raw image bytes, encoded in their original format (PNG, JPEG, SVG markup, ...)
+    pub data: *const u8,
+    /// Number of bytes at `data`
+    pub data_len: usize,
+    /// Image format, e.g. "png", "jpeg" or "svg"
+    pub format: *const c_char,
+    /// Generated filename, or NULL
+    pub filename: *const c_char,
+    /// Alt text or title from the source element, or NULL
+    pub description: *const c_char,
+    /// Width in pixels, or 0 when the dimensions were not inferred
+    pub width: u32,
+    /// Height in pixels, or 0 when the dimensions were not inferred
+    pub height: u32,
+    /// Where the image came from: "img_data_uri", "svg_element" or "img_remote"
+    pub source: *const c_char,
+    /// Other attributes of the source element as a JSON object
+    pub attributes_json: *const c_char,
+}
+
+/// A non-fatal problem met while extracting an image.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct HtmlToMarkdownInlineImageWarning {
+    /// 1-based index of the image the warning is about
+    pub index: usize,
+    /// Description of the problem
+    pub message: *const c_char,
+}
+
+/// Markdown and the images extracted while producing it.
+///
+/// Returned by `html_to_markdown_convert_with_inline_images()` and released, together with
+/// everything it points to, by `html_to_markdown_inline_image_extraction_free()`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct HtmlToMarkdownInlineImageExtraction {
+    /// Converted Markdown, NULL-terminated
+    pub markdown: *const c_char,
+    /// Length of `markdown` in bytes, without the terminator
+    pub markdown_len: usize,
+    /// Array of `image_count` images, or NULL when there are none
+    pub images: *const HtmlToMarkdownInlineImage,
+    /// Number of entries in `images`
+    pub image_count: usize,
+    /// Array of `warning_count` warnings, or NULL when there are none
+    pub warnings: *const HtmlToMarkdownInlineImageWarning,
+    /// Number of entries in `warnings`
+    pub warning_count: usize,
+}
+
+/// Owner of an extraction's buffers. `view` must stay the first field: the pointer handed to C
+/// points at it and is cast back to this struct when freed.
+#[repr(C)]
+struct ExtractionStorage {
+    view: HtmlToMarkdownInlineImageExtraction,
+    strings: Vec<CString>,
+    data: Vec<Vec<u8>>,
+    images: Vec<HtmlToMarkdownInlineImage>,
+    warnings: Vec<HtmlToMarkdownInlineImageWarning>,
+}
+
+impl ExtractionStorage {
+    fn new(extraction: HtmlExtraction) -> Result<Box<Self>, String> {
+        let mut storage = Box::new(Self {
+            view: HtmlToMarkdownInlineImageExtraction {
+                markdown: ptr::null(),
+                markdown_len: extraction.markdown.len(),
+                images: ptr::null(),
+                image_count: 0,
+                warnings: ptr::null(),
+                warning_count: 0,
+            },
+            strings: Vec::new(),
+            data: Vec::new(),
+            images: Vec::with_capacity(extraction.inline_images.len()),
+            warnings: Vec::with_capacity(extraction.warnings.len()),
+        });
+
+        storage.view.markdown = storage.string(extraction.markdown, "markdown result")?;
+
+        for image in extraction.inline_images {
+            let (width, height) = image.dimensions.unwrap_or((0, 0));
+            let attributes_json = serde_json::to_string(&image.attributes)
+                .map_err(|err| format!("failed to serialize image attributes: {err}"))?;
+            let entry = HtmlToMarkdownInlineImage {
+                data: image.data.as_ptr(),
+                data_len: image.data.len(),
+                format: storage.string(image.format.to_string(), "image format")?,
+                filename: storage.optional_string(image.filename, "image filename")?,
+                description: storage.optional_string(image.description, "image description")?,
+                width,
+                height,
+                source: storage.string(image.source.to_string(), "image source")?,
+                attributes_json: storage.string(attributes_json, "image attributes")?,
+            };
+            storage.data.push(image.data);
+            storage.images.push(entry);
+        }
+
+        for warning in extraction.warnings {
+            let message = storage.string(warning.message, "image warning")?;
+            storage.warnings.push(HtmlToMarkdownInlineImageWarning {
+                index: warning.index,
+                message,
+            });
+        }
+
+        if !storage.images.is_empty() {
+            storage.view.images = storage.images.as_ptr();
+            storage.view.image_count = storage.images.len();
+        }
+        if !storage.warnings.is_empty() {
+            storage.view.warnings = storage.warnings.as_ptr();
+            storage.view.warning_count = storage.warnings.len();
+        }
+        Ok(storage)
+    }
+
+    fn string(&mut self, value: String, context: &str) -> Result<*const c_char, String> {
+        let c_string = string_to_c_string(value, context)?;
+        let ptr = c_string.as_ptr();
+        self.strings.push(c_string);
+        Ok(ptr)
+    }
+
+    fn optional_string(&mut self, value: Option<String>, context: &str) -> Result<*const c_char, String> {
+        value.map_or(Ok(ptr::null()), |value| self.string(value, context))
+    }
+}
+
+/// Convert HTML to Markdown and extract its inline images.
+///
+/// Collects `data:` URI images and inline `<svg>` elements, with their raw bytes, alongside
+/// the Markdown.
+///
+/// # Arguments
+///
+/// - `html`: NULL-terminated HTML string
+/// - `options`: Options handle from `html_to_markdown_options_new_from_json()`, or NULL for
+///   the default options
+/// - `image_config_json`: Extraction settings as a JSON object (`maxDecodedSizeBytes`,
+///   `filenamePrefix`, `captureSvg`, `inferDimensions`), or NULL for the defaults
+///
+/// # Returns
+///
+/// - Non-NULL: Extraction, to be released with `html_to_markdown_inline_image_extraction_free()`
+/// - NULL: Conversion failed; call `html_to_markdown_last_error()`
+///
+/// # Safety
+///
+/// - `html` must be a valid null-terminated C string
+/// - `options` must be NULL or a handle that has not been freed
+/// - `image_config_json` must be NULL or a valid null-terminated UTF-8 C string
+///
+/// # Example (C)
+///
+/// ```c
+/// HtmlToMarkdownInlineImageExtraction *result = html_to_markdown_convert_with_inline_images(
+///     "<img src=\"data:image/png;base64,iVBORw0KGgo=\" alt=\"Logo\">", NULL,
+///     "{\"inferDimensions\":true}");
+/// if (result != NULL) {
+///     for (size_t i = 0; i < result->image_count; i++) {
+///         const HtmlToMarkdownInlineImage *image = &result->images[i];
+///         write_file(image->filename, image->data, image->data_len);
+///     }
+///     html_to_markdown_inline_image_extraction_free(result);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_convert_with_inline_images(
+    html: *const c_char,
+    options: *const HtmlToMarkdownOptions,
+    image_config_json: *const c_char,
+) -> *mut HtmlToMarkdownInlineImageExtraction {
+    if html.is_null() {
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
+        return ptr::null_mut();
+    }
+
+    let html_str = if let Ok(s) = unsafe { CStr::from_ptr(html) }.to_str() {
+        s
+    } else {
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
+        return ptr::null_mut();
+    };
+
+    let image_cfg = if image_config_json.is_null() {
+        InlineImageConfig::from_update(Default::default())
+    } else {
+        let json = if let Ok(s) = unsafe { CStr::from_ptr(image_config_json) }.to_str() {
+            s
+        } else {
+            set_error(
+                HtmlToMarkdownErrorCode::InvalidUtf8,
+                "image config must be valid UTF-8".to_string(),
+            );
+            return ptr::null_mut();
+        };
+        match inline_image_config_from_json(json) {
+            Ok(config) => config,
+            Err(err) => {
+                capture_error(err);
+                return ptr::null_mut();
+            }
+        }
+    };
+
+    let options = if options.is_null() {
+        None
+    } else {
+        Some(unsafe { (*options).conversion_options() }.clone())
+    };
+
+    match guard_panic(|| {
+        profiling::maybe_profile(|| convert_with_inline_images(html_str, options.clone(), image_cfg.clone(), None))
+    }) {
+        Ok(extraction) => match ExtractionStorage::new(extraction) {
+            Ok(storage) => {
+                set_last_error(None);
+                Box::into_raw(storage).cast::<HtmlToMarkdownInlineImageExtraction>()
+            }
+            Err(err) => {
+                set_error(
+                    HtmlToMarkdownErrorCode::InvalidOutput,
+                    format!("failed to build inline image extraction: {err}"),
+                );
+                ptr::null_mut()
+            }
+        },
+        Err(err) => {
+            capture_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Free an extraction returned by `html_to_markdown_convert_with_inline_images()`.
+///
+/// Releases the Markdown, the image and warning arrays, and every string and byte buffer they
+/// point to.
+///
+/// # Safety
+///
+/// - `extraction` must come from `html_to_markdown_convert_with_inline_images()`, or be NULL
+///   (a no-op)
+/// - Nothing reached through `extraction` may be used after this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_inline_image_extraction_free(
+    extraction: *mut HtmlToMarkdownInlineImageExtraction,
+) {
+    if extraction.is_null() {
+        return;
+    }
+    drop(unsafe { Box::from_raw(extraction.cast::<ExtractionStorage>()) });
+}
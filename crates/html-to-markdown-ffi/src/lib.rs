@@ -17,22 +17,35 @@ use html_to_markdown_rs::safety::guard_panic;
 #[cfg(feature = "metadata")]
 use html_to_markdown_rs::{MetadataConfig, convert_with_metadata, metadata::DEFAULT_MAX_STRUCTURED_DATA_SIZE};
 mod error;
+mod inline_images;
+mod options;
 mod profiling;
+mod streaming;
 mod strings;
 pub mod visitor;
 
-pub use error::html_to_markdown_last_error;
-use error::{capture_error, set_last_error};
+pub use error::{
+    HtmlToMarkdownErrorCode, html_to_markdown_clear_error, html_to_markdown_error_code, html_to_markdown_last_error,
+    html_to_markdown_last_error_for_thread,
+};
+use error::{capture_error, set_error, set_last_error};
+pub use inline_images::{
+    HtmlToMarkdownInlineImage, HtmlToMarkdownInlineImageExtraction, HtmlToMarkdownInlineImageWarning,
+    html_to_markdown_convert_with_inline_images, html_to_markdown_inline_image_extraction_free,
+};
+pub use options::{
+    HtmlToMarkdownOptions, html_to_markdown_convert_with_options, html_to_markdown_options_free,
+    html_to_markdown_options_new_from_json,
+};
+pub use streaming::{HtmlToMarkdownChunkCallback, html_to_markdown_convert_streaming};
 
 #[allow(dead_code)]
-fn bytes_to_c_string(mut bytes: Vec<u8>, context: &str) -> Result<CString, String> {
+fn bytes_to_c_string(bytes: Vec<u8>, context: &str) -> Result<CString, String> {
     if bytes.contains(&0) {
         return Err(format!("{context} contained an interior null byte"));
     }
 
-    bytes.reserve(1);
-    bytes.push(0);
-
+    // `from_vec_unchecked` appends the terminator itself.
     Ok(unsafe { CString::from_vec_unchecked(bytes) })
 }
 
@@ -51,14 +64,17 @@ fn string_to_c_string(value: String, context: &str) -> Result<CString, String> {
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn html_to_markdown_profile_start(output: *const c_char, frequency: i32) -> bool {
     if output.is_null() {
-        set_last_error(Some("output path was null".to_string()));
+        set_error(HtmlToMarkdownErrorCode::NullPointer, "output path was null".to_string());
         return false;
     }
 
     let output_str = if let Ok(s) = unsafe { CStr::from_ptr(output) }.to_str() {
         s
     } else {
-        set_last_error(Some("output path must be valid UTF-8".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "output path must be valid UTF-8".to_string(),
+        );
         return false;
     };
 
@@ -95,6 +111,49 @@ pub unsafe extern "C" fn html_to_markdown_profile_stop() -> bool {
     }
 }
 
+/// Check conversion options given as a JSON object, in the camelCase form the other bindings accept.
+///
+/// Returns 1 when the options parse and are valid, 0 otherwise. Unknown fields count as errors
+/// unless the object sets `"strictOptions": false`. On failure `html_to_markdown_last_error`
+/// holds the parse error or every problem found, separated by `"; "`, with the same wording as
+/// the other bindings use.
+///
+/// # Safety
+///
+/// - `options_json` must be a valid, null-terminated UTF-8 C string for the duration of the call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_validate_options(options_json: *const c_char) -> bool {
+    if options_json.is_null() {
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "options pointer was null".to_string(),
+        );
+        return false;
+    }
+
+    let Ok(json) = (unsafe { CStr::from_ptr(options_json) }).to_str() else {
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "options must be valid UTF-8".to_string(),
+        );
+        return false;
+    };
+
+    match html_to_markdown_rs::ConversionOptionsUpdate::from_json_strict(json)
+        .map(html_to_markdown_rs::ConversionOptions::from)
+        .and_then(|options| options.validate())
+    {
+        Ok(()) => {
+            set_last_error(None);
+            true
+        }
+        Err(err) => {
+            capture_error(err);
+            false
+        }
+    }
+}
+
 /// Convert HTML to Markdown using default options.
 ///
 /// # Safety
@@ -116,14 +175,20 @@ pub unsafe extern "C" fn html_to_markdown_profile_stop() -> bool {
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn html_to_markdown_convert(html: *const c_char) -> *mut c_char {
     if html.is_null() {
-        set_last_error(Some("html pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     let html_str = if let Ok(s) = unsafe { CStr::from_ptr(html) }.to_str() {
         s
     } else {
-        set_last_error(Some("html must be valid UTF-8".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
         return ptr::null_mut();
     };
 
@@ -133,7 +198,10 @@ pub unsafe extern "C" fn html_to_markdown_convert(html: *const c_char) -> *mut c
             match string_to_c_string(markdown, "markdown result") {
                 Ok(c_string) => c_string.into_raw(),
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for markdown result: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for markdown result: {err}"),
+                    );
                     ptr::null_mut()
                 }
             }
@@ -156,19 +224,28 @@ pub unsafe extern "C" fn html_to_markdown_convert(html: *const c_char) -> *mut c
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn html_to_markdown_convert_with_len(html: *const c_char, len_out: *mut usize) -> *mut c_char {
     if html.is_null() {
-        set_last_error(Some("html pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     if len_out.is_null() {
-        set_last_error(Some("len_out pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "len_out pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     let html_str = if let Ok(s) = unsafe { CStr::from_ptr(html) }.to_str() {
         s
     } else {
-        set_last_error(Some("html must be valid UTF-8".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
         return ptr::null_mut();
     };
 
@@ -183,7 +260,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_len(html: *const c_char,
                     c_string.into_raw()
                 }
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for markdown result: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for markdown result: {err}"),
+                    );
                     ptr::null_mut()
                 }
             }
@@ -210,12 +290,18 @@ pub unsafe extern "C" fn html_to_markdown_convert_bytes_with_len(
     len_out: *mut usize,
 ) -> *mut c_char {
     if html.is_null() {
-        set_last_error(Some("html pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     if len_out.is_null() {
-        set_last_error(Some("len_out pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "len_out pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
@@ -223,7 +309,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_bytes_with_len(
     let html_str = if let Ok(s) = std::str::from_utf8(html_bytes) {
         s
     } else {
-        set_last_error(Some("html must be valid UTF-8".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
         return ptr::null_mut();
     };
 
@@ -238,7 +327,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_bytes_with_len(
                     c_string.into_raw()
                 }
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for markdown result: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for markdown result: {err}"),
+                    );
                     ptr::null_mut()
                 }
             }
@@ -272,6 +364,111 @@ pub unsafe extern "C" fn html_to_markdown_free_string(s: *mut c_char) {
     }
 }
 
+/// Convert an array of HTML documents to Markdown using default options.
+///
+/// Returns an array of `count` Markdown strings in input order, converting every document in
+/// one call to avoid per-document FFI overhead. Entries for documents that could not be
+/// converted are NULL, and `html_to_markdown_last_error` and `html_to_markdown_error_code`
+/// describe the first such failure.
+///
+/// # Safety
+///
+/// - `htmls` must point to `count` pointers, each NULL or a valid null-terminated C string
+/// - The returned array must be freed with `html_to_markdown_free_string_array` using the same `count`
+/// - Returns NULL if `htmls` is NULL
+///
+/// # Example (C)
+///
+/// ```c
+/// const char* docs[] = {"<h1>One</h1>", "<p>Two</p>"};
+/// char** results = html_to_markdown_convert_batch(docs, 2);
+/// if (results != NULL) {
+///     for (size_t i = 0; i < 2; i++) {
+///         printf("%s\n", results[i] ? results[i] : html_to_markdown_last_error());
+///     }
+///     html_to_markdown_free_string_array(results, 2);
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_convert_batch(htmls: *const *const c_char, count: usize) -> *mut *mut c_char {
+    if htmls.is_null() {
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "htmls pointer was null".to_string(),
+        );
+        return ptr::null_mut();
+    }
+
+    let pointers = unsafe { slice::from_raw_parts(htmls, count) };
+    let mut input_errors: Vec<Option<(HtmlToMarkdownErrorCode, &str)>> = vec![None; count];
+    let documents: Vec<&str> = pointers
+        .iter()
+        .zip(input_errors.iter_mut())
+        .map(|(&html, input_error)| {
+            if html.is_null() {
+                *input_error = Some((HtmlToMarkdownErrorCode::NullPointer, "html pointer was null"));
+                return "";
+            }
+            unsafe { CStr::from_ptr(html) }.to_str().unwrap_or_else(|_| {
+                *input_error = Some((HtmlToMarkdownErrorCode::InvalidUtf8, "html must be valid UTF-8"));
+                ""
+            })
+        })
+        .collect();
+
+    let mut first_error: Option<(HtmlToMarkdownErrorCode, String)> = None;
+    let outputs: Vec<*mut c_char> = html_to_markdown_rs::convert_many(&documents, None)
+        .into_iter()
+        .zip(input_errors)
+        .enumerate()
+        .map(|(index, (result, input_error))| {
+            let converted = match input_error {
+                Some((code, message)) => Err((code, message.to_string())),
+                None => result
+                    .map_err(|err| (HtmlToMarkdownErrorCode::from(&err), err.to_string()))
+                    .and_then(|markdown| {
+                        string_to_c_string(markdown, "markdown result")
+                            .map_err(|err| (HtmlToMarkdownErrorCode::InvalidOutput, err))
+                    }),
+            };
+            match converted {
+                Ok(c_string) => c_string.into_raw(),
+                Err((code, err)) => {
+                    first_error.get_or_insert_with(|| (code, format!("document {index}: {err}")));
+                    ptr::null_mut()
+                }
+            }
+        })
+        .collect();
+
+    match first_error {
+        Some((code, message)) => set_error(code, message),
+        None => set_last_error(None),
+    }
+    Box::into_raw(outputs.into_boxed_slice()).cast::<*mut c_char>()
+}
+
+/// Free an array returned by `html_to_markdown_convert_batch`, including every string in it.
+///
+/// # Safety
+///
+/// - `strings` must be an array previously returned by `html_to_markdown_convert_batch`, or NULL
+/// - `count` must match the `count` passed to `html_to_markdown_convert_batch`
+/// - Neither the array nor its strings may be used after this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_free_string_array(strings: *mut *mut c_char, count: usize) {
+    if strings.is_null() {
+        return;
+    }
+
+    let array = unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(strings, count)) };
+    for &s in &array {
+        if !s.is_null() {
+            unsafe { drop(CString::from_raw(s)) };
+        }
+    }
+}
+
 /// Get the library version string.
 ///
 /// # Safety
@@ -312,19 +509,28 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata(
     metadata_json_out: *mut *mut c_char,
 ) -> *mut c_char {
     if html.is_null() {
-        set_last_error(Some("html pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     if metadata_json_out.is_null() {
-        set_last_error(Some("metadata_json_out pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "metadata_json_out pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     let html_str = if let Ok(s) = unsafe { CStr::from_ptr(html) }.to_str() {
         s
     } else {
-        set_last_error(Some("html must be valid UTF-8".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
         return ptr::null_mut();
     };
 
@@ -334,6 +540,8 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata(
         extract_links: true,
         extract_images: true,
         extract_structured_data: true,
+        extract_resources: false,
+        extract_source_map: false,
         max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
     };
 
@@ -345,7 +553,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata(
             let metadata_json = match serde_json::to_vec(&metadata) {
                 Ok(json) => json,
                 Err(e) => {
-                    set_last_error(Some(format!("failed to serialize metadata to JSON: {e}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to serialize metadata to JSON: {e}"),
+                    );
                     return ptr::null_mut();
                 }
             };
@@ -353,7 +564,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata(
             let metadata_c_string = match bytes_to_c_string(metadata_json, "metadata JSON") {
                 Ok(s) => s,
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for metadata JSON: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for metadata JSON: {err}"),
+                    );
                     return ptr::null_mut();
                 }
             };
@@ -365,7 +579,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata(
             match string_to_c_string(markdown, "markdown result") {
                 Ok(c_string) => c_string.into_raw(),
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for markdown result: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for markdown result: {err}"),
+                    );
                     unsafe {
                         if !metadata_json_out.is_null() && !(*metadata_json_out).is_null() {
                             html_to_markdown_free_string(*metadata_json_out);
@@ -402,24 +619,36 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_with_len(
     metadata_len_out: *mut usize,
 ) -> *mut c_char {
     if html.is_null() {
-        set_last_error(Some("html pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     if metadata_json_out.is_null() {
-        set_last_error(Some("metadata_json_out pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "metadata_json_out pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     if markdown_len_out.is_null() || metadata_len_out.is_null() {
-        set_last_error(Some("length output pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "length output pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     let html_str = if let Ok(s) = unsafe { CStr::from_ptr(html) }.to_str() {
         s
     } else {
-        set_last_error(Some("html must be valid UTF-8".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
         return ptr::null_mut();
     };
 
@@ -429,6 +658,8 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_with_len(
         extract_links: true,
         extract_images: true,
         extract_structured_data: true,
+        extract_resources: false,
+        extract_source_map: false,
         max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
     };
 
@@ -440,7 +671,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_with_len(
             let metadata_json = match serde_json::to_vec(&metadata) {
                 Ok(json) => json,
                 Err(e) => {
-                    set_last_error(Some(format!("failed to serialize metadata to JSON: {e}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to serialize metadata to JSON: {e}"),
+                    );
                     return ptr::null_mut();
                 }
             };
@@ -448,7 +682,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_with_len(
             let metadata_c_string = match bytes_to_c_string(metadata_json, "metadata JSON") {
                 Ok(s) => s,
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for metadata JSON: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for metadata JSON: {err}"),
+                    );
                     return ptr::null_mut();
                 }
             };
@@ -466,7 +703,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_with_len(
                     c_string.into_raw()
                 }
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for markdown result: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for markdown result: {err}"),
+                    );
                     unsafe {
                         if !metadata_json_out.is_null() && !(*metadata_json_out).is_null() {
                             html_to_markdown_free_string(*metadata_json_out);
@@ -507,17 +747,26 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_bytes_with_len(
     metadata_len_out: *mut usize,
 ) -> *mut c_char {
     if html.is_null() {
-        set_last_error(Some("html pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     if metadata_json_out.is_null() {
-        set_last_error(Some("metadata_json_out pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "metadata_json_out pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     if markdown_len_out.is_null() || metadata_len_out.is_null() {
-        set_last_error(Some("length output pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "length output pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
@@ -525,7 +774,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_bytes_with_len(
     let html_str = if let Ok(s) = std::str::from_utf8(html_bytes) {
         s
     } else {
-        set_last_error(Some("html must be valid UTF-8".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
         return ptr::null_mut();
     };
 
@@ -535,6 +787,8 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_bytes_with_len(
         extract_links: true,
         extract_images: true,
         extract_structured_data: true,
+        extract_resources: false,
+        extract_source_map: false,
         max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
     };
 
@@ -546,7 +800,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_bytes_with_len(
             let metadata_json = match serde_json::to_vec(&metadata) {
                 Ok(json) => json,
                 Err(e) => {
-                    set_last_error(Some(format!("failed to serialize metadata to JSON: {e}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to serialize metadata to JSON: {e}"),
+                    );
                     return ptr::null_mut();
                 }
             };
@@ -554,7 +811,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_bytes_with_len(
             let metadata_c_string = match bytes_to_c_string(metadata_json, "metadata JSON") {
                 Ok(s) => s,
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for metadata JSON: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for metadata JSON: {err}"),
+                    );
                     return ptr::null_mut();
                 }
             };
@@ -572,7 +832,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_bytes_with_len(
                     c_string.into_raw()
                 }
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for markdown result: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for markdown result: {err}"),
+                    );
                     unsafe {
                         if !metadata_json_out.is_null() && !(*metadata_json_out).is_null() {
                             html_to_markdown_free_string(*metadata_json_out);
@@ -651,6 +914,114 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_error_codes() {
+        unsafe {
+            let _ = html_to_markdown_convert(ptr::null());
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::NullPointer);
+
+            let invalid = [0xff_u8, 0];
+            let _ = html_to_markdown_convert(invalid.as_ptr().cast());
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::InvalidUtf8);
+
+            let binary = CString::new("%PDF-1.7 binary").unwrap();
+            let _ = html_to_markdown_convert(binary.as_ptr());
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::InvalidInput);
+
+            let json = CString::new(r#"{"wrap":true,"wrapWidth":0}"#).unwrap();
+            assert!(!html_to_markdown_validate_options(json.as_ptr()));
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::ConfigError);
+
+            let html = CString::new("<p>ok</p>").unwrap();
+            let result = html_to_markdown_convert(html.as_ptr());
+            html_to_markdown_free_string(result);
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::Ok);
+        }
+    }
+
+    unsafe extern "C" fn collect_chunk(user_data: *mut std::ffi::c_void, chunk: *const c_char, len: usize) -> bool {
+        let chunks = unsafe { &mut *user_data.cast::<Vec<String>>() };
+        let bytes = unsafe { slice::from_raw_parts(chunk.cast::<u8>(), len) };
+        chunks.push(std::str::from_utf8(bytes).unwrap().to_string());
+        true
+    }
+
+    unsafe extern "C" fn refuse_chunk(_: *mut std::ffi::c_void, _: *const c_char, _: usize) -> bool {
+        false
+    }
+
+    #[test]
+    fn test_convert_streaming_delivers_chunks() {
+        unsafe {
+            let html = (0..20).fold(String::new(), |html, i| html + &format!("<p>paragraph {i} é</p>"));
+            let mut chunks: Vec<String> = Vec::new();
+            let ok = html_to_markdown_convert_streaming(
+                html.as_ptr(),
+                html.len(),
+                Some(collect_chunk),
+                (&raw mut chunks).cast(),
+            );
+            assert!(ok);
+            assert!(chunks.len() > 1);
+            assert_eq!(chunks.concat(), convert(&html, None).unwrap());
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::Ok);
+        }
+    }
+
+    #[test]
+    fn test_convert_streaming_can_be_cancelled() {
+        unsafe {
+            let html = "<p>a</p><p>b</p>";
+            let ok = html_to_markdown_convert_streaming(html.as_ptr(), html.len(), Some(refuse_chunk), ptr::null_mut());
+            assert!(!ok);
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::Cancelled);
+
+            assert!(!html_to_markdown_convert_streaming(
+                html.as_ptr(),
+                html.len(),
+                None,
+                ptr::null_mut()
+            ));
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::NullPointer);
+        }
+    }
+
+    #[test]
+    fn test_errors_stay_on_their_thread() {
+        unsafe {
+            html_to_markdown_clear_error();
+            std::thread::spawn(|| {
+                let _ = html_to_markdown_convert(ptr::null());
+                assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::NullPointer);
+
+                let message = html_to_markdown_last_error_for_thread();
+                assert_eq!(CStr::from_ptr(message).to_str().unwrap(), "html pointer was null");
+                html_to_markdown_clear_error();
+                assert!(html_to_markdown_last_error().is_null());
+                assert_eq!(CStr::from_ptr(message).to_str().unwrap(), "html pointer was null");
+                html_to_markdown_free_string(message);
+            })
+            .join()
+            .unwrap();
+
+            assert!(html_to_markdown_last_error().is_null());
+            assert!(html_to_markdown_last_error_for_thread().is_null());
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::Ok);
+        }
+    }
+
+    #[test]
+    fn test_batch_reports_first_error_code() {
+        unsafe {
+            let html = CString::new("<p>ok</p>").unwrap();
+            let invalid = [0xff_u8, 0];
+            let htmls = [html.as_ptr(), invalid.as_ptr().cast(), ptr::null()];
+            let results = html_to_markdown_convert_batch(htmls.as_ptr(), htmls.len());
+            assert_eq!(html_to_markdown_error_code(), HtmlToMarkdownErrorCode::InvalidUtf8);
+            html_to_markdown_free_string_array(results, htmls.len());
+        }
+    }
+
     #[cfg(feature = "metadata")]
     #[test]
     fn test_convert_with_metadata_basic() {
@@ -724,6 +1095,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_options_handle_is_reused() {
+        unsafe {
+            let json = CString::new(r#"{"headingStyle":"atx","stripTags":["span"]}"#).unwrap();
+            let options = html_to_markdown_options_new_from_json(json.as_ptr());
+            assert!(!options.is_null());
+
+            for _ in 0..2 {
+                let html = CString::new("<h1>Title</h1><p><span>kept</span> text</p>").unwrap();
+                let result = html_to_markdown_convert_with_options(options, html.as_ptr());
+                assert!(!result.is_null());
+                assert_eq!(CStr::from_ptr(result).to_str().unwrap(), "# Title\n\nkept text\n");
+                html_to_markdown_free_string(result);
+            }
+
+            html_to_markdown_options_free(options);
+            html_to_markdown_options_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_convert_with_inline_images() {
+        unsafe {
+            let html = CString::new(
+                r#"<p><img src="data:image/png;base64,iVBORw0KGgo=" alt="Logo" data-id="brand"></p><svg width="4" height="4"><rect/></svg>"#,
+            )
+            .unwrap();
+            let config = CString::new(r#"{"filenamePrefix":"asset"}"#).unwrap();
+            let result = html_to_markdown_convert_with_inline_images(html.as_ptr(), ptr::null(), config.as_ptr());
+            assert!(!result.is_null());
+
+            let extraction = &*result;
+            let markdown = CStr::from_ptr(extraction.markdown).to_str().unwrap();
+            assert_eq!(markdown.len(), extraction.markdown_len);
+            assert!(markdown.contains("![Logo]"), "{markdown}");
+            assert_eq!(extraction.image_count, 2);
+
+            let images = slice::from_raw_parts(extraction.images, extraction.image_count);
+            let png = &images[0];
+            assert_eq!(
+                slice::from_raw_parts(png.data, png.data_len),
+                b"\x89PNG\r\n\x1a\n".as_slice()
+            );
+            assert_eq!(CStr::from_ptr(png.format).to_str().unwrap(), "png");
+            assert_eq!(CStr::from_ptr(png.source).to_str().unwrap(), "img_data_uri");
+            assert_eq!(CStr::from_ptr(png.description).to_str().unwrap(), "Logo");
+            assert!(CStr::from_ptr(png.filename).to_str().unwrap().starts_with("asset"));
+            let attributes = CStr::from_ptr(png.attributes_json).to_str().unwrap();
+            assert!(attributes.contains(r#""data-id":"brand""#), "{attributes}");
+            assert_eq!((png.width, png.height), (0, 0));
+
+            let svg = &images[1];
+            assert_eq!(CStr::from_ptr(svg.source).to_str().unwrap(), "svg_element");
+            assert!(svg.description.is_null());
+
+            html_to_markdown_inline_image_extraction_free(result);
+            html_to_markdown_inline_image_extraction_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_convert_with_inline_images_invalid_config() {
+        unsafe {
+            let html = CString::new("<p>x</p>").unwrap();
+            let config = CString::new(r#"{"maxDecodedSizeBytes":0}"#).unwrap();
+            let result = html_to_markdown_convert_with_inline_images(html.as_ptr(), ptr::null(), config.as_ptr());
+            assert!(result.is_null());
+            let msg = CStr::from_ptr(html_to_markdown_last_error()).to_str().unwrap();
+            assert!(msg.contains("max_decoded_size_bytes"), "{msg}");
+        }
+    }
+
+    #[test]
+    fn test_options_handle_errors() {
+        unsafe {
+            let json = CString::new(r#"{"wrap":true,"wrapWidth":0}"#).unwrap();
+            assert!(html_to_markdown_options_new_from_json(json.as_ptr()).is_null());
+            let msg = CStr::from_ptr(html_to_markdown_last_error()).to_str().unwrap();
+            assert!(msg.contains("wrap_width"), "{msg}");
+
+            let html = CString::new("<p>x</p>").unwrap();
+            assert!(html_to_markdown_convert_with_options(ptr::null(), html.as_ptr()).is_null());
+            let msg = CStr::from_ptr(html_to_markdown_last_error()).to_str().unwrap();
+            assert_eq!(msg, "options handle was null");
+        }
+    }
+
     #[cfg(feature = "metadata")]
     #[test]
     fn test_convert_with_metadata_null_html() {
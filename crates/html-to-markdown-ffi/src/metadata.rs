@@ -68,6 +68,8 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata(
         extract_links: true,
         extract_images: true,
         extract_structured_data: true,
+        extract_resources: false,
+        extract_source_map: false,
         max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
     };
 
@@ -162,6 +164,8 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_with_len(
         extract_links: true,
         extract_images: true,
         extract_structured_data: true,
+        extract_resources: false,
+        extract_source_map: false,
         max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
     };
 
@@ -267,6 +271,8 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_metadata_bytes_with_len(
         extract_links: true,
         extract_images: true,
         extract_structured_data: true,
+        extract_resources: false,
+        extract_source_map: false,
         max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
     };
 
@@ -0,0 +1,177 @@
+//! Reusable conversion options for C FFI.
+//!
+//! An options handle is parsed and validated once from JSON, then passed to every conversion
+//! that should use it, so callers do not re-send their configuration with each document.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr;
+
+use html_to_markdown_rs::safety::guard_panic;
+use html_to_markdown_rs::{ConversionOptions, ConversionOptionsUpdate, convert_with_options};
+
+use crate::error::{HtmlToMarkdownErrorCode, capture_error, set_error, set_last_error};
+use crate::{profiling, string_to_c_string};
+
+/// Opaque conversion options created by `html_to_markdown_options_new_from_json`.
+pub struct HtmlToMarkdownOptions {
+    options: ConversionOptions,
+}
+
+impl HtmlToMarkdownOptions {
+    pub(crate) const fn conversion_options(&self) -> &ConversionOptions {
+        &self.options
+    }
+}
+
+/// Create an options handle from a JSON object, in the camelCase form the other bindings accept.
+///
+/// Fields left out keep their defaults. Unknown fields are rejected unless the object sets
+/// `"strictOptions": false`, and the options are validated the same way as by
+/// `html_to_markdown_validate_options`.
+///
+/// # Returns
+///
+/// - Non-NULL: Options handle, to be released with `html_to_markdown_options_free()`
+/// - NULL: The JSON did not parse or held invalid values; call `html_to_markdown_last_error()`
+///
+/// # Safety
+///
+/// - `options_json` must be a valid, null-terminated UTF-8 C string for the duration of the call
+/// - The handle may be used from any thread, but not from two threads while one frees it
+///
+/// # Example (C)
+///
+/// ```c
+/// HtmlToMarkdownOptions *options =
+///     html_to_markdown_options_new_from_json("{\"headingStyle\":\"atx\",\"stripTags\":[\"span\"]}");
+/// if (options == NULL) {
+///     fprintf(stderr, "Invalid options: %s\n", html_to_markdown_last_error());
+///     return 1;
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_options_new_from_json(
+    options_json: *const c_char,
+) -> *mut HtmlToMarkdownOptions {
+    if options_json.is_null() {
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "options pointer was null".to_string(),
+        );
+        return ptr::null_mut();
+    }
+
+    let json = if let Ok(s) = unsafe { CStr::from_ptr(options_json) }.to_str() {
+        s
+    } else {
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "options must be valid UTF-8".to_string(),
+        );
+        return ptr::null_mut();
+    };
+
+    match ConversionOptionsUpdate::from_json_strict(json)
+        .map(ConversionOptions::from)
+        .and_then(|options| options.validate().map(|()| options))
+    {
+        Ok(options) => {
+            set_last_error(None);
+            Box::into_raw(Box::new(HtmlToMarkdownOptions { options }))
+        }
+        Err(err) => {
+            capture_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Convert HTML to Markdown with an options handle.
+///
+/// The handle is only read, so it can be reused for any number of conversions.
+///
+/// # Safety
+///
+/// - `options` must be a handle from `html_to_markdown_options_new_from_json()` that has not
+///   been freed
+/// - `html` must be a valid null-terminated C string
+/// - The returned string must be freed with `html_to_markdown_free_string`
+/// - Returns NULL on error
+///
+/// # Example (C)
+///
+/// ```c
+/// char *markdown = html_to_markdown_convert_with_options(options, "<h1>Hello</h1>");
+/// if (markdown != NULL) {
+///     printf("%s\n", markdown);
+///     html_to_markdown_free_string(markdown);
+/// }
+/// html_to_markdown_options_free(options);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_convert_with_options(
+    options: *const HtmlToMarkdownOptions,
+    html: *const c_char,
+) -> *mut c_char {
+    if options.is_null() {
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "options handle was null".to_string(),
+        );
+        return ptr::null_mut();
+    }
+    if html.is_null() {
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
+        return ptr::null_mut();
+    }
+
+    let html_str = if let Ok(s) = unsafe { CStr::from_ptr(html) }.to_str() {
+        s
+    } else {
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
+        return ptr::null_mut();
+    };
+    let options = unsafe { &(*options).options };
+
+    match guard_panic(|| profiling::maybe_profile(|| convert_with_options(html_str, options))) {
+        Ok(markdown) => {
+            set_last_error(None);
+            match string_to_c_string(markdown, "markdown result") {
+                Ok(c_string) => c_string.into_raw(),
+                Err(err) => {
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for markdown result: {err}"),
+                    );
+                    ptr::null_mut()
+                }
+            }
+        }
+        Err(err) => {
+            capture_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Free an options handle created by `html_to_markdown_options_new_from_json()`.
+///
+/// # Safety
+///
+/// - `options` must be a handle from `html_to_markdown_options_new_from_json()`, or NULL
+///   (a no-op)
+/// - The handle must not be used after this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_options_free(options: *mut HtmlToMarkdownOptions) {
+    if options.is_null() {
+        return;
+    }
+    drop(unsafe { Box::from_raw(options) });
+}
@@ -9,6 +9,7 @@ mod enabled {
 
     const ENV_OUTPUT: &str = "HTML_TO_MARKDOWN_PROFILE_OUTPUT";
     const ENV_FREQUENCY: &str = "HTML_TO_MARKDOWN_PROFILE_FREQUENCY";
+    const ENV_FORMAT: &str = "HTML_TO_MARKDOWN_PROFILE_FORMAT";
     const ENV_ONCE: &str = "HTML_TO_MARKDOWN_PROFILE_ONCE";
     const ENV_REPEAT: &str = "HTML_TO_MARKDOWN_PROFILE_REPEAT";
 
@@ -20,6 +21,9 @@ mod enabled {
         profile_once: bool,
         repeat: usize,
         frequency: i32,
+        /// `HTML_TO_MARKDOWN_PROFILE_FORMAT=trace`: write a Chrome trace of conversion phases and
+        /// top-level elements instead of a flamegraph.
+        trace: bool,
     }
 
     fn env_profile_config() -> &'static EnvProfileConfig {
@@ -46,11 +50,14 @@ mod enabled {
                 .and_then(|value| value.parse::<i32>().ok())
                 .unwrap_or(1000);
 
+            let trace = std::env::var(ENV_FORMAT).is_ok_and(|value| value.trim().eq_ignore_ascii_case("trace"));
+
             EnvProfileConfig {
                 output,
                 profile_once,
                 repeat,
                 frequency,
+                trace,
             }
         })
     }
@@ -58,6 +65,7 @@ mod enabled {
     struct ProfileState {
         guard: Option<pprof::ProfilerGuard<'static>>,
         output: Option<PathBuf>,
+        tracing: bool,
     }
 
     fn state() -> &'static Mutex<ProfileState> {
@@ -66,6 +74,7 @@ mod enabled {
             Mutex::new(ProfileState {
                 guard: None,
                 output: None,
+                tracing: false,
             })
         })
     }
@@ -75,33 +84,46 @@ mod enabled {
             .lock()
             .map_err(|_| ConversionError::Other("profiling state lock poisoned".to_string()))?;
 
-        if state.guard.is_some() {
+        if state.guard.is_some() || state.tracing {
             return Err(ConversionError::Other("profiling already active".to_string()));
         }
 
-        let guard = pprof::ProfilerGuardBuilder::default()
-            .frequency(frequency)
-            .blocklist(&["libc", "libpthread", "libgcc", "libm"])
-            .build()
-            .map_err(|err| ConversionError::Other(format!("Profiling init failed: {err}")))?;
-
-        state.guard = Some(guard);
+        if env_profile_config().trace {
+            html_to_markdown_rs::trace::start()?;
+            state.tracing = true;
+        } else {
+            let guard = pprof::ProfilerGuardBuilder::default()
+                .frequency(frequency)
+                .blocklist(&["libc", "libpthread", "libgcc", "libm"])
+                .build()
+                .map_err(|err| ConversionError::Other(format!("Profiling init failed: {err}")))?;
+            state.guard = Some(guard);
+        }
         state.output = Some(output_path);
         PROFILE_ACTIVE.store(true, Ordering::Release);
         Ok(())
     }
 
     pub fn stop() -> Result<()> {
-        let (guard, output) = {
+        let (guard, tracing, output) = {
             let mut state = state()
                 .lock()
                 .map_err(|_| ConversionError::Other("profiling state lock poisoned".to_string()))?;
             let guard = state.guard.take();
+            let tracing = std::mem::take(&mut state.tracing);
             let output = state.output.take();
-            (guard, output)
+            (guard, tracing, output)
         };
         PROFILE_ACTIVE.store(false, Ordering::Release);
 
+        if tracing {
+            let trace = html_to_markdown_rs::trace::stop()?;
+            let Some(output_path) = output else {
+                return Err(ConversionError::Other("profiling output path missing".to_string()));
+            };
+            return write_trace(&trace, &output_path);
+        }
+
         let Some(guard) = guard else {
             return Err(ConversionError::Other("profiling not active".to_string()));
         };
@@ -126,6 +148,16 @@ mod enabled {
         Ok(())
     }
 
+    fn write_trace(trace: &html_to_markdown_rs::trace::Trace, output_path: &std::path::Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            std::fs::create_dir_all(parent).map_err(ConversionError::IoError)?;
+        }
+        let file = std::fs::File::create(output_path).map_err(ConversionError::IoError)?;
+        trace
+            .write_chrome_json(std::io::BufWriter::new(file))
+            .map_err(ConversionError::IoError)
+    }
+
     pub fn maybe_profile<T, F>(mut f: F) -> Result<T>
     where
         F: FnMut() -> Result<T>,
@@ -152,6 +184,22 @@ mod enabled {
         PROFILE_ACTIVE.store(true, Ordering::Release);
         let _active = ActiveGuard;
 
+        if config.trace {
+            html_to_markdown_rs::trace::start()?;
+            let mut result = f();
+            for _ in 1..config.repeat {
+                if result.is_err() {
+                    break;
+                }
+                result = f();
+            }
+            let trace = html_to_markdown_rs::trace::stop()?;
+            if result.is_ok() {
+                write_trace(&trace, output_path)?;
+            }
+            return result;
+        }
+
         let guard = pprof::ProfilerGuardBuilder::default()
             .frequency(config.frequency)
             .blocklist(&["libc", "libpthread", "libgcc", "libm"])
@@ -0,0 +1,154 @@
+//! Streaming conversion for C FFI.
+//!
+//! Markdown is handed to a callback a few top-level blocks at a time, so hosts converting very
+//! large documents never hold the whole result in one allocation on either side of the boundary.
+
+use std::ffi::c_void;
+use std::fmt;
+use std::os::raw::c_char;
+use std::panic::AssertUnwindSafe;
+use std::slice;
+
+use html_to_markdown_rs::convert_to_fmt;
+use html_to_markdown_rs::safety::guard_panic;
+
+use crate::error::{HtmlToMarkdownErrorCode, capture_error, set_error, set_last_error};
+use crate::profiling;
+
+/// Callback receiving one chunk of Markdown from `html_to_markdown_convert_streaming()`.
+///
+/// # Parameters
+///
+/// - `user_data`: The pointer passed to `html_to_markdown_convert_streaming()`
+/// - `chunk`: `len` bytes of UTF-8 Markdown; not NULL-terminated, and never split inside a
+///   character
+/// - `len`: Number of bytes at `chunk`
+///
+/// # Returns
+///
+/// `true` to continue, `false` to stop the conversion.
+///
+/// # Safety
+///
+/// - `chunk` is valid only during the callback; copy it if needed
+pub type HtmlToMarkdownChunkCallback =
+    Option<unsafe extern "C" fn(user_data: *mut c_void, chunk: *const c_char, len: usize) -> bool>;
+
+/// Forwards every chunk to the C callback and remembers whether it asked to stop.
+struct CallbackWriter {
+    callback: unsafe extern "C" fn(*mut c_void, *const c_char, usize) -> bool,
+    user_data: *mut c_void,
+    cancelled: bool,
+}
+
+impl fmt::Write for CallbackWriter {
+    fn write_str(&mut self, chunk: &str) -> fmt::Result {
+        if chunk.is_empty() {
+            return Ok(());
+        }
+        if unsafe { (self.callback)(self.user_data, chunk.as_ptr().cast::<c_char>(), chunk.len()) } {
+            Ok(())
+        } else {
+            self.cancelled = true;
+            Err(fmt::Error)
+        }
+    }
+}
+
+/// Convert UTF-8 HTML bytes to Markdown, passing the output to a callback in chunks.
+///
+/// Produces the same Markdown as `html_to_markdown_convert`, delivered as the conversion
+/// finishes each top-level block. Concatenating the chunks gives the complete output.
+///
+/// # Arguments
+///
+/// - `html`: Pointer to `len` bytes of UTF-8 HTML
+/// - `len`: Number of bytes at `html`
+/// - `chunk_cb`: Called with each chunk of Markdown, in order
+/// - `user_data`: Passed unchanged to `chunk_cb`
+///
+/// # Returns
+///
+/// - `true`: Every chunk was delivered
+/// - `false`: The conversion failed or `chunk_cb` returned `false`
+///   (`html_to_markdown_error_code()` is then `HtmlToMarkdownErrorCode_Cancelled`); chunks
+///   delivered before the failure are not taken back
+///
+/// # Safety
+///
+/// - `html` must point to `len` bytes of UTF-8 data
+/// - `chunk_cb` must be safe to call with `user_data` on the calling thread
+///
+/// # Example (C)
+///
+/// ```c
+/// bool write_chunk(void *user_data, const char *chunk, size_t len) {
+///     return fwrite(chunk, 1, len, (FILE *)user_data) == len;
+/// }
+///
+/// if (!html_to_markdown_convert_streaming(html, html_len, write_chunk, out)) {
+///     fprintf(stderr, "Conversion failed: %s\n", html_to_markdown_last_error());
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_to_markdown_convert_streaming(
+    html: *const u8,
+    len: usize,
+    chunk_cb: HtmlToMarkdownChunkCallback,
+    user_data: *mut c_void,
+) -> bool {
+    if html.is_null() {
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
+        return false;
+    }
+
+    let Some(callback) = chunk_cb else {
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "chunk callback was null".to_string(),
+        );
+        return false;
+    };
+
+    let html_bytes = unsafe { slice::from_raw_parts(html, len) };
+    let html_str = if let Ok(s) = std::str::from_utf8(html_bytes) {
+        s
+    } else {
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
+        return false;
+    };
+
+    let mut writer = CallbackWriter {
+        callback,
+        user_data,
+        cancelled: false,
+    };
+
+    // The writer is only inspected for `cancelled` afterwards, which a panic cannot leave half-set.
+    let result = guard_panic(AssertUnwindSafe(|| {
+        profiling::maybe_profile(|| convert_to_fmt(html_str, None, &mut writer))
+    }));
+    match result {
+        Ok(()) => {
+            set_last_error(None);
+            true
+        }
+        Err(_) if writer.cancelled => {
+            set_error(
+                HtmlToMarkdownErrorCode::Cancelled,
+                "chunk callback stopped the conversion".to_string(),
+            );
+            false
+        }
+        Err(err) => {
+            capture_error(err);
+            false
+        }
+    }
+}
@@ -34,6 +34,66 @@ fn test_null_html() {
     }
 }
 
+#[test]
+fn test_validate_options() {
+    unsafe {
+        let valid = CString::new(r#"{"headingStyle":"atx","bullets":"-"}"#).unwrap();
+        assert!(html_to_markdown_validate_options(valid.as_ptr()));
+        assert!(html_to_markdown_last_error().is_null());
+
+        let invalid = CString::new(r#"{"bullets":"","orderedMarkers":"-"}"#).unwrap();
+        assert!(!html_to_markdown_validate_options(invalid.as_ptr()));
+        let msg = CStr::from_ptr(html_to_markdown_last_error()).to_str().unwrap();
+        assert_eq!(
+            msg,
+            "Invalid configuration: bullets cannot be empty; ordered_markers must only contain '.' or ')', got \"-\""
+        );
+
+        let malformed = CString::new("{").unwrap();
+        assert!(!html_to_markdown_validate_options(malformed.as_ptr()));
+        assert!(!html_to_markdown_last_error().is_null());
+    }
+}
+
+#[test]
+fn test_validate_options_rejects_unknown_fields() {
+    unsafe {
+        let misspelled = CString::new(r#"{"headingStlye":"atx","wrapWidth":"80"}"#).unwrap();
+        assert!(!html_to_markdown_validate_options(misspelled.as_ptr()));
+        let msg = CStr::from_ptr(html_to_markdown_last_error()).to_str().unwrap();
+        assert!(msg.contains("unknown option headingStlye (did you mean headingStyle?)"));
+        assert!(msg.contains("wrapWidth: invalid type"));
+
+        let relaxed = CString::new(r#"{"headingStlye":"atx","strictOptions":false}"#).unwrap();
+        assert!(html_to_markdown_validate_options(relaxed.as_ptr()));
+
+        let handle = html_to_markdown_options_new_from_json(misspelled.as_ptr());
+        assert!(handle.is_null());
+    }
+}
+
+#[test]
+fn test_convert_batch() {
+    unsafe {
+        let first = CString::new("<h1>One</h1>").unwrap();
+        let second = CString::new("<p>Two</p>").unwrap();
+        let docs = [first.as_ptr(), ptr::null(), second.as_ptr()];
+
+        let results = html_to_markdown_convert_batch(docs.as_ptr(), docs.len());
+        assert!(!results.is_null());
+
+        let outputs = std::slice::from_raw_parts(results, docs.len());
+        assert_eq!(CStr::from_ptr(outputs[0]).to_str().unwrap(), "# One\n");
+        assert!(outputs[1].is_null());
+        assert_eq!(CStr::from_ptr(outputs[2]).to_str().unwrap(), "Two\n");
+
+        let msg = CStr::from_ptr(html_to_markdown_last_error()).to_str().unwrap();
+        assert_eq!(msg, "document 1: html pointer was null");
+
+        html_to_markdown_free_string_array(results, docs.len());
+    }
+}
+
 #[test]
 fn test_version() {
     unsafe {
@@ -116,7 +176,7 @@ fn test_convert_with_len_reports_length() {
         let mut len: usize = 0;
         let result = html_to_markdown_convert_with_len(html.as_ptr(), &mut len);
         assert!(!result.is_null());
-        assert!(len > 0);
+        assert_eq!(len, CStr::from_ptr(result).to_bytes().len());
         html_to_markdown_free_string(result);
     }
 }
@@ -128,7 +188,7 @@ fn test_convert_bytes_with_len_reports_length() {
         let mut len: usize = 0;
         let result = html_to_markdown_convert_bytes_with_len(html.as_ptr(), html.len(), &mut len);
         assert!(!result.is_null());
-        assert!(len > 0);
+        assert_eq!(len, CStr::from_ptr(result).to_bytes().len());
         html_to_markdown_free_string(result);
     }
 }
@@ -328,8 +388,8 @@ fn test_convert_with_metadata_with_len_reports_lengths() {
         );
         assert!(!result.is_null());
         assert!(!metadata_json.is_null());
-        assert!(markdown_len > 0);
-        assert!(metadata_len > 0);
+        assert_eq!(markdown_len, CStr::from_ptr(result).to_bytes().len());
+        assert_eq!(metadata_len, CStr::from_ptr(metadata_json).to_bytes().len());
         html_to_markdown_free_string(result);
         html_to_markdown_free_string(metadata_json);
     }
@@ -352,8 +412,8 @@ fn test_convert_with_metadata_bytes_with_len_reports_lengths() {
         );
         assert!(!result.is_null());
         assert!(!metadata_json.is_null());
-        assert!(markdown_len > 0);
-        assert!(metadata_len > 0);
+        assert_eq!(markdown_len, CStr::from_ptr(result).to_bytes().len());
+        assert_eq!(metadata_len, CStr::from_ptr(metadata_json).to_bytes().len());
         html_to_markdown_free_string(result);
         html_to_markdown_free_string(metadata_json);
     }
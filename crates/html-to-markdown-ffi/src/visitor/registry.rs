@@ -90,7 +90,7 @@ use std::rc::Rc;
 use html_to_markdown_rs::convert;
 use html_to_markdown_rs::visitor::{HtmlVisitor, NodeContext, NodeType, VisitResult};
 
-use crate::error::{capture_error, set_last_error};
+use crate::error::{HtmlToMarkdownErrorCode, capture_error, set_error, set_last_error};
 use crate::strings::string_to_c_string;
 
 /// Opaque handle to a visitor instance.
@@ -322,11 +322,13 @@ pub struct HtmlToMarkdownNodeContext {
 /// - `ctx` is valid only during callback; don't store or dereference after
 /// - `text` is valid only during callback; make a copy if needed
 /// - Returned `custom_output` MUST be malloc'd; will be freed with `free()`
-pub type HtmlToMarkdownVisitTextCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitTextCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for start of elements.
 ///
@@ -341,10 +343,12 @@ pub type HtmlToMarkdownVisitTextCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action to take.
-pub type HtmlToMarkdownVisitElementStartCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitElementStartCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for end of elements.
 ///
@@ -360,11 +364,13 @@ pub type HtmlToMarkdownVisitElementStartCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` with optional custom replacement output.
-pub type HtmlToMarkdownVisitElementEndCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    output: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitElementEndCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        output: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for links.
 ///
@@ -381,13 +387,15 @@ pub type HtmlToMarkdownVisitElementEndCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitLinkCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    href: *const c_char,
-    text: *const c_char,
-    title: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitLinkCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        href: *const c_char,
+        text: *const c_char,
+        title: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for images.
 ///
@@ -404,13 +412,15 @@ pub type HtmlToMarkdownVisitLinkCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitImageCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    src: *const c_char,
-    alt: *const c_char,
-    title: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitImageCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        src: *const c_char,
+        alt: *const c_char,
+        title: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for headings.
 ///
@@ -427,13 +437,15 @@ pub type HtmlToMarkdownVisitImageCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitHeadingCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    level: u32,
-    text: *const c_char,
-    id: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitHeadingCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        level: u32,
+        text: *const c_char,
+        id: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for code blocks.
 ///
@@ -449,12 +461,14 @@ pub type HtmlToMarkdownVisitHeadingCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitCodeBlockCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    lang: *const c_char,
-    code: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitCodeBlockCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        lang: *const c_char,
+        code: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for inline code.
 ///
@@ -469,11 +483,13 @@ pub type HtmlToMarkdownVisitCodeBlockCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitCodeInlineCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    code: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitCodeInlineCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        code: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for list start.
 ///
@@ -488,11 +504,13 @@ pub type HtmlToMarkdownVisitCodeInlineCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitListStartCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    ordered: bool,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitListStartCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        ordered: bool,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for list items.
 ///
@@ -509,13 +527,15 @@ pub type HtmlToMarkdownVisitListStartCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitListItemCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    ordered: bool,
-    marker: *const c_char,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitListItemCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        ordered: bool,
+        marker: *const c_char,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for list end.
 ///
@@ -531,12 +551,14 @@ pub type HtmlToMarkdownVisitListItemCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitListEndCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    ordered: bool,
-    output: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitListEndCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        ordered: bool,
+        output: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for table start.
 ///
@@ -550,10 +572,12 @@ pub type HtmlToMarkdownVisitListEndCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitTableStartCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitTableStartCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for table rows.
 ///
@@ -570,13 +594,15 @@ pub type HtmlToMarkdownVisitTableStartCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitTableRowCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    cells: *const *const c_char,
-    cell_count: usize,
-    is_header: bool,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitTableRowCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        cells: *const *const c_char,
+        cell_count: usize,
+        is_header: bool,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for table end.
 ///
@@ -591,11 +617,13 @@ pub type HtmlToMarkdownVisitTableRowCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitTableEndCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    output: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitTableEndCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        output: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for blockquotes.
 ///
@@ -611,12 +639,14 @@ pub type HtmlToMarkdownVisitTableEndCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitBlockquoteCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    content: *const c_char,
-    depth: usize,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitBlockquoteCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        content: *const c_char,
+        depth: usize,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for strong/bold elements.
 ///
@@ -631,11 +661,13 @@ pub type HtmlToMarkdownVisitBlockquoteCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitStrongCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitStrongCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for emphasis/italic elements.
 ///
@@ -650,11 +682,13 @@ pub type HtmlToMarkdownVisitStrongCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitEmphasisCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitEmphasisCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for strikethrough elements.
 ///
@@ -669,11 +703,13 @@ pub type HtmlToMarkdownVisitEmphasisCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitStrikethroughCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitStrikethroughCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for underline elements.
 ///
@@ -688,11 +724,13 @@ pub type HtmlToMarkdownVisitStrikethroughCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitUnderlineCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitUnderlineCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for subscript elements.
 ///
@@ -707,11 +745,13 @@ pub type HtmlToMarkdownVisitUnderlineCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitSubscriptCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitSubscriptCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for superscript elements.
 ///
@@ -726,11 +766,13 @@ pub type HtmlToMarkdownVisitSubscriptCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitSuperscriptCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitSuperscriptCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for mark/highlight elements.
 ///
@@ -745,11 +787,13 @@ pub type HtmlToMarkdownVisitSuperscriptCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitMarkCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitMarkCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for line breaks.
 ///
@@ -763,10 +807,12 @@ pub type HtmlToMarkdownVisitMarkCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitLineBreakCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitLineBreakCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for horizontal rules.
 ///
@@ -780,10 +826,12 @@ pub type HtmlToMarkdownVisitLineBreakCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitHorizontalRuleCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitHorizontalRuleCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for custom/unknown elements.
 ///
@@ -799,12 +847,14 @@ pub type HtmlToMarkdownVisitHorizontalRuleCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitCustomElementCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    tag_name: *const c_char,
-    html: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitCustomElementCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        tag_name: *const c_char,
+        html: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for definition list start.
 ///
@@ -818,10 +868,12 @@ pub type HtmlToMarkdownVisitCustomElementCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitDefinitionListStartCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitDefinitionListStartCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for definition terms.
 ///
@@ -836,11 +888,13 @@ pub type HtmlToMarkdownVisitDefinitionListStartCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitDefinitionTermCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitDefinitionTermCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for definition descriptions.
 ///
@@ -855,11 +909,13 @@ pub type HtmlToMarkdownVisitDefinitionTermCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitDefinitionDescriptionCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitDefinitionDescriptionCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for definition list end.
 ///
@@ -874,11 +930,13 @@ pub type HtmlToMarkdownVisitDefinitionDescriptionCallback = unsafe extern "C" fn
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitDefinitionListEndCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    output: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitDefinitionListEndCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        output: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for form elements.
 ///
@@ -894,12 +952,14 @@ pub type HtmlToMarkdownVisitDefinitionListEndCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitFormCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    action: *const c_char,
-    method: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitFormCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        action: *const c_char,
+        method: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for input elements.
 ///
@@ -916,13 +976,15 @@ pub type HtmlToMarkdownVisitFormCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitInputCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    input_type: *const c_char,
-    name: *const c_char,
-    value: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitInputCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        input_type: *const c_char,
+        name: *const c_char,
+        value: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for button elements.
 ///
@@ -937,11 +999,13 @@ pub type HtmlToMarkdownVisitInputCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitButtonCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitButtonCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for audio elements.
 ///
@@ -956,11 +1020,13 @@ pub type HtmlToMarkdownVisitButtonCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitAudioCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    src: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitAudioCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        src: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for video elements.
 ///
@@ -975,11 +1041,13 @@ pub type HtmlToMarkdownVisitAudioCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitVideoCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    src: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitVideoCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        src: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for iframe elements.
 ///
@@ -994,11 +1062,13 @@ pub type HtmlToMarkdownVisitVideoCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitIframeCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    src: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitIframeCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        src: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for details elements.
 ///
@@ -1013,11 +1083,13 @@ pub type HtmlToMarkdownVisitIframeCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitDetailsCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    open: bool,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitDetailsCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        open: bool,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for summary elements.
 ///
@@ -1032,11 +1104,13 @@ pub type HtmlToMarkdownVisitDetailsCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitSummaryCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitSummaryCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for figure start.
 ///
@@ -1050,10 +1124,12 @@ pub type HtmlToMarkdownVisitSummaryCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitFigureStartCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitFigureStartCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for figcaption elements.
 ///
@@ -1068,11 +1144,13 @@ pub type HtmlToMarkdownVisitFigureStartCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitFigcaptionCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    text: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitFigcaptionCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        text: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Visitor callback function type for figure end.
 ///
@@ -1087,11 +1165,13 @@ pub type HtmlToMarkdownVisitFigcaptionCallback = unsafe extern "C" fn(
 /// # Returns
 ///
 /// `HtmlToMarkdownVisitResult` indicating action.
-pub type HtmlToMarkdownVisitFigureEndCallback = unsafe extern "C" fn(
-    user_data: *mut std::ffi::c_void,
-    ctx: *const HtmlToMarkdownNodeContext,
-    output: *const c_char,
-) -> HtmlToMarkdownVisitResult;
+pub type HtmlToMarkdownVisitFigureEndCallback = Option<
+    unsafe extern "C" fn(
+        user_data: *mut std::ffi::c_void,
+        ctx: *const HtmlToMarkdownNodeContext,
+        output: *const c_char,
+    ) -> HtmlToMarkdownVisitResult,
+>;
 
 /// Complete callback table for visitor (C-compatible).
 ///
@@ -1117,124 +1197,124 @@ pub struct HtmlToMarkdownVisitorCallbacks {
     pub user_data: *mut std::ffi::c_void,
 
     /// Called for text nodes (most frequent)
-    pub visit_text: Option<HtmlToMarkdownVisitTextCallback>,
+    pub visit_text: HtmlToMarkdownVisitTextCallback,
 
     /// Called before entering any element
-    pub visit_element_start: Option<HtmlToMarkdownVisitElementStartCallback>,
+    pub visit_element_start: HtmlToMarkdownVisitElementStartCallback,
 
     /// Called after exiting any element
-    pub visit_element_end: Option<HtmlToMarkdownVisitElementEndCallback>,
+    pub visit_element_end: HtmlToMarkdownVisitElementEndCallback,
 
     /// Called for anchor links
-    pub visit_link: Option<HtmlToMarkdownVisitLinkCallback>,
+    pub visit_link: HtmlToMarkdownVisitLinkCallback,
 
     /// Called for images
-    pub visit_image: Option<HtmlToMarkdownVisitImageCallback>,
+    pub visit_image: HtmlToMarkdownVisitImageCallback,
 
     /// Called for headings
-    pub visit_heading: Option<HtmlToMarkdownVisitHeadingCallback>,
+    pub visit_heading: HtmlToMarkdownVisitHeadingCallback,
 
     /// Called for code blocks
-    pub visit_code_block: Option<HtmlToMarkdownVisitCodeBlockCallback>,
+    pub visit_code_block: HtmlToMarkdownVisitCodeBlockCallback,
 
     /// Called for inline code
-    pub visit_code_inline: Option<HtmlToMarkdownVisitCodeInlineCallback>,
+    pub visit_code_inline: HtmlToMarkdownVisitCodeInlineCallback,
 
     /// Called for list items
-    pub visit_list_item: Option<HtmlToMarkdownVisitListItemCallback>,
+    pub visit_list_item: HtmlToMarkdownVisitListItemCallback,
 
     /// Called before processing a list
-    pub visit_list_start: Option<HtmlToMarkdownVisitListStartCallback>,
+    pub visit_list_start: HtmlToMarkdownVisitListStartCallback,
 
     /// Called after processing a list
-    pub visit_list_end: Option<HtmlToMarkdownVisitListEndCallback>,
+    pub visit_list_end: HtmlToMarkdownVisitListEndCallback,
 
     /// Called before processing a table
-    pub visit_table_start: Option<HtmlToMarkdownVisitTableStartCallback>,
+    pub visit_table_start: HtmlToMarkdownVisitTableStartCallback,
 
     /// Called for table rows
-    pub visit_table_row: Option<HtmlToMarkdownVisitTableRowCallback>,
+    pub visit_table_row: HtmlToMarkdownVisitTableRowCallback,
 
     /// Called after processing a table
-    pub visit_table_end: Option<HtmlToMarkdownVisitTableEndCallback>,
+    pub visit_table_end: HtmlToMarkdownVisitTableEndCallback,
 
     /// Called for blockquotes
-    pub visit_blockquote: Option<HtmlToMarkdownVisitBlockquoteCallback>,
+    pub visit_blockquote: HtmlToMarkdownVisitBlockquoteCallback,
 
     /// Called for strong text
-    pub visit_strong: Option<HtmlToMarkdownVisitStrongCallback>,
+    pub visit_strong: HtmlToMarkdownVisitStrongCallback,
 
     /// Called for emphasis text
-    pub visit_emphasis: Option<HtmlToMarkdownVisitEmphasisCallback>,
+    pub visit_emphasis: HtmlToMarkdownVisitEmphasisCallback,
 
     /// Called for strikethrough text
-    pub visit_strikethrough: Option<HtmlToMarkdownVisitStrikethroughCallback>,
+    pub visit_strikethrough: HtmlToMarkdownVisitStrikethroughCallback,
 
     /// Called for underline text
-    pub visit_underline: Option<HtmlToMarkdownVisitUnderlineCallback>,
+    pub visit_underline: HtmlToMarkdownVisitUnderlineCallback,
 
     /// Called for subscript text
-    pub visit_subscript: Option<HtmlToMarkdownVisitSubscriptCallback>,
+    pub visit_subscript: HtmlToMarkdownVisitSubscriptCallback,
 
     /// Called for superscript text
-    pub visit_superscript: Option<HtmlToMarkdownVisitSuperscriptCallback>,
+    pub visit_superscript: HtmlToMarkdownVisitSuperscriptCallback,
 
     /// Called for mark text
-    pub visit_mark: Option<HtmlToMarkdownVisitMarkCallback>,
+    pub visit_mark: HtmlToMarkdownVisitMarkCallback,
 
     /// Called for line breaks
-    pub visit_line_break: Option<HtmlToMarkdownVisitLineBreakCallback>,
+    pub visit_line_break: HtmlToMarkdownVisitLineBreakCallback,
 
     /// Called for horizontal rules
-    pub visit_horizontal_rule: Option<HtmlToMarkdownVisitHorizontalRuleCallback>,
+    pub visit_horizontal_rule: HtmlToMarkdownVisitHorizontalRuleCallback,
 
     /// Called for custom elements
-    pub visit_custom_element: Option<HtmlToMarkdownVisitCustomElementCallback>,
+    pub visit_custom_element: HtmlToMarkdownVisitCustomElementCallback,
 
     /// Called before processing a definition list
-    pub visit_definition_list_start: Option<HtmlToMarkdownVisitDefinitionListStartCallback>,
+    pub visit_definition_list_start: HtmlToMarkdownVisitDefinitionListStartCallback,
 
     /// Called for definition terms
-    pub visit_definition_term: Option<HtmlToMarkdownVisitDefinitionTermCallback>,
+    pub visit_definition_term: HtmlToMarkdownVisitDefinitionTermCallback,
 
     /// Called for definition descriptions
-    pub visit_definition_description: Option<HtmlToMarkdownVisitDefinitionDescriptionCallback>,
+    pub visit_definition_description: HtmlToMarkdownVisitDefinitionDescriptionCallback,
 
     /// Called after processing a definition list
-    pub visit_definition_list_end: Option<HtmlToMarkdownVisitDefinitionListEndCallback>,
+    pub visit_definition_list_end: HtmlToMarkdownVisitDefinitionListEndCallback,
 
     /// Called for form elements
-    pub visit_form: Option<HtmlToMarkdownVisitFormCallback>,
+    pub visit_form: HtmlToMarkdownVisitFormCallback,
 
     /// Called for input elements
-    pub visit_input: Option<HtmlToMarkdownVisitInputCallback>,
+    pub visit_input: HtmlToMarkdownVisitInputCallback,
 
     /// Called for button elements
-    pub visit_button: Option<HtmlToMarkdownVisitButtonCallback>,
+    pub visit_button: HtmlToMarkdownVisitButtonCallback,
 
     /// Called for audio elements
-    pub visit_audio: Option<HtmlToMarkdownVisitAudioCallback>,
+    pub visit_audio: HtmlToMarkdownVisitAudioCallback,
 
     /// Called for video elements
-    pub visit_video: Option<HtmlToMarkdownVisitVideoCallback>,
+    pub visit_video: HtmlToMarkdownVisitVideoCallback,
 
     /// Called for iframe elements
-    pub visit_iframe: Option<HtmlToMarkdownVisitIframeCallback>,
+    pub visit_iframe: HtmlToMarkdownVisitIframeCallback,
 
     /// Called for details elements
-    pub visit_details: Option<HtmlToMarkdownVisitDetailsCallback>,
+    pub visit_details: HtmlToMarkdownVisitDetailsCallback,
 
     /// Called for summary elements
-    pub visit_summary: Option<HtmlToMarkdownVisitSummaryCallback>,
+    pub visit_summary: HtmlToMarkdownVisitSummaryCallback,
 
     /// Called before processing a figure
-    pub visit_figure_start: Option<HtmlToMarkdownVisitFigureStartCallback>,
+    pub visit_figure_start: HtmlToMarkdownVisitFigureStartCallback,
 
     /// Called for figcaption elements
-    pub visit_figcaption: Option<HtmlToMarkdownVisitFigcaptionCallback>,
+    pub visit_figcaption: HtmlToMarkdownVisitFigcaptionCallback,
 
     /// Called after processing a figure
-    pub visit_figure_end: Option<HtmlToMarkdownVisitFigureEndCallback>,
+    pub visit_figure_end: HtmlToMarkdownVisitFigureEndCallback,
 }
 
 /// Internal wrapper implementing `HtmlVisitor` trait from C callbacks.
@@ -2072,7 +2152,10 @@ pub unsafe extern "C" fn html_to_markdown_visitor_create(
     callbacks: *const HtmlToMarkdownVisitorCallbacks,
 ) -> HtmlToMarkdownVisitor {
     if callbacks.is_null() {
-        set_last_error(Some("callbacks pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "callbacks pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
@@ -2171,19 +2254,28 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_visitor(
     len_out: *mut usize,
 ) -> *mut c_char {
     if html.is_null() {
-        set_last_error(Some("html pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     if visitor.is_null() {
-        set_last_error(Some("visitor handle was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "visitor handle was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     let html_str = if let Ok(s) = unsafe { CStr::from_ptr(html).to_str() } {
         s
     } else {
-        set_last_error(Some("html must be valid UTF-8".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
         return ptr::null_mut();
     };
 
@@ -2200,7 +2292,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_with_visitor(
                     c_string.into_raw()
                 }
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for markdown result: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for markdown result: {err}"),
+                    );
                     ptr::null_mut()
                 }
             }
@@ -2261,12 +2356,18 @@ pub unsafe extern "C" fn html_to_markdown_convert_bytes_with_visitor(
     len_out: *mut usize,
 ) -> *mut c_char {
     if html.is_null() {
-        set_last_error(Some("html pointer was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "html pointer was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
     if visitor.is_null() {
-        set_last_error(Some("visitor handle was null".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::NullPointer,
+            "visitor handle was null".to_string(),
+        );
         return ptr::null_mut();
     }
 
@@ -2274,7 +2375,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_bytes_with_visitor(
     let html_str = if let Ok(s) = std::str::from_utf8(html_bytes) {
         s
     } else {
-        set_last_error(Some("html must be valid UTF-8".to_string()));
+        set_error(
+            HtmlToMarkdownErrorCode::InvalidUtf8,
+            "html must be valid UTF-8".to_string(),
+        );
         return ptr::null_mut();
     };
 
@@ -2291,7 +2395,10 @@ pub unsafe extern "C" fn html_to_markdown_convert_bytes_with_visitor(
                     c_string.into_raw()
                 }
                 Err(err) => {
-                    set_last_error(Some(format!("failed to build CString for markdown result: {err}")));
+                    set_error(
+                        HtmlToMarkdownErrorCode::InvalidOutput,
+                        format!("failed to build CString for markdown result: {err}"),
+                    );
                     ptr::null_mut()
                 }
             }
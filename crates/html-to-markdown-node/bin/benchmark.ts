@@ -1,5 +1,6 @@
 import { readFileSync } from "node:fs";
 import { resolve } from "node:path";
+import { Worker, isMainThread, parentPort } from "node:worker_threads";
 import { convert, convertWithInlineImages, convertWithMetadata, convertWithVisitor, startProfiling, stopProfiling } from "@kreuzberg/html-to-markdown-node";
 
 type Scenario =
@@ -19,10 +20,12 @@ const options: {
 	format: "html" | "hocr";
 	scenario: Scenario;
 	visitor?: VisitorType;
+	threads: number;
 } = {
 	iterations: 50,
 	format: "html",
 	scenario: "convert-default",
+	threads: 1,
 };
 
 for (let i = 0; i < args.length; i += 1) {
@@ -48,6 +51,9 @@ for (let i = 0; i < args.length; i += 1) {
 			options.visitor = visitor as VisitorType;
 		}
 		i += 1;
+	} else if (arg === "--threads" && args[i + 1]) {
+		options.threads = Math.max(1, Number.parseInt(args[i + 1] ?? "1", 10) || 1);
+		i += 1;
 	}
 }
 
@@ -185,43 +191,97 @@ const warmup = Math.max(
 	0,
 	Number.parseInt(process.env.HTML_TO_MARKDOWN_BENCH_WARMUP ?? "0", 10) || 0,
 );
-for (let i = 0; i < warmup; i += 1) {
-	runScenario();
-}
 
-const profileOutput = process.env.HTML_TO_MARKDOWN_PROFILE_OUTPUT;
-if (profileOutput) {
-	const frequency = Math.max(
-		1,
-		Number.parseInt(process.env.HTML_TO_MARKDOWN_PROFILE_FREQUENCY ?? "1000", 10) || 1000,
-	);
-	startProfiling(profileOutput, frequency);
-}
+const runIterations = (): void => {
+	for (let i = 0; i < options.iterations; i += 1) {
+		runScenario();
+	}
+};
 
-const start = process.hrtime.bigint();
-for (let i = 0; i < options.iterations; i += 1) {
-	runScenario();
+// Worker side of a scaling run: warm up, wait for the start signal, then run every iteration.
+if (!isMainThread) {
+	for (let i = 0; i < warmup; i += 1) {
+		runScenario();
+	}
+	parentPort?.on("message", () => {
+		runIterations();
+		parentPort?.postMessage("done");
+		parentPort?.close();
+	});
+	parentPort?.postMessage("ready");
+} else {
+	main().catch((error: unknown) => {
+		console.error(error);
+		process.exit(1);
+	});
 }
-const elapsedSeconds = Number(process.hrtime.bigint() - start) / 1_000_000_000;
 
-if (profileOutput) {
-	stopProfiling();
+// Spawn one worker per thread and resolve with the wall time from the start signal until all finish.
+async function runWorkers(threads: number): Promise<number> {
+	const workerArgs = args.filter((_, index) => args[index] !== "--threads" && args[index - 1] !== "--threads");
+	const workers = Array.from({ length: threads }, () => new Worker(__filename, { argv: workerArgs }));
+	const waitFor = (worker: Worker, message: string) =>
+		new Promise<void>((resolvePromise, reject) => {
+			worker.once("error", reject);
+			worker.once("message", (value) => (value === message ? resolvePromise() : reject(new Error(String(value)))));
+		});
+
+	await Promise.all(workers.map((worker) => waitFor(worker, "ready")));
+	const done = workers.map((worker) => waitFor(worker, "done"));
+	const start = process.hrtime.bigint();
+	for (const worker of workers) {
+		worker.postMessage("start");
+	}
+	await Promise.all(done);
+	const elapsedSeconds = Number(process.hrtime.bigint() - start) / 1_000_000_000;
+	await Promise.all(workers.map((worker) => worker.terminate()));
+	return elapsedSeconds;
 }
 
-const bytesProcessed = bytesProcessedPerIteration * options.iterations;
-const opsPerSec = options.iterations / elapsedSeconds;
-const mbPerSec = bytesProcessed / (1024 * 1024) / elapsedSeconds;
-
-const result = {
-	language: "node",
-	fixture: filePath.split("/").pop() ?? filePath,
-	fixture_path: filePath,
-	scenario: options.scenario,
-	iterations: options.iterations,
-	elapsed_seconds: elapsedSeconds,
-	ops_per_sec: opsPerSec,
-	mb_per_sec: mbPerSec,
-	bytes_processed: bytesProcessed,
-};
+async function main(): Promise<void> {
+	for (let i = 0; i < warmup; i += 1) {
+		runScenario();
+	}
 
-console.log(JSON.stringify(result));
+	const profileOutput = process.env.HTML_TO_MARKDOWN_PROFILE_OUTPUT;
+	if (profileOutput) {
+		const frequency = Math.max(
+			1,
+			Number.parseInt(process.env.HTML_TO_MARKDOWN_PROFILE_FREQUENCY ?? "1000", 10) || 1000,
+		);
+		startProfiling(profileOutput, frequency);
+	}
+
+	let elapsedSeconds: number;
+	if (options.threads > 1) {
+		elapsedSeconds = await runWorkers(options.threads);
+	} else {
+		const start = process.hrtime.bigint();
+		runIterations();
+		elapsedSeconds = Number(process.hrtime.bigint() - start) / 1_000_000_000;
+	}
+
+	if (profileOutput) {
+		stopProfiling();
+	}
+
+	const totalIterations = options.iterations * options.threads;
+	const bytesProcessed = bytesProcessedPerIteration * totalIterations;
+	const opsPerSec = totalIterations / elapsedSeconds;
+	const mbPerSec = bytesProcessed / (1024 * 1024) / elapsedSeconds;
+
+	const result = {
+		language: "node",
+		fixture: filePath.split("/").pop() ?? filePath,
+		fixture_path: filePath,
+		scenario: options.scenario,
+		threads: options.threads,
+		iterations: totalIterations,
+		elapsed_seconds: elapsedSeconds,
+		ops_per_sec: opsPerSec,
+		mb_per_sec: mbPerSec,
+		bytes_processed: bytesProcessed,
+	};
+
+	console.log(JSON.stringify(result));
+}
@@ -573,6 +573,9 @@ if (!nativeBinding) {
 
 module.exports = nativeBinding
 module.exports.convert = nativeBinding.convert
+module.exports.convertAsync = nativeBinding.convertAsync
+module.exports.convertFile = nativeBinding.convertFile
+module.exports.convertBatch = nativeBinding.convertBatch
 module.exports.convertBuffer = nativeBinding.convertBuffer
 module.exports.convertBufferJson = nativeBinding.convertBufferJson
 module.exports.convertBufferWithOptionsHandle = nativeBinding.convertBufferWithOptionsHandle
@@ -580,10 +583,14 @@ module.exports.convertInlineImagesBuffer = nativeBinding.convertInlineImagesBuff
 module.exports.convertInlineImagesBufferJson = nativeBinding.convertInlineImagesBufferJson
 module.exports.convertInlineImagesBufferWithOptionsHandle = nativeBinding.convertInlineImagesBufferWithOptionsHandle
 module.exports.convertJson = nativeBinding.convertJson
+module.exports.convertUrl = nativeBinding.convertUrl
+module.exports.convertWithFingerprint = nativeBinding.convertWithFingerprint
 module.exports.convertWithInlineImages = nativeBinding.convertWithInlineImages
+module.exports.convertWithInlineImagesAsync = nativeBinding.convertWithInlineImagesAsync
 module.exports.convertWithInlineImagesHandle = nativeBinding.convertWithInlineImagesHandle
 module.exports.convertWithInlineImagesJson = nativeBinding.convertWithInlineImagesJson
 module.exports.convertWithMetadata = nativeBinding.convertWithMetadata
+module.exports.convertWithMetadataAsync = nativeBinding.convertWithMetadataAsync
 module.exports.convertWithMetadataBuffer = nativeBinding.convertWithMetadataBuffer
 module.exports.convertWithMetadataBufferJson = nativeBinding.convertWithMetadataBufferJson
 module.exports.convertWithMetadataBufferWithMetadataHandle = nativeBinding.convertWithMetadataBufferWithMetadataHandle
@@ -592,11 +599,14 @@ module.exports.convertWithMetadataBufferWithOptionsHandle = nativeBinding.conver
 module.exports.convertWithMetadataHandle = nativeBinding.convertWithMetadataHandle
 module.exports.convertWithMetadataJson = nativeBinding.convertWithMetadataJson
 module.exports.convertWithOptionsHandle = nativeBinding.convertWithOptionsHandle
+module.exports.convertWithProgress = nativeBinding.convertWithProgress
+module.exports.convertWithStats = nativeBinding.convertWithStats
 module.exports.convertWithVisitor = nativeBinding.convertWithVisitor
 module.exports.createConversionOptionsHandle = nativeBinding.createConversionOptionsHandle
 module.exports.createConversionOptionsHandleJson = nativeBinding.createConversionOptionsHandleJson
 module.exports.createMetadataConfigHandle = nativeBinding.createMetadataConfigHandle
 module.exports.createMetadataConfigHandleJson = nativeBinding.createMetadataConfigHandleJson
+module.exports.detectInputFormat = nativeBinding.detectInputFormat
 module.exports.JsCodeBlockStyle = nativeBinding.JsCodeBlockStyle
 module.exports.JsHeadingStyle = nativeBinding.JsHeadingStyle
 module.exports.JsHighlightStyle = nativeBinding.JsHighlightStyle
@@ -604,5 +614,6 @@ module.exports.JsListIndentType = nativeBinding.JsListIndentType
 module.exports.JsNewlineStyle = nativeBinding.JsNewlineStyle
 module.exports.JsPreprocessingPreset = nativeBinding.JsPreprocessingPreset
 module.exports.JsWhitespaceMode = nativeBinding.JsWhitespaceMode
+module.exports.knownOptions = nativeBinding.knownOptions
 module.exports.startProfiling = nativeBinding.startProfiling
 module.exports.stopProfiling = nativeBinding.stopProfiling
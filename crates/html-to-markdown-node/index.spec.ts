@@ -1,15 +1,29 @@
 import { Buffer } from "node:buffer";
-import { readFileSync } from "node:fs";
+import { mkdtempSync, readFileSync, writeFileSync } from "node:fs";
+import { createServer } from "node:http";
+import type { AddressInfo } from "node:net";
+import { tmpdir } from "node:os";
 import { join } from "node:path";
 import { describe, expect, it } from "vitest";
 import {
 	convert,
+	convertAsync,
+	convertBatch,
 	convertBuffer,
 	convertBufferWithOptionsHandle,
+	convertJson,
+	convertFile,
 	convertInlineImagesBuffer,
+	convertUrl,
+	convertWithFingerprint,
 	convertWithInlineImages,
+	convertWithInlineImagesAsync,
+	convertWithMetadataAsync,
 	convertWithOptionsHandle,
+	convertWithProgress,
+	convertWithStats,
 	createConversionOptionsHandle,
+	detectInputFormat,
 	JsCodeBlockStyle,
 	JsHeadingStyle,
 	JsHighlightStyle,
@@ -17,6 +31,7 @@ import {
 	JsNewlineStyle,
 	JsPreprocessingPreset,
 	JsWhitespaceMode,
+	knownOptions,
 } from "./index.js";
 
 const loadTestDoc = (path: string): string => {
@@ -103,6 +118,45 @@ describe("html-to-markdown-node - NAPI-RS Bindings", () => {
 		});
 	});
 
+	describe("Option validation", () => {
+		it("should report every invalid option at once", () => {
+			expect(() => convert("<p>x</p>", { bullets: "", strongEmSymbol: "~" })).toThrow(
+				"Invalid configuration: bullets cannot be empty; strong_em_symbol must be '*' or '_', got '~'",
+			);
+		});
+
+		it("should reject multi-character symbols", () => {
+			expect(() => createConversionOptionsHandle({ strongEmSymbol: "**" })).toThrow(
+				/strong_em_symbol must be a single character/,
+			);
+		});
+
+		it("should reject invalid options before starting async work", () => {
+			expect(() => convertAsync("<p>x</p>", { orderedMarkers: "-" })).toThrow(/ordered_markers/);
+		});
+
+		it("should reject unknown JSON options unless strictOptions is false", () => {
+			const html = "<h1>Title</h1>";
+			expect(() => convertJson(html, JSON.stringify({ headingStlye: "atx" }))).toThrow(
+				"unknown option headingStlye (did you mean headingStyle?)",
+			);
+			expect(convertJson(html, JSON.stringify({ headingStlye: "atx", strictOptions: false }))).toContain("Title");
+			expect(convertJson(html, JSON.stringify({ headingStyle: "atx", strictOptions: true }))).toBe("# Title\n");
+		});
+
+		it("should report every mistyped JSON option", () => {
+			const convert = () => convertJson("<p>x</p>", JSON.stringify({ wrapWidth: "80", headingStyle: "banner" }));
+			expect(convert).toThrow("wrapWidth: invalid type");
+			expect(convert).toThrow('headingStyle: unknown value "banner"');
+		});
+
+		it("should list the known option names", () => {
+			const names = knownOptions();
+			expect(names).toContain("headingStyle");
+			expect(names).not.toContain("strictOptions");
+		});
+	});
+
 	describe("Buffer conversions", () => {
 		it("should convert buffers without UTF-16 copies", () => {
 			const html = Buffer.from("<h1>Buffer</h1>");
@@ -125,6 +179,118 @@ describe("html-to-markdown-node - NAPI-RS Bindings", () => {
 		});
 	});
 
+	describe("Batch conversion", () => {
+		it("should convert every document in order", () => {
+			const docs = ["<h1>One</h1>", "<p>Two</p>", ""];
+			const options = { headingStyle: JsHeadingStyle.Atx };
+			expect(convertBatch(docs, options)).toEqual(docs.map((html) => convert(html, options)));
+		});
+	});
+
+	describe("Conversion stats", () => {
+		it("should report resource usage when collectStats is set", () => {
+			const html = "<h1>Title</h1><p>Some <strong>text</strong></p>";
+			const { markdown, stats } = convertWithStats(html, { collectStats: true });
+			expect(markdown).toBe(convert(html));
+			expect(stats?.inputBytes).toBe(html.length);
+			expect(stats?.outputBytes).toBe(markdown.length);
+			expect(stats?.nodesParsed).toBeGreaterThan(0);
+			expect(stats?.totalMs).toBeGreaterThanOrEqual(0);
+		});
+
+		it("should leave stats empty by default", () => {
+			expect(convertWithStats("<p>hi</p>").stats).toBeUndefined();
+		});
+	});
+
+	describe("Content fingerprint", () => {
+		it("should match for documents that differ only in markup", () => {
+			const a = convertWithFingerprint("<p>Hello <b>world</b></p>", { contentFingerprint: true });
+			const b = convertWithFingerprint("<div>Hello\n<strong>world</strong></div>", { contentFingerprint: true });
+			expect(a.fingerprint).toHaveLength(32);
+			expect(a.fingerprint).toBe(b.fingerprint);
+		});
+
+		it("should leave the fingerprint empty by default", () => {
+			expect(convertWithFingerprint("<p>hi</p>").fingerprint).toBeUndefined();
+		});
+	});
+
+	describe("Input format detection", () => {
+		it("should tell hOCR apart from plain HTML", () => {
+			expect(detectInputFormat('<div class="ocr_page"><span class="ocrx_word">Hi</span></div>')).toBe("hocr");
+			expect(detectInputFormat("<p>Hello</p>")).toBe("html");
+			expect(detectInputFormat("just words")).toBe("text");
+		});
+	});
+
+	describe("Progress reporting", () => {
+		it("should report progress up to completion", async () => {
+			const html = "<p>x</p>".repeat(500);
+			const events: { fraction: number; done: boolean }[] = [];
+			const markdown = await convertWithProgress(html, null, (event) => events.push(event), 100);
+
+			expect(markdown).toBe(convert(html));
+			await new Promise((resolve) => setImmediate(resolve));
+			expect(events.length).toBeGreaterThan(2);
+			expect(events.at(-1)).toMatchObject({ fraction: 1, done: true });
+			expect(events.slice(0, -1).every((event) => !event.done)).toBe(true);
+		});
+	});
+
+	describe("Async conversions", () => {
+		it("should resolve with the same output as convert", async () => {
+			const html = "<h1>Async</h1><p>Off the <em>main</em> thread</p>";
+			const options = { headingStyle: JsHeadingStyle.Atx };
+			await expect(convertAsync(html, options)).resolves.toBe(convert(html, options));
+		});
+
+		it("should run concurrent conversions", async () => {
+			const results = await Promise.all([1, 2, 3].map((n) => convertAsync(`<p>Doc ${n}</p>`)));
+			expect(results).toEqual(["Doc 1\n", "Doc 2\n", "Doc 3\n"]);
+		});
+
+		it("should extract metadata asynchronously", async () => {
+			const html = "<html><head><title>Async Title</title></head><body><h1>Hello</h1></body></html>";
+			const result = await convertWithMetadataAsync(html);
+			expect(result.markdown).toContain("Hello");
+			expect(result.metadata.document.title).toBe("Async Title");
+		});
+
+		it("should collect inline images asynchronously", async () => {
+			const html = '<img src="data:image/png;base64,iVBORw0KGgo=" alt="Pixel">';
+			const result = await convertWithInlineImagesAsync(html);
+			expect(result.inlineImages).toHaveLength(1);
+			expect(result.inlineImages[0].description).toBe("Pixel");
+		});
+	});
+
+	describe("File and URL conversions", () => {
+		it("should convert a file read natively", async () => {
+			const path = join(mkdtempSync(join(tmpdir(), "h2m-")), "page.html");
+			writeFileSync(path, "<h1>From disk</h1><p>Body</p>");
+			await expect(convertFile(path, { headingStyle: JsHeadingStyle.Atx })).resolves.toBe("# From disk\n\nBody\n");
+		});
+
+		it("should reject a missing file", async () => {
+			await expect(convertFile(join(tmpdir(), "h2m-missing.html"))).rejects.toThrow(/Failed to read/);
+		});
+
+		it("should fetch and convert a URL", async () => {
+			const server = createServer((_req, res) => {
+				res.writeHead(200, { "Content-Type": "text/html; charset=iso-8859-1" });
+				res.end(Buffer.from("<p>Caf\xe9</p>", "latin1"));
+			});
+			await new Promise<void>((resolve) => server.listen(0, "127.0.0.1", resolve));
+			const { port } = server.address() as AddressInfo;
+			try {
+				await expect(convertUrl(`http://127.0.0.1:${port}/`, null, { maxRetries: 0 })).resolves.toBe("Café\n");
+			} finally {
+				server.close();
+			}
+		});
+	});
+
 	describe("Code Block Styles", () => {
 		it("should use backticks for code blocks", () => {
 			const html = "<pre><code>function test() {}</code></pre>";
@@ -4,9 +4,10 @@
 
 #[cfg(feature = "metadata")]
 use html_to_markdown_rs::metadata::{
-    DocumentMetadata as RustDocumentMetadata, ExtendedMetadata as RustExtendedMetadata,
-    HeaderMetadata as RustHeaderMetadata, ImageMetadata as RustImageMetadata, LinkMetadata as RustLinkMetadata,
-    MetadataConfig as RustMetadataConfig, StructuredData as RustStructuredData,
+    AuthorProfile as RustAuthorProfile, DocumentMetadata as RustDocumentMetadata,
+    ExtendedMetadata as RustExtendedMetadata, HeaderMetadata as RustHeaderMetadata, ImageMetadata as RustImageMetadata,
+    LinkMetadata as RustLinkMetadata, MetadataConfig as RustMetadataConfig, ResourceMetadata as RustResourceMetadata,
+    SourceMapEntry as RustSourceMapEntry, StructuredData as RustStructuredData,
 };
 use html_to_markdown_rs::safety::guard_panic;
 mod profiling;
@@ -20,15 +21,15 @@ use html_to_markdown_rs::visitor::HtmlVisitor;
 #[cfg(any(feature = "visitor", feature = "async-visitor"))]
 use html_to_markdown_rs::visitor::{NodeContext as RustNodeContext, VisitResult as RustVisitResult};
 use html_to_markdown_rs::{
-    CodeBlockStyle, ConversionError, ConversionOptions as RustConversionOptions, ConversionOptionsUpdate,
-    DEFAULT_INLINE_IMAGE_LIMIT, HeadingStyle, HighlightStyle, InlineImageConfig as RustInlineImageConfig,
-    InlineImageConfigUpdate, ListIndentType, NewlineStyle, PreprocessingOptions as RustPreprocessingOptions,
-    PreprocessingOptionsUpdate, PreprocessingPreset, WhitespaceMode,
+    AbbrStyle, BidiMode, CodeBlockStyle, ContactLinkStyle, ConversionError, ConversionOptions as RustConversionOptions,
+    ConversionOptionsUpdate, DEFAULT_INLINE_IMAGE_LIMIT, DetailsStyle, DfnStyle, FigureStyle, FrontMatterFormat,
+    HeaderBreakStyle, HeadingStyle, HighlightStyle, InlineImageConfig as RustInlineImageConfig,
+    InlineImageConfigUpdate, ListIndentType, ListSpacing, MathStyle, NbspMode, NewlineStyle,
+    PreprocessingOptions as RustPreprocessingOptions, PreprocessingOptionsUpdate, PreprocessingPreset, SrcsetPolicy,
+    SvgTextMode, TimeStyle, UnrepresentablePolicy, WhitespaceMode, option_char,
 };
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
-#[cfg(feature = "async-visitor")]
-#[allow(unused_imports)]
 use std::sync::Arc;
 use std::{collections::HashMap, str};
 
@@ -50,10 +51,26 @@ fn parse_options_json(options_json: Option<String>) -> Result<Option<RustConvers
         return Ok(None);
     }
 
-    let options = html_to_markdown_rs::conversion_options_from_json(&json).map_err(to_js_error)?;
+    let update = ConversionOptionsUpdate::from_json_strict(&json).map_err(to_js_error)?;
+    let options = RustConversionOptions::from(update);
+    options.validate().map_err(to_js_error)?;
     Ok(Some(options))
 }
 
+/// Convert options passed from JavaScript, rejecting values the core's validation does not accept.
+fn validated_options(options: Option<JsConversionOptions>) -> Result<Option<RustConversionOptions>> {
+    options
+        .map(|options| {
+            if let Some(symbol) = &options.strong_em_symbol {
+                option_char("strong_em_symbol", symbol).map_err(to_js_error)?;
+            }
+            let options = RustConversionOptions::from(options);
+            options.validate().map_err(to_js_error)?;
+            Ok(options)
+        })
+        .transpose()
+}
+
 fn parse_inline_image_config_json(config_json: Option<String>) -> Result<RustInlineImageConfig> {
     let Some(json) = config_json else {
         return Ok(RustInlineImageConfig::new(DEFAULT_INLINE_IMAGE_LIMIT));
@@ -116,6 +133,262 @@ impl From<JsListIndentType> for ListIndentType {
     }
 }
 
+/// Front matter format
+#[napi(string_enum)]
+pub enum JsFrontMatterFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl From<JsFrontMatterFormat> for FrontMatterFormat {
+    fn from(val: JsFrontMatterFormat) -> Self {
+        match val {
+            JsFrontMatterFormat::Yaml => Self::Yaml,
+            JsFrontMatterFormat::Toml => Self::Toml,
+            JsFrontMatterFormat::Json => Self::Json,
+        }
+    }
+}
+
+/// BiDi (right-to-left) handling mode
+#[napi(string_enum)]
+pub enum JsBidiMode {
+    Ignore,
+    Marks,
+    Embedding,
+    Html,
+}
+
+impl From<JsBidiMode> for BidiMode {
+    fn from(val: JsBidiMode) -> Self {
+        match val {
+            JsBidiMode::Ignore => Self::Ignore,
+            JsBidiMode::Marks => Self::Marks,
+            JsBidiMode::Embedding => Self::Embedding,
+            JsBidiMode::Html => Self::Html,
+        }
+    }
+}
+
+/// List item spacing
+#[napi(string_enum)]
+pub enum JsListSpacing {
+    Auto,
+    Tight,
+    Loose,
+}
+
+impl From<JsListSpacing> for ListSpacing {
+    fn from(val: JsListSpacing) -> Self {
+        match val {
+            JsListSpacing::Auto => Self::Auto,
+            JsListSpacing::Tight => Self::Tight,
+            JsListSpacing::Loose => Self::Loose,
+        }
+    }
+}
+
+/// Handling of content without a Markdown equivalent
+#[napi(string_enum)]
+pub enum JsUnrepresentablePolicy {
+    Drop,
+    Passthrough,
+    Diagnose,
+}
+
+impl From<JsUnrepresentablePolicy> for UnrepresentablePolicy {
+    fn from(val: JsUnrepresentablePolicy) -> Self {
+        match val {
+            JsUnrepresentablePolicy::Drop => Self::Drop,
+            JsUnrepresentablePolicy::Passthrough => Self::Passthrough,
+            JsUnrepresentablePolicy::Diagnose => Self::Diagnose,
+        }
+    }
+}
+
+/// How inline SVG text is surfaced
+#[napi(string_enum)]
+pub enum JsSvgTextMode {
+    Off,
+    Alt,
+    Caption,
+    Replace,
+}
+
+impl From<JsSvgTextMode> for SvgTextMode {
+    fn from(val: JsSvgTextMode) -> Self {
+        match val {
+            JsSvgTextMode::Off => Self::Off,
+            JsSvgTextMode::Alt => Self::Alt,
+            JsSvgTextMode::Caption => Self::Caption,
+            JsSvgTextMode::Replace => Self::Replace,
+        }
+    }
+}
+
+/// How MathML and TeX math is written
+#[napi(string_enum)]
+pub enum JsMathStyle {
+    Comment,
+    Dollar,
+    Fenced,
+}
+
+impl From<JsMathStyle> for MathStyle {
+    fn from(val: JsMathStyle) -> Self {
+        match val {
+            JsMathStyle::Comment => Self::Comment,
+            JsMathStyle::Dollar => Self::Dollar,
+            JsMathStyle::Fenced => Self::Fenced,
+        }
+    }
+}
+
+/// Which srcset candidate replaces a missing img src
+#[napi(string_enum)]
+pub enum JsSrcsetPolicy {
+    First,
+    Largest,
+    Smallest,
+}
+
+impl From<JsSrcsetPolicy> for SrcsetPolicy {
+    fn from(val: JsSrcsetPolicy) -> Self {
+        match val {
+            JsSrcsetPolicy::First => Self::First,
+            JsSrcsetPolicy::Largest => Self::Largest,
+            JsSrcsetPolicy::Smallest => Self::Smallest,
+        }
+    }
+}
+
+/// How figure captions are written
+#[napi(string_enum)]
+pub enum JsFigureStyle {
+    Italic,
+    Blockquote,
+    Html,
+}
+
+impl From<JsFigureStyle> for FigureStyle {
+    fn from(val: JsFigureStyle) -> Self {
+        match val {
+            JsFigureStyle::Italic => Self::Italic,
+            JsFigureStyle::Blockquote => Self::Blockquote,
+            JsFigureStyle::Html => Self::Html,
+        }
+    }
+}
+
+/// How details sections are written
+#[napi(string_enum)]
+pub enum JsDetailsStyle {
+    Markdown,
+    Html,
+}
+
+impl From<JsDetailsStyle> for DetailsStyle {
+    fn from(val: JsDetailsStyle) -> Self {
+        match val {
+            JsDetailsStyle::Markdown => Self::Markdown,
+            JsDetailsStyle::Html => Self::Html,
+        }
+    }
+}
+
+/// How abbreviations are written
+#[napi(string_enum)]
+pub enum JsAbbrStyle {
+    Expand,
+    Text,
+    Html,
+}
+
+impl From<JsAbbrStyle> for AbbrStyle {
+    fn from(val: JsAbbrStyle) -> Self {
+        match val {
+            JsAbbrStyle::Expand => Self::Expand,
+            JsAbbrStyle::Text => Self::Text,
+            JsAbbrStyle::Html => Self::Html,
+        }
+    }
+}
+
+/// How time elements are written
+#[napi(string_enum)]
+pub enum JsTimeStyle {
+    Text,
+    Datetime,
+    Html,
+}
+
+impl From<JsTimeStyle> for TimeStyle {
+    fn from(val: JsTimeStyle) -> Self {
+        match val {
+            JsTimeStyle::Text => Self::Text,
+            JsTimeStyle::Datetime => Self::Datetime,
+            JsTimeStyle::Html => Self::Html,
+        }
+    }
+}
+
+/// How definition terms are written
+#[napi(string_enum)]
+pub enum JsDfnStyle {
+    Emphasis,
+    Text,
+    Html,
+}
+
+impl From<JsDfnStyle> for DfnStyle {
+    fn from(val: JsDfnStyle) -> Self {
+        match val {
+            JsDfnStyle::Emphasis => Self::Emphasis,
+            JsDfnStyle::Text => Self::Text,
+            JsDfnStyle::Html => Self::Html,
+        }
+    }
+}
+
+/// How mailto and tel links are written
+#[napi(string_enum)]
+pub enum JsContactLinkStyle {
+    Link,
+    Autolink,
+    Text,
+}
+
+impl From<JsContactLinkStyle> for ContactLinkStyle {
+    fn from(val: JsContactLinkStyle) -> Self {
+        match val {
+            JsContactLinkStyle::Link => Self::Link,
+            JsContactLinkStyle::Autolink => Self::Autolink,
+            JsContactLinkStyle::Text => Self::Text,
+        }
+    }
+}
+
+/// What br becomes inside headings and header cells
+#[napi(string_enum)]
+pub enum JsHeaderBreakStyle {
+    Inherit,
+    Space,
+    Slash,
+    Html,
+}
+
+impl From<JsHeaderBreakStyle> for HeaderBreakStyle {
+    fn from(val: JsHeaderBreakStyle) -> Self {
+        match val {
+            JsHeaderBreakStyle::Inherit => Self::Inherit,
+            JsHeaderBreakStyle::Space => Self::Space,
+            JsHeaderBreakStyle::Slash => Self::Slash,
+            JsHeaderBreakStyle::Html => Self::Html,
+        }
+    }
+}
+
 /// Whitespace handling mode
 #[napi(string_enum)]
 pub enum JsWhitespaceMode {
@@ -132,6 +405,22 @@ impl From<JsWhitespaceMode> for WhitespaceMode {
     }
 }
 
+/// Non-breaking space handling
+#[napi(string_enum)]
+pub enum JsNbspMode {
+    Space,
+    Preserve,
+}
+
+impl From<JsNbspMode> for NbspMode {
+    fn from(val: JsNbspMode) -> Self {
+        match val {
+            JsNbspMode::Space => Self::Space,
+            JsNbspMode::Preserve => Self::Preserve,
+        }
+    }
+}
+
 /// Newline style
 #[napi(string_enum)]
 pub enum JsNewlineStyle {
@@ -201,6 +490,7 @@ pub enum JsPreprocessingPreset {
     Minimal,
     Standard,
     Aggressive,
+    Confluence,
 }
 
 impl From<JsPreprocessingPreset> for PreprocessingPreset {
@@ -209,6 +499,7 @@ impl From<JsPreprocessingPreset> for PreprocessingPreset {
             JsPreprocessingPreset::Minimal => Self::Minimal,
             JsPreprocessingPreset::Standard => Self::Standard,
             JsPreprocessingPreset::Aggressive => Self::Aggressive,
+            JsPreprocessingPreset::Confluence => Self::Confluence,
         }
     }
 }
@@ -224,6 +515,16 @@ pub struct JsPreprocessingOptions {
     pub remove_navigation: Option<bool>,
     /// Remove form elements
     pub remove_forms: Option<bool>,
+    /// Drop elements hidden by the print stylesheet
+    pub apply_print_styles: Option<bool>,
+    /// Convert only the main article content
+    pub extract_main_content: Option<bool>,
+    /// Flatten declarative shadow roots into the light DOM
+    pub flatten_shadow_dom: Option<bool>,
+    /// CSS selectors whose matching elements are dropped with their content
+    pub exclude_selectors: Option<Vec<String>>,
+    /// CSS selector for the only elements to convert
+    pub include_selector: Option<String>,
 }
 
 impl From<JsPreprocessingOptions> for PreprocessingOptionsUpdate {
@@ -233,6 +534,11 @@ impl From<JsPreprocessingOptions> for PreprocessingOptionsUpdate {
             preset: val.preset.map(Into::into),
             remove_navigation: val.remove_navigation,
             remove_forms: val.remove_forms,
+            apply_print_styles: val.apply_print_styles,
+            extract_main_content: val.extract_main_content,
+            flatten_shadow_dom: val.flatten_shadow_dom,
+            exclude_selectors: val.exclude_selectors,
+            include_selector: val.include_selector,
         }
     }
 }
@@ -309,10 +615,90 @@ pub struct JsConversionOptions {
     pub debug: Option<bool>,
     /// List of HTML tags to strip
     pub strip_tags: Option<Vec<String>>,
+    /// List of HTML tags to remove together with their content
+    pub remove_tags: Option<Vec<String>>,
     /// List of HTML tags to preserve as-is in the output
     pub preserve_tags: Option<Vec<String>>,
     /// Skip image conversion (keep as HTML)
     pub skip_images: Option<bool>,
+    /// Remove whitespace between inline elements and following punctuation
+    pub remove_space_before_punctuation: Option<bool>,
+    /// Non-breaking space handling in prose
+    pub nbsp_in_prose: Option<JsNbspMode>,
+    /// Non-breaking space handling in table cells
+    pub nbsp_in_tables: Option<JsNbspMode>,
+    /// Front matter format for extracted metadata
+    pub front_matter_format: Option<JsFrontMatterFormat>,
+    /// Remove soft hyphens and word joiners from text
+    pub remove_soft_hyphens: Option<bool>,
+    /// Handling of right-to-left (`dir`) sections
+    pub bidi_mode: Option<JsBidiMode>,
+    /// Use language-appropriate punctuation for generated quotes and parentheses
+    pub localized_punctuation: Option<bool>,
+    /// Ordered list delimiters per nesting level (`.` or `)`)
+    pub ordered_markers: Option<String>,
+    /// Cycle bullets by nesting level (false keeps the first bullet everywhere)
+    pub cycle_bullets: Option<bool>,
+    /// Force tight or loose lists (default: follow the source)
+    pub list_spacing: Option<JsListSpacing>,
+    /// Handling of content without a Markdown equivalent (drop, passthrough, diagnose)
+    pub unrepresentable_policy: Option<JsUnrepresentablePolicy>,
+    /// Post-processors to run on the output, in order (`compact-blank-lines`, `normalize-urls`, `prune-empty-elements`, `reference-links`, `toc`)
+    pub post_processors: Option<Vec<String>>,
+    /// Collect a resource usage report, returned by `convertWithStats`
+    pub collect_stats: Option<bool>,
+    /// Title sources tried in order for links without a title and documents without `<title>` (`og-title`, `first-heading`, `url-basename`)
+    pub title_fallback: Option<Vec<String>>,
+    /// Emit Pandoc attribute blocks (`{#id .class}`) for headings, code blocks, links and images
+    pub pandoc_attributes: Option<bool>,
+    /// How inline SVG text (title, desc, labels) is surfaced
+    pub svg_text_mode: Option<JsSvgTextMode>,
+    /// Link the `data`/`src` URL of `<object>` and `<embed>` elements
+    pub object_links: Option<bool>,
+    /// Keep heading ids as `<a id>` anchors (or `{#id}` with `pandocAttributes`)
+    pub preserve_heading_ids: Option<bool>,
+    /// Compute a content fingerprint, returned by `convertWithFingerprint`
+    pub content_fingerprint: Option<bool>,
+    /// How MathML and TeX math is written (comment, dollar, fenced)
+    pub math_style: Option<JsMathStyle>,
+    /// Infer the fence language of code blocks from highlighter classes, `data-lang` or the code
+    pub infer_code_language: Option<bool>,
+    /// Drop blank lines at the start and end of `<pre>` code blocks
+    pub code_block_trim: Option<bool>,
+    /// Which srcset candidate replaces a missing img src (first, largest, smallest)
+    pub srcset_policy: Option<JsSrcsetPolicy>,
+    /// How figure captions are written (italic, blockquote, html)
+    pub figure_style: Option<JsFigureStyle>,
+    /// How details sections are written (markdown, html)
+    pub details_style: Option<JsDetailsStyle>,
+    /// How abbreviations are written (expand, text, html)
+    pub abbr_style: Option<JsAbbrStyle>,
+    /// How time elements are written (text, datetime, html)
+    pub time_style: Option<JsTimeStyle>,
+    /// How definition terms are written (emphasis, text, html)
+    pub dfn_style: Option<JsDfnStyle>,
+    /// Query parameters `normalize-urls` keeps; when set, all others are dropped
+    pub url_param_allowlist: Option<Vec<String>>,
+    /// Query parameters `normalize-urls` drops (default: tracking parameters such as `utm_*`)
+    pub url_param_denylist: Option<Vec<String>>,
+    /// How mailto and tel links are written (link, autolink, text)
+    pub contact_link_style: Option<JsContactLinkStyle>,
+    /// Keep table, form and MathML subtrees as HTML when more than this fraction of their structure would be lost (0.0-1.0, 1.0 disables)
+    pub fidelity_threshold: Option<f64>,
+    /// Turn title attributes on links, abbreviations and spans into footnotes
+    pub title_footnotes: Option<bool>,
+    /// What br becomes inside headings and header cells (inherit, space, slash, html)
+    pub header_break_style: Option<JsHeaderBreakStyle>,
+    /// Report how much text each removal rule dropped, as diagnostics
+    pub report_dropped_content: Option<bool>,
+    /// Convert concatenated HTML documents one at a time, separated by thematic breaks
+    pub split_documents: Option<bool>,
+    /// Attributes kept on elements written out as HTML; when set, all others are dropped
+    pub preserved_attributes: Option<Vec<String>>,
+    /// Cap the output at this many characters, cutting between top-level blocks (0 removes the cap)
+    pub max_output_chars: Option<u32>,
+    /// Cap the output at this many estimated tokens, cutting between top-level blocks (0 removes the cap)
+    pub max_output_tokens: Option<u32>,
 }
 
 impl From<JsConversionOptions> for ConversionOptionsUpdate {
@@ -348,8 +734,48 @@ impl From<JsConversionOptions> for ConversionOptionsUpdate {
             encoding: val.encoding,
             debug: val.debug,
             strip_tags: val.strip_tags,
+            remove_tags: val.remove_tags,
             preserve_tags: val.preserve_tags,
             skip_images: val.skip_images,
+            remove_space_before_punctuation: val.remove_space_before_punctuation,
+            nbsp_in_prose: val.nbsp_in_prose.map(Into::into),
+            nbsp_in_tables: val.nbsp_in_tables.map(Into::into),
+            front_matter_format: val.front_matter_format.map(Into::into),
+            remove_soft_hyphens: val.remove_soft_hyphens,
+            bidi_mode: val.bidi_mode.map(Into::into),
+            localized_punctuation: val.localized_punctuation,
+            ordered_markers: val.ordered_markers,
+            cycle_bullets: val.cycle_bullets,
+            list_spacing: val.list_spacing.map(Into::into),
+            unrepresentable_policy: val.unrepresentable_policy.map(Into::into),
+            post_processors: val.post_processors,
+            collect_stats: val.collect_stats,
+            title_fallback: val.title_fallback,
+            pandoc_attributes: val.pandoc_attributes,
+            svg_text_mode: val.svg_text_mode.map(Into::into),
+            object_links: val.object_links,
+            preserve_heading_ids: val.preserve_heading_ids,
+            content_fingerprint: val.content_fingerprint,
+            math_style: val.math_style.map(Into::into),
+            infer_code_language: val.infer_code_language,
+            code_block_trim: val.code_block_trim,
+            srcset_policy: val.srcset_policy.map(Into::into),
+            figure_style: val.figure_style.map(Into::into),
+            details_style: val.details_style.map(Into::into),
+            abbr_style: val.abbr_style.map(Into::into),
+            time_style: val.time_style.map(Into::into),
+            dfn_style: val.dfn_style.map(Into::into),
+            url_param_allowlist: val.url_param_allowlist,
+            url_param_denylist: val.url_param_denylist,
+            contact_link_style: val.contact_link_style.map(Into::into),
+            fidelity_threshold: val.fidelity_threshold,
+            title_footnotes: val.title_footnotes,
+            header_break_style: val.header_break_style.map(Into::into),
+            report_dropped_content: val.report_dropped_content,
+            split_documents: val.split_documents,
+            preserved_attributes: val.preserved_attributes,
+            max_output_chars: val.max_output_chars.map(|value| value as usize),
+            max_output_tokens: val.max_output_tokens.map(|value| value as usize),
         }
     }
 }
@@ -438,6 +864,63 @@ pub struct JsHtmlExtraction {
     pub warnings: Vec<JsInlineImageWarning>,
 }
 
+/// Resource usage of one conversion (sizes in bytes, timings in milliseconds)
+#[napi(object)]
+pub struct JsConversionStats {
+    /// Size of the HTML input
+    pub input_bytes: i64,
+    /// Size of the Markdown output
+    pub output_bytes: i64,
+    /// Number of nodes in the parsed DOM
+    pub nodes_parsed: i64,
+    /// Estimate of the largest amount of working memory held at once
+    pub peak_memory_estimate: i64,
+    /// Time spent preprocessing the HTML
+    pub preprocess_ms: f64,
+    /// Time spent parsing the HTML
+    pub parse_ms: f64,
+    /// Time spent writing Markdown
+    pub convert_ms: f64,
+    /// Time spent in post-processors and wrapping
+    pub postprocess_ms: f64,
+    /// Sum of all phases
+    pub total_ms: f64,
+}
+
+impl From<html_to_markdown_rs::ConversionStats> for JsConversionStats {
+    fn from(stats: html_to_markdown_rs::ConversionStats) -> Self {
+        Self {
+            input_bytes: stats.input_bytes as i64,
+            output_bytes: stats.output_bytes as i64,
+            nodes_parsed: stats.nodes_parsed as i64,
+            peak_memory_estimate: stats.peak_memory_estimate as i64,
+            preprocess_ms: stats.preprocess_time.as_secs_f64() * 1000.0,
+            parse_ms: stats.parse_time.as_secs_f64() * 1000.0,
+            convert_ms: stats.convert_time.as_secs_f64() * 1000.0,
+            postprocess_ms: stats.postprocess_time.as_secs_f64() * 1000.0,
+            total_ms: stats.total_time().as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+/// Markdown together with the resource usage report
+#[napi(object)]
+pub struct JsStatsResult {
+    /// Converted markdown
+    pub markdown: String,
+    /// Resource usage, present when `collectStats` is set
+    pub stats: Option<JsConversionStats>,
+}
+
+/// Markdown together with its content fingerprint
+#[napi(object)]
+pub struct JsFingerprintResult {
+    /// Converted markdown
+    pub markdown: String,
+    /// 32 hex digit hash of the output's text, present when `contentFingerprint` is set
+    pub fingerprint: Option<String>,
+}
+
 /// Metadata extraction configuration
 #[cfg(feature = "metadata")]
 #[napi(object)]
@@ -452,6 +935,10 @@ pub struct JsMetadataConfig {
     pub extract_images: Option<bool>,
     #[napi(js_name = "extract_structured_data")]
     pub extract_structured_data: Option<bool>,
+    #[napi(js_name = "extract_resources")]
+    pub extract_resources: Option<bool>,
+    #[napi(js_name = "extract_source_map")]
+    pub extract_source_map: Option<bool>,
     #[napi(js_name = "max_structured_data_size")]
     pub max_structured_data_size: Option<i64>,
 }
@@ -465,6 +952,8 @@ impl From<JsMetadataConfig> for RustMetadataConfig {
             extract_links: val.extract_links,
             extract_images: val.extract_images,
             extract_structured_data: val.extract_structured_data,
+            extract_resources: val.extract_resources,
+            extract_source_map: val.extract_source_map,
             max_structured_data_size: val.max_structured_data_size.map(|value| value as usize),
         };
         Self::from(update)
@@ -479,6 +968,8 @@ pub struct JsDocumentMetadata {
     pub description: Option<String>,
     pub keywords: Vec<String>,
     pub author: Option<String>,
+    #[napi(js_name = "author_profiles")]
+    pub author_profiles: Vec<JsAuthorProfile>,
     #[napi(js_name = "canonical_url")]
     pub canonical_url: Option<String>,
     #[napi(js_name = "base_href")]
@@ -494,6 +985,15 @@ pub struct JsDocumentMetadata {
     pub meta_tags: HashMap<String, String>,
 }
 
+/// Author or organization profile URL
+#[cfg(feature = "metadata")]
+#[napi(object)]
+pub struct JsAuthorProfile {
+    pub url: String,
+    pub name: Option<String>,
+    pub source: String,
+}
+
 /// Header element metadata
 #[cfg(feature = "metadata")]
 #[napi(object)]
@@ -544,6 +1044,30 @@ pub struct JsStructuredData {
     pub schema_type: Option<String>,
 }
 
+/// External resource (script, stylesheet, font, media) the document loads
+#[cfg(feature = "metadata")]
+#[napi(object)]
+pub struct JsResourceMetadata {
+    pub url: String,
+    #[napi(js_name = "resource_type")]
+    pub resource_type: String,
+}
+
+/// HTML and Markdown byte ranges of one emitted block
+#[cfg(feature = "metadata")]
+#[napi(object)]
+pub struct JsSourceMapEntry {
+    pub tag: String,
+    #[napi(js_name = "html_start")]
+    pub html_start: u32,
+    #[napi(js_name = "html_end")]
+    pub html_end: u32,
+    #[napi(js_name = "markdown_start")]
+    pub markdown_start: u32,
+    #[napi(js_name = "markdown_end")]
+    pub markdown_end: u32,
+}
+
 /// Complete extracted metadata
 #[cfg(feature = "metadata")]
 #[napi(object)]
@@ -553,6 +1077,8 @@ pub struct JsExtendedMetadata {
     pub links: Vec<JsLinkMetadata>,
     pub images: Vec<JsImageMetadata>,
     pub structured_data: Vec<JsStructuredData>,
+    pub resources: Vec<JsResourceMetadata>,
+    pub source_map: Vec<JsSourceMapEntry>,
 }
 
 /// Result of conversion with metadata extraction
@@ -570,6 +1096,7 @@ fn convert_document_metadata(doc: RustDocumentMetadata) -> JsDocumentMetadata {
         description: doc.description,
         keywords: doc.keywords,
         author: doc.author,
+        author_profiles: convert_author_profiles(doc.author_profiles),
         canonical_url: doc.canonical_url,
         base_href: doc.base_href,
         language: doc.language,
@@ -580,6 +1107,18 @@ fn convert_document_metadata(doc: RustDocumentMetadata) -> JsDocumentMetadata {
     }
 }
 
+#[cfg(feature = "metadata")]
+fn convert_author_profiles(profiles: Vec<RustAuthorProfile>) -> Vec<JsAuthorProfile> {
+    profiles
+        .into_iter()
+        .map(|profile| JsAuthorProfile {
+            url: profile.url,
+            name: profile.name,
+            source: profile.source.to_string(),
+        })
+        .collect()
+}
+
 #[cfg(feature = "metadata")]
 fn convert_headers(headers: Vec<RustHeaderMetadata>) -> Vec<JsHeaderMetadata> {
     headers
@@ -635,6 +1174,31 @@ fn convert_structured_data(data: Vec<RustStructuredData>) -> Vec<JsStructuredDat
         .collect()
 }
 
+#[cfg(feature = "metadata")]
+fn convert_resources(resources: Vec<RustResourceMetadata>) -> Vec<JsResourceMetadata> {
+    resources
+        .into_iter()
+        .map(|r| JsResourceMetadata {
+            url: r.url,
+            resource_type: r.resource_type.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(feature = "metadata")]
+fn convert_source_map(source_map: Vec<RustSourceMapEntry>) -> Vec<JsSourceMapEntry> {
+    source_map
+        .into_iter()
+        .map(|e| JsSourceMapEntry {
+            tag: e.tag,
+            html_start: e.html_start as u32,
+            html_end: e.html_end as u32,
+            markdown_start: e.markdown_start as u32,
+            markdown_end: e.markdown_end as u32,
+        })
+        .collect()
+}
+
 #[cfg(feature = "metadata")]
 fn convert_metadata(metadata: RustExtendedMetadata) -> JsExtendedMetadata {
     JsExtendedMetadata {
@@ -643,6 +1207,8 @@ fn convert_metadata(metadata: RustExtendedMetadata) -> JsExtendedMetadata {
         links: convert_links(metadata.links),
         images: convert_images(metadata.images),
         structured_data: convert_structured_data(metadata.structured_data),
+        resources: convert_resources(metadata.resources),
+        source_map: convert_source_map(metadata.source_map),
     }
 }
 
@@ -671,54 +1237,80 @@ pub struct JsVisitResult {
     #[napi(js_name = "type")]
     pub result_type: String,
     pub output: Option<String>,
+    pub message: Option<String>,
 }
 
 /// NAPI-RS `AsyncHtmlVisitor` Bridge Implementation
 ///
 /// # Architecture
 ///
-/// This bridge enables full async visitor pattern support for Node.js by:
+/// This bridge enables visitor callbacks for Node.js by:
 /// 1. Accepting JavaScript visitor objects at the NAPI boundary
 /// 2. Wrapping JS callbacks as NAPI `ThreadsafeFunction` references
-/// 3. Implementing `AsyncHtmlVisitor` trait with proper .await on JS calls
-/// 4. Executing the async conversion pipeline via tokio runtime
+/// 3. Running the conversion on a libuv worker thread (`AsyncTask`)
+/// 4. Queuing each callback onto the JS thread and awaiting its (possibly async) result
 ///
 /// # Key Design Decisions
 ///
 /// - **Feature Gate**: Uses `async-visitor` feature (not `visitor`)
 /// - **`ThreadsafeFunction`**: Stores Arc<ThreadsafeFunction> for each visitor method
-/// - **Async Methods**: All visitor methods are `async fn` to properly .await on JS calls
-/// - **Runtime**: Uses `tokio::runtime` to `block_on` the async conversion
-/// - **Error Handling**: JS callback errors default to `VisitResult::Continue`
+/// - **Worker Thread**: The JS thread stays free to run callbacks while conversion blocks
+/// - **Return Values**: Callbacks may return a result object, a Promise of one, or nothing
+/// - **Error Handling**: Thrown errors and rejected promises become `VisitResult::Error`
 ///
 /// # JavaScript Integration
 ///
-/// From JavaScript, pass a visitor object with optional async methods:
+/// From JavaScript, pass a visitor object with optional (sync or async) methods:
 /// ```javascript
 /// const visitor = {
 ///   visitText: async (ctx, text) => ({ type: 'continue' }),
-///   visitLink: async (ctx, href, text, title) => ({ type: 'continue' }),
+///   visitLink: (ctx, href, text, title) => ({ type: 'custom', output: text }),
 ///   // ... other methods as needed
 /// };
 /// ```
-///
-/// # Type alias for `ThreadsafeFunction`
-///
-/// Each visitor method callback is wrapped as an Arc-based `ThreadsafeFunction`
-/// that accepts a String parameter and returns a Promise<String>.
 #[cfg(feature = "async-visitor")]
-type VisitorFn = Arc<
-    napi::threadsafe_function::ThreadsafeFunction<
-        String,
-        napi::bindgen_prelude::Promise<String>,
-        String,
-        napi::Status,
-        false,
-    >,
->;
+type VisitorFn =
+    Arc<napi::threadsafe_function::ThreadsafeFunction<VisitorArgs, VisitorReturn, VisitorArgs, napi::Status, false>>;
+
+/// Value returned by a JS visitor callback: a result object (or nothing), possibly wrapped in a Promise.
+#[cfg(feature = "async-visitor")]
+type VisitorReturn = Either<Promise<Option<JsVisitResult>>, Option<JsVisitResult>>;
+
+/// A single argument passed to a JS visitor callback.
+#[cfg(feature = "async-visitor")]
+enum VisitorArg {
+    Context(JsNodeContext),
+    Str(String),
+    OptStr(Option<String>),
+    Strings(Vec<String>),
+    Bool(bool),
+    Number(u32),
+}
+
+/// Positional arguments for a JS visitor callback (arity differs per method).
+#[cfg(feature = "async-visitor")]
+struct VisitorArgs(Vec<VisitorArg>);
+
+#[cfg(feature = "async-visitor")]
+impl JsValuesTupleIntoVec for VisitorArgs {
+    fn into_vec(self, env: napi::sys::napi_env) -> Result<Vec<napi::sys::napi_value>> {
+        self.0
+            .into_iter()
+            .map(|arg| unsafe {
+                match arg {
+                    VisitorArg::Context(ctx) => JsNodeContext::to_napi_value(env, ctx),
+                    VisitorArg::Str(value) => String::to_napi_value(env, value),
+                    VisitorArg::OptStr(value) => Option::<String>::to_napi_value(env, value),
+                    VisitorArg::Strings(values) => Vec::<String>::to_napi_value(env, values),
+                    VisitorArg::Bool(value) => bool::to_napi_value(env, value),
+                    VisitorArg::Number(value) => u32::to_napi_value(env, value),
+                }
+            })
+            .collect()
+    }
+}
 
 #[cfg(feature = "async-visitor")]
-#[allow(dead_code)]
 #[derive(Clone)]
 struct JsVisitorBridge {
     visit_element_start_fn: Option<VisitorFn>,
@@ -770,12 +1362,6 @@ impl std::fmt::Debug for JsVisitorBridge {
     }
 }
 
-#[cfg(feature = "async-visitor")]
-unsafe impl Send for JsVisitorBridge {}
-
-#[cfg(feature = "async-visitor")]
-unsafe impl Sync for JsVisitorBridge {}
-
 #[cfg(feature = "async-visitor")]
 impl JsVisitorBridge {
     const fn new() -> Self {
@@ -823,7 +1409,6 @@ impl JsVisitorBridge {
         }
     }
 
-    #[allow(dead_code)]
     fn node_context_to_js(ctx: &RustNodeContext) -> JsNodeContext {
         let mut attributes = HashMap::new();
         for (k, v) in &ctx.attributes {
@@ -841,262 +1426,480 @@ impl JsVisitorBridge {
         }
     }
 
-    #[allow(dead_code)]
-    fn visit_result_from_js(js_result: &JsVisitResult) -> RustVisitResult {
-        match js_result.result_type.to_lowercase().as_str() {
-            "continue" => RustVisitResult::Continue,
-            "custom" => RustVisitResult::Custom(js_result.output.clone().unwrap_or_default()),
+    fn visit_result_from_js(js_result: Option<JsVisitResult>) -> RustVisitResult {
+        let Some(js_result) = js_result else {
+            return RustVisitResult::Continue;
+        };
+        let kind = js_result.result_type.to_lowercase().replace(['_', '-'], "");
+        match kind.as_str() {
+            "custom" => RustVisitResult::Custom(js_result.output.unwrap_or_default()),
             "skip" => RustVisitResult::Skip,
             "preservehtml" => RustVisitResult::PreserveHtml,
-            "error" => RustVisitResult::Error(js_result.output.clone().unwrap_or_else(|| "Unknown error".to_string())),
+            "error" => RustVisitResult::Error(
+                js_result
+                    .message
+                    .or(js_result.output)
+                    .unwrap_or_else(|| "Unknown error".to_string()),
+            ),
             _ => RustVisitResult::Continue,
         }
     }
 
-    /// Serialize visitor parameters to JSON string
-    ///
-    /// # Arguments
-    ///
-    /// * `params` - Parameters implementing `serde::Serialize` trait
-    ///
-    /// # Returns
-    ///
-    /// JSON string representation or `serde_json` error
-    ///
-    /// # Example
+    /// Queue a callback onto the JS thread and wait for its result.
     ///
-    /// ```ignore
-    /// let ctx = JsNodeContext { /* ... */ };
-    /// let json = JsVisitorBridge::serialize_params(&ctx)?;
-    /// ```
-    #[allow(dead_code)]
-    fn serialize_params<T: serde::Serialize>(params: &T) -> std::result::Result<String, serde_json::Error> {
-        serde_json::to_string(params)
-    }
+    /// Missing callbacks continue with the default conversion without crossing into JS.
+    async fn dispatch(callback: Option<&VisitorFn>, args: impl FnOnce() -> Vec<VisitorArg>) -> RustVisitResult {
+        let Some(callback) = callback else {
+            return RustVisitResult::Continue;
+        };
 
-    /// Deserialize visitor result from JSON string
-    ///
-    /// # Arguments
-    ///
-    /// * `json` - JSON string representation of `JsVisitResult`
-    ///
-    /// # Returns
-    ///
-    /// Deserialized `JsVisitResult` or `serde_json` error
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// let json = r#"{"type":"continue","output":null}"#;
-    /// let result = JsVisitorBridge::deserialize_result(json)?;
-    /// ```
-    #[allow(dead_code)]
-    fn deserialize_result(json: &str) -> std::result::Result<JsVisitResult, serde_json::Error> {
-        serde_json::from_str(json)
+        let returned = match callback.call_async_catch(VisitorArgs(args())).await {
+            Ok(Either::A(promise)) => promise.await,
+            Ok(Either::B(result)) => Ok(result),
+            Err(err) => Err(err),
+        };
+
+        match returned {
+            Ok(result) => Self::visit_result_from_js(result),
+            Err(err) => RustVisitResult::Error(format!("visitor callback failed: {}", err.reason)),
+        }
     }
 }
 
 #[cfg(feature = "async-visitor")]
 #[async_trait]
 impl AsyncHtmlVisitor for JsVisitorBridge {
-    async fn visit_element_start(&mut self, _ctx: &RustNodeContext) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_element_start(&mut self, ctx: &RustNodeContext) -> RustVisitResult {
+        Self::dispatch(self.visit_element_start_fn.as_ref(), || {
+            vec![VisitorArg::Context(Self::node_context_to_js(ctx))]
+        })
+        .await
     }
 
-    async fn visit_element_end(&mut self, _ctx: &RustNodeContext, _output: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_element_end(&mut self, ctx: &RustNodeContext, output: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_element_end_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(output.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_text(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_text(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_text_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
     async fn visit_link(
         &mut self,
-        _ctx: &RustNodeContext,
-        _href: &str,
-        _text: &str,
-        _title: Option<&str>,
+        ctx: &RustNodeContext,
+        href: &str,
+        text: &str,
+        title: Option<&str>,
     ) -> RustVisitResult {
-        RustVisitResult::Continue
+        Self::dispatch(self.visit_link_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(href.to_string()),
+                VisitorArg::Str(text.to_string()),
+                VisitorArg::OptStr(title.map(str::to_string)),
+            ]
+        })
+        .await
     }
 
     async fn visit_image(
         &mut self,
-        _ctx: &RustNodeContext,
-        _src: &str,
-        _alt: &str,
-        _title: Option<&str>,
+        ctx: &RustNodeContext,
+        src: &str,
+        alt: &str,
+        title: Option<&str>,
     ) -> RustVisitResult {
-        RustVisitResult::Continue
+        Self::dispatch(self.visit_image_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(src.to_string()),
+                VisitorArg::Str(alt.to_string()),
+                VisitorArg::OptStr(title.map(str::to_string)),
+            ]
+        })
+        .await
     }
 
     async fn visit_heading(
         &mut self,
-        _ctx: &RustNodeContext,
-        _level: u32,
-        _text: &str,
-        _id: Option<&str>,
+        ctx: &RustNodeContext,
+        level: u32,
+        text: &str,
+        id: Option<&str>,
     ) -> RustVisitResult {
-        RustVisitResult::Continue
+        Self::dispatch(self.visit_heading_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Number(level),
+                VisitorArg::Str(text.to_string()),
+                VisitorArg::OptStr(id.map(str::to_string)),
+            ]
+        })
+        .await
     }
 
-    async fn visit_code_block(&mut self, _ctx: &RustNodeContext, _lang: Option<&str>, _code: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_code_block(&mut self, ctx: &RustNodeContext, lang: Option<&str>, code: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_code_block_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::OptStr(lang.map(str::to_string)),
+                VisitorArg::Str(code.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_code_inline(&mut self, _ctx: &RustNodeContext, _code: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_code_inline(&mut self, ctx: &RustNodeContext, code: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_code_inline_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(code.to_string()),
+            ]
+        })
+        .await
     }
 
     async fn visit_list_item(
         &mut self,
-        _ctx: &RustNodeContext,
-        _ordered: bool,
-        _marker: &str,
-        _text: &str,
+        ctx: &RustNodeContext,
+        ordered: bool,
+        marker: &str,
+        text: &str,
     ) -> RustVisitResult {
-        RustVisitResult::Continue
+        Self::dispatch(self.visit_list_item_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Bool(ordered),
+                VisitorArg::Str(marker.to_string()),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_list_start(&mut self, _ctx: &RustNodeContext, _ordered: bool) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_list_start(&mut self, ctx: &RustNodeContext, ordered: bool) -> RustVisitResult {
+        Self::dispatch(self.visit_list_start_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Bool(ordered),
+            ]
+        })
+        .await
     }
 
-    async fn visit_list_end(&mut self, _ctx: &RustNodeContext, _ordered: bool, _output: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_list_end(&mut self, ctx: &RustNodeContext, ordered: bool, output: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_list_end_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Bool(ordered),
+                VisitorArg::Str(output.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_table_start(&mut self, _ctx: &RustNodeContext) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_table_start(&mut self, ctx: &RustNodeContext) -> RustVisitResult {
+        Self::dispatch(self.visit_table_start_fn.as_ref(), || {
+            vec![VisitorArg::Context(Self::node_context_to_js(ctx))]
+        })
+        .await
     }
 
-    async fn visit_table_row(
-        &mut self,
-        _ctx: &RustNodeContext,
-        _cells: &[String],
-        _is_header: bool,
-    ) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_table_row(&mut self, ctx: &RustNodeContext, cells: &[String], is_header: bool) -> RustVisitResult {
+        Self::dispatch(self.visit_table_row_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Strings(cells.to_vec()),
+                VisitorArg::Bool(is_header),
+            ]
+        })
+        .await
     }
 
-    async fn visit_table_end(&mut self, _ctx: &RustNodeContext, _output: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_table_end(&mut self, ctx: &RustNodeContext, output: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_table_end_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(output.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_blockquote(&mut self, _ctx: &RustNodeContext, _content: &str, _depth: usize) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_blockquote(&mut self, ctx: &RustNodeContext, content: &str, depth: usize) -> RustVisitResult {
+        Self::dispatch(self.visit_blockquote_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(content.to_string()),
+                VisitorArg::Number(u32::try_from(depth).unwrap_or(u32::MAX)),
+            ]
+        })
+        .await
     }
 
-    async fn visit_strong(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_strong(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_strong_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_emphasis(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_emphasis(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_emphasis_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_strikethrough(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_strikethrough(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_strikethrough_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_underline(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_underline(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_underline_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_subscript(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_subscript(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_subscript_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_superscript(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_superscript(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_superscript_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_mark(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_mark(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_mark_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_line_break(&mut self, _ctx: &RustNodeContext) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_line_break(&mut self, ctx: &RustNodeContext) -> RustVisitResult {
+        Self::dispatch(self.visit_line_break_fn.as_ref(), || {
+            vec![VisitorArg::Context(Self::node_context_to_js(ctx))]
+        })
+        .await
     }
 
-    async fn visit_horizontal_rule(&mut self, _ctx: &RustNodeContext) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_horizontal_rule(&mut self, ctx: &RustNodeContext) -> RustVisitResult {
+        Self::dispatch(self.visit_horizontal_rule_fn.as_ref(), || {
+            vec![VisitorArg::Context(Self::node_context_to_js(ctx))]
+        })
+        .await
     }
 
-    async fn visit_custom_element(&mut self, _ctx: &RustNodeContext, _tag_name: &str, _html: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_custom_element(&mut self, ctx: &RustNodeContext, tag_name: &str, html: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_custom_element_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(tag_name.to_string()),
+                VisitorArg::Str(html.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_definition_list_start(&mut self, _ctx: &RustNodeContext) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_definition_list_start(&mut self, ctx: &RustNodeContext) -> RustVisitResult {
+        Self::dispatch(self.visit_definition_list_start_fn.as_ref(), || {
+            vec![VisitorArg::Context(Self::node_context_to_js(ctx))]
+        })
+        .await
     }
 
-    async fn visit_definition_term(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_definition_term(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_definition_term_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_definition_description(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_definition_description(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_definition_description_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_definition_list_end(&mut self, _ctx: &RustNodeContext, _output: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_definition_list_end(&mut self, ctx: &RustNodeContext, output: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_definition_list_end_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(output.to_string()),
+            ]
+        })
+        .await
     }
 
     async fn visit_form(
         &mut self,
-        _ctx: &RustNodeContext,
-        _action: Option<&str>,
-        _method: Option<&str>,
+        ctx: &RustNodeContext,
+        action: Option<&str>,
+        method: Option<&str>,
     ) -> RustVisitResult {
-        RustVisitResult::Continue
+        Self::dispatch(self.visit_form_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::OptStr(action.map(str::to_string)),
+                VisitorArg::OptStr(method.map(str::to_string)),
+            ]
+        })
+        .await
     }
 
     async fn visit_input(
         &mut self,
-        _ctx: &RustNodeContext,
-        _input_type: &str,
-        _name: Option<&str>,
-        _value: Option<&str>,
+        ctx: &RustNodeContext,
+        input_type: &str,
+        name: Option<&str>,
+        value: Option<&str>,
     ) -> RustVisitResult {
-        RustVisitResult::Continue
+        Self::dispatch(self.visit_input_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(input_type.to_string()),
+                VisitorArg::OptStr(name.map(str::to_string)),
+                VisitorArg::OptStr(value.map(str::to_string)),
+            ]
+        })
+        .await
     }
 
-    async fn visit_button(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_button(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_button_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_audio(&mut self, _ctx: &RustNodeContext, _src: Option<&str>) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_audio(&mut self, ctx: &RustNodeContext, src: Option<&str>) -> RustVisitResult {
+        Self::dispatch(self.visit_audio_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::OptStr(src.map(str::to_string)),
+            ]
+        })
+        .await
     }
 
-    async fn visit_video(&mut self, _ctx: &RustNodeContext, _src: Option<&str>) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_video(&mut self, ctx: &RustNodeContext, src: Option<&str>) -> RustVisitResult {
+        Self::dispatch(self.visit_video_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::OptStr(src.map(str::to_string)),
+            ]
+        })
+        .await
     }
 
-    async fn visit_iframe(&mut self, _ctx: &RustNodeContext, _src: Option<&str>) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_iframe(&mut self, ctx: &RustNodeContext, src: Option<&str>) -> RustVisitResult {
+        Self::dispatch(self.visit_iframe_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::OptStr(src.map(str::to_string)),
+            ]
+        })
+        .await
     }
 
-    async fn visit_details(&mut self, _ctx: &RustNodeContext, _open: bool) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_details(&mut self, ctx: &RustNodeContext, open: bool) -> RustVisitResult {
+        Self::dispatch(self.visit_details_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Bool(open),
+            ]
+        })
+        .await
     }
 
-    async fn visit_summary(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_summary(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_summary_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_figure_start(&mut self, _ctx: &RustNodeContext) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_figure_start(&mut self, ctx: &RustNodeContext) -> RustVisitResult {
+        Self::dispatch(self.visit_figure_start_fn.as_ref(), || {
+            vec![VisitorArg::Context(Self::node_context_to_js(ctx))]
+        })
+        .await
     }
 
-    async fn visit_figcaption(&mut self, _ctx: &RustNodeContext, _text: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_figcaption(&mut self, ctx: &RustNodeContext, text: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_figcaption_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(text.to_string()),
+            ]
+        })
+        .await
     }
 
-    async fn visit_figure_end(&mut self, _ctx: &RustNodeContext, _output: &str) -> RustVisitResult {
-        RustVisitResult::Continue
+    async fn visit_figure_end(&mut self, ctx: &RustNodeContext, output: &str) -> RustVisitResult {
+        Self::dispatch(self.visit_figure_end_fn.as_ref(), || {
+            vec![
+                VisitorArg::Context(Self::node_context_to_js(ctx)),
+                VisitorArg::Str(output.to_string()),
+            ]
+        })
+        .await
     }
 }
 
@@ -1115,7 +1918,7 @@ impl AsyncHtmlVisitor for JsVisitorBridge {
 /// ```
 #[napi]
 pub fn convert(html: String, options: Option<JsConversionOptions>, visitor: Option<Object>) -> Result<String> {
-    let rust_options = options.map(Into::into);
+    let rust_options = validated_options(options)?;
 
     #[cfg(feature = "visitor")]
     if visitor.is_some() {
@@ -1132,15 +1935,294 @@ pub fn convert(html: String, options: Option<JsConversionOptions>, visitor: Opti
         .map_err(to_js_error)
 }
 
+/// Convert HTML to Markdown on the libuv thread pool.
+///
+/// Same as `convert`, but the conversion runs off the main thread and the result is
+/// delivered through a Promise, so large documents don't block the event loop.
+///
+/// # Example
+///
+/// ```javascript
+/// const { convertAsync } = require('@kreuzberg/html-to-markdown-node');
+///
+/// const markdown = await convertAsync('<h1>Hello World</h1>');
+/// ```
+#[napi(js_name = "convertAsync")]
+pub fn convert_async(html: String, options: Option<JsConversionOptions>) -> Result<AsyncTask<ConvertTask>> {
+    Ok(AsyncTask::new(ConvertTask {
+        html,
+        options: validated_options(options)?,
+    }))
+}
+
+/// Background task behind `convertAsync`.
+pub struct ConvertTask {
+    html: String,
+    options: Option<RustConversionOptions>,
+}
+
+impl Task for ConvertTask {
+    type Output = String;
+    type JsValue = String;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let html = std::mem::take(&mut self.html);
+        let options = self.options.take();
+        guard_panic(move || html_to_markdown_rs::convert(&html, options)).map_err(to_js_error)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Read an HTML file and convert it to Markdown on the libuv thread pool.
+///
+/// The file is read in Rust, so the document never exists as a JavaScript string; for very
+/// large inputs this roughly halves peak memory compared to `readFile` followed by
+/// `convertAsync`. The file must be UTF-8.
+///
+/// # Example
+///
+/// ```javascript
+/// const { convertFile } = require('@kreuzberg/html-to-markdown-node');
+///
+/// const markdown = await convertFile('./dump.html', { headingStyle: 'Atx' });
+/// ```
+#[napi(js_name = "convertFile")]
+pub fn convert_file(path: String, options: Option<JsConversionOptions>) -> Result<AsyncTask<ConvertFileTask>> {
+    Ok(AsyncTask::new(ConvertFileTask {
+        path,
+        options: validated_options(options)?,
+    }))
+}
+
+/// Background task behind `convertFile`.
+pub struct ConvertFileTask {
+    path: String,
+    options: Option<RustConversionOptions>,
+}
+
+impl Task for ConvertFileTask {
+    type Output = String;
+    type JsValue = String;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let bytes = std::fs::read(&self.path)
+            .map_err(|e| Error::new(Status::GenericFailure, format!("Failed to read {}: {e}", self.path)))?;
+        let html = String::from_utf8(bytes)
+            .map_err(|_| Error::new(Status::InvalidArg, format!("{} is not valid UTF-8", self.path)))?;
+        let options = self.options.take();
+        guard_panic(move || html_to_markdown_rs::convert(&html, options)).map_err(to_js_error)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// HTTP settings for `convertUrl`
+#[cfg(feature = "fetch")]
+#[napi(object)]
+#[derive(Default)]
+pub struct JsFetchOptions {
+    /// User-Agent header (default "html-to-markdown/<version>")
+    pub user_agent: Option<String>,
+    /// Per-request timeout in milliseconds (default 15000)
+    pub timeout_ms: Option<u32>,
+    /// Retries after a transient failure such as a timeout, 429 or 5xx (default 2)
+    pub max_retries: Option<u32>,
+    /// Redirects followed before giving up (default 5)
+    pub max_redirects: Option<u32>,
+}
+
+#[cfg(feature = "fetch")]
+impl From<JsFetchOptions> for html_to_markdown_rs::fetch::FetchConfig {
+    fn from(val: JsFetchOptions) -> Self {
+        let defaults = Self::default();
+        Self {
+            user_agent: val.user_agent.unwrap_or(defaults.user_agent),
+            timeout: val
+                .timeout_ms
+                .map_or(defaults.timeout, |ms| std::time::Duration::from_millis(u64::from(ms))),
+            max_retries: val.max_retries.unwrap_or(defaults.max_retries),
+            max_redirects: val.max_redirects.map_or(defaults.max_redirects, |max| max as usize),
+            ..defaults
+        }
+    }
+}
+
+/// Download a page and convert it to Markdown on the libuv thread pool.
+///
+/// The request is made in Rust with retries on transient failures, and the body is decoded
+/// using the charset from the `Content-Type` header or the page's `<meta charset>`. Nothing
+/// but the Markdown crosses into JavaScript.
+///
+/// # Example
+///
+/// ```javascript
+/// const { convertUrl } = require('@kreuzberg/html-to-markdown-node');
+///
+/// const markdown = await convertUrl('https://example.com/', null, { timeoutMs: 10000 });
+/// ```
+#[cfg(feature = "fetch")]
+#[napi(js_name = "convertUrl")]
+pub fn convert_url(
+    url: String,
+    options: Option<JsConversionOptions>,
+    fetch_options: Option<JsFetchOptions>,
+) -> Result<AsyncTask<ConvertUrlTask>> {
+    Ok(AsyncTask::new(ConvertUrlTask {
+        url,
+        options: validated_options(options)?,
+        config: Some(fetch_options.unwrap_or_default().into()),
+    }))
+}
+
+/// Background task behind `convertUrl`.
+#[cfg(feature = "fetch")]
+pub struct ConvertUrlTask {
+    url: String,
+    options: Option<RustConversionOptions>,
+    config: Option<html_to_markdown_rs::fetch::FetchConfig>,
+}
+
+#[cfg(feature = "fetch")]
+impl Task for ConvertUrlTask {
+    type Output = String;
+    type JsValue = String;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let fetch_error = |e: html_to_markdown_rs::fetch::FetchError| {
+            Error::new(Status::GenericFailure, format!("Failed to fetch {}: {e}", self.url))
+        };
+        let fetcher =
+            html_to_markdown_rs::fetch::Fetcher::new(self.config.take().unwrap_or_default()).map_err(fetch_error)?;
+        let response = fetcher.fetch(&self.url).map_err(fetch_error)?;
+        let html = html_to_markdown_rs::encoding::decode_html(&response.body, response.charset());
+        let options = self.options.take();
+        guard_panic(move || html_to_markdown_rs::convert(&html, options)).map_err(to_js_error)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Progress of a running conversion, passed to the `convertWithProgress` callback
+#[napi(object)]
+#[derive(Clone, Copy)]
+pub struct JsProgressEvent {
+    /// DOM nodes visited so far
+    pub nodes_processed: i64,
+    /// DOM nodes in the parsed document
+    pub total_nodes: i64,
+    /// Furthest position reached in the parsed document
+    pub bytes_processed: i64,
+    /// Size of the parsed document in bytes
+    pub total_bytes: i64,
+    /// Approximate share of the document converted, from 0 to 1
+    pub fraction: f64,
+    /// Whether this is the final event
+    pub done: bool,
+}
+
+impl From<html_to_markdown_rs::ProgressEvent> for JsProgressEvent {
+    fn from(event: html_to_markdown_rs::ProgressEvent) -> Self {
+        Self {
+            nodes_processed: event.nodes_processed as i64,
+            total_nodes: event.total_nodes as i64,
+            bytes_processed: event.bytes_processed as i64,
+            total_bytes: event.total_bytes as i64,
+            fraction: event.fraction(),
+            done: event.is_done(),
+        }
+    }
+}
+
+/// DOM nodes between progress callbacks when `convertWithProgress` is not given an interval.
+const DEFAULT_PROGRESS_INTERVAL: usize = 1024;
+
+type ProgressFn = napi::threadsafe_function::ThreadsafeFunction<JsProgressEvent, (), JsProgressEvent, Status, false>;
+
+/// Convert HTML to Markdown on the libuv thread pool, reporting progress as it goes.
+///
+/// `onProgress` is called on the main thread once the document is parsed, every `interval`
+/// DOM nodes (default 1024) and once more with `done` set before the Promise resolves. Calls
+/// are queued without blocking the conversion, so a slow callback never stalls it.
+///
+/// # Example
+///
+/// ```javascript
+/// const { convertWithProgress } = require('@kreuzberg/html-to-markdown-node');
+///
+/// const markdown = await convertWithProgress(html, null, (event) => {
+///   progressBar.value = event.fraction;
+/// });
+/// ```
+#[napi(js_name = "convertWithProgress")]
+pub fn convert_with_progress(
+    html: String,
+    options: Option<JsConversionOptions>,
+    on_progress: Function<JsProgressEvent, ()>,
+    interval: Option<u32>,
+) -> Result<AsyncTask<ConvertWithProgressTask>> {
+    let options = validated_options(options)?;
+    let on_progress = on_progress
+        .build_threadsafe_function::<JsProgressEvent>()
+        .callee_handled::<false>()
+        .build()?;
+    Ok(AsyncTask::new(ConvertWithProgressTask {
+        html,
+        options,
+        interval: interval.map_or(DEFAULT_PROGRESS_INTERVAL, |interval| interval as usize),
+        on_progress: Arc::new(on_progress),
+    }))
+}
+
+/// Background task behind `convertWithProgress`.
+pub struct ConvertWithProgressTask {
+    html: String,
+    options: Option<RustConversionOptions>,
+    interval: usize,
+    on_progress: Arc<ProgressFn>,
+}
+
+impl Task for ConvertWithProgressTask {
+    type Output = String;
+    type JsValue = String;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let html = std::mem::take(&mut self.html);
+        let options = self.options.take();
+        let interval = self.interval;
+        let on_progress = Arc::clone(&self.on_progress);
+        guard_panic(move || {
+            html_to_markdown_rs::convert_with_progress(&html, options, interval, move |event| {
+                on_progress.call(
+                    event.into(),
+                    napi::threadsafe_function::ThreadsafeFunctionCallMode::NonBlocking,
+                );
+            })
+        })
+        .map_err(to_js_error)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
 /// Convert HTML to Markdown with an async visitor object.
 ///
 /// # Async Visitor Support
 ///
 /// This function enables full async visitor pattern support for Node.js:
-/// - JavaScript visitor callbacks are invoked asynchronously via NAPI `ThreadsafeFunction`
-/// - All 30+ visitor methods are supported (links, images, headings, code, lists, tables, etc.)
-/// - Callback errors gracefully default to `VisitResult::Continue`
-/// - Powered by tokio async runtime for seamless JS-Rust cooperation
+/// - Conversion runs on a worker thread and the returned Promise resolves with the Markdown
+/// - JavaScript visitor callbacks are invoked on the JS thread via NAPI `ThreadsafeFunction`
+/// - Callbacks may be sync or async; returning nothing continues with the default output
+/// - All 40 visitor methods are supported (links, images, headings, code, lists, tables, etc.)
+/// - A thrown error or rejected promise rejects the conversion
 ///
 /// # Visitor Methods
 ///
@@ -1159,8 +2241,9 @@ pub fn convert(html: String, options: Option<JsConversionOptions>, visitor: Opti
 /// # `VisitResult` Types
 ///
 /// Each callback should return an object with:
-/// - `type: 'continue' | 'skip' | 'custom' | 'preservehtml' | 'error'`
-/// - `output?: string` (required for 'custom' and 'error' types)
+/// - `type: 'continue' | 'skip' | 'custom' | 'preserve_html' | 'error'`
+/// - `output?: string` (required for 'custom')
+/// - `message?: string` (error message for 'error')
 ///
 /// # Arguments
 ///
@@ -1189,21 +2272,20 @@ pub fn convert(html: String, options: Option<JsConversionOptions>, visitor: Opti
 #[cfg(feature = "async-visitor")]
 #[napi(js_name = "convertWithVisitor")]
 pub fn convert_with_visitor(
-    _env: Env,
     html: String,
     options: Option<JsConversionOptions>,
     visitor: Object,
-) -> napi::Result<String> {
-    let rust_options = options.map(Into::into);
+) -> napi::Result<AsyncTask<ConvertWithVisitorTask>> {
+    let rust_options = validated_options(options)?;
 
     let mut bridge = JsVisitorBridge::new();
 
     macro_rules! extract_fn {
         ($method_name:literal, $field:ident) => {
-            if let Ok(func) = visitor.get_named_property::<Function<String, Promise<String>>>($method_name) {
+            if let Ok(func) = visitor.get_named_property::<Function<VisitorArgs, VisitorReturn>>($method_name) {
                 if let Ok(tsfn) = func
-                    .build_threadsafe_function::<String>()
-                    .build_callback(|ctx: napi::threadsafe_function::ThreadsafeCallContext<String>| Ok(ctx.value))
+                    .build_threadsafe_function::<VisitorArgs>()
+                    .build_callback(|ctx: napi::threadsafe_function::ThreadsafeCallContext<VisitorArgs>| Ok(ctx.value))
                 {
                     bridge.$field = Some(Arc::new(tsfn));
                 }
@@ -1252,23 +2334,139 @@ pub fn convert_with_visitor(
     extract_fn!("visitFigcaption", visit_figcaption_fn);
     extract_fn!("visitFigureEnd", visit_figure_end_fn);
 
-    let rt = tokio::runtime::Builder::new_current_thread()
-        .enable_all()
-        .build()
-        .map_err(|e| napi::Error::new(napi::Status::GenericFailure, format!("Failed to create runtime: {e}")))?;
-
-    let result = rt
-        .block_on(async {
-            html_to_markdown_rs::convert_with_async_visitor(
-                &html,
-                rust_options,
-                Some(std::rc::Rc::new(std::cell::RefCell::new(bridge))),
-            )
-            .await
+    Ok(AsyncTask::new(ConvertWithVisitorTask {
+        html,
+        options: rust_options,
+        visitor: bridge,
+    }))
+}
+
+/// Background conversion driving a JS visitor from a libuv worker thread.
+#[cfg(feature = "async-visitor")]
+pub struct ConvertWithVisitorTask {
+    html: String,
+    options: Option<RustConversionOptions>,
+    visitor: JsVisitorBridge,
+}
+
+#[cfg(feature = "async-visitor")]
+impl Task for ConvertWithVisitorTask {
+    type Output = String;
+    type JsValue = String;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .map_err(|e| napi::Error::new(napi::Status::GenericFailure, format!("Failed to create runtime: {e}")))?;
+
+        rt.block_on(html_to_markdown_rs::visitor::convert_with_visitor_async(
+            &self.html,
+            self.options.take(),
+            &mut self.visitor,
+        ))
+        .map_err(to_js_error)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Convert many HTML documents in a single call.
+///
+/// All documents share `options`, and the Markdown is returned in input order. Batching
+/// avoids crossing the N-API boundary once per document in crawler-style pipelines. If a
+/// document fails, an error naming its index is thrown.
+///
+/// # Example
+///
+/// ```javascript
+/// const { convertBatch } = require('@kreuzberg/html-to-markdown-node');
+///
+/// const [first, second] = convertBatch(['<h1>One</h1>', '<p>Two</p>']);
+/// ```
+#[napi(js_name = "convertBatch")]
+pub fn convert_batch(htmls: Vec<String>, options: Option<JsConversionOptions>) -> Result<Vec<String>> {
+    let rust_options = validated_options(options)?;
+    html_to_markdown_rs::convert_many(&htmls, rust_options)
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| {
+            result.map_err(|err| {
+                let err = to_js_error(err);
+                Error::new(err.status, format!("document {index}: {}", err.reason))
+            })
         })
-        .map_err(to_js_error)?;
+        .collect()
+}
+
+/// Convert HTML to Markdown and report the resources the conversion used.
+///
+/// `stats` is only filled in when `collectStats` is set in the options.
+///
+/// # Example
+///
+/// ```javascript
+/// const { convertWithStats } = require('@kreuzberg/html-to-markdown-node');
+///
+/// const { markdown, stats } = convertWithStats(html, { collectStats: true });
+/// console.log(stats.nodesParsed, stats.totalMs);
+/// ```
+#[napi(js_name = "convertWithStats")]
+pub fn convert_with_stats(html: String, options: Option<JsConversionOptions>) -> Result<JsStatsResult> {
+    let rust_options = validated_options(options)?;
+    let (markdown, stats) = guard_panic(|| {
+        profiling::maybe_profile(|| html_to_markdown_rs::convert_with_stats(&html, rust_options.clone()))
+    })
+    .map_err(to_js_error)?;
+    Ok(JsStatsResult {
+        markdown,
+        stats: stats.map(Into::into),
+    })
+}
+
+/// Convert HTML to Markdown and fingerprint the result for deduplication.
+///
+/// `fingerprint` is only filled in when `contentFingerprint` is set in the options. It ignores
+/// whitespace, markup and link targets, so mirrors of the same page share it.
+///
+/// # Example
+///
+/// ```javascript
+/// const { convertWithFingerprint } = require('@kreuzberg/html-to-markdown-node');
+///
+/// const { markdown, fingerprint } = convertWithFingerprint(html, { contentFingerprint: true });
+/// if (seen.has(fingerprint)) return;
+/// ```
+#[napi(js_name = "convertWithFingerprint")]
+pub fn convert_with_fingerprint(html: String, options: Option<JsConversionOptions>) -> Result<JsFingerprintResult> {
+    let rust_options = validated_options(options)?;
+    let (markdown, fingerprint) = guard_panic(|| {
+        profiling::maybe_profile(|| html_to_markdown_rs::convert_with_fingerprint(&html, rust_options.clone()))
+    })
+    .map_err(to_js_error)?;
+    Ok(JsFingerprintResult { markdown, fingerprint })
+}
 
-    Ok(result)
+/// Classify HTML input as `"html"`, `"hocr"`, `"alto"`, `"mhtml"`, `"warc"`, `"xml"` or `"text"`.
+///
+/// Uses the same heuristics as the converter, so a pipeline can pick options (such as
+/// `hocrSpatialTables`) before converting.
+#[napi(js_name = "detectInputFormat")]
+pub fn detect_input_format(html: String) -> String {
+    html_to_markdown_rs::detect_input_format(&html).as_str().to_string()
+}
+
+/// List the option names accepted by the JSON entry points, in their camelCase spelling.
+///
+/// Those entry points reject any other key; add `"strictOptions": false` to the options JSON to
+/// have unknown keys ignored instead.
+#[napi(js_name = "knownOptions")]
+pub fn known_options() -> Vec<String> {
+    ConversionOptionsUpdate::field_names()
+        .iter()
+        .map(ToString::to_string)
+        .collect()
 }
 
 #[napi(js_name = "convertJson")]
@@ -1297,7 +2495,7 @@ fn buffer_to_str(html: &Buffer) -> Result<&str> {
 #[napi(js_name = "convertBuffer")]
 pub fn convert_buffer(html: Buffer, options: Option<JsConversionOptions>) -> Result<String> {
     let html = buffer_to_str(&html)?;
-    let rust_options = options.map(Into::into);
+    let rust_options = validated_options(options)?;
     guard_panic(|| profiling::maybe_profile(|| html_to_markdown_rs::convert(html, rust_options.clone())))
         .map_err(to_js_error)
 }
@@ -1312,8 +2510,10 @@ pub fn convert_buffer_json(html: Buffer, options_json: Option<String>) -> Result
 
 /// Create a reusable `ConversionOptions` handle.
 #[napi]
-pub fn create_conversion_options_handle(options: Option<JsConversionOptions>) -> External<RustConversionOptions> {
-    External::new(options.map(Into::into).unwrap_or_default())
+pub fn create_conversion_options_handle(
+    options: Option<JsConversionOptions>,
+) -> Result<External<RustConversionOptions>> {
+    Ok(External::new(validated_options(options)?.unwrap_or_default()))
 }
 
 #[napi(js_name = "createConversionOptionsHandleJson")]
@@ -1389,7 +2589,7 @@ fn convert_inline_images_impl(
     options: Option<JsConversionOptions>,
     image_config: Option<JsInlineImageConfig>,
 ) -> Result<JsHtmlExtraction> {
-    let rust_options = options.map(Into::into);
+    let rust_options = validated_options(options)?;
     let rust_config = image_config.map_or_else(|| RustInlineImageConfig::new(DEFAULT_INLINE_IMAGE_LIMIT), Into::into);
 
     let extraction =
@@ -1457,6 +2657,46 @@ pub fn convert_with_inline_images(
     convert_inline_images_impl(&html, options, image_config)
 }
 
+/// Convert HTML to Markdown while collecting inline images, on the libuv thread pool.
+///
+/// Resolves with the same result as `convertWithInlineImages`.
+#[napi(js_name = "convertWithInlineImagesAsync")]
+pub fn convert_with_inline_images_async(
+    html: String,
+    options: Option<JsConversionOptions>,
+    image_config: Option<JsInlineImageConfig>,
+) -> Result<AsyncTask<ConvertWithInlineImagesTask>> {
+    Ok(AsyncTask::new(ConvertWithInlineImagesTask {
+        html,
+        options: validated_options(options)?,
+        image_config: image_config.map_or_else(|| RustInlineImageConfig::new(DEFAULT_INLINE_IMAGE_LIMIT), Into::into),
+    }))
+}
+
+/// Background task behind `convertWithInlineImagesAsync`.
+pub struct ConvertWithInlineImagesTask {
+    html: String,
+    options: Option<RustConversionOptions>,
+    image_config: RustInlineImageConfig,
+}
+
+impl Task for ConvertWithInlineImagesTask {
+    type Output = html_to_markdown_rs::HtmlExtraction;
+    type JsValue = JsHtmlExtraction;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let html = std::mem::take(&mut self.html);
+        let options = self.options.take();
+        let image_config = self.image_config.clone();
+        guard_panic(move || html_to_markdown_rs::convert_with_inline_images(&html, options, image_config, None))
+            .map_err(to_js_error)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(build_js_extraction(output))
+    }
+}
+
 /// Convert HTML to Markdown while collecting inline images using a pre-created options handle.
 #[napi(js_name = "convertWithInlineImagesHandle")]
 pub fn convert_with_inline_images_handle(
@@ -1546,7 +2786,7 @@ pub fn convert_with_metadata(
     #[cfg(not(feature = "visitor"))]
     let _ = visitor;
 
-    let rust_options = options.map(Into::into);
+    let rust_options = validated_options(options)?;
     let rust_config = metadata_config.map(Into::into).unwrap_or_default();
 
     let (markdown, metadata) =
@@ -1559,6 +2799,52 @@ pub fn convert_with_metadata(
     })
 }
 
+/// Convert HTML to Markdown with metadata extraction, on the libuv thread pool.
+///
+/// Resolves with the same result as `convertWithMetadata`.
+#[cfg(feature = "metadata")]
+#[napi(js_name = "convertWithMetadataAsync")]
+pub fn convert_with_metadata_async(
+    html: String,
+    options: Option<JsConversionOptions>,
+    metadata_config: Option<JsMetadataConfig>,
+) -> Result<AsyncTask<ConvertWithMetadataTask>> {
+    Ok(AsyncTask::new(ConvertWithMetadataTask {
+        html,
+        options: validated_options(options)?,
+        metadata_config: metadata_config.map(Into::into).unwrap_or_default(),
+    }))
+}
+
+/// Background task behind `convertWithMetadataAsync`.
+#[cfg(feature = "metadata")]
+pub struct ConvertWithMetadataTask {
+    html: String,
+    options: Option<RustConversionOptions>,
+    metadata_config: RustMetadataConfig,
+}
+
+#[cfg(feature = "metadata")]
+impl Task for ConvertWithMetadataTask {
+    type Output = (String, RustExtendedMetadata);
+    type JsValue = JsMetadataExtraction;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        let html = std::mem::take(&mut self.html);
+        let options = self.options.take();
+        let metadata_config = self.metadata_config.clone();
+        guard_panic(move || html_to_markdown_rs::convert_with_metadata(&html, options, metadata_config, None))
+            .map_err(to_js_error)
+    }
+
+    fn resolve(&mut self, _env: Env, (markdown, metadata): Self::Output) -> Result<Self::JsValue> {
+        Ok(JsMetadataExtraction {
+            markdown,
+            metadata: convert_metadata(metadata),
+        })
+    }
+}
+
 /// Convert HTML to Markdown with metadata extraction using a pre-created options handle.
 #[cfg(feature = "metadata")]
 #[napi(js_name = "convertWithMetadataHandle")]
@@ -1597,7 +2883,7 @@ pub fn convert_with_metadata_buffer(
     metadata_config: Option<JsMetadataConfig>,
 ) -> Result<JsMetadataExtraction> {
     let html = buffer_to_str(&html)?;
-    let rust_options = options.map(Into::into);
+    let rust_options = validated_options(options)?;
     let rust_config = metadata_config.map(Into::into).unwrap_or_default();
 
     let (markdown, metadata) =
@@ -1753,8 +3039,48 @@ mod tests {
             encoding: None,
             debug: None,
             strip_tags: None,
+            remove_tags: None,
             preserve_tags: None,
             skip_images: None,
+            remove_space_before_punctuation: None,
+            nbsp_in_prose: None,
+            nbsp_in_tables: None,
+            front_matter_format: None,
+            remove_soft_hyphens: None,
+            bidi_mode: None,
+            localized_punctuation: None,
+            ordered_markers: None,
+            cycle_bullets: None,
+            list_spacing: None,
+            unrepresentable_policy: None,
+            post_processors: None,
+            collect_stats: None,
+            title_fallback: None,
+            pandoc_attributes: None,
+            svg_text_mode: None,
+            object_links: None,
+            preserve_heading_ids: None,
+            content_fingerprint: None,
+            math_style: None,
+            infer_code_language: None,
+            code_block_trim: None,
+            srcset_policy: None,
+            figure_style: None,
+            details_style: None,
+            abbr_style: None,
+            time_style: None,
+            dfn_style: None,
+            url_param_allowlist: None,
+            url_param_denylist: None,
+            contact_link_style: None,
+            fidelity_threshold: None,
+            title_footnotes: None,
+            header_break_style: None,
+            report_dropped_content: None,
+            split_documents: None,
+            preserved_attributes: None,
+            max_output_chars: None,
+            max_output_tokens: None,
         };
 
         let rust_opts: RustConversionOptions = opts.into();
@@ -1769,6 +3095,11 @@ mod tests {
             preset: Some(JsPreprocessingPreset::Aggressive),
             remove_navigation: Some(false),
             remove_forms: Some(true),
+            apply_print_styles: None,
+            extract_main_content: None,
+            flatten_shadow_dom: None,
+            exclude_selectors: None,
+            include_selector: None,
         };
 
         let rust_opts: RustPreprocessingOptions = opts.into();
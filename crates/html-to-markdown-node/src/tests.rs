@@ -55,6 +55,11 @@ mod tests {
             preset: Some(JsPreprocessingPreset::Aggressive),
             remove_navigation: Some(false),
             remove_forms: Some(true),
+            apply_print_styles: None,
+            extract_main_content: None,
+            flatten_shadow_dom: None,
+            exclude_selectors: None,
+            include_selector: None,
         };
 
         let rust_opts: RustPreprocessingOptions = opts.into();
@@ -9,20 +9,22 @@ use ext_php_rs::binary::Binary;
 use ext_php_rs::boxed::ZBox;
 use ext_php_rs::prelude::*;
 use ext_php_rs::types::{ArrayKey, ZendHashTable, Zval};
+use html_to_markdown_rs::encoding::decode_html;
 #[cfg(feature = "metadata")]
 use html_to_markdown_rs::metadata::{
-    DocumentMetadata, ExtendedMetadata, HeaderMetadata, ImageMetadata, LinkMetadata, MetadataConfig, StructuredData,
-    TextDirection,
+    AuthorProfile, DocumentMetadata, ExtendedMetadata, HeaderMetadata, ImageMetadata, LinkMetadata, MetadataConfig,
+    ResourceMetadata, SourceMapEntry, StructuredData, TextDirection,
 };
 use html_to_markdown_rs::safety::guard_panic;
 mod profiling;
 #[cfg(feature = "visitor")]
 mod visitor_support;
 use html_to_markdown_rs::{
-    CodeBlockStyle, ConversionError, ConversionOptions, ConversionOptionsUpdate, DEFAULT_INLINE_IMAGE_LIMIT,
-    HeadingStyle, HighlightStyle, HtmlExtraction, InlineImage, InlineImageConfig, InlineImageConfigUpdate,
-    InlineImageWarning, ListIndentType, MetadataConfigUpdate, NewlineStyle, PreprocessingOptionsUpdate,
-    PreprocessingPreset, WhitespaceMode,
+    AbbrStyle, BidiMode, CodeBlockStyle, ContactLinkStyle, ConversionError, ConversionOptions, ConversionOptionsUpdate,
+    DEFAULT_INLINE_IMAGE_LIMIT, DetailsStyle, DfnStyle, FigureStyle, FrontMatterFormat, HeaderBreakStyle, HeadingStyle,
+    HighlightStyle, HtmlExtraction, InlineImage, InlineImageConfig, InlineImageConfigUpdate, InlineImageWarning,
+    ListIndentType, ListSpacing, MathStyle, MetadataConfigUpdate, NbspMode, NewlineStyle, PreprocessingOptionsUpdate,
+    PreprocessingPreset, SrcsetPolicy, SvgTextMode, TimeStyle, UnrepresentablePolicy, WhitespaceMode, option_char,
 };
 use std::path::PathBuf;
 
@@ -55,6 +57,68 @@ fn convert_with_options(html: &str, options: Option<ConversionOptions>) -> PhpRe
         .map_err(to_php_exception)
 }
 
+/// Convert a list of HTML documents with shared options in one call.
+#[php_function]
+#[php(name = "html_to_markdown_convert_batch")]
+pub fn convert_html_batch(htmls: Vec<String>, options: Option<&ZendHashTable>) -> PhpResult<Vec<String>> {
+    let rust_options = match options {
+        Some(table) => Some(parse_conversion_options(table)?),
+        None => None,
+    };
+
+    html_to_markdown_rs::convert_many(&htmls, rust_options)
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| {
+            result.map_err(|err| match err {
+                ConversionError::Panic(message) => PhpException::default(format!(
+                    "document {index}: html-to-markdown panic during conversion: {message}"
+                )),
+                other => PhpException::default(format!("document {index}: {other}")),
+            })
+        })
+        .collect()
+}
+
+/// Read an HTML file and convert it, decoding it from its BOM or `<meta charset>` natively.
+#[php_function]
+#[php(name = "html_to_markdown_convert_file")]
+pub fn convert_file(path: String, options: Option<&ZendHashTable>) -> PhpResult<String> {
+    let rust_options = match options {
+        Some(table) => Some(parse_conversion_options(table)?),
+        None => None,
+    };
+
+    let bytes = std::fs::read(&path).map_err(|err| PhpException::default(format!("Failed to read {path}: {err}")))?;
+    let html = decode_html(&bytes, None);
+    convert_with_options(&html, rust_options)
+}
+
+/// Conversion options parsed once, for reuse across many conversions.
+#[php_class]
+#[php(name = "HtmlToMarkdown\\Native\\OptionsHandle")]
+pub struct OptionsHandle {
+    options: ConversionOptions,
+}
+
+/// Parse and validate an options array once, returning a handle for
+/// `html_to_markdown_convert_with_options_handle`.
+#[php_function]
+#[php(name = "html_to_markdown_options_create")]
+pub fn options_create(options: Option<&ZendHashTable>) -> PhpResult<OptionsHandle> {
+    let options = match options {
+        Some(table) => parse_conversion_options(table)?,
+        None => ConversionOptions::default(),
+    };
+    Ok(OptionsHandle { options })
+}
+
+#[php_function]
+#[php(name = "html_to_markdown_convert_with_options_handle")]
+pub fn convert_with_options_handle(html: String, handle: &OptionsHandle) -> PhpResult<String> {
+    convert_with_options(&html, Some(handle.options.clone()))
+}
+
 #[php_function]
 #[php(name = "html_to_markdown_profile_start")]
 pub fn profile_start(output_path: String, frequency: Option<i64>) -> PhpResult<bool> {
@@ -171,6 +235,11 @@ pub fn module(module: ModuleBuilder) -> ModuleBuilder {
         .name("html_to_markdown")
         .version(env!("CARGO_PKG_VERSION"))
         .function(wrap_function!(convert_html))
+        .function(wrap_function!(convert_html_batch))
+        .function(wrap_function!(convert_file))
+        .class::<OptionsHandle>()
+        .function(wrap_function!(options_create))
+        .function(wrap_function!(convert_with_options_handle))
         .function(wrap_function!(convert_html_with_inline_images))
         .function(wrap_function!(profile_start))
         .function(wrap_function!(profile_stop));
@@ -190,6 +259,8 @@ pub fn module(module: ModuleBuilder) -> ModuleBuilder {
 
 fn parse_conversion_options(table: &ZendHashTable) -> PhpResult<ConversionOptions> {
     let mut update = ConversionOptionsUpdate::default();
+    let mut strict = false;
+    let mut unknown_keys = Vec::new();
 
     for (key, value) in table {
         let key_str = key_to_string(&key)?;
@@ -199,6 +270,9 @@ fn parse_conversion_options(table: &ZendHashTable) -> PhpResult<ConversionOption
         }
 
         match key_str.as_str() {
+            "strict_options" => {
+                strict = read_bool(value, &key_str)?;
+            }
             "heading_style" => {
                 update.heading_style = Some(parse_heading_style(value, &key_str)?);
             }
@@ -292,14 +366,142 @@ fn parse_conversion_options(table: &ZendHashTable) -> PhpResult<ConversionOption
             "strip_tags" => {
                 update.strip_tags = Some(read_string_list(value, &key_str)?);
             }
+            "remove_tags" => {
+                update.remove_tags = Some(read_string_list(value, &key_str)?);
+            }
             "preserve_tags" => {
                 update.preserve_tags = Some(read_string_list(value, &key_str)?);
             }
-            _ => {}
+            "remove_space_before_punctuation" => {
+                update.remove_space_before_punctuation = Some(read_bool(value, &key_str)?);
+            }
+            "nbsp_in_prose" => {
+                update.nbsp_in_prose = Some(parse_nbsp_mode(value, &key_str)?);
+            }
+            "nbsp_in_tables" => {
+                update.nbsp_in_tables = Some(parse_nbsp_mode(value, &key_str)?);
+            }
+            "front_matter_format" => {
+                update.front_matter_format = Some(parse_front_matter_format(value, &key_str)?);
+            }
+            "remove_soft_hyphens" => {
+                update.remove_soft_hyphens = Some(read_bool(value, &key_str)?);
+            }
+            "bidi_mode" => {
+                update.bidi_mode = Some(parse_bidi_mode(value, &key_str)?);
+            }
+            "localized_punctuation" => {
+                update.localized_punctuation = Some(read_bool(value, &key_str)?);
+            }
+            "ordered_markers" => {
+                update.ordered_markers = Some(read_string(value, &key_str)?);
+            }
+            "cycle_bullets" => {
+                update.cycle_bullets = Some(read_bool(value, &key_str)?);
+            }
+            "list_spacing" => {
+                update.list_spacing = Some(parse_list_spacing(value, &key_str)?);
+            }
+            "unrepresentable_policy" => {
+                update.unrepresentable_policy = Some(parse_unrepresentable_policy(value, &key_str)?);
+            }
+            "post_processors" => {
+                update.post_processors = Some(read_string_list(value, &key_str)?);
+            }
+            "collect_stats" => {
+                update.collect_stats = Some(read_bool(value, &key_str)?);
+            }
+            "title_fallback" => {
+                update.title_fallback = Some(read_string_list(value, &key_str)?);
+            }
+            "pandoc_attributes" => {
+                update.pandoc_attributes = Some(read_bool(value, &key_str)?);
+            }
+            "svg_text_mode" => {
+                update.svg_text_mode = Some(parse_svg_text_mode(value, &key_str)?);
+            }
+            "object_links" => {
+                update.object_links = Some(read_bool(value, &key_str)?);
+            }
+            "preserve_heading_ids" => {
+                update.preserve_heading_ids = Some(read_bool(value, &key_str)?);
+            }
+            "content_fingerprint" => {
+                update.content_fingerprint = Some(read_bool(value, &key_str)?);
+            }
+            "math_style" => {
+                update.math_style = Some(parse_math_style(value, &key_str)?);
+            }
+            "infer_code_language" => {
+                update.infer_code_language = Some(read_bool(value, &key_str)?);
+            }
+            "code_block_trim" => {
+                update.code_block_trim = Some(read_bool(value, &key_str)?);
+            }
+            "srcset_policy" => {
+                update.srcset_policy = Some(parse_srcset_policy(value, &key_str)?);
+            }
+            "figure_style" => {
+                update.figure_style = Some(parse_figure_style(value, &key_str)?);
+            }
+            "details_style" => {
+                update.details_style = Some(parse_details_style(value, &key_str)?);
+            }
+            "abbr_style" => {
+                update.abbr_style = Some(parse_abbr_style(value, &key_str)?);
+            }
+            "time_style" => {
+                update.time_style = Some(parse_time_style(value, &key_str)?);
+            }
+            "dfn_style" => {
+                update.dfn_style = Some(parse_dfn_style(value, &key_str)?);
+            }
+            "url_param_allowlist" => {
+                update.url_param_allowlist = Some(read_string_list(value, &key_str)?);
+            }
+            "url_param_denylist" => {
+                update.url_param_denylist = Some(read_string_list(value, &key_str)?);
+            }
+            "contact_link_style" => {
+                update.contact_link_style = Some(parse_contact_link_style(value, &key_str)?);
+            }
+            "fidelity_threshold" => {
+                update.fidelity_threshold = Some(read_f64(value, &key_str)?);
+            }
+            "title_footnotes" => {
+                update.title_footnotes = Some(read_bool(value, &key_str)?);
+            }
+            "header_break_style" => {
+                update.header_break_style = Some(parse_header_break_style(value, &key_str)?);
+            }
+            "report_dropped_content" => {
+                update.report_dropped_content = Some(read_bool(value, &key_str)?);
+            }
+            "split_documents" => {
+                update.split_documents = Some(read_bool(value, &key_str)?);
+            }
+            "preserved_attributes" => {
+                update.preserved_attributes = Some(read_string_list(value, &key_str)?);
+            }
+            "max_output_chars" => {
+                update.max_output_chars = Some(read_usize(value, &key_str)?);
+            }
+            "max_output_tokens" => {
+                update.max_output_tokens = Some(read_usize(value, &key_str)?);
+            }
+            _ => unknown_keys.push(key_str),
+        }
+    }
+
+    if strict {
+        if let Some(key) = unknown_keys.first() {
+            return Err(PhpException::default(format!("Unknown option '{key}'")));
         }
     }
 
-    Ok(ConversionOptions::from(update))
+    let options = ConversionOptions::from(update);
+    options.validate().map_err(to_php_exception)?;
+    Ok(options)
 }
 
 fn parse_inline_image_config(table: &ZendHashTable) -> PhpResult<InlineImageConfig> {
@@ -365,6 +567,12 @@ fn parse_metadata_config(table: &ZendHashTable) -> PhpResult<MetadataConfig> {
             "extract_structured_data" => {
                 update.extract_structured_data = Some(read_bool(value, &key_str)?);
             }
+            "extract_resources" => {
+                update.extract_resources = Some(read_bool(value, &key_str)?);
+            }
+            "extract_source_map" => {
+                update.extract_source_map = Some(read_bool(value, &key_str)?);
+            }
             "max_structured_data_size" => {
                 update.max_structured_data_size = Some(read_usize(value, &key_str)?);
             }
@@ -402,6 +610,21 @@ fn parse_preprocessing_options(value: &Zval, key: &str) -> PhpResult<Preprocessi
             "remove_forms" => {
                 update.remove_forms = Some(read_bool(entry_value, &format!("{key}.remove_forms"))?);
             }
+            "apply_print_styles" => {
+                update.apply_print_styles = Some(read_bool(entry_value, &format!("{key}.apply_print_styles"))?);
+            }
+            "extract_main_content" => {
+                update.extract_main_content = Some(read_bool(entry_value, &format!("{key}.extract_main_content"))?);
+            }
+            "flatten_shadow_dom" => {
+                update.flatten_shadow_dom = Some(read_bool(entry_value, &format!("{key}.flatten_shadow_dom"))?);
+            }
+            "exclude_selectors" => {
+                update.exclude_selectors = Some(read_string_list(entry_value, &format!("{key}.exclude_selectors"))?);
+            }
+            "include_selector" => {
+                update.include_selector = Some(read_string(entry_value, &format!("{key}.include_selector"))?);
+            }
             _ => {}
         }
     }
@@ -436,6 +659,136 @@ fn parse_highlight_style(value: &Zval, key: &str) -> PhpResult<HighlightStyle> {
     }
 }
 
+fn parse_front_matter_format(value: &Zval, key: &str) -> PhpResult<FrontMatterFormat> {
+    match read_string(value, key)?.as_str() {
+        "yaml" => Ok(FrontMatterFormat::Yaml),
+        "toml" => Ok(FrontMatterFormat::Toml),
+        "json" => Ok(FrontMatterFormat::Json),
+        other => Err(PhpException::default(format!("Invalid front_matter_format '{other}'"))),
+    }
+}
+
+fn parse_bidi_mode(value: &Zval, key: &str) -> PhpResult<BidiMode> {
+    match read_string(value, key)?.as_str() {
+        "ignore" => Ok(BidiMode::Ignore),
+        "marks" => Ok(BidiMode::Marks),
+        "embedding" => Ok(BidiMode::Embedding),
+        "html" => Ok(BidiMode::Html),
+        other => Err(PhpException::default(format!("Invalid bidi_mode '{other}'"))),
+    }
+}
+
+fn parse_list_spacing(value: &Zval, key: &str) -> PhpResult<ListSpacing> {
+    match read_string(value, key)?.as_str() {
+        "auto" => Ok(ListSpacing::Auto),
+        "tight" => Ok(ListSpacing::Tight),
+        "loose" => Ok(ListSpacing::Loose),
+        other => Err(PhpException::default(format!("Invalid list_spacing '{other}'"))),
+    }
+}
+
+fn parse_unrepresentable_policy(value: &Zval, key: &str) -> PhpResult<UnrepresentablePolicy> {
+    match read_string(value, key)?.as_str() {
+        "drop" => Ok(UnrepresentablePolicy::Drop),
+        "passthrough" => Ok(UnrepresentablePolicy::Passthrough),
+        "diagnose" => Ok(UnrepresentablePolicy::Diagnose),
+        other => Err(PhpException::default(format!(
+            "Invalid unrepresentable_policy '{other}'"
+        ))),
+    }
+}
+
+fn parse_svg_text_mode(value: &Zval, key: &str) -> PhpResult<SvgTextMode> {
+    match read_string(value, key)?.as_str() {
+        "off" => Ok(SvgTextMode::Off),
+        "alt" => Ok(SvgTextMode::Alt),
+        "caption" => Ok(SvgTextMode::Caption),
+        "replace" => Ok(SvgTextMode::Replace),
+        other => Err(PhpException::default(format!("Invalid svg_text_mode '{other}'"))),
+    }
+}
+
+fn parse_math_style(value: &Zval, key: &str) -> PhpResult<MathStyle> {
+    match read_string(value, key)?.as_str() {
+        "comment" => Ok(MathStyle::Comment),
+        "dollar" => Ok(MathStyle::Dollar),
+        "fenced" => Ok(MathStyle::Fenced),
+        other => Err(PhpException::default(format!("Invalid math_style '{other}'"))),
+    }
+}
+
+fn parse_srcset_policy(value: &Zval, key: &str) -> PhpResult<SrcsetPolicy> {
+    match read_string(value, key)?.as_str() {
+        "first" => Ok(SrcsetPolicy::First),
+        "largest" => Ok(SrcsetPolicy::Largest),
+        "smallest" => Ok(SrcsetPolicy::Smallest),
+        other => Err(PhpException::default(format!("Invalid srcset_policy '{other}'"))),
+    }
+}
+
+fn parse_figure_style(value: &Zval, key: &str) -> PhpResult<FigureStyle> {
+    match read_string(value, key)?.as_str() {
+        "italic" => Ok(FigureStyle::Italic),
+        "blockquote" => Ok(FigureStyle::Blockquote),
+        "html" => Ok(FigureStyle::Html),
+        other => Err(PhpException::default(format!("Invalid figure_style '{other}'"))),
+    }
+}
+
+fn parse_details_style(value: &Zval, key: &str) -> PhpResult<DetailsStyle> {
+    match read_string(value, key)?.as_str() {
+        "markdown" => Ok(DetailsStyle::Markdown),
+        "html" => Ok(DetailsStyle::Html),
+        other => Err(PhpException::default(format!("Invalid details_style '{other}'"))),
+    }
+}
+
+fn parse_abbr_style(value: &Zval, key: &str) -> PhpResult<AbbrStyle> {
+    match read_string(value, key)?.as_str() {
+        "expand" => Ok(AbbrStyle::Expand),
+        "text" => Ok(AbbrStyle::Text),
+        "html" => Ok(AbbrStyle::Html),
+        other => Err(PhpException::default(format!("Invalid abbr_style '{other}'"))),
+    }
+}
+
+fn parse_time_style(value: &Zval, key: &str) -> PhpResult<TimeStyle> {
+    match read_string(value, key)?.as_str() {
+        "text" => Ok(TimeStyle::Text),
+        "datetime" => Ok(TimeStyle::Datetime),
+        "html" => Ok(TimeStyle::Html),
+        other => Err(PhpException::default(format!("Invalid time_style '{other}'"))),
+    }
+}
+
+fn parse_dfn_style(value: &Zval, key: &str) -> PhpResult<DfnStyle> {
+    match read_string(value, key)?.as_str() {
+        "emphasis" => Ok(DfnStyle::Emphasis),
+        "text" => Ok(DfnStyle::Text),
+        "html" => Ok(DfnStyle::Html),
+        other => Err(PhpException::default(format!("Invalid dfn_style '{other}'"))),
+    }
+}
+
+fn parse_contact_link_style(value: &Zval, key: &str) -> PhpResult<ContactLinkStyle> {
+    match read_string(value, key)?.as_str() {
+        "link" => Ok(ContactLinkStyle::Link),
+        "autolink" => Ok(ContactLinkStyle::Autolink),
+        "text" => Ok(ContactLinkStyle::Text),
+        other => Err(PhpException::default(format!("Invalid contact_link_style '{other}'"))),
+    }
+}
+
+fn parse_header_break_style(value: &Zval, key: &str) -> PhpResult<HeaderBreakStyle> {
+    match read_string(value, key)?.as_str() {
+        "inherit" => Ok(HeaderBreakStyle::Inherit),
+        "space" => Ok(HeaderBreakStyle::Space),
+        "slash" => Ok(HeaderBreakStyle::Slash),
+        "html" => Ok(HeaderBreakStyle::Html),
+        other => Err(PhpException::default(format!("Invalid header_break_style '{other}'"))),
+    }
+}
+
 fn parse_whitespace_mode(value: &Zval, key: &str) -> PhpResult<WhitespaceMode> {
     match read_string(value, key)?.as_str() {
         "normalized" => Ok(WhitespaceMode::Normalized),
@@ -444,6 +797,14 @@ fn parse_whitespace_mode(value: &Zval, key: &str) -> PhpResult<WhitespaceMode> {
     }
 }
 
+fn parse_nbsp_mode(value: &Zval, key: &str) -> PhpResult<NbspMode> {
+    match read_string(value, key)?.as_str() {
+        "space" => Ok(NbspMode::Space),
+        "preserve" => Ok(NbspMode::Preserve),
+        other => Err(PhpException::default(format!("Invalid {key} '{other}'"))),
+    }
+}
+
 fn parse_newline_style(value: &Zval, key: &str) -> PhpResult<NewlineStyle> {
     match read_string(value, key)?.as_str() {
         "spaces" => Ok(NewlineStyle::Spaces),
@@ -466,6 +827,7 @@ fn parse_preprocessing_preset(value: &Zval, key: &str) -> PhpResult<Preprocessin
         "minimal" => Ok(PreprocessingPreset::Minimal),
         "standard" => Ok(PreprocessingPreset::Standard),
         "aggressive" => Ok(PreprocessingPreset::Aggressive),
+        "confluence" => Ok(PreprocessingPreset::Confluence),
         other => Err(PhpException::default(format!("Invalid preprocessing preset '{other}'"))),
     }
 }
@@ -504,6 +866,13 @@ fn read_u64(value: &Zval, key: &str) -> PhpResult<u64> {
     Ok(number as u64)
 }
 
+fn read_f64(value: &Zval, key: &str) -> PhpResult<f64> {
+    value
+        .double()
+        .or_else(|| value.long().map(|number| number as f64))
+        .ok_or_else(|| PhpException::default(format!("'{key}' must be a number (got {:?})", value.get_type())))
+}
+
 fn read_string_list(value: &Zval, key: &str) -> PhpResult<Vec<String>> {
     let array = value
         .array()
@@ -524,16 +893,7 @@ fn read_string_list(value: &Zval, key: &str) -> PhpResult<Vec<String>> {
 
 fn parse_single_char(value: &Zval, key: &str) -> PhpResult<char> {
     let string = read_string(value, key)?;
-    let mut chars = string.chars();
-    if let Some(ch) = chars.next() {
-        if chars.next().is_some() {
-            Err(PhpException::default(format!("'{key}' must be a single character")))
-        } else {
-            Ok(ch)
-        }
-    } else {
-        Err(PhpException::default(format!("'{key}' must not be empty")))
-    }
+    option_char(key, &string).map_err(to_php_exception)
 }
 
 fn build_html_extraction(extraction: HtmlExtraction) -> PhpResult<ZBox<ZendHashTable>> {
@@ -635,6 +995,8 @@ fn build_extended_metadata(metadata: ExtendedMetadata) -> PhpResult<ZBox<ZendHas
         "structured_data",
         build_structured_data_array(metadata.structured_data)?,
     )?;
+    table.insert("resources", build_resources_array(metadata.resources)?)?;
+    table.insert("source_map", build_source_map_array(metadata.source_map)?)?;
     Ok(table)
 }
 
@@ -659,6 +1021,8 @@ fn build_document_metadata(doc: DocumentMetadata) -> PhpResult<ZBox<ZendHashTabl
         None => table.insert("author", ())?,
     }
 
+    table.insert("author_profiles", build_author_profiles_array(doc.author_profiles)?)?;
+
     match doc.canonical_url {
         Some(url) => table.insert("canonical_url", url)?,
         None => table.insert("canonical_url", ())?,
@@ -682,6 +1046,27 @@ fn build_document_metadata(doc: DocumentMetadata) -> PhpResult<ZBox<ZendHashTabl
     Ok(table)
 }
 
+#[cfg(feature = "metadata")]
+fn build_author_profiles_array(profiles: Vec<AuthorProfile>) -> PhpResult<ZBox<ZendHashTable>> {
+    let mut array = ZendHashTable::with_capacity(table_capacity(profiles.len()));
+
+    for profile in profiles {
+        let mut entry = ZendHashTable::new();
+        entry.insert("url", profile.url)?;
+
+        match profile.name {
+            Some(name) => entry.insert("name", name)?,
+            None => entry.insert("name", ())?,
+        }
+
+        entry.insert("source", profile.source.to_string())?;
+
+        array.push(entry)?;
+    }
+
+    Ok(array)
+}
+
 #[cfg(feature = "metadata")]
 fn build_headers_array(headers: Vec<HeaderMetadata>) -> PhpResult<ZBox<ZendHashTable>> {
     let mut array = ZendHashTable::with_capacity(table_capacity(headers.len()));
@@ -786,6 +1171,37 @@ fn build_structured_data_array(data: Vec<StructuredData>) -> PhpResult<ZBox<Zend
     Ok(array)
 }
 
+#[cfg(feature = "metadata")]
+fn build_resources_array(resources: Vec<ResourceMetadata>) -> PhpResult<ZBox<ZendHashTable>> {
+    let mut array = ZendHashTable::with_capacity(table_capacity(resources.len()));
+
+    for resource in resources {
+        let mut entry = ZendHashTable::new();
+        entry.insert("url", resource.url)?;
+        entry.insert("resource_type", resource.resource_type.to_string())?;
+        array.push(entry)?;
+    }
+
+    Ok(array)
+}
+
+#[cfg(feature = "metadata")]
+fn build_source_map_array(source_map: Vec<SourceMapEntry>) -> PhpResult<ZBox<ZendHashTable>> {
+    let mut array = ZendHashTable::with_capacity(table_capacity(source_map.len()));
+
+    for source in source_map {
+        let mut entry = ZendHashTable::new();
+        entry.insert("tag", source.tag)?;
+        entry.insert("html_start", source.html_start as i64)?;
+        entry.insert("html_end", source.html_end as i64)?;
+        entry.insert("markdown_start", source.markdown_start as i64)?;
+        entry.insert("markdown_end", source.markdown_end as i64)?;
+        array.push(entry)?;
+    }
+
+    Ok(array)
+}
+
 #[cfg(feature = "metadata")]
 fn build_string_map(map: BTreeMap<String, String>) -> PhpResult<ZBox<ZendHashTable>> {
     let mut table = ZendHashTable::with_capacity(table_capacity(map.len()));
@@ -2,16 +2,26 @@ from typing import Literal, TypedDict
 
 class PreprocessingOptions:
     enabled: bool
-    preset: Literal["minimal", "standard", "aggressive"]
+    preset: Literal["minimal", "standard", "aggressive", "confluence"]
     remove_navigation: bool
     remove_forms: bool
+    apply_print_styles: bool
+    extract_main_content: bool
+    flatten_shadow_dom: bool
+    exclude_selectors: list[str]
+    include_selector: str | None
 
     def __init__(
         self,
         enabled: bool = False,
-        preset: Literal["minimal", "standard", "aggressive"] = "standard",
+        preset: Literal["minimal", "standard", "aggressive", "confluence"] = "standard",
         remove_navigation: bool = True,
         remove_forms: bool = True,
+        apply_print_styles: bool = False,
+        extract_main_content: bool = False,
+        flatten_shadow_dom: bool = False,
+        exclude_selectors: list[str] = [],
+        include_selector: str | None = None,
     ) -> None: ...
 
 class ConversionOptions:
@@ -1,19 +1,21 @@
 #![allow(clippy::all, clippy::pedantic, clippy::nursery, missing_docs)]
 #[cfg(feature = "metadata")]
 use html_to_markdown_rs::metadata::{
-    DEFAULT_MAX_STRUCTURED_DATA_SIZE, DocumentMetadata as RustDocumentMetadata,
+    AuthorProfile as RustAuthorProfile, DEFAULT_MAX_STRUCTURED_DATA_SIZE, DocumentMetadata as RustDocumentMetadata,
     ExtendedMetadata as RustExtendedMetadata, HeaderMetadata as RustHeaderMetadata, ImageMetadata as RustImageMetadata,
-    LinkMetadata as RustLinkMetadata, MetadataConfig as RustMetadataConfig, StructuredData as RustStructuredData,
-    TextDirection as RustTextDirection,
+    LinkMetadata as RustLinkMetadata, MetadataConfig as RustMetadataConfig, ResourceMetadata as RustResourceMetadata,
+    SourceMapEntry as RustSourceMapEntry, StructuredData as RustStructuredData, TextDirection as RustTextDirection,
 };
 use html_to_markdown_rs::safety::guard_panic;
 mod profiling;
 #[cfg(feature = "visitor")]
 use html_to_markdown_rs::visitor::{HtmlVisitor, NodeContext, VisitResult};
 use html_to_markdown_rs::{
-    CodeBlockStyle, ConversionError, ConversionOptions as RustConversionOptions, HeadingStyle, HighlightStyle,
-    ListIndentType, NewlineStyle, PreprocessingOptions as RustPreprocessingOptions, PreprocessingPreset,
-    WhitespaceMode,
+    AbbrStyle, BidiMode, CodeBlockStyle, ContactLinkStyle, ConversionError, ConversionOptions as RustConversionOptions,
+    DEFAULT_URL_PARAM_DENYLIST, DetailsStyle, DfnStyle, FigureStyle, FrontMatterFormat, HeaderBreakStyle, HeadingStyle,
+    HighlightStyle, ListIndentType, ListSpacing, MathStyle, NbspMode, NewlineStyle, PostProcessorChain,
+    PreprocessingOptions as RustPreprocessingOptions, PreprocessingPreset, SrcsetPolicy, SvgTextMode, TimeStyle,
+    TitleSource, UnrepresentablePolicy, WhitespaceMode,
 };
 #[cfg(feature = "inline-images")]
 use html_to_markdown_rs::{DEFAULT_INLINE_IMAGE_LIMIT, InlineImageConfig as RustInlineImageConfig};
@@ -22,7 +24,6 @@ use once_cell::sync::OnceCell;
 use pyo3::prelude::*;
 #[cfg(feature = "inline-images")]
 use pyo3::types::PyBytes;
-#[cfg(any(feature = "inline-images", feature = "metadata"))]
 use pyo3::types::PyDict;
 #[cfg(feature = "metadata")]
 use pyo3::types::{PyList, PyTuple};
@@ -48,6 +49,23 @@ fn to_py_err(err: ConversionError) -> PyErr {
     }
 }
 
+fn to_py_batch_err(index: usize, err: ConversionError) -> PyErr {
+    match err {
+        ConversionError::Panic(message) => pyo3::exceptions::PyRuntimeError::new_err(format!(
+            "document {index}: html-to-markdown panic during conversion: {message}"
+        )),
+        other => pyo3::exceptions::PyValueError::new_err(format!("document {index}: {other}")),
+    }
+}
+
+fn collect_batch(results: Vec<html_to_markdown_rs::Result<String>>) -> PyResult<Vec<String>> {
+    results
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| result.map_err(|err| to_py_batch_err(index, err)))
+        .collect()
+}
+
 fn run_with_guard_and_profile<F, T>(f: F) -> html_to_markdown_rs::Result<T>
 where
     F: FnMut() -> html_to_markdown_rs::Result<T> + UnwindSafe,
@@ -163,18 +181,43 @@ struct PreprocessingOptions {
     remove_navigation: bool,
     #[pyo3(get, set)]
     remove_forms: bool,
+    #[pyo3(get, set)]
+    apply_print_styles: bool,
+    #[pyo3(get, set)]
+    extract_main_content: bool,
+    #[pyo3(get, set)]
+    flatten_shadow_dom: bool,
+    #[pyo3(get, set)]
+    exclude_selectors: Vec<String>,
+    #[pyo3(get, set)]
+    include_selector: Option<String>,
 }
 
 #[pymethods]
 impl PreprocessingOptions {
     #[new]
-    #[pyo3(signature = (enabled=false, preset="standard".to_string(), remove_navigation=true, remove_forms=true))]
-    const fn new(enabled: bool, preset: String, remove_navigation: bool, remove_forms: bool) -> Self {
+    #[pyo3(signature = (enabled=false, preset="standard".to_string(), remove_navigation=true, remove_forms=true, apply_print_styles=false, extract_main_content=false, flatten_shadow_dom=false, exclude_selectors=Vec::new(), include_selector=None))]
+    fn new(
+        enabled: bool,
+        preset: String,
+        remove_navigation: bool,
+        remove_forms: bool,
+        apply_print_styles: bool,
+        extract_main_content: bool,
+        flatten_shadow_dom: bool,
+        exclude_selectors: Vec<String>,
+        include_selector: Option<String>,
+    ) -> Self {
         Self {
             enabled,
             preset,
             remove_navigation,
             remove_forms,
+            apply_print_styles,
+            extract_main_content,
+            flatten_shadow_dom,
+            exclude_selectors,
+            include_selector,
         }
     }
 }
@@ -187,10 +230,16 @@ impl PreprocessingOptions {
             preset: match self.preset.as_str() {
                 "minimal" => PreprocessingPreset::Minimal,
                 "aggressive" => PreprocessingPreset::Aggressive,
+                "confluence" => PreprocessingPreset::Confluence,
                 _ => PreprocessingPreset::Standard,
             },
             remove_navigation: self.remove_navigation,
             remove_forms: self.remove_forms,
+            apply_print_styles: self.apply_print_styles,
+            extract_main_content: self.extract_main_content,
+            flatten_shadow_dom: self.flatten_shadow_dom,
+            exclude_selectors: self.exclude_selectors.clone(),
+            include_selector: self.include_selector.clone(),
         }
     }
 }
@@ -263,6 +312,10 @@ struct MetadataConfig {
     extract_structured_data: bool,
     #[pyo3(get, set)]
     max_structured_data_size: usize,
+    #[pyo3(get, set)]
+    extract_resources: bool,
+    #[pyo3(get, set)]
+    extract_source_map: bool,
 }
 
 #[cfg(feature = "metadata")]
@@ -275,7 +328,9 @@ impl MetadataConfig {
         extract_links=true,
         extract_images=true,
         extract_structured_data=true,
-        max_structured_data_size=DEFAULT_MAX_STRUCTURED_DATA_SIZE
+        max_structured_data_size=DEFAULT_MAX_STRUCTURED_DATA_SIZE,
+        extract_resources=false,
+        extract_source_map=false
     ))]
     const fn new(
         extract_document: bool,
@@ -284,6 +339,8 @@ impl MetadataConfig {
         extract_images: bool,
         extract_structured_data: bool,
         max_structured_data_size: usize,
+        extract_resources: bool,
+        extract_source_map: bool,
     ) -> Self {
         Self {
             extract_document,
@@ -292,6 +349,8 @@ impl MetadataConfig {
             extract_images,
             extract_structured_data,
             max_structured_data_size,
+            extract_resources,
+            extract_source_map,
         }
     }
 }
@@ -305,6 +364,8 @@ impl MetadataConfig {
             extract_links: self.extract_links,
             extract_images: self.extract_images,
             extract_structured_data: self.extract_structured_data,
+            extract_resources: self.extract_resources,
+            extract_source_map: self.extract_source_map,
             max_structured_data_size: self.max_structured_data_size,
         }
     }
@@ -373,11 +434,91 @@ struct ConversionOptions {
     #[pyo3(get, set)]
     strip_tags: Vec<String>,
     #[pyo3(get, set)]
+    remove_tags: Vec<String>,
+    #[pyo3(get, set)]
     preserve_tags: Vec<String>,
     #[pyo3(get, set)]
     encoding: String,
     #[pyo3(get, set)]
     skip_images: bool,
+    #[pyo3(get, set)]
+    remove_space_before_punctuation: bool,
+    #[pyo3(get, set)]
+    nbsp_in_prose: String,
+    #[pyo3(get, set)]
+    nbsp_in_tables: String,
+    #[pyo3(get, set)]
+    front_matter_format: String,
+    #[pyo3(get, set)]
+    remove_soft_hyphens: bool,
+    #[pyo3(get, set)]
+    bidi_mode: String,
+    #[pyo3(get, set)]
+    localized_punctuation: bool,
+    #[pyo3(get, set)]
+    ordered_markers: String,
+    #[pyo3(get, set)]
+    cycle_bullets: bool,
+    #[pyo3(get, set)]
+    list_spacing: String,
+    #[pyo3(get, set)]
+    unrepresentable_policy: String,
+    #[pyo3(get, set)]
+    post_processors: Vec<String>,
+    #[pyo3(get, set)]
+    collect_stats: bool,
+    #[pyo3(get, set)]
+    title_fallback: Vec<String>,
+    #[pyo3(get, set)]
+    pandoc_attributes: bool,
+    #[pyo3(get, set)]
+    svg_text_mode: String,
+    #[pyo3(get, set)]
+    object_links: bool,
+    #[pyo3(get, set)]
+    preserve_heading_ids: bool,
+    #[pyo3(get, set)]
+    content_fingerprint: bool,
+    #[pyo3(get, set)]
+    math_style: String,
+    #[pyo3(get, set)]
+    infer_code_language: bool,
+    #[pyo3(get, set)]
+    code_block_trim: bool,
+    #[pyo3(get, set)]
+    srcset_policy: String,
+    #[pyo3(get, set)]
+    figure_style: String,
+    #[pyo3(get, set)]
+    details_style: String,
+    #[pyo3(get, set)]
+    abbr_style: String,
+    #[pyo3(get, set)]
+    time_style: String,
+    #[pyo3(get, set)]
+    dfn_style: String,
+    #[pyo3(get, set)]
+    url_param_allowlist: Vec<String>,
+    #[pyo3(get, set)]
+    url_param_denylist: Option<Vec<String>>,
+    #[pyo3(get, set)]
+    contact_link_style: String,
+    #[pyo3(get, set)]
+    fidelity_threshold: f64,
+    #[pyo3(get, set)]
+    title_footnotes: bool,
+    #[pyo3(get, set)]
+    header_break_style: String,
+    #[pyo3(get, set)]
+    report_dropped_content: bool,
+    #[pyo3(get, set)]
+    split_documents: bool,
+    #[pyo3(get, set)]
+    preserved_attributes: Vec<String>,
+    #[pyo3(get, set)]
+    max_output_chars: Option<usize>,
+    #[pyo3(get, set)]
+    max_output_tokens: Option<usize>,
 }
 
 #[pymethods]
@@ -414,9 +555,49 @@ impl ConversionOptions {
         preprocessing=None,
         debug=false,
         strip_tags=Vec::new(),
+        remove_tags=Vec::new(),
         preserve_tags=Vec::new(),
         encoding="utf-8".to_string(),
-        skip_images=false
+        skip_images=false,
+        remove_space_before_punctuation=false,
+        nbsp_in_prose="space".to_string(),
+        nbsp_in_tables="space".to_string(),
+        front_matter_format="yaml".to_string(),
+        remove_soft_hyphens=true,
+        bidi_mode="ignore".to_string(),
+        localized_punctuation=false,
+        ordered_markers=".".to_string(),
+        cycle_bullets=true,
+        list_spacing="auto".to_string(),
+        unrepresentable_policy="drop".to_string(),
+        post_processors=Vec::new(),
+        collect_stats=false,
+        title_fallback=Vec::new(),
+        pandoc_attributes=false,
+        svg_text_mode="off".to_string(),
+        object_links=false,
+        preserve_heading_ids=false,
+        content_fingerprint=false,
+        math_style="comment".to_string(),
+        infer_code_language=false,
+        code_block_trim=true,
+        srcset_policy="first".to_string(),
+        figure_style="italic".to_string(),
+        details_style="markdown".to_string(),
+        abbr_style="expand".to_string(),
+        time_style="text".to_string(),
+        dfn_style="emphasis".to_string(),
+        url_param_allowlist=Vec::new(),
+        url_param_denylist=None,
+        contact_link_style="link".to_string(),
+        fidelity_threshold=1.0,
+        title_footnotes=false,
+        header_break_style="inherit".to_string(),
+        report_dropped_content=false,
+        split_documents=false,
+        preserved_attributes=Vec::new(),
+        max_output_chars=None,
+        max_output_tokens=None
     ))]
     fn new(
         heading_style: String,
@@ -448,9 +629,49 @@ impl ConversionOptions {
         preprocessing: Option<PreprocessingOptions>,
         debug: bool,
         strip_tags: Vec<String>,
+        remove_tags: Vec<String>,
         preserve_tags: Vec<String>,
         encoding: String,
         skip_images: bool,
+        remove_space_before_punctuation: bool,
+        nbsp_in_prose: String,
+        nbsp_in_tables: String,
+        front_matter_format: String,
+        remove_soft_hyphens: bool,
+        bidi_mode: String,
+        localized_punctuation: bool,
+        ordered_markers: String,
+        cycle_bullets: bool,
+        list_spacing: String,
+        unrepresentable_policy: String,
+        post_processors: Vec<String>,
+        collect_stats: bool,
+        title_fallback: Vec<String>,
+        pandoc_attributes: bool,
+        svg_text_mode: String,
+        object_links: bool,
+        preserve_heading_ids: bool,
+        content_fingerprint: bool,
+        math_style: String,
+        infer_code_language: bool,
+        code_block_trim: bool,
+        srcset_policy: String,
+        figure_style: String,
+        details_style: String,
+        abbr_style: String,
+        time_style: String,
+        dfn_style: String,
+        url_param_allowlist: Vec<String>,
+        url_param_denylist: Option<Vec<String>>,
+        contact_link_style: String,
+        fidelity_threshold: f64,
+        title_footnotes: bool,
+        header_break_style: String,
+        report_dropped_content: bool,
+        split_documents: bool,
+        preserved_attributes: Vec<String>,
+        max_output_chars: Option<usize>,
+        max_output_tokens: Option<usize>,
     ) -> Self {
         Self {
             heading_style,
@@ -479,13 +700,64 @@ impl ConversionOptions {
             newline_style,
             code_block_style,
             keep_inline_images_in,
-            preprocessing: preprocessing
-                .unwrap_or_else(|| PreprocessingOptions::new(false, "standard".to_string(), true, true)),
+            preprocessing: preprocessing.unwrap_or_else(|| {
+                PreprocessingOptions::new(
+                    false,
+                    "standard".to_string(),
+                    true,
+                    true,
+                    false,
+                    false,
+                    false,
+                    Vec::new(),
+                    None,
+                )
+            }),
             debug,
             strip_tags,
+            remove_tags,
             preserve_tags,
             encoding,
             skip_images,
+            remove_space_before_punctuation,
+            nbsp_in_prose,
+            nbsp_in_tables,
+            front_matter_format,
+            remove_soft_hyphens,
+            bidi_mode,
+            localized_punctuation,
+            ordered_markers,
+            cycle_bullets,
+            list_spacing,
+            unrepresentable_policy,
+            post_processors,
+            collect_stats,
+            title_fallback,
+            pandoc_attributes,
+            svg_text_mode,
+            object_links,
+            preserve_heading_ids,
+            content_fingerprint,
+            math_style,
+            infer_code_language,
+            code_block_trim,
+            srcset_policy,
+            figure_style,
+            details_style,
+            abbr_style,
+            time_style,
+            dfn_style,
+            url_param_allowlist,
+            url_param_denylist,
+            contact_link_style,
+            fidelity_threshold,
+            title_footnotes,
+            header_break_style,
+            report_dropped_content,
+            split_documents,
+            preserved_attributes,
+            max_output_chars,
+            max_output_tokens,
         }
     }
 }
@@ -524,8 +796,52 @@ impl ConversionOptions {
             encoding: self.encoding.clone(),
             debug: self.debug,
             strip_tags: self.strip_tags.clone(),
+            remove_tags: self.remove_tags.clone(),
             preserve_tags: self.preserve_tags.clone(),
             skip_images: self.skip_images,
+            remove_space_before_punctuation: self.remove_space_before_punctuation,
+            nbsp_in_prose: NbspMode::parse(self.nbsp_in_prose.as_str()),
+            nbsp_in_tables: NbspMode::parse(self.nbsp_in_tables.as_str()),
+            front_matter_format: FrontMatterFormat::parse(self.front_matter_format.as_str()),
+            remove_soft_hyphens: self.remove_soft_hyphens,
+            bidi_mode: BidiMode::parse(self.bidi_mode.as_str()),
+            localized_punctuation: self.localized_punctuation,
+            ordered_markers: self.ordered_markers.clone(),
+            cycle_bullets: self.cycle_bullets,
+            list_spacing: ListSpacing::parse(self.list_spacing.as_str()),
+            unrepresentable_policy: UnrepresentablePolicy::parse(self.unrepresentable_policy.as_str()),
+            post_processors: PostProcessorChain::from_names(&self.post_processors),
+            collect_stats: self.collect_stats,
+            title_fallback: TitleSource::from_names(&self.title_fallback),
+            pandoc_attributes: self.pandoc_attributes,
+            svg_text_mode: SvgTextMode::parse(self.svg_text_mode.as_str()),
+            object_links: self.object_links,
+            preserve_heading_ids: self.preserve_heading_ids,
+            content_fingerprint: self.content_fingerprint,
+            math_style: MathStyle::parse(self.math_style.as_str()),
+            infer_code_language: self.infer_code_language,
+            code_block_trim: self.code_block_trim,
+            srcset_policy: SrcsetPolicy::parse(self.srcset_policy.as_str()),
+            figure_style: FigureStyle::parse(self.figure_style.as_str()),
+            details_style: DetailsStyle::parse(self.details_style.as_str()),
+            abbr_style: AbbrStyle::parse(self.abbr_style.as_str()),
+            time_style: TimeStyle::parse(self.time_style.as_str()),
+            dfn_style: DfnStyle::parse(self.dfn_style.as_str()),
+            url_param_allowlist: self.url_param_allowlist.clone(),
+            url_param_denylist: self
+                .url_param_denylist
+                .clone()
+                .unwrap_or_else(|| DEFAULT_URL_PARAM_DENYLIST.iter().map(ToString::to_string).collect()),
+            contact_link_style: ContactLinkStyle::parse(self.contact_link_style.as_str()),
+            fidelity_threshold: self.fidelity_threshold,
+            title_footnotes: self.title_footnotes,
+            header_break_style: HeaderBreakStyle::parse(self.header_break_style.as_str()),
+            report_dropped_content: self.report_dropped_content,
+            split_documents: self.split_documents,
+            preserved_attributes: self.preserved_attributes.clone(),
+            max_output_chars: self.max_output_chars.filter(|&max| max > 0),
+            max_output_tokens: self.max_output_tokens.filter(|&max| max > 0),
+            token_estimator: Default::default(),
         }
     }
 }
@@ -651,6 +967,132 @@ fn convert_json(py: Python<'_>, html: &str, options_json: Option<&str>) -> PyRes
         .map_err(to_py_err)
 }
 
+/// Convert HTML and return `(markdown, stats)`; `stats` is `None` unless `collect_stats` is set.
+#[pyfunction]
+#[pyo3(signature = (html, options_json=None))]
+fn convert_with_stats_json(py: Python<'_>, html: &str, options_json: Option<&str>) -> PyResult<(String, Py<PyAny>)> {
+    let html = html.to_owned();
+    let rust_options = parse_options_json(options_json)?;
+    let (markdown, stats) = py
+        .detach(move || {
+            run_with_guard_and_profile(|| html_to_markdown_rs::convert_with_stats(&html, rust_options.clone()))
+        })
+        .map_err(to_py_err)?;
+    let stats = match stats {
+        Some(stats) => stats_to_py(py, &stats)?,
+        None => py.None(),
+    };
+    Ok((markdown, stats))
+}
+
+/// Convert HTML and return `(markdown, fingerprint)`; `fingerprint` is `None` unless `content_fingerprint` is set.
+#[pyfunction]
+#[pyo3(signature = (html, options_json=None))]
+fn convert_with_fingerprint_json(
+    py: Python<'_>,
+    html: &str,
+    options_json: Option<&str>,
+) -> PyResult<(String, Option<String>)> {
+    let html = html.to_owned();
+    let rust_options = parse_options_json(options_json)?;
+    py.detach(move || {
+        run_with_guard_and_profile(|| html_to_markdown_rs::convert_with_fingerprint(&html, rust_options.clone()))
+    })
+    .map_err(to_py_err)
+}
+
+/// Classify HTML input as `"html"`, `"hocr"`, `"alto"`, `"mhtml"`, `"warc"`, `"xml"` or `"text"`.
+#[pyfunction]
+fn detect_input_format(html: &str) -> &'static str {
+    html_to_markdown_rs::detect_input_format(html).as_str()
+}
+
+fn stats_to_py(py: Python<'_>, stats: &html_to_markdown_rs::ConversionStats) -> PyResult<Py<PyAny>> {
+    let dict = PyDict::new(py);
+    dict.set_item("input_bytes", stats.input_bytes)?;
+    dict.set_item("output_bytes", stats.output_bytes)?;
+    dict.set_item("nodes_parsed", stats.nodes_parsed)?;
+    dict.set_item("peak_memory_estimate", stats.peak_memory_estimate)?;
+    dict.set_item("preprocess_ms", stats.preprocess_time.as_secs_f64() * 1000.0)?;
+    dict.set_item("parse_ms", stats.parse_time.as_secs_f64() * 1000.0)?;
+    dict.set_item("convert_ms", stats.convert_time.as_secs_f64() * 1000.0)?;
+    dict.set_item("postprocess_ms", stats.postprocess_time.as_secs_f64() * 1000.0)?;
+    dict.set_item("total_ms", stats.total_time().as_secs_f64() * 1000.0)?;
+    Ok(dict.into())
+}
+
+/// Number of threads for a batch: `max_workers` if given, else one per available CPU.
+fn batch_workers(max_workers: Option<usize>) -> PyResult<usize> {
+    match max_workers {
+        Some(0) => Err(pyo3::exceptions::PyValueError::new_err("max_workers must be greater than 0")),
+        Some(workers) => Ok(workers),
+        None => Ok(std::thread::available_parallelism().map_or(1, std::num::NonZero::get)),
+    }
+}
+
+/// Convert `htmls` on up to `workers` threads, one contiguous slice each, keeping input order.
+fn convert_batch_threads(
+    htmls: &[String],
+    options: Option<RustConversionOptions>,
+    workers: usize,
+) -> Vec<html_to_markdown_rs::Result<String>> {
+    let workers = workers.min(htmls.len());
+    if workers <= 1 {
+        return html_to_markdown_rs::convert_many(htmls, options);
+    }
+    let chunk_size = htmls.len().div_ceil(workers);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = htmls
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let options = options.clone();
+                (chunk.len(), scope.spawn(move || html_to_markdown_rs::convert_many(chunk, options)))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|(len, handle)| {
+                // `convert_many` catches conversion panics, so this only fails if the thread itself died.
+                handle.join().unwrap_or_else(|_| {
+                    (0..len)
+                        .map(|_| Err(ConversionError::Panic("batch worker thread panicked".to_string())))
+                        .collect()
+                })
+            })
+            .collect()
+    })
+}
+
+/// Convert a list of HTML documents with shared options, releasing the GIL for the whole batch.
+///
+/// Documents are spread over `max_workers` threads (default: one per CPU); `max_workers=1`
+/// converts them one after another.
+#[pyfunction]
+#[pyo3(signature = (htmls, options=None, max_workers=None))]
+fn convert_batch(
+    py: Python<'_>,
+    htmls: Vec<String>,
+    options: Option<ConversionOptions>,
+    max_workers: Option<usize>,
+) -> PyResult<Vec<String>> {
+    let workers = batch_workers(max_workers)?;
+    let rust_options = options.map(|opts| opts.to_rust());
+    collect_batch(py.detach(move || convert_batch_threads(&htmls, rust_options, workers)))
+}
+
+#[pyfunction]
+#[pyo3(signature = (htmls, options_json=None, max_workers=None))]
+fn convert_batch_json(
+    py: Python<'_>,
+    htmls: Vec<String>,
+    options_json: Option<&str>,
+    max_workers: Option<usize>,
+) -> PyResult<Vec<String>> {
+    let workers = batch_workers(max_workers)?;
+    let rust_options = parse_options_json(options_json)?;
+    collect_batch(py.detach(move || convert_batch_threads(&htmls, rust_options, workers)))
+}
+
 #[pyfunction]
 #[pyo3(signature = (html, handle))]
 fn convert_with_options_handle(py: Python<'_>, html: &str, handle: &ConversionOptionsHandle) -> PyResult<String> {
@@ -955,6 +1397,7 @@ fn document_metadata_to_py<'py>(py: Python<'py>, doc: RustDocumentMetadata) -> P
     dict.set_item("description", opt_string_to_py(py, doc.description)?)?;
     dict.set_item("keywords", doc.keywords)?;
     dict.set_item("author", opt_string_to_py(py, doc.author)?)?;
+    dict.set_item("author_profiles", author_profiles_to_py(py, doc.author_profiles)?)?;
     dict.set_item("canonical_url", opt_string_to_py(py, doc.canonical_url)?)?;
     dict.set_item("base_href", opt_string_to_py(py, doc.base_href)?)?;
     dict.set_item("language", opt_string_to_py(py, doc.language)?)?;
@@ -966,6 +1409,19 @@ fn document_metadata_to_py<'py>(py: Python<'py>, doc: RustDocumentMetadata) -> P
     Ok(dict.into())
 }
 
+#[cfg(feature = "metadata")]
+fn author_profiles_to_py<'py>(py: Python<'py>, profiles: Vec<RustAuthorProfile>) -> PyResult<Py<PyAny>> {
+    let list = PyList::empty(py);
+    for profile in profiles {
+        let dict = PyDict::new(py);
+        dict.set_item("url", profile.url)?;
+        dict.set_item("name", opt_string_to_py(py, profile.name)?)?;
+        dict.set_item("source", profile.source.to_string())?;
+        list.append(dict)?;
+    }
+    Ok(list.into())
+}
+
 #[cfg(feature = "metadata")]
 fn headers_to_py<'py>(py: Python<'py>, headers: Vec<RustHeaderMetadata>) -> PyResult<Py<PyAny>> {
     let list = PyList::empty(py);
@@ -1035,6 +1491,33 @@ fn structured_data_to_py<'py>(py: Python<'py>, data: Vec<RustStructuredData>) ->
     Ok(list.into())
 }
 
+#[cfg(feature = "metadata")]
+fn resources_to_py<'py>(py: Python<'py>, resources: Vec<RustResourceMetadata>) -> PyResult<Py<PyAny>> {
+    let list = PyList::empty(py);
+    for resource in resources {
+        let dict = PyDict::new(py);
+        dict.set_item("url", resource.url)?;
+        dict.set_item("resource_type", resource.resource_type.to_string())?;
+        list.append(dict)?;
+    }
+    Ok(list.into())
+}
+
+#[cfg(feature = "metadata")]
+fn source_map_to_py<'py>(py: Python<'py>, source_map: Vec<RustSourceMapEntry>) -> PyResult<Py<PyAny>> {
+    let list = PyList::empty(py);
+    for entry in source_map {
+        let dict = PyDict::new(py);
+        dict.set_item("tag", entry.tag)?;
+        dict.set_item("html_start", entry.html_start)?;
+        dict.set_item("html_end", entry.html_end)?;
+        dict.set_item("markdown_start", entry.markdown_start)?;
+        dict.set_item("markdown_end", entry.markdown_end)?;
+        list.append(dict)?;
+    }
+    Ok(list.into())
+}
+
 #[cfg(feature = "metadata")]
 fn extended_metadata_to_py<'py>(py: Python<'py>, metadata: RustExtendedMetadata) -> PyResult<Py<PyAny>> {
     let dict = PyDict::new(py);
@@ -1043,6 +1526,8 @@ fn extended_metadata_to_py<'py>(py: Python<'py>, metadata: RustExtendedMetadata)
     dict.set_item("links", links_to_py(py, metadata.links)?)?;
     dict.set_item("images", images_to_py(py, metadata.images)?)?;
     dict.set_item("structured_data", structured_data_to_py(py, metadata.structured_data)?)?;
+    dict.set_item("resources", resources_to_py(py, metadata.resources)?)?;
+    dict.set_item("source_map", source_map_to_py(py, metadata.source_map)?)?;
     Ok(dict.into())
 }
 
@@ -1067,6 +1552,8 @@ fn extended_metadata_to_py<'py>(py: Python<'py>, metadata: RustExtendedMetadata)
 ///         - extract_images: bool - Extract image elements
 ///         - extract_structured_data: bool - Extract JSON-LD/Microdata/RDFa
 ///         - max_structured_data_size: int - Size limit for structured data (bytes)
+///         - extract_resources: bool - Collect script/stylesheet/font/media URLs (off by default)
+///         - extract_source_map: bool - Record HTML/Markdown byte ranges of blocks (off by default)
 ///
 /// Returns:
 ///     tuple[str, dict]: A tuple of (markdown_string, metadata_dict) where:
@@ -1080,6 +1567,8 @@ fn extended_metadata_to_py<'py>(py: Python<'py>, metadata: RustExtendedMetadata)
 ///             - description: str | None - From <meta name="description">
 ///             - keywords: list[str] - Keywords from <meta name="keywords">
 ///             - author: str | None - Author from <meta name="author">
+///             - author_profiles: list[dict] - rel="author"/rel="me" links and schema.org
+///               author/sameAs URLs, each with url, name and source
 ///             - language: str | None - Language from lang attribute
 ///             - text_direction: str | None - Text direction ("ltr", "rtl", "auto")
 ///             - canonical_url: str | None - Canonical URL from <link rel="canonical">
@@ -1116,6 +1605,16 @@ fn extended_metadata_to_py<'py>(py: Python<'py>, metadata: RustExtendedMetadata)
 ///             - raw_json: str - Raw JSON string content
 ///             - schema_type: str | None - Schema type (e.g., "Article", "Event")
 ///
+///         - resources: list[dict] containing (only with extract_resources):
+///             - url: str - Resource URL as written in the document
+///             - resource_type: str - Type: "script", "stylesheet", "font", "image", "media",
+///               "embed" or "other"
+///
+///         - source_map: list[dict] containing (only with extract_source_map):
+///             - tag: str - Tag name of the block element (e.g., "p", "table")
+///             - html_start, html_end: int - Byte range of the element in the input HTML
+///             - markdown_start, markdown_end: int - Byte range of the block in the Markdown
+///
 /// Raises:
 ///     ValueError: If HTML parsing fails or configuration is invalid
 ///     RuntimeError: If a panic occurs during conversion
@@ -2870,6 +3369,11 @@ fn convert_with_async_visitor(
 fn _html_to_markdown(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(convert, m)?)?;
     m.add_function(wrap_pyfunction!(convert_json, m)?)?;
+    m.add_function(wrap_pyfunction!(convert_with_stats_json, m)?)?;
+    m.add_function(wrap_pyfunction!(convert_with_fingerprint_json, m)?)?;
+    m.add_function(wrap_pyfunction!(detect_input_format, m)?)?;
+    m.add_function(wrap_pyfunction!(convert_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(convert_batch_json, m)?)?;
     m.add_function(wrap_pyfunction!(convert_with_options_handle, m)?)?;
     m.add_function(wrap_pyfunction!(create_options_handle, m)?)?;
     m.add_function(wrap_pyfunction!(create_options_handle_json, m)?)?;
@@ -2949,8 +3453,48 @@ mod tests {
             false,
             Vec::new(),
             Vec::new(),
+            Vec::new(),
             "utf-8".to_string(),
             false,
+            false,
+            "space".to_string(),
+            "space".to_string(),
+            "yaml".to_string(),
+            true,
+            "ignore".to_string(),
+            false,
+            ".".to_string(),
+            true,
+            "auto".to_string(),
+            "drop".to_string(),
+            Vec::new(),
+            false,
+            Vec::new(),
+            false,
+            "off".to_string(),
+            false,
+            false,
+            false,
+            "comment".to_string(),
+            false,
+            true,
+            "first".to_string(),
+            "italic".to_string(),
+            "markdown".to_string(),
+            "expand".to_string(),
+            "text".to_string(),
+            "emphasis".to_string(),
+            Vec::new(),
+            None,
+            "link".to_string(),
+            1.0,
+            false,
+            "inherit".to_string(),
+            false,
+            false,
+            Vec::new(),
+            None,
+            None,
         );
         let rust_opts = opts.to_rust();
         assert_eq!(rust_opts.list_indent_width, 4);
@@ -2959,7 +3503,17 @@ mod tests {
 
     #[test]
     fn test_preprocessing_options_conversion() {
-        let preprocessing = PreprocessingOptions::new(true, "aggressive".to_string(), true, false);
+        let preprocessing = PreprocessingOptions::new(
+            true,
+            "aggressive".to_string(),
+            true,
+            false,
+            false,
+            false,
+            false,
+            Vec::new(),
+            None,
+        );
         let rust_preprocessing = preprocessing.to_rust();
         assert!(rust_preprocessing.enabled);
         assert!(matches!(
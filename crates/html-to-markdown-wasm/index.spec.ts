@@ -4,11 +4,16 @@ import { describe, expect, it } from "vitest";
 import {
 	convert,
 	convertBytes,
+	convertBytesToUint8Array,
 	convertBytesWithInlineImages,
 	convertBytesWithOptionsHandle,
+	ConvertStream,
+	convertToUint8Array,
 	convertWithInlineImages,
+	convertWithMetadata,
 	createConversionOptionsHandle,
 	WasmInlineImageConfig,
+	WasmMetadataConfig,
 } from "./dist-node/html_to_markdown_wasm.js";
 
 const loadTestDoc = (path: string): string => {
@@ -39,6 +44,14 @@ describe("html-to-markdown-wasm - WebAssembly Bindings", () => {
 			const markdown = convert(html, undefined);
 			expect(markdown).toContain("Test");
 		});
+
+		it("should reject misspelled options unless strictOptions is false", () => {
+			const html = "<h1>Test</h1>";
+			expect(() => convert(html, { headingStlye: "atx" })).toThrow(
+				"unknown option headingStlye (did you mean headingStyle?)",
+			);
+			expect(convert(html, { headingStlye: "atx", strictOptions: false })).toContain("Test");
+		});
 	});
 
 	describe("Byte-based Conversion", () => {
@@ -67,12 +80,51 @@ describe("html-to-markdown-wasm - WebAssembly Bindings", () => {
 			expect(result.markdown).toContain("buffered");
 		});
 
+		it("should return transferable UTF-8 results", () => {
+			const html = "<h1>Café</h1>";
+			const fromString = convertToUint8Array(html, null);
+			const fromBytes = convertBytesToUint8Array(new TextEncoder().encode(html), null);
+			expect(fromString).toBeInstanceOf(Uint8Array);
+			expect(fromString.byteOffset).toBe(0);
+			expect(fromString.byteLength).toBe(fromString.buffer.byteLength);
+			expect(new TextDecoder().decode(fromString)).toBe(convert(html));
+			expect(fromBytes).toEqual(fromString);
+		});
+
+		it("should stream chunks that split multi-byte characters", () => {
+			const bytes = new TextEncoder().encode("<h1>Café</h1><p>Body</p>");
+			const parts: string[] = [];
+			const stream = new ConvertStream((chunk: string) => parts.push(chunk), null);
+			for (let i = 0; i < bytes.length; i += 3) {
+				stream.push(bytes.subarray(i, i + 3));
+			}
+			stream.finish();
+			expect(parts.join("")).toBe(convert("<h1>Café</h1><p>Body</p>"));
+			expect(() => stream.push(bytes)).toThrow();
+		});
+
 		it("should reject invalid UTF-8 byte streams", () => {
 			const bytes = new Uint8Array([0xff, 0xfe, 0xfd]);
 			expect(() => convertBytes(bytes)).toThrow(/UTF-8/);
 		});
 	});
 
+	describe("Metadata Extraction", () => {
+		it("should return document, headers, links and images as plain objects", () => {
+			const html =
+				'<html><head><title>Page</title><meta property="og:title" content="OG"></head>' +
+				'<body><h1 id="top">Title</h1><a href="/docs">Docs</a><img src="a.png" alt="A"></body></html>';
+			const result = convertWithMetadata(html, null, new WasmMetadataConfig());
+
+			expect(result.markdown).toContain("# Title");
+			expect(result.metadata.document.title).toBe("Page");
+			expect(result.metadata.document.open_graph).toEqual({ title: "OG" });
+			expect(result.metadata.headers[0]).toMatchObject({ level: 1, text: "Title", id: "top" });
+			expect(result.metadata.links[0]).toMatchObject({ href: "/docs", link_type: "internal" });
+			expect(result.metadata.images[0]).toMatchObject({ src: "a.png", alt: "A" });
+		});
+	});
+
 	describe("Heading Styles", () => {
 		it("should use ATX style", () => {
 			const html = "<h1>Test</h1><h2>Subtest</h2>";
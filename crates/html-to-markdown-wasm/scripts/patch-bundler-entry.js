@@ -27,13 +27,27 @@ export type WasmWhitespaceMode = "normalized" | "strict";
 export type WasmNewlineStyle = "spaces" | "backslash";
 export type WasmCodeBlockStyle = "indented" | "backticks" | "tildes";
 export type WasmHighlightStyle = "doubleEqual" | "html" | "bold" | "none";
-export type WasmPreprocessingPreset = "minimal" | "standard" | "aggressive";
+export type WasmPreprocessingPreset = "minimal" | "standard" | "aggressive" | "confluence";
+export type WasmMathStyle = "comment" | "dollar" | "fenced";
+export type WasmSrcsetPolicy = "first" | "largest" | "smallest";
+export type WasmFigureStyle = "italic" | "blockquote" | "html";
+export type WasmDetailsStyle = "markdown" | "html";
+export type WasmAbbrStyle = "expand" | "text" | "html";
+export type WasmTimeStyle = "text" | "datetime" | "html";
+export type WasmDfnStyle = "emphasis" | "text" | "html";
+export type WasmHeaderBreakStyle = "inherit" | "space" | "slash" | "html";
+export type WasmContactLinkStyle = "link" | "autolink" | "text";
 
 export interface WasmPreprocessingOptions {
   enabled?: boolean;
   preset?: WasmPreprocessingPreset;
   removeNavigation?: boolean;
   removeForms?: boolean;
+  applyPrintStyles?: boolean;
+  extractMainContent?: boolean;
+  flattenShadowDom?: boolean;
+  excludeSelectors?: string[];
+  includeSelector?: string;
 }
 
 export interface WasmConversionOptions {
@@ -67,8 +81,98 @@ export interface WasmConversionOptions {
   encoding?: string;
   debug?: boolean;
   stripTags?: string[];
+  removeTags?: string[];
+  pandocAttributes?: boolean;
+  objectLinks?: boolean;
+  preserveHeadingIds?: boolean;
+  mathStyle?: WasmMathStyle;
+  inferCodeLanguage?: boolean;
+  codeBlockTrim?: boolean;
+  srcsetPolicy?: WasmSrcsetPolicy;
+  figureStyle?: WasmFigureStyle;
+  detailsStyle?: WasmDetailsStyle;
+  abbrStyle?: WasmAbbrStyle;
+  timeStyle?: WasmTimeStyle;
+  dfnStyle?: WasmDfnStyle;
+  urlParamAllowlist?: string[];
+  urlParamDenylist?: string[];
+  contactLinkStyle?: WasmContactLinkStyle;
+  fidelityThreshold?: number;
+  titleFootnotes?: boolean;
+  headerBreakStyle?: WasmHeaderBreakStyle;
+  reportDroppedContent?: boolean;
+  splitDocuments?: boolean;
+  preservedAttributes?: string[];
+  maxOutputChars?: number;
+  maxOutputTokens?: number;
   preserveTags?: string[];
 }
+
+export interface WasmDocumentMetadata {
+  title: string | null;
+  description: string | null;
+  keywords: string[];
+  author: string | null;
+  author_profiles: { url: string; name: string | null; source: "rel_author" | "rel_me" | "schema_author" | "schema_same_as" }[];
+  canonical_url: string | null;
+  base_href: string | null;
+  language: string | null;
+  text_direction: "ltr" | "rtl" | "auto" | null;
+  open_graph: Record<string, string>;
+  twitter_card: Record<string, string>;
+  meta_tags: Record<string, string>;
+}
+
+export interface WasmHeaderMetadata {
+  level: number;
+  text: string;
+  id: string | null;
+  depth: number;
+  html_offset: number;
+}
+
+export interface WasmLinkMetadata {
+  href: string;
+  text: string;
+  title: string | null;
+  link_type: "anchor" | "internal" | "external" | "email" | "phone" | "other";
+  rel: string[];
+  attributes: Record<string, string>;
+}
+
+export interface WasmImageMetadata {
+  src: string;
+  alt: string | null;
+  title: string | null;
+  dimensions: [number, number] | null;
+  image_type: "data_uri" | "inline_svg" | "external" | "relative";
+  attributes: Record<string, string>;
+}
+
+export interface WasmStructuredData {
+  data_type: "json_ld" | "microdata" | "rdfa";
+  raw_json: string;
+  schema_type: string | null;
+}
+
+export interface WasmResourceMetadata {
+  url: string;
+  resource_type: "script" | "stylesheet" | "font" | "image" | "media" | "embed" | "other";
+}
+
+export interface WasmExtendedMetadata {
+  document: WasmDocumentMetadata;
+  headers: WasmHeaderMetadata[];
+  links: WasmLinkMetadata[];
+  images: WasmImageMetadata[];
+  structured_data: WasmStructuredData[];
+  resources: WasmResourceMetadata[];
+}
+
+export interface WasmMetadataExtraction {
+  markdown: string;
+  metadata: WasmExtendedMetadata;
+}
 `;
 
 function injectTypedef(content, specifier) {
@@ -91,6 +195,9 @@ function patchJsDoc(targetPath, typeSpecifier) {
 
   jsContent = injectTypedef(jsContent, typeSpecifier);
 
+  const metadataReturnsPattern = /@returns\s+\{any\}(\s*\*\/\s*export function convert(?:Bytes)?WithMetadata\b)/g;
+  jsContent = jsContent.replace(metadataReturnsPattern, '@returns {import("' + typeSpecifier + '").WasmMetadataExtraction}$1');
+
   const optionsPattern = /@param\s+\{any\}\s+options/g;
   const optionsReplacement = '@param {WasmConversionOptions | null | undefined} [options]';
   jsContent = jsContent.replace(optionsPattern, optionsReplacement);
@@ -248,6 +355,10 @@ if (content.includes('options: any')) {
 }
 
 content = content.replace('readonly attributes: any;', 'readonly attributes: Record<string, string>;');
+content = content.replace(
+  /(export function convert(?:Bytes)?WithMetadata\([^)]*\)): any;/g,
+  '$1: WasmMetadataExtraction;'
+);
 
 if (!content.includes('interface WasmConversionOptions')) {
   content += `\n${typeDefinitions}`;
@@ -0,0 +1,22 @@
+//! WASI command entry point; see `html_to_markdown_wasm::wasi`.
+
+use std::io;
+use std::process::ExitCode;
+
+use html_to_markdown_wasm::wasi::{self, OPTIONS_ENV};
+
+fn main() -> ExitCode {
+    let result = wasi::run(
+        std::env::args().skip(1),
+        std::env::var(OPTIONS_ENV).ok(),
+        io::stdin().lock(),
+        io::stdout().lock(),
+    );
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("html-to-markdown-wasi: {err}");
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
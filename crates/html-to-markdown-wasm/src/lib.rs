@@ -12,8 +12,10 @@ use html_to_markdown_rs::MetadataConfigUpdate;
 #[cfg(any(feature = "js-bindings", feature = "wasmtime-testing"))]
 use html_to_markdown_rs::safety::guard_panic;
 use html_to_markdown_rs::{
-    CodeBlockStyle, ConversionOptions as RustConversionOptions, ConversionOptionsUpdate, HeadingStyle, HighlightStyle,
-    ListIndentType, NewlineStyle, PreprocessingOptionsUpdate, PreprocessingPreset, WhitespaceMode,
+    AbbrStyle, BidiMode, CodeBlockStyle, ContactLinkStyle, ConversionOptions as RustConversionOptions,
+    ConversionOptionsUpdate, DetailsStyle, DfnStyle, FigureStyle, FrontMatterFormat, HeaderBreakStyle, HeadingStyle,
+    HighlightStyle, ListIndentType, ListSpacing, MathStyle, NbspMode, NewlineStyle, PreprocessingOptionsUpdate,
+    PreprocessingPreset, SrcsetPolicy, SvgTextMode, TimeStyle, UnrepresentablePolicy, WhitespaceMode,
 };
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +27,12 @@ use wasm_bindgen::{JsCast, prelude::*};
 mod inline_images;
 #[cfg(feature = "js-bindings")]
 pub use inline_images::{WasmHtmlExtraction, WasmInlineImage, WasmInlineImageConfig, WasmInlineImageWarning};
+#[cfg(feature = "js-bindings")]
+mod stream;
+#[cfg(feature = "wasi")]
+pub mod wasi;
+#[cfg(feature = "js-bindings")]
+pub use stream::WasmConvertStream;
 
 #[cfg(feature = "js-bindings")]
 fn to_js_error(err: ConversionError) -> JsValue {
@@ -81,6 +89,276 @@ impl From<WasmListIndentType> for ListIndentType {
     }
 }
 
+/// Front matter format
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmFrontMatterFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl From<WasmFrontMatterFormat> for FrontMatterFormat {
+    fn from(val: WasmFrontMatterFormat) -> Self {
+        match val {
+            WasmFrontMatterFormat::Yaml => FrontMatterFormat::Yaml,
+            WasmFrontMatterFormat::Toml => FrontMatterFormat::Toml,
+            WasmFrontMatterFormat::Json => FrontMatterFormat::Json,
+        }
+    }
+}
+
+/// BiDi (right-to-left) handling mode
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmBidiMode {
+    Ignore,
+    Marks,
+    Embedding,
+    Html,
+}
+
+impl From<WasmBidiMode> for BidiMode {
+    fn from(val: WasmBidiMode) -> Self {
+        match val {
+            WasmBidiMode::Ignore => BidiMode::Ignore,
+            WasmBidiMode::Marks => BidiMode::Marks,
+            WasmBidiMode::Embedding => BidiMode::Embedding,
+            WasmBidiMode::Html => BidiMode::Html,
+        }
+    }
+}
+
+/// List item spacing
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmListSpacing {
+    Auto,
+    Tight,
+    Loose,
+}
+
+impl From<WasmListSpacing> for ListSpacing {
+    fn from(val: WasmListSpacing) -> Self {
+        match val {
+            WasmListSpacing::Auto => ListSpacing::Auto,
+            WasmListSpacing::Tight => ListSpacing::Tight,
+            WasmListSpacing::Loose => ListSpacing::Loose,
+        }
+    }
+}
+
+/// Handling of content without a Markdown equivalent
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmUnrepresentablePolicy {
+    Drop,
+    Passthrough,
+    Diagnose,
+}
+
+impl From<WasmUnrepresentablePolicy> for UnrepresentablePolicy {
+    fn from(val: WasmUnrepresentablePolicy) -> Self {
+        match val {
+            WasmUnrepresentablePolicy::Drop => UnrepresentablePolicy::Drop,
+            WasmUnrepresentablePolicy::Passthrough => UnrepresentablePolicy::Passthrough,
+            WasmUnrepresentablePolicy::Diagnose => UnrepresentablePolicy::Diagnose,
+        }
+    }
+}
+
+/// How inline SVG text is surfaced
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmSvgTextMode {
+    Off,
+    Alt,
+    Caption,
+    Replace,
+}
+
+impl From<WasmSvgTextMode> for SvgTextMode {
+    fn from(val: WasmSvgTextMode) -> Self {
+        match val {
+            WasmSvgTextMode::Off => SvgTextMode::Off,
+            WasmSvgTextMode::Alt => SvgTextMode::Alt,
+            WasmSvgTextMode::Caption => SvgTextMode::Caption,
+            WasmSvgTextMode::Replace => SvgTextMode::Replace,
+        }
+    }
+}
+
+/// How MathML and TeX math is written
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmMathStyle {
+    Comment,
+    Dollar,
+    Fenced,
+}
+
+impl From<WasmMathStyle> for MathStyle {
+    fn from(val: WasmMathStyle) -> Self {
+        match val {
+            WasmMathStyle::Comment => MathStyle::Comment,
+            WasmMathStyle::Dollar => MathStyle::Dollar,
+            WasmMathStyle::Fenced => MathStyle::Fenced,
+        }
+    }
+}
+
+/// Which srcset candidate replaces a missing img src
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmSrcsetPolicy {
+    First,
+    Largest,
+    Smallest,
+}
+
+impl From<WasmSrcsetPolicy> for SrcsetPolicy {
+    fn from(val: WasmSrcsetPolicy) -> Self {
+        match val {
+            WasmSrcsetPolicy::First => SrcsetPolicy::First,
+            WasmSrcsetPolicy::Largest => SrcsetPolicy::Largest,
+            WasmSrcsetPolicy::Smallest => SrcsetPolicy::Smallest,
+        }
+    }
+}
+
+/// How figure captions are written
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmFigureStyle {
+    Italic,
+    Blockquote,
+    Html,
+}
+
+impl From<WasmFigureStyle> for FigureStyle {
+    fn from(val: WasmFigureStyle) -> Self {
+        match val {
+            WasmFigureStyle::Italic => FigureStyle::Italic,
+            WasmFigureStyle::Blockquote => FigureStyle::Blockquote,
+            WasmFigureStyle::Html => FigureStyle::Html,
+        }
+    }
+}
+
+/// How details sections are written
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmDetailsStyle {
+    Markdown,
+    Html,
+}
+
+impl From<WasmDetailsStyle> for DetailsStyle {
+    fn from(val: WasmDetailsStyle) -> Self {
+        match val {
+            WasmDetailsStyle::Markdown => DetailsStyle::Markdown,
+            WasmDetailsStyle::Html => DetailsStyle::Html,
+        }
+    }
+}
+
+/// How abbreviations are written
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmAbbrStyle {
+    Expand,
+    Text,
+    Html,
+}
+
+impl From<WasmAbbrStyle> for AbbrStyle {
+    fn from(val: WasmAbbrStyle) -> Self {
+        match val {
+            WasmAbbrStyle::Expand => AbbrStyle::Expand,
+            WasmAbbrStyle::Text => AbbrStyle::Text,
+            WasmAbbrStyle::Html => AbbrStyle::Html,
+        }
+    }
+}
+
+/// How time elements are written
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmTimeStyle {
+    Text,
+    Datetime,
+    Html,
+}
+
+impl From<WasmTimeStyle> for TimeStyle {
+    fn from(val: WasmTimeStyle) -> Self {
+        match val {
+            WasmTimeStyle::Text => TimeStyle::Text,
+            WasmTimeStyle::Datetime => TimeStyle::Datetime,
+            WasmTimeStyle::Html => TimeStyle::Html,
+        }
+    }
+}
+
+/// How definition terms are written
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmDfnStyle {
+    Emphasis,
+    Text,
+    Html,
+}
+
+impl From<WasmDfnStyle> for DfnStyle {
+    fn from(val: WasmDfnStyle) -> Self {
+        match val {
+            WasmDfnStyle::Emphasis => DfnStyle::Emphasis,
+            WasmDfnStyle::Text => DfnStyle::Text,
+            WasmDfnStyle::Html => DfnStyle::Html,
+        }
+    }
+}
+
+/// How mailto and tel links are written
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmContactLinkStyle {
+    Link,
+    Autolink,
+    Text,
+}
+
+impl From<WasmContactLinkStyle> for ContactLinkStyle {
+    fn from(val: WasmContactLinkStyle) -> Self {
+        match val {
+            WasmContactLinkStyle::Link => ContactLinkStyle::Link,
+            WasmContactLinkStyle::Autolink => ContactLinkStyle::Autolink,
+            WasmContactLinkStyle::Text => ContactLinkStyle::Text,
+        }
+    }
+}
+
+/// What br becomes inside headings and header cells
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmHeaderBreakStyle {
+    Inherit,
+    Space,
+    Slash,
+    Html,
+}
+
+impl From<WasmHeaderBreakStyle> for HeaderBreakStyle {
+    fn from(val: WasmHeaderBreakStyle) -> Self {
+        match val {
+            WasmHeaderBreakStyle::Inherit => HeaderBreakStyle::Inherit,
+            WasmHeaderBreakStyle::Space => HeaderBreakStyle::Space,
+            WasmHeaderBreakStyle::Slash => HeaderBreakStyle::Slash,
+            WasmHeaderBreakStyle::Html => HeaderBreakStyle::Html,
+        }
+    }
+}
+
 /// Whitespace handling mode
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -98,6 +376,23 @@ impl From<WasmWhitespaceMode> for WhitespaceMode {
     }
 }
 
+/// Non-breaking space handling
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WasmNbspMode {
+    Space,
+    Preserve,
+}
+
+impl From<WasmNbspMode> for NbspMode {
+    fn from(val: WasmNbspMode) -> Self {
+        match val {
+            WasmNbspMode::Space => NbspMode::Space,
+            WasmNbspMode::Preserve => NbspMode::Preserve,
+        }
+    }
+}
+
 /// Newline style
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -171,6 +466,7 @@ pub enum WasmPreprocessingPreset {
     Minimal,
     Standard,
     Aggressive,
+    Confluence,
 }
 
 impl From<WasmPreprocessingPreset> for PreprocessingPreset {
@@ -179,6 +475,7 @@ impl From<WasmPreprocessingPreset> for PreprocessingPreset {
             WasmPreprocessingPreset::Minimal => PreprocessingPreset::Minimal,
             WasmPreprocessingPreset::Standard => PreprocessingPreset::Standard,
             WasmPreprocessingPreset::Aggressive => PreprocessingPreset::Aggressive,
+            WasmPreprocessingPreset::Confluence => PreprocessingPreset::Confluence,
         }
     }
 }
@@ -199,6 +496,21 @@ pub struct WasmPreprocessingOptions {
     /// Remove form elements
     #[serde(default = "default_true")]
     pub remove_forms: bool,
+    /// Drop elements hidden by the print stylesheet
+    #[serde(default)]
+    pub apply_print_styles: bool,
+    /// Convert only the main article content
+    #[serde(default)]
+    pub extract_main_content: bool,
+    /// Replace declarative shadow roots (`<template shadowrootmode>`) with their hosts' composed content
+    #[serde(default)]
+    pub flatten_shadow_dom: bool,
+    /// CSS selectors whose matching elements are dropped with their content
+    #[serde(default)]
+    pub exclude_selectors: Vec<String>,
+    /// CSS selector for the only elements to convert
+    #[serde(default)]
+    pub include_selector: Option<String>,
 }
 
 fn default_true() -> bool {
@@ -212,6 +524,11 @@ impl From<WasmPreprocessingOptions> for PreprocessingOptionsUpdate {
             preset: val.preset.map(Into::into),
             remove_navigation: Some(val.remove_navigation),
             remove_forms: Some(val.remove_forms),
+            apply_print_styles: Some(val.apply_print_styles),
+            extract_main_content: Some(val.extract_main_content),
+            flatten_shadow_dom: Some(val.flatten_shadow_dom),
+            exclude_selectors: Some(val.exclude_selectors),
+            include_selector: val.include_selector,
         }
     }
 }
@@ -282,8 +599,88 @@ pub struct WasmConversionOptions {
     pub debug: Option<bool>,
     /// List of HTML tags to strip
     pub strip_tags: Option<Vec<String>>,
+    /// List of HTML tags to remove together with their content
+    pub remove_tags: Option<Vec<String>>,
     /// List of HTML tags to preserve as-is in the output
     pub preserve_tags: Option<Vec<String>>,
+    /// Remove whitespace between inline elements and following punctuation
+    pub remove_space_before_punctuation: Option<bool>,
+    /// Non-breaking space handling in prose
+    pub nbsp_in_prose: Option<WasmNbspMode>,
+    /// Non-breaking space handling in table cells
+    pub nbsp_in_tables: Option<WasmNbspMode>,
+    /// Front matter format for extracted metadata
+    pub front_matter_format: Option<WasmFrontMatterFormat>,
+    /// Remove soft hyphens and word joiners from text
+    pub remove_soft_hyphens: Option<bool>,
+    /// Handling of right-to-left (`dir`) sections
+    pub bidi_mode: Option<WasmBidiMode>,
+    /// Use language-appropriate punctuation for generated quotes and parentheses
+    pub localized_punctuation: Option<bool>,
+    /// Ordered list delimiters per nesting level (`.` or `)`)
+    pub ordered_markers: Option<String>,
+    /// Cycle bullets by nesting level (false keeps the first bullet everywhere)
+    pub cycle_bullets: Option<bool>,
+    /// Force tight or loose lists (default: follow the source)
+    pub list_spacing: Option<WasmListSpacing>,
+    /// Handling of content without a Markdown equivalent (drop, passthrough, diagnose)
+    pub unrepresentable_policy: Option<WasmUnrepresentablePolicy>,
+    /// Post-processors to run on the output, in order (`compact-blank-lines`, `normalize-urls`, `prune-empty-elements`, `reference-links`, `toc`)
+    pub post_processors: Option<Vec<String>>,
+    /// Collect a resource usage report, returned by `convertWithStats`
+    pub collect_stats: Option<bool>,
+    /// Title sources tried in order for links without a title and documents without `<title>` (`og-title`, `first-heading`, `url-basename`)
+    pub title_fallback: Option<Vec<String>>,
+    /// Emit Pandoc attribute blocks (`{#id .class}`) for headings, code blocks, links and images
+    pub pandoc_attributes: Option<bool>,
+    /// How inline SVG text (title, desc, labels) is surfaced
+    pub svg_text_mode: Option<WasmSvgTextMode>,
+    /// Link the `data`/`src` URL of `<object>` and `<embed>` elements
+    pub object_links: Option<bool>,
+    /// Keep heading ids as `<a id>` anchors (or `{#id}` with `pandocAttributes`)
+    pub preserve_heading_ids: Option<bool>,
+    /// Compute a content fingerprint of the output
+    pub content_fingerprint: Option<bool>,
+    /// How MathML and TeX math is written (comment, dollar, fenced)
+    pub math_style: Option<WasmMathStyle>,
+    /// Infer the fence language of code blocks from highlighter classes, `data-lang` or the code
+    pub infer_code_language: Option<bool>,
+    /// Drop blank lines at the start and end of `<pre>` code blocks
+    pub code_block_trim: Option<bool>,
+    /// Which srcset candidate replaces a missing img src (first, largest, smallest)
+    pub srcset_policy: Option<WasmSrcsetPolicy>,
+    /// How figure captions are written (italic, blockquote, html)
+    pub figure_style: Option<WasmFigureStyle>,
+    /// How details sections are written (markdown, html)
+    pub details_style: Option<WasmDetailsStyle>,
+    /// How abbreviations are written (expand, text, html)
+    pub abbr_style: Option<WasmAbbrStyle>,
+    /// How time elements are written (text, datetime, html)
+    pub time_style: Option<WasmTimeStyle>,
+    /// How definition terms are written (emphasis, text, html)
+    pub dfn_style: Option<WasmDfnStyle>,
+    /// Query parameters `normalize-urls` keeps; when set, all others are dropped
+    pub url_param_allowlist: Option<Vec<String>>,
+    /// Query parameters `normalize-urls` drops (default: tracking parameters such as `utm_*`)
+    pub url_param_denylist: Option<Vec<String>>,
+    /// How mailto and tel links are written (link, autolink, text)
+    pub contact_link_style: Option<WasmContactLinkStyle>,
+    /// Keep table, form and MathML subtrees as HTML when more than this fraction of their structure would be lost (0.0-1.0, 1.0 disables)
+    pub fidelity_threshold: Option<f64>,
+    /// Turn title attributes on links, abbreviations and spans into footnotes
+    pub title_footnotes: Option<bool>,
+    /// What br becomes inside headings and header cells (inherit, space, slash, html)
+    pub header_break_style: Option<WasmHeaderBreakStyle>,
+    /// Report how much text each removal rule dropped, as diagnostics
+    pub report_dropped_content: Option<bool>,
+    /// Convert concatenated HTML documents one at a time, separated by thematic breaks
+    pub split_documents: Option<bool>,
+    /// Attributes kept on elements written out as HTML; when set, all others are dropped
+    pub preserved_attributes: Option<Vec<String>>,
+    /// Cap the output at this many characters, cutting between top-level blocks (0 removes the cap)
+    pub max_output_chars: Option<usize>,
+    /// Cap the output at this many estimated tokens, cutting between top-level blocks (0 removes the cap)
+    pub max_output_tokens: Option<usize>,
 }
 
 impl From<WasmConversionOptions> for ConversionOptionsUpdate {
@@ -320,7 +717,47 @@ impl From<WasmConversionOptions> for ConversionOptionsUpdate {
             encoding: val.encoding,
             debug: val.debug,
             strip_tags: val.strip_tags,
+            remove_tags: val.remove_tags,
             preserve_tags: val.preserve_tags,
+            remove_space_before_punctuation: val.remove_space_before_punctuation,
+            nbsp_in_prose: val.nbsp_in_prose.map(Into::into),
+            nbsp_in_tables: val.nbsp_in_tables.map(Into::into),
+            front_matter_format: val.front_matter_format.map(Into::into),
+            remove_soft_hyphens: val.remove_soft_hyphens,
+            bidi_mode: val.bidi_mode.map(Into::into),
+            localized_punctuation: val.localized_punctuation,
+            ordered_markers: val.ordered_markers,
+            cycle_bullets: val.cycle_bullets,
+            list_spacing: val.list_spacing.map(Into::into),
+            unrepresentable_policy: val.unrepresentable_policy.map(Into::into),
+            post_processors: val.post_processors,
+            collect_stats: val.collect_stats,
+            title_fallback: val.title_fallback,
+            pandoc_attributes: val.pandoc_attributes,
+            svg_text_mode: val.svg_text_mode.map(Into::into),
+            object_links: val.object_links,
+            preserve_heading_ids: val.preserve_heading_ids,
+            content_fingerprint: val.content_fingerprint,
+            math_style: val.math_style.map(Into::into),
+            infer_code_language: val.infer_code_language,
+            code_block_trim: val.code_block_trim,
+            srcset_policy: val.srcset_policy.map(Into::into),
+            figure_style: val.figure_style.map(Into::into),
+            details_style: val.details_style.map(Into::into),
+            abbr_style: val.abbr_style.map(Into::into),
+            time_style: val.time_style.map(Into::into),
+            dfn_style: val.dfn_style.map(Into::into),
+            url_param_allowlist: val.url_param_allowlist,
+            url_param_denylist: val.url_param_denylist,
+            contact_link_style: val.contact_link_style.map(Into::into),
+            fidelity_threshold: val.fidelity_threshold,
+            title_footnotes: val.title_footnotes,
+            header_break_style: val.header_break_style.map(Into::into),
+            report_dropped_content: val.report_dropped_content,
+            split_documents: val.split_documents,
+            preserved_attributes: val.preserved_attributes,
+            max_output_chars: val.max_output_chars,
+            max_output_tokens: val.max_output_tokens,
         }
     }
 }
@@ -343,7 +780,9 @@ fn parse_wasm_options(options: JsValue) -> Result<Option<RustConversionOptions>,
         }
     }
 
-    let update: html_to_markdown_rs::ConversionOptionsUpdate = serde_wasm_bindgen::from_value(options)
+    let value: serde_json::Value = serde_wasm_bindgen::from_value(options)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse options: {}", e)))?;
+    let update = html_to_markdown_rs::ConversionOptionsUpdate::from_json_value_strict(value)
         .map_err(|e| JsValue::from_str(&format!("Failed to parse options: {}", e)))?;
     Ok(Some(update.into()))
 }
@@ -455,6 +894,64 @@ pub fn convert_bytes_with_options_handle(
     }
 }
 
+#[cfg(feature = "js-bindings")]
+fn convert_to_utf8(html: &str, options: Option<RustConversionOptions>) -> Result<Vec<u8>, JsValue> {
+    guard_panic(|| {
+        let mut markdown = Vec::new();
+        html_to_markdown_rs::convert_to_writer(html, options, &mut markdown)?;
+        Ok(markdown)
+    })
+    .map_err(to_js_error)
+}
+
+/// Convert HTML to Markdown, returning the result as UTF-8 bytes
+///
+/// The returned `Uint8Array` owns its `ArrayBuffer`, so a Web Worker can hand a large result to
+/// the main thread with `postMessage(result, [result.buffer])` instead of copying a string.
+///
+/// # Arguments
+///
+/// * `html` - The HTML string to convert
+/// * `options` - Optional conversion options (as a JavaScript object)
+///
+/// # Example
+///
+/// ```javascript
+/// import { convertToUint8Array } from 'html-to-markdown-wasm';
+///
+/// const bytes = convertToUint8Array(html);
+/// self.postMessage(bytes, [bytes.buffer]);
+/// ```
+#[cfg(feature = "js-bindings")]
+#[wasm_bindgen(js_name = convertToUint8Array)]
+pub fn convert_to_uint8_array(html: String, options: JsValue) -> Result<Vec<u8>, JsValue> {
+    let rust_options = parse_wasm_options(options)?;
+    convert_to_utf8(&html, rust_options)
+}
+
+/// Convert HTML bytes to Markdown, returning the result as UTF-8 bytes
+///
+/// Byte-in, byte-out counterpart of `convertToUint8Array`, for workers that receive the HTML
+/// as a transferred buffer as well.
+#[cfg(feature = "js-bindings")]
+#[wasm_bindgen(js_name = convertBytesToUint8Array)]
+pub fn convert_bytes_to_uint8_array(html: js_sys::Uint8Array, options: JsValue) -> Result<Vec<u8>, JsValue> {
+    let html = bytes_to_string(html)?;
+    let rust_options = parse_wasm_options(options)?;
+    convert_to_utf8(&html, rust_options)
+}
+
+/// Convert HTML bytes to Markdown with a pre-parsed options handle, returning UTF-8 bytes
+#[cfg(feature = "js-bindings")]
+#[wasm_bindgen(js_name = convertBytesWithOptionsHandleToUint8Array)]
+pub fn convert_bytes_with_options_handle_to_uint8_array(
+    html: js_sys::Uint8Array,
+    handle: &WasmConversionOptionsHandle,
+) -> Result<Vec<u8>, JsValue> {
+    let html = bytes_to_string(html)?;
+    convert_to_utf8(&html, Some(handle.inner.clone()))
+}
+
 /// Convert HTML to Markdown while collecting inline images
 ///
 /// # Arguments
@@ -534,6 +1031,8 @@ pub struct WasmMetadataConfig {
     extract_links: bool,
     extract_images: bool,
     extract_structured_data: bool,
+    extract_resources: bool,
+    extract_source_map: bool,
     max_structured_data_size: usize,
 }
 
@@ -542,7 +1041,7 @@ pub struct WasmMetadataConfig {
 impl WasmMetadataConfig {
     /// Create a new metadata configuration with defaults
     ///
-    /// All extraction types enabled by default with 1MB structured data limit
+    /// All extraction types except resources and the source map enabled by default with 1MB structured data limit
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
         Self {
@@ -551,6 +1050,8 @@ impl WasmMetadataConfig {
             extract_links: true,
             extract_images: true,
             extract_structured_data: true,
+            extract_resources: false,
+            extract_source_map: false,
             max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
         }
     }
@@ -605,6 +1106,26 @@ impl WasmMetadataConfig {
         self.extract_structured_data = value;
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn extract_resources(&self) -> bool {
+        self.extract_resources
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_extract_resources(&mut self, value: bool) {
+        self.extract_resources = value;
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn extract_source_map(&self) -> bool {
+        self.extract_source_map
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_extract_source_map(&mut self, value: bool) {
+        self.extract_source_map = value;
+    }
+
     #[wasm_bindgen(getter)]
     pub fn max_structured_data_size(&self) -> usize {
         self.max_structured_data_size
@@ -632,6 +1153,8 @@ impl From<WasmMetadataConfig> for html_to_markdown_rs::MetadataConfig {
             extract_links: Some(cfg.extract_links),
             extract_images: Some(cfg.extract_images),
             extract_structured_data: Some(cfg.extract_structured_data),
+            extract_resources: Some(cfg.extract_resources),
+            extract_source_map: Some(cfg.extract_source_map),
             max_structured_data_size: Some(cfg.max_structured_data_size),
         };
         html_to_markdown_rs::MetadataConfig::from(update)
@@ -648,7 +1171,10 @@ impl From<WasmMetadataConfig> for html_to_markdown_rs::MetadataConfig {
 ///
 /// # Returns
 ///
-/// JavaScript object with `markdown` (string) and `metadata` (object) fields
+/// JavaScript object with `markdown` (string) and `metadata` (object) fields. `metadata` holds
+/// `document`, `headers`, `links`, `images`, `structured_data`, `resources` and `source_map` as
+/// plain objects and arrays with snake_case keys, the same shape `convertWithMetadata` returns in
+/// Node.
 ///
 /// # Example
 ///
@@ -687,7 +1213,10 @@ pub fn convert_with_metadata(
     }
     .map_err(to_js_error)?;
 
-    let metadata_js = serde_wasm_bindgen::to_value(&metadata).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    // `json_compatible` turns maps into plain objects, matching the shape the Node binding returns.
+    let metadata_js = metadata
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
 
     let result = js_sys::Object::new();
     js_sys::Reflect::set(&result, &JsValue::from_str("markdown"), &JsValue::from_str(&markdown))
@@ -873,7 +1402,47 @@ mod tests {
             encoding: None,
             debug: None,
             strip_tags: None,
+            remove_tags: None,
             preserve_tags: None,
+            remove_space_before_punctuation: None,
+            nbsp_in_prose: None,
+            nbsp_in_tables: None,
+            front_matter_format: None,
+            remove_soft_hyphens: None,
+            bidi_mode: None,
+            localized_punctuation: None,
+            ordered_markers: None,
+            cycle_bullets: None,
+            list_spacing: None,
+            unrepresentable_policy: None,
+            post_processors: None,
+            collect_stats: None,
+            title_fallback: None,
+            pandoc_attributes: None,
+            svg_text_mode: None,
+            object_links: None,
+            preserve_heading_ids: None,
+            content_fingerprint: None,
+            math_style: None,
+            infer_code_language: None,
+            code_block_trim: None,
+            srcset_policy: None,
+            figure_style: None,
+            details_style: None,
+            abbr_style: None,
+            time_style: None,
+            dfn_style: None,
+            url_param_allowlist: None,
+            url_param_denylist: None,
+            contact_link_style: None,
+            fidelity_threshold: None,
+            title_footnotes: None,
+            header_break_style: None,
+            report_dropped_content: None,
+            split_documents: None,
+            preserved_attributes: None,
+            max_output_chars: None,
+            max_output_tokens: None,
         };
 
         let js_options = serde_wasm_bindgen::to_value(&options).unwrap();
@@ -881,6 +1450,47 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[wasm_bindgen_test]
+    fn test_convert_to_uint8_array_matches_convert() {
+        let html = "<h1>Café</h1><p>Body</p>";
+        let expected = convert(html.to_string(), JsValue::UNDEFINED).unwrap();
+
+        let bytes = convert_to_uint8_array(html.to_string(), JsValue::UNDEFINED).unwrap();
+        assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+
+        let input = js_sys::Uint8Array::from(html.as_bytes());
+        let handle = WasmConversionOptionsHandle::new(JsValue::UNDEFINED).unwrap();
+        let bytes = convert_bytes_with_options_handle_to_uint8_array(input, &handle).unwrap();
+        assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_stream_matches_convert() {
+        let html = "<h1>Café</h1><p>One</p><ul><li>Two</li></ul>";
+        let chunks = js_sys::Array::new();
+        let on_chunk = js_sys::Function::new_with_args("chunk", "this.push(chunk)").bind(&chunks);
+        let mut stream = WasmConvertStream::new(on_chunk, JsValue::UNDEFINED).unwrap();
+
+        // Split inside the two-byte "é" to exercise incomplete trailing characters.
+        let bytes = html.as_bytes();
+        let split = html.find('é').unwrap() + 1;
+        stream.push(js_sys::Uint8Array::from(&bytes[..split])).unwrap();
+        stream.push(js_sys::Uint8Array::from(&bytes[split..])).unwrap();
+        stream.finish().unwrap();
+
+        let streamed: String = chunks.iter().map(|chunk| chunk.as_string().unwrap()).collect();
+        assert_eq!(streamed, convert(html.to_string(), JsValue::UNDEFINED).unwrap());
+        assert!(stream.finish().is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_convert_stream_rejects_invalid_utf8() {
+        let on_chunk = js_sys::Function::new_no_args("");
+        let mut stream = WasmConvertStream::new(on_chunk, JsValue::UNDEFINED).unwrap();
+        stream.push(js_sys::Uint8Array::from(&b"<p>ok</p>"[..])).unwrap();
+        assert!(stream.push(js_sys::Uint8Array::from(&[0xff_u8, 0x41][..])).is_err());
+    }
+
     #[cfg(feature = "metadata")]
     #[wasm_bindgen_test]
     fn test_metadata_config_new() {
@@ -943,6 +1553,27 @@ mod tests {
         assert!(markdown_str.contains("Main Title"));
     }
 
+    #[cfg(feature = "metadata")]
+    #[wasm_bindgen_test]
+    fn test_convert_with_metadata_returns_plain_objects() {
+        let html = r#"<html><head><meta property="og:title" content="Page"></head><body><a href="/docs">Docs</a></body></html>"#
+            .to_string();
+        let config = WasmMetadataConfig::new();
+
+        let obj = convert_with_metadata(html, JsValue::UNDEFINED, Some(config)).unwrap();
+        let metadata = js_sys::Reflect::get(&obj, &JsValue::from_str("metadata")).unwrap();
+        let document = js_sys::Reflect::get(&metadata, &JsValue::from_str("document")).unwrap();
+        let open_graph = js_sys::Reflect::get(&document, &JsValue::from_str("open_graph")).unwrap();
+        assert!(!open_graph.is_instance_of::<js_sys::Map>());
+        let title = js_sys::Reflect::get(&open_graph, &JsValue::from_str("title")).unwrap();
+        assert_eq!(title.as_string().as_deref(), Some("Page"));
+
+        let links = js_sys::Reflect::get(&metadata, &JsValue::from_str("links")).unwrap();
+        let link = js_sys::Array::from(&links).get(0);
+        let link_type = js_sys::Reflect::get(&link, &JsValue::from_str("link_type")).unwrap();
+        assert_eq!(link_type.as_string().as_deref(), Some("internal"));
+    }
+
     #[cfg(feature = "metadata")]
     #[wasm_bindgen_test]
     fn test_convert_bytes_with_metadata() {
@@ -0,0 +1,144 @@
+#![allow(clippy::all, clippy::pedantic, clippy::nursery, missing_docs)]
+//! Conversion of HTML that arrives in pieces.
+//!
+//! Bytes pushed into a [`WasmConvertStream`] are copied straight into WebAssembly memory, so
+//! the page is held once instead of being concatenated on the JavaScript side first. Markdown is
+//! handed to a callback block by block, so the result is never held in one string either.
+
+use std::fmt;
+use std::panic::AssertUnwindSafe;
+
+use html_to_markdown_rs::safety::guard_panic;
+use html_to_markdown_rs::{ConversionOptions as RustConversionOptions, convert_to_fmt};
+use js_sys::{Function, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+use crate::{parse_wasm_options, to_js_error};
+
+/// Incremental HTML to Markdown converter
+///
+/// Feed UTF-8 bytes with `push()` as they arrive, e.g. from a `ReadableStream`, then call
+/// `finish()`. Chunks may end anywhere, including inside a multi-byte character. Invalid UTF-8
+/// is reported by the `push()` that completes it.
+///
+/// The converter needs the whole document (tables, reference links and headings look ahead),
+/// so Markdown is produced by `finish()`: `onChunk` receives it as strings, one or more
+/// top-level blocks at a time, never split inside a character. Joined, the chunks equal the
+/// output of `convert()`.
+///
+/// # Example
+///
+/// ```javascript
+/// import { ConvertStream } from 'html-to-markdown-wasm';
+///
+/// const parts = [];
+/// const stream = new ConvertStream((chunk) => parts.push(chunk), { headingStyle: 'atx' });
+/// for await (const bytes of response.body) {
+///   stream.push(bytes);
+/// }
+/// stream.finish();
+/// console.log(parts.join(''));
+/// ```
+#[wasm_bindgen(js_name = ConvertStream)]
+pub struct WasmConvertStream {
+    buffer: Vec<u8>,
+    /// Length of the prefix of `buffer` known to be valid UTF-8.
+    validated: usize,
+    options: Option<RustConversionOptions>,
+    on_chunk: Function,
+    finished: bool,
+}
+
+#[wasm_bindgen(js_class = ConvertStream)]
+impl WasmConvertStream {
+    #[wasm_bindgen(constructor)]
+    pub fn new(on_chunk: Function, options: JsValue) -> Result<WasmConvertStream, JsValue> {
+        Ok(Self {
+            buffer: Vec::new(),
+            validated: 0,
+            options: parse_wasm_options(options)?,
+            on_chunk,
+            finished: false,
+        })
+    }
+
+    /// Append a chunk of UTF-8 HTML bytes.
+    pub fn push(&mut self, bytes: Uint8Array) -> Result<(), JsValue> {
+        self.ensure_open()?;
+        let start = self.buffer.len();
+        self.buffer.resize(start + bytes.length() as usize, 0);
+        bytes.copy_to(&mut self.buffer[start..]);
+
+        match std::str::from_utf8(&self.buffer[self.validated..]) {
+            Ok(_) => self.validated = self.buffer.len(),
+            // An incomplete character at the end may be completed by the next chunk.
+            Err(err) if err.error_len().is_none() => self.validated += err.valid_up_to(),
+            Err(err) => {
+                self.finished = true;
+                return Err(JsValue::from_str(&format!(
+                    "HTML must be valid UTF-8: invalid byte at offset {}",
+                    self.validated + err.valid_up_to()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Convert everything pushed so far, passing the Markdown to `onChunk`.
+    ///
+    /// Errors thrown by `onChunk` stop the conversion and are rethrown. The stream cannot be
+    /// used afterwards.
+    pub fn finish(&mut self) -> Result<(), JsValue> {
+        self.ensure_open()?;
+        self.finished = true;
+        let buffer = std::mem::take(&mut self.buffer);
+        if self.validated != buffer.len() {
+            return Err(JsValue::from_str("HTML must be valid UTF-8: input ends inside a character"));
+        }
+        // Validated chunk by chunk in `push`, so this cannot fail.
+        let html = std::str::from_utf8(&buffer).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let mut writer = CallbackWriter {
+            callback: &self.on_chunk,
+            error: None,
+        };
+        // The writer is only inspected for `error` afterwards, which a panic cannot leave half-set.
+        let result = guard_panic(AssertUnwindSafe(|| {
+            convert_to_fmt(html, self.options.clone(), &mut writer)
+        }));
+        match (result, writer.error) {
+            (Ok(()), _) => Ok(()),
+            (Err(_), Some(thrown)) => Err(thrown),
+            (Err(err), None) => Err(to_js_error(err)),
+        }
+    }
+
+    fn ensure_open(&self) -> Result<(), JsValue> {
+        if self.finished {
+            Err(JsValue::from_str("ConvertStream is already finished"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Forwards every chunk to the JavaScript callback and keeps what it throws.
+struct CallbackWriter<'a> {
+    callback: &'a Function,
+    error: Option<JsValue>,
+}
+
+impl fmt::Write for CallbackWriter<'_> {
+    fn write_str(&mut self, chunk: &str) -> fmt::Result {
+        if chunk.is_empty() {
+            return Ok(());
+        }
+        match self.callback.call1(&JsValue::NULL, &JsValue::from_str(chunk)) {
+            Ok(_) => Ok(()),
+            Err(thrown) => {
+                self.error = Some(thrown);
+                Err(fmt::Error)
+            }
+        }
+    }
+}
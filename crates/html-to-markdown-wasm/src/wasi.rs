@@ -0,0 +1,115 @@
+#![allow(clippy::all, clippy::pedantic, clippy::nursery, missing_docs)]
+//! WASI command: HTML on stdin, Markdown on stdout.
+//!
+//! Built as the `html-to-markdown-wasi` binary for `wasm32-wasip1`, so the converter runs in any
+//! WASI runtime (Wasmtime, Fermyon Spin, wasmCloud, ...) without JavaScript:
+//!
+//! ```text
+//! cargo build -p html-to-markdown-wasm --release --target wasm32-wasip1 \
+//!     --no-default-features --features wasi --bin html-to-markdown-wasi
+//! wasmtime run html-to-markdown-wasi.wasm --options '{"headingStyle":"atx"}' < page.html
+//! ```
+//!
+//! Options are the camelCase JSON accepted by `convert()` in JavaScript. They are read from
+//! `--options <json>` or, when that is absent, from the [`OPTIONS_ENV`] environment variable.
+
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use html_to_markdown_rs::safety::guard_panic;
+use html_to_markdown_rs::{ConversionError, ConversionOptions as RustConversionOptions};
+
+/// Environment variable holding options JSON when `--options` is not given.
+pub const OPTIONS_ENV: &str = "HTML_TO_MARKDOWN_OPTIONS";
+
+const USAGE: &str = "\
+Usage: html-to-markdown-wasi [--options <JSON>]
+
+Reads HTML from stdin and writes Markdown to stdout.
+
+Options:
+  --options <JSON>  Conversion options as camelCase JSON (overrides $HTML_TO_MARKDOWN_OPTIONS)
+  -h, --help        Print this help
+  -V, --version     Print the version
+";
+
+/// Why the command failed.
+#[derive(Debug)]
+pub enum WasiError {
+    /// Invalid arguments or options JSON
+    Usage(String),
+    /// Reading stdin or writing stdout failed
+    Io(io::Error),
+    /// The conversion itself failed
+    Conversion(ConversionError),
+}
+
+impl WasiError {
+    /// Process exit code for this error: 2 for usage errors, 1 otherwise.
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Self::Usage(_) => 2,
+            Self::Io(_) | Self::Conversion(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for WasiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usage(message) => write!(f, "{message}\n\n{USAGE}"),
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::Conversion(err) => write!(f, "conversion failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for WasiError {}
+
+/// Run the command.
+///
+/// `args` excludes the program name. `env_options` is the value of [`OPTIONS_ENV`], if set.
+pub fn run<I, R, W>(args: I, env_options: Option<String>, mut input: R, mut output: W) -> Result<(), WasiError>
+where
+    I: IntoIterator<Item = String>,
+    R: Read,
+    W: Write,
+{
+    let mut options_json = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return output.write_all(USAGE.as_bytes()).map_err(WasiError::Io),
+            "-V" | "--version" => {
+                return writeln!(output, "html-to-markdown-wasi {}", env!("CARGO_PKG_VERSION")).map_err(WasiError::Io);
+            }
+            "--options" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| WasiError::Usage("--options requires a JSON value".to_string()))?;
+                options_json = Some(value);
+            }
+            _ => match arg.strip_prefix("--options=") {
+                Some(value) => options_json = Some(value.to_string()),
+                None => return Err(WasiError::Usage(format!("unexpected argument '{arg}'"))),
+            },
+        }
+    }
+    let options = parse_options(options_json.or(env_options).as_deref())?;
+
+    let mut html = String::new();
+    input.read_to_string(&mut html).map_err(WasiError::Io)?;
+
+    let markdown = guard_panic(|| html_to_markdown_rs::convert(&html, options)).map_err(WasiError::Conversion)?;
+    output.write_all(markdown.as_bytes()).map_err(WasiError::Io)?;
+    output.flush().map_err(WasiError::Io)
+}
+
+fn parse_options(json: Option<&str>) -> Result<Option<RustConversionOptions>, WasiError> {
+    match json.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(json) => serde_json::from_str(json)
+            .map(Some)
+            .map_err(|e| WasiError::Usage(format!("invalid options JSON: {e}"))),
+    }
+}
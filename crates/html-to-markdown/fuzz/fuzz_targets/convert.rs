@@ -0,0 +1,46 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use html_to_markdown_rs::{CodeBlockStyle, ConversionOptions, HeadingStyle, ListSpacing, convert};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input<'a> {
+    html: &'a str,
+    wrap: bool,
+    wrap_width: u8,
+    list_indent_width: u8,
+    br_in_tables: bool,
+    convert_as_inline: bool,
+    code_block_style: u8,
+    heading_style: u8,
+    list_spacing: u8,
+}
+
+fuzz_target!(|input: Input<'_>| {
+    let options = ConversionOptions {
+        wrap: input.wrap,
+        wrap_width: usize::from(input.wrap_width),
+        list_indent_width: usize::from(input.list_indent_width % 9),
+        br_in_tables: input.br_in_tables,
+        convert_as_inline: input.convert_as_inline,
+        code_block_style: match input.code_block_style % 3 {
+            0 => CodeBlockStyle::Indented,
+            1 => CodeBlockStyle::Backticks,
+            _ => CodeBlockStyle::Tildes,
+        },
+        heading_style: match input.heading_style % 3 {
+            0 => HeadingStyle::Underlined,
+            1 => HeadingStyle::Atx,
+            _ => HeadingStyle::AtxClosed,
+        },
+        list_spacing: match input.list_spacing % 3 {
+            0 => ListSpacing::Auto,
+            1 => ListSpacing::Tight,
+            _ => ListSpacing::Loose,
+        },
+        ..Default::default()
+    };
+    // Errors are fine; panics are what the fuzzer is looking for.
+    let _ = convert(input.html, Some(options));
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use html_to_markdown_rs::convert;
+use libfuzzer_sys::fuzz_target;
+
+// Wrap the input in an hOCR page so the fuzzer spends its time in the OCR code paths.
+fuzz_target!(|body: &str| {
+    let html = format!(
+        "<html><head><meta name=\"ocr-system\" content=\"tesseract 5\"></head>\
+         <body><div class=\"ocr_page\" title=\"bbox 0 0 1000 1000\">{body}</div></body></html>"
+    );
+    let _ = convert(&html, None);
+});
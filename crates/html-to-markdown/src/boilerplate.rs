@@ -0,0 +1,250 @@
+//! Cross-document boilerplate removal for batches.
+//!
+//! A site footer or newsletter signup looks like ordinary content inside one page; it only
+//! stands out when the same block shows up in most pages of a crawl. [`strip_boilerplate`]
+//! compares the Markdown of a whole batch block by block (paragraphs, lists, headings, fenced
+//! code) and removes every block that appears verbatim in enough of the documents.
+//! [`convert_many_deduplicated`](crate::convert_many_deduplicated) converts and strips in one
+//! call.
+
+use std::collections::{HashMap, HashSet};
+
+/// When a block counts as boilerplate.
+#[derive(Debug, Clone)]
+pub struct BoilerplateConfig {
+    /// Share of the batch (from `0.0` to `1.0`) a block must appear in to be removed. Default `0.6`.
+    pub min_share: f64,
+    /// Smallest batch that is looked at; smaller batches are left alone, since two pages sharing
+    /// a paragraph says little. Default `3`.
+    pub min_documents: usize,
+}
+
+impl Default for BoilerplateConfig {
+    fn default() -> Self {
+        Self {
+            min_share: 0.6,
+            min_documents: 3,
+        }
+    }
+}
+
+/// A block removed as boilerplate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize))]
+pub struct BoilerplateBlock {
+    /// The Markdown of the block.
+    pub text: String,
+    /// Number of documents it was removed from.
+    pub documents: usize,
+}
+
+/// What [`strip_boilerplate`] removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize))]
+pub struct BoilerplateReport {
+    /// Number of documents compared.
+    pub documents: usize,
+    /// Removed blocks, in the order they first appear in the batch.
+    pub blocks: Vec<BoilerplateBlock>,
+}
+
+impl BoilerplateReport {
+    /// Whether nothing was removed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+}
+
+/// Remove the blocks that appear in at least `config.min_share` of `documents`.
+///
+/// Blocks are compared after trimming trailing whitespace from each line. Blocks without any
+/// letters or digits (thematic breaks, lone table rules) and a leading front matter block are
+/// never removed.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::boilerplate::{BoilerplateConfig, strip_boilerplate};
+///
+/// let footer = "Subscribe to our newsletter";
+/// let mut pages: Vec<String> = ["One", "Two", "Three"]
+///     .iter()
+///     .map(|title| format!("# {title}\n\nBody of {title}.\n\n{footer}\n"))
+///     .collect();
+/// let report = strip_boilerplate(&mut pages, &BoilerplateConfig::default());
+///
+/// assert_eq!(pages[0], "# One\n\nBody of One.\n");
+/// assert_eq!(report.blocks[0].text, footer);
+/// assert_eq!(report.blocks[0].documents, 3);
+/// ```
+pub fn strip_boilerplate(documents: &mut [String], config: &BoilerplateConfig) -> BoilerplateReport {
+    let mut report = BoilerplateReport {
+        documents: documents.len(),
+        blocks: Vec::new(),
+    };
+    if documents.len() < config.min_documents.max(2) {
+        return report;
+    }
+
+    let split: Vec<Vec<Block>> = documents.iter().map(|doc| split_blocks(doc)).collect();
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let mut order: Vec<&str> = Vec::new();
+    for blocks in &split {
+        let mut seen = HashSet::new();
+        for block in blocks.iter().filter(|block| block.candidate) {
+            if seen.insert(block.key.as_str()) {
+                let count = counts.entry(block.key.as_str()).or_insert(0);
+                if *count == 0 {
+                    order.push(block.key.as_str());
+                }
+                *count += 1;
+            }
+        }
+    }
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let needed = ((config.min_share.clamp(0.0, 1.0) * documents.len() as f64).ceil() as usize).max(2);
+    let boilerplate: HashSet<&str> = order.iter().copied().filter(|key| counts[key] >= needed).collect();
+    if boilerplate.is_empty() {
+        return report;
+    }
+
+    report.blocks = order
+        .iter()
+        .filter(|key| boilerplate.contains(*key))
+        .map(|key| BoilerplateBlock {
+            text: (*key).to_string(),
+            documents: counts[key],
+        })
+        .collect();
+
+    let stripped: Vec<Option<String>> = split
+        .iter()
+        .map(|blocks| {
+            if !blocks.iter().any(|block| boilerplate.contains(block.key.as_str())) {
+                return None;
+            }
+            let kept: Vec<&str> = blocks
+                .iter()
+                .filter(|block| !boilerplate.contains(block.key.as_str()))
+                .map(|block| block.text.as_str())
+                .collect();
+            let mut markdown = kept.join("\n\n");
+            if !markdown.is_empty() {
+                markdown.push('\n');
+            }
+            Some(markdown)
+        })
+        .collect();
+    for (document, markdown) in documents.iter_mut().zip(stripped) {
+        if let Some(markdown) = markdown {
+            *document = markdown;
+        }
+    }
+    report
+}
+
+/// [`strip_boilerplate`] over the successful entries of a batch result.
+pub(crate) fn strip_results(results: &mut [crate::Result<String>], config: &BoilerplateConfig) -> BoilerplateReport {
+    let mut markdown: Vec<String> = results
+        .iter_mut()
+        .filter_map(|result| result.as_mut().ok().map(std::mem::take))
+        .collect();
+    let report = strip_boilerplate(&mut markdown, config);
+    for (result, markdown) in results
+        .iter_mut()
+        .filter_map(|result| result.as_mut().ok())
+        .zip(markdown)
+    {
+        *result = markdown;
+    }
+    report
+}
+
+struct Block {
+    /// The block with trailing whitespace removed from every line, for comparison.
+    key: String,
+    /// The block as written, hard line breaks included.
+    text: String,
+    candidate: bool,
+}
+
+/// Split Markdown into blank-line separated blocks, keeping fenced code blocks whole.
+fn split_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut lines: Vec<&str> = Vec::new();
+    let mut fence: Option<(char, usize)> = None;
+
+    for line in markdown.lines() {
+        if let Some((marker, len)) = fence {
+            lines.push(line);
+            let trimmed = line.trim();
+            if trimmed.len() >= len && trimmed.chars().all(|c| c == marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if line.trim().is_empty() {
+            finish_block(&mut lines, &mut blocks);
+            continue;
+        }
+        let trimmed = line.trim_start();
+        if let Some(marker) = trimmed.chars().next().filter(|c| matches!(c, '`' | '~')) {
+            let len = trimmed.chars().take_while(|&c| c == marker).count();
+            if len >= 3 {
+                fence = Some((marker, len));
+            }
+        }
+        lines.push(line);
+    }
+    finish_block(&mut lines, &mut blocks);
+    blocks
+}
+
+fn finish_block(lines: &mut Vec<&str>, blocks: &mut Vec<Block>) {
+    if lines.is_empty() {
+        return;
+    }
+    let text = lines.join("\n");
+    let key = lines.iter().map(|line| line.trim_end()).collect::<Vec<_>>().join("\n");
+    let front_matter = blocks.is_empty() && (key.starts_with("---\n") || key.starts_with("+++\n"));
+    let candidate = !front_matter && key.chars().any(char::is_alphanumeric);
+    blocks.push(Block { key, text, candidate });
+    lines.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fenced_code_stays_one_block() {
+        let blocks = split_blocks("Intro\n\n```\na\n\nb\n```\n\nOutro\n");
+        let keys: Vec<&str> = blocks.iter().map(|block| block.key.as_str()).collect();
+        assert_eq!(keys, ["Intro", "```\na\n\nb\n```", "Outro"]);
+    }
+
+    #[test]
+    fn test_front_matter_and_rules_are_kept() {
+        let mut documents: Vec<String> = (0..3)
+            .map(|i| format!("---\nlang: en\n---\n\nPage {i}\n\n---\n\nFooter\n"))
+            .collect();
+        let report = strip_boilerplate(&mut documents, &BoilerplateConfig::default());
+
+        assert_eq!(report.blocks.len(), 1);
+        assert_eq!(documents[1], "---\nlang: en\n---\n\nPage 1\n\n---\n");
+    }
+
+    #[test]
+    fn test_small_batches_are_left_alone() {
+        let mut documents = vec!["A\n\nFooter\n".to_string(), "B\n\nFooter\n".to_string()];
+        assert!(strip_boilerplate(&mut documents, &BoilerplateConfig::default()).is_empty());
+        assert_eq!(documents[0], "A\n\nFooter\n");
+    }
+}
@@ -0,0 +1,207 @@
+//! Caching of conversion results.
+//!
+//! A [`CachedConverter`] looks every conversion up in a [`ConversionCache`] before running it.
+//! Entries are keyed by [`cache_key`]: a hash of the HTML, a hash of the options and the crate
+//! version, so changing either the input, any option or the converter itself never returns a
+//! stale result. [`FsCache`] stores one file per entry and suits build pipelines that convert
+//! the same pages on every run; [`MemoryCache`] keeps entries for the life of the process.
+//!
+//! ```
+//! use html_to_markdown_rs::cache::{CachedConverter, MemoryCache};
+//!
+//! let converter = CachedConverter::new(MemoryCache::default());
+//! let first = converter.convert("<h1>Title</h1>", None).unwrap();
+//! let second = converter.convert("<h1>Title</h1>", None).unwrap();
+//!
+//! assert_eq!(first, second);
+//! assert_eq!(converter.stats().hits, 1);
+//! assert_eq!(converter.stats().misses, 1);
+//! ```
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use crate::error::Result;
+use crate::fingerprint::hash_bytes;
+use crate::options::ConversionOptions;
+
+/// Storage for converted Markdown, keyed by [`cache_key`].
+///
+/// Caches are best-effort: a lookup that fails for any reason should return `None`, and a
+/// failed store is ignored, so a broken cache slows conversions down but never fails them.
+pub trait ConversionCache: Send + Sync {
+    /// The Markdown stored under `key`, if any.
+    fn get(&self, key: &str) -> Option<String>;
+
+    /// Store `markdown` under `key`.
+    fn put(&self, key: &str, markdown: &str);
+}
+
+/// Cache key for converting `html` with `options` using this version of the crate.
+///
+/// The options part hashes their `Debug` form. Custom post-processors appear in it only by
+/// name, so give differently behaving processors different names when caching.
+#[must_use]
+pub fn cache_key(html: &str, options: &ConversionOptions) -> String {
+    let input = hash_bytes(html.as_bytes());
+    let options = hash_bytes(format!("{options:?}").as_bytes());
+    format!("{input:032x}-{options:032x}-{}", env!("CARGO_PKG_VERSION"))
+}
+
+/// Hit and miss counts of a [`CachedConverter`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Conversions answered from the cache.
+    pub hits: u64,
+    /// Conversions that ran and were then stored.
+    pub misses: u64,
+}
+
+/// Converter that consults a [`ConversionCache`] before converting.
+#[derive(Debug)]
+pub struct CachedConverter<C> {
+    cache: C,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<C: ConversionCache> CachedConverter<C> {
+    /// Wrap `cache`, starting with zeroed statistics.
+    pub const fn new(cache: C) -> Self {
+        Self {
+            cache,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Like [`convert`](crate::convert), but returns the cached Markdown for inputs and options
+    /// seen before. Failed conversions are not cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns the conversion error on a cache miss; hits cannot fail.
+    pub fn convert(&self, html: &str, options: Option<ConversionOptions>) -> Result<String> {
+        let options = options.unwrap_or_default();
+        let key = cache_key(html, &options);
+        if let Some(markdown) = self.cache.get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(markdown);
+        }
+
+        let markdown = crate::convert(html, Some(options))?;
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        self.cache.put(&key, &markdown);
+        Ok(markdown)
+    }
+
+    /// Hits and misses since creation or the last [`reset_stats`](Self::reset_stats).
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Zero the hit and miss counters.
+    pub fn reset_stats(&self) {
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    /// The underlying cache.
+    pub const fn cache(&self) -> &C {
+        &self.cache
+    }
+}
+
+/// Cache storing each entry as `<key>.md` in a directory.
+///
+/// Entries are written to a temporary file and renamed into place, so concurrent processes
+/// sharing the directory never read a partial entry. Nothing is ever evicted; delete the
+/// directory to clear the cache.
+#[derive(Debug, Clone)]
+pub struct FsCache {
+    dir: PathBuf,
+}
+
+impl FsCache {
+    /// Use `dir` as the cache directory, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// The cache directory.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.md"))
+    }
+}
+
+impl ConversionCache for FsCache {
+    fn get(&self, key: &str) -> Option<String> {
+        fs::read_to_string(self.path(key)).ok()
+    }
+
+    fn put(&self, key: &str, markdown: &str) {
+        let path = self.path(key);
+        let temp = self.dir.join(format!("{key}.{}.tmp", std::process::id()));
+        if fs::write(&temp, markdown).is_err() || fs::rename(&temp, &path).is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+    }
+}
+
+/// In-process cache without eviction.
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, String>>,
+}
+
+impl MemoryCache {
+    /// Number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    /// Whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all entries.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+}
+
+impl ConversionCache for MemoryCache {
+    fn get(&self, key: &str) -> Option<String> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned()
+    }
+
+    fn put(&self, key: &str, markdown: &str) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.to_string(), markdown.to_string());
+    }
+}
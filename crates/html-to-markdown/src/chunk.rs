@@ -0,0 +1,229 @@
+//! Heading-aware chunking for [`crate::convert_chunked`].
+//!
+//! The document is converted once, with the metadata collector recording its headings, and the
+//! Markdown is then cut between top-level blocks (blank lines outside code). Every chunk carries
+//! the path of headings it sits under as plain text, with links, emphasis and code markers
+//! removed.
+//!
+//! ```
+//! use html_to_markdown_rs::{ChunkConfig, ConversionOptions, convert_chunked};
+//!
+//! let html = "<h1>Guide</h1><p>Intro.</p><h2>Install</h2><p>Run it.</p>";
+//! let options = ConversionOptions {
+//!     extract_metadata: false,
+//!     ..Default::default()
+//! };
+//! let chunks = convert_chunked(html, Some(options), ChunkConfig::default()).unwrap();
+//! assert_eq!(chunks.len(), 2);
+//! assert_eq!(chunks[1].content, "## Install\n\nRun it.");
+//! assert_eq!(chunks[1].heading_path, ["Guide", "Install"]);
+//! ```
+
+use crate::metadata::HeaderMetadata;
+use crate::postprocess::{classify_lines, heading_plain_text};
+
+/// Default for [`ChunkConfig::max_chars`].
+pub const DEFAULT_CHUNK_MAX_CHARS: usize = 2000;
+
+/// How [`crate::convert_chunked`] cuts the Markdown.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "metadata", serde(rename_all = "camelCase", default))]
+pub struct ChunkConfig {
+    /// Largest chunk, in characters. Blocks are never split, so a single block longer than
+    /// this becomes a chunk of its own. Must be greater than 0.
+    pub max_chars: usize,
+
+    /// Start a new chunk at every heading, so no chunk spans two sections.
+    pub split_on_headings: bool,
+}
+
+impl Default for ChunkConfig {
+    fn default() -> Self {
+        Self {
+            max_chars: DEFAULT_CHUNK_MAX_CHARS,
+            split_on_headings: true,
+        }
+    }
+}
+
+/// One piece of the Markdown produced by [`crate::convert_chunked`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "metadata", serde(rename_all = "camelCase"))]
+pub struct MarkdownChunk {
+    /// The chunk's Markdown, without leading or trailing blank lines.
+    pub content: String,
+
+    /// Text of the enclosing headings, outermost first. A chunk that starts with a heading
+    /// includes that heading as the last entry.
+    pub heading_path: Vec<String>,
+}
+
+struct Block<'a> {
+    text: &'a str,
+    heading: Option<(u8, String)>,
+}
+
+/// Cut `markdown` into chunks according to `config`, naming headings from `headers`.
+pub(crate) fn chunk_markdown(markdown: &str, headers: &[HeaderMetadata], config: &ChunkConfig) -> Vec<MarkdownChunk> {
+    let mut headers = headers.iter();
+    let mut path: Vec<(u8, String)> = Vec::new();
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_path = Vec::new();
+
+    for block in blocks(markdown) {
+        let starts_section = block.heading.is_some() && config.split_on_headings;
+        if let Some((level, fallback)) = block.heading {
+            let text = headers
+                .by_ref()
+                .find(|header| header.level == level)
+                .map_or(fallback, |header| header.text.clone());
+            let text = heading_plain_text(&text);
+            path.retain(|(outer, _)| *outer < level);
+            path.push((level, text));
+        }
+
+        let too_long = current.chars().count() + 2 + block.text.chars().count() > config.max_chars;
+        if !current.is_empty() && (starts_section || too_long) {
+            chunks.push(MarkdownChunk {
+                content: std::mem::take(&mut current),
+                heading_path: std::mem::take(&mut current_path),
+            });
+        }
+        if current.is_empty() {
+            current_path = path.iter().map(|(_, text)| text.clone()).collect();
+        } else {
+            current.push_str("\n\n");
+        }
+        current.push_str(block.text);
+    }
+
+    if !current.is_empty() {
+        chunks.push(MarkdownChunk {
+            content: current,
+            heading_path: current_path,
+        });
+    }
+    chunks
+}
+
+/// Split `markdown` into top-level blocks, recognising ATX and setext headings.
+fn blocks(markdown: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+    for line in classify_lines(markdown) {
+        let blank = line.text.trim().is_empty() && !line.code;
+        match (blank, start) {
+            (true, Some(block_start)) => {
+                blocks.push(block(&markdown[block_start..offset - 1]));
+                start = None;
+            }
+            (false, None) => start = Some(offset),
+            _ => {}
+        }
+        offset += line.text.len() + 1;
+    }
+    if let Some(block_start) = start {
+        blocks.push(block(markdown[block_start..].trim_end_matches('\n')));
+    }
+    blocks
+}
+
+fn block(text: &str) -> Block<'_> {
+    Block {
+        text,
+        heading: heading(text),
+    }
+}
+
+/// Level and Markdown text of the heading `block` starts with, if any.
+fn heading(block: &str) -> Option<(u8, String)> {
+    let mut lines = block.lines();
+    let first = lines.next()?;
+
+    let hashes = first.bytes().take_while(|&byte| byte == b'#').count();
+    let atx_level = u8::try_from(hashes).ok().filter(|level| (1..=6).contains(level));
+    if let Some(level) = atx_level.filter(|_| first[hashes..].chars().next().is_none_or(char::is_whitespace)) {
+        let text = first[hashes..].trim();
+        // A closing run of `#` only counts when it is separated from the text.
+        let text = match text.trim_end_matches('#') {
+            "" => "",
+            rest if rest.ends_with(char::is_whitespace) => rest.trim_end(),
+            _ => text,
+        };
+        return Some((level, text.to_string()));
+    }
+
+    let underline = lines.next()?.trim_end();
+    let level = if !underline.is_empty() && underline.bytes().all(|byte| byte == b'=') {
+        1
+    } else if underline.len() >= 2 && underline.bytes().all(|byte| byte == b'-') {
+        2
+    } else {
+        return None;
+    };
+    Some((level, first.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(max_chars: usize, split_on_headings: bool) -> ChunkConfig {
+        ChunkConfig {
+            max_chars,
+            split_on_headings,
+        }
+    }
+
+    #[test]
+    fn test_heading_detection() {
+        assert_eq!(heading("## Setup ##"), Some((2, "Setup".to_string())));
+        assert_eq!(heading("Title\n====="), Some((1, "Title".to_string())));
+        assert_eq!(heading("Part\n---"), Some((2, "Part".to_string())));
+        assert_eq!(heading("### Learn C#"), Some((3, "Learn C#".to_string())));
+        assert_eq!(heading("#hashtag"), None);
+        assert_eq!(heading("####### seven"), None);
+        assert_eq!(heading("plain\ntext"), None);
+    }
+
+    #[test]
+    fn test_code_blocks_stay_whole_and_headings_inside_are_ignored() {
+        let markdown = "# A\n\n```\n# not a heading\n\nstill code\n```\n\ntail\n";
+        let chunks = chunk_markdown(markdown, &[], &config(20, true));
+        assert_eq!(
+            chunks,
+            [
+                MarkdownChunk {
+                    content: "# A".to_string(),
+                    heading_path: vec!["A".to_string()],
+                },
+                MarkdownChunk {
+                    content: "```\n# not a heading\n\nstill code\n```".to_string(),
+                    heading_path: vec!["A".to_string()],
+                },
+                MarkdownChunk {
+                    content: "tail".to_string(),
+                    heading_path: vec!["A".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sibling_headings_replace_each_other_in_the_path() {
+        let markdown = "# A\n\n## B\n\nb\n\n## C\n\nc\n\n# D\n";
+        let paths: Vec<_> = chunk_markdown(markdown, &[], &config(100, true))
+            .into_iter()
+            .map(|chunk| chunk.heading_path.join(" > "))
+            .collect();
+        assert_eq!(paths, ["A", "A > B", "A > C", "D"]);
+
+        let chunks = chunk_markdown(markdown, &[], &config(100, false));
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].content, markdown.trim_end());
+    }
+}
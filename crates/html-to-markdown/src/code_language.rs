@@ -0,0 +1,260 @@
+//! Fence language inference for `infer_code_language`.
+//!
+//! Highlighters record the language in many ways besides `language-*`: SyntaxHighlighter
+//! writes `class="brush: js"`, GitHub `highlight-source-python`, pandoc `sourceCode python`,
+//! and others use `data-lang`. When none of those is present the code itself is inspected,
+//! but only for lines that are characteristic of one language; anything ambiguous is left
+//! without a language rather than guessed.
+
+/// Classes that accompany a language name without being one (`sourceCode python`, `hljs rust`).
+const HIGHLIGHTER_CLASSES: &[&str] = &["sourceCode", "hljs", "prettyprint", "codehilite"];
+
+/// The language named by a highlighter-specific `class` attribute value.
+pub fn class_language(class: &str) -> Option<String> {
+    if let Some(start) = class.find("brush:") {
+        let language = class[start + "brush:".len()..]
+            .split(|c: char| c == ';' || c.is_whitespace())
+            .find(|part| !part.is_empty())?;
+        return Some(language.to_ascii_lowercase());
+    }
+
+    let classes: Vec<&str> = class.split_whitespace().collect();
+    for class in &classes {
+        if let Some(language) = class.strip_prefix("highlight-source-") {
+            return Some(language.to_ascii_lowercase());
+        }
+        if let Some(language) = class.strip_prefix("highlight-text-") {
+            let language = language.split('-').next().unwrap_or(language);
+            return Some(language.to_ascii_lowercase());
+        }
+    }
+
+    if classes.iter().any(|class| HIGHLIGHTER_CLASSES.contains(class)) {
+        return classes
+            .iter()
+            .find(|class| {
+                !HIGHLIGHTER_CLASSES.contains(class)
+                    && class.len() <= 20
+                    && class
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '#' | '-'))
+                    && !matches!(**class, "numberLines" | "linenums" | "notranslate")
+            })
+            .map(|class| class.to_ascii_lowercase());
+    }
+    None
+}
+
+/// Guess the language of `code` from distinctive lines, or `None` if there is no clear winner.
+pub fn guess_language(code: &str) -> Option<&'static str> {
+    let trimmed = code.trim();
+    if let Some(interpreter) = trimmed.lines().next().and_then(|line| line.strip_prefix("#!")) {
+        return shebang_language(interpreter);
+    }
+    let lower_start: String = trimmed.chars().take(16).collect::<String>().to_ascii_lowercase();
+    if lower_start.starts_with("<?php") {
+        return Some("php");
+    }
+    if lower_start.starts_with("<?xml") {
+        return Some("xml");
+    }
+    if lower_start.starts_with("<!doctype html") || lower_start.starts_with("<html") {
+        return Some("html");
+    }
+    if looks_like_json(trimmed) {
+        return Some("json");
+    }
+
+    let mut scores: Vec<(&'static str, usize)> = Vec::new();
+    let mut has_css_selector = false;
+    for line in trimmed.lines().map(str::trim) {
+        if is_css_selector(line) {
+            has_css_selector = true;
+        }
+        if let Some(language) = line_language(line) {
+            match scores.iter_mut().find(|(name, _)| *name == language) {
+                Some((_, score)) => *score += 1,
+                None => scores.push((language, 1)),
+            }
+        }
+    }
+    if !has_css_selector {
+        scores.retain(|(language, _)| *language != "css");
+    }
+    if scores.iter().any(|(language, _)| *language == "cpp") {
+        let c = scores.iter().position(|(language, _)| *language == "c");
+        if let Some(c) = c {
+            let (_, score) = scores.remove(c);
+            if let Some((_, cpp)) = scores.iter_mut().find(|(language, _)| *language == "cpp") {
+                *cpp += score;
+            }
+        }
+    }
+
+    let best = scores.iter().map(|(_, score)| *score).max()?;
+    let mut winners = scores.iter().filter(|(_, score)| *score == best);
+    let (language, _) = winners.next()?;
+    winners.next().is_none().then_some(*language)
+}
+
+fn shebang_language(interpreter: &str) -> Option<&'static str> {
+    let program = interpreter
+        .split_whitespace()
+        .find(|part| !part.ends_with("/env") && !part.starts_with('-'))?;
+    let program = program.rsplit('/').next().unwrap_or(program);
+    match program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.') {
+        "sh" | "bash" | "zsh" | "dash" | "ksh" => Some("bash"),
+        "python" => Some("python"),
+        "node" | "deno" => Some("javascript"),
+        "ruby" => Some("ruby"),
+        "perl" => Some("perl"),
+        "php" => Some("php"),
+        _ => None,
+    }
+}
+
+fn looks_like_json(code: &str) -> bool {
+    let object = code.starts_with('{') && code.ends_with('}') && code.contains("\":");
+    let array = code.starts_with('[') && code.ends_with(']') && (code.contains("\":") || code.contains("\","));
+    (object || array) && !code.contains(';')
+}
+
+fn is_css_selector(line: &str) -> bool {
+    line.len() > 1
+        && line.ends_with('{')
+        && !line.contains(['(', '=', ';'])
+        && ![
+            "interface ",
+            "class ",
+            "struct ",
+            "enum ",
+            "impl ",
+            "type ",
+            "namespace ",
+        ]
+        .iter()
+        .any(|keyword| line.starts_with(keyword))
+}
+
+/// The language a single trimmed line is characteristic of.
+fn line_language(line: &str) -> Option<&'static str> {
+    const PREFIXES: &[(&str, &str)] = &[
+        ("fn main()", "rust"),
+        ("pub fn ", "rust"),
+        ("use std::", "rust"),
+        ("let mut ", "rust"),
+        ("impl ", "rust"),
+        ("#[derive(", "rust"),
+        ("println!(", "rust"),
+        ("from __future__", "python"),
+        ("elif ", "python"),
+        ("if __name__ ==", "python"),
+        ("func ", "go"),
+        ("package main", "go"),
+        ("fmt.Print", "go"),
+        ("public class ", "java"),
+        ("public static void main", "java"),
+        ("System.out.print", "java"),
+        ("import java.", "java"),
+        ("#include <iostream>", "cpp"),
+        ("std::", "cpp"),
+        ("#include ", "c"),
+        ("printf(", "c"),
+        ("function ", "javascript"),
+        ("console.log(", "javascript"),
+        ("module.exports", "javascript"),
+        ("export default ", "javascript"),
+        ("$ ", "console"),
+        ("sudo ", "bash"),
+        ("echo ", "bash"),
+        ("apt-get ", "bash"),
+        ("npm install", "bash"),
+        ("pip install", "bash"),
+        ("<?php", "php"),
+    ];
+    const SQL: &[&str] = &[
+        "SELECT ",
+        "INSERT INTO ",
+        "CREATE TABLE ",
+        "DELETE FROM ",
+        "ALTER TABLE ",
+        "UPDATE ",
+    ];
+
+    if let Some((_, language)) = PREFIXES.iter().find(|(prefix, _)| line.starts_with(prefix)) {
+        return Some(language);
+    }
+    if line.starts_with("def ") && line.ends_with(':') {
+        return Some("python");
+    }
+    if line.starts_with("from ") && line.contains(" import ") {
+        return Some("python");
+    }
+    if line.starts_with("import ") && (line.contains(" from '") || line.contains(" from \"")) {
+        return Some("javascript");
+    }
+    if line.contains("=> {") || (line.starts_with("const ") && line.contains("require(")) {
+        return Some("javascript");
+    }
+    if line.contains(" := ") {
+        return Some("go");
+    }
+    if SQL.iter().any(|keyword| {
+        line.get(..keyword.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(keyword))
+    }) {
+        return Some("sql");
+    }
+    if is_css_declaration(line) {
+        return Some("css");
+    }
+    None
+}
+
+fn is_css_declaration(line: &str) -> bool {
+    let Some((property, value)) = line.strip_suffix(';').and_then(|line| line.split_once(':')) else {
+        return false;
+    };
+    !property.is_empty() && property.chars().all(|c| c.is_ascii_lowercase() || c == '-') && !value.trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_class_language_variants() {
+        assert_eq!(class_language("brush: js; gutter: false").as_deref(), Some("js"));
+        assert_eq!(
+            class_language("highlight highlight-source-python").as_deref(),
+            Some("python")
+        );
+        assert_eq!(class_language("highlight-text-html-basic").as_deref(), Some("html"));
+        assert_eq!(
+            class_language("sourceCode Haskell numberLines").as_deref(),
+            Some("haskell")
+        );
+        assert_eq!(class_language("hljs"), None);
+        assert_eq!(class_language("wide dark"), None);
+    }
+
+    #[test]
+    fn test_guess_language() {
+        assert_eq!(guess_language("#!/usr/bin/env python3\nprint('hi')"), Some("python"));
+        assert_eq!(guess_language("fn main() {\n    let mut x = 1;\n}"), Some("rust"));
+        assert_eq!(guess_language("def add(a, b):\n    return a + b"), Some("python"));
+        assert_eq!(guess_language("package main\n\nfunc main() {\n\tx := 1\n}"), Some("go"));
+        assert_eq!(guess_language("{\"name\": \"demo\"}"), Some("json"));
+        assert_eq!(guess_language("select id from users;"), Some("sql"));
+        assert_eq!(guess_language(".button {\n  color: red;\n}"), Some("css"));
+        assert_eq!(guess_language("#include <stdio.h>\n#include <iostream>"), Some("cpp"));
+    }
+
+    #[test]
+    fn test_ambiguous_or_plain_code_is_not_guessed() {
+        assert_eq!(guess_language("hello world"), None);
+        assert_eq!(guess_language("x = 1\ny = 2"), None);
+        assert_eq!(guess_language("color: red;"), None);
+        assert_eq!(guess_language("fn main() {}\nfunction main() {}"), None);
+    }
+}
@@ -75,11 +75,20 @@ use std::borrow::Cow;
 use std::num::NonZeroUsize;
 use std::str;
 
+use crate::diagnostics::Diagnostic;
 use crate::error::Result;
+use crate::footnotes::TitleFootnotes;
 #[cfg(feature = "inline-images")]
 use crate::inline_images::{InlineImageCollector, InlineImageFormat, InlineImageSource};
-use crate::options::{ConversionOptions, HeadingStyle, ListIndentType};
+use crate::options::{
+    BidiMode, ConversionOptions, FrontMatterFormat, HeaderBreakStyle, HeadingStyle, ListIndentType, ListSpacing,
+    MathStyle, NbspMode, NewlineStyle, PreprocessingPreset, SvgTextMode, TitleSource, UnrepresentablePolicy,
+};
+use crate::print_styles::PrintStyles;
+use crate::progress::ProgressTracker;
+use crate::stats::{ConversionStats, Stopwatch};
 use crate::text;
+use crate::typography::Typography;
 
 #[cfg(feature = "inline-images")]
 type InlineCollectorHandle = Rc<RefCell<InlineImageCollector>>;
@@ -133,18 +142,86 @@ fn trim_trailing_whitespace(output: &mut String) {
     }
 }
 
+/// Collapse whitespace according to the non-breaking space policy for this context.
+fn normalize_whitespace_for(text: &str, nbsp: NbspMode) -> Cow<'_, str> {
+    match nbsp {
+        NbspMode::Space => text::normalize_whitespace_cow(text),
+        NbspMode::Preserve => text::normalize_whitespace_keep_nbsp_cow(text),
+    }
+}
+
+/// Trim surrounding whitespace, keeping U+00A0 when the policy preserves it.
+fn trim_for(text: &str, nbsp: NbspMode) -> &str {
+    match nbsp {
+        NbspMode::Space => text.trim(),
+        NbspMode::Preserve => text::trim_keep_nbsp(text),
+    }
+}
+
+/// Punctuation that should attach directly to the preceding word.
+const fn is_closing_punctuation(c: char) -> bool {
+    matches!(c, '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}' | '…')
+}
+
+/// Drop the spaces an inline element boundary left before punctuation.
+///
+/// Returns `true` when the punctuation can attach to the preceding text.
+/// Spaces following a list or blockquote marker are kept so block structure
+/// is never altered.
+fn remove_space_before_punctuation(output: &mut String) -> bool {
+    let trimmed_len = output.trim_end_matches(' ').len();
+    let line_start = output[..trimmed_len].rfind('\n').map_or(0, |pos| pos + 1);
+    let line = output[line_start..trimmed_len].trim_start();
+    let is_block_marker = line.is_empty()
+        || matches!(line, "*" | "-" | "+" | ">")
+        || line
+            .strip_suffix(['.', ')'])
+            .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()));
+    if is_block_marker {
+        return false;
+    }
+
+    output.truncate(trimmed_len);
+    true
+}
+
 /// Remove trailing spaces/tabs from every line while preserving newlines.
+///
+/// Lines inside code blocks are left alone: their trailing whitespace is part of the code.
 fn trim_line_end_whitespace(output: &mut String) {
     if output.is_empty() {
         return;
     }
 
+    let indented_code: Vec<bool> = crate::postprocess::classify_lines(output)
+        .iter()
+        .map(|line| line.code && crate::postprocess::fence_marker(line.text).is_none())
+        .collect();
+    let mut fence: Option<(char, usize)> = None;
     let mut cleaned = String::with_capacity(output.len());
     for (idx, line) in output.split('\n').enumerate() {
         if idx > 0 {
             cleaned.push('\n');
         }
 
+        // Fences may sit behind list indentation or blockquote markers.
+        let body = line.trim_start_matches([' ', '>']);
+        let marker = crate::postprocess::fence_marker(body);
+        if let Some((open_marker, open_len)) = fence {
+            let closes = marker.is_some_and(|(m, l)| m == open_marker && l >= open_len && body.trim_end().len() == l);
+            if closes {
+                fence = None;
+            } else {
+                cleaned.push_str(line);
+                continue;
+            }
+        } else if marker.is_some() {
+            fence = marker;
+        } else if indented_code.get(idx).copied().unwrap_or(false) {
+            cleaned.push_str(line);
+            continue;
+        }
+
         let has_soft_break = line.ends_with("  ");
         let trimmed = line.trim_end_matches([' ', '\t']);
 
@@ -171,40 +248,24 @@ fn truncate_at_char_boundary(value: &mut String, max_len: usize) {
     value.truncate(new_len);
 }
 
-/// Remove common leading whitespace from all lines in a code block.
+/// Text of a `<pre>` block as it goes between the fences.
 ///
-/// This is useful when HTML authors indent `<pre>` content for readability,
-/// so we can strip the shared indentation without touching meaningful spacing.
-fn dedent_code_block(content: &str) -> String {
-    let lines: Vec<&str> = content.lines().collect();
-    if lines.is_empty() {
+/// Only line breaks are touched: the newline HTML ignores right after `<pre>` and the final
+/// line break are dropped, and with `trim` so are blank lines at the start and end. Spaces
+/// and tabs within lines, including indentation, are kept exactly.
+fn code_block_content(content: &str, trim: bool) -> String {
+    let content = content.strip_prefix('\n').unwrap_or(content);
+    let content = content.strip_suffix('\n').unwrap_or(content);
+    if !trim {
+        return content.to_string();
+    }
+
+    let lines: Vec<&str> = content.split('\n').collect();
+    let Some(first) = lines.iter().position(|line| !line.trim().is_empty()) else {
         return String::new();
-    }
-
-    let min_indent = lines
-        .iter()
-        .filter(|line| !line.trim().is_empty())
-        .map(|line| {
-            line.char_indices()
-                .take_while(|(_, c)| c.is_whitespace())
-                .map(|(idx, c)| idx + c.len_utf8())
-                .last()
-                .unwrap_or(0)
-        })
-        .min()
-        .unwrap_or(0);
-
-    lines
-        .iter()
-        .map(|line| {
-            if line.trim().is_empty() {
-                *line
-            } else {
-                &line[min_indent.min(line.len())..]
-            }
-        })
-        .collect::<Vec<_>>()
-        .join("\n")
+    };
+    let last = lines.iter().rposition(|line| !line.trim().is_empty()).unwrap_or(first);
+    lines[first..=last].join("\n")
 }
 
 /// Calculate indentation level for list item continuations.
@@ -355,13 +416,20 @@ fn continuation_indent_string(list_depth: usize, options: &ConversionOptions) ->
     Some(indent)
 }
 
+/// Check whether output ends right after a list item marker (`- `, `1. `, `1) `, ...).
+fn ends_with_list_marker(output: &str) -> bool {
+    ["* ", "- ", "+ ", ". ", ") "]
+        .iter()
+        .any(|marker| output.ends_with(marker))
+}
+
 /// Add appropriate leading separator before a list.
 ///
 /// Lists need different separators depending on context:
 /// - In table cells: <br> tag if there's already content
 /// - Outside lists: blank line (\n\n) if needed
-/// - Inside list items: blank line before nested list
-fn add_list_leading_separator(output: &mut String, ctx: &Context) {
+/// - Inside list items: newline before nested list (no blank line for tight `list_spacing`)
+fn add_list_leading_separator(output: &mut String, options: &ConversionOptions, ctx: &Context) {
     if ctx.in_table_cell {
         let is_table_continuation =
             !output.is_empty() && !output.ends_with('|') && !output.ends_with(' ') && !output.ends_with("<br>");
@@ -372,8 +440,7 @@ fn add_list_leading_separator(output: &mut String, ctx: &Context) {
     }
 
     if !output.is_empty() && !ctx.in_list {
-        let needs_newline =
-            !output.ends_with("\n\n") && !output.ends_with("* ") && !output.ends_with("- ") && !output.ends_with(". ");
+        let needs_newline = !output.ends_with("\n\n") && !ends_with_list_marker(output);
         if needs_newline {
             output.push_str("\n\n");
         }
@@ -381,8 +448,10 @@ fn add_list_leading_separator(output: &mut String, ctx: &Context) {
     }
 
     if ctx.in_list_item && !output.is_empty() {
-        let needs_newline =
-            !output.ends_with('\n') && !output.ends_with("* ") && !output.ends_with("- ") && !output.ends_with(". ");
+        if options.list_spacing == ListSpacing::Tight && output.ends_with("\n\n") {
+            output.pop();
+        }
+        let needs_newline = !output.ends_with('\n') && !ends_with_list_marker(output);
         if needs_newline {
             trim_trailing_whitespace(output);
             output.push('\n');
@@ -390,6 +459,20 @@ fn add_list_leading_separator(output: &mut String, ctx: &Context) {
     }
 }
 
+/// Decide whether a list is rendered loose, honoring `options.list_spacing` over the source.
+fn list_is_loose(
+    node_handle: tl::NodeHandle,
+    parser: &tl::Parser,
+    options: &ConversionOptions,
+    dom_ctx: &DomContext,
+) -> bool {
+    match options.list_spacing {
+        ListSpacing::Auto => is_loose_list(node_handle, parser, dom_ctx),
+        ListSpacing::Tight => false,
+        ListSpacing::Loose => true,
+    }
+}
+
 /// Add appropriate trailing separator after a nested list.
 ///
 /// Nested lists inside list items need trailing newlines to separate
@@ -411,6 +494,34 @@ fn add_nested_list_trailing_separator(output: &mut String, ctx: &Context) {
     }
 }
 
+/// Bullet for an unordered list item at the given `<ul>` nesting depth (1-based).
+fn unordered_list_bullet(options: &ConversionOptions, ul_depth: usize) -> char {
+    let level = if options.cycle_bullets {
+        ul_depth.saturating_sub(1)
+    } else {
+        0
+    };
+    let count = options.bullets.chars().count();
+    if count == 0 {
+        return '*';
+    }
+    options.bullets.chars().nth(level % count).unwrap_or('*')
+}
+
+/// Delimiter following the number of an ordered list item at the given `<ol>` nesting depth.
+///
+/// Only `.` and `)` are valid `CommonMark` delimiters; anything else falls back to `.`.
+fn ordered_list_delimiter(options: &ConversionOptions, ol_depth: usize) -> char {
+    let count = options.ordered_markers.chars().count();
+    if count == 0 {
+        return '.';
+    }
+    match options.ordered_markers.chars().nth(ol_depth.saturating_sub(1) % count) {
+        Some(')') => ')',
+        _ => '.',
+    }
+}
+
 /// Calculate the nesting depth for a list.
 ///
 /// If we're in a list but NOT in a list item, this is incorrectly nested HTML
@@ -424,6 +535,22 @@ const fn calculate_list_nesting_depth(ctx: &Context) -> usize {
     }
 }
 
+/// Largest ordered list item number; CommonMark list markers have at most nine digits.
+const MAX_LIST_NUMBER: usize = 999_999_999;
+
+/// Start number for an ordered list, lowered where needed so its last item still fits
+/// within [`MAX_LIST_NUMBER`].
+fn clamp_list_start(start: usize, node_handle: tl::NodeHandle, parser: &tl::Parser, dom_ctx: &DomContext) -> usize {
+    let items = node_handle.get(parser).and_then(tl::Node::as_tag).map_or(0, |tag| {
+        tag.children()
+            .top()
+            .iter()
+            .filter(|child| is_list_item(**child, parser, dom_ctx))
+            .count()
+    });
+    start.min(MAX_LIST_NUMBER.saturating_sub(items.saturating_sub(1)).max(1))
+}
+
 /// Process a list's children, tracking which items had block elements.
 ///
 /// This is used to determine proper spacing between list items.
@@ -460,6 +587,7 @@ fn process_list_children(
                     in_list: true,
                     list_depth: nested_depth,
                     ul_depth: if is_ordered { ctx.ul_depth } else { ctx.ul_depth + 1 },
+                    ol_depth: if is_ordered { ctx.ol_depth + 1 } else { ctx.ol_depth },
                     loose_list: is_loose,
                     prev_item_had_blocks: false,
                     ..ctx.clone()
@@ -468,7 +596,7 @@ fn process_list_children(
                 walk_node(child_handle, parser, output, options, &list_ctx, depth, dom_ctx);
 
                 if is_ordered && is_list_item(*child_handle, parser, dom_ctx) {
-                    counter += 1;
+                    counter = counter.saturating_add(1);
                 }
             }
         }
@@ -485,10 +613,74 @@ fn is_list_item(node_handle: tl::NodeHandle, parser: &tl::Parser, dom_ctx: &DomC
     )
 }
 
+/// Receives Markdown from [`convert_html_to_writer`] and [`convert_html_to_fmt`] a few blocks at a time.
+struct ChunkSink<'w> {
+    write: &'w mut dyn FnMut(&str) -> Result<()>,
+    /// Whether anything has been written yet.
+    wrote: bool,
+    /// End of the finished blocks in the output that have not been written yet.
+    held: usize,
+    /// The first write error; nothing more is written after it.
+    error: Option<crate::error::ConversionError>,
+}
+
+impl std::fmt::Debug for ChunkSink<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChunkSink")
+            .field("wrote", &self.wrote)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChunkSink<'_> {
+    fn write(&mut self, chunk: &str) {
+        if self.error.is_some() || chunk.is_empty() {
+            return;
+        }
+        match (self.write)(chunk) {
+            Ok(()) => self.wrote = true,
+            Err(err) => self.error = Some(err),
+        }
+    }
+
+    /// Write what is left of the output once the walk is over, ending it with one newline.
+    fn finish(&mut self, rest: &str) -> Result<()> {
+        let trimmed = rest.trim_end_matches('\n');
+        if !trimmed.is_empty() || self.wrote {
+            self.write(trimmed);
+            self.write("\n");
+        }
+        self.error.take().map_or(Ok(()), Err)
+    }
+}
+
+/// Hand the finished blocks at the front of `output` to the sink, if there is one.
+///
+/// Only called between top-level blocks. The most recent block stays in `output` until the
+/// next one is finished, so code that looks back at the previous block (spacing, footnote
+/// definitions) sees the same text as without a sink.
+fn flush_finished_blocks(output: &mut String, options: &ConversionOptions, ctx: &Context) {
+    let Some(sink) = &ctx.sink else { return };
+    // Direction markers on `html` and `body` wrap their whole output once the walk returns.
+    if options.bidi_mode != BidiMode::Ignore || !output.ends_with("\n\n") {
+        return;
+    }
+    let mut sink = sink.borrow_mut();
+    let held = sink.held;
+    if held > 0 && held < output.len() && output.is_char_boundary(held) {
+        let rest = output.split_off(held);
+        let mut chunk = std::mem::replace(output, rest);
+        trim_line_end_whitespace(&mut chunk);
+        chunk.pop();
+        sink.write(&chunk);
+    }
+    sink.held = output.trim_end().len();
+}
+
 /// Conversion context to track state during traversal
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
-struct Context {
+struct Context<'w> {
     /// Are we inside a code-like element (pre, code, kbd, samp)?
     in_code: bool,
     /// Current list item counter for ordered lists
@@ -511,6 +703,8 @@ struct Context {
     list_depth: usize,
     /// Unordered list nesting depth (for bullet cycling)
     ul_depth: usize,
+    /// Ordered list nesting depth (for delimiter cycling)
+    ol_depth: usize,
     /// Are we inside any list (ul or ol)?
     in_list: bool,
     /// Is this a "loose" list where all items should have blank lines?
@@ -529,10 +723,16 @@ struct Context {
     in_strong: bool,
     /// Tag names that should be stripped during conversion.
     strip_tags: Rc<HashSet<String>>,
+    /// Tag names that should be dropped together with their content.
+    remove_tags: Rc<HashSet<String>>,
     /// Tag names that should be preserved as raw HTML.
     preserve_tags: Rc<HashSet<String>>,
     /// Tag names that allow inline images inside headings.
     keep_inline_images_in: Rc<HashSet<String>>,
+    /// Print stylesheet rules, present when `preprocessing.apply_print_styles` is active.
+    print_styles: Option<Rc<PrintStyles>>,
+    /// Node ids matched by `preprocessing.exclude_selectors`.
+    excluded_nodes: Rc<HashSet<u32>>,
     #[cfg(feature = "inline-images")]
     /// Shared collector for inline images when enabled.
     inline_collector: Option<InlineCollectorHandle>,
@@ -549,12 +749,26 @@ struct Context {
     metadata_wants_images: bool,
     #[cfg(feature = "metadata")]
     metadata_wants_structured_data: bool,
+    #[cfg(feature = "metadata")]
+    metadata_wants_source_map: bool,
     #[cfg(feature = "visitor")]
     /// Optional visitor for custom HTML traversal callbacks.
     visitor: Option<crate::visitor::VisitorHandle>,
     #[cfg(feature = "visitor")]
     /// Stores the first visitor error encountered during traversal.
     visitor_error: Rc<RefCell<Option<String>>>,
+    /// Diagnostics recorded under `UnrepresentablePolicy::Diagnose`.
+    diagnostics: Rc<RefCell<Vec<Diagnostic>>>,
+    /// Text characters removed per rule under `report_dropped_content`, in first-use order.
+    dropped_content: Rc<RefCell<Vec<(&'static str, usize)>>>,
+    /// Progress reporting for `convert_with_progress`.
+    progress: Option<Rc<ProgressTracker>>,
+    /// Footnotes collected from `title` attributes under `title_footnotes`.
+    footnotes: Rc<RefCell<TitleFootnotes>>,
+    /// Element whose `title` is being turned into a footnote, so it is not written inline as well.
+    footnoted_node: Option<u32>,
+    /// Where finished blocks go when converting into a writer.
+    sink: Option<Rc<RefCell<ChunkSink<'w>>>>,
 }
 
 struct DomContext {
@@ -856,6 +1070,49 @@ impl DomContext {
         None
     }
 
+    /// Resolve the direction set by the nearest ancestor with a `dir` attribute (LTR if none).
+    fn inherited_direction(&self, node_id: u32, parser: &tl::Parser) -> Direction {
+        let mut current = self.parent_of(node_id);
+        while let Some(id) = current {
+            if let Some(tl::Node::Tag(tag)) = self.node_handle(id).and_then(|handle| handle.get(parser)) {
+                if let Some(direction) = Direction::from_tag(tag) {
+                    return direction;
+                }
+            }
+            current = self.parent_of(id);
+        }
+        Direction::Ltr
+    }
+
+    /// Punctuation conventions for a node, from the nearest `lang` attribute on it or its ancestors.
+    fn typography(&self, node_id: u32, parser: &tl::Parser, options: &ConversionOptions) -> Typography {
+        if !options.localized_punctuation {
+            return Typography::ASCII;
+        }
+        let mut current = Some(node_id);
+        while let Some(id) = current {
+            if let Some(tl::Node::Tag(tag)) = self.node_handle(id).and_then(|handle| handle.get(parser)) {
+                if let Some(lang) = tag.attributes().get("lang").flatten() {
+                    return Typography::for_language(&lang.as_utf8_str());
+                }
+            }
+            current = self.parent_of(id);
+        }
+        Typography::ASCII
+    }
+
+    /// Whether any ancestor of the node is a `<tag_name>` element.
+    fn has_ancestor_tag(&self, node_id: u32, parser: &tl::Parser, tag_name: &str) -> bool {
+        let mut current = self.parent_of(node_id);
+        while let Some(id) = current {
+            if self.tag_info(id, parser).is_some_and(|info| info.name == tag_name) {
+                return true;
+            }
+            current = self.parent_of(id);
+        }
+        false
+    }
+
     /// Get the index of a node among its siblings.
     ///
     /// Returns the 0-based index if the node has siblings,
@@ -964,6 +1221,243 @@ fn append_markdown_link(
     output.push(')');
 }
 
+/// Format a Pandoc attribute block such as `{#intro .lead width=50%}`; empty when there is nothing to keep.
+fn format_pandoc_attributes(id: Option<&str>, classes: &[String], pairs: &[(&str, String)]) -> String {
+    let mut parts = Vec::new();
+    if let Some(id) = id
+        .map(str::trim)
+        .filter(|id| !id.is_empty() && !id.contains(char::is_whitespace))
+    {
+        parts.push(format!("#{id}"));
+    }
+    parts.extend(classes.iter().map(|class| format!(".{class}")));
+    for (key, value) in pairs {
+        if value.is_empty() || value.contains(|c: char| c.is_whitespace() || matches!(c, '"' | '{' | '}')) {
+            parts.push(format!(
+                "{key}=\"{}\"",
+                value.replace('\\', "\\\\").replace('"', "\\\"")
+            ));
+        } else {
+            parts.push(format!("{key}={value}"));
+        }
+    }
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", parts.join(" "))
+    }
+}
+
+/// Pandoc attributes (`id`, `class` and the listed `keys`) of an element when `pandoc_attributes` is on.
+fn element_pandoc_attributes(tag: &tl::HTMLTag, options: &ConversionOptions, keys: &[&str]) -> String {
+    if !options.pandoc_attributes {
+        return String::new();
+    }
+    let attribute = |name: &str| {
+        tag.attributes()
+            .get(name)
+            .flatten()
+            .map(|value| text::decode_html_entities(value.as_utf8_str().trim()))
+    };
+    let classes: Vec<String> = attribute("class")
+        .map(|class| class.split_whitespace().map(str::to_string).collect())
+        .unwrap_or_default();
+    let pairs: Vec<(&str, String)> = keys.iter().filter_map(|key| Some((*key, attribute(key)?))).collect();
+    format_pandoc_attributes(attribute("id").as_deref(), &classes, &pairs)
+}
+
+/// Empty `<a id="...">` anchor for a heading's `id` when `preserve_heading_ids` is on.
+///
+/// With `pandoc_attributes` the id is already written as `{#id}`, so no anchor is needed.
+fn heading_id_anchor(tag: &tl::HTMLTag, options: &ConversionOptions) -> String {
+    if !options.preserve_heading_ids || options.pandoc_attributes {
+        return String::new();
+    }
+    let Some(id) = tag
+        .attributes()
+        .id()
+        .map(|id| text::decode_html_entities(id.as_utf8_str().trim()))
+    else {
+        return String::new();
+    };
+    if id.is_empty() {
+        return String::new();
+    }
+    let escaped = id.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;");
+    format!("<a id=\"{escaped}\"></a>")
+}
+
+/// Fence info string for a `<pre>` block when `pandoc_attributes` is on.
+///
+/// A bare language stays in the usual ```` ```rust ```` form; once the block (or its `<code>`)
+/// carries an id or other classes they are written as `{#id .rust .numberLines}`.
+fn code_block_pandoc_info(tag: &tl::HTMLTag, parser: &tl::Parser, language: &str) -> String {
+    let code = tag.children().top().iter().find_map(|child| match child.get(parser) {
+        Some(tl::Node::Tag(child)) if child.name().as_utf8_str().eq_ignore_ascii_case("code") => Some(child),
+        _ => None,
+    });
+    let attribute = |name: &str| {
+        std::iter::once(tag)
+            .chain(code)
+            .filter_map(|element| element.attributes().get(name).flatten())
+            .map(|value| text::decode_html_entities(value.as_utf8_str().trim()))
+            .collect::<Vec<_>>()
+    };
+
+    let mut classes: Vec<String> = Vec::new();
+    if !language.is_empty() {
+        classes.push(language.to_string());
+    }
+    for class_attr in attribute("class") {
+        for class in class_attr.split_whitespace() {
+            let is_language = class.starts_with("language-") || class.starts_with("lang-");
+            if !is_language && !classes.iter().any(|existing| existing == class) {
+                classes.push(class.to_string());
+            }
+        }
+    }
+    let id = attribute("id").into_iter().find(|id| !id.is_empty());
+    if id.is_none() && classes.len() <= 1 {
+        return classes.pop().unwrap_or_default();
+    }
+    format_pandoc_attributes(id.as_deref(), &classes, &[])
+}
+
+/// Link label for an `<object>`/`<embed>` resource: its `title`, `aria-label` or `name`, else the
+/// file name of the URL.
+fn object_link_label(tag: &tl::HTMLTag, url: &str) -> String {
+    ["title", "aria-label", "name"]
+        .iter()
+        .find_map(|name| {
+            let value = tag.attributes().get(*name).flatten()?;
+            let value = text::decode_html_entities(value.as_utf8_str().trim());
+            (!value.is_empty()).then_some(value)
+        })
+        .unwrap_or_else(|| {
+            let path = url.split(['?', '#']).next().unwrap_or(url);
+            let file_name = path.trim_end_matches('/').rsplit('/').next().unwrap_or_default();
+            if file_name.is_empty() { url } else { file_name }.to_string()
+        })
+}
+
+/// Title for a link without a `title` attribute, from the first `title_fallback` source that has one.
+fn link_fallback_title(
+    node_handle: &tl::NodeHandle,
+    parser: &tl::Parser,
+    dom_ctx: &DomContext,
+    href: &str,
+    options: &ConversionOptions,
+) -> Option<String> {
+    options.title_fallback.iter().find_map(|source| match source {
+        TitleSource::OpenGraph => None,
+        TitleSource::FirstHeading => first_heading_text(std::slice::from_ref(node_handle), parser, dom_ctx),
+        TitleSource::UrlBasename => url_basename(href),
+    })
+}
+
+/// Scheme and address of a `mailto:` or `tel:` link, leaving off any `mailto:` query.
+fn contact_address(href: &str) -> Option<(&'static str, &str)> {
+    let (scheme, rest) = href.split_once(':')?;
+    let (scheme, address) = if scheme.eq_ignore_ascii_case("mailto") {
+        ("mailto", rest.split('?').next().unwrap_or_default())
+    } else if scheme.eq_ignore_ascii_case("tel") {
+        ("tel", rest)
+    } else {
+        return None;
+    };
+    let address = address.trim();
+    (!address.is_empty()).then_some((scheme, address))
+}
+
+/// Whether link text spells out a contact address, with or without its scheme. Email addresses
+/// compare case-insensitively and phone numbers by their digits and `+` alone.
+fn contact_text_matches(scheme: &str, address: &str, text: &str) -> bool {
+    let text = text.trim();
+    let text = text
+        .split_once(':')
+        .filter(|(prefix, _)| prefix.eq_ignore_ascii_case(scheme))
+        .map_or(text, |(_, rest)| rest.trim());
+    if scheme == "tel" {
+        let dialable = |value: &str| {
+            value
+                .chars()
+                .filter(|c| c.is_ascii_digit() || *c == '+')
+                .collect::<String>()
+        };
+        let digits = dialable(address);
+        !digits.is_empty() && dialable(text) == digits && !text.chars().any(char::is_alphabetic)
+    } else {
+        text.eq_ignore_ascii_case(address)
+    }
+}
+
+/// A CommonMark autolink for a contact address, if the address can be written as one.
+fn contact_autolink(scheme: &str, address: &str) -> Option<String> {
+    if scheme == "tel" {
+        let number: String = address.chars().filter(|c| !c.is_whitespace()).collect();
+        return (!number.contains(['<', '>'])).then(|| format!("<tel:{number}>"));
+    }
+    let valid =
+        address.contains('@') && !address.contains(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '\\'));
+    valid.then(|| format!("<{address}>"))
+}
+
+/// Title for the front matter of a document without a `<title>`, from the first `title_fallback`
+/// source that has one.
+fn document_fallback_title(
+    metadata: &BTreeMap<String, String>,
+    roots: &[tl::NodeHandle],
+    parser: &tl::Parser,
+    dom_ctx: &DomContext,
+    options: &ConversionOptions,
+) -> Option<String> {
+    options.title_fallback.iter().find_map(|source| match source {
+        TitleSource::OpenGraph => metadata
+            .get("meta-og-title")
+            .map(|title| text::normalize_whitespace(title).trim().to_string())
+            .filter(|title| !title.is_empty()),
+        TitleSource::FirstHeading => first_heading_text(roots, parser, dom_ctx),
+        TitleSource::UrlBasename => ["canonical", "meta-og-url", "base-href"]
+            .iter()
+            .find_map(|key| metadata.get(*key).and_then(|url| url_basename(url))),
+    })
+}
+
+/// Text of the first non-empty heading at or below `roots`, in document order.
+fn first_heading_text(roots: &[tl::NodeHandle], parser: &tl::Parser, dom_ctx: &DomContext) -> Option<String> {
+    roots.iter().find_map(|handle| {
+        let Some(tl::Node::Tag(tag)) = handle.get(parser) else {
+            return None;
+        };
+        if heading_level_from_name(normalized_tag_name(tag.name().as_utf8_str()).as_ref()).is_some() {
+            let heading = text::normalize_whitespace(&get_text_content(handle, parser, dom_ctx))
+                .trim()
+                .to_string();
+            if !heading.is_empty() {
+                return Some(heading);
+            }
+        }
+        first_heading_text(tag.children().top().as_slice(), parser, dom_ctx)
+    })
+}
+
+/// Last path segment of a URL (`https://a.test/docs/intro.html` -> `intro.html`), falling back to
+/// the host when the path is empty. Returns `None` for fragments and non-hierarchical URLs such
+/// as `mailto:`.
+fn url_basename(url: &str) -> Option<String> {
+    let url = url.trim();
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = match url.split_once("://") {
+        Some((_, rest)) => rest.split_once('/').unwrap_or((rest, "")),
+        None if url.split('/').next().is_some_and(|first| first.contains(':')) => return None,
+        None => ("", url),
+    };
+    path.rsplit('/')
+        .find(|segment| !segment.is_empty())
+        .or_else(|| Some(host).filter(|host| !host.is_empty()))
+        .map(str::to_string)
+}
+
 fn heading_level_from_name(name: &str) -> Option<usize> {
     match name {
         "h1" => Some(1),
@@ -1015,7 +1509,14 @@ fn find_single_heading_child(node_handle: tl::NodeHandle, parser: &tl::Parser) -
     heading_data
 }
 
-fn push_heading(output: &mut String, ctx: &Context, options: &ConversionOptions, level: usize, text: &str) {
+fn push_heading(
+    output: &mut String,
+    ctx: &Context,
+    options: &ConversionOptions,
+    level: usize,
+    text: &str,
+    attributes: &str,
+) {
     if text.is_empty() {
         return;
     }
@@ -1058,26 +1559,36 @@ fn push_heading(output: &mut String, ctx: &Context, options: &ConversionOptions,
         "\n\n"
     };
 
+    // Pandoc reads attributes at the end of the heading line, after any closing hashes.
+    let attributes_suffix = if attributes.is_empty() {
+        String::new()
+    } else {
+        format!(" {attributes}")
+    };
     match options.heading_style {
         HeadingStyle::Underlined => {
             if level == 1 {
                 output.push_str(text);
+                output.push_str(&attributes_suffix);
                 output.push('\n');
                 output.push_str(&"=".repeat(text.len()));
             } else if level == 2 {
                 output.push_str(text);
+                output.push_str(&attributes_suffix);
                 output.push('\n');
                 output.push_str(&"-".repeat(text.len()));
             } else {
                 output.push_str(&"#".repeat(level));
                 output.push(' ');
                 output.push_str(text);
+                output.push_str(&attributes_suffix);
             }
         }
         HeadingStyle::Atx => {
             output.push_str(&"#".repeat(level));
             output.push(' ');
             output.push_str(text);
+            output.push_str(&attributes_suffix);
         }
         HeadingStyle::AtxClosed => {
             output.push_str(&"#".repeat(level));
@@ -1085,6 +1596,7 @@ fn push_heading(output: &mut String, ctx: &Context, options: &ConversionOptions,
             output.push_str(text);
             output.push(' ');
             output.push_str(&"#".repeat(level));
+            output.push_str(&attributes_suffix);
         }
     }
     output.push_str(heading_suffix);
@@ -1094,6 +1606,41 @@ fn heading_allows_inline_images(tag_name: &str, keep_inline_images_in: &HashSet<
     keep_inline_images_in.contains(tag_name)
 }
 
+/// Join the lines a `<br>` started in a heading or header cell with the separator of `style`.
+///
+/// The hard break markers before each line end (trailing spaces, or a backslash under
+/// `NewlineStyle::Backslash`) are dropped, and so are empty lines, so a leading or trailing
+/// `<br>` adds nothing.
+fn join_header_lines(text: &str, options: &ConversionOptions) -> String {
+    let separator = match options.header_break_style {
+        HeaderBreakStyle::Inherit | HeaderBreakStyle::Space => " ",
+        HeaderBreakStyle::Slash => " / ",
+        HeaderBreakStyle::Html => "<br>",
+    };
+    let mut lines = text.split('\n').peekable();
+    let mut joined = String::with_capacity(text.len());
+    while let Some(line) = lines.next() {
+        let line = if lines.peek().is_some() {
+            let line = line.trim_end_matches([' ', '\t']);
+            match options.newline_style {
+                NewlineStyle::Backslash => line.strip_suffix('\\').unwrap_or(line),
+                NewlineStyle::Spaces => line,
+            }
+        } else {
+            line
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if !joined.is_empty() {
+            joined.push_str(separator);
+        }
+        joined.push_str(line);
+    }
+    joined
+}
+
 fn normalize_heading_text(text: &str) -> Cow<'_, str> {
     if !text.contains('\n') && !text.contains('\r') {
         return Cow::Borrowed(text);
@@ -1167,6 +1714,7 @@ fn repair_with_html5ever(input: &str) -> Option<String> {
         .from_utf8()
         .read_from(&mut input.as_bytes())
         .ok()?;
+    crate::shadow_dom::inline_template_contents(&dom.document);
 
     let mut buf = Vec::with_capacity(input.len());
     let handle = SerializableHandle::from(dom.document);
@@ -1235,24 +1783,6 @@ fn record_node_hierarchy(node_handle: tl::NodeHandle, parent: Option<u32>, parse
     }
 }
 
-fn may_be_hocr(input: &str) -> bool {
-    let bytes = input.as_bytes();
-    if bytes.len() < 4 {
-        return false;
-    }
-    let mut idx = 0;
-    while idx + 3 < bytes.len() {
-        if bytes[idx] == b'o' && bytes[idx + 1] == b'c' && bytes[idx + 2] == b'r' {
-            match bytes[idx + 3] {
-                b'_' | b'-' | b'x' => return true,
-                _ => {}
-            }
-        }
-        idx += 1;
-    }
-    false
-}
-
 /// Check if a document is an hOCR (HTML-based OCR) document.
 ///
 /// hOCR documents should have metadata extraction disabled to avoid
@@ -1321,12 +1851,20 @@ fn extract_metadata(
     options: &ConversionOptions,
 ) -> BTreeMap<String, String> {
     let mut metadata = BTreeMap::new();
-    let strip_title = options.strip_tags.iter().any(|tag| tag.eq_ignore_ascii_case("title"));
+    let strip_title = options
+        .strip_tags
+        .iter()
+        .chain(&options.remove_tags)
+        .any(|tag| tag.eq_ignore_ascii_case("title"));
     let preserve_title = options
         .preserve_tags
         .iter()
         .any(|tag| tag.eq_ignore_ascii_case("title"));
-    let strip_meta = options.strip_tags.iter().any(|tag| tag.eq_ignore_ascii_case("meta"));
+    let strip_meta = options
+        .strip_tags
+        .iter()
+        .chain(&options.remove_tags)
+        .any(|tag| tag.eq_ignore_ascii_case("meta"));
     let preserve_meta = options.preserve_tags.iter().any(|tag| tag.eq_ignore_ascii_case("meta"));
 
     #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -1449,13 +1987,21 @@ fn extract_metadata(
     metadata
 }
 
-/// Format metadata as YAML frontmatter.
+/// Format metadata as a front matter block in the configured format.
 #[allow(clippy::trivially_copy_pass_by_ref)]
-fn format_metadata_frontmatter(metadata: &BTreeMap<String, String>) -> String {
+fn format_metadata_frontmatter(metadata: &BTreeMap<String, String>, format: FrontMatterFormat) -> String {
     if metadata.is_empty() {
         return String::new();
     }
 
+    match format {
+        FrontMatterFormat::Yaml => format_yaml_frontmatter(metadata),
+        FrontMatterFormat::Toml => format_toml_frontmatter(metadata),
+        FrontMatterFormat::Json => format_json_frontmatter(metadata),
+    }
+}
+
+fn format_yaml_frontmatter(metadata: &BTreeMap<String, String>) -> String {
     let mut output = String::from("---\n");
     for (key, value) in metadata {
         let needs_quotes = value.contains(':') || value.contains('#') || value.contains('[') || value.contains(']');
@@ -1475,6 +2021,61 @@ fn format_metadata_frontmatter(metadata: &BTreeMap<String, String>) -> String {
     output
 }
 
+fn format_toml_frontmatter(metadata: &BTreeMap<String, String>) -> String {
+    let mut output = String::from("+++\n");
+    for (key, value) in metadata {
+        let bare_key = !key.is_empty()
+            && key
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_');
+        if bare_key {
+            output.push_str(key);
+        } else {
+            push_quoted_string(&mut output, key);
+        }
+        output.push_str(" = ");
+        push_quoted_string(&mut output, value);
+        output.push('\n');
+    }
+    output.push_str("+++\n\n");
+    output
+}
+
+fn format_json_frontmatter(metadata: &BTreeMap<String, String>) -> String {
+    let mut output = String::from("{\n");
+    for (index, (key, value)) in metadata.iter().enumerate() {
+        if index > 0 {
+            output.push_str(",\n");
+        }
+        output.push_str("  ");
+        push_quoted_string(&mut output, key);
+        output.push_str(": ");
+        push_quoted_string(&mut output, value);
+    }
+    output.push_str("\n}\n\n");
+    output
+}
+
+/// Append `value` as a double-quoted string valid in both JSON and TOML.
+fn push_quoted_string(output: &mut String, value: &str) {
+    output.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if c.is_control() => {
+                use std::fmt::Write;
+                let _ = write!(output, "\\u{:04X}", u32::from(c));
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
 /// Check if a handle is an empty inline element (abbr, var, ins, dfn, etc. with no text content).
 #[allow(clippy::trivially_copy_pass_by_ref)]
 fn is_empty_inline_element(node_handle: &tl::NodeHandle, parser: &tl::Parser, dom_ctx: &DomContext) -> bool {
@@ -1753,21 +2354,7 @@ fn handle_inline_data_image(
         return;
     }
 
-    let format = if subtype_raw.eq_ignore_ascii_case("png") {
-        InlineImageFormat::Png
-    } else if subtype_raw.eq_ignore_ascii_case("jpeg") || subtype_raw.eq_ignore_ascii_case("jpg") {
-        InlineImageFormat::Jpeg
-    } else if subtype_raw.eq_ignore_ascii_case("gif") {
-        InlineImageFormat::Gif
-    } else if subtype_raw.eq_ignore_ascii_case("bmp") {
-        InlineImageFormat::Bmp
-    } else if subtype_raw.eq_ignore_ascii_case("webp") {
-        InlineImageFormat::Webp
-    } else if subtype_raw.eq_ignore_ascii_case("svg+xml") {
-        InlineImageFormat::Svg
-    } else {
-        InlineImageFormat::Other(subtype_raw.to_ascii_lowercase())
-    };
+    let format = inline_image_format_from_subtype(subtype_raw);
 
     let description = non_empty_trimmed(alt).or_else(|| title.and_then(non_empty_trimmed));
 
@@ -1794,24 +2381,205 @@ fn handle_inline_data_image(
 }
 
 #[cfg(feature = "inline-images")]
-#[allow(clippy::trivially_copy_pass_by_ref)]
-#[allow(clippy::needless_pass_by_value)]
-#[allow(clippy::option_if_let_else)]
-fn handle_inline_svg(
-    collector_ref: &InlineCollectorHandle,
-    node_handle: &tl::NodeHandle,
-    parser: &tl::Parser,
-    title_opt: Option<String>,
-    attributes: BTreeMap<String, String>,
-) {
-    let max_size = {
-        let borrow = collector_ref.borrow();
-        if !borrow.capture_svg() {
-            return;
-        }
-        borrow.max_decoded_size()
-    };
-
+fn inline_image_format_from_subtype(subtype: &str) -> InlineImageFormat {
+    if subtype.eq_ignore_ascii_case("png") {
+        InlineImageFormat::Png
+    } else if subtype.eq_ignore_ascii_case("jpeg") || subtype.eq_ignore_ascii_case("jpg") {
+        InlineImageFormat::Jpeg
+    } else if subtype.eq_ignore_ascii_case("gif") {
+        InlineImageFormat::Gif
+    } else if subtype.eq_ignore_ascii_case("bmp") {
+        InlineImageFormat::Bmp
+    } else if subtype.eq_ignore_ascii_case("webp") {
+        InlineImageFormat::Webp
+    } else if subtype.eq_ignore_ascii_case("svg+xml") {
+        InlineImageFormat::Svg
+    } else {
+        InlineImageFormat::Other(subtype.to_ascii_lowercase())
+    }
+}
+
+#[cfg(feature = "inline-images")]
+fn is_remote_image_src(src: &str) -> bool {
+    let src = src.trim_start();
+    src.get(..7)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("http://"))
+        || src
+            .get(..8)
+            .is_some_and(|scheme| scheme.eq_ignore_ascii_case("https://"))
+}
+
+/// Derive an inline image format for fetched bytes.
+///
+/// The reported MIME type wins; otherwise the payload is sniffed and finally the URL
+/// extension is consulted.
+#[cfg(feature = "inline-images")]
+fn remote_image_format(mime_type: Option<&str>, data: &[u8], url: &str) -> InlineImageFormat {
+    if let Some((top_level, subtype)) = mime_type.and_then(|mime| mime.split(';').next()?.trim().split_once('/')) {
+        if top_level.eq_ignore_ascii_case("image") && !subtype.is_empty() {
+            return inline_image_format_from_subtype(subtype);
+        }
+    }
+
+    match image::guess_format(data) {
+        Ok(image::ImageFormat::Png) => return InlineImageFormat::Png,
+        Ok(image::ImageFormat::Jpeg) => return InlineImageFormat::Jpeg,
+        Ok(image::ImageFormat::Gif) => return InlineImageFormat::Gif,
+        Ok(image::ImageFormat::Bmp) => return InlineImageFormat::Bmp,
+        Ok(image::ImageFormat::WebP) => return InlineImageFormat::Webp,
+        _ => {}
+    }
+
+    match remote_image_basename(url).and_then(|name| name.rsplit_once('.')) {
+        Some((_, "svg")) => InlineImageFormat::Svg,
+        Some((_, extension)) if !extension.is_empty() => inline_image_format_from_subtype(extension),
+        _ => InlineImageFormat::Other("octet-stream".to_string()),
+    }
+}
+
+/// Last path segment of a URL, without query string or fragment.
+#[cfg(feature = "inline-images")]
+fn remote_image_basename(url: &str) -> Option<&str> {
+    let without_suffix = url.split(['?', '#']).next().unwrap_or(url);
+    let (_, path) = without_suffix.split_once("://")?;
+    let (_, path) = path.split_once('/')?;
+    path.rsplit('/').next().filter(|segment| !segment.is_empty())
+}
+
+/// Resolve a remote `<img>` through the configured fetcher and collect it.
+#[cfg(feature = "inline-images")]
+#[allow(clippy::needless_pass_by_value)]
+fn handle_remote_image(
+    collector_ref: &InlineCollectorHandle,
+    src: &str,
+    alt: &str,
+    title: Option<&str>,
+    attributes: BTreeMap<String, String>,
+) {
+    let Some(fetcher) = collector_ref.borrow().fetcher() else {
+        return;
+    };
+
+    let url = src.trim();
+    let index = collector_ref.borrow_mut().next_index();
+
+    let payload = match fetcher.fetch(url) {
+        Ok(Some(payload)) => payload,
+        Ok(None) => return,
+        Err(reason) => {
+            collector_ref
+                .borrow_mut()
+                .warn_skip(index, format!("failed to fetch {url} ({reason})"));
+            return;
+        }
+    };
+
+    let mut collector = collector_ref.borrow_mut();
+
+    if payload.data.is_empty() {
+        collector.warn_skip(index, format!("empty response for {url}"));
+        return;
+    }
+
+    let max_size = collector.max_decoded_size();
+    if payload.data.len() as u64 > max_size {
+        collector.warn_skip(
+            index,
+            format!(
+                "fetched payload ({} bytes) exceeds configured max ({})",
+                payload.data.len(),
+                max_size
+            ),
+        );
+        return;
+    }
+
+    let format = remote_image_format(payload.mime_type.as_deref(), &payload.data, url);
+    let description = non_empty_trimmed(alt).or_else(|| title.and_then(non_empty_trimmed));
+    let filename_candidate = attributes
+        .get("data-filename")
+        .cloned()
+        .or_else(|| attributes.get("filename").cloned())
+        .or_else(|| attributes.get("data-name").cloned())
+        .or_else(|| remote_image_basename(url).map(str::to_string));
+    let dimensions = collector.infer_dimensions(index, &payload.data, &format);
+
+    let image = collector.build_image(
+        payload.data,
+        format,
+        filename_candidate,
+        description,
+        dimensions,
+        InlineImageSource::ImgRemote,
+        attributes,
+    );
+
+    collector.push_image(index, image);
+}
+
+/// Collect the accessible text of an inline `<svg>`: its `aria-label`, top-level `<title>` and
+/// `<desc>`, then every `<text>` label in document order, deduplicated and joined with `; `.
+fn extract_svg_text(node_handle: &tl::NodeHandle, parser: &tl::Parser, dom_ctx: &DomContext) -> String {
+    fn collect_labels(
+        handle: &tl::NodeHandle,
+        parser: &tl::Parser,
+        dom_ctx: &DomContext,
+        top_level: bool,
+        parts: &mut Vec<String>,
+    ) {
+        let Some(tl::Node::Tag(tag)) = handle.get(parser) else {
+            return;
+        };
+        for child in tag.children().top().iter() {
+            let Some(tl::Node::Tag(child_tag)) = child.get(parser) else {
+                continue;
+            };
+            match normalized_tag_name(child_tag.name().as_utf8_str()).as_ref() {
+                "text" => parts.push(get_text_content(child, parser, dom_ctx)),
+                "title" | "desc" if top_level => parts.push(get_text_content(child, parser, dom_ctx)),
+                "title" | "desc" | "style" | "script" => {}
+                _ => collect_labels(child, parser, dom_ctx, false, parts),
+            }
+        }
+    }
+
+    let mut parts = Vec::new();
+    if let Some(tl::Node::Tag(tag)) = node_handle.get(parser) {
+        if let Some(label) = tag.attributes().get("aria-label").flatten() {
+            parts.push(text::decode_html_entities(&label.as_utf8_str()));
+        }
+    }
+    collect_labels(node_handle, parser, dom_ctx, true, &mut parts);
+
+    let mut labels: Vec<String> = Vec::with_capacity(parts.len());
+    for part in parts {
+        let label = text::normalize_whitespace(&part).trim().to_string();
+        if !label.is_empty() && !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+    labels.join("; ")
+}
+
+#[cfg(feature = "inline-images")]
+#[allow(clippy::trivially_copy_pass_by_ref)]
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::option_if_let_else)]
+fn handle_inline_svg(
+    collector_ref: &InlineCollectorHandle,
+    node_handle: &tl::NodeHandle,
+    parser: &tl::Parser,
+    title_opt: Option<String>,
+    attributes: BTreeMap<String, String>,
+) {
+    let max_size = {
+        let borrow = collector_ref.borrow();
+        if !borrow.capture_svg() {
+            return;
+        }
+        borrow.max_decoded_size()
+    };
+
     if max_size == 0 {
         let mut collector = collector_ref.borrow_mut();
         let index = collector.next_index();
@@ -1885,6 +2653,87 @@ pub fn convert_html(html: &str, options: &ConversionOptions) -> Result<String> {
     convert_html_impl(html, options, None, None, None)
 }
 
+/// Convert HTML to Markdown into an [`io::Write`](std::io::Write) sink.
+///
+/// The Markdown is the same as [`convert_html`] returns, but it is written a few top-level
+/// blocks at a time, so the whole document is never held as one string. The writer is not
+/// flushed.
+///
+/// # Errors
+///
+/// Returns the errors of [`convert_html`], and [`ConversionError::IoError`] if writing fails.
+///
+/// [`ConversionError::IoError`]: crate::ConversionError::IoError
+pub fn convert_html_to_writer<W: std::io::Write + ?Sized>(
+    html: &str,
+    options: &ConversionOptions,
+    writer: &mut W,
+) -> Result<()> {
+    convert_html_into(html, options, &mut |chunk| {
+        writer.write_all(chunk.as_bytes()).map_err(Into::into)
+    })
+}
+
+/// Convert HTML to Markdown into a [`fmt::Write`](std::fmt::Write) sink, such as a `String`
+/// that already holds other text.
+///
+/// # Errors
+///
+/// Returns the errors of [`convert_html`], and [`ConversionError::IoError`] if the sink
+/// reports a formatting error.
+///
+/// [`ConversionError::IoError`]: crate::ConversionError::IoError
+pub fn convert_html_to_fmt<W: std::fmt::Write + ?Sized>(
+    html: &str,
+    options: &ConversionOptions,
+    writer: &mut W,
+) -> Result<()> {
+    convert_html_into(html, options, &mut |chunk| {
+        writer.write_str(chunk).map_err(|err| std::io::Error::other(err).into())
+    })
+}
+
+pub(crate) fn convert_html_into(
+    html: &str,
+    options: &ConversionOptions,
+    write: &mut dyn FnMut(&str) -> Result<()>,
+) -> Result<()> {
+    let sink = Rc::new(RefCell::new(ChunkSink {
+        write,
+        wrote: false,
+        held: 0,
+        error: None,
+    }));
+    let (rest, _) = convert_html_collecting(html, options, None, None, None, None, None, Some(Rc::clone(&sink)))?;
+    sink.borrow_mut().finish(&rest)
+}
+
+/// Convert HTML to Markdown, also returning the diagnostics recorded during traversal.
+pub(crate) fn convert_html_with_diagnostics(
+    html: &str,
+    options: &ConversionOptions,
+) -> Result<(String, Vec<Diagnostic>)> {
+    convert_html_collecting(html, options, None, None, None, None, None, None)
+}
+
+/// Convert HTML to Markdown, filling in the preprocessing, parsing and conversion parts of `stats`.
+pub(crate) fn convert_html_with_stats(
+    html: &str,
+    options: &ConversionOptions,
+    stats: &mut ConversionStats,
+) -> Result<String> {
+    convert_html_collecting(html, options, None, None, None, Some(stats), None, None).map(|(markdown, _)| markdown)
+}
+
+/// Convert HTML to Markdown, reporting the DOM walk to `progress`.
+pub(crate) fn convert_html_with_progress(
+    html: &str,
+    options: &ConversionOptions,
+    progress: Rc<ProgressTracker>,
+) -> Result<String> {
+    convert_html_collecting(html, options, None, None, None, None, Some(progress), None).map(|(markdown, _)| markdown)
+}
+
 #[cfg(feature = "visitor")]
 pub(crate) fn convert_html_with_visitor(
     html: &str,
@@ -1894,12 +2743,34 @@ pub(crate) fn convert_html_with_visitor(
     convert_html_impl(html, options, None, None, visitor)
 }
 
+pub(crate) fn convert_html_impl(
+    html: &str,
+    options: &ConversionOptions,
+    inline_collector: Option<InlineCollectorHandle>,
+    #[cfg(feature = "metadata")] metadata_collector: Option<crate::metadata::MetadataCollectorHandle>,
+    #[cfg(not(feature = "metadata"))] metadata_collector: Option<()>,
+    #[cfg(feature = "visitor")] visitor: Option<crate::visitor::VisitorHandle>,
+    #[cfg(not(feature = "visitor"))] visitor: Option<()>,
+) -> Result<String> {
+    convert_html_collecting(
+        html,
+        options,
+        inline_collector,
+        metadata_collector,
+        visitor,
+        None,
+        None,
+        None,
+    )
+    .map(|(markdown, _)| markdown)
+}
+
 #[cfg_attr(
     any(not(feature = "inline-images"), not(feature = "metadata"), not(feature = "visitor")),
     allow(unused_variables)
 )]
 #[allow(clippy::too_many_lines)]
-pub(crate) fn convert_html_impl(
+fn convert_html_collecting(
     html: &str,
     options: &ConversionOptions,
     inline_collector: Option<InlineCollectorHandle>,
@@ -1907,10 +2778,43 @@ pub(crate) fn convert_html_impl(
     #[cfg(not(feature = "metadata"))] _metadata_collector: Option<()>,
     #[cfg(feature = "visitor")] visitor: Option<crate::visitor::VisitorHandle>,
     #[cfg(not(feature = "visitor"))] _visitor: Option<()>,
-) -> Result<String> {
+    mut stats: Option<&mut ConversionStats>,
+    progress: Option<Rc<ProgressTracker>>,
+    sink: Option<Rc<RefCell<ChunkSink<'_>>>>,
+) -> Result<(String, Vec<Diagnostic>)> {
+    let mut stopwatch = stats.is_some().then(Stopwatch::start);
+    let preprocess_span = crate::trace::span("phase", || Cow::Borrowed("preprocess"));
+
+    // Style elements are stripped below, so print rules have to be read from the raw input.
+    let print_styles = (options.preprocessing.enabled && options.preprocessing.apply_print_styles)
+        .then(|| Rc::new(PrintStyles::extract(html)));
+
+    // Likewise for the scripts and stylesheets of `MetadataConfig::extract_resources`.
+    #[cfg(feature = "metadata")]
+    if let Some(ref collector) = metadata_collector {
+        if collector.borrow().wants_resources() {
+            collector
+                .borrow_mut()
+                .set_resources(crate::resources::collect_resources(html));
+        }
+    }
+
     // Strip script and style tags completely to prevent parser confusion from HTML-like content
     // inside script/style elements. This preserves JSON-LD for metadata extraction.
-    let stripped = strip_script_and_style_tags(html);
+    let stripped = strip_script_and_style_tags(html, options.math_style != MathStyle::Comment);
+    let stripped = if is_confluence_preset(options) {
+        Cow::Owned(prepare_confluence_markup(&stripped).into_owned())
+    } else {
+        stripped
+    };
+    let stripped = if options.preprocessing.enabled
+        && options.preprocessing.flatten_shadow_dom
+        && crate::shadow_dom::has_declarative_shadow_root(&stripped)
+    {
+        crate::shadow_dom::flatten_shadow_roots(&stripped).map_or(stripped, Cow::Owned)
+    } else {
+        stripped
+    };
     let mut preprocessed = preprocess_html(&stripped).into_owned();
     let mut preprocessed_len = preprocessed.len();
 
@@ -1921,6 +2825,12 @@ pub(crate) fn convert_html_impl(
             preprocessed_len = preprocessed.len();
         }
     }
+    if let (Some(stats), Some(stopwatch)) = (stats.as_deref_mut(), stopwatch.as_mut()) {
+        stats.preprocess_time = stopwatch.lap();
+    }
+    drop(preprocess_span);
+
+    let parse_span = crate::trace::span("phase", || Cow::Borrowed("parse"));
     let parser_options = tl::ParserOptions::default();
     let dom = loop {
         if let Ok(dom) = tl::parse(&preprocessed, parser_options) {
@@ -1937,9 +2847,19 @@ pub(crate) fn convert_html_impl(
     };
     let parser = dom.parser();
     let mut output = String::with_capacity(preprocessed_len.saturating_add(preprocessed_len / 4));
+    let working_memory = html.len() + preprocessed.capacity() + std::mem::size_of_val(dom.nodes());
+    if let (Some(stats), Some(stopwatch)) = (stats.as_deref_mut(), stopwatch.as_mut()) {
+        stats.nodes_parsed = dom.nodes().len();
+        stats.parse_time = stopwatch.lap();
+    }
+    drop(parse_span);
+    if let Some(progress) = &progress {
+        progress.begin(dom.nodes().len(), preprocessed_len);
+    }
+    let _convert_span = crate::trace::span("phase", || Cow::Borrowed("convert"));
 
     let mut is_hocr = false;
-    if may_be_hocr(preprocessed.as_ref()) {
+    if crate::format::has_hocr_markers(preprocessed.as_ref()) {
         for child_handle in dom.children() {
             if is_hocr_document(*child_handle, parser) {
                 is_hocr = true;
@@ -1972,25 +2892,42 @@ pub(crate) fn convert_html_impl(
             }
 
             if !metadata_map.is_empty() {
-                output.push_str(&format_metadata_frontmatter(&metadata_map));
+                output.push_str(&format_metadata_frontmatter(&metadata_map, options.front_matter_format));
             }
         }
 
         let mut markdown = convert_hocr_to_markdown(&elements, true, options.hocr_spatial_tables);
 
-        if markdown.trim().is_empty() {
-            return Ok(output);
+        if !markdown.trim().is_empty() {
+            markdown.truncate(markdown.trim_end().len());
+            output.push_str(&markdown);
+            output.push('\n');
         }
 
-        markdown.truncate(markdown.trim_end().len());
-        output.push_str(&markdown);
-        output.push('\n');
+        if let (Some(stats), Some(stopwatch)) = (stats, stopwatch.as_mut()) {
+            stats.convert_time = stopwatch.lap();
+            stats.note_memory(working_memory + markdown.capacity() + output.capacity());
+        }
 
-        return Ok(output);
+        return Ok((output, Vec::new()));
     }
 
     let dom_ctx = build_dom_context(&dom, parser, preprocessed_len);
 
+    let (excluded_nodes, included_nodes) = if options.preprocessing.enabled {
+        let excluded = query_selectors(&dom, &options.preprocessing.exclude_selectors, &dom_ctx)?;
+        let included = options
+            .preprocessing
+            .include_selector
+            .as_ref()
+            .map(|selector| query_selectors(&dom, std::slice::from_ref(selector), &dom_ctx))
+            .transpose()?
+            .map(|handles| outermost_nodes(handles, &dom_ctx));
+        (excluded.iter().map(tl::NodeHandle::get_inner).collect(), included)
+    } else {
+        (HashSet::new(), None)
+    };
+
     let wants_frontmatter = options.extract_metadata && !options.convert_as_inline;
     #[cfg(feature = "metadata")]
     let wants_document = metadata_collector
@@ -2034,10 +2971,19 @@ pub(crate) fn convert_html_impl(
             }
         }
 
+        if !options.title_fallback.is_empty() {
+            let metadata = head_metadata.get_or_insert_with(BTreeMap::new);
+            if !metadata.contains_key("title") {
+                if let Some(title) = document_fallback_title(metadata, dom.children(), parser, &dom_ctx, options) {
+                    metadata.insert("title".to_string(), title);
+                }
+            }
+        }
+
         if wants_frontmatter {
             if let Some(metadata) = head_metadata.as_ref() {
                 if !metadata.is_empty() {
-                    let metadata_frontmatter = format_metadata_frontmatter(metadata);
+                    let metadata_frontmatter = format_metadata_frontmatter(metadata, options.front_matter_format);
                     output.push_str(&metadata_frontmatter);
                 }
             }
@@ -2068,6 +3014,7 @@ pub(crate) fn convert_html_impl(
         metadata_wants_links,
         metadata_wants_images,
         metadata_wants_structured_data,
+        metadata_wants_source_map,
     ) = if let Some(ref collector) = metadata_collector {
         let guard = collector.borrow();
         (
@@ -2076,9 +3023,10 @@ pub(crate) fn convert_html_impl(
             guard.wants_links(),
             guard.wants_images(),
             guard.wants_structured_data(),
+            guard.wants_source_map(),
         )
     } else {
-        (false, false, false, false, false)
+        (false, false, false, false, false, false)
     };
 
     let ctx = Context {
@@ -2093,6 +3041,7 @@ pub(crate) fn convert_html_impl(
         in_list_item: false,
         list_depth: 0,
         ul_depth: 0,
+        ol_depth: 0,
         in_list: false,
         loose_list: false,
         prev_item_had_blocks: false,
@@ -2102,8 +3051,11 @@ pub(crate) fn convert_html_impl(
         in_ruby: false,
         in_strong: false,
         strip_tags: Rc::new(options.strip_tags.iter().cloned().collect()),
+        remove_tags: Rc::new(options.remove_tags.iter().map(|tag| tag.to_ascii_lowercase()).collect()),
         preserve_tags: Rc::new(options.preserve_tags.iter().cloned().collect()),
         keep_inline_images_in: Rc::new(options.keep_inline_images_in.iter().cloned().collect()),
+        print_styles,
+        excluded_nodes: Rc::new(excluded_nodes),
         #[cfg(feature = "inline-images")]
         inline_collector,
         #[cfg(feature = "metadata")]
@@ -2118,14 +3070,31 @@ pub(crate) fn convert_html_impl(
         metadata_wants_images,
         #[cfg(feature = "metadata")]
         metadata_wants_structured_data,
+        #[cfg(feature = "metadata")]
+        metadata_wants_source_map,
         #[cfg(feature = "visitor")]
         visitor: visitor.clone(),
         #[cfg(feature = "visitor")]
         visitor_error: Rc::new(RefCell::new(None)),
+        diagnostics: Rc::new(RefCell::new(Vec::new())),
+        dropped_content: Rc::new(RefCell::new(Vec::new())),
+        progress,
+        footnotes: Rc::new(RefCell::new(TitleFootnotes::default())),
+        footnoted_node: None,
+        sink,
     };
 
-    for child_handle in dom.children() {
+    let main_content = if included_nodes.is_some() {
+        included_nodes
+    } else if options.preprocessing.enabled && options.preprocessing.extract_main_content {
+        crate::main_content::find_main_content(&dom)
+    } else {
+        None
+    };
+    let roots = main_content.as_deref().unwrap_or_else(|| dom.children());
+    for child_handle in roots {
         walk_node(child_handle, parser, &mut output, options, &ctx, 0, &dom_ctx);
+        flush_finished_blocks(&mut output, options, &ctx);
     }
 
     #[cfg(feature = "visitor")]
@@ -2133,19 +3102,59 @@ pub(crate) fn convert_html_impl(
         return Err(crate::error::ConversionError::Visitor(err.clone()));
     }
 
+    // Source map ranges were recorded against the rewritten input; point them at `html`.
+    #[cfg(feature = "metadata")]
+    if ctx.metadata_wants_source_map && preprocessed != html {
+        if let Some(ref collector) = ctx.metadata_collector {
+            let offsets = crate::source_map::OffsetMap::new(&preprocessed, html);
+            collector.borrow_mut().remap_source_offsets(&offsets);
+        }
+    }
+
+    ctx.footnotes.borrow_mut().flush(&mut output);
     trim_line_end_whitespace(&mut output);
+    if let (Some(stats), Some(stopwatch)) = (stats, stopwatch.as_mut()) {
+        stats.convert_time = stopwatch.lap();
+        stats.note_memory(working_memory + output.capacity());
+    }
+    if options.report_dropped_content {
+        let document_characters: usize = dom.children().iter().map(|child| text_length(child, parser)).sum();
+        if let Some(roots) = main_content.as_deref() {
+            let kept: usize = roots.iter().map(|root| text_length(root, parser)).sum();
+            let rule = if options.preprocessing.include_selector.is_some() {
+                "include_selector"
+            } else {
+                "extract_main_content"
+            };
+            ctx.dropped_content
+                .borrow_mut()
+                .insert(0, (rule, document_characters.saturating_sub(kept)));
+        }
+        ctx.diagnostics.borrow_mut().extend(
+            ctx.dropped_content
+                .take()
+                .into_iter()
+                .map(|(rule, characters)| Diagnostic::dropped_content(rule, characters, document_characters)),
+        );
+    }
+    let diagnostics = ctx.diagnostics.take();
+    if ctx.sink.is_some() {
+        // `ChunkSink::finish` ends the output once it knows whether anything came before.
+        return Ok((output, diagnostics));
+    }
     let trimmed = output.trim_end_matches('\n');
     if trimmed.is_empty() {
-        Ok(String::new())
+        Ok((String::new(), diagnostics))
     } else {
-        Ok(format!("{trimmed}\n"))
+        Ok((format!("{trimmed}\n"), diagnostics))
     }
 }
 
 /// Strip script and style tags completely from HTML before parsing.
 ///
 /// This function performs a fast, single-pass removal of <script> and <style> tags
-/// along with their entire content. It preserves JSON-LD script tags for metadata extraction.
+/// along with their entire content. It preserves JSON-LD script tags for metadata extraction,
+/// and MathJax `math/tex` scripts when `keep_tex_scripts` is set.
 ///
 /// # Performance
 ///
@@ -2166,12 +3175,12 @@ pub(crate) fn convert_html_impl(
 ///
 /// ```ignore
 /// let html = r#"<html><head><script>bad code</script></head><body>content</body></html>"#;
-/// let stripped = strip_script_and_style_tags(html);
+/// let stripped = strip_script_and_style_tags(html, false);
 /// assert!(!stripped.contains("<script>"));
 /// assert!(stripped.contains("content"));
 /// ```
 #[inline]
-fn strip_script_and_style_tags(input: &str) -> Cow<'_, str> {
+pub(crate) fn strip_script_and_style_tags(input: &str, keep_tex_scripts: bool) -> Cow<'_, str> {
     let bytes = input.as_bytes();
     let len = bytes.len();
 
@@ -2250,7 +3259,9 @@ fn strip_script_and_style_tags(input: &str) -> Cow<'_, str> {
 
                         // Check if this is a JSON-LD script tag
                         let tag_content = &input[idx..tag_end];
-                        if !is_json_ld_script_open_tag(tag_content) {
+                        let preserved = is_json_ld_script_open_tag(tag_content)
+                            || (keep_tex_scripts && is_tex_script_open_tag(tag_content));
+                        if !preserved {
                             // Find the closing </script> tag
                             let close_tag = find_closing_tag_bytes(bytes, tag_end, b"script");
                             if let Some(close_idx) = close_tag {
@@ -2447,7 +3458,10 @@ fn preprocess_html(input: &str) -> Cow<'_, str> {
                 for tag in TAGS {
                     if matches_tag_start(bytes, idx + 1, tag) {
                         if let Some(open_end) = find_tag_end(bytes, idx + 1 + tag.len()) {
-                            if tag == b"script" && is_json_ld_script_open_tag(&input[idx..open_end]) {
+                            if tag == b"script"
+                                && (is_json_ld_script_open_tag(&input[idx..open_end])
+                                    || is_tex_script_open_tag(&input[idx..open_end]))
+                            {
                                 continue;
                             }
                             let remove_end = find_closing_tag(bytes, open_end, tag).unwrap_or(len);
@@ -2536,6 +3550,17 @@ fn preprocess_html(input: &str) -> Cow<'_, str> {
 }
 
 fn is_json_ld_script_open_tag(tag: &str) -> bool {
+    script_media_type(tag)
+        .is_some_and(|media_type| eq_ascii_case_insensitive(media_type.as_bytes(), b"application/ld+json"))
+}
+
+/// MathJax keeps TeX in `<script type="math/tex">`, which `math_style` turns into Markdown math.
+fn is_tex_script_open_tag(tag: &str) -> bool {
+    script_media_type(tag).is_some_and(|media_type| media_type.eq_ignore_ascii_case("math/tex"))
+}
+
+/// The `type` attribute of a `<script>` open tag, without parameters.
+fn script_media_type(tag: &str) -> Option<&str> {
     let bytes = tag.as_bytes();
     let mut idx = 0;
     while idx + 4 <= bytes.len() {
@@ -2565,7 +3590,7 @@ fn is_json_ld_script_open_tag(tag: &str) -> bool {
                 i += 1;
             }
             if i >= bytes.len() {
-                return false;
+                return None;
             }
 
             let (value_start, value_end) = match bytes[i] {
@@ -2589,12 +3614,11 @@ fn is_json_ld_script_open_tag(tag: &str) -> bool {
             };
 
             let value = &tag[value_start..value_end];
-            let media_type = value.split(';').next().unwrap_or(value).trim();
-            return eq_ascii_case_insensitive(media_type.as_bytes(), b"application/ld+json");
+            return Some(value.split(';').next().unwrap_or(value).trim());
         }
         idx += 1;
     }
-    false
+    None
 }
 
 fn eq_ascii_case_insensitive(haystack: &[u8], needle: &[u8]) -> bool {
@@ -2728,58 +3752,499 @@ fn tag_name_eq(name: Cow<'_, str>, needle: &str) -> bool {
     name.eq_ignore_ascii_case(needle)
 }
 
-#[allow(clippy::trivially_copy_pass_by_ref)]
-fn should_drop_for_preprocessing(
-    node_handle: &tl::NodeHandle,
-    tag_name: &str,
-    tag: &tl::HTMLTag,
-    parser: &tl::Parser,
-    dom_ctx: &DomContext,
-    options: &ConversionOptions,
-) -> bool {
-    if !options.preprocessing.enabled {
-        return false;
-    }
+const fn is_confluence_preset(options: &ConversionOptions) -> bool {
+    options.preprocessing.enabled && matches!(options.preprocessing.preset, PreprocessingPreset::Confluence)
+}
 
-    if options.preprocessing.remove_navigation {
-        let has_nav_hint = element_has_navigation_hint(tag);
+/// Rewrite Confluence storage-format markup that the HTML parser cannot handle.
+///
+/// Code macro bodies are kept in `<![CDATA[...]]>`, which HTML parsers treat as a bogus comment
+/// ending at the first `>`; those sections become escaped text. `<colgroup>` blocks are dropped,
+/// as their self-closed `<col/>` children swallow the rest of the table.
+fn prepare_confluence_markup(input: &str) -> Cow<'_, str> {
+    const CDATA_OPEN: &[u8] = b"<![CDATA[";
+    const CDATA_CLOSE: &str = "]]>";
 
-        if tag_name == "nav" {
-            return true;
-        }
+    let bytes = input.as_bytes();
+    let mut output: Option<String> = None;
+    let mut last = 0;
+    let mut idx = 0;
 
-        if tag_name == "header" {
-            let inside_semantic_content = has_semantic_content_ancestor(node_handle, parser, dom_ctx);
-            if !inside_semantic_content {
-                return true;
-            }
-            if has_nav_hint {
-                return true;
-            }
-        } else if tag_name == "footer" || tag_name == "aside" {
-            if has_nav_hint {
-                return true;
+    while let Some(offset) = bytes[idx..].iter().position(|&b| b == b'<') {
+        idx += offset;
+        if bytes[idx..].starts_with(CDATA_OPEN) {
+            let out = output.get_or_insert_with(|| String::with_capacity(input.len()));
+            out.push_str(&input[last..idx]);
+            let data_start = idx + CDATA_OPEN.len();
+            let data_end = input[data_start..]
+                .find(CDATA_CLOSE)
+                .map_or(input.len(), |end| data_start + end);
+            for ch in input[data_start..data_end].chars() {
+                match ch {
+                    '&' => out.push_str("&amp;"),
+                    '<' => out.push_str("&lt;"),
+                    '>' => out.push_str("&gt;"),
+                    _ => out.push(ch),
+                }
             }
-        } else if has_nav_hint && !matches!(tag_name, "main" | "article" | "html" | "body" | "head") {
-            return true;
+            idx = (data_end + CDATA_CLOSE.len()).min(input.len());
+            last = idx;
+        } else if matches_tag_start(bytes, idx + 1, b"colgroup") {
+            let out = output.get_or_insert_with(|| String::with_capacity(input.len()));
+            out.push_str(&input[last..idx]);
+            idx = find_closing_tag_bytes(bytes, idx, b"colgroup")
+                .or_else(|| find_tag_end(bytes, idx))
+                .unwrap_or(input.len());
+            last = idx;
+        } else {
+            idx += 1;
         }
     }
 
-    if options.preprocessing.remove_forms {
-        if tag_name == "form" {
-            let preserves_form = options.preserve_tags.iter().any(|t| t == "form");
-            if !preserves_form {
-                return true;
-            }
-        } else if matches!(
-            tag_name,
-            "button" | "select" | "textarea" | "label" | "fieldset" | "legend"
-        ) {
-            return true;
+    match output {
+        Some(mut out) => {
+            out.push_str(&input[last..]);
+            Cow::Owned(out)
         }
+        None => Cow::Borrowed(input),
     }
+}
 
-    false
+/// Emoji and default title of the Confluence panel macros rendered as labelled blockquotes.
+fn confluence_panel_label(macro_name: &str) -> Option<(&'static str, &'static str)> {
+    match macro_name {
+        "info" => Some(("\u{2139}\u{fe0f}", "Info")),
+        "note" => Some(("\u{1f4dd}", "Note")),
+        "tip" => Some(("\u{1f4a1}", "Tip")),
+        "warning" => Some(("\u{26a0}\u{fe0f}", "Warning")),
+        _ => None,
+    }
+}
+
+/// Trace label (`tag#id`) for an element directly under `<body>` or at the document root.
+fn top_level_element_label(
+    node_handle: &tl::NodeHandle,
+    node: &tl::Node,
+    parser: &tl::Parser,
+    dom_ctx: &DomContext,
+) -> Option<String> {
+    let tl::Node::Tag(tag) = node else { return None };
+    let parent = dom_ctx
+        .parent_of(node_handle.get_inner())
+        .and_then(|parent| dom_ctx.node_handle(parent))
+        .and_then(|parent| parent.get(parser))
+        .and_then(tl::Node::as_tag);
+    if parent.is_some_and(|parent| !tag_name_eq(parent.name().as_utf8_str(), "body")) {
+        return None;
+    }
+
+    let mut label = normalized_tag_name(tag.name().as_utf8_str()).into_owned();
+    if let Some(id) = tag.attributes().id() {
+        label.push('#');
+        label.push_str(&id.as_utf8_str());
+    }
+    Some(label)
+}
+
+/// Write a `<details>` element as HTML for `DetailsStyle::Html`.
+///
+/// The first `<summary>` becomes inline Markdown inside `<summary>` tags; everything else is
+/// converted as usual, separated from the tags by blank lines so GitHub renders it as Markdown.
+fn write_details_html(
+    tag: &tl::HTMLTag,
+    parser: &tl::Parser,
+    output: &mut String,
+    options: &ConversionOptions,
+    ctx: &Context,
+    depth: usize,
+    dom_ctx: &DomContext,
+) {
+    let mut summary: Option<String> = None;
+    let mut body = String::with_capacity(256);
+    let mut summary_ctx = ctx.clone();
+    summary_ctx.convert_as_inline = true;
+
+    for child_handle in tag.children().top().iter() {
+        if summary.is_none() {
+            if let Some(tl::Node::Tag(child)) = child_handle.get(parser) {
+                if tag_name_eq(child.name().as_utf8_str(), "summary") {
+                    let mut text = String::with_capacity(64);
+                    for grandchild in child.children().top().iter() {
+                        walk_node(grandchild, parser, &mut text, options, &summary_ctx, depth + 1, dom_ctx);
+                    }
+                    summary = Some(text);
+                    continue;
+                }
+            }
+        }
+        walk_node(child_handle, parser, &mut body, options, ctx, depth, dom_ctx);
+    }
+
+    if !output.is_empty() && !output.ends_with("\n\n") {
+        trim_trailing_whitespace(output);
+        output.push_str(if output.ends_with('\n') { "\n" } else { "\n\n" });
+    }
+    output.push_str("<details");
+    if tag.attributes().get("open").is_some() {
+        output.push_str(" open");
+    }
+    output.push_str(">\n");
+    if let Some(summary) = summary {
+        output.push_str("<summary>");
+        output.push_str(summary.trim());
+        output.push_str("</summary>\n");
+    }
+    let body = body.trim_start_matches('\n').trim_end();
+    if !body.is_empty() {
+        output.push('\n');
+        output.push_str(body);
+        output.push_str("\n\n");
+    }
+    output.push_str("</details>\n\n");
+}
+
+/// URL for an `<img>` without a `src`, chosen by `srcset_policy` from its own `srcset` and,
+/// inside `<picture>`, the `srcset` of the `<source>` elements before it.
+fn srcset_fallback(
+    node_handle: &tl::NodeHandle,
+    tag: &tl::HTMLTag,
+    parser: &tl::Parser,
+    policy: crate::options::SrcsetPolicy,
+    dom_ctx: &DomContext,
+) -> Option<String> {
+    let mut candidates = Vec::new();
+
+    let picture = dom_ctx
+        .parent_of(node_handle.get_inner())
+        .and_then(|parent| dom_ctx.node_handle(parent))
+        .and_then(|parent| parent.get(parser))
+        .and_then(tl::Node::as_tag)
+        .filter(|parent| tag_name_eq(parent.name().as_utf8_str(), "picture"));
+    if let Some(picture) = picture {
+        for child in picture.children().top().iter() {
+            if child.get_inner() == node_handle.get_inner() {
+                break;
+            }
+            if let Some(tl::Node::Tag(source)) = child.get(parser) {
+                if tag_name_eq(source.name().as_utf8_str(), "source") {
+                    if let Some(srcset) = source.attributes().get("srcset").flatten() {
+                        crate::srcset::parse_into(&srcset.as_utf8_str(), &mut candidates);
+                    }
+                }
+            }
+        }
+    }
+    if let Some(srcset) = tag.attributes().get("srcset").flatten() {
+        crate::srcset::parse_into(&srcset.as_utf8_str(), &mut candidates);
+    }
+
+    crate::srcset::select(&candidates, policy).map(str::to_string)
+}
+
+/// Language of a `<pre>` block without a `language-*` class, for `infer_code_language`.
+///
+/// Looks at highlighter classes and `data-lang`/`data-language` on the `<pre>` and its first
+/// `<code>` child, then at the code itself.
+fn infer_code_block_language(tag: &tl::HTMLTag, parser: &tl::Parser, code: &str) -> Option<String> {
+    let code_child = tag.children().top().iter().find_map(|child| match child.get(parser) {
+        Some(tl::Node::Tag(child)) if child.name().as_utf8_str().eq_ignore_ascii_case("code") => Some(child),
+        _ => None,
+    });
+    let declared = std::iter::once(tag).chain(code_child).find_map(|element| {
+        let attributes = element.attributes();
+        let data_lang = ["data-lang", "data-language"].iter().find_map(|name| {
+            attributes
+                .get(*name)
+                .flatten()
+                .map(|value| value.as_utf8_str().trim().to_ascii_lowercase())
+                .filter(|value| !value.is_empty())
+        });
+        attributes
+            .get("class")
+            .flatten()
+            .and_then(|class| crate::code_language::class_language(&class.as_utf8_str()))
+            .or(data_lang)
+    });
+    declared.or_else(|| crate::code_language::guess_language(code).map(str::to_string))
+}
+
+/// Write a code block in the configured style, without the `<pre>` handling for whitespace.
+fn push_code_block(output: &mut String, ctx: &Context, options: &ConversionOptions, language: &str, code: &str) {
+    if !ctx.convert_as_inline && !output.is_empty() && !output.ends_with("\n\n") {
+        if output.ends_with('\n') {
+            output.push('\n');
+        } else {
+            output.push_str("\n\n");
+        }
+    }
+
+    let fence = match options.code_block_style {
+        crate::options::CodeBlockStyle::Indented => {
+            for line in code.lines() {
+                if !line.is_empty() {
+                    output.push_str("    ");
+                    output.push_str(line);
+                }
+                output.push('\n');
+            }
+            output.push('\n');
+            return;
+        }
+        crate::options::CodeBlockStyle::Backticks => "```",
+        crate::options::CodeBlockStyle::Tildes => "~~~",
+    };
+
+    output.push_str(fence);
+    output.push_str(if language.is_empty() {
+        &options.code_language
+    } else {
+        language
+    });
+    output.push('\n');
+    output.push_str(code);
+    output.push('\n');
+    output.push_str(fence);
+    output.push('\n');
+}
+
+/// Write `content` as a blockquote, followed by an optional `(prefix, url)` attribution line.
+fn push_blockquote(output: &mut String, ctx: &Context, content: &str, attribution: Option<(&str, &str)>) {
+    if content.is_empty() {
+        return;
+    }
+
+    if ctx.blockquote_depth > 0 {
+        output.push_str("\n\n\n");
+    } else if !output.is_empty() {
+        if output.ends_with("\n\n") {
+            // Paragraph already added \n\n; blockquote needs just \n
+            output.truncate(output.len() - 1);
+        } else if !output.ends_with('\n') {
+            output.push_str("\n\n");
+        } else if !output.ends_with("\n\n") {
+            output.push('\n');
+        }
+    }
+
+    let prefix = "> ";
+
+    for line in content.lines() {
+        output.push_str(prefix);
+        output.push_str(line.trim());
+        output.push('\n');
+    }
+
+    if let Some((attribution, url)) = attribution {
+        output.push('\n');
+        output.push_str(attribution);
+        output.push('<');
+        output.push_str(url);
+        output.push_str(">\n\n");
+    }
+
+    // Add trailing newlines only when appropriate for proper spacing
+    // (matching paragraph conditional logic for CommonMark compliance)
+    if !ctx.convert_as_inline && !ctx.in_table_cell && !ctx.in_list_item {
+        while output.ends_with('\n') {
+            output.truncate(output.len() - 1);
+        }
+        output.push_str("\n\n");
+    }
+}
+
+/// The preprocessing option that removes this element, if any.
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn preprocessing_drop_rule(
+    node_handle: &tl::NodeHandle,
+    tag_name: &str,
+    tag: &tl::HTMLTag,
+    parser: &tl::Parser,
+    dom_ctx: &DomContext,
+    options: &ConversionOptions,
+) -> Option<&'static str> {
+    if !options.preprocessing.enabled {
+        return None;
+    }
+
+    if options.preprocessing.remove_navigation {
+        let has_nav_hint = element_has_navigation_hint(tag);
+
+        if tag_name == "nav" {
+            return Some("remove_navigation");
+        }
+
+        if tag_name == "header" {
+            let inside_semantic_content = has_semantic_content_ancestor(node_handle, parser, dom_ctx);
+            if !inside_semantic_content {
+                return Some("remove_navigation");
+            }
+            if has_nav_hint {
+                return Some("remove_navigation");
+            }
+        } else if tag_name == "footer" || tag_name == "aside" {
+            if has_nav_hint {
+                return Some("remove_navigation");
+            }
+        } else if has_nav_hint && !matches!(tag_name, "main" | "article" | "html" | "body" | "head") {
+            return Some("remove_navigation");
+        }
+    }
+
+    if options.preprocessing.remove_forms {
+        if tag_name == "form" {
+            let preserves_form = options.preserve_tags.iter().any(|t| t == "form");
+            if !preserves_form {
+                return Some("remove_forms");
+            }
+        } else if matches!(
+            tag_name,
+            "button" | "select" | "textarea" | "label" | "fieldset" | "legend"
+        ) {
+            return Some("remove_forms");
+        }
+    }
+
+    None
+}
+
+/// Characters of text under a node, whitespace excluded, as counted by `report_dropped_content`.
+fn text_length(node_handle: &tl::NodeHandle, parser: &tl::Parser) -> usize {
+    match node_handle.get(parser) {
+        Some(tl::Node::Raw(bytes)) => text::decode_html_entities_cow(bytes.as_utf8_str().as_ref())
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .count(),
+        Some(tl::Node::Tag(tag)) => {
+            let name = tag.name().as_utf8_str();
+            if ["head", "script", "style", "template"]
+                .iter()
+                .any(|skipped| name.eq_ignore_ascii_case(skipped))
+            {
+                return 0;
+            }
+            tag.children()
+                .top()
+                .iter()
+                .map(|child| text_length(child, parser))
+                .sum()
+        }
+        _ => 0,
+    }
+}
+
+fn record_dropped_content(ctx: &Context, rule: &'static str, node_handle: &tl::NodeHandle, parser: &tl::Parser) {
+    let characters = text_length(node_handle, parser);
+    let mut dropped = ctx.dropped_content.borrow_mut();
+    if let Some(entry) = dropped.iter_mut().find(|(name, _)| *name == rule) {
+        entry.1 += characters;
+    } else {
+        dropped.push((rule, characters));
+    }
+}
+
+/// Escape prose text, rewriting MathJax `\(...\)` and `\[...\]` delimiters as Markdown math
+/// unless `math_style` keeps math as HTML comments.
+fn escape_prose(text: &str, options: &ConversionOptions) -> String {
+    let escaping = text::Escaping::from(options);
+    let escape = |plain: &str| text::escape_with(plain, escaping);
+    if options.math_style != MathStyle::Comment {
+        if let Some(converted) = crate::math::replace_tex_delimiters(text, options.math_style, escape) {
+            return converted;
+        }
+    }
+    escape(text)
+}
+
+/// Whether a `<script>` holds MathJax TeX (`math/tex`, optionally with `; mode=display`).
+fn is_tex_script(tag: &tl::HTMLTag) -> bool {
+    tag.attributes().get("type").flatten().is_some_and(|value| {
+        let value = value.as_utf8_str();
+        value
+            .split(';')
+            .next()
+            .unwrap_or(&value)
+            .trim()
+            .eq_ignore_ascii_case("math/tex")
+    })
+}
+
+/// Write TeX in the configured math style: display math as its own block where a block can
+/// start, everything else inline.
+fn push_math(output: &mut String, tex: &str, display: bool, options: &ConversionOptions, ctx: &Context) {
+    if tex.is_empty() {
+        return;
+    }
+    let can_start_block = !(ctx.in_paragraph
+        || ctx.convert_as_inline
+        || ctx.in_table_cell
+        || ctx.in_heading
+        || ctx.in_list_item
+        || ctx.inline_depth > 0);
+    if display && can_start_block {
+        trim_trailing_whitespace(output);
+        if output.ends_with('\n') {
+            if !output.ends_with("\n\n") {
+                output.push('\n');
+            }
+        } else if !output.is_empty() {
+            output.push_str("\n\n");
+        }
+        output.push_str(&crate::math::display_math(tex, options.math_style));
+        output.push_str("\n\n");
+    } else {
+        output.push_str(&crate::math::inline_math(tex, display, options.math_style));
+    }
+}
+
+/// Resolve CSS selectors against the parsed document, rejecting unsupported ones.
+fn query_selectors(dom: &tl::VDom, selectors: &[String], dom_ctx: &DomContext) -> Result<Vec<tl::NodeHandle>> {
+    let mut handles = Vec::new();
+    for selector in selectors {
+        let selector = selector.trim();
+        if selector.is_empty() {
+            continue;
+        }
+        let list = crate::selector::SelectorList::parse(selector)
+            .ok_or_else(|| crate::error::ConversionError::ConfigError(format!("invalid CSS selector: {selector:?}")))?;
+        let parent_of = |id: u32| dom_ctx.parent_of(id);
+        handles.extend(
+            (0..dom.nodes().len() as u32)
+                .filter(|id| list.matches(*id, dom.parser(), &parent_of))
+                .map(tl::NodeHandle::new),
+        );
+    }
+    Ok(handles)
+}
+
+/// Keep only the matched nodes that are not nested inside another match, in document order.
+fn outermost_nodes(mut handles: Vec<tl::NodeHandle>, dom_ctx: &DomContext) -> Vec<tl::NodeHandle> {
+    let ids: HashSet<u32> = handles.iter().map(tl::NodeHandle::get_inner).collect();
+    handles.sort_by_key(tl::NodeHandle::get_inner);
+    handles.dedup_by_key(|handle| handle.get_inner());
+    handles.retain(|handle| {
+        let mut current = handle.get_inner();
+        while let Some(parent) = dom_ctx.parent_of(current) {
+            if ids.contains(&parent) {
+                return false;
+            }
+            current = parent;
+        }
+        true
+    });
+    handles
+}
+
+fn is_hidden_in_print(tag_name: &str, tag: &tl::HTMLTag, ctx: &Context) -> bool {
+    let Some(print_styles) = ctx.print_styles.as_deref() else {
+        return false;
+    };
+    let attribute = |name: &str| {
+        tag.attributes()
+            .get(name)
+            .flatten()
+            .map(|value| value.as_utf8_str().into_owned())
+    };
+    print_styles.hides(tag_name, attribute("id").as_deref(), attribute("class").as_deref())
 }
 
 #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -2925,16 +4390,22 @@ fn attribute_contains_any(tag: &tl::HTMLTag, attr: &str, keywords: &[&str]) -> b
 /// Serialize a tag and its children back to HTML.
 ///
 /// This is used for the `preserve_tags` feature to output original HTML for specific elements.
+/// Attributes are filtered through `preserved_attributes` (see [`retain_attribute`]).
 #[allow(clippy::trivially_copy_pass_by_ref)]
-fn serialize_tag_to_html(handle: &tl::NodeHandle, parser: &tl::Parser) -> String {
+fn serialize_tag_to_html(handle: &tl::NodeHandle, parser: &tl::Parser, preserved_attributes: &[String]) -> String {
     let mut html = String::new();
-    serialize_node_to_html(handle, parser, &mut html);
+    serialize_node_to_html(handle, parser, &mut html, preserved_attributes);
     html
 }
 
 /// Recursively serialize a node to HTML.
 #[allow(clippy::trivially_copy_pass_by_ref)]
-fn serialize_node_to_html(handle: &tl::NodeHandle, parser: &tl::Parser, output: &mut String) {
+fn serialize_node_to_html(
+    handle: &tl::NodeHandle,
+    parser: &tl::Parser,
+    output: &mut String,
+    preserved_attributes: &[String],
+) {
     match handle.get(parser) {
         Some(tl::Node::Tag(tag)) => {
             let tag_name = normalized_tag_name(tag.name().as_utf8_str());
@@ -2942,7 +4413,10 @@ fn serialize_node_to_html(handle: &tl::NodeHandle, parser: &tl::Parser, output:
             output.push('<');
             output.push_str(&tag_name);
 
-            for (key, value) in tag.attributes().iter() {
+            for (key, mut value) in tag.attributes().iter() {
+                if !retain_attribute(&key, &mut value, preserved_attributes) {
+                    continue;
+                }
                 output.push(' ');
                 output.push_str(&key);
                 if let Some(val) = value {
@@ -2956,25 +4430,10 @@ fn serialize_node_to_html(handle: &tl::NodeHandle, parser: &tl::Parser, output:
 
             let children = tag.children();
             for child_handle in children.top().iter() {
-                serialize_node_to_html(child_handle, parser, output);
-            }
-
-            if !matches!(
-                tag_name.as_ref(),
-                "br" | "hr"
-                    | "img"
-                    | "input"
-                    | "meta"
-                    | "link"
-                    | "area"
-                    | "base"
-                    | "col"
-                    | "embed"
-                    | "param"
-                    | "source"
-                    | "track"
-                    | "wbr"
-            ) {
+                serialize_node_to_html(child_handle, parser, output, preserved_attributes);
+            }
+
+            if !is_void_tag(&tag_name) {
                 output.push_str("</");
                 output.push_str(&tag_name);
                 output.push('>');
@@ -2989,6 +4448,248 @@ fn serialize_node_to_html(handle: &tl::NodeHandle, parser: &tl::Parser, output:
     }
 }
 
+/// Apply the `preserved_attributes` allowlist to one attribute of an element written out as HTML.
+///
+/// Returns `false` when the attribute must be dropped. An empty list keeps everything. Listed
+/// names match case-insensitively; `style:<property>` entries keep the `style` attribute with
+/// only those declarations, rewriting `value` accordingly.
+fn retain_attribute(name: &str, value: &mut Option<Cow<'_, str>>, preserved_attributes: &[String]) -> bool {
+    if preserved_attributes.is_empty() || preserved_attributes.iter().any(|kept| kept.eq_ignore_ascii_case(name)) {
+        return true;
+    }
+    if !name.eq_ignore_ascii_case("style") {
+        return false;
+    }
+    let Some(style) = value.as_deref() else {
+        return false;
+    };
+
+    let kept_properties: Vec<&str> = preserved_attributes
+        .iter()
+        .filter_map(|kept| {
+            kept.get(..6)
+                .filter(|prefix| prefix.eq_ignore_ascii_case("style:"))
+                .map(|_| kept[6..].trim())
+        })
+        .collect();
+    let declarations: Vec<&str> = style
+        .split(';')
+        .map(str::trim)
+        .filter(|declaration| {
+            declaration.split_once(':').is_some_and(|(property, _)| {
+                kept_properties
+                    .iter()
+                    .any(|kept| kept.eq_ignore_ascii_case(property.trim()))
+            })
+        })
+        .collect();
+    if declarations.is_empty() {
+        return false;
+    }
+    *value = Some(Cow::Owned(declarations.join("; ")));
+    true
+}
+
+fn is_void_tag(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "br" | "hr"
+            | "img"
+            | "input"
+            | "meta"
+            | "link"
+            | "area"
+            | "base"
+            | "col"
+            | "embed"
+            | "param"
+            | "source"
+            | "track"
+            | "wbr"
+    )
+}
+
+/// Elements counted by the fidelity guard: the structure a reader of the Markdown expects to
+/// find again.
+const STRUCTURAL_TAGS: [&str; 30] = [
+    "table",
+    "caption",
+    "tr",
+    "th",
+    "td",
+    "ul",
+    "ol",
+    "li",
+    "dl",
+    "dt",
+    "dd",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "pre",
+    "blockquote",
+    "img",
+    "form",
+    "fieldset",
+    "legend",
+    "label",
+    "input",
+    "select",
+    "option",
+    "textarea",
+    "button",
+    "math",
+];
+
+/// Keep a table, form or `<math>` subtree as sanitized HTML when converting it would lose more
+/// than `fidelity_threshold` of its structural elements. Returns whether the subtree was written.
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn keep_lossy_subtree(
+    node_handle: &tl::NodeHandle,
+    tag_name: &str,
+    parser: &tl::Parser,
+    output: &mut String,
+    options: &ConversionOptions,
+    ctx: &Context,
+) -> bool {
+    let (mut lost, mut total) = (0, 0);
+    count_structure(node_handle, parser, options, ctx.in_table_cell, &mut lost, &mut total);
+    #[allow(clippy::cast_precision_loss)]
+    if total == 0 || (lost as f64) / (total as f64) <= options.fidelity_threshold {
+        return false;
+    }
+
+    let mut html = String::new();
+    serialize_sanitized_html(node_handle, parser, &mut html, &options.preserved_attributes);
+    if ctx.in_table_cell {
+        html = html.replace(['\n', '\r'], " ");
+    }
+    let block = tag_name != "math" && !ctx.in_table_cell && !ctx.convert_as_inline;
+    if block && !output.is_empty() && !output.ends_with("\n\n") {
+        output.push_str("\n\n");
+    }
+    output.push_str(html.trim());
+    if block {
+        output.push_str("\n\n");
+    }
+
+    ctx.diagnostics.borrow_mut().push(Diagnostic::kept_as_html(
+        tag_name,
+        format!("kept <{tag_name}> as HTML: {lost} of {total} structural elements would be lost in Markdown"),
+    ));
+    true
+}
+
+/// Count the structural elements under `handle`, and how many of them the conversion drops or
+/// flattens: everything block-level inside a table cell, merged cells, form controls, and
+/// MathML that is only kept as a comment.
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn count_structure(
+    handle: &tl::NodeHandle,
+    parser: &tl::Parser,
+    options: &ConversionOptions,
+    in_cell: bool,
+    lost: &mut usize,
+    total: &mut usize,
+) {
+    let Some(tl::Node::Tag(tag)) = handle.get(parser) else {
+        return;
+    };
+    let tag_name = normalized_tag_name(tag.name().as_utf8_str());
+    let tag_name = tag_name.as_ref();
+
+    if STRUCTURAL_TAGS.contains(&tag_name) {
+        *total += 1;
+        let spans = || {
+            ["colspan", "rowspan"].iter().any(|name| {
+                tag.attributes()
+                    .get(*name)
+                    .flatten()
+                    .and_then(|value| value.as_utf8_str().trim().parse::<usize>().ok())
+                    .is_some_and(|span| span > 1)
+            })
+        };
+        let is_lost = match tag_name {
+            "img" => false,
+            _ if in_cell => true,
+            "td" | "th" => spans(),
+            "form" | "input" | "select" | "option" | "textarea" | "button" => true,
+            "math" => options.math_style == MathStyle::Comment,
+            _ => false,
+        };
+        if is_lost {
+            *lost += 1;
+        }
+    }
+    if tag_name == "math" {
+        return;
+    }
+
+    let in_cell = in_cell || matches!(tag_name, "td" | "th");
+    for child in tag.children().top().iter() {
+        count_structure(child, parser, options, in_cell, lost, total);
+    }
+}
+
+/// Serialize a subtree to HTML without scripts, styles, event handler attributes or
+/// `javascript:` URLs.
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn serialize_sanitized_html(
+    handle: &tl::NodeHandle,
+    parser: &tl::Parser,
+    output: &mut String,
+    preserved_attributes: &[String],
+) {
+    match handle.get(parser) {
+        Some(tl::Node::Tag(tag)) => {
+            let tag_name = normalized_tag_name(tag.name().as_utf8_str());
+            if matches!(tag_name.as_ref(), "script" | "style" | "template") {
+                return;
+            }
+
+            output.push('<');
+            output.push_str(&tag_name);
+            for (key, mut value) in tag.attributes().iter() {
+                if key.get(..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("on"))
+                    || !retain_attribute(&key, &mut value, preserved_attributes)
+                {
+                    continue;
+                }
+                if let Some(value) = value {
+                    let url = value.trim_start().to_ascii_lowercase();
+                    if url.starts_with("javascript:") || url.starts_with("vbscript:") {
+                        continue;
+                    }
+                    output.push(' ');
+                    output.push_str(&key);
+                    output.push_str("=\"");
+                    output.push_str(&value.replace('"', "&quot;"));
+                    output.push('"');
+                } else {
+                    output.push(' ');
+                    output.push_str(&key);
+                }
+            }
+            output.push('>');
+
+            for child in tag.children().top().iter() {
+                serialize_sanitized_html(child, parser, output, preserved_attributes);
+            }
+
+            if !is_void_tag(&tag_name) {
+                output.push_str("</");
+                output.push_str(&tag_name);
+                output.push('>');
+            }
+        }
+        Some(tl::Node::Raw(bytes)) => output.push_str(&bytes.as_utf8_str()),
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 fn strip_script_and_style_sections(input: &str) -> Cow<'_, str> {
     const TAGS: [&[u8]; 2] = [b"script", b"style"];
@@ -3307,6 +5008,277 @@ fn next_sibling_is_inline_tag(node_handle: &tl::NodeHandle, parser: &tl::Parser,
     dom_ctx.next_inline_like(*node_handle, parser)
 }
 
+/// Whether invisible formatting characters are stripped from text, per `remove_soft_hyphens`
+/// and the unrepresentable content policy.
+pub(crate) fn strips_invisible_formatting(options: &ConversionOptions) -> bool {
+    options.remove_soft_hyphens && options.unrepresentable_policy != UnrepresentablePolicy::Passthrough
+}
+
+/// Remove soft hyphens and word joiners, recording a diagnostic when the policy asks for one.
+fn remove_invisible_formatting<'a>(text: &'a str, options: &ConversionOptions, ctx: &Context) -> Cow<'a, str> {
+    let stripped = text::remove_soft_hyphens_cow(text);
+    if options.unrepresentable_policy == UnrepresentablePolicy::Diagnose {
+        if let Cow::Owned(ref remaining) = stripped {
+            let removed = text.chars().count() - remaining.chars().count();
+            record_unrepresentable(
+                ctx,
+                None,
+                format!("removed {removed} invisible formatting character(s) (soft hyphen or word joiner)"),
+            );
+        }
+    }
+    stripped
+}
+
+fn record_unrepresentable(ctx: &Context, element: Option<&str>, message: String) {
+    ctx.diagnostics
+        .borrow_mut()
+        .push(Diagnostic::unrepresentable(element, message));
+}
+
+/// Keep or report a `<bdo>`/`<bdi>` whose direction semantics Markdown cannot express.
+fn apply_unrepresentable_isolate(
+    output: &mut String,
+    start: usize,
+    tag: &tl::HTMLTag,
+    tag_name: &str,
+    ctx: &Context,
+    options: &ConversionOptions,
+) {
+    let dir = tag
+        .attributes()
+        .get("dir")
+        .flatten()
+        .map(|dir| dir.as_utf8_str().trim().to_ascii_lowercase());
+
+    match options.unrepresentable_policy {
+        UnrepresentablePolicy::Drop => {}
+        UnrepresentablePolicy::Passthrough => {
+            let open = match dir.as_deref() {
+                Some(dir) if matches!(dir, "ltr" | "rtl" | "auto") => format!("<{tag_name} dir=\"{dir}\">"),
+                _ => format!("<{tag_name}>"),
+            };
+            output.insert_str(start, &open);
+            output.push_str("</");
+            output.push_str(tag_name);
+            output.push('>');
+        }
+        UnrepresentablePolicy::Diagnose => {
+            let what = if tag_name == "bdo" {
+                "direction override"
+            } else {
+                "direction isolation"
+            };
+            let message = match dir {
+                Some(dir) => format!("dropped <{tag_name} dir=\"{dir}\"> {what}"),
+                None => format!("dropped <{tag_name}> {what}"),
+            };
+            record_unrepresentable(ctx, Some(tag_name), message);
+        }
+    }
+}
+
+/// Explicit text direction taken from an element's `dir` attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    fn from_tag(tag: &tl::HTMLTag) -> Option<Self> {
+        let dir = tag.attributes().get("dir").flatten()?;
+        let dir = dir.as_utf8_str();
+        let dir = dir.trim();
+        if dir.eq_ignore_ascii_case("rtl") {
+            Some(Self::Rtl)
+        } else if dir.eq_ignore_ascii_case("ltr") {
+            Some(Self::Ltr)
+        } else {
+            None
+        }
+    }
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+
+    /// Implicit directional mark (LRM / RLM).
+    const fn mark(self) -> char {
+        match self {
+            Self::Ltr => '\u{200E}',
+            Self::Rtl => '\u{200F}',
+        }
+    }
+
+    /// Explicit embedding control (LRE / RLE), closed by [`POP_DIRECTIONAL_FORMATTING`].
+    const fn embedding(self) -> char {
+        match self {
+            Self::Ltr => '\u{202A}',
+            Self::Rtl => '\u{202B}',
+        }
+    }
+}
+
+const POP_DIRECTIONAL_FORMATTING: char = '\u{202C}';
+
+/// Carry an element's direction change into the output it produced since `start`.
+fn apply_bidi_direction(output: &mut String, start: usize, direction: Direction, mode: BidiMode, inline: bool) {
+    let content = &output[start..];
+    // Block output keeps its leading indentation, which may belong to an indented code block.
+    let unindented = if inline {
+        content.trim_start()
+    } else {
+        content.trim_start_matches('\n')
+    };
+    let body = unindented.trim_end();
+    if body.trim_start().is_empty() {
+        return;
+    }
+    let leading = &content[..content.len() - unindented.len()];
+    let trailing = &unindented[body.len()..];
+
+    let mut result = String::with_capacity(content.len() + 32);
+    result.push_str(leading);
+    if inline {
+        // List items wrapped as a span keep their marker in front of the wrapper.
+        let (prefix, rest) = body.split_at(markdown_line_prefix(body).0);
+        result.push_str(prefix);
+        match mode {
+            BidiMode::Ignore => return,
+            BidiMode::Marks => {
+                result.push(direction.mark());
+                result.push_str(rest);
+                result.push(direction.mark());
+            }
+            BidiMode::Embedding => {
+                result.push(direction.embedding());
+                result.push_str(rest);
+                result.push(POP_DIRECTIONAL_FORMATTING);
+            }
+            BidiMode::Html => {
+                result.push_str("<span dir=\"");
+                result.push_str(direction.as_str());
+                result.push_str("\">");
+                result.push_str(rest);
+                result.push_str("</span>");
+            }
+        }
+    } else {
+        match mode {
+            BidiMode::Ignore => return,
+            BidiMode::Marks | BidiMode::Embedding => push_bidi_lines(&mut result, body, direction, mode),
+            BidiMode::Html => {
+                result.push_str("<div dir=\"");
+                result.push_str(direction.as_str());
+                result.push_str("\">\n\n");
+                result.push_str(body);
+                result.push_str("\n\n</div>");
+            }
+        }
+    }
+    result.push_str(trailing);
+
+    output.truncate(start);
+    output.push_str(&result);
+}
+
+/// Mark every prose line of a block, leaving code, tables, rules and raw HTML untouched.
+///
+/// Controls are inserted after any Markdown line prefix (indentation, `>`, list markers,
+/// ATX heading markers) so block structure keeps parsing.
+fn push_bidi_lines(result: &mut String, body: &str, direction: Direction, mode: BidiMode) {
+    let mut in_fence = false;
+    for (index, line) in body.split('\n').enumerate() {
+        if index > 0 {
+            result.push('\n');
+        }
+        let (prefix_len, has_marker) = markdown_line_prefix(line);
+        let (prefix, rest) = line.split_at(prefix_len);
+
+        if rest.starts_with("```") || rest.starts_with("~~~") {
+            in_fence = !in_fence;
+            result.push_str(line);
+            continue;
+        }
+        let indented_code = !has_marker && prefix.chars().map(|ch| if ch == '\t' { 4 } else { 1 }).sum::<usize>() >= 4;
+        if in_fence || indented_code || rest.is_empty() || is_bidi_passthrough_line(rest) {
+            result.push_str(line);
+            continue;
+        }
+
+        result.push_str(prefix);
+        if mode == BidiMode::Marks {
+            result.push(direction.mark());
+            result.push_str(rest);
+        } else {
+            let mut text = rest.trim_end();
+            if text.ends_with('\\') && text.len() == rest.len() {
+                text = &text[..text.len() - 1];
+            }
+            result.push(direction.embedding());
+            result.push_str(text);
+            result.push(POP_DIRECTIONAL_FORMATTING);
+            result.push_str(&rest[text.len()..]);
+        }
+    }
+}
+
+/// Length of the Markdown block prefix of `line`, and whether it contained a block marker.
+fn markdown_line_prefix(line: &str) -> (usize, bool) {
+    let bytes = line.as_bytes();
+    let mut idx = 0;
+    let mut has_marker = false;
+    loop {
+        while idx < bytes.len() && matches!(bytes[idx], b' ' | b'\t') {
+            idx += 1;
+        }
+        let rest = &bytes[idx..];
+        let marker_len = match rest.first() {
+            Some(b'>') => 1,
+            Some(b'-' | b'*' | b'+') if rest.get(1) == Some(&b' ') => 1,
+            Some(b'#') => {
+                let hashes = rest.iter().take_while(|&&b| b == b'#').count();
+                if hashes <= 6 && rest.get(hashes) == Some(&b' ') {
+                    hashes
+                } else {
+                    0
+                }
+            }
+            Some(b'0'..=b'9') => {
+                let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+                if matches!(rest.get(digits), Some(b'.' | b')')) && rest.get(digits + 1) == Some(&b' ') {
+                    digits + 1
+                } else {
+                    0
+                }
+            }
+            _ => 0,
+        };
+        if marker_len == 0 {
+            return (idx, has_marker);
+        }
+        has_marker = true;
+        idx += marker_len;
+    }
+}
+
+/// Lines that would break if a control character were put in front of them, or that
+/// already carry one from a nested element with its own direction.
+fn is_bidi_passthrough_line(rest: &str) -> bool {
+    if rest.starts_with(['|', '<', '\u{200E}', '\u{200F}', '\u{202A}', '\u{202B}']) {
+        return true;
+    }
+    if rest.starts_with('[') && rest.contains("]: ") {
+        return true;
+    }
+    let trimmed = rest.trim_end();
+    trimmed.len() >= 3 && trimmed.chars().all(|ch| matches!(ch, '-' | '=' | '*' | '_' | ' '))
+}
+
 #[allow(clippy::trivially_copy_pass_by_ref)]
 fn append_inline_suffix(
     output: &mut String,
@@ -3327,6 +5299,68 @@ fn append_inline_suffix(
     output.push_str(suffix);
 }
 
+/// The `title` of a link, `<abbr>` or `<span>` to turn into a footnote, escaped and on one line.
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn footnote_title(
+    node_handle: &tl::NodeHandle,
+    node: &tl::Node,
+    options: &ConversionOptions,
+    ctx: &Context,
+) -> Option<String> {
+    let tag = node.as_tag()?;
+    if ctx.in_code || ctx.footnoted_node == Some(node_handle.get_inner()) {
+        return None;
+    }
+    let tag_name = normalized_tag_name(tag.name().as_utf8_str());
+    match tag_name.as_ref() {
+        "a" | "span" => {}
+        "abbr" if options.abbr_style != crate::options::AbbrStyle::Html => {}
+        _ => return None,
+    }
+    if ctx.preserve_tags.contains(tag_name.as_ref()) {
+        return None;
+    }
+    let title = tag.attributes().get("title").flatten()?;
+    let title = text::normalize_whitespace(&text::decode_html_entities(title.as_utf8_str().trim()));
+    let title = title.trim();
+    if title.is_empty() {
+        return None;
+    }
+    Some(text::escape(
+        title,
+        options.escape_misc,
+        options.escape_asterisks,
+        options.escape_underscores,
+        options.escape_ascii,
+    ))
+}
+
+/// Elements whose Markdown gets a `MetadataConfig::extract_source_map` entry.
+#[cfg(feature = "metadata")]
+fn is_source_mapped_block(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "p" | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "pre"
+            | "blockquote"
+            | "ul"
+            | "ol"
+            | "menu"
+            | "dl"
+            | "table"
+            | "hr"
+            | "figure"
+            | "details"
+            | "address"
+            | "math"
+    )
+}
+
 /// Recursively walk DOM nodes and convert to Markdown.
 #[allow(clippy::only_used_in_recursion)]
 #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -3341,6 +5375,53 @@ fn walk_node(
     dom_ctx: &DomContext,
 ) {
     let Some(node) = node_handle.get(parser) else { return };
+    if options.title_footnotes {
+        if let Some(title) = footnote_title(node_handle, node, options, ctx) {
+            let footnote_ctx = Context {
+                footnoted_node: Some(node_handle.get_inner()),
+                ..ctx.clone()
+            };
+            let start = output.len();
+            walk_node(node_handle, parser, output, options, &footnote_ctx, depth, dom_ctx);
+            if !output[start..].trim().is_empty() {
+                let number = ctx.footnotes.borrow_mut().reference(title);
+                let end = output.trim_end().len();
+                output.insert_str(end, &format!("[^{number}]"));
+            }
+            return;
+        }
+    }
+    #[cfg(feature = "metadata")]
+    if ctx.metadata_wants_source_map {
+        if let Some(tag) = node
+            .as_tag()
+            .filter(|tag| is_source_mapped_block(&tag.name().as_utf8_str()))
+        {
+            if let Some(ref collector) = ctx.metadata_collector {
+                if collector.borrow_mut().open_source_block() {
+                    let start = output.len();
+                    walk_node(node_handle, parser, output, options, ctx, depth, dom_ctx);
+                    let (html_start, html_end) = tag.boundaries(parser);
+                    let markdown = output.get(start.min(output.len())..).unwrap_or_default();
+                    collector.borrow_mut().close_source_block(
+                        &tag.name().as_utf8_str(),
+                        html_start..html_end + 1,
+                        markdown,
+                    );
+                    return;
+                }
+            }
+        }
+    }
+    if let Some(progress) = &ctx.progress {
+        progress.node(node.as_tag().map(|tag| tag.boundaries(parser).0));
+    }
+    let _element_span = if crate::trace::is_active() {
+        top_level_element_label(node_handle, node, parser, dom_ctx)
+            .and_then(|label| crate::trace::span("element", || Cow::Owned(label)))
+    } else {
+        None
+    };
 
     // Log entry to walk_node for body and immediate children
     if options.debug {
@@ -3371,6 +5452,15 @@ fn walk_node(
                 text = Cow::Owned(text.replace(['\r', '\n'], " "));
             }
 
+            if strips_invisible_formatting(options) && !ctx.in_code {
+                if let Cow::Owned(stripped) = remove_invisible_formatting(text.as_ref(), options, ctx) {
+                    if stripped.is_empty() {
+                        return;
+                    }
+                    text = Cow::Owned(stripped);
+                }
+            }
+
             if text.trim().is_empty() {
                 if ctx.in_code {
                     output.push_str(text.as_ref());
@@ -3426,7 +5516,7 @@ fn walk_node(
                 text.into_owned()
             } else if ctx.in_table_cell {
                 let escaped = if options.whitespace_mode == crate::options::WhitespaceMode::Normalized {
-                    let normalized_text = text::normalize_whitespace_cow(text.as_ref());
+                    let normalized_text = normalize_whitespace_for(text.as_ref(), options.nbsp_in_tables);
                     text::escape(
                         normalized_text.as_ref(),
                         options.escape_misc,
@@ -3449,23 +5539,25 @@ fn walk_node(
                     escaped.replace('|', r"\|")
                 }
             } else if options.whitespace_mode == crate::options::WhitespaceMode::Strict {
-                text::escape(
-                    text.as_ref(),
-                    options.escape_misc,
-                    options.escape_asterisks,
-                    options.escape_underscores,
-                    options.escape_ascii,
-                )
+                escape_prose(text.as_ref(), options)
             } else {
                 let has_double_newline = text.contains("\n\n") || text.contains("\r\n\r\n");
                 let has_trailing_single_newline =
                     text.ends_with('\n') && !text.ends_with("\n\n") && !text.ends_with("\r\n\r\n");
 
-                let normalized_text = text::normalize_whitespace_cow(text.as_ref());
+                let normalized_text = normalize_whitespace_for(text.as_ref(), options.nbsp_in_prose);
 
-                let (prefix, suffix, core) = text::chomp(normalized_text.as_ref());
+                let (prefix, suffix, core) = match options.nbsp_in_prose {
+                    NbspMode::Space => text::chomp(normalized_text.as_ref()),
+                    NbspMode::Preserve => text::chomp_keep_nbsp(normalized_text.as_ref()),
+                };
+
+                let attaches_to_previous = options.remove_space_before_punctuation
+                    && core.starts_with(is_closing_punctuation)
+                    && remove_space_before_punctuation(output);
 
-                let skip_prefix = output.ends_with("\n\n")
+                let skip_prefix = attaches_to_previous
+                    || output.ends_with("\n\n")
                     || output.ends_with("* ")
                     || output.ends_with("- ")
                     || output.ends_with(". ")
@@ -3480,13 +5572,7 @@ fn walk_node(
                     final_text.push_str(prefix);
                 }
 
-                let escaped_core = text::escape(
-                    core,
-                    options.escape_misc,
-                    options.escape_asterisks,
-                    options.escape_underscores,
-                    options.escape_ascii,
-                );
+                let escaped_core = escape_prose(core, options);
                 final_text.push_str(&escaped_core);
 
                 if !suffix.is_empty() {
@@ -3635,7 +5721,23 @@ fn walk_node(
                 }
             }
 
-            if should_drop_for_preprocessing(node_handle, tag_name.as_ref(), tag, parser, dom_ctx, options) {
+            let drop_rule = preprocessing_drop_rule(node_handle, tag_name.as_ref(), tag, parser, dom_ctx, options)
+                .or_else(|| is_hidden_in_print(tag_name.as_ref(), tag, ctx).then_some("print_styles"))
+                .or_else(|| ctx.remove_tags.contains(tag_name.as_ref()).then_some("remove_tags"))
+                .or_else(|| {
+                    ctx.excluded_nodes
+                        .contains(&node_handle.get_inner())
+                        .then_some("exclude_selectors")
+                });
+            if let Some(rule) = drop_rule {
+                if options.report_dropped_content {
+                    record_dropped_content(ctx, rule, node_handle, parser);
+                }
+                trim_trailing_whitespace(output);
+                return;
+            }
+            // KaTeX and MathJax renderings are replaced by their source, so no content is lost here.
+            if options.math_style != MathStyle::Comment && crate::math::is_rendered_math(tag) {
                 trim_trailing_whitespace(output);
                 return;
             }
@@ -3651,11 +5753,18 @@ fn walk_node(
             }
 
             if ctx.preserve_tags.contains(tag_name.as_ref()) {
-                let html = serialize_tag_to_html(node_handle, parser);
+                let html = serialize_tag_to_html(node_handle, parser, &options.preserved_attributes);
                 output.push_str(&html);
                 return;
             }
 
+            if options.fidelity_threshold < 1.0
+                && matches!(tag_name.as_ref(), "table" | "form" | "math")
+                && keep_lossy_subtree(node_handle, tag_name.as_ref(), parser, output, options, ctx)
+            {
+                return;
+            }
+
             #[cfg(feature = "metadata")]
             if matches!(tag_name.as_ref(), "html" | "head" | "body") && ctx.metadata_wants_document {
                 if let Some(ref collector) = ctx.metadata_collector {
@@ -3671,7 +5780,6 @@ fn walk_node(
                 }
             }
 
-            #[cfg_attr(not(feature = "visitor"), allow(unused_variables))]
             let element_output_start = output.len();
 
             match tag_name.as_ref() {
@@ -3679,12 +5787,12 @@ fn walk_node(
                     let level = tag_name.chars().last().and_then(|c| c.to_digit(10)).unwrap_or(1) as usize;
 
                     // Add spacing before heading if needed (similar to paragraph handling)
-                    let needs_leading_sep = !ctx.in_table_cell
-                        && !ctx.in_list_item
-                        && !ctx.convert_as_inline
-                        && ctx.blockquote_depth == 0
-                        && !output.is_empty()
-                        && !output.ends_with("\n\n");
+                    let top_level =
+                        !ctx.in_table_cell && !ctx.in_list_item && !ctx.convert_as_inline && ctx.blockquote_depth == 0;
+                    if top_level {
+                        ctx.footnotes.borrow_mut().flush(output);
+                    }
+                    let needs_leading_sep = top_level && !output.is_empty() && !output.ends_with("\n\n");
 
                     if needs_leading_sep {
                         trim_trailing_whitespace(output);
@@ -3715,9 +5823,19 @@ fn walk_node(
                             );
                         }
                     }
+                    if options.header_break_style != HeaderBreakStyle::Inherit {
+                        text = join_header_lines(&text, options);
+                    }
                     let trimmed = text.trim();
                     if !trimmed.is_empty() {
                         let normalized = normalize_heading_text(trimmed);
+                        let heading_attributes = element_pandoc_attributes(tag, options, &[]);
+                        let anchor = heading_id_anchor(tag, options);
+                        let heading_text = if anchor.is_empty() {
+                            normalized.clone()
+                        } else {
+                            Cow::Owned(format!("{anchor}{normalized}"))
+                        };
 
                         #[cfg(feature = "visitor")]
                         let heading_output = if let Some(ref visitor_handle) = ctx.visitor {
@@ -3754,7 +5872,14 @@ fn walk_node(
                             match visitor.visit_heading(&node_ctx, level as u32, &normalized, id_attr.as_deref()) {
                                 VisitResult::Continue => {
                                     let mut buf = String::new();
-                                    push_heading(&mut buf, ctx, options, level, normalized.as_ref());
+                                    push_heading(
+                                        &mut buf,
+                                        ctx,
+                                        options,
+                                        level,
+                                        heading_text.as_ref(),
+                                        &heading_attributes,
+                                    );
                                     Some(buf)
                                 }
                                 VisitResult::Custom(custom) => Some(custom),
@@ -3767,20 +5892,41 @@ fn walk_node(
                                 }
                                 VisitResult::PreserveHtml => {
                                     let mut buf = String::new();
-                                    push_heading(&mut buf, ctx, options, level, normalized.as_ref());
+                                    push_heading(
+                                        &mut buf,
+                                        ctx,
+                                        options,
+                                        level,
+                                        heading_text.as_ref(),
+                                        &heading_attributes,
+                                    );
                                     Some(buf)
                                 }
                             }
                         } else {
                             let mut buf = String::new();
-                            push_heading(&mut buf, ctx, options, level, normalized.as_ref());
+                            push_heading(
+                                &mut buf,
+                                ctx,
+                                options,
+                                level,
+                                heading_text.as_ref(),
+                                &heading_attributes,
+                            );
                             Some(buf)
                         };
 
                         #[cfg(not(feature = "visitor"))]
                         let heading_output = {
                             let mut buf = String::new();
-                            push_heading(&mut buf, ctx, options, level, normalized.as_ref());
+                            push_heading(
+                                &mut buf,
+                                ctx,
+                                options,
+                                level,
+                                heading_text.as_ref(),
+                                &heading_attributes,
+                            );
                             Some(buf)
                         };
 
@@ -3810,11 +5956,7 @@ fn walk_node(
                     let is_table_continuation =
                         ctx.in_table_cell && !output.is_empty() && !output.ends_with('|') && !output.ends_with("<br>");
 
-                    let is_list_continuation = ctx.in_list_item
-                        && !output.is_empty()
-                        && !output.ends_with("* ")
-                        && !output.ends_with("- ")
-                        && !output.ends_with(". ");
+                    let is_list_continuation = ctx.in_list_item && !output.is_empty() && !ends_with_list_marker(output);
 
                     let after_code_block = output.ends_with("```\n");
                     let needs_leading_sep = !ctx.in_table_cell
@@ -3832,7 +5974,8 @@ fn walk_node(
                         add_list_continuation_indent(output, ctx.list_depth, true, options);
                     } else if needs_leading_sep {
                         trim_trailing_whitespace(output);
-                        output.push_str("\n\n");
+                        // Blocks such as lists and tables end with a single newline already.
+                        output.push_str(if output.ends_with('\n') { "\n" } else { "\n\n" });
                     }
 
                     let p_ctx = Context {
@@ -4112,18 +6255,38 @@ fn walk_node(
                         .get("href")
                         .flatten()
                         .map(|v| text::decode_html_entities(&v.as_utf8_str()));
+                    let footnoted = ctx.footnoted_node == Some(node_handle.get_inner());
                     let title = tag
                         .attributes()
                         .get("title")
                         .flatten()
+                        .filter(|_| !footnoted)
                         .map(|v| v.as_utf8_str().to_string());
 
                     if let Some(href) = href_attr {
                         let raw_text = text::normalize_whitespace(&get_text_content(node_handle, parser, dom_ctx))
                             .trim()
                             .to_string();
+                        let title = title.or_else(|| {
+                            link_fallback_title(node_handle, parser, dom_ctx, &href, options).filter(|_| !footnoted)
+                        });
+
+                        let contact = contact_address(&href)
+                            .filter(|_| options.contact_link_style != crate::options::ContactLinkStyle::Link);
+
+                        if let Some((scheme, address)) = contact {
+                            if options.contact_link_style == crate::options::ContactLinkStyle::Autolink
+                                && contact_text_matches(scheme, address, &raw_text)
+                            {
+                                if let Some(autolink) = contact_autolink(scheme, address) {
+                                    output.push_str(&autolink);
+                                    return;
+                                }
+                            }
+                        }
 
-                        let is_autolink = options.autolinks
+                        let is_autolink = contact.is_none()
+                            && options.autolinks
                             && !options.default_title
                             && !href.is_empty()
                             && (raw_text == href || (href.starts_with("mailto:") && raw_text == href[7..]));
@@ -4175,7 +6338,7 @@ fn walk_node(
                                             raw_text.as_str(),
                                             options,
                                         );
-                                        push_heading(output, ctx, options, heading_level, link_buffer.as_str());
+                                        push_heading(output, ctx, options, heading_level, link_buffer.as_str(), "");
                                         return;
                                     }
                                 }
@@ -4246,6 +6409,12 @@ fn walk_node(
                             label = normalize_link_label(&raw_text);
                         }
 
+                        if label.is_empty() && !options.title_fallback.is_empty() {
+                            if let Some(title) = &title {
+                                label = normalize_link_label(title);
+                            }
+                        }
+
                         if label.is_empty() && !href.is_empty() && !children.is_empty() {
                             label = href.clone();
                         }
@@ -4255,6 +6424,23 @@ fn walk_node(
                             label.push('…');
                         }
 
+                        if let Some((scheme, address)) = contact {
+                            if options.contact_link_style == crate::options::ContactLinkStyle::Text {
+                                if label.is_empty() {
+                                    output.push_str(address);
+                                } else if contact_text_matches(scheme, address, &raw_text) {
+                                    output.push_str(&label);
+                                } else {
+                                    let typography = dom_ctx.typography(node_handle.get_inner(), parser, options);
+                                    output.push_str(&label);
+                                    output.push_str(typography.paren_open);
+                                    output.push_str(address);
+                                    output.push_str(typography.paren_close);
+                                }
+                                return;
+                            }
+                        }
+
                         let escaped_label = escape_link_label(&label);
 
                         #[cfg(feature = "visitor")]
@@ -4294,6 +6480,7 @@ fn walk_node(
                                         label.as_str(),
                                         options,
                                     );
+                                    buf.push_str(&element_pandoc_attributes(tag, options, &[]));
                                     Some(buf)
                                 }
                                 VisitResult::Custom(custom) => Some(custom),
@@ -4316,6 +6503,7 @@ fn walk_node(
                                 label.as_str(),
                                 options,
                             );
+                            buf.push_str(&element_pandoc_attributes(tag, options, &[]));
                             Some(buf)
                         };
 
@@ -4330,6 +6518,7 @@ fn walk_node(
                                 label.as_str(),
                                 options,
                             );
+                            buf.push_str(&element_pandoc_attributes(tag, options, &[]));
                             Some(buf)
                         };
 
@@ -4337,6 +6526,18 @@ fn walk_node(
                             output.push_str(&link_text);
                         }
 
+                        #[cfg(feature = "metadata")]
+                        if ctx.metadata_wants_document {
+                            if let (Some(collector), Some(rel)) =
+                                (&ctx.metadata_collector, tag.attributes().get("rel").flatten())
+                            {
+                                let name = (!label.trim().is_empty()).then(|| label.trim().to_string());
+                                collector
+                                    .borrow_mut()
+                                    .add_rel_profile(&rel.as_utf8_str(), href.as_str(), name);
+                            }
+                        }
+
                         #[cfg(feature = "metadata")]
                         if ctx.metadata_wants_links {
                             if let Some(ref collector) = ctx.metadata_collector {
@@ -4377,11 +6578,18 @@ fn walk_node(
                 "img" => {
                     use std::borrow::Cow;
 
-                    let src = tag
+                    let mut src = tag
                         .attributes()
                         .get("src")
                         .flatten()
                         .map_or(Cow::Borrowed(""), |v| v.as_utf8_str());
+                    if src.trim().is_empty() {
+                        if let Some(fallback) =
+                            srcset_fallback(node_handle, tag, parser, options.srcset_policy, dom_ctx)
+                        {
+                            src = Cow::Owned(fallback);
+                        }
+                    }
 
                     let alt = tag
                         .attributes()
@@ -4419,7 +6627,8 @@ fn walk_node(
 
                     #[cfg(feature = "inline-images")]
                     if let Some(ref collector_ref) = ctx.inline_collector {
-                        if src.trim_start().starts_with("data:") {
+                        let is_data_uri = src.trim_start().starts_with("data:");
+                        if is_data_uri || is_remote_image_src(src.as_ref()) {
                             let mut attributes_map = BTreeMap::new();
                             for (key, value_opt) in tag.attributes().iter() {
                                 let key_str = key.to_string();
@@ -4433,13 +6642,23 @@ fn walk_node(
                                     attributes_map.insert(key_str, value);
                                 }
                             }
-                            handle_inline_data_image(
-                                collector_ref,
-                                src.as_ref(),
-                                alt.as_ref(),
-                                title.as_deref(),
-                                attributes_map,
-                            );
+                            if is_data_uri {
+                                handle_inline_data_image(
+                                    collector_ref,
+                                    src.as_ref(),
+                                    alt.as_ref(),
+                                    title.as_deref(),
+                                    attributes_map,
+                                );
+                            } else {
+                                handle_remote_image(
+                                    collector_ref,
+                                    src.as_ref(),
+                                    alt.as_ref(),
+                                    title.as_deref(),
+                                    attributes_map,
+                                );
+                            }
                         }
                     }
 
@@ -4490,6 +6709,7 @@ fn walk_node(
                                         buf.push('"');
                                     }
                                     buf.push(')');
+                                    buf.push_str(&element_pandoc_attributes(tag, options, &["width", "height"]));
                                 }
                                 Some(buf)
                             }
@@ -4518,6 +6738,7 @@ fn walk_node(
                                 buf.push('"');
                             }
                             buf.push(')');
+                            buf.push_str(&element_pandoc_attributes(tag, options, &["width", "height"]));
                         }
                         Some(buf)
                     };
@@ -4538,6 +6759,7 @@ fn walk_node(
                                 buf.push('"');
                             }
                             buf.push(')');
+                            buf.push_str(&element_pandoc_attributes(tag, options, &["width", "height"]));
                         }
                         Some(buf)
                     };
@@ -5141,6 +7363,18 @@ fn walk_node(
                     }
                 }
 
+                "dfn" | "abbr" | "time"
+                    if (tag_name == "dfn" && options.dfn_style == crate::options::DfnStyle::Html)
+                        || (tag_name == "abbr" && options.abbr_style == crate::options::AbbrStyle::Html)
+                        || (tag_name == "time" && options.time_style == crate::options::TimeStyle::Html) =>
+                {
+                    output.push_str(&serialize_tag_to_html(
+                        node_handle,
+                        parser,
+                        &options.preserved_attributes,
+                    ));
+                }
+
                 "dfn" => {
                     let mut content = String::with_capacity(32);
                     let children = tag.children();
@@ -5149,6 +7383,10 @@ fn walk_node(
                             walk_node(child_handle, parser, &mut content, options, ctx, depth + 1, dom_ctx);
                         }
                     }
+                    if options.dfn_style == crate::options::DfnStyle::Text {
+                        output.push_str(&content);
+                        return;
+                    }
                     let (prefix, suffix, trimmed) = chomp_inline(&content);
                     if !trimmed.is_empty() {
                         output.push_str(prefix);
@@ -5172,17 +7410,46 @@ fn walk_node(
                     if !trimmed.is_empty() {
                         output.push_str(trimmed);
 
+                        if options.abbr_style == crate::options::AbbrStyle::Text
+                            || ctx.footnoted_node == Some(node_handle.get_inner())
+                        {
+                            return;
+                        }
                         if let Some(title) = tag.attributes().get("title").flatten().map(|v| v.as_utf8_str()) {
                             let trimmed_title = title.trim();
                             if !trimmed_title.is_empty() {
-                                output.push_str(" (");
+                                let typography = dom_ctx.typography(node_handle.get_inner(), parser, options);
+                                output.push_str(typography.paren_open);
                                 output.push_str(trimmed_title);
-                                output.push(')');
+                                output.push_str(typography.paren_close);
                             }
                         }
                     }
                 }
 
+                "time" if options.time_style == crate::options::TimeStyle::Datetime => {
+                    let mut content = String::with_capacity(32);
+                    for child_handle in tag.children().top().iter() {
+                        walk_node(child_handle, parser, &mut content, options, ctx, depth + 1, dom_ctx);
+                    }
+                    let datetime = tag.attributes().get("datetime").flatten().map(|v| v.as_utf8_str());
+                    let datetime = datetime.as_deref().map(str::trim).filter(|value| !value.is_empty());
+                    let (prefix, suffix, trimmed) = chomp_inline(&content);
+                    match datetime {
+                        Some(datetime) if trimmed.is_empty() => output.push_str(datetime),
+                        Some(datetime) if trimmed != datetime => {
+                            let typography = dom_ctx.typography(node_handle.get_inner(), parser, options);
+                            output.push_str(prefix);
+                            output.push_str(trimmed);
+                            output.push_str(typography.paren_open);
+                            output.push_str(datetime);
+                            output.push_str(typography.paren_close);
+                            output.push_str(suffix);
+                        }
+                        _ => output.push_str(&content),
+                    }
+                }
+
                 "time" | "data" => {
                     let children = tag.children();
                     {
@@ -5192,7 +7459,13 @@ fn walk_node(
                     }
                 }
 
-                "wbr" => {}
+                "wbr" => match options.unrepresentable_policy {
+                    UnrepresentablePolicy::Drop => {}
+                    UnrepresentablePolicy::Passthrough => output.push_str("<wbr>"),
+                    UnrepresentablePolicy::Diagnose => {
+                        record_unrepresentable(ctx, Some("wbr"), "dropped <wbr> line break opportunity".to_string());
+                    }
+                },
 
                 "code" => {
                     let code_ctx = Context {
@@ -5384,7 +7657,6 @@ fn walk_node(
                         ..ctx.clone()
                     };
 
-                    #[cfg_attr(not(feature = "visitor"), allow(unused_variables))]
                     let language: Option<String> = {
                         let mut lang: Option<String> = None;
 
@@ -5450,36 +7722,16 @@ fn walk_node(
                     }
 
                     if !content.is_empty() {
-                        let leading_newlines = content.chars().take_while(|&c| c == '\n').count();
-                        let trailing_newlines = content.chars().rev().take_while(|&c| c == '\n').count();
-                        let core = content.trim_matches('\n');
-                        let is_whitespace_only = core.trim().is_empty();
-
                         let processed_content = if options.whitespace_mode == crate::options::WhitespaceMode::Strict {
                             content
                         } else {
-                            let mut core_text = if leading_newlines > 0 {
-                                dedent_code_block(core)
-                            } else {
-                                core.to_string()
-                            };
+                            code_block_content(&content, options.code_block_trim)
+                        };
 
-                            if is_whitespace_only {
-                                let mut rebuilt = String::new();
-                                for _ in 0..leading_newlines {
-                                    rebuilt.push('\n');
-                                }
-                                rebuilt.push_str(&core_text);
-                                for _ in 0..trailing_newlines {
-                                    rebuilt.push('\n');
-                                }
-                                rebuilt
-                            } else {
-                                for _ in 0..trailing_newlines {
-                                    core_text.push('\n');
-                                }
-                                core_text
-                            }
+                        let language = if options.infer_code_language {
+                            language.or_else(|| infer_code_block_language(tag, parser, &processed_content))
+                        } else {
+                            language
                         };
 
                         #[cfg(feature = "visitor")]
@@ -5570,7 +7822,14 @@ fn walk_node(
                                     };
 
                                     output.push_str(fence);
-                                    if !options.code_language.is_empty() {
+                                    if options.pandoc_attributes {
+                                        let language = language.as_deref().unwrap_or(&options.code_language);
+                                        output.push_str(&code_block_pandoc_info(tag, parser, language));
+                                    } else if let Some(language) =
+                                        language.as_deref().filter(|_| options.infer_code_language)
+                                    {
+                                        output.push_str(language);
+                                    } else if !options.code_language.is_empty() {
                                         output.push_str(&options.code_language);
                                     }
                                     output.push('\n');
@@ -5626,7 +7885,14 @@ fn walk_node(
                                     };
 
                                     output.push_str(fence);
-                                    if !options.code_language.is_empty() {
+                                    if options.pandoc_attributes {
+                                        let language = language.as_deref().unwrap_or(&options.code_language);
+                                        output.push_str(&code_block_pandoc_info(tag, parser, language));
+                                    } else if let Some(language) =
+                                        language.as_deref().filter(|_| options.infer_code_language)
+                                    {
+                                        output.push_str(language);
+                                    } else if !options.code_language.is_empty() {
                                         output.push_str(&options.code_language);
                                     }
                                     output.push('\n');
@@ -5640,6 +7906,132 @@ fn walk_node(
                     }
                 }
 
+                "ac:structured-macro" if is_confluence_preset(options) => {
+                    let macro_name = tag
+                        .attributes()
+                        .get("ac:name")
+                        .flatten()
+                        .map(|name| name.as_utf8_str().to_ascii_lowercase())
+                        .unwrap_or_default();
+
+                    let mut title = None;
+                    let mut language = String::new();
+                    let mut body = None;
+                    for child_handle in tag.children().top().iter() {
+                        let Some(tl::Node::Tag(child_tag)) = child_handle.get(parser) else {
+                            continue;
+                        };
+                        match normalized_tag_name(child_tag.name().as_utf8_str()).as_ref() {
+                            "ac:parameter" => {
+                                let value = text::decode_html_entities(child_tag.inner_text(parser).trim());
+                                match child_tag.attributes().get("ac:name").flatten() {
+                                    Some(name) if name.as_utf8_str() == "title" && !value.is_empty() => {
+                                        title = Some(value);
+                                    }
+                                    Some(name) if name.as_utf8_str() == "language" => language = value,
+                                    _ => {}
+                                }
+                            }
+                            "ac:rich-text-body" | "ac:plain-text-body" => body = Some((*child_handle, child_tag)),
+                            _ => {}
+                        }
+                    }
+                    let Some((body_handle, body_tag)) = body else {
+                        return;
+                    };
+
+                    if matches!(macro_name.as_str(), "code" | "noformat") {
+                        let code = text::decode_html_entities(&body_tag.inner_text(parser));
+                        let code = code.trim_matches('\n');
+                        if ctx.convert_as_inline {
+                            output.push_str(code);
+                        } else if !code.trim().is_empty() {
+                            push_code_block(output, ctx, options, &language, code);
+                        }
+                        return;
+                    }
+
+                    let panel = confluence_panel_label(&macro_name).filter(|_| !ctx.convert_as_inline);
+                    let body_ctx = Context {
+                        blockquote_depth: ctx.blockquote_depth + usize::from(panel.is_some()),
+                        ..ctx.clone()
+                    };
+                    let mut content = String::with_capacity(256);
+                    let body_output = if panel.is_some() { &mut content } else { &mut *output };
+                    if let Some(tl::Node::Tag(body)) = body_handle.get(parser) {
+                        for child_handle in body.children().top().iter() {
+                            walk_node(
+                                child_handle,
+                                parser,
+                                body_output,
+                                options,
+                                &body_ctx,
+                                depth + 2,
+                                dom_ctx,
+                            );
+                        }
+                    }
+
+                    if let Some((emoji, default_title)) = panel {
+                        let title = title.as_deref().unwrap_or(default_title);
+                        let strong = options.strong_em_symbol.to_string().repeat(2);
+                        let mut quoted = format!("{emoji} {strong}{title}{strong}");
+                        let content = content.trim();
+                        if !content.is_empty() {
+                            quoted.push_str("\n\n");
+                            quoted.push_str(content);
+                        }
+                        push_blockquote(output, ctx, &quoted, None);
+                    }
+                }
+
+                "ac:image" if is_confluence_preset(options) => {
+                    let attribute = |tag: &tl::HTMLTag, name: &str| {
+                        tag.attributes()
+                            .get(name)
+                            .flatten()
+                            .map(|value| text::decode_html_entities(&value.as_utf8_str()))
+                    };
+
+                    let src = tag.children().top().iter().find_map(|child_handle| {
+                        let Some(tl::Node::Tag(child_tag)) = child_handle.get(parser) else {
+                            return None;
+                        };
+                        match normalized_tag_name(child_tag.name().as_utf8_str()).as_ref() {
+                            "ri:url" => attribute(child_tag, "ri:value"),
+                            "ri:attachment" => attribute(child_tag, "ri:filename"),
+                            _ => None,
+                        }
+                    });
+                    let Some(src) = src.filter(|src| !src.is_empty()) else {
+                        return;
+                    };
+
+                    let alt = attribute(tag, "ac:alt").unwrap_or_default();
+                    if ctx.convert_as_inline {
+                        output.push_str(&alt);
+                        return;
+                    }
+                    output.push_str("![");
+                    output.push_str(&escape_link_label(&alt));
+                    output.push_str("](");
+                    if src.contains(char::is_whitespace) {
+                        output.push('<');
+                        output.push_str(&src);
+                        output.push('>');
+                    } else {
+                        output.push_str(&src);
+                    }
+                    if let Some(title) = attribute(tag, "ac:title") {
+                        output.push_str(" \"");
+                        output.push_str(&title);
+                        output.push('"');
+                    }
+                    output.push(')');
+                }
+
+                "ac:parameter" | "ac:placeholder" if is_confluence_preset(options) => {}
+
                 "blockquote" => {
                     if ctx.convert_as_inline {
                         let children = tag.children();
@@ -5714,7 +8106,12 @@ fn walk_node(
                             VisitResult::Skip => return,
                             VisitResult::PreserveHtml => {
                                 let mut html_output = String::new();
-                                serialize_node_to_html(node_handle, parser, &mut html_output);
+                                serialize_node_to_html(
+                                    node_handle,
+                                    parser,
+                                    &mut html_output,
+                                    &options.preserved_attributes,
+                                );
                                 output.push_str(&html_output);
                                 return;
                             }
@@ -5726,59 +8123,28 @@ fn walk_node(
                             }
                         }
                     }
-                    if !trimmed_content.is_empty() {
-                        if ctx.blockquote_depth > 0 {
-                            output.push_str("\n\n\n");
-                        } else if !output.is_empty() {
-                            if output.ends_with("\n\n") {
-                                // Paragraph already added \n\n; blockquote needs just \n
-                                output.truncate(output.len() - 1);
-                            } else if !output.ends_with('\n') {
-                                output.push_str("\n\n");
-                            } else if !output.ends_with("\n\n") {
-                                output.push('\n');
-                            }
-                        }
-
-                        let prefix = "> ";
-
-                        for line in trimmed_content.lines() {
-                            output.push_str(prefix);
-                            output.push_str(line.trim());
-                            output.push('\n');
-                        }
-
-                        if let Some(url) = cite {
-                            output.push('\n');
-                            output.push_str("— <");
-                            output.push_str(&url);
-                            output.push_str(">\n\n");
-                        }
-
-                        // Add trailing newlines only when appropriate for proper spacing
-                        // (matching paragraph conditional logic for CommonMark compliance)
-                        if !ctx.convert_as_inline && !ctx.in_table_cell && !ctx.in_list_item {
-                            while output.ends_with('\n') {
-                                output.truncate(output.len() - 1);
-                            }
-                            output.push_str("\n\n");
-                        }
-                    }
+                    let attribution = cite.as_deref().map(|url| {
+                        let prefix = dom_ctx.typography(node_handle.get_inner(), parser, options).attribution;
+                        (prefix, url)
+                    });
+                    push_blockquote(output, ctx, trimmed_content, attribution);
                 }
 
                 "br" => {
                     if ctx.in_heading {
                         trim_trailing_whitespace(output);
-                        output.push_str("  ");
-                    } else {
-                        use crate::options::NewlineStyle;
-                        if output.is_empty() || output.ends_with('\n') {
-                            output.push('\n');
+                        if options.header_break_style == HeaderBreakStyle::Inherit {
+                            output.push_str("  ");
                         } else {
-                            match options.newline_style {
-                                NewlineStyle::Spaces => output.push_str("  \n"),
-                                NewlineStyle::Backslash => output.push_str("\\\n"),
-                            }
+                            // Joined with the chosen separator once the heading text is complete.
+                            output.push('\n');
+                        }
+                    } else if output.is_empty() || output.ends_with('\n') {
+                        output.push('\n');
+                    } else {
+                        match options.newline_style {
+                            NewlineStyle::Spaces => output.push_str("  \n"),
+                            NewlineStyle::Backslash => output.push_str("\\\n"),
                         }
                     }
                 }
@@ -5815,10 +8181,10 @@ fn walk_node(
                 }
 
                 "ul" => {
-                    add_list_leading_separator(output, ctx);
+                    add_list_leading_separator(output, options, ctx);
 
                     let nested_depth = calculate_list_nesting_depth(ctx);
-                    let is_loose = is_loose_list(*node_handle, parser, dom_ctx);
+                    let is_loose = list_is_loose(*node_handle, parser, options, dom_ctx);
 
                     #[cfg(feature = "visitor")]
                     let list_output_start = output.len();
@@ -5864,7 +8230,7 @@ fn walk_node(
                                 return;
                             }
                             VisitResult::PreserveHtml => {
-                                serialize_node_to_html(node_handle, parser, output);
+                                serialize_node_to_html(node_handle, parser, output, &options.preserved_attributes);
                                 return;
                             }
                             VisitResult::Error(err) => {
@@ -5943,7 +8309,7 @@ fn walk_node(
                             }
                             VisitResult::PreserveHtml => {
                                 output.truncate(list_output_start);
-                                serialize_node_to_html(node_handle, parser, output);
+                                serialize_node_to_html(node_handle, parser, output, &options.preserved_attributes);
                             }
                             VisitResult::Error(err) => {
                                 if ctx.visitor_error.borrow().is_none() {
@@ -5956,17 +8322,17 @@ fn walk_node(
                 }
 
                 "ol" => {
-                    add_list_leading_separator(output, ctx);
+                    add_list_leading_separator(output, options, ctx);
 
                     let nested_depth = calculate_list_nesting_depth(ctx);
-                    let is_loose = is_loose_list(*node_handle, parser, dom_ctx);
+                    let is_loose = list_is_loose(*node_handle, parser, options, dom_ctx);
 
                     let start = tag
                         .attributes()
                         .get("start")
                         .flatten()
                         .and_then(|v| v.as_utf8_str().parse::<usize>().ok())
-                        .unwrap_or(1);
+                        .map_or(1, |start| clamp_list_start(start, *node_handle, parser, dom_ctx));
 
                     #[cfg(feature = "visitor")]
                     let list_output_start = output.len();
@@ -6012,7 +8378,7 @@ fn walk_node(
                                 return;
                             }
                             VisitResult::PreserveHtml => {
-                                serialize_node_to_html(node_handle, parser, output);
+                                serialize_node_to_html(node_handle, parser, output, &options.preserved_attributes);
                                 return;
                             }
                             VisitResult::Error(err) => {
@@ -6091,7 +8457,7 @@ fn walk_node(
                             }
                             VisitResult::PreserveHtml => {
                                 output.truncate(list_output_start);
-                                serialize_node_to_html(node_handle, parser, output);
+                                serialize_node_to_html(node_handle, parser, output, &options.preserved_attributes);
                             }
                             VisitResult::Error(err) => {
                                 if ctx.visitor_error.borrow().is_none() {
@@ -6273,12 +8639,13 @@ fn walk_node(
                     } else {
                         if !ctx.in_table_cell {
                             if ctx.in_ordered_list {
-                                output.push_str(&format!("{}. ", ctx.list_counter));
+                                output.push_str(&format!(
+                                    "{}{} ",
+                                    ctx.list_counter,
+                                    ordered_list_delimiter(options, ctx.ol_depth)
+                                ));
                             } else {
-                                let bullets: Vec<char> = options.bullets.chars().collect();
-                                let bullet_index = if ctx.ul_depth > 0 { ctx.ul_depth - 1 } else { 0 };
-                                let bullet = bullets.get(bullet_index % bullets.len()).copied().unwrap_or('*');
-                                output.push(bullet);
+                                output.push(unordered_list_bullet(options, ctx.ul_depth));
                                 output.push(' ');
                             }
                         }
@@ -6328,13 +8695,12 @@ fn walk_node(
                                 let text_start = last_line.find(task_marker).map_or(0, |pos| pos + task_marker.len());
                                 (task_marker.to_string(), last_line[text_start..].trim().to_string())
                             } else if ctx.in_ordered_list {
-                                let marker_text = format!("{}.", ctx.list_counter);
+                                let marker_text =
+                                    format!("{}{}", ctx.list_counter, ordered_list_delimiter(options, ctx.ol_depth));
                                 let text_start = last_line.find(&marker_text).map_or(0, |pos| pos + marker_text.len());
                                 (marker_text, last_line[text_start..].trim().to_string())
                             } else {
-                                let bullets: Vec<char> = options.bullets.chars().collect();
-                                let bullet_index = if ctx.ul_depth > 0 { ctx.ul_depth - 1 } else { 0 };
-                                let bullet = bullets.get(bullet_index % bullets.len()).copied().unwrap_or('*');
+                                let bullet = unordered_list_bullet(options, ctx.ul_depth);
                                 let bullet_str = bullet.to_string();
                                 let text_start = last_line.find(bullet).map_or(0, |pos| pos + 1);
                                 (bullet_str, last_line[text_start..].trim().to_string())
@@ -6357,7 +8723,7 @@ fn walk_node(
                                 }
                                 VisitResult::PreserveHtml => {
                                     output.truncate(last_line_start);
-                                    serialize_node_to_html(node_handle, parser, output);
+                                    serialize_node_to_html(node_handle, parser, output, &options.preserved_attributes);
                                     if !ctx.in_table_cell && !output.ends_with('\n') {
                                         output.push('\n');
                                     }
@@ -6374,7 +8740,12 @@ fn walk_node(
                     }
 
                     if !ctx.in_table_cell {
-                        if has_block_children || ctx.loose_list || ctx.prev_item_had_blocks {
+                        if options.list_spacing == ListSpacing::Tight {
+                            while output.ends_with('\n') {
+                                output.pop();
+                            }
+                            output.push('\n');
+                        } else if has_block_children || ctx.loose_list || ctx.prev_item_had_blocks {
                             if !output.ends_with("\n\n") {
                                 if output.ends_with('\n') {
                                     output.push('\n');
@@ -6489,6 +8860,13 @@ fn walk_node(
                         output.push_str("\n\n");
                     }
 
+                    if options.figure_style == crate::options::FigureStyle::Html {
+                        output
+                            .push_str(serialize_tag_to_html(node_handle, parser, &options.preserved_attributes).trim());
+                        output.push_str("\n\n");
+                        return;
+                    }
+
                     let mut figure_content = String::new();
                     let children = tag.children();
                     {
@@ -6534,9 +8912,21 @@ fn walk_node(
                                 }
                             }
                         }
-                        output.push('*');
-                        output.push_str(text);
-                        output.push_str("*\n\n");
+                        if options.figure_style == crate::options::FigureStyle::Blockquote {
+                            for line in text.lines() {
+                                output.push('>');
+                                if !line.trim().is_empty() {
+                                    output.push(' ');
+                                    output.push_str(line);
+                                }
+                                output.push('\n');
+                            }
+                            output.push('\n');
+                        } else {
+                            output.push('*');
+                            output.push_str(text);
+                            output.push_str("*\n\n");
+                        }
                     }
                 }
 
@@ -6582,10 +8972,21 @@ fn walk_node(
                         if ctx.convert_as_inline {
                             output.push_str(trimmed);
                         } else {
-                            output.push('"');
-                            let escaped = trimmed.replace('\\', r"\\").replace('"', r#"\""#);
-                            output.push_str(&escaped);
-                            output.push('"');
+                            let node_id = node_handle.get_inner();
+                            let typography = dom_ctx.typography(node_id, parser, options);
+                            let (open, close) = if dom_ctx.has_ancestor_tag(node_id, parser, "q") {
+                                typography.inner_quotes
+                            } else {
+                                typography.quotes
+                            };
+                            output.push_str(open);
+                            if open == "\"" {
+                                let escaped = trimmed.replace('\\', r"\\").replace('"', r#"\""#);
+                                output.push_str(&escaped);
+                            } else {
+                                output.push_str(trimmed);
+                            }
+                            output.push_str(close);
                         }
                     }
                 }
@@ -6697,6 +9098,11 @@ fn walk_node(
                         return;
                     }
 
+                    if options.details_style == crate::options::DetailsStyle::Html {
+                        write_details_html(tag, parser, output, options, ctx, depth, dom_ctx);
+                        return;
+                    }
+
                     let mut content = String::with_capacity(256);
                     let children = tag.children();
                     {
@@ -6988,8 +9394,20 @@ fn walk_node(
                         }
                     }
 
+                    let svg_text = if options.svg_text_mode == SvgTextMode::Off {
+                        String::new()
+                    } else {
+                        extract_svg_text(node_handle, parser, dom_ctx)
+                    };
+                    let replace_with_text = options.svg_text_mode == SvgTextMode::Replace && !svg_text.is_empty();
+                    if options.svg_text_mode == SvgTextMode::Alt && !svg_text.is_empty() {
+                        title.clone_from(&svg_text);
+                    }
+
                     #[cfg(feature = "inline-images")]
-                    if let Some(ref collector_ref) = ctx.inline_collector {
+                    if let Some(ref collector_ref) = ctx.inline_collector
+                        && !replace_with_text
+                    {
                         let title_opt = if title == "SVG Image" {
                             None
                         } else {
@@ -7011,7 +9429,15 @@ fn walk_node(
                         handle_inline_svg(collector_ref, node_handle, parser, title_opt, attributes_map);
                     }
 
-                    if ctx.convert_as_inline {
+                    if replace_with_text {
+                        output.push_str(&text::escape(
+                            &svg_text,
+                            options.escape_misc,
+                            options.escape_asterisks,
+                            options.escape_underscores,
+                            options.escape_ascii,
+                        ));
+                    } else if ctx.convert_as_inline {
                         output.push_str(&title);
                     } else {
                         use base64::{Engine as _, engine::general_purpose::STANDARD};
@@ -7025,9 +9451,26 @@ fn walk_node(
                         output.push_str("](data:image/svg+xml;base64,");
                         output.push_str(&base64_svg);
                         output.push(')');
+
+                        if options.svg_text_mode == SvgTextMode::Caption && !svg_text.is_empty() {
+                            output.push_str("\n\n*");
+                            output.push_str(&text::escape(
+                                &svg_text,
+                                options.escape_misc,
+                                true,
+                                options.escape_underscores,
+                                options.escape_ascii,
+                            ));
+                            output.push_str("*\n\n");
+                        }
                     }
                 }
 
+                "math" if options.math_style != MathStyle::Comment => {
+                    let tex = crate::math::mathml_to_tex(tag, parser);
+                    push_math(output, &tex, crate::math::is_display(tag), options, ctx);
+                }
+
                 "math" => {
                     let text_content = get_text_content(node_handle, parser, dom_ctx).trim().to_string();
 
@@ -7568,11 +10011,7 @@ fn walk_node(
                     let is_table_continuation =
                         ctx.in_table_cell && !output.is_empty() && !output.ends_with('|') && !output.ends_with("<br>");
 
-                    let is_list_continuation = ctx.in_list_item
-                        && !output.is_empty()
-                        && !output.ends_with("* ")
-                        && !output.ends_with("- ")
-                        && !output.ends_with(". ");
+                    let is_list_continuation = ctx.in_list_item && !output.is_empty() && !ends_with_list_marker(output);
 
                     let needs_leading_sep = !ctx.in_table_cell
                         && !ctx.in_list_item
@@ -7643,14 +10082,33 @@ fn walk_node(
                     });
 
                     #[cfg(feature = "metadata")]
-                    if ctx.metadata_wants_structured_data {
+                    if ctx.metadata_wants_structured_data || ctx.metadata_wants_document {
                         if let Some(ref collector) = ctx.metadata_collector {
                             for child_handle in children.top().iter() {
                                 if let Some(tl::Node::Tag(child_tag)) = child_handle.get(parser) {
                                     let child_name = dom_ctx
                                         .tag_name_for(*child_handle, parser)
                                         .unwrap_or_else(|| normalized_tag_name(child_tag.name().as_utf8_str()));
-                                    if child_name.as_ref() == "script" {
+                                    if child_name.as_ref() == "link" {
+                                        if let (Some(rel), Some(href)) = (
+                                            child_tag.attributes().get("rel").flatten(),
+                                            child_tag.attributes().get("href").flatten(),
+                                        ) {
+                                            let title = child_tag
+                                                .attributes()
+                                                .get("title")
+                                                .flatten()
+                                                .map(|title| {
+                                                    text::normalize_whitespace(&title.as_utf8_str()).trim().to_string()
+                                                })
+                                                .filter(|title| !title.is_empty());
+                                            collector.borrow_mut().add_rel_profile(
+                                                &rel.as_utf8_str(),
+                                                &href.as_utf8_str(),
+                                                title,
+                                            );
+                                        }
+                                    } else if child_name.as_ref() == "script" {
                                         if let Some(type_attr) = child_tag.attributes().get("type").flatten() {
                                             let type_value = type_attr.as_utf8_str();
                                             let type_value = type_value.as_ref();
@@ -7679,6 +10137,16 @@ fn walk_node(
                     }
                 }
 
+                "script" if options.math_style != MathStyle::Comment && is_tex_script(tag) => {
+                    let display = tag
+                        .attributes()
+                        .get("type")
+                        .flatten()
+                        .is_some_and(|value| value.as_utf8_str().contains("mode=display"));
+                    let tex = tag.inner_text(parser);
+                    push_math(output, tex.trim(), display, options, ctx);
+                }
+
                 "script" =>
                 {
                     #[cfg(feature = "metadata")]
@@ -7687,7 +10155,7 @@ fn walk_node(
                         let type_value = type_value.as_ref();
                         let type_value = type_value.split(';').next().unwrap_or(type_value);
                         if type_value.trim().eq_ignore_ascii_case("application/ld+json")
-                            && ctx.metadata_wants_structured_data
+                            && (ctx.metadata_wants_structured_data || ctx.metadata_wants_document)
                         {
                             if let Some(ref collector) = ctx.metadata_collector {
                                 let json = tag.inner_text(parser);
@@ -7734,15 +10202,57 @@ fn walk_node(
                     }
                 }
 
+                "object" | "embed" if options.object_links => {
+                    let url_attribute = if tag_name == "object" { "data" } else { "src" };
+                    let url = tag
+                        .attributes()
+                        .get(url_attribute)
+                        .flatten()
+                        .map(|value| value.as_utf8_str().trim().to_string())
+                        .unwrap_or_default();
+
+                    let len_before = output.len();
+                    for child_handle in tag.children().top().iter() {
+                        walk_node(child_handle, parser, output, options, ctx, depth + 1, dom_ctx);
+                    }
+                    if output[len_before..].trim().is_empty() {
+                        output.truncate(len_before);
+                    }
+
+                    // Fallback content often links to the same resource already.
+                    if url.is_empty() || output[len_before..].contains(&format!("]({url}")) {
+                        return;
+                    }
+                    let label = object_link_label(tag, &url);
+                    if output.len() > len_before {
+                        if output.ends_with('\n') {
+                            if !output.ends_with("\n\n") {
+                                output.push('\n');
+                            }
+                        } else {
+                            output.push(' ');
+                        }
+                    }
+                    append_markdown_link(output, &escape_link_label(&label), &url, None, &label, options);
+                }
+
                 "body" | "html" => {
                     // Process children of body/html tags directly without whitespace truncation
                     // These are structural container tags that should always preserve their content
 
                     let children = tag.children();
+                    // Elements around a nested `body` measure their output by its length, so
+                    // only the document's own `html` and `body` hand blocks to a sink.
+                    let top_level = depth == 0
+                        || (depth == 1
+                            && dom_ctx.parent_tag_name(node_handle.get_inner(), parser).as_deref() == Some("html"));
 
                     {
                         for child_handle in children.top().iter() {
                             walk_node(child_handle, parser, output, options, ctx, depth + 1, dom_ctx);
+                            if top_level {
+                                flush_finished_blocks(output, options, ctx);
+                            }
                         }
                     }
                 }
@@ -7788,6 +10298,27 @@ fn walk_node(
                 }
             }
 
+            if matches!(tag_name.as_ref(), "bdo" | "bdi")
+                && options.bidi_mode == BidiMode::Ignore
+                && options.unrepresentable_policy != UnrepresentablePolicy::Drop
+            {
+                apply_unrepresentable_isolate(output, element_output_start, tag, tag_name.as_ref(), ctx, options);
+            }
+
+            if options.bidi_mode != BidiMode::Ignore
+                && !ctx.in_code
+                && !matches!(tag_name.as_ref(), "pre" | "code" | "kbd" | "samp")
+            {
+                if let Some(direction) = Direction::from_tag(tag) {
+                    if direction != dom_ctx.inherited_direction(node_handle.get_inner(), parser) {
+                        let inline = ctx.convert_as_inline
+                            || is_inline_element(tag_name.as_ref())
+                            || (options.bidi_mode == BidiMode::Html && matches!(tag_name.as_ref(), "li" | "dt" | "dd"));
+                        apply_bidi_direction(output, element_output_start, direction, options.bidi_mode, inline);
+                    }
+                }
+            }
+
             #[cfg(feature = "visitor")]
             if !matches!(tag_name.as_ref(), "table") {
                 if let Some(ref visitor_handle) = ctx.visitor {
@@ -7984,11 +10515,16 @@ fn convert_table_cell(
             }
         } else {
             let raw = dom_ctx.text_content(*node_handle, parser);
-            let normalized = if options.whitespace_mode == crate::options::WhitespaceMode::Normalized {
-                text::normalize_whitespace_cow(raw.as_str())
+            let raw = if strips_invisible_formatting(options) {
+                remove_invisible_formatting(raw.as_str(), options, ctx)
             } else {
                 Cow::Borrowed(raw.as_str())
             };
+            let normalized = if options.whitespace_mode == crate::options::WhitespaceMode::Normalized {
+                normalize_whitespace_for(raw.as_ref(), options.nbsp_in_tables)
+            } else {
+                raw
+            };
             let escaped = text::escape(
                 normalized.as_ref(),
                 options.escape_misc,
@@ -8004,8 +10540,11 @@ fn convert_table_cell(
         }
     }
 
-    let text = text.trim();
-    let text = if options.br_in_tables {
+    let text = trim_for(&text, options.nbsp_in_tables);
+    let is_header = matches!(node_handle.get(parser), Some(tl::Node::Tag(tag)) if tag.name().as_utf8_str() == "th");
+    let text = if is_header && options.header_break_style != HeaderBreakStyle::Inherit {
+        join_header_lines(text, options)
+    } else if options.br_in_tables {
         let mut joined = String::with_capacity(text.len());
         let mut first = true;
         for segment in text.split('\n').filter(|s| !s.is_empty()) {
@@ -8068,7 +10607,11 @@ fn convert_table_row(
                         walk_node(child_handle, parser, &mut text, options, &cell_ctx, 0, dom_ctx);
                     }
                 }
-                text::normalize_whitespace_cow(&text).trim().to_string()
+                trim_for(
+                    &normalize_whitespace_for(&text, options.nbsp_in_tables),
+                    options.nbsp_in_tables,
+                )
+                .to_string()
             })
             .collect()
     } else {
@@ -8305,12 +10848,13 @@ fn append_layout_row(
                     for cell_child in cell_children.top().iter() {
                         walk_node(cell_child, parser, &mut cell_text, options, &cell_ctx, 0, dom_ctx);
                     }
-                    let cell_content = text::normalize_whitespace_cow(&cell_text);
-                    if !cell_content.trim().is_empty() {
+                    let cell_content = normalize_whitespace_for(&cell_text, options.nbsp_in_prose);
+                    let cell_content = trim_for(&cell_content, options.nbsp_in_prose);
+                    if !cell_content.is_empty() {
                         if !row_text.is_empty() {
                             row_text.push(' ');
                         }
-                        row_text.push_str(cell_content.trim());
+                        row_text.push_str(cell_content);
                     }
                 }
             }
@@ -9128,6 +11672,32 @@ mod tests {
         assert!(result.contains("Text"), "Should keep span text content");
     }
 
+    #[test]
+    fn test_remove_tags_vs_strip_tags() {
+        let html = r#"<p>Intro <span>kept</span></p><aside><p>Related stories</p></aside><div class="cookie"><p>We use cookies</p></div><p>Outro</p>"#;
+        let options = ConversionOptions {
+            strip_tags: vec!["span".to_string()],
+            remove_tags: vec!["ASIDE".to_string(), "div".to_string()],
+            ..Default::default()
+        };
+        let result = convert_html(html, &options).unwrap();
+
+        assert_eq!(result, "Intro kept\n\nOutro\n");
+    }
+
+    #[test]
+    fn test_remove_tags_title_skips_front_matter_title() {
+        let html = "<html><head><title>Page</title></head><body><p>Body</p></body></html>";
+        let options = ConversionOptions {
+            remove_tags: vec!["title".to_string()],
+            ..Default::default()
+        };
+        let result = convert_html(html, &options).unwrap();
+
+        assert!(!result.contains("Page"), "{result}");
+        assert!(result.contains("Body"));
+    }
+
     #[test]
     fn test_table_colspan_clamped() {
         let html = r#"<table><tr><td colspan="9007199254740991">Cell</td></tr></table>"#;
@@ -0,0 +1,104 @@
+//! Diagnostics recorded while converting a document.
+//!
+//! Markdown cannot express everything HTML can. Diagnostics give pipelines that need an audit
+//! trail (for example compliance archiving) a record of what the conversion left out. They are
+//! returned by [`convert_with_diagnostics`](crate::convert_with_diagnostics).
+//!
+//! With [`ConversionOptions::report_dropped_content`](crate::ConversionOptions::report_dropped_content)
+//! they also say how much of the page each removal rule discarded. A preset that suddenly drops
+//! most of a site's text has usually stopped matching that site's markup.
+
+/// Category of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "metadata", serde(rename_all = "snake_case"))]
+pub enum DiagnosticKind {
+    /// Content with no Markdown equivalent was dropped under
+    /// [`UnrepresentablePolicy::Diagnose`](crate::UnrepresentablePolicy::Diagnose).
+    UnrepresentableContent,
+    /// A subtree was kept as sanitized HTML because converting it would have lost more of its
+    /// structure than [`ConversionOptions::fidelity_threshold`](crate::ConversionOptions::fidelity_threshold)
+    /// allows.
+    KeptAsHtml,
+    /// Text removed by one removal rule, totalled over the document; see [`Diagnostic::dropped`].
+    DroppedContent,
+}
+
+/// A single note about content the conversion could not carry over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagnostic {
+    /// What kind of loss this records.
+    pub kind: DiagnosticKind,
+    /// Tag name of the element involved, or `None` for text-level changes.
+    pub element: Option<String>,
+    /// Human-readable description, suitable for logs.
+    pub message: String,
+    /// How much text was removed, for [`DiagnosticKind::DroppedContent`].
+    #[cfg_attr(feature = "metadata", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub dropped: Option<DroppedContent>,
+}
+
+/// Amount of text a removal rule dropped.
+///
+/// Lengths count the characters of text nodes, whitespace excluded, outside `<head>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+pub struct DroppedContent {
+    /// The rule that removed the text: `remove_navigation`, `remove_forms`, `remove_tags`,
+    /// `exclude_selectors`, `print_styles`, `include_selector` or `extract_main_content`.
+    pub rule: String,
+    /// Characters of text the rule removed.
+    pub characters: usize,
+    /// Characters of text in the whole document.
+    pub document_characters: usize,
+}
+
+impl DroppedContent {
+    /// Fraction of the document's text that was removed, from `0.0` to `1.0`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn share(&self) -> f64 {
+        if self.document_characters == 0 {
+            return 0.0;
+        }
+        self.characters as f64 / self.document_characters as f64
+    }
+}
+
+impl Diagnostic {
+    pub(crate) fn unrepresentable(element: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            kind: DiagnosticKind::UnrepresentableContent,
+            element: element.map(str::to_string),
+            message: message.into(),
+            dropped: None,
+        }
+    }
+
+    pub(crate) fn kept_as_html(element: &str, message: impl Into<String>) -> Self {
+        Self {
+            kind: DiagnosticKind::KeptAsHtml,
+            element: Some(element.to_string()),
+            message: message.into(),
+            dropped: None,
+        }
+    }
+
+    pub(crate) fn dropped_content(rule: &str, characters: usize, document_characters: usize) -> Self {
+        let dropped = DroppedContent {
+            rule: rule.to_string(),
+            characters,
+            document_characters,
+        };
+        Self {
+            kind: DiagnosticKind::DroppedContent,
+            element: None,
+            message: format!(
+                "{rule} removed {characters} of {document_characters} text characters ({:.1}%)",
+                dropped.share() * 100.0
+            ),
+            dropped: Some(dropped),
+        }
+    }
+}
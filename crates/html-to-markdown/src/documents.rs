@@ -0,0 +1,134 @@
+//! Splitting input that holds several complete HTML documents, for `split_documents`.
+//!
+//! Log exports and email digests often glue whole pages together. Parsed as a single tree, the
+//! later documents' `<head>` content ends up in the body, and per-document steps such as main
+//! content extraction or `<title>` front matter only ever see one of them. [`split_documents`]
+//! finds where each document starts so they can be converted one at a time.
+
+/// Split `html` before every document after the first.
+///
+/// A new document starts at a `<!DOCTYPE>` once the current one has a doctype, `<html>` or
+/// `<body>`; at an `<html>` tag once the current one has an `<html>` tag or a closed body; and
+/// at a `<body>` tag once the current body was closed. Comments, scripts and styles are skipped.
+/// Input with a single document is returned as one slice.
+pub fn split_documents(html: &str) -> Vec<&str> {
+    let bytes = html.as_bytes();
+    let mut starts = vec![0];
+    let mut has_root = false;
+    let mut has_html = false;
+    let mut body_closed = false;
+    let mut pos = 0;
+
+    while let Some(offset) = bytes[pos..].iter().position(|&b| b == b'<') {
+        let at = pos + offset;
+        let rest = &bytes[at..];
+        pos = at + 1;
+
+        if rest.starts_with(b"<!--") {
+            match find(bytes, at + 4, b"-->") {
+                Some(end) => pos = end + 3,
+                None => break,
+            }
+            continue;
+        }
+
+        let starts_document = if starts_with_ignore_case(rest, b"<!doctype") {
+            has_root
+        } else if is_tag(rest, b"html") {
+            let split = has_html || body_closed;
+            has_html = true;
+            split
+        } else if is_tag(rest, b"body") {
+            body_closed
+        } else {
+            if is_tag(rest, b"/body") || is_tag(rest, b"/html") {
+                body_closed = true;
+            } else if let Some(name) = [b"script".as_slice(), b"style"]
+                .into_iter()
+                .find(|name| is_tag(rest, name))
+            {
+                let mut closing = b"</".to_vec();
+                closing.extend_from_slice(name);
+                match find_ignore_case(bytes, at + 1, &closing) {
+                    Some(end) => pos = end + closing.len(),
+                    None => break,
+                }
+            }
+            continue;
+        };
+
+        if starts_document {
+            starts.push(at);
+            body_closed = false;
+            has_html = is_tag(rest, b"html");
+        }
+        has_root = true;
+    }
+
+    starts.push(html.len());
+    starts.windows(2).map(|range| &html[range[0]..range[1]]).collect()
+}
+
+fn starts_with_ignore_case(haystack: &[u8], prefix: &[u8]) -> bool {
+    haystack.len() >= prefix.len() && haystack[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+/// Whether `rest` opens with the tag `<name`, `name` being followed by the end of the tag name.
+fn is_tag(rest: &[u8], name: &[u8]) -> bool {
+    starts_with_ignore_case(&rest[1..], name)
+        && rest
+            .get(name.len() + 1)
+            .is_none_or(|&b| b == b'>' || b == b'/' || b.is_ascii_whitespace())
+}
+
+fn find(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|offset| from + offset)
+}
+
+fn find_ignore_case(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))
+        .map(|offset| from + offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_documents_are_not_split() {
+        for html in [
+            "<!DOCTYPE html><html><head></head><body><p>a</p></body></html>\n",
+            "<html><body><p>a</p></body></html><p>trailing</p>",
+            "<p>fragment</p><p>only</p>",
+            "<html><body><script>document.write('<html><body>')</script><!-- <html> --></body></html>",
+        ] {
+            assert_eq!(split_documents(html), [html]);
+        }
+    }
+
+    #[test]
+    fn test_documents_are_split_at_their_start() {
+        assert_eq!(
+            split_documents("<!doctype html><html><p>a</p></html>\n<!DOCTYPE html><html><p>b</p></html>"),
+            [
+                "<!doctype html><html><p>a</p></html>\n",
+                "<!DOCTYPE html><html><p>b</p></html>"
+            ]
+        );
+        assert_eq!(
+            split_documents("<html><p>a</p></html><HTML lang=en><p>b</p></HTML>"),
+            ["<html><p>a</p></html>", "<HTML lang=en><p>b</p></HTML>"]
+        );
+        assert_eq!(
+            split_documents("<body>a</body><body>b</body><body>c</body>"),
+            ["<body>a</body>", "<body>b</body>", "<body>c</body>"]
+        );
+    }
+}
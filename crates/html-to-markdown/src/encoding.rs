@@ -0,0 +1,77 @@
+//! Character set detection for HTML read from files or the network (requires the `encoding`
+//! feature).
+//!
+//! [`decode_html`] follows the order browsers use, minus the statistical guessing: a byte order
+//! mark, then the charset the transport declared (an HTTP `Content-Type` parameter), then a
+//! `<meta charset>` or `<meta http-equiv>` near the top of the document. Without any of those
+//! the bytes are read as UTF-8 when they are valid UTF-8 and as Windows-1252 otherwise, which is
+//! what most undeclared legacy pages turn out to be.
+//!
+//! ```
+//! use html_to_markdown_rs::encoding::decode_html;
+//!
+//! let latin1 = b"<meta charset=\"iso-8859-1\"><p>Caf\xe9</p>";
+//! assert_eq!(decode_html(latin1, None), "<meta charset=\"iso-8859-1\"><p>Café</p>");
+//! assert_eq!(decode_html(b"<p>Caf\xe9</p>", None), "<p>Café</p>");
+//! ```
+
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
+
+/// How far into a document to look for a `<meta charset>` declaration.
+const META_SNIFF_LIMIT: usize = 1024;
+
+/// Decode an HTML document to a `String`.
+///
+/// `declared` is a charset label from outside the document, such as the `charset` parameter of
+/// an HTTP `Content-Type` header; unknown labels are ignored. Bytes that are invalid in the
+/// chosen encoding become U+FFFD.
+#[must_use]
+pub fn decode_html(bytes: &[u8], declared: Option<&str>) -> String {
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        return encoding.decode_without_bom_handling(&bytes[bom_len..]).0.into_owned();
+    }
+    let encoding = declared
+        .and_then(|label| Encoding::for_label(label.trim().as_bytes()))
+        .or_else(|| sniff_meta_charset(bytes))
+        .unwrap_or_else(|| {
+            if std::str::from_utf8(bytes).is_ok() {
+                UTF_8
+            } else {
+                WINDOWS_1252
+            }
+        });
+    encoding.decode_without_bom_handling(bytes).0.into_owned()
+}
+
+/// The encoding named by a `charset=` near the start of the document, if any.
+fn sniff_meta_charset(bytes: &[u8]) -> Option<&'static Encoding> {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(META_SNIFF_LIMIT)]).to_ascii_lowercase();
+    let start = head.find("charset=")? + "charset=".len();
+    let label: String = head[start..]
+        .trim_start_matches(['"', '\''])
+        .chars()
+        .take_while(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | ':' | '.'))
+        .collect();
+    Encoding::for_label(label.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precedence() {
+        let body = b"\xEF\xBB\xBF<meta charset=\"iso-8859-1\"><p>\xC3\xA9</p>";
+        assert_eq!(
+            decode_html(body, Some("shift_jis")),
+            "<meta charset=\"iso-8859-1\"><p>é</p>"
+        );
+
+        let body = b"<meta charset=\"utf-8\"><p>\xe9</p>";
+        assert_eq!(decode_html(body, Some("latin1")), "<meta charset=\"utf-8\"><p>é</p>");
+        assert_eq!(
+            decode_html(body, Some("no-such-charset")),
+            "<meta charset=\"utf-8\"><p>\u{FFFD}</p>"
+        );
+    }
+}
@@ -25,6 +25,9 @@ pub enum ConversionError {
     IoError(#[from] std::io::Error),
 
     /// Panic caught during conversion to prevent unwinding across FFI boundaries
+    ///
+    /// Malformed input is not reported this way: out-of-range table spans, ordered list starts
+    /// and hOCR coordinates are clamped and converted. This variant indicates a converter bug.
     #[error("Internal panic: {0}")]
     Panic(String),
 
@@ -0,0 +1,499 @@
+//! HTTP fetching for URL input (requires the `fetch` feature).
+//!
+//! [`Fetcher`] wraps a blocking HTTP client with the behaviour a crawler needs and a one-off
+//! download should not have to think about:
+//!
+//! - transient failures (connection errors, timeouts, `408`, `429` and `5xx` responses) are
+//!   retried with exponential backoff, honouring `Retry-After` when the server sends one;
+//! - requests to the same host are spaced at least [`FetchConfig::per_host_delay`] apart, also
+//!   when the fetcher is shared between threads;
+//! - cookies set by a host are sent back to it, and redirects are followed by hand so cookies
+//!   and politeness apply to every hop;
+//! - `ETag` and `Last-Modified` validators are remembered per URL and replayed as conditional
+//!   requests, so an unchanged page comes back as [`FetchResponse::not_modified`] without a body.
+//!
+//! Cookies and validators live in a [`FetchState`]. With the `serde` feature it can be saved
+//! between runs and handed to [`Fetcher::with_state`], which lets an interrupted crawl resume
+//! without downloading unchanged pages again.
+//!
+//! ```no_run
+//! use html_to_markdown_rs::fetch::{FetchConfig, Fetcher};
+//!
+//! let fetcher = Fetcher::new(FetchConfig::default())?;
+//! let response = fetcher.fetch("https://example.com/")?;
+//! let html = String::from_utf8_lossy(&response.body);
+//! let markdown = html_to_markdown_rs::convert(&html, None)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use reqwest::Url;
+use reqwest::blocking::{Client, Response};
+use reqwest::header::{
+    CONTENT_TYPE, COOKIE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, LOCATION, RETRY_AFTER, SET_COOKIE,
+    USER_AGENT,
+};
+use thiserror::Error;
+
+/// User agent sent when [`FetchConfig::user_agent`] is left at its default.
+pub const DEFAULT_USER_AGENT: &str = concat!("html-to-markdown/", env!("CARGO_PKG_VERSION"));
+
+/// Errors returned by [`Fetcher`].
+#[derive(Error, Debug)]
+pub enum FetchError {
+    /// The URL could not be parsed or uses a scheme other than `http`/`https`.
+    #[error("Invalid URL '{url}': {message}")]
+    InvalidUrl {
+        /// URL as given.
+        url: String,
+        /// Why the URL was rejected.
+        message: String,
+    },
+
+    /// The HTTP client could not be built.
+    #[error("Failed to build HTTP client: {0}")]
+    Client(String),
+
+    /// The request failed before a response arrived, after all retries.
+    #[error("Failed to fetch '{url}': {message}")]
+    Transport {
+        /// URL being fetched.
+        url: String,
+        /// Reason reported by the HTTP client.
+        message: String,
+    },
+
+    /// The server answered with a non-success status, after all retries.
+    #[error("Request failed for '{url}': HTTP {status}")]
+    Status {
+        /// URL being fetched.
+        url: String,
+        /// HTTP status code of the final response.
+        status: u16,
+    },
+
+    /// More redirects than [`FetchConfig::max_redirects`].
+    #[error("Too many redirects fetching '{url}'")]
+    TooManyRedirects {
+        /// URL being fetched.
+        url: String,
+    },
+
+    /// The response body could not be read.
+    #[error("Failed to read response body from '{url}': {message}")]
+    Body {
+        /// URL being fetched.
+        url: String,
+        /// Reason reported by the HTTP client.
+        message: String,
+    },
+}
+
+/// Settings for a [`Fetcher`].
+#[derive(Debug, Clone)]
+pub struct FetchConfig {
+    /// `User-Agent` header sent with every request.
+    pub user_agent: String,
+    /// Timeout for a single request, including reading the body.
+    pub timeout: Duration,
+    /// Redirects followed before giving up.
+    pub max_redirects: usize,
+    /// Retries after the first attempt for transient failures.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each further retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for a retry delay, including one asked for by `Retry-After`.
+    pub max_backoff: Duration,
+    /// Minimum time between the starts of two requests to the same host.
+    pub per_host_delay: Duration,
+    /// Send `If-None-Match` / `If-Modified-Since` for URLs fetched before.
+    pub conditional_requests: bool,
+    /// Remember cookies set by a host and send them back to it.
+    pub cookies: bool,
+}
+
+impl Default for FetchConfig {
+    fn default() -> Self {
+        Self {
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            timeout: Duration::from_secs(15),
+            max_redirects: 5,
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            per_host_delay: Duration::ZERO,
+            conditional_requests: true,
+            cookies: true,
+        }
+    }
+}
+
+/// Cache validators remembered for a URL.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Validators {
+    /// Last `ETag` header returned for the URL.
+    pub etag: Option<String>,
+    /// Last `Last-Modified` header returned for the URL.
+    pub last_modified: Option<String>,
+}
+
+/// A cookie remembered for a host.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cookie {
+    /// Cookie value.
+    pub value: String,
+    /// Set with the `Secure` attribute: only sent over `https`.
+    pub secure: bool,
+}
+
+/// Cookies and cache validators collected by a [`Fetcher`].
+///
+/// Cookies are kept per host and sent back to exactly that host; the `Domain`, `Path` and
+/// expiry attributes are not tracked, except that `Max-Age=0` deletes a cookie. `Secure`
+/// cookies are only accepted from and sent to `https` URLs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FetchState {
+    /// Validators by final (post-redirect) URL.
+    pub validators: BTreeMap<String, Validators>,
+    /// Cookie values by host, then by cookie name.
+    pub cookies: BTreeMap<String, BTreeMap<String, Cookie>>,
+}
+
+/// A successful (`2xx` or `304`) response.
+#[derive(Debug, Clone)]
+pub struct FetchResponse {
+    /// URL the body was served from, after redirects.
+    pub url: String,
+    /// HTTP status code.
+    pub status: u16,
+    /// `Content-Type` header, if any.
+    pub content_type: Option<String>,
+    /// Response body, with any `Content-Encoding` already removed. Empty when `not_modified`.
+    pub body: Vec<u8>,
+    /// The server answered a conditional request with `304 Not Modified`.
+    pub not_modified: bool,
+}
+
+impl FetchResponse {
+    /// The `charset` parameter of the `Content-Type` header, if present.
+    #[must_use]
+    pub fn charset(&self) -> Option<&str> {
+        self.content_type.as_deref()?.split(';').skip(1).find_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            key.trim()
+                .eq_ignore_ascii_case("charset")
+                .then(|| value.trim().trim_matches('"'))
+        })
+    }
+}
+
+/// Blocking HTTP fetcher with retries, politeness, cookies and conditional requests.
+///
+/// A `Fetcher` is `Send + Sync`; share one between threads to keep per-host politeness and
+/// cookies consistent across a crawl.
+#[derive(Debug)]
+pub struct Fetcher {
+    client: Client,
+    config: FetchConfig,
+    state: Mutex<FetchState>,
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl Fetcher {
+    /// Create a fetcher with empty state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FetchError::Client`] if the underlying HTTP client cannot be built.
+    pub fn new(config: FetchConfig) -> Result<Self, FetchError> {
+        Self::with_state(config, FetchState::default())
+    }
+
+    /// Create a fetcher that starts from previously saved cookies and validators.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FetchError::Client`] if the underlying HTTP client cannot be built.
+    pub fn with_state(config: FetchConfig, state: FetchState) -> Result<Self, FetchError> {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|err| FetchError::Client(err.to_string()))?;
+        Ok(Self {
+            client,
+            config,
+            state: Mutex::new(state),
+            next_slot: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// The configuration this fetcher was created with.
+    #[must_use]
+    pub const fn config(&self) -> &FetchConfig {
+        &self.config
+    }
+
+    /// A snapshot of the cookies and validators collected so far.
+    #[must_use]
+    pub fn state(&self) -> FetchState {
+        lock(&self.state).clone()
+    }
+
+    /// Fetch `url`, following redirects and retrying transient failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FetchError`] when the URL is invalid, the request keeps failing, the final
+    /// status is not `2xx`/`304`, or the body cannot be read.
+    pub fn fetch(&self, url: &str) -> Result<FetchResponse, FetchError> {
+        let mut current = parse_url(url)?;
+        for _ in 0..=self.config.max_redirects {
+            let response = self.send_with_retries(&current)?;
+            let status = response.status();
+            if status.is_redirection() && status.as_u16() != 304 {
+                let location = response
+                    .headers()
+                    .get(LOCATION)
+                    .and_then(|value| value.to_str().ok())
+                    .ok_or_else(|| FetchError::Status {
+                        url: current.to_string(),
+                        status: status.as_u16(),
+                    })?;
+                current = current.join(location).map_err(|err| FetchError::InvalidUrl {
+                    url: location.to_string(),
+                    message: err.to_string(),
+                })?;
+                continue;
+            }
+            return self.finish(&current, response);
+        }
+        Err(FetchError::TooManyRedirects { url: url.to_string() })
+    }
+
+    fn finish(&self, url: &Url, response: Response) -> Result<FetchResponse, FetchError> {
+        let status = response.status().as_u16();
+        let content_type = header(&response, CONTENT_TYPE.as_str());
+        if status == 304 {
+            return Ok(FetchResponse {
+                url: url.to_string(),
+                status,
+                content_type,
+                body: Vec::new(),
+                not_modified: true,
+            });
+        }
+        if !response.status().is_success() {
+            return Err(FetchError::Status {
+                url: url.to_string(),
+                status,
+            });
+        }
+
+        let validators = Validators {
+            etag: header(&response, ETAG.as_str()),
+            last_modified: header(&response, LAST_MODIFIED.as_str()),
+        };
+        if validators != Validators::default() {
+            lock(&self.state).validators.insert(url.to_string(), validators);
+        }
+
+        let body = response.bytes().map_err(|err| FetchError::Body {
+            url: url.to_string(),
+            message: err.to_string(),
+        })?;
+        Ok(FetchResponse {
+            url: url.to_string(),
+            status,
+            content_type,
+            body: body.to_vec(),
+            not_modified: false,
+        })
+    }
+
+    fn send_with_retries(&self, url: &Url) -> Result<Response, FetchError> {
+        let mut attempt = 0;
+        loop {
+            self.wait_for_host(url);
+            let result = self.request(url).send();
+            let retry_after = match &result {
+                Ok(response) if is_retryable_status(response.status().as_u16()) => Some(retry_after(response)),
+                Err(err) if err.is_connect() || err.is_timeout() || err.is_request() => Some(None),
+                _ => None,
+            };
+
+            match (result, retry_after) {
+                (result, Some(requested)) if attempt < self.config.max_retries => {
+                    drop(result);
+                    thread::sleep(
+                        requested
+                            .unwrap_or_else(|| self.backoff(attempt))
+                            .min(self.config.max_backoff),
+                    );
+                    attempt += 1;
+                }
+                (Ok(response), _) => {
+                    self.store_cookies(url, &response);
+                    return Ok(response);
+                }
+                (Err(err), _) => {
+                    return Err(FetchError::Transport {
+                        url: url.to_string(),
+                        message: err.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn request(&self, url: &Url) -> reqwest::blocking::RequestBuilder {
+        let mut request = self
+            .client
+            .get(url.clone())
+            .header(USER_AGENT, self.config.user_agent.as_str());
+
+        let state = lock(&self.state);
+        if self.config.conditional_requests {
+            if let Some(validators) = state.validators.get(url.as_str()) {
+                if let Some(etag) = &validators.etag {
+                    request = request.header(IF_NONE_MATCH, etag.as_str());
+                }
+                if let Some(last_modified) = &validators.last_modified {
+                    request = request.header(IF_MODIFIED_SINCE, last_modified.as_str());
+                }
+            }
+        }
+        if self.config.cookies {
+            if let Some(cookies) = url.host_str().and_then(|host| state.cookies.get(host)) {
+                let secure_url = url.scheme() == "https";
+                let header = cookies
+                    .iter()
+                    .filter(|(_, cookie)| secure_url || !cookie.secure)
+                    .map(|(name, cookie)| format!("{name}={}", cookie.value))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                if !header.is_empty() {
+                    request = request.header(COOKIE, header);
+                }
+            }
+        }
+        drop(state);
+        request
+    }
+
+    fn store_cookies(&self, url: &Url, response: &Response) {
+        let Some(host) = url.host_str().filter(|_| self.config.cookies) else {
+            return;
+        };
+        let mut set_cookies = response
+            .headers()
+            .get_all(SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .peekable();
+        if set_cookies.peek().is_none() {
+            return;
+        }
+
+        let mut state = lock(&self.state);
+        let jar = state.cookies.entry(host.to_string()).or_default();
+        for set_cookie in set_cookies {
+            let mut parts = set_cookie.split(';');
+            let Some((name, value)) = parts.next().and_then(|pair| pair.split_once('=')) else {
+                continue;
+            };
+            let name = name.trim();
+            let (mut expired, mut secure) = (false, false);
+            for attribute in parts {
+                let (key, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+                let key = key.trim();
+                expired |= key.eq_ignore_ascii_case("max-age") && value.trim() == "0";
+                secure |= key.eq_ignore_ascii_case("secure");
+            }
+            if secure && url.scheme() != "https" {
+                continue;
+            }
+            if expired {
+                jar.remove(name);
+            } else if !name.is_empty() {
+                let cookie = Cookie {
+                    value: value.trim().to_string(),
+                    secure,
+                };
+                jar.insert(name.to_string(), cookie);
+            }
+        }
+        drop(state);
+    }
+
+    /// Sleep until this fetcher may start another request to the URL's host.
+    fn wait_for_host(&self, url: &Url) {
+        if self.config.per_host_delay.is_zero() {
+            return;
+        }
+        let host = url.host_str().unwrap_or_default().to_string();
+        let start = {
+            let mut slots = lock(&self.next_slot);
+            let now = Instant::now();
+            let start = slots.get(&host).map_or(now, |slot| (*slot).max(now));
+            slots.insert(host, start + self.config.per_host_delay);
+            start
+        };
+        let wait = start.saturating_duration_since(Instant::now());
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+    }
+
+    const fn backoff(&self, attempt: u32) -> Duration {
+        self.config
+            .initial_backoff
+            .saturating_mul(2_u32.saturating_pow(attempt))
+    }
+}
+
+fn parse_url(url: &str) -> Result<Url, FetchError> {
+    let parsed = Url::parse(url).map_err(|err| FetchError::InvalidUrl {
+        url: url.to_string(),
+        message: err.to_string(),
+    })?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(FetchError::InvalidUrl {
+            url: url.to_string(),
+            message: format!("unsupported scheme '{}'", parsed.scheme()),
+        });
+    }
+    Ok(parsed)
+}
+
+fn header(response: &Response, name: &str) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+const fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 429 | 500 | 502 | 503 | 504)
+}
+
+/// The delay asked for by a `Retry-After: <seconds>` header. HTTP dates are not supported.
+fn retry_after(response: &Response) -> Option<Duration> {
+    header(response, RETRY_AFTER.as_str())?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,117 @@
+//! Content fingerprints for deduplicating converted documents.
+//!
+//! The fingerprint hashes the words of the Markdown and nothing else: link and image
+//! destinations, reference definitions, inline HTML tags, list numbers, emphasis and other
+//! punctuation are dropped and whitespace is collapsed. Two renderings of the same text,
+//! whether wrapped differently, using `_` instead of `*` or linking to a mirror's own URLs,
+//! therefore share a fingerprint.
+//!
+//! The hash is 128-bit FNV-1a over the space-joined words. It is fixed by this module rather
+//! than taken from `std`, so fingerprints stay comparable across platforms and releases.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::postprocess::heading_plain_text;
+
+static HTML_TAG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"</?[A-Za-z][^<>]*>").unwrap());
+
+static REFERENCE_LABEL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\]\[[^\[\]]*\]").unwrap());
+
+static ORDERED_MARKER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^[ \t>]*\d{1,9}[.)][ \t]").unwrap());
+
+static REFERENCE_DEFINITION_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^ {0,3}\[[^\]]+\]:\s+\S").unwrap());
+
+const FNV_OFFSET_BASIS: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+const FNV_PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+/// Fingerprint of the text of a Markdown document, as 32 lowercase hex digits.
+///
+/// Front matter is hashed like any other text; strip it first if mirrors may disagree on it.
+/// [`convert_with_fingerprint`](crate::convert_with_fingerprint) does that for the front
+/// matter it emits itself.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::content_fingerprint;
+///
+/// assert_eq!(
+///     content_fingerprint("# Title\n\nSome **bold** [text](https://a.example/).\n"),
+///     content_fingerprint("Title\n=====\n\nSome __bold__\n[text](https://b.example/)\n"),
+/// );
+/// ```
+#[must_use]
+pub fn content_fingerprint(markdown: &str) -> String {
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut first = true;
+    for word in words(markdown) {
+        if !first {
+            hash = fnv1a(hash, b" ");
+        }
+        hash = fnv1a(hash, word.as_bytes());
+        first = false;
+    }
+    format!("{hash:032x}")
+}
+
+/// The words of `markdown`, block by block, with syntax and link destinations removed.
+fn words(markdown: &str) -> impl Iterator<Item = String> + '_ {
+    markdown.split("\n\n").flat_map(|block| {
+        let block: Vec<&str> = block
+            .lines()
+            .filter(|line| !REFERENCE_DEFINITION_RE.is_match(line))
+            .collect();
+        let block = block.join("\n");
+        let text = HTML_TAG_RE.replace_all(&block, " ");
+        let text = ORDERED_MARKER_RE.replace_all(&text, "");
+        let text = REFERENCE_LABEL_RE.replace_all(&text, "]");
+        heading_plain_text(&text)
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    })
+}
+
+/// 128-bit FNV-1a hash of `bytes`, shared with the conversion cache keys.
+pub fn hash_bytes(bytes: &[u8]) -> u128 {
+    fnv1a(FNV_OFFSET_BASIS, bytes)
+}
+
+fn fnv1a(mut hash: u128, bytes: &[u8]) -> u128 {
+    for byte in bytes {
+        hash ^= u128::from(*byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_input_hashes_the_offset_basis() {
+        assert_eq!(content_fingerprint(""), format!("{FNV_OFFSET_BASIS:032x}"));
+        assert_eq!(content_fingerprint("---\n\n* * *\n"), content_fingerprint(""));
+    }
+
+    #[test]
+    fn test_words_skip_destinations_tags_and_definitions() {
+        let markdown = "<a id=\"intro\"></a>Read [the *docs*][1] and ![logo](/logo.png \"Logo\").\n\n[1]: https://example.com/docs";
+
+        assert_eq!(
+            words(markdown).collect::<Vec<_>>(),
+            ["Read", "the", "docs", "and", "logo"]
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_is_case_sensitive_and_order_sensitive() {
+        assert_ne!(content_fingerprint("one two"), content_fingerprint("two one"));
+        assert_ne!(content_fingerprint("one two"), content_fingerprint("One two"));
+        assert_ne!(content_fingerprint("ab"), content_fingerprint("a b"));
+    }
+}
@@ -0,0 +1,46 @@
+//! Footnotes built from `title` attributes under `title_footnotes`.
+//!
+//! Each distinct title gets one number for the whole document. Its definition is written at the
+//! end of the section the title first appears in: before the next top-level heading, or at the
+//! end of the document.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct TitleFootnotes {
+    numbers: HashMap<String, usize>,
+    pending: Vec<(usize, String)>,
+}
+
+impl TitleFootnotes {
+    /// Number of the footnote for `text`, registering it if this is the first use.
+    pub fn reference(&mut self, text: String) -> usize {
+        if let Some(&number) = self.numbers.get(&text) {
+            return number;
+        }
+        let number = self.numbers.len() + 1;
+        self.numbers.insert(text.clone(), number);
+        self.pending.push((number, text));
+        number
+    }
+
+    /// Append the definitions not written yet as a block of their own.
+    pub fn flush(&mut self, output: &mut String) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let trimmed = output.trim_end_matches([' ', '\t', '\n']).len();
+        output.truncate(trimmed);
+        if !output.is_empty() {
+            output.push_str("\n\n");
+        }
+        for (number, text) in self.pending.drain(..) {
+            output.push_str("[^");
+            output.push_str(&number.to_string());
+            output.push_str("]: ");
+            output.push_str(&text);
+            output.push('\n');
+        }
+        output.push('\n');
+    }
+}
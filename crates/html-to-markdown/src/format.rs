@@ -0,0 +1,232 @@
+//! Input format classification.
+//!
+//! [`detect_input_format`] tells apart the kinds of document that reach the converter, so a
+//! pipeline can branch before converting (enable `hocr_spatial_tables` for OCR output, unpack
+//! a web archive first) with the same heuristics the converter itself uses.
+
+use std::fmt;
+
+/// How far into the input the header and root element checks look.
+const SNIFF_LEN: usize = 4096;
+
+/// hOCR class names and `<meta>` names that mark OCR output.
+const HOCR_MARKERS: [&str; 7] = [
+    "ocr_page",
+    "ocrx_word",
+    "ocr_carea",
+    "ocr_par",
+    "ocr_line",
+    "ocr-system",
+    "ocr-capabilities",
+];
+
+/// The kind of document an input string holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputFormat {
+    /// HTML or XHTML.
+    Html,
+    /// hOCR: HTML produced by an OCR engine such as Tesseract, with `ocr_*` classes. Converted
+    /// with the dedicated hOCR pipeline.
+    Hocr,
+    /// ALTO XML, the Library of Congress OCR layout format.
+    Alto,
+    /// A MIME web archive (`.mhtml`/`.mht`) with the page and its resources as
+    /// `multipart/related` parts.
+    Mhtml,
+    /// A WARC web archive; see the `warc` feature.
+    Warc,
+    /// XML with a root element other than `<html>` or `<alto>`.
+    Xml,
+    /// No markup at all.
+    Text,
+}
+
+impl InputFormat {
+    /// Lowercase name: `"html"`, `"hocr"`, `"alto"`, `"mhtml"`, `"warc"`, `"xml"` or `"text"`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Hocr => "hocr",
+            Self::Alto => "alto",
+            Self::Mhtml => "mhtml",
+            Self::Warc => "warc",
+            Self::Xml => "xml",
+            Self::Text => "text",
+        }
+    }
+}
+
+impl fmt::Display for InputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Classify `input` without parsing it.
+///
+/// Archive and MIME headers and the root element are read from the first few kilobytes; the
+/// hOCR markers are looked for in the whole document, as OCR engines may write them after a
+/// long `<head>`. Anything with markup that is not recognised as one of the other formats is
+/// [`InputFormat::Html`].
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::{InputFormat, detect_input_format};
+///
+/// let hocr = r#"<html><body><div class="ocr_page"><span class="ocrx_word">Hi</span></div></body></html>"#;
+/// assert_eq!(detect_input_format(hocr), InputFormat::Hocr);
+/// assert_eq!(detect_input_format("<p>Hello</p>"), InputFormat::Html);
+/// assert_eq!(detect_input_format("just words"), InputFormat::Text);
+/// ```
+#[must_use]
+pub fn detect_input_format(input: &str) -> InputFormat {
+    let input = input.trim_start_matches('\u{feff}').trim_start();
+    let head = prefix(input, SNIFF_LEN);
+
+    if head.starts_with("WARC/") {
+        return InputFormat::Warc;
+    }
+    if !head.starts_with('<') && is_mhtml_header(head) {
+        return InputFormat::Mhtml;
+    }
+
+    let root = root_element(head);
+    match root.as_deref() {
+        Some("alto") => return InputFormat::Alto,
+        Some(name) if name != "html" && head.starts_with("<?xml") => return InputFormat::Xml,
+        _ => {}
+    }
+
+    if has_hocr_markers(input) {
+        InputFormat::Hocr
+    } else if root.is_some() || has_markup(input) {
+        InputFormat::Html
+    } else {
+        InputFormat::Text
+    }
+}
+
+/// Whether `input` mentions any hOCR class or meta name. The converter confirms a match against
+/// the parsed document before switching to the hOCR pipeline.
+pub(crate) fn has_hocr_markers(input: &str) -> bool {
+    let bytes = input.as_bytes();
+    bytes.windows(4).enumerate().any(|(index, window)| {
+        window.starts_with(b"ocr")
+            && matches!(window[3], b'_' | b'-' | b'x')
+            && HOCR_MARKERS
+                .iter()
+                .any(|marker| bytes[index..].starts_with(marker.as_bytes()))
+    })
+}
+
+fn prefix(input: &str, len: usize) -> &str {
+    if input.len() <= len {
+        return input;
+    }
+    let mut end = len;
+    while !input.is_char_boundary(end) {
+        end -= 1;
+    }
+    &input[..end]
+}
+
+/// MIME headers (`MIME-Version:`, `Content-Type: multipart/related`) before the first blank line.
+fn is_mhtml_header(head: &str) -> bool {
+    let headers = head.split("\n\n").next().unwrap_or_default();
+    let headers = headers
+        .split("\r\n\r\n")
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    headers.contains("content-type: multipart/related")
+        || (headers.starts_with("mime-version:") && headers.contains("multipart/"))
+        || (headers.starts_with("from:") && headers.contains("multipart/related"))
+}
+
+/// Lowercase local name of the first element, skipping the XML declaration, processing
+/// instructions, comments and the doctype.
+fn root_element(head: &str) -> Option<String> {
+    let mut rest = head;
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = &after[after.find("-->")? + 3..];
+        } else if rest.starts_with("<?") || rest.starts_with("<!") {
+            rest = &rest[rest.find('>')? + 1..];
+        } else {
+            let name = rest.strip_prefix('<')?;
+            let end = name
+                .find(|c: char| c.is_whitespace() || matches!(c, '>' | '/'))
+                .unwrap_or(name.len());
+            let name = &name[..end];
+            let local = name.rsplit(':').next().unwrap_or(name);
+            return (!local.is_empty() && local.starts_with(|c: char| c.is_ascii_alphabetic()))
+                .then(|| local.to_ascii_lowercase());
+        }
+    }
+}
+
+/// Whether anything in `input` looks like a tag, comment or doctype.
+fn has_markup(input: &str) -> bool {
+    input
+        .as_bytes()
+        .windows(2)
+        .any(|pair| pair[0] == b'<' && (pair[1].is_ascii_alphabetic() || matches!(pair[1], b'/' | b'!' | b'?')))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_archives() {
+        assert_eq!(
+            detect_input_format("WARC/1.1\r\nWARC-Type: response\r\n\r\n"),
+            InputFormat::Warc
+        );
+        let mhtml = "From: <Saved by Blink>\r\nMIME-Version: 1.0\r\nContent-Type: multipart/related;\r\n\ttype=\"text/html\"; boundary=\"----b\"\r\n\r\n------b\r\nContent-Type: text/html\r\n\r\n<html></html>";
+        assert_eq!(detect_input_format(mhtml), InputFormat::Mhtml);
+    }
+
+    #[test]
+    fn test_detects_xml_roots() {
+        let alto = r#"<?xml version="1.0" encoding="UTF-8"?>
+<alto xmlns="http://www.loc.gov/standards/alto/ns-v4#"><Layout/></alto>"#;
+        assert_eq!(detect_input_format(alto), InputFormat::Alto);
+        assert_eq!(
+            detect_input_format("<?xml version=\"1.0\"?><rss><channel/></rss>"),
+            InputFormat::Xml
+        );
+        assert_eq!(
+            detect_input_format(
+                "<?xml version=\"1.0\"?>\n<!DOCTYPE html>\n<html xmlns=\"http://www.w3.org/1999/xhtml\"><body/></html>"
+            ),
+            InputFormat::Html
+        );
+    }
+
+    #[test]
+    fn test_detects_hocr_after_long_head() {
+        let hocr = format!(
+            "<!DOCTYPE html><html><head><meta name='ocr-system' content='tesseract'/>{}</head><body></body></html>",
+            "<meta name='x'/>".repeat(1000)
+        );
+        assert_eq!(detect_input_format(&hocr), InputFormat::Hocr);
+
+        let late = format!(
+            "<html><body>{}<div class='ocr_page'></div></body></html>",
+            "<p>x</p>".repeat(1000)
+        );
+        assert_eq!(detect_input_format(&late), InputFormat::Hocr);
+    }
+
+    #[test]
+    fn test_plain_text_and_fragments() {
+        assert_eq!(detect_input_format(""), InputFormat::Text);
+        assert_eq!(detect_input_format("1 < 2 and 3 > 2"), InputFormat::Text);
+        assert_eq!(detect_input_format("Intro text <b>bold</b>"), InputFormat::Html);
+        assert_eq!(detect_input_format("\u{feff}  <div>x</div>"), InputFormat::Html);
+    }
+}
@@ -1242,7 +1242,7 @@ fn is_confident_code_block(lines: &[CodeLineInfo]) -> bool {
             assignment_lines += 1;
         }
 
-        if info.x1 > min_x + 8 {
+        if info.x1 > min_x.saturating_add(8) {
             indent_lines += 1;
         }
     }
@@ -1314,6 +1314,11 @@ fn post_process_table(mut table: Vec<Vec<String>>) -> Option<Vec<Vec<String>>> {
     if table.is_empty() {
         return None;
     }
+    // Column merging below indexes every row by header position, so square up ragged rows.
+    let width = table.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut table {
+        row.resize(width, String::new());
+    }
 
     let mut non_empty = 0;
     let mut long_cells = 0;
@@ -1603,6 +1608,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_post_process_table_accepts_ragged_rows() {
+        let row = |cells: &[&str]| cells.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let table = vec![
+            row(&["Item", "Qty"]),
+            row(&["Nut"]),
+            row(&["Bolt", "10", "steel", "ok"]),
+            row(&["Pin", "123", "456", "789"]),
+        ];
+        let processed = post_process_table(table).expect("table is kept");
+        assert!(processed.iter().all(|row| row.len() == processed[0].len()));
+    }
+
     #[test]
     fn test_convert_paragraph_with_words() {
         let par = HocrElement {
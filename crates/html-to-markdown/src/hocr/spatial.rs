@@ -339,7 +339,7 @@ fn remove_empty_rows_and_columns(table: Vec<Vec<String>>) -> Vec<Vec<String>> {
         return table;
     }
 
-    let num_cols = table[0].len();
+    let num_cols = table.iter().map(Vec::len).max().unwrap_or(0);
     let mut non_empty_cols: Vec<bool> = vec![false; num_cols];
 
     for row in &table {
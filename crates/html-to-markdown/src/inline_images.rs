@@ -1,10 +1,46 @@
 #![allow(clippy::cast_precision_loss, clippy::cast_sign_loss, clippy::unused_self)]
 use std::collections::BTreeMap;
+use std::panic::RefUnwindSafe;
+use std::sync::Arc;
 
 use crate::error::ConversionError;
 
-/// Configuration for capturing inline images during conversion.
+/// Image bytes returned by an [`ImageFetcher`].
 #[derive(Debug, Clone)]
+pub struct FetchedImage {
+    /// Raw image data as bytes (encoded in its original format).
+    pub data: Vec<u8>,
+    /// MIME type reported by the source (e.g. `image/png`), if known.
+    pub mime_type: Option<String>,
+}
+
+/// Resolves remote `<img src>` URLs into image bytes.
+///
+/// Implementations may download the image, read it from a cache, or look it up in a
+/// user-provided store. Returning `Ok(None)` leaves the image out of the extraction;
+/// errors are reported as [`InlineImageWarning`]s and never abort the conversion.
+///
+/// Closures with the matching signature implement this trait automatically.
+pub trait ImageFetcher: Send + Sync + RefUnwindSafe {
+    /// Fetch the image referenced by `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable reason when the image could not be retrieved.
+    fn fetch(&self, url: &str) -> Result<Option<FetchedImage>, String>;
+}
+
+impl<F> ImageFetcher for F
+where
+    F: Fn(&str) -> Result<Option<FetchedImage>, String> + Send + Sync + RefUnwindSafe,
+{
+    fn fetch(&self, url: &str) -> Result<Option<FetchedImage>, String> {
+        self(url)
+    }
+}
+
+/// Configuration for capturing inline images during conversion.
+#[derive(Clone)]
 pub struct InlineImageConfig {
     /// Maximum allowed decoded size in bytes; larger payloads are rejected.
     pub max_decoded_size_bytes: u64,
@@ -14,6 +50,20 @@ pub struct InlineImageConfig {
     pub capture_svg: bool,
     /// Whether to decode raster images to infer dimensions (defaults to false).
     pub infer_dimensions: bool,
+    /// Optional fetcher used to collect remote `http(s)` images (defaults to none).
+    pub fetcher: Option<Arc<dyn ImageFetcher>>,
+}
+
+impl std::fmt::Debug for InlineImageConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InlineImageConfig")
+            .field("max_decoded_size_bytes", &self.max_decoded_size_bytes)
+            .field("filename_prefix", &self.filename_prefix)
+            .field("capture_svg", &self.capture_svg)
+            .field("infer_dimensions", &self.infer_dimensions)
+            .field("fetcher", &self.fetcher.as_ref().map(|_| "<fetcher>"))
+            .finish()
+    }
 }
 
 /// Default maximum size for inline image extraction (5 MB).
@@ -47,9 +97,17 @@ impl InlineImageConfig {
             filename_prefix: None,
             capture_svg: true,
             infer_dimensions: false,
+            fetcher: None,
         }
     }
 
+    /// Attach a fetcher so remote `<img src="https://...">` images are collected too.
+    #[must_use]
+    pub fn with_fetcher(mut self, fetcher: impl ImageFetcher + 'static) -> Self {
+        self.fetcher = Some(Arc::new(fetcher));
+        self
+    }
+
     /// Apply a partial update to this inline image configuration.
     ///
     /// Any specified fields in the update will override the current values.
@@ -133,6 +191,8 @@ pub enum InlineImageSource {
     ImgDataUri,
     /// Image sourced from an inline `<svg>` element.
     SvgElement,
+    /// Image sourced from a remote `<img>` URL resolved by an [`ImageFetcher`].
+    ImgRemote,
 }
 
 impl std::fmt::Display for InlineImageSource {
@@ -140,6 +200,7 @@ impl std::fmt::Display for InlineImageSource {
         match self {
             Self::ImgDataUri => write!(f, "img_data_uri"),
             Self::SvgElement => write!(f, "svg_element"),
+            Self::ImgRemote => write!(f, "img_remote"),
         }
     }
 }
@@ -157,7 +218,7 @@ pub struct InlineImage {
     pub description: Option<String>,
     /// Image dimensions in pixels (width, height); only present if inferred.
     pub dimensions: Option<(u32, u32)>,
-    /// Where the image originated (data URI or SVG element).
+    /// Where the image originated (data URI, SVG element or remote URL).
     pub source: InlineImageSource,
     /// Additional HTML attributes from the source element.
     pub attributes: BTreeMap<String, String>,
@@ -225,6 +286,10 @@ impl InlineImageCollector {
         self.config.infer_dimensions
     }
 
+    pub(crate) fn fetcher(&self) -> Option<Arc<dyn ImageFetcher>> {
+        self.config.fetcher.clone()
+    }
+
     pub(crate) const fn max_decoded_size(&self) -> u64 {
         self.config.max_decoded_size_bytes
     }
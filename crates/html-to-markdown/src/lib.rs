@@ -25,40 +25,216 @@
 //!
 //! Enable the `inline-images` Cargo feature to collect embedded data URI images and inline SVG
 //! assets alongside the produced Markdown.
+//!
+//! ## Parallel batch conversion
+//!
+//! Enable the `parallel` Cargo feature for [`convert_parallel`], which converts many documents
+//! at once on an internal rayon thread pool.
+//!
+//! ## Caching
+//!
+//! [`cache::CachedConverter`] skips conversions it has already done, looking results up in a
+//! [`cache::ConversionCache`] keyed by the input, the options and the crate version. Use
+//! [`cache::FsCache`] to keep results across runs of a build pipeline.
+//!
+//! ## Snapshot testing
+//!
+//! The `test-util` Cargo feature adds [`test_util`], which checks a directory of HTML fixtures
+//! against committed Markdown snapshots.
+//!
+//! ## Web archives
+//!
+//! The `warc` Cargo feature adds [`warc`], which reads HTML responses out of WARC archives
+//! (optionally gzip-compressed) and converts each one with its target URI and capture date.
+//!
+//! ## Fetching URLs
+//!
+//! The `fetch` Cargo feature adds [`fetch`], a blocking HTTP fetcher with retries, per-host
+//! politeness, cookies and conditional requests. The CLI uses it for `--url`.
+//!
+//! ## Bug reports
+//!
+//! The `repro` Cargo feature adds [`repro`], which packages a conversion's input bytes,
+//! options, crate version and output into one archive that can be replayed elsewhere. The CLI
+//! writes one with `--capture-repro`.
+//!
+//! ## Character sets
+//!
+//! The `encoding` Cargo feature adds [`encoding`], which decodes HTML bytes from a file or a
+//! response using the BOM, a declared charset or `<meta charset>`, for callers that read
+//! documents themselves.
+//!
+//! ## Thread safety
+//!
+//! Every conversion builds its own parser and state, and the crate keeps no mutable global
+//! state apart from the lazily created `parallel` pool, so any number of conversions can run
+//! concurrently on different threads.
+//!
+//! Options, configuration, results and errors are `Send + Sync` (enforced at compile time):
+//! [`ConversionOptions`], [`ConversionOptionsUpdate`], [`ConversionOptionsBuilder`],
+//! [`PreprocessingOptions`], [`PostProcessorChain`], [`TokenEstimator`], [`ConversionError`],
+//! [`Diagnostic`], [`ConversionStats`], the hOCR types, the metadata
+//! types such as `MetadataConfig` and `ExtendedMetadata`, the chunking types `ChunkConfig` and
+//! `MarkdownChunk`, and the inline image types such as
+//! `InlineImageConfig` and `HtmlExtraction`. A configured value can be shared by reference
+//! across a worker pool.
+//!
+//! Visitor handles (`VisitorHandle`, `AsyncVisitorHandle`) are `Rc<RefCell<_>>` and therefore
+//! neither `Send` nor `Sync`: create one per thread, next to the conversion that uses it.
 use std::borrow::Cow;
 
+pub mod boilerplate;
+pub mod cache;
+#[cfg(feature = "metadata")]
+pub mod chunk;
+mod code_language;
 pub mod converter;
+pub mod diagnostics;
+mod documents;
+#[cfg(feature = "encoding")]
+pub mod encoding;
 pub mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+mod fingerprint;
+mod footnotes;
+pub mod format;
 pub mod hocr;
 #[cfg(feature = "inline-images")]
 mod inline_images;
+mod main_content;
+mod math;
 #[cfg(feature = "metadata")]
 pub mod metadata;
 pub mod options;
+#[cfg(feature = "parallel")]
+mod parallel;
+pub mod postprocess;
+mod print_styles;
+pub mod progress;
+#[cfg(feature = "repro")]
+pub mod repro;
+#[cfg(feature = "metadata")]
+mod resources;
 pub mod safety;
+#[cfg(any(feature = "serde", feature = "metadata"))]
+mod schema;
+mod section;
+mod selector;
+mod shadow_dom;
+#[cfg(feature = "metadata")]
+mod source_map;
+mod srcset;
+pub mod stats;
+#[cfg(any(feature = "serde", feature = "metadata"))]
+mod strict_json;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 pub mod text;
+pub mod trace;
+pub mod truncate;
+mod typography;
 #[cfg(feature = "visitor")]
 pub mod visitor;
+#[cfg(feature = "async-visitor")]
+mod visitor_async;
 #[cfg(feature = "visitor")]
 pub mod visitor_helpers;
+#[cfg(feature = "warc")]
+pub mod warc;
 #[cfg(feature = "async-visitor")]
 pub use visitor_helpers::AsyncVisitorHandle;
 pub mod wrapper;
 
+pub use boilerplate::{BoilerplateConfig, BoilerplateReport};
+#[cfg(feature = "metadata")]
+pub use chunk::{ChunkConfig, DEFAULT_CHUNK_MAX_CHARS, MarkdownChunk};
+pub use diagnostics::{Diagnostic, DiagnosticKind, DroppedContent};
 pub use error::{ConversionError, Result};
+pub use fingerprint::content_fingerprint;
+pub use format::{InputFormat, detect_input_format};
 #[cfg(feature = "inline-images")]
 pub use inline_images::{
-    DEFAULT_INLINE_IMAGE_LIMIT, HtmlExtraction, InlineImage, InlineImageConfig, InlineImageConfigUpdate,
-    InlineImageFormat, InlineImageSource, InlineImageWarning,
+    DEFAULT_INLINE_IMAGE_LIMIT, FetchedImage, HtmlExtraction, ImageFetcher, InlineImage, InlineImageConfig,
+    InlineImageConfigUpdate, InlineImageFormat, InlineImageSource, InlineImageWarning,
 };
 #[cfg(feature = "metadata")]
 pub use metadata::{
-    DEFAULT_MAX_STRUCTURED_DATA_SIZE, DocumentMetadata, ExtendedMetadata, HeaderMetadata, ImageMetadata, ImageType,
-    LinkMetadata, LinkType, MetadataConfig, MetadataConfigUpdate, StructuredData, StructuredDataType, TextDirection,
+    AuthorProfile, DEFAULT_MAX_STRUCTURED_DATA_SIZE, DocumentMetadata, ExtendedMetadata, HeaderMetadata, ImageMetadata,
+    ImageType, LinkMetadata, LinkType, MetadataConfig, MetadataConfigUpdate, ProfileSource, ResourceMetadata,
+    ResourceType, SourceMapEntry, StructuredData, StructuredDataType, TextDirection,
 };
 pub use options::{
-    CodeBlockStyle, ConversionOptions, ConversionOptionsUpdate, HeadingStyle, HighlightStyle, ListIndentType,
-    NewlineStyle, PreprocessingOptions, PreprocessingOptionsUpdate, PreprocessingPreset, WhitespaceMode,
+    AbbrStyle, BidiMode, CodeBlockStyle, ContactLinkStyle, ConversionOptions, ConversionOptionsBuilder,
+    ConversionOptionsUpdate, ConversionPreset, DetailsStyle, DfnStyle, FigureStyle, FrontMatterFormat,
+    HeaderBreakStyle, HeadingStyle, HighlightStyle, ListIndentType, ListSpacing, MathStyle, NbspMode, NewlineStyle,
+    OptionViolation, PreprocessingOptions, PreprocessingOptionsUpdate, PreprocessingPreset, SrcsetPolicy, SvgTextMode,
+    TimeStyle, TitleSource, UnrepresentablePolicy, WhitespaceMode, option_char,
+};
+#[cfg(feature = "parallel")]
+pub use parallel::convert_parallel;
+pub use postprocess::{BuiltinPostProcessor, DEFAULT_URL_PARAM_DENYLIST, PostProcessorChain};
+pub use progress::{ProgressEvent, ProgressInterval};
+pub use stats::ConversionStats;
+pub use text::{Escaping, decode_html_entities, escape_with, normalize_text, normalize_whitespace};
+pub use truncate::{TRUNCATION_MARKER, TokenEstimator};
+
+// Binding authors rely on these being shareable across worker threads; keep this list in sync
+// with the "Thread safety" section above.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<ConversionOptions>();
+    assert_send_sync::<ConversionOptionsUpdate>();
+    assert_send_sync::<ConversionOptionsBuilder>();
+    assert_send_sync::<PreprocessingOptions>();
+    assert_send_sync::<PreprocessingOptionsUpdate>();
+    assert_send_sync::<PostProcessorChain>();
+    assert_send_sync::<TokenEstimator>();
+    assert_send_sync::<ConversionError>();
+    assert_send_sync::<Diagnostic>();
+    assert_send_sync::<ConversionStats>();
+    assert_send_sync::<hocr::HocrElement>();
+    #[cfg(feature = "metadata")]
+    {
+        assert_send_sync::<MetadataConfig>();
+        assert_send_sync::<MetadataConfigUpdate>();
+        assert_send_sync::<ExtendedMetadata>();
+        assert_send_sync::<ChunkConfig>();
+        assert_send_sync::<MarkdownChunk>();
+    }
+    #[cfg(feature = "inline-images")]
+    {
+        assert_send_sync::<InlineImageConfig>();
+        assert_send_sync::<InlineImageConfigUpdate>();
+        assert_send_sync::<HtmlExtraction>();
+        assert_send_sync::<InlineImage>();
+    }
+    #[cfg(feature = "visitor")]
+    {
+        assert_send_sync::<visitor::NodeContext>();
+        assert_send_sync::<visitor::VisitResult>();
+    }
+    #[cfg(feature = "test-util")]
+    assert_send_sync::<test_util::GoldenCorpus>();
+    #[cfg(feature = "warc")]
+    {
+        assert_send_sync::<warc::WarcRecord>();
+        assert_send_sync::<warc::WarcDocument>();
+    }
+    #[cfg(feature = "repro")]
+    {
+        assert_send_sync::<repro::ReproBundle>();
+        assert_send_sync::<repro::ReproReplay>();
+    }
+    assert_send_sync::<cache::CachedConverter<cache::FsCache>>();
+    assert_send_sync::<cache::MemoryCache>();
+    #[cfg(feature = "fetch")]
+    {
+        assert_send_sync::<fetch::Fetcher>();
+        assert_send_sync::<fetch::FetchState>();
+        assert_send_sync::<fetch::FetchError>();
+    }
 };
 
 const BINARY_SCAN_LIMIT: usize = 8192;
@@ -185,13 +361,21 @@ fn fast_text_only(html: &str, options: &ConversionOptions) -> Option<String> {
     if options.strip_newlines && (decoded.contains('\n') || decoded.contains('\r')) {
         decoded = Cow::Owned(decoded.replace(&['\r', '\n'][..], " "));
     }
+    if converter::strips_invisible_formatting(options) {
+        if let Cow::Owned(stripped) = text::remove_soft_hyphens_cow(decoded.as_ref()) {
+            decoded = Cow::Owned(stripped);
+        }
+    }
     let trimmed = decoded.trim_end_matches('\n');
     if trimmed.is_empty() {
         return Some(String::new());
     }
 
     let normalized = if options.whitespace_mode == WhitespaceMode::Normalized {
-        text::normalize_whitespace_cow(trimmed)
+        match options.nbsp_in_prose {
+            NbspMode::Space => text::normalize_whitespace_cow(trimmed),
+            NbspMode::Preserve => text::normalize_whitespace_keep_nbsp_cow(trimmed),
+        }
     } else {
         Cow::Borrowed(trimmed)
     };
@@ -241,8 +425,30 @@ fn parse_json<T: serde::de::DeserializeOwned>(json: &str) -> Result<T> {
 ///
 /// Returns `ConversionError::ConfigError` if JSON parsing fails or contains invalid option values
 pub fn conversion_options_from_json(json: &str) -> Result<ConversionOptions> {
-    let update: ConversionOptionsUpdate = parse_json(json)?;
-    Ok(ConversionOptions::from(update))
+    parse_json(json)
+}
+
+#[cfg(any(feature = "serde", feature = "metadata"))]
+/// JSON Schema (draft 2020-12) for the JSON form of `ConversionOptions`.
+///
+/// Describes every option with its type, allowed values, default and a short description, in
+/// the camelCase spelling accepted by [`conversion_options_from_json`]. Bindings and config file
+/// loaders can use it to validate options before handing them to the converter, or to drive
+/// editor completion.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::conversion_options_json_schema;
+///
+/// let schema = conversion_options_json_schema();
+/// let heading_style = &schema["properties"]["headingStyle"];
+/// assert_eq!(heading_style["default"], "atx");
+/// assert_eq!(heading_style["enum"][2], "atx_closed");
+/// ```
+#[must_use]
+pub fn conversion_options_json_schema() -> serde_json::Value {
+    schema::conversion_options_schema()
 }
 
 #[cfg(any(feature = "serde", feature = "metadata"))]
@@ -330,24 +536,486 @@ pub fn metadata_config_from_json(json: &str) -> Result<MetadataConfig> {
 ///
 /// Returns an error if HTML parsing fails or if the input contains invalid UTF-8.
 pub fn convert(html: &str, options: Option<ConversionOptions>) -> Result<String> {
+    convert_with_options(html, &options.unwrap_or_default())
+}
+
+/// Convert HTML to Markdown, writing the result into `writer`.
+///
+/// Produces the same Markdown as [`convert`], but hands it to the writer a few top-level
+/// blocks at a time instead of building one string for the whole document. Post-processors,
+/// `wrap`, `split_documents` and the output budgets need the complete output, so with any of
+/// them set the Markdown is written in one piece at the end. The writer is not flushed.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::convert_to_writer;
+///
+/// let mut out = Vec::new();
+/// convert_to_writer("<h1>Title</h1><p>Body</p>", None, &mut out).unwrap();
+/// assert_eq!(out, b"# Title\n\nBody\n");
+/// ```
+/// # Errors
+///
+/// Returns the errors of [`convert`], and [`ConversionError::IoError`] if writing fails.
+/// Markdown written before a write error is not taken back.
+pub fn convert_to_writer<W: std::io::Write + ?Sized>(
+    html: &str,
+    options: Option<ConversionOptions>,
+    writer: &mut W,
+) -> Result<()> {
+    convert_into(html, &options.unwrap_or_default(), &mut |chunk| {
+        writer.write_all(chunk.as_bytes()).map_err(Into::into)
+    })
+}
+
+/// Convert HTML to Markdown, appending the result to a [`std::fmt::Write`] sink.
+///
+/// The `fmt` counterpart of [`convert_to_writer`], for appending to a `String` or writing
+/// into a `Formatter` from a `Display` implementation.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::convert_to_fmt;
+///
+/// let mut page = String::from("<!-- generated -->\n");
+/// convert_to_fmt("<p>Hello</p>", None, &mut page).unwrap();
+/// assert_eq!(page, "<!-- generated -->\nHello\n");
+/// ```
+/// # Errors
+///
+/// Returns the errors of [`convert`], and [`ConversionError::IoError`] if the sink reports
+/// an error.
+pub fn convert_to_fmt<W: std::fmt::Write + ?Sized>(
+    html: &str,
+    options: Option<ConversionOptions>,
+    writer: &mut W,
+) -> Result<()> {
+    convert_into(html, &options.unwrap_or_default(), &mut |chunk| {
+        writer.write_str(chunk).map_err(|err| std::io::Error::other(err).into())
+    })
+}
+
+fn convert_into(html: &str, options: &ConversionOptions, write: &mut dyn FnMut(&str) -> Result<()>) -> Result<()> {
+    if options.wrap
+        || !options.post_processors.is_empty()
+        || options.split_documents
+        || options.max_output_chars.is_some()
+        || options.max_output_tokens.is_some()
+    {
+        return write(&convert_with_options(html, options)?);
+    }
+    validate_input(html)?;
+    let normalized_html = normalize_line_endings(html);
+    if let Some(markdown) = fast_text_only(normalized_html.as_ref(), options) {
+        return write(&markdown);
+    }
+    converter::convert_html_into(normalized_html.as_ref(), options, write)
+}
+
+/// Convert only the section of a document introduced by one heading.
+///
+/// `heading` names the heading by its `id` (`"install"` or `"#install"`), by its text
+/// (`"Getting started"`, compared case-insensitively) or by a CSS selector (`"h2.api"`),
+/// tried in that order. The section runs from that heading up to the next heading of the
+/// same or a higher level, or to the end of the document, and is converted with `options`.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::convert_section;
+///
+/// let html = "<h2 id=\"a\">A</h2><p>one</p><h3>A.1</h3><p>two</p><h2>B</h2><p>three</p>";
+/// let markdown = convert_section(html, "#a", None).unwrap();
+/// assert_eq!(markdown, "## A\n\none\n\n### A.1\n\ntwo\n");
+/// ```
+/// # Errors
+///
+/// Returns [`ConversionError::InvalidInput`] if no heading matches `heading`, and the same
+/// errors as [`convert`] otherwise.
+pub fn convert_section(html: &str, heading: &str, options: Option<ConversionOptions>) -> Result<String> {
     validate_input(html)?;
+    let section = section::extract_section(html, heading)
+        .ok_or_else(|| ConversionError::InvalidInput(format!("no heading matches {heading:?}")))?;
+    convert_with_options(section.as_ref(), &options.unwrap_or_default())
+}
+
+/// Convert a batch of HTML documents that share one set of options.
+///
+/// Documents are converted independently and results are returned in input order, so a
+/// malformed document only fails its own slot. A panic while converting one document is
+/// reported as that document's [`ConversionError::Panic`].
+///
+/// Documents are converted one after another on the calling thread; with the `parallel`
+/// feature, `convert_parallel` spreads the same work over several threads.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::convert_many;
+///
+/// let results = convert_many(&["<h1>One</h1>", "<p>Two</p>"], None);
+/// assert_eq!(results[0].as_deref().unwrap(), "# One\n");
+/// assert_eq!(results[1].as_deref().unwrap(), "Two\n");
+/// ```
+pub fn convert_many<S: AsRef<str>>(documents: &[S], options: Option<ConversionOptions>) -> Vec<Result<String>> {
+    let options = options.unwrap_or_default();
+    documents
+        .iter()
+        .map(|html| {
+            let html = html.as_ref();
+            safety::guard_panic(|| convert_with_options(html, &options))
+        })
+        .collect()
+}
+
+/// Convert a batch of HTML documents and remove the blocks most of them share.
+///
+/// Works like [`convert_many`], then runs [`boilerplate::strip_boilerplate`] over the documents
+/// that converted successfully, so a site footer or signup form repeated on every page of a
+/// crawl is dropped. The report lists what was removed.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::{BoilerplateConfig, convert_many_deduplicated};
+///
+/// let pages: Vec<String> = (1..=3)
+///     .map(|i| format!("<h1>Page {i}</h1><footer><p>© Example Corp</p></footer>"))
+///     .collect();
+/// let (results, report) = convert_many_deduplicated(&pages, None, &BoilerplateConfig::default());
+///
+/// assert_eq!(results[0].as_deref().unwrap(), "# Page 1\n");
+/// assert_eq!(report.blocks[0].text, "© Example Corp");
+/// ```
+pub fn convert_many_deduplicated<S: AsRef<str>>(
+    documents: &[S],
+    options: Option<ConversionOptions>,
+    config: &BoilerplateConfig,
+) -> (Vec<Result<String>>, BoilerplateReport) {
+    let mut results = convert_many(documents, options);
+    let report = boilerplate::strip_results(&mut results, config);
+    (results, report)
+}
+
+/// Convert HTML to Markdown and return the diagnostics recorded along the way.
+///
+/// Diagnostics are only recorded for categories the options opt into:
+/// [`UnrepresentablePolicy::Diagnose`], a `fidelity_threshold` below `1.0` and
+/// `report_dropped_content`. With the default options the list is empty.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::{ConversionOptions, UnrepresentablePolicy, convert_with_diagnostics};
+///
+/// let options = ConversionOptions {
+///     unrepresentable_policy: UnrepresentablePolicy::Diagnose,
+///     ..Default::default()
+/// };
+/// let (markdown, diagnostics) =
+///     convert_with_diagnostics("<p>long<wbr>word</p>", Some(options)).unwrap();
+/// assert_eq!(markdown, "longword\n");
+/// assert_eq!(diagnostics[0].element.as_deref(), Some("wbr"));
+/// ```
+/// # Errors
+///
+/// Returns an error if HTML parsing fails or if the input contains invalid UTF-8.
+pub fn convert_with_diagnostics(html: &str, options: Option<ConversionOptions>) -> Result<(String, Vec<Diagnostic>)> {
     let options = options.unwrap_or_default();
 
+    let mut diagnostics = Vec::new();
+    let split = convert_documents(html, &options, |document, options| {
+        let (markdown, found) = convert_document_with_diagnostics(document, options)?;
+        diagnostics.extend(found);
+        Ok(markdown)
+    });
+    if let Some(markdown) = split {
+        return Ok((markdown?, diagnostics));
+    }
+    convert_document_with_diagnostics(html, &options)
+}
+
+fn convert_document_with_diagnostics(html: &str, options: &ConversionOptions) -> Result<(String, Vec<Diagnostic>)> {
+    validate_input(html)?;
+
+    let normalized_html = normalize_line_endings(html);
+    let (markdown, diagnostics) = converter::convert_html_with_diagnostics(normalized_html.as_ref(), options)?;
+
+    Ok((finalize_markdown(markdown, options)?, diagnostics))
+}
+
+/// Convert HTML to Markdown and report the resources the conversion used.
+///
+/// The report is only collected when [`ConversionOptions::collect_stats`] is set; otherwise this
+/// behaves like [`convert`] and returns `None` in its place.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::{ConversionOptions, convert_with_stats};
+///
+/// let options = ConversionOptions {
+///     collect_stats: true,
+///     ..Default::default()
+/// };
+/// let (markdown, stats) = convert_with_stats("<h1>Hi</h1><p>there</p>", Some(options)).unwrap();
+/// let stats = stats.unwrap();
+/// assert_eq!(stats.output_bytes, markdown.len());
+/// assert!(stats.nodes_parsed >= 4);
+/// ```
+/// # Errors
+///
+/// Returns an error if HTML parsing fails or if the input contains invalid UTF-8.
+pub fn convert_with_stats(html: &str, options: Option<ConversionOptions>) -> Result<(String, Option<ConversionStats>)> {
+    let options = options.unwrap_or_default();
+    if !options.collect_stats {
+        return convert_with_options(html, &options).map(|markdown| (markdown, None));
+    }
+
+    let mut stats = ConversionStats {
+        input_bytes: html.len(),
+        ..ConversionStats::default()
+    };
+    let split = convert_documents(html, &options, |document, options| {
+        let (markdown, document_stats) = convert_document_with_stats(document, options)?;
+        stats.add_document(&document_stats);
+        Ok(markdown)
+    });
+    let markdown = if let Some(markdown) = split {
+        markdown?
+    } else {
+        let (markdown, document_stats) = convert_document_with_stats(html, &options)?;
+        stats.add_document(&document_stats);
+        markdown
+    };
+    stats.output_bytes = markdown.len();
+    stats.note_memory(html.len() + markdown.len());
+
+    Ok((markdown, Some(stats)))
+}
+
+fn convert_document_with_stats(html: &str, options: &ConversionOptions) -> Result<(String, ConversionStats)> {
+    validate_input(html)?;
+    let mut stats = ConversionStats::default();
+
+    let normalized_html = normalize_line_endings(html);
+    let fast_path = if options.wrap {
+        None
+    } else {
+        let mut stopwatch = stats::Stopwatch::start();
+        fast_text_only(normalized_html.as_ref(), options).inspect(|_| stats.convert_time = stopwatch.lap())
+    };
+    let markdown = match fast_path {
+        Some(markdown) => markdown,
+        None => converter::convert_html_with_stats(normalized_html.as_ref(), options, &mut stats)?,
+    };
+
+    let converted_len = markdown.len();
+    let mut stopwatch = stats::Stopwatch::start();
+    let markdown = finalize_markdown(markdown, options)?;
+    stats.postprocess_time = stopwatch.lap();
+    stats.note_memory(html.len() + converted_len + markdown.len());
+
+    Ok((markdown, stats))
+}
+
+/// Convert HTML to Markdown and fingerprint the result.
+///
+/// The fingerprint is only computed when [`ConversionOptions::content_fingerprint`] is set;
+/// otherwise this behaves like [`convert`] and returns `None` in its place. It is the
+/// [`content_fingerprint`] of the Markdown without the front matter emitted by
+/// `extract_metadata`, so documents that differ only in whitespace, markup or link targets
+/// share it.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::{ConversionOptions, convert_with_fingerprint};
+///
+/// let options = ConversionOptions {
+///     content_fingerprint: true,
+///     ..Default::default()
+/// };
+/// let (_, original) = convert_with_fingerprint("<p>Hello <b>world</b></p>", Some(options.clone())).unwrap();
+/// let (_, mirror) = convert_with_fingerprint("<div>Hello\n  <strong>world</strong></div>", Some(options)).unwrap();
+/// assert_eq!(original, mirror);
+/// ```
+/// # Errors
+///
+/// Returns an error if HTML parsing fails or if the input contains invalid UTF-8.
+pub fn convert_with_fingerprint(html: &str, options: Option<ConversionOptions>) -> Result<(String, Option<String>)> {
+    let options = options.unwrap_or_default();
+    let markdown = convert_with_options(html, &options)?;
+    if !options.content_fingerprint {
+        return Ok((markdown, None));
+    }
+
+    let body_start = if options.extract_metadata {
+        postprocess::front_matter_len(&markdown)
+    } else {
+        0
+    };
+    let fingerprint = content_fingerprint(&markdown[body_start..]);
+    Ok((markdown, Some(fingerprint)))
+}
+
+/// Convert HTML to Markdown, calling `callback` as the conversion advances.
+///
+/// The callback runs on the calling thread: once after parsing with nothing processed yet, at
+/// each `interval` during the walk, and once more when the Markdown is complete, with
+/// [`ProgressEvent::is_done`] set. `interval` is a [`ProgressInterval`]; a bare `usize` counts
+/// DOM nodes. See [`progress`] for what the byte counts measure.
+///
+/// # Example
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use html_to_markdown_rs::convert_with_progress;
+///
+/// let events = Rc::new(RefCell::new(Vec::new()));
+/// let sink = Rc::clone(&events);
+/// let markdown = convert_with_progress("<h1>Title</h1><p>Body</p>", None, 1, move |event| {
+///     sink.borrow_mut().push(event.fraction());
+/// })
+/// .unwrap();
+///
+/// assert_eq!(markdown, "# Title\n\nBody\n");
+/// let events = events.borrow();
+/// assert_eq!(events.first(), Some(&0.0));
+/// assert_eq!(events.last(), Some(&1.0));
+/// ```
+/// # Errors
+///
+/// Returns an error if HTML parsing fails or if the input contains invalid UTF-8.
+pub fn convert_with_progress<F>(
+    html: &str,
+    options: Option<ConversionOptions>,
+    interval: impl Into<ProgressInterval>,
+    callback: F,
+) -> Result<String>
+where
+    F: Fn(ProgressEvent) + 'static,
+{
+    let options = options.unwrap_or_default();
+    let callback: std::rc::Rc<dyn Fn(ProgressEvent)> = std::rc::Rc::new(callback);
+    let interval = interval.into();
+
+    let documents = progress::SplitProgress::new(std::rc::Rc::clone(&callback), interval, html.len());
+    let split = convert_documents(html, &options, |document, options| {
+        convert_document_with_progress(document, options, &documents.document(document.len()))
+    });
+    if let Some(markdown) = split {
+        return markdown;
+    }
+    let tracker = progress::ProgressTracker::new(callback, interval);
+    convert_document_with_progress(html, &options, &std::rc::Rc::new(tracker))
+}
+
+fn convert_document_with_progress(
+    html: &str,
+    options: &ConversionOptions,
+    tracker: &std::rc::Rc<progress::ProgressTracker>,
+) -> Result<String> {
+    validate_input(html)?;
+
+    let normalized_html = normalize_line_endings(html);
+    let markdown =
+        converter::convert_html_with_progress(normalized_html.as_ref(), options, std::rc::Rc::clone(tracker))?;
+    let markdown = finalize_markdown(markdown, options)?;
+    tracker.finish();
+    Ok(markdown)
+}
+
+/// Convert HTML to Markdown with borrowed options.
+///
+/// Same as [`convert`], for callers that keep one set of options around for many documents
+/// (an options handle in a binding, a server's configuration) and would otherwise clone it
+/// for every call.
+///
+/// # Errors
+///
+/// Returns an error if HTML parsing fails or if the input contains invalid UTF-8.
+pub fn convert_with_options(html: &str, options: &ConversionOptions) -> Result<String> {
+    convert_documents(html, options, convert_document).unwrap_or_else(|| convert_document(html, options))
+}
+
+/// With `split_documents` set and more than one document in `html`, convert each document with
+/// `convert_one` and join the results. Returns `None` for input that is converted as a whole.
+fn convert_documents(
+    html: &str,
+    options: &ConversionOptions,
+    mut convert_one: impl FnMut(&str, &ConversionOptions) -> Result<String>,
+) -> Option<Result<String>> {
+    if !options.split_documents {
+        return None;
+    }
+    let documents = documents::split_documents(html);
+    if documents.len() < 2 {
+        return None;
+    }
+    // The budgets apply to the joined output, not to each document.
+    let unbounded = ConversionOptions {
+        max_output_chars: None,
+        max_output_tokens: None,
+        ..options.clone()
+    };
+    let markdown = join_documents(documents.iter().map(|document| convert_one(document, &unbounded)));
+    Some(markdown.map(|markdown| truncate::truncate_markdown(markdown, options)))
+}
+
+/// Join separately converted documents with thematic breaks, leaving out empty ones.
+fn join_documents(documents: impl Iterator<Item = Result<String>>) -> Result<String> {
+    let mut markdown = String::new();
+    for document in documents {
+        let document = document?;
+        let document = document.trim_end_matches('\n');
+        if document.trim().is_empty() {
+            continue;
+        }
+        if !markdown.is_empty() {
+            markdown.push_str("\n\n---\n\n");
+        }
+        markdown.push_str(document);
+    }
+    if !markdown.is_empty() {
+        markdown.push('\n');
+    }
+    Ok(markdown)
+}
+
+fn convert_document(html: &str, options: &ConversionOptions) -> Result<String> {
+    validate_input(html)?;
+
     let normalized_html = normalize_line_endings(html);
 
     if !options.wrap {
-        if let Some(markdown) = fast_text_only(normalized_html.as_ref(), &options) {
-            return Ok(markdown);
+        if let Some(markdown) = fast_text_only(normalized_html.as_ref(), options) {
+            return finalize_markdown(markdown, options);
         }
     }
 
-    let markdown = converter::convert_html(normalized_html.as_ref(), &options)?;
+    let markdown = converter::convert_html(normalized_html.as_ref(), options)?;
+    finalize_markdown(markdown, options)
+}
 
-    if options.wrap {
-        Ok(wrapper::wrap_markdown(&markdown, &options))
+/// Run the configured post-processors, wrap lines if requested, then apply the output budgets.
+fn finalize_markdown(markdown: String, options: &ConversionOptions) -> Result<String> {
+    let _span = trace::span("phase", || std::borrow::Cow::Borrowed("postprocess"));
+    let markdown = if options.post_processors.is_empty() {
+        markdown
     } else {
-        Ok(markdown)
-    }
+        options.post_processors.run(markdown, options)?
+    };
+
+    let markdown = if options.wrap {
+        wrapper::wrap_markdown(&markdown, options)
+    } else {
+        markdown
+    };
+    Ok(truncate::truncate_markdown(markdown, options))
 }
 
 /// Convert HTML to Markdown while collecting inline image assets (requires the `inline-images` feature).
@@ -392,11 +1060,7 @@ pub fn convert_with_inline_images(
     #[cfg(not(feature = "visitor"))]
     let markdown = converter::convert_html_impl(normalized_html.as_ref(), &options, Some(Rc::clone(&collector)), None)?;
 
-    let markdown = if options.wrap {
-        wrapper::wrap_markdown(&markdown, &options)
-    } else {
-        markdown
-    };
+    let markdown = finalize_markdown(markdown, &options)?;
 
     let collector = Rc::try_unwrap(collector)
         .map_err(|_| ConversionError::Other("failed to recover inline image state".to_string()))?
@@ -435,6 +1099,7 @@ pub fn convert_with_inline_images(
 ///   - `links`: Hyperlinks classified as anchor, internal, external, email, or phone
 ///   - `images`: Image elements with source, dimensions, and alt text
 ///   - `structured_data`: JSON-LD, Microdata, and `RDFa` blocks
+///   - `source_map`: HTML and Markdown byte ranges of each emitted block (with `extract_source_map`)
 ///
 /// # Errors
 ///
@@ -489,6 +1154,8 @@ pub fn convert_with_inline_images(
 ///     extract_links: false,
 ///     extract_images: false,
 ///     extract_structured_data: false,
+///     extract_resources: false,
+///     extract_source_map: false,
 ///     max_structured_data_size: 0,
 /// };
 ///
@@ -547,11 +1214,7 @@ pub fn convert_with_metadata(
         let markdown = converter::convert_html_impl(normalized_html.as_ref(), &options, None, None, visitor)?;
         #[cfg(not(feature = "visitor"))]
         let markdown = converter::convert_html_impl(normalized_html.as_ref(), &options, None, None, None)?;
-        let markdown = if options.wrap {
-            wrapper::wrap_markdown(&markdown, &options)
-        } else {
-            markdown
-        };
+        let markdown = finalize_markdown(markdown, &options)?;
         return Ok((markdown, ExtendedMetadata::default()));
     }
 
@@ -576,20 +1239,58 @@ pub fn convert_with_metadata(
         None,
     )?;
 
-    let markdown = if options.wrap {
-        wrapper::wrap_markdown(&markdown, &options)
-    } else {
-        markdown
-    };
+    let markdown = finalize_markdown(markdown, &options)?;
 
-    let metadata_collector = Rc::try_unwrap(metadata_collector)
+    let mut metadata_collector = Rc::try_unwrap(metadata_collector)
         .map_err(|_| ConversionError::Other("failed to recover metadata state".to_string()))?
         .into_inner();
+    if metadata_collector.wants_source_map() {
+        if matches!(normalized_html, Cow::Owned(_)) {
+            metadata_collector.remap_source_offsets(&source_map::OffsetMap::for_line_endings(html));
+        }
+        metadata_collector.resolve_source_map(&markdown);
+    }
     let metadata = metadata_collector.finish();
 
     Ok((markdown, metadata))
 }
 
+/// Convert HTML to Markdown and cut the result into chunks for embedding (requires the `metadata` feature).
+///
+/// Chunks end between top-level blocks, so paragraphs, lists, tables and code blocks are never
+/// split. With `split_on_headings` every heading starts a new chunk; otherwise blocks are packed
+/// up to `max_chars`. Each chunk carries the text of the headings it sits under, collected by the
+/// same header extraction [`convert_with_metadata`] uses. See [`chunk`] for an example.
+///
+/// # Errors
+///
+/// Returns `ConversionError::ConfigError` if `config.max_chars` is 0, and otherwise the same
+/// errors as [`convert_with_metadata`].
+#[cfg(feature = "metadata")]
+pub fn convert_chunked(
+    html: &str,
+    options: Option<ConversionOptions>,
+    config: ChunkConfig,
+) -> Result<Vec<MarkdownChunk>> {
+    if config.max_chars == 0 {
+        return Err(ConversionError::ConfigError(
+            "max_chars must be greater than 0".to_string(),
+        ));
+    }
+    let metadata_cfg = MetadataConfig {
+        extract_document: false,
+        extract_headers: true,
+        extract_links: false,
+        extract_images: false,
+        extract_structured_data: false,
+        extract_resources: false,
+        extract_source_map: false,
+        max_structured_data_size: 0,
+    };
+    let (markdown, metadata) = convert_with_metadata(html, options, metadata_cfg, None)?;
+    Ok(chunk::chunk_markdown(&markdown, &metadata.headers, &config))
+}
+
 /// Convert HTML to Markdown with a custom visitor callback.
 ///
 /// This function allows you to provide a visitor implementation that can inspect,
@@ -641,11 +1342,7 @@ pub fn convert_with_visitor(
 
     let markdown = converter::convert_html_with_visitor(normalized_html.as_ref(), &options, visitor)?;
 
-    if options.wrap {
-        Ok(wrapper::wrap_markdown(&markdown, &options))
-    } else {
-        Ok(markdown)
-    }
+    finalize_markdown(markdown, &options)
 }
 
 #[cfg(feature = "async-visitor")]
@@ -709,32 +1406,25 @@ pub fn convert_with_visitor(
 ///
 /// # Implementation Note
 ///
-/// Currently, this function placeholder documents the async visitor interface for binding
-/// implementations. The actual async dispatch integration will be implemented in binding
-/// layers (Python, TypeScript, etc.) to properly bridge async/await semantics to the
-/// synchronous Rust conversion core.
-#[allow(clippy::future_not_send)]
+/// The conversion pipeline runs synchronously, so visitor events are buffered and awaited
+/// between conversion passes. See [`visitor::convert_with_visitor_async`] for details.
+///
 /// # Errors
 ///
-/// Returns an error if HTML parsing fails or if the input contains invalid UTF-8.
-#[allow(clippy::unused_async)]
+/// Returns an error if HTML parsing fails, the input contains invalid UTF-8, or the visitor
+/// returns `VisitResult::Error`.
+#[allow(clippy::future_not_send, clippy::await_holding_refcell_ref)]
 pub async fn convert_with_async_visitor(
     html: &str,
     options: Option<ConversionOptions>,
-    _visitor: Option<visitor_helpers::AsyncVisitorHandle>,
+    visitor: Option<visitor_helpers::AsyncVisitorHandle>,
 ) -> Result<String> {
-    validate_input(html)?;
-    let options = options.unwrap_or_default();
-
-    let normalized_html = normalize_line_endings(html);
-
-    // TODO: Implement async dispatch in conversion pipeline
-    let markdown = converter::convert_html(normalized_html.as_ref(), &options)?;
-
-    if options.wrap {
-        Ok(wrapper::wrap_markdown(&markdown, &options))
-    } else {
-        Ok(markdown)
+    match visitor {
+        Some(visitor) => {
+            let mut visitor = visitor.borrow_mut();
+            visitor::convert_with_visitor_async(html, options, &mut *visitor).await
+        }
+        None => convert(html, options),
     }
 }
 
@@ -752,6 +1442,8 @@ mod tests {
             extract_links: true,
             extract_images: true,
             extract_structured_data: true,
+            extract_resources: false,
+            extract_source_map: false,
             max_structured_data_size: metadata::DEFAULT_MAX_STRUCTURED_DATA_SIZE,
         };
 
@@ -815,6 +1507,8 @@ mod tests {
             extract_links: false,
             extract_images: false,
             extract_structured_data: false,
+            extract_resources: false,
+            extract_source_map: false,
             max_structured_data_size: 0,
         };
 
@@ -0,0 +1,354 @@
+//! Readability-style main-content detection for `PreprocessingOptions::extract_main_content`.
+//!
+//! Every paragraph-like block (`p`, `pre`, `td`, `section`, `h2`-`h6`, and `div`s without
+//! block children) with at least [`MIN_PARAGRAPH_CHARS`] characters of text scores
+//! `1 + commas + min(chars / 100, 3)`. That score is credited to its ancestors, fully to
+//! the parent, half to the grandparent and progressively less further up. Each ancestor
+//! starts from a weight derived from its tag and from `class`/`id` hints such as `article`
+//! or `comment`, and its final score is scaled down by its link density.
+//!
+//! The best candidate is returned together with those of its siblings that score close to
+//! it, so articles split across several containers are kept whole. Subtrees whose
+//! `class`/`id` mark them as unlikely content (comments, sidebars, footers, ...) are not
+//! scored at all.
+
+use std::collections::HashMap;
+
+/// Blocks shorter than this are ignored when scoring.
+const MIN_PARAGRAPH_CHARS: usize = 25;
+
+/// How many ancestors receive a share of a paragraph's score.
+const SCORED_ANCESTOR_LEVELS: usize = 5;
+
+const UNLIKELY_HINTS: &[&str] = &[
+    "banner",
+    "breadcrumbs",
+    "combx",
+    "comment",
+    "community",
+    "cover-wrap",
+    "disqus",
+    "extra",
+    "footer",
+    "gdpr",
+    "header",
+    "legends",
+    "menu",
+    "related",
+    "remark",
+    "replies",
+    "rss",
+    "shoutbox",
+    "sidebar",
+    "skyscraper",
+    "social",
+    "sponsor",
+    "supplemental",
+    "ad-break",
+    "agegate",
+    "pagination",
+    "pager",
+    "popup",
+];
+
+/// Hints that rescue an element from [`UNLIKELY_HINTS`] (`article-header`, `main-column`).
+const MAYBE_HINTS: &[&str] = &["and", "article", "body", "column", "content", "main", "shadow"];
+
+const POSITIVE_HINTS: &[&str] = &[
+    "article", "body", "content", "entry", "hentry", "h-entry", "main", "page", "post", "text", "blog", "story",
+];
+
+const NEGATIVE_HINTS: &[&str] = &[
+    "hidden",
+    "banner",
+    "combx",
+    "comment",
+    "com-",
+    "contact",
+    "foot",
+    "footnote",
+    "masthead",
+    "media",
+    "meta",
+    "outbrain",
+    "promo",
+    "related",
+    "scroll",
+    "share",
+    "shoutbox",
+    "sidebar",
+    "skyscraper",
+    "sponsor",
+    "shopping",
+    "tags",
+    "tool",
+    "widget",
+];
+
+#[derive(Debug, Clone, Copy, Default)]
+struct TextStats {
+    chars: usize,
+    link_chars: usize,
+    commas: usize,
+}
+
+impl TextStats {
+    fn link_density(self) -> f64 {
+        if self.chars == 0 {
+            0.0
+        } else {
+            self.link_chars as f64 / self.chars as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Candidate {
+    handle: tl::NodeHandle,
+    parent: Option<tl::NodeHandle>,
+    tag_name: String,
+    score: f64,
+}
+
+#[derive(Default)]
+struct Scorer {
+    candidates: HashMap<u32, Candidate>,
+    stats: HashMap<u32, TextStats>,
+}
+
+/// Find the element(s) holding the main content of `dom`, in document order.
+///
+/// Returns `None` when nothing scores or when the best candidate is the whole document, in
+/// which case the caller should convert everything as usual.
+pub fn find_main_content(dom: &tl::VDom<'_>) -> Option<Vec<tl::NodeHandle>> {
+    let parser = dom.parser();
+    let mut scorer = Scorer::default();
+    let mut ancestors = Vec::new();
+    for child in dom.children() {
+        scorer.measure(*child, parser, &mut ancestors);
+    }
+
+    let final_score = |candidate: &Candidate| {
+        let density = scorer
+            .stats
+            .get(&candidate.handle.get_inner())
+            .map_or(0.0, |stats| stats.link_density());
+        candidate.score * (1.0 - density)
+    };
+
+    let top = scorer
+        .candidates
+        .values()
+        .max_by(|a, b| final_score(a).total_cmp(&final_score(b)))?;
+    if matches!(top.tag_name.as_str(), "html" | "body") {
+        return None;
+    }
+
+    let Some(parent) = top.parent.and_then(|parent| match parent.get(parser) {
+        Some(tl::Node::Tag(tag)) => Some(tag),
+        _ => None,
+    }) else {
+        return Some(vec![top.handle]);
+    };
+
+    let top_score = final_score(top);
+    let threshold = (top_score * 0.2).max(10.0);
+    let mut selected = Vec::new();
+    for sibling in parent.children().top().iter() {
+        let id = sibling.get_inner();
+        let keep = if id == top.handle.get_inner() {
+            true
+        } else if let Some(candidate) = scorer.candidates.get(&id) {
+            final_score(candidate) >= threshold
+        } else if is_tag(*sibling, parser, "p") {
+            let stats = scorer.stats.get(&id).copied().unwrap_or_default();
+            stats.chars > 80 && stats.link_density() < 0.25
+        } else {
+            false
+        };
+        if keep {
+            selected.push(*sibling);
+        }
+    }
+    Some(selected)
+}
+
+impl Scorer {
+    /// Measure the text below `handle`, scoring paragraphs on the way back up.
+    ///
+    /// Returns the text statistics of the subtree and whether it contains a block element.
+    fn measure(
+        &mut self,
+        handle: tl::NodeHandle,
+        parser: &tl::Parser<'_>,
+        ancestors: &mut Vec<tl::NodeHandle>,
+    ) -> (TextStats, bool) {
+        let Some(node) = handle.get(parser) else {
+            return (TextStats::default(), false);
+        };
+        let tag = match node {
+            tl::Node::Raw(bytes) => {
+                let text = bytes.as_utf8_str();
+                let text = text.trim();
+                let stats = TextStats {
+                    chars: text.chars().count(),
+                    link_chars: 0,
+                    commas: text.matches(',').count(),
+                };
+                return (stats, false);
+            }
+            tl::Node::Comment(_) => return (TextStats::default(), false),
+            tl::Node::Tag(tag) => tag,
+        };
+
+        let tag_name = tag.name().as_utf8_str().to_ascii_lowercase();
+        if matches!(
+            tag_name.as_str(),
+            "script" | "style" | "noscript" | "template" | "nav" | "aside" | "footer" | "form"
+        ) || is_unlikely_candidate(tag, &tag_name)
+        {
+            return (TextStats::default(), is_block(&tag_name));
+        }
+
+        ancestors.push(handle);
+        let mut stats = TextStats::default();
+        let mut has_block_child = false;
+        for child in tag.children().top().iter() {
+            let (child_stats, child_is_block) = self.measure(*child, parser, ancestors);
+            stats.chars += child_stats.chars;
+            stats.link_chars += child_stats.link_chars;
+            stats.commas += child_stats.commas;
+            has_block_child |= child_is_block;
+        }
+        ancestors.pop();
+
+        if tag_name == "a" {
+            stats.link_chars = stats.chars;
+        }
+        self.stats.insert(handle.get_inner(), stats);
+
+        let scores_as_paragraph = match tag_name.as_str() {
+            "p" | "pre" | "td" | "section" | "h2" | "h3" | "h4" | "h5" | "h6" => true,
+            "div" => !has_block_child,
+            _ => false,
+        };
+        if scores_as_paragraph && stats.chars >= MIN_PARAGRAPH_CHARS {
+            let score = 1.0 + stats.commas as f64 + (stats.chars / 100).min(3) as f64;
+            self.credit_ancestors(score, parser, ancestors);
+        }
+
+        (stats, has_block_child || is_block(&tag_name))
+    }
+
+    fn credit_ancestors(&mut self, score: f64, parser: &tl::Parser<'_>, ancestors: &[tl::NodeHandle]) {
+        for (level, index) in (0..ancestors.len()).rev().take(SCORED_ANCESTOR_LEVELS).enumerate() {
+            let handle = ancestors[index];
+            let Some(tl::Node::Tag(tag)) = handle.get(parser) else {
+                continue;
+            };
+            let divider = match level {
+                0 => 1.0,
+                1 => 2.0,
+                _ => level as f64 * 3.0,
+            };
+            let candidate = self.candidates.entry(handle.get_inner()).or_insert_with(|| {
+                let tag_name = tag.name().as_utf8_str().to_ascii_lowercase();
+                Candidate {
+                    handle,
+                    parent: index.checked_sub(1).map(|parent| ancestors[parent]),
+                    score: initial_score(tag, &tag_name),
+                    tag_name,
+                }
+            });
+            candidate.score += score / divider;
+        }
+    }
+}
+
+fn initial_score(tag: &tl::HTMLTag<'_>, tag_name: &str) -> f64 {
+    let tag_weight = match tag_name {
+        "div" | "article" | "main" => 5.0,
+        "pre" | "td" | "blockquote" => 3.0,
+        "address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+    tag_weight + class_weight(tag)
+}
+
+fn class_weight(tag: &tl::HTMLTag<'_>) -> f64 {
+    let mut weight = 0.0;
+    for attr in ["class", "id"] {
+        let Some(value) = attribute(tag, attr) else {
+            continue;
+        };
+        if contains_any(&value, NEGATIVE_HINTS) {
+            weight -= 25.0;
+        }
+        if contains_any(&value, POSITIVE_HINTS) {
+            weight += 25.0;
+        }
+    }
+    weight
+}
+
+fn is_unlikely_candidate(tag: &tl::HTMLTag<'_>, tag_name: &str) -> bool {
+    if matches!(tag_name, "html" | "body" | "article" | "main" | "a") {
+        return false;
+    }
+    let hints = [attribute(tag, "class"), attribute(tag, "id")]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+    contains_any(&hints, UNLIKELY_HINTS) && !contains_any(&hints, MAYBE_HINTS)
+}
+
+fn attribute(tag: &tl::HTMLTag<'_>, name: &str) -> Option<String> {
+    tag.attributes()
+        .get(name)
+        .flatten()
+        .map(|value| value.as_utf8_str().to_ascii_lowercase())
+}
+
+fn contains_any(value: &str, hints: &[&str]) -> bool {
+    hints.iter().any(|hint| value.contains(hint))
+}
+
+fn is_tag(handle: tl::NodeHandle, parser: &tl::Parser<'_>, name: &str) -> bool {
+    matches!(handle.get(parser), Some(tl::Node::Tag(tag)) if tag.name().as_utf8_str().eq_ignore_ascii_case(name))
+}
+
+fn is_block(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "address"
+            | "article"
+            | "aside"
+            | "blockquote"
+            | "dd"
+            | "div"
+            | "dl"
+            | "dt"
+            | "figure"
+            | "footer"
+            | "form"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "header"
+            | "hr"
+            | "li"
+            | "main"
+            | "nav"
+            | "ol"
+            | "p"
+            | "pre"
+            | "section"
+            | "table"
+            | "ul"
+    )
+}
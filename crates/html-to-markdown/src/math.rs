@@ -0,0 +1,663 @@
+//! TeX output for `ConversionOptions::math_style`.
+//!
+//! MathML is translated element by element: token elements (`mi`, `mn`, `mo`, `mtext`) map
+//! their characters to TeX symbols and layout elements (`mfrac`, `msqrt`, `msup`, `mtable`,
+//! ...) become the matching TeX constructs. A TeX `<annotation>`, which KaTeX, MathJax and
+//! most authoring tools write into `<semantics>`, is preferred over the translation because
+//! it is the author's own source.
+
+use std::fmt::Write as _;
+
+use crate::options::MathStyle;
+use crate::text;
+
+/// Classes of the visual renderings KaTeX and MathJax 2 place next to the source math. The
+/// source is converted instead, so these would only duplicate it.
+const RENDERED_MATH_CLASSES: &[&str] = &[
+    "katex-html",
+    "MathJax_Preview",
+    "MathJax",
+    "MathJax_Display",
+    "MathJax_CHTML",
+    "MathJax_SVG",
+    "MathJax_SVG_Display",
+];
+
+/// `encoding` values of `<annotation>` elements holding TeX.
+const TEX_ENCODINGS: &[&str] = &["application/x-tex", "application/x-latex", "tex", "latex"];
+
+/// Identifiers and operators written as TeX commands (`\sin`, `\lim`).
+const FUNCTIONS: &[&str] = &[
+    "arccos", "arcsin", "arctan", "arg", "cos", "cosh", "cot", "coth", "csc", "deg", "det", "dim", "exp", "gcd", "hom",
+    "inf", "ker", "lg", "lim", "liminf", "limsup", "ln", "log", "max", "min", "Pr", "sec", "sin", "sinh", "sup", "tan",
+    "tanh",
+];
+
+/// Operators whose `munder`/`mover` scripts are limits rather than stacked notes.
+const LIMIT_OPERATORS: &[&str] = &[
+    "\\sum", "\\prod", "\\coprod", "\\int", "\\iint", "\\iiint", "\\oint", "\\bigcup", "\\bigcap", "\\lim", "\\max",
+    "\\min", "\\sup", "\\inf", "\\liminf", "\\limsup", "\\det", "\\gcd", "\\Pr",
+];
+
+/// Whether `tag` is a KaTeX or MathJax rendering of math whose source is converted instead.
+pub fn is_rendered_math(tag: &tl::HTMLTag) -> bool {
+    tag.attributes().class().is_some_and(|classes| {
+        classes
+            .as_utf8_str()
+            .split_whitespace()
+            .any(|class| RENDERED_MATH_CLASSES.contains(&class))
+    })
+}
+
+/// Whether a `<math>` element is display (block) math.
+pub fn is_display(tag: &tl::HTMLTag) -> bool {
+    let attribute_is = |name: &str, expected: &str| {
+        tag.attributes()
+            .get(name)
+            .flatten()
+            .is_some_and(|value| value.as_utf8_str().trim().eq_ignore_ascii_case(expected))
+    };
+    attribute_is("display", "block") || attribute_is("mode", "display")
+}
+
+/// TeX source of a `<math>` element: its TeX annotation if it has one, otherwise a translation
+/// of the MathML.
+pub fn mathml_to_tex(tag: &tl::HTMLTag, parser: &tl::Parser) -> String {
+    if let Some(tex) = tex_annotation(tag, parser) {
+        return tex;
+    }
+    let mut out = String::new();
+    children_tex(tag, parser, &mut out);
+    out.trim().to_string()
+}
+
+/// Math written inside running text. Display math is kept on the line as `$$...$$`.
+pub fn inline_math(tex: &str, display: bool, style: MathStyle) -> String {
+    match style {
+        MathStyle::Fenced => format!("$`{tex}`$"),
+        MathStyle::Comment | MathStyle::Dollar if display => format!("$$ {tex} $$"),
+        MathStyle::Comment | MathStyle::Dollar => format!("${tex}$"),
+    }
+}
+
+/// Display math as a block of its own, without the surrounding blank lines.
+pub fn display_math(tex: &str, style: MathStyle) -> String {
+    match style {
+        MathStyle::Fenced => format!("```math\n{tex}\n```"),
+        MathStyle::Comment | MathStyle::Dollar => format!("$$\n{tex}\n$$"),
+    }
+}
+
+/// Rewrite MathJax `\(...\)` and `\[...\]` delimiters in `text` as Markdown math, passing the
+/// text around them through `plain`. Returns `None` if `text` has no complete delimiter pair.
+pub fn replace_tex_delimiters(text: &str, style: MathStyle, plain: impl Fn(&str) -> String) -> Option<String> {
+    let mut out = String::new();
+    let mut rest = text;
+    let mut found = false;
+    loop {
+        let inline = rest.find("\\(");
+        let display = rest.find("\\[");
+        let (open, is_display) = match (inline, display) {
+            (Some(inline), Some(display)) if display < inline => (display, true),
+            (Some(inline), _) => (inline, false),
+            (None, Some(display)) => (display, true),
+            (None, None) => break,
+        };
+        let close_delimiter = if is_display { "\\]" } else { "\\)" };
+        let Some(close) = rest[open + 2..].find(close_delimiter).map(|close| close + open + 2) else {
+            break;
+        };
+
+        out.push_str(&plain(&rest[..open]));
+        let tex = rest[open + 2..close].trim();
+        if !tex.is_empty() {
+            out.push_str(&inline_math(tex, is_display, style));
+        }
+        rest = &rest[close + 2..];
+        found = true;
+    }
+    if !found {
+        return None;
+    }
+    out.push_str(&plain(rest));
+    Some(out)
+}
+
+fn tex_annotation(tag: &tl::HTMLTag, parser: &tl::Parser) -> Option<String> {
+    for child in tag.children().top().iter() {
+        let Some(tl::Node::Tag(child)) = child.get(parser) else {
+            continue;
+        };
+        if local_name(child) == "annotation" {
+            let encoding = child.attributes().get("encoding").flatten();
+            if encoding.is_some_and(|encoding| {
+                let encoding = encoding.as_utf8_str();
+                TEX_ENCODINGS
+                    .iter()
+                    .any(|tex| encoding.trim().eq_ignore_ascii_case(tex))
+            }) {
+                let tex = text::decode_html_entities(child.inner_text(parser).trim());
+                if !tex.is_empty() {
+                    return Some(tex);
+                }
+            }
+        } else if let Some(tex) = tex_annotation(child, parser) {
+            return Some(tex);
+        }
+    }
+    None
+}
+
+/// Element name without a namespace prefix such as `m:`, lowercased.
+fn local_name(tag: &tl::HTMLTag) -> String {
+    let name = tag.name().as_utf8_str();
+    let name = name.rsplit(':').next().unwrap_or(&name);
+    name.to_ascii_lowercase()
+}
+
+fn child_elements<'p>(tag: &tl::HTMLTag, parser: &'p tl::Parser) -> Vec<&'p tl::HTMLTag<'p>> {
+    tag.children()
+        .top()
+        .iter()
+        .filter_map(|child| match child.get(parser) {
+            Some(tl::Node::Tag(child)) => Some(child),
+            _ => None,
+        })
+        .collect()
+}
+
+fn children_tex(tag: &tl::HTMLTag, parser: &tl::Parser, out: &mut String) {
+    for child in tag.children().top().iter() {
+        match child.get(parser) {
+            Some(tl::Node::Tag(child)) => element_tex(child, parser, out),
+            Some(tl::Node::Raw(raw)) => {
+                let raw = text::decode_html_entities(&raw.as_utf8_str());
+                push_tex(out, &symbols(raw.trim()));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn element_tex(tag: &tl::HTMLTag, parser: &tl::Parser, out: &mut String) {
+    let name = local_name(tag);
+    let kids = child_elements(tag, parser);
+    let content = || text::decode_html_entities(&tag.inner_text(parser));
+    let arg = |index: usize| kids.get(index).map(|kid| group(kid, parser)).unwrap_or_default();
+
+    let tex = match name.as_str() {
+        "mi" => identifier(&content()),
+        "mn" => symbols(content().trim()),
+        "mo" => operator(&content()),
+        "mtext" | "ms" => {
+            let content = content();
+            if content.trim().is_empty() {
+                "\\ ".to_string()
+            } else {
+                format!("\\text{{{}}}", escape_text(&content))
+            }
+        }
+        "mspace" => "\\ ".to_string(),
+        "msup" => format!("{}^{{{}}}", base(&kids, parser), arg(1)),
+        "msub" => format!("{}_{{{}}}", base(&kids, parser), arg(1)),
+        "msubsup" | "munderover" => format!("{}_{{{}}}^{{{}}}", base(&kids, parser), arg(1), arg(2)),
+        "mover" => over_tex(&kids, parser),
+        "munder" => under_tex(&kids, parser),
+        "mfrac" => {
+            let command = if tag
+                .attributes()
+                .get("linethickness")
+                .flatten()
+                .is_some_and(|value| value.as_utf8_str().trim().trim_end_matches("px") == "0")
+            {
+                "binom"
+            } else {
+                "frac"
+            };
+            format!("\\{command}{{{}}}{{{}}}", arg(0), arg(1))
+        }
+        "msqrt" => {
+            let mut inner = String::new();
+            children_tex(tag, parser, &mut inner);
+            format!("\\sqrt{{{}}}", inner.trim())
+        }
+        "mroot" => format!("\\sqrt[{}]{{{}}}", arg(1), arg(0)),
+        "mfenced" => fenced_tex(tag, &kids, parser),
+        "mtable" => table_tex(&kids, parser),
+        "menclose" => {
+            let mut inner = String::new();
+            children_tex(tag, parser, &mut inner);
+            let boxed = tag
+                .attributes()
+                .get("notation")
+                .flatten()
+                .is_some_and(|notation| notation.as_utf8_str().contains("box"));
+            if boxed {
+                format!("\\boxed{{{}}}", inner.trim())
+            } else {
+                inner
+            }
+        }
+        "mmultiscripts" => {
+            let mut tex = base(&kids, parser);
+            for pair in kids.get(1..).unwrap_or_default().chunks(2) {
+                if pair.iter().any(|kid| local_name(kid) == "mprescripts") {
+                    break;
+                }
+                let script = |kid: Option<&&tl::HTMLTag>| {
+                    kid.filter(|kid| local_name(kid) != "none")
+                        .map(|kid| group(kid, parser))
+                        .filter(|script| !script.is_empty())
+                };
+                if let Some(sub) = script(pair.first()) {
+                    let _ = write!(tex, "_{{{sub}}}");
+                }
+                if let Some(sup) = script(pair.get(1)) {
+                    let _ = write!(tex, "^{{{sup}}}");
+                }
+            }
+            tex
+        }
+        "semantics" | "maction" => kids.first().map(|kid| group(kid, parser)).unwrap_or_default(),
+        "annotation" | "annotation-xml" | "mphantom" | "none" | "mprescripts" => String::new(),
+        _ => {
+            children_tex(tag, parser, out);
+            return;
+        }
+    };
+    push_tex(out, &tex);
+}
+
+/// TeX of one element on its own.
+fn group(tag: &tl::HTMLTag, parser: &tl::Parser) -> String {
+    let mut out = String::new();
+    element_tex(tag, parser, &mut out);
+    out.trim().to_string()
+}
+
+/// The base of a script, braced unless it is a single token.
+fn base(kids: &[&tl::HTMLTag], parser: &tl::Parser) -> String {
+    let Some(first) = kids.first() else {
+        return "{}".to_string();
+    };
+    let tex = group(first, parser);
+    let is_token = matches!(local_name(first).as_str(), "mi" | "mn" | "mo");
+    if is_token || tex.chars().count() == 1 {
+        tex
+    } else {
+        format!("{{{tex}}}")
+    }
+}
+
+fn over_tex(kids: &[&tl::HTMLTag], parser: &tl::Parser) -> String {
+    let base_tex = base(kids, parser);
+    let over = kids.get(1).map(|kid| group(kid, parser)).unwrap_or_default();
+    if is_limit_operator(kids, &base_tex) {
+        return format!("{base_tex}^{{{over}}}");
+    }
+    let inner = kids.first().map(|kid| group(kid, parser)).unwrap_or_default();
+    let accent = match over.as_str() {
+        "^" | "\\hat{}" | "ˆ" => Some("hat"),
+        "¯" | "‾" | "-" | "_" | "\\_" => Some(if inner.chars().count() == 1 { "bar" } else { "overline" }),
+        "\\to" | "⃗" => Some("vec"),
+        "~" | "\\sim" | "˜" => Some("tilde"),
+        "˙" | "." => Some("dot"),
+        "¨" | ".." => Some("ddot"),
+        "ˇ" => Some("check"),
+        "˘" => Some("breve"),
+        "⏞" => Some("overbrace"),
+        _ => None,
+    };
+    match accent {
+        Some(accent) => format!("\\{accent}{{{inner}}}"),
+        None => format!("\\overset{{{over}}}{{{inner}}}"),
+    }
+}
+
+fn under_tex(kids: &[&tl::HTMLTag], parser: &tl::Parser) -> String {
+    let base_tex = base(kids, parser);
+    let under = kids.get(1).map(|kid| group(kid, parser)).unwrap_or_default();
+    if is_limit_operator(kids, &base_tex) {
+        return format!("{base_tex}_{{{under}}}");
+    }
+    let inner = kids.first().map(|kid| group(kid, parser)).unwrap_or_default();
+    match under.as_str() {
+        "¯" | "‾" | "-" | "_" | "\\_" => format!("\\underline{{{inner}}}"),
+        "⏟" => format!("\\underbrace{{{inner}}}"),
+        _ => format!("\\underset{{{under}}}{{{inner}}}"),
+    }
+}
+
+fn is_limit_operator(kids: &[&tl::HTMLTag], base_tex: &str) -> bool {
+    LIMIT_OPERATORS.contains(&base_tex) || kids.first().is_some_and(|kid| local_name(kid) == "mo")
+}
+
+fn fenced_tex(tag: &tl::HTMLTag, kids: &[&tl::HTMLTag], parser: &tl::Parser) -> String {
+    let attribute = |name: &str, default: &str| {
+        tag.attributes()
+            .get(name)
+            .flatten()
+            .map_or_else(|| default.to_string(), |value| value.as_utf8_str().trim().to_string())
+    };
+    let separators: Vec<char> = attribute("separators", ",")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    let mut tex = operator(&attribute("open", "("));
+    for (index, kid) in kids.iter().enumerate() {
+        if index > 0 {
+            if let Some(separator) = separators.get(index - 1).or_else(|| separators.last()) {
+                tex.push_str(&operator(&separator.to_string()));
+                tex.push(' ');
+            }
+        }
+        push_tex(&mut tex, &group(kid, parser));
+    }
+    tex.push_str(&operator(&attribute("close", ")")));
+    tex
+}
+
+fn table_tex(rows: &[&tl::HTMLTag], parser: &tl::Parser) -> String {
+    let rows: Vec<String> = rows
+        .iter()
+        .filter(|row| matches!(local_name(row).as_str(), "mtr" | "mlabeledtr"))
+        .map(|row| {
+            let skip = usize::from(local_name(row) == "mlabeledtr");
+            child_elements(row, parser)
+                .iter()
+                .skip(skip)
+                .map(|cell| group(cell, parser))
+                .collect::<Vec<_>>()
+                .join(" & ")
+        })
+        .collect();
+    format!("\\begin{{matrix}} {} \\end{{matrix}}", rows.join(" \\\\ "))
+}
+
+/// Append `tex`, separating it from a preceding command name (`\alpha x`, not `\alphax`).
+fn push_tex(out: &mut String, tex: &str) {
+    if tex.is_empty() {
+        return;
+    }
+    let letters = out.len() - out.trim_end_matches(|c: char| c.is_ascii_alphabetic()).len();
+    let ends_with_command = letters > 0 && out[..out.len() - letters].ends_with('\\');
+    if ends_with_command && tex.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        out.push(' ');
+    }
+    out.push_str(tex);
+}
+
+fn identifier(content: &str) -> String {
+    let content = content.trim();
+    if content.chars().count() == 1 {
+        return symbols(content);
+    }
+    if FUNCTIONS.contains(&content) {
+        return format!("\\{content}");
+    }
+    if content.chars().all(|c| symbol(c).is_some()) {
+        return symbols(content);
+    }
+    format!("\\mathrm{{{}}}", escape_text(content))
+}
+
+fn operator(content: &str) -> String {
+    let content = content.trim();
+    if FUNCTIONS.contains(&content) {
+        return format!("\\{content}");
+    }
+    symbols(content)
+}
+
+/// Map each character to its TeX form.
+fn symbols(content: &str) -> String {
+    let mut tex = String::with_capacity(content.len());
+    for c in content.chars() {
+        match symbol(c) {
+            Some(command) => push_tex(&mut tex, command),
+            None => tex.push(c),
+        }
+    }
+    tex
+}
+
+/// Escape the characters that are special inside `\text{}`.
+fn escape_text(content: &str) -> String {
+    let mut escaped = String::with_capacity(content.len());
+    for c in content.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' | '#' | '$' | '%' | '&' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\u{a0}' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[allow(clippy::too_many_lines)]
+const fn symbol(c: char) -> Option<&'static str> {
+    Some(match c {
+        '\u{2061}'..='\u{2064}' => "",
+        '\u{a0}' => " ",
+        '\\' => "\\backslash",
+        '{' => "\\{",
+        '}' => "\\}",
+        '#' => "\\#",
+        '$' => "\\$",
+        '%' => "\\%",
+        '&' => "\\&",
+        '_' => "\\_",
+        '~' => "\\sim",
+        '^' => "\\hat{}",
+        'α' => "\\alpha",
+        'β' => "\\beta",
+        'γ' => "\\gamma",
+        'δ' => "\\delta",
+        'ε' => "\\varepsilon",
+        'ϵ' => "\\epsilon",
+        'ζ' => "\\zeta",
+        'η' => "\\eta",
+        'θ' => "\\theta",
+        'ϑ' => "\\vartheta",
+        'ι' => "\\iota",
+        'κ' => "\\kappa",
+        'λ' => "\\lambda",
+        'μ' | 'µ' => "\\mu",
+        'ν' => "\\nu",
+        'ξ' => "\\xi",
+        'π' => "\\pi",
+        'ϖ' => "\\varpi",
+        'ρ' => "\\rho",
+        'ϱ' => "\\varrho",
+        'σ' => "\\sigma",
+        'ς' => "\\varsigma",
+        'τ' => "\\tau",
+        'υ' => "\\upsilon",
+        'φ' => "\\varphi",
+        'ϕ' => "\\phi",
+        'χ' => "\\chi",
+        'ψ' => "\\psi",
+        'ω' => "\\omega",
+        'Γ' => "\\Gamma",
+        'Δ' => "\\Delta",
+        'Θ' => "\\Theta",
+        'Λ' => "\\Lambda",
+        'Ξ' => "\\Xi",
+        'Π' => "\\Pi",
+        'Σ' => "\\Sigma",
+        'Υ' => "\\Upsilon",
+        'Φ' => "\\Phi",
+        'Ψ' => "\\Psi",
+        'Ω' => "\\Omega",
+        '±' => "\\pm",
+        '∓' => "\\mp",
+        '×' => "\\times",
+        '÷' => "\\div",
+        '·' | '⋅' => "\\cdot",
+        '∗' => "\\ast",
+        '∘' => "\\circ",
+        '−' => "-",
+        '≤' => "\\leq",
+        '≥' => "\\geq",
+        '≠' => "\\neq",
+        '≈' => "\\approx",
+        '≡' => "\\equiv",
+        '∼' => "\\sim",
+        '≃' => "\\simeq",
+        '≅' => "\\cong",
+        '∝' => "\\propto",
+        '≪' => "\\ll",
+        '≫' => "\\gg",
+        '∞' => "\\infty",
+        '∂' => "\\partial",
+        '∇' => "\\nabla",
+        '∑' => "\\sum",
+        '∏' => "\\prod",
+        '∐' => "\\coprod",
+        '∫' => "\\int",
+        '∬' => "\\iint",
+        '∭' => "\\iiint",
+        '∮' => "\\oint",
+        '√' => "\\surd",
+        '∈' => "\\in",
+        '∉' => "\\notin",
+        '∋' => "\\ni",
+        '⊂' => "\\subset",
+        '⊃' => "\\supset",
+        '⊆' => "\\subseteq",
+        '⊇' => "\\supseteq",
+        '∪' => "\\cup",
+        '∩' => "\\cap",
+        '⋃' => "\\bigcup",
+        '⋂' => "\\bigcap",
+        '∖' => "\\setminus",
+        '∅' => "\\emptyset",
+        '∀' => "\\forall",
+        '∃' => "\\exists",
+        '¬' => "\\neg",
+        '∧' => "\\land",
+        '∨' => "\\lor",
+        '⊕' => "\\oplus",
+        '⊗' => "\\otimes",
+        '⊥' => "\\perp",
+        '∥' => "\\parallel",
+        '∠' => "\\angle",
+        '△' => "\\triangle",
+        '⊢' => "\\vdash",
+        '→' => "\\to",
+        '←' => "\\leftarrow",
+        '↔' => "\\leftrightarrow",
+        '⇒' => "\\Rightarrow",
+        '⇐' => "\\Leftarrow",
+        '⇔' => "\\Leftrightarrow",
+        '↦' => "\\mapsto",
+        '…' => "\\ldots",
+        '⋯' => "\\cdots",
+        '⋮' => "\\vdots",
+        '⋱' => "\\ddots",
+        '′' => "'",
+        '″' => "''",
+        '⟨' | '〈' => "\\langle",
+        '⟩' | '〉' => "\\rangle",
+        '‖' => "\\|",
+        '∣' => "\\mid",
+        '⌊' => "\\lfloor",
+        '⌋' => "\\rfloor",
+        '⌈' => "\\lceil",
+        '⌉' => "\\rceil",
+        'ℝ' => "\\mathbb{R}",
+        'ℕ' => "\\mathbb{N}",
+        'ℤ' => "\\mathbb{Z}",
+        'ℚ' => "\\mathbb{Q}",
+        'ℂ' => "\\mathbb{C}",
+        'ℏ' => "\\hbar",
+        'ℓ' => "\\ell",
+        '°' => "^\\circ",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tex(mathml: &str) -> String {
+        let dom = tl::parse(mathml, tl::ParserOptions::default()).unwrap();
+        let parser = dom.parser();
+        let math = dom.children()[0].get(parser).unwrap().as_tag().unwrap();
+        mathml_to_tex(math, parser)
+    }
+
+    #[test]
+    fn test_translates_scripts_fractions_and_roots() {
+        assert_eq!(
+            tex(
+                "<math><msup><mi>x</mi><mn>2</mn></msup><mo>+</mo><mfrac><mn>1</mn><msqrt><mi>y</mi></msqrt></mfrac></math>"
+            ),
+            "x^{2}+\\frac{1}{\\sqrt{y}}"
+        );
+        assert_eq!(
+            tex("<math><msup><mrow><mo>(</mo><mi>a</mi><mo>+</mo><mi>b</mi><mo>)</mo></mrow><mi>n</mi></msup></math>"),
+            "{(a+b)}^{n}"
+        );
+        assert_eq!(tex("<math><mroot><mi>x</mi><mn>3</mn></mroot></math>"), "\\sqrt[3]{x}");
+    }
+
+    #[test]
+    fn test_maps_symbols_and_functions() {
+        assert_eq!(
+            tex(
+                "<math><munderover><mo>&#x2211;</mo><mrow><mi>i</mi><mo>=</mo><mn>1</mn></mrow><mi>n</mi></munderover><mi>&alpha;</mi><mi>sin</mi><mi>x</mi></math>"
+            ),
+            "\\sum_{i=1}^{n}\\alpha\\sin x"
+        );
+        assert_eq!(
+            tex("<math><mover><mi>v</mi><mo>&#x2192;</mo></mover></math>"),
+            "\\vec{v}"
+        );
+        assert_eq!(
+            tex("<math><mtext>if </mtext><mi>x</mi><mo>&ge;</mo><mn>0</mn></math>"),
+            "\\text{if }x\\geq 0"
+        );
+    }
+
+    #[test]
+    fn test_prefers_tex_annotation() {
+        assert_eq!(
+            tex(
+                r#"<math><semantics><mi>E</mi><annotation encoding="application/x-tex">E = mc^2</annotation></semantics></math>"#
+            ),
+            "E = mc^2"
+        );
+    }
+
+    #[test]
+    fn test_tables() {
+        assert_eq!(
+            tex(
+                "<math><mtable><mtr><mtd><mn>1</mn></mtd><mtd><mn>0</mn></mtd></mtr><mtr><mtd><mn>0</mn></mtd><mtd><mn>1</mn></mtd></mtr></mtable></math>"
+            ),
+            "\\begin{matrix} 1 & 0 \\\\ 0 & 1 \\end{matrix}"
+        );
+    }
+
+    #[test]
+    fn test_replace_tex_delimiters() {
+        let plain = |text: &str| text.replace('*', "\\*");
+
+        assert_eq!(
+            replace_tex_delimiters("a* \\(x^2\\) and \\[y\\]", MathStyle::Dollar, plain).as_deref(),
+            Some("a\\* $x^2$ and $$ y $$")
+        );
+        assert_eq!(
+            replace_tex_delimiters("\\(x\\)", MathStyle::Fenced, plain).as_deref(),
+            Some("$`x`$")
+        );
+        assert_eq!(replace_tex_delimiters("no \\(close", MathStyle::Dollar, plain), None);
+    }
+}
@@ -9,6 +9,8 @@
 //! - **Links**: Hyperlinks with type classification (anchor, internal, external, email, phone)
 //! - **Images**: Image elements with source, alt text, dimensions, and type (data URI, external, etc.)
 //! - **Structured data**: JSON-LD, Microdata, and `RDFa` blocks
+//! - **Resources**: Scripts, stylesheets, fonts and media the page loads (opt-in)
+//! - **Source map**: The HTML byte range each Markdown block came from (opt-in)
 //!
 //! The implementation follows a single-pass collector pattern for zero-overhead extraction
 //! when metadata features are disabled.
@@ -31,14 +33,19 @@
 //! - [`LinkType`]: Link classification (Anchor, Internal, External, Email, Phone, Other)
 //! - [`ImageType`]: Image source type (`DataUri`, External, Relative, `InlineSvg`)
 //! - [`StructuredDataType`]: Structured data format (`JsonLd`, Microdata, `RDFa`)
+//! - [`ProfileSource`]: Origin of an author profile (`rel` link or schema.org property)
+//! - [`ResourceType`]: Kind of external resource (Script, Stylesheet, Font, Image, Media, Embed, Other)
 //!
 //! ## Structures
 //!
 //! - [`DocumentMetadata`]: Head-level metadata with maps for Open Graph and Twitter Card
+//! - [`AuthorProfile`]: Author or organization profile URL with optional display name
 //! - [`HeaderMetadata`]: Heading element with level (1-6), text, ID, hierarchy depth, and position
 //! - [`LinkMetadata`]: Hyperlink with href, text, title, type, rel attributes, and custom attributes
 //! - [`ImageMetadata`]: Image element with src, alt, title, dimensions, type, and attributes
 //! - [`StructuredData`]: Structured data block with type and raw JSON
+//! - [`ResourceMetadata`]: External resource URL with its type
+//! - [`SourceMapEntry`]: HTML and Markdown byte ranges of one emitted block
 //! - [`MetadataConfig`]: Configuration controlling extraction granularity and size limits
 //! - [`ExtendedMetadata`]: Top-level result containing all extracted metadata
 //!
@@ -95,6 +102,8 @@
 //!     extract_links: true,
 //!     extract_images: false,  // Skip images
 //!     extract_structured_data: false,  // Skip structured data
+//!     extract_resources: false,
+//!     extract_source_map: false,
 //!     max_structured_data_size: 0,
 //! };
 //!
@@ -292,6 +301,176 @@ impl std::fmt::Display for StructuredDataType {
     }
 }
 
+/// Where an [`AuthorProfile`] was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "metadata", serde(rename_all = "snake_case"))]
+pub enum ProfileSource {
+    /// `<a rel="author">` or `<link rel="author">`
+    RelAuthor,
+    /// `<a rel="me">` or `<link rel="me">` (identity links, as used by Mastodon and `IndieWeb`)
+    RelMe,
+    /// `url` (or a bare URL string) of a schema.org `author` in JSON-LD
+    SchemaAuthor,
+    /// `sameAs` of a schema.org `Person` or `Organization` in JSON-LD
+    SchemaSameAs,
+}
+
+impl std::fmt::Display for ProfileSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RelAuthor => write!(f, "rel_author"),
+            Self::RelMe => write!(f, "rel_me"),
+            Self::SchemaAuthor => write!(f, "schema_author"),
+            Self::SchemaSameAs => write!(f, "schema_same_as"),
+        }
+    }
+}
+
+/// Kind of external resource a document loads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "metadata", serde(rename_all = "snake_case"))]
+pub enum ResourceType {
+    /// `<script src>` and script preloads
+    Script,
+    /// `<link rel="stylesheet">`, style preloads and CSS `@import`s
+    Stylesheet,
+    /// Font preloads and `url()`s in `@font-face` rules
+    Font,
+    /// Images, including `srcset` candidates, video posters, icons and CSS backgrounds
+    Image,
+    /// `<video>`, `<audio>` and `<track>` sources
+    Media,
+    /// Documents loaded by `<iframe>`, `<frame>`, `<embed>` and `<object>`
+    Embed,
+    /// Other preloads and the web app manifest
+    Other,
+}
+
+impl std::fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Script => write!(f, "script"),
+            Self::Stylesheet => write!(f, "stylesheet"),
+            Self::Font => write!(f, "font"),
+            Self::Image => write!(f, "image"),
+            Self::Media => write!(f, "media"),
+            Self::Embed => write!(f, "embed"),
+            Self::Other => write!(f, "other"),
+        }
+    }
+}
+
+/// A URL a document loads to render, as opposed to a link a reader follows.
+///
+/// Archiving tools use these as a fetch list to make a capture self-contained. Each URL is
+/// reported once, with the type of the first element that referenced it.
+///
+/// # Examples
+///
+/// ```
+/// # use html_to_markdown_rs::metadata::{ResourceMetadata, ResourceType};
+/// let resource = ResourceMetadata {
+///     url: "/static/app.css".to_string(),
+///     resource_type: ResourceType::Stylesheet,
+/// };
+///
+/// assert_eq!(resource.resource_type.to_string(), "stylesheet");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourceMetadata {
+    /// Resource URL, as written in the document (relative URLs are not resolved)
+    pub url: String,
+
+    /// What kind of resource the URL was referenced as
+    pub resource_type: ResourceType,
+}
+
+/// Where one emitted Markdown block came from in the HTML.
+///
+/// Recorded for paragraphs, headings, lists, tables, code blocks, block quotes, thematic
+/// breaks and similar block elements; a block nested in another one is covered by the outer
+/// entry. Offsets are byte offsets into the HTML passed to the converter and into the returned
+/// Markdown, with exclusive ends.
+///
+/// # Examples
+///
+/// ```
+/// # use html_to_markdown_rs::metadata::SourceMapEntry;
+/// let html = "<h1>Title</h1>";
+/// let markdown = "# Title\n";
+/// let entry = SourceMapEntry {
+///     tag: "h1".to_string(),
+///     html_start: 0,
+///     html_end: 14,
+///     markdown_start: 0,
+///     markdown_end: 7,
+/// };
+///
+/// assert_eq!(&html[entry.html_start..entry.html_end], "<h1>Title</h1>");
+/// assert_eq!(&markdown[entry.markdown_start..entry.markdown_end], "# Title");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+pub struct SourceMapEntry {
+    /// Tag name of the element the block was converted from, e.g. `p` or `table`
+    pub tag: String,
+
+    /// Byte offset of the element's opening tag in the input HTML
+    pub html_start: usize,
+
+    /// Byte offset just past the element's closing tag in the input HTML
+    pub html_end: usize,
+
+    /// Byte offset of the block in the Markdown
+    pub markdown_start: usize,
+
+    /// Byte offset just past the block in the Markdown
+    pub markdown_end: usize,
+}
+
+/// A block recorded during the walk, before its place in the final Markdown is known.
+#[derive(Debug)]
+struct SourceBlock {
+    tag: String,
+    html_range: std::ops::Range<usize>,
+    markdown: String,
+}
+
+/// A URL identifying the author or publishing organization of a document.
+///
+/// Collected from `rel="author"`/`rel="me"` links and from schema.org `author`/`sameAs`
+/// properties in JSON-LD, so attribution does not require filtering
+/// [`ExtendedMetadata::links`] by hand. Each URL is reported once, from the first place it
+/// was seen.
+///
+/// # Examples
+///
+/// ```
+/// # use html_to_markdown_rs::metadata::{AuthorProfile, ProfileSource};
+/// let profile = AuthorProfile {
+///     url: "https://mastodon.social/@jane".to_string(),
+///     name: Some("Jane Doe".to_string()),
+///     source: ProfileSource::RelMe,
+/// };
+///
+/// assert_eq!(profile.source.to_string(), "rel_me");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "metadata", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthorProfile {
+    /// Profile URL, as written in the document (relative URLs are not resolved)
+    pub url: String,
+
+    /// Display name: the link text, or the schema.org `name` of the author
+    pub name: Option<String>,
+
+    /// Markup the URL came from
+    pub source: ProfileSource,
+}
+
 /// Document-level metadata extracted from `<head>` and top-level elements.
 ///
 /// Contains all metadata typically used by search engines, social media platforms,
@@ -325,6 +504,11 @@ pub struct DocumentMetadata {
     /// Document author from `<meta name="author">` tag
     pub author: Option<String>,
 
+    /// Author and organization profile URLs from `rel="author"`/`rel="me"` links and
+    /// schema.org `author`/`sameAs` JSON-LD properties
+    #[cfg_attr(feature = "metadata", serde(default))]
+    pub author_profiles: Vec<AuthorProfile>,
+
     /// Canonical URL from `<link rel="canonical">` tag
     pub canonical_url: Option<String>,
 
@@ -593,6 +777,8 @@ pub const DEFAULT_MAX_STRUCTURED_DATA_SIZE: usize = 1_000_000;
 /// - `extract_links`: Enable anchor element extraction with link type classification
 /// - `extract_images`: Enable image element extraction with source and dimension metadata
 /// - `extract_structured_data`: Enable structured data extraction (JSON-LD, Microdata, `RDFa`)
+/// - `extract_resources`: Enable collection of external resource URLs (scripts, stylesheets, fonts, media)
+/// - `extract_source_map`: Record the HTML byte range each emitted Markdown block came from
 /// - `max_structured_data_size`: Safety limit on total structured data size in bytes
 ///
 /// # Examples
@@ -605,6 +791,8 @@ pub const DEFAULT_MAX_STRUCTURED_DATA_SIZE: usize = 1_000_000;
 ///     extract_links: true,
 ///     extract_images: true,
 ///     extract_structured_data: true,
+///     extract_resources: false,
+///     extract_source_map: false,
 ///     max_structured_data_size: 1_000_000,
 /// };
 ///
@@ -665,6 +853,20 @@ pub struct MetadataConfig {
     /// - Extracted schema type if detectable
     pub extract_structured_data: bool,
 
+    /// Collect the URLs of external resources the page loads.
+    ///
+    /// When enabled, collects scripts, stylesheets, fonts, images, media and embedded
+    /// documents, including `srcset` candidates and `url()`s in inline CSS, as a fetch list
+    /// for archiving. Content links (`<a href>`) are not included. Off by default.
+    pub extract_resources: bool,
+
+    /// Record where each emitted Markdown block came from in the HTML.
+    ///
+    /// When enabled, [`ExtendedMetadata::source_map`] lists the byte range of every
+    /// paragraph, heading, list, table, code block and other top-level block in both the
+    /// input HTML and the returned Markdown, for annotation tooling. Off by default.
+    pub extract_source_map: bool,
+
     /// Maximum total size of structured data to collect (bytes).
     ///
     /// Prevents memory exhaustion attacks on malformed or adversarial documents
@@ -687,6 +889,8 @@ pub struct MetadataConfig {
 /// - `extract_links`: Optional override for link element extraction
 /// - `extract_images`: Optional override for image element extraction
 /// - `extract_structured_data`: Optional override for structured data extraction
+/// - `extract_resources`: Optional override for external resource collection
+/// - `extract_source_map`: Optional override for source map recording
 /// - `max_structured_data_size`: Optional override for structured data size limit
 ///
 /// # Examples
@@ -699,6 +903,8 @@ pub struct MetadataConfig {
 ///     extract_links: None,  // No change
 ///     extract_images: None,  // No change
 ///     extract_structured_data: None,  // No change
+///     extract_resources: None,  // No change
+///     extract_source_map: None,  // No change
 ///     max_structured_data_size: None,  // No change
 /// };
 ///
@@ -749,6 +955,20 @@ pub struct MetadataConfigUpdate {
     )]
     pub extract_structured_data: Option<bool>,
 
+    /// Optional override for collecting external resource URLs.
+    ///
+    /// When Some(true), enables resource collection; Some(false) disables it.
+    /// None leaves the current setting unchanged.
+    #[cfg_attr(any(feature = "serde", feature = "metadata"), serde(alias = "extract_resources"))]
+    pub extract_resources: Option<bool>,
+
+    /// Optional override for recording the HTML range of each emitted block.
+    ///
+    /// When Some(true), enables the source map; Some(false) disables it.
+    /// None leaves the current setting unchanged.
+    #[cfg_attr(any(feature = "serde", feature = "metadata"), serde(alias = "extract_source_map"))]
+    pub extract_source_map: Option<bool>,
+
     /// Optional override for maximum structured data collection size in bytes.
     ///
     /// When Some(size), sets the new size limit. None leaves the current limit unchanged.
@@ -763,7 +983,8 @@ pub struct MetadataConfigUpdate {
 impl Default for MetadataConfig {
     /// Create default metadata configuration.
     ///
-    /// Defaults to extracting all metadata types with 1MB limit on structured data.
+    /// Defaults to extracting all metadata types except resources and the source map, with 1MB
+    /// limit on structured data.
     fn default() -> Self {
         Self {
             extract_document: true,
@@ -771,6 +992,8 @@ impl Default for MetadataConfig {
             extract_links: true,
             extract_images: true,
             extract_structured_data: true,
+            extract_resources: false,
+            extract_source_map: false,
             max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
         }
     }
@@ -801,6 +1024,8 @@ impl MetadataConfig {
     ///     extract_links: false,
     ///     extract_images: false,
     ///     extract_structured_data: false,
+    ///     extract_resources: false,
+    ///     extract_source_map: false,
     ///     max_structured_data_size: 1_000_000,
     /// };
     /// assert!(config.any_enabled());
@@ -812,6 +1037,8 @@ impl MetadataConfig {
     ///     extract_links: false,
     ///     extract_images: false,
     ///     extract_structured_data: false,
+    ///     extract_resources: false,
+    ///     extract_source_map: false,
     ///     max_structured_data_size: 1_000_000,
     /// };
     /// assert!(!config.any_enabled());
@@ -823,6 +1050,8 @@ impl MetadataConfig {
             || self.extract_links
             || self.extract_images
             || self.extract_structured_data
+            || self.extract_resources
+            || self.extract_source_map
     }
 
     /// Apply a partial update to this metadata configuration.
@@ -872,6 +1101,12 @@ impl MetadataConfig {
         if let Some(extract_structured_data) = update.extract_structured_data {
             self.extract_structured_data = extract_structured_data;
         }
+        if let Some(extract_resources) = update.extract_resources {
+            self.extract_resources = extract_resources;
+        }
+        if let Some(extract_source_map) = update.extract_source_map {
+            self.extract_source_map = extract_source_map;
+        }
         if let Some(max_structured_data_size) = update.max_structured_data_size {
             self.max_structured_data_size = max_structured_data_size;
         }
@@ -902,6 +1137,8 @@ impl MetadataConfig {
     ///     extract_links: Some(true),
     ///     extract_images: None,  // Will use default (true)
     ///     extract_structured_data: None,  // Will use default (true)
+    ///     extract_resources: None,  // Will use default (false)
+    ///     extract_source_map: None,  // Will use default (false)
     ///     max_structured_data_size: None,  // Will use default (1MB)
     /// };
     ///
@@ -942,6 +1179,8 @@ impl From<MetadataConfigUpdate> for MetadataConfig {
 ///     links: Vec::new(),
 ///     images: Vec::new(),
 ///     structured_data: Vec::new(),
+///     resources: Vec::new(),
+///     source_map: Vec::new(),
 /// };
 ///
 /// assert!(metadata.headers.is_empty());
@@ -963,6 +1202,13 @@ pub struct ExtendedMetadata {
 
     /// Extracted structured data blocks
     pub structured_data: Vec<StructuredData>,
+
+    /// External resources the page loads, when `extract_resources` is enabled
+    pub resources: Vec<ResourceMetadata>,
+
+    /// Emitted blocks in output order with their HTML and Markdown ranges, when
+    /// `extract_source_map` is enabled
+    pub source_map: Vec<SourceMapEntry>,
 }
 
 /// Internal metadata collector for single-pass extraction.
@@ -991,6 +1237,10 @@ pub struct ExtendedMetadata {
 /// - `links`: Collected link elements
 /// - `base_href`: Base URL for relative link resolution
 /// - `json_ld`: JSON-LD script block contents
+/// - `profiles`: Author profile URLs, deduplicated by URL
+/// - `resources`: External resource URLs, set once from the raw HTML
+/// - `source_blocks`: Blocks recorded for the source map, located in the Markdown at the end
+/// - `source_block_open`: Whether a recorded block is being converted, so nested ones are skipped
 /// - `lang`: Document language
 /// - `dir`: Document text direction
 #[derive(Debug)]
@@ -1002,6 +1252,11 @@ pub(crate) struct MetadataCollector {
     links: Vec<LinkMetadata>,
     images: Vec<ImageMetadata>,
     json_ld: Vec<String>,
+    profiles: Vec<AuthorProfile>,
+    resources: Vec<ResourceMetadata>,
+    source_blocks: Vec<SourceBlock>,
+    source_block_open: bool,
+    source_map: Vec<SourceMapEntry>,
     structured_data_size: usize,
     config: MetadataConfig,
     lang: Option<String>,
@@ -1037,6 +1292,11 @@ impl MetadataCollector {
             links: Vec::with_capacity(64),
             images: Vec::with_capacity(16),
             json_ld: Vec::with_capacity(4),
+            profiles: Vec::new(),
+            resources: Vec::new(),
+            source_blocks: Vec::new(),
+            source_block_open: false,
+            source_map: Vec::new(),
             structured_data_size: 0,
             config,
             lang: None,
@@ -1170,12 +1430,18 @@ impl MetadataCollector {
     ///
     /// * `json_content` - Raw JSON string content
     pub(crate) fn add_json_ld(&mut self, json_content: String) {
-        if !self.config.extract_structured_data {
+        let content_size = json_content.len();
+        if content_size > self.config.max_structured_data_size {
             return;
         }
 
-        let content_size = json_content.len();
-        if content_size > self.config.max_structured_data_size {
+        if self.config.extract_document {
+            if let Ok(value) = serde_json::from_str::<serde_json::Value>(&json_content) {
+                self.collect_json_ld_profiles(&value, false);
+            }
+        }
+
+        if !self.config.extract_structured_data {
             return;
         }
         if self.structured_data_size + content_size > self.config.max_structured_data_size {
@@ -1186,6 +1452,100 @@ impl MetadataCollector {
         self.json_ld.push(json_content);
     }
 
+    /// Record an author profile from a `rel` attribute on `<a>` or `<link>`.
+    ///
+    /// Only `author` and `me` relations are kept; `author` wins when both are present.
+    ///
+    /// # Arguments
+    ///
+    /// * `rel` - Space-separated rel attribute value
+    /// * `href` - Link target
+    /// * `name` - Link text, if any
+    pub(crate) fn add_rel_profile(&mut self, rel: &str, href: &str, name: Option<String>) {
+        if !self.config.extract_document || href.is_empty() {
+            return;
+        }
+
+        let mut source = None;
+        for token in rel.split_ascii_whitespace() {
+            if token.eq_ignore_ascii_case("author") {
+                source = Some(ProfileSource::RelAuthor);
+                break;
+            }
+            if token.eq_ignore_ascii_case("me") {
+                source = Some(ProfileSource::RelMe);
+            }
+        }
+
+        if let Some(source) = source {
+            self.push_profile(href, name, source);
+        }
+    }
+
+    /// Walk a JSON-LD value for schema.org `author` URLs and `Person`/`Organization` `sameAs` lists.
+    fn collect_json_ld_profiles(&mut self, value: &serde_json::Value, is_author: bool) {
+        match value {
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    self.collect_json_ld_profiles(item, is_author);
+                }
+            }
+            serde_json::Value::String(url)
+                if is_author && (url.starts_with("http://") || url.starts_with("https://")) =>
+            {
+                self.push_profile(url, None, ProfileSource::SchemaAuthor);
+            }
+            serde_json::Value::Object(object) => {
+                let name = object.get("name").and_then(serde_json::Value::as_str).map(str::trim);
+                let name = name.filter(|name| !name.is_empty());
+
+                if is_author {
+                    if let Some(url) = object.get("url").and_then(serde_json::Value::as_str) {
+                        self.push_profile(url, name.map(str::to_string), ProfileSource::SchemaAuthor);
+                    }
+                }
+
+                let is_agent = is_author
+                    || object.get("@type").is_some_and(|ty| {
+                        let is_agent_type =
+                            |ty: &serde_json::Value| matches!(ty.as_str(), Some("Person" | "Organization"));
+                        ty.as_array()
+                            .map_or_else(|| is_agent_type(ty), |types| types.iter().any(is_agent_type))
+                    });
+                if is_agent {
+                    let same_as = object.get("sameAs").map(|same_as| match same_as {
+                        serde_json::Value::Array(urls) => urls.iter().filter_map(serde_json::Value::as_str).collect(),
+                        other => other.as_str().into_iter().collect::<Vec<_>>(),
+                    });
+                    for url in same_as.unwrap_or_default() {
+                        self.push_profile(url, name.map(str::to_string), ProfileSource::SchemaSameAs);
+                    }
+                }
+
+                for (key, child) in object {
+                    match key.as_str() {
+                        "author" | "creator" => self.collect_json_ld_profiles(child, true),
+                        "sameAs" | "url" | "name" => {}
+                        _ => self.collect_json_ld_profiles(child, false),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push_profile(&mut self, url: &str, name: Option<String>, source: ProfileSource) {
+        let url = url.trim();
+        if url.is_empty() || self.profiles.iter().any(|profile| profile.url == url) {
+            return;
+        }
+        self.profiles.push(AuthorProfile {
+            url: url.to_string(),
+            name,
+            source,
+        });
+    }
+
     /// Set document head metadata from extracted head section.
     ///
     /// Merges metadata pairs from head elements (meta, title, link, etc.)
@@ -1255,6 +1615,72 @@ impl MetadataCollector {
         self.config.extract_structured_data
     }
 
+    pub(crate) const fn wants_resources(&self) -> bool {
+        self.config.extract_resources
+    }
+
+    /// Record the external resources found by [`crate::resources::collect_resources`].
+    pub(crate) fn set_resources(&mut self, resources: Vec<ResourceMetadata>) {
+        if self.config.extract_resources {
+            self.resources = resources;
+        }
+    }
+
+    pub(crate) const fn wants_source_map(&self) -> bool {
+        self.config.extract_source_map
+    }
+
+    /// Start recording a block for the source map. Returns `false` inside another recorded
+    /// block, whose range already covers this one.
+    pub(crate) const fn open_source_block(&mut self) -> bool {
+        !std::mem::replace(&mut self.source_block_open, true)
+    }
+
+    /// Finish the block opened by [`Self::open_source_block`], given the Markdown written for it
+    /// and its byte range in the parsed HTML.
+    pub(crate) fn close_source_block(&mut self, tag: &str, html_range: std::ops::Range<usize>, markdown: &str) {
+        self.source_block_open = false;
+        let markdown = markdown.trim();
+        if !markdown.is_empty() {
+            self.source_blocks.push(SourceBlock {
+                tag: tag.to_string(),
+                html_range,
+                markdown: markdown.to_string(),
+            });
+        }
+    }
+
+    /// Translate the HTML ranges of the recorded blocks through `offsets`, from a rewritten copy
+    /// of the input back to the input itself.
+    pub(crate) fn remap_source_offsets(&mut self, offsets: &crate::source_map::OffsetMap) {
+        for block in &mut self.source_blocks {
+            // Map the last byte rather than the end, so text removed right after the element
+            // (a `<script>` that followed it) is not pulled into the range.
+            let start = offsets.map(block.html_range.start);
+            let end = offsets.map(block.html_range.end.saturating_sub(1)) + 1;
+            block.html_range = start..end.max(start);
+        }
+    }
+
+    /// Locate the recorded blocks in the final `markdown`, in order. Blocks that post-processors
+    /// or wrapping rewrote beyond recognition are left out.
+    pub(crate) fn resolve_source_map(&mut self, markdown: &str) {
+        let mut cursor = 0;
+        for block in std::mem::take(&mut self.source_blocks) {
+            let Some(range) = crate::source_map::locate_block(markdown, &block.markdown, cursor) else {
+                continue;
+            };
+            cursor = range.end;
+            self.source_map.push(SourceMapEntry {
+                tag: block.tag,
+                html_start: block.html_range.start,
+                html_end: block.html_range.end,
+                markdown_start: range.start,
+                markdown_end: range.end,
+            });
+        }
+    }
+
     /// Extract document metadata from collected head metadata.
     ///
     /// Parses head metadata into structured document metadata,
@@ -1445,7 +1871,8 @@ impl MetadataCollector {
     #[allow(dead_code)]
     pub(crate) fn finish(self) -> ExtendedMetadata {
         let structured_data = Self::extract_structured_data(self.json_ld);
-        let document = Self::extract_document_metadata(self.head_metadata, self.lang, self.dir);
+        let mut document = Self::extract_document_metadata(self.head_metadata, self.lang, self.dir);
+        document.author_profiles = self.profiles;
 
         ExtendedMetadata {
             document,
@@ -1453,6 +1880,8 @@ impl MetadataCollector {
             links: self.links,
             images: self.images,
             structured_data,
+            resources: self.resources,
+            source_map: self.source_map,
         }
     }
 
@@ -1635,6 +2064,8 @@ mod tests {
             extract_links: false,
             extract_images: false,
             extract_structured_data: false,
+            extract_resources: false,
+            extract_source_map: false,
             max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
         };
         let mut collector = MetadataCollector::new(config);
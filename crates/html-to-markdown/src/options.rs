@@ -1,6 +1,59 @@
 #![allow(clippy::cast_precision_loss, clippy::cast_sign_loss, clippy::unused_self)]
 //! Configuration options for HTML to Markdown conversion.
 
+use std::fmt;
+
+use crate::error::ConversionError;
+use crate::postprocess::{DEFAULT_URL_PARAM_DENYLIST, PostProcessorChain};
+use crate::truncate::TokenEstimator;
+
+/// Give an option enum a canonical `name()`, accepted by its `parse` and used for serialization.
+macro_rules! impl_option_names {
+    ($($ty:ident { $($variant:ident => $name:literal),+ $(,)? })+) => {
+        $(
+            impl $ty {
+                /// Canonical name of this value, as produced by serialization and accepted by `parse`.
+                #[must_use]
+                pub const fn name(self) -> &'static str {
+                    match self {
+                        $(Self::$variant => $name),+
+                    }
+                }
+
+                #[cfg_attr(not(any(feature = "serde", feature = "metadata")), allow(dead_code))]
+                pub(crate) const NAMES: &'static [&'static str] = &[$($name),+];
+            }
+        )+
+    };
+}
+
+impl_option_names! {
+    HeadingStyle { Underlined => "underlined", Atx => "atx", AtxClosed => "atx_closed" }
+    ListIndentType { Spaces => "spaces", Tabs => "tabs" }
+    WhitespaceMode { Normalized => "normalized", Strict => "strict" }
+    NbspMode { Space => "space", Preserve => "preserve" }
+    FrontMatterFormat { Yaml => "yaml", Toml => "toml", Json => "json" }
+    BidiMode { Ignore => "ignore", Marks => "marks", Embedding => "embedding", Html => "html" }
+    ListSpacing { Auto => "auto", Tight => "tight", Loose => "loose" }
+    UnrepresentablePolicy { Drop => "drop", Passthrough => "passthrough", Diagnose => "diagnose" }
+    TitleSource { OpenGraph => "og-title", FirstHeading => "first-heading", UrlBasename => "url-basename" }
+    NewlineStyle { Spaces => "spaces", Backslash => "backslash" }
+    CodeBlockStyle { Indented => "indented", Backticks => "backticks", Tildes => "tildes" }
+    HighlightStyle { DoubleEqual => "double-equal", Html => "html", Bold => "bold", None => "none" }
+    PreprocessingPreset { Minimal => "minimal", Standard => "standard", Aggressive => "aggressive", Confluence => "confluence" }
+    ConversionPreset { Chat => "chat", Docs => "docs", Scraping => "scraping", Llm => "llm" }
+    SvgTextMode { Off => "off", Alt => "alt", Caption => "caption", Replace => "replace" }
+    MathStyle { Comment => "comment", Dollar => "dollar", Fenced => "fenced" }
+    SrcsetPolicy { First => "first", Largest => "largest", Smallest => "smallest" }
+    FigureStyle { Italic => "italic", Blockquote => "blockquote", Html => "html" }
+    DetailsStyle { Markdown => "markdown", Html => "html" }
+    AbbrStyle { Expand => "expand", Text => "text", Html => "html" }
+    TimeStyle { Text => "text", Datetime => "datetime", Html => "html" }
+    DfnStyle { Emphasis => "emphasis", Text => "text", Html => "html" }
+    ContactLinkStyle { Link => "link", Autolink => "autolink", Text => "text" }
+    HeaderBreakStyle { Inherit => "inherit", Space => "space", Slash => "slash", Html => "html" }
+}
+
 /// Heading style options for Markdown output.
 ///
 /// Controls how headings (h1-h6) are rendered in the output Markdown.
@@ -82,6 +135,490 @@ impl WhitespaceMode {
     }
 }
 
+/// Handling of U+00A0 non-breaking spaces.
+///
+/// Applied separately to prose and table cells; code spans and blocks always keep
+/// their non-breaking spaces verbatim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NbspMode {
+    /// Convert non-breaking spaces to regular spaces and collapse them. Default.
+    #[default]
+    Space,
+    /// Keep non-breaking spaces as-is so they survive whitespace collapsing.
+    Preserve,
+}
+
+impl NbspMode {
+    /// Parse a non-breaking space mode from a string.
+    ///
+    /// Accepts "preserve" or defaults to Space.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "preserve" => Self::Preserve,
+            _ => Self::Space,
+        }
+    }
+}
+
+/// Serialization format for the metadata front matter block.
+///
+/// Only used when `extract_metadata` is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrontMatterFormat {
+    /// YAML between `---` fences. Default.
+    #[default]
+    Yaml,
+    /// TOML between `+++` fences (Hugo, Zola).
+    Toml,
+    /// A JSON object at the top of the document.
+    Json,
+}
+
+impl FrontMatterFormat {
+    /// Parse a front matter format from a string.
+    ///
+    /// Accepts "toml" or "json", defaults to Yaml.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "toml" => Self::Toml,
+            "json" => Self::Json,
+            _ => Self::Yaml,
+        }
+    }
+}
+
+/// Handling of elements whose `dir` attribute changes the text direction.
+///
+/// Markdown has no notion of direction, so right-to-left sections lose their layout
+/// unless the direction is carried in the output text itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BidiMode {
+    /// Ignore `dir` attributes. Direction marks already present in the text are kept. Default.
+    #[default]
+    Ignore,
+    /// Prefix each line of a direction change with an RLM (U+200F) or LRM (U+200E) mark.
+    Marks,
+    /// Wrap each line of a direction change in RLE/LRE (U+202B/U+202A) and PDF (U+202C).
+    Embedding,
+    /// Wrap the converted content in a `<div dir="...">` (or `<span dir="...">`) passthrough.
+    Html,
+}
+
+impl BidiMode {
+    /// Parse a BiDi mode from a string.
+    ///
+    /// Accepts "marks", "embedding" or "html", defaults to Ignore.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "marks" => Self::Marks,
+            "embedding" => Self::Embedding,
+            "html" => Self::Html,
+            _ => Self::Ignore,
+        }
+    }
+}
+
+/// Spacing between list items.
+///
+/// Renderers style tight lists (items on consecutive lines) and loose lists (items separated
+/// by blank lines) differently, so mixing both in one document looks inconsistent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListSpacing {
+    /// Follow the source: a list is loose when any item contains a paragraph or other block. Default.
+    #[default]
+    Auto,
+    /// Never put blank lines between list items.
+    ///
+    /// An item holding several paragraphs still needs blank lines between them, so such lists
+    /// can remain loose by CommonMark rules.
+    Tight,
+    /// Always put a blank line between list items.
+    Loose,
+}
+
+impl ListSpacing {
+    /// Parse a list spacing mode from a string.
+    ///
+    /// Accepts "tight" or "loose", defaults to Auto.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "tight" => Self::Tight,
+            "loose" => Self::Loose,
+            _ => Self::Auto,
+        }
+    }
+}
+
+/// How the text of inline `<svg>` elements (`<title>`, `<desc>` and `<text>` labels) is surfaced.
+///
+/// Charts and diagrams often carry their labels as SVG text, which is lost once the element is
+/// reduced to an opaque data URI or a captured inline image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SvgTextMode {
+    /// Only the `<title>` is used, as the image alt text. Default.
+    #[default]
+    Off,
+    /// Use all extracted text as the image alt text.
+    Alt,
+    /// Keep the image and add the extracted text as an italic caption below it.
+    Caption,
+    /// Emit the extracted text instead of the image, and skip inline image capture.
+    ///
+    /// SVGs without any text are still emitted as images.
+    Replace,
+}
+
+impl SvgTextMode {
+    /// Parse an SVG text mode from a string.
+    ///
+    /// Accepts "alt", "caption" or "replace", defaults to Off.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "alt" => Self::Alt,
+            "caption" => Self::Caption,
+            "replace" => Self::Replace,
+            _ => Self::Off,
+        }
+    }
+}
+
+/// How `<math>` elements and TeX embedded in HTML are written.
+///
+/// TeX is taken from a MathML `application/x-tex` annotation when present and otherwise
+/// translated from the MathML markup. Besides `<math>`, the `Dollar` and `Fenced` styles also
+/// pick up MathJax payloads: `<script type="math/tex">` and `\(...\)` / `\[...\]` delimiters
+/// in text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MathStyle {
+    /// Keep `<math>` as an HTML comment followed by its plain text. Default.
+    #[default]
+    Comment,
+    /// `$...$` inline and `$$...$$` display math (Pandoc, Obsidian, GitHub).
+    Dollar,
+    /// ``$`...`$`` inline math and ```` ```math ```` fenced display blocks (GitLab, GitHub).
+    Fenced,
+}
+
+impl MathStyle {
+    /// Parse a math style from a string.
+    ///
+    /// Accepts "dollar" or "fenced", defaults to Comment.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "dollar" => Self::Dollar,
+            "fenced" => Self::Fenced,
+            _ => Self::Comment,
+        }
+    }
+}
+
+/// Which `srcset` candidate stands in for an `<img>` without a usable `src`.
+///
+/// Candidates come from the image's own `srcset` and, inside `<picture>`, from the `srcset` of
+/// its `<source>` elements. Sizes compare the `w` or `x` descriptor; a candidate without one
+/// counts as `1x`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SrcsetPolicy {
+    /// The first candidate in document order. Default.
+    #[default]
+    First,
+    /// The candidate with the largest descriptor.
+    Largest,
+    /// The candidate with the smallest descriptor.
+    Smallest,
+}
+
+impl SrcsetPolicy {
+    /// Parse a srcset policy from a string.
+    ///
+    /// Accepts "largest" or "smallest", defaults to First.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "largest" => Self::Largest,
+            "smallest" => Self::Smallest,
+            _ => Self::First,
+        }
+    }
+}
+
+/// How `<figure>` elements and their `<figcaption>` are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FigureStyle {
+    /// The figure content followed by the caption as an italic paragraph. Default.
+    #[default]
+    Italic,
+    /// The figure content followed by the caption as a blockquote.
+    Blockquote,
+    /// Keep the whole figure as HTML.
+    Html,
+}
+
+impl FigureStyle {
+    /// Parse a figure style from a string.
+    ///
+    /// Accepts "blockquote" or "html", defaults to Italic.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "blockquote" => Self::Blockquote,
+            "html" => Self::Html,
+            _ => Self::Italic,
+        }
+    }
+}
+
+/// How `<details>` disclosure widgets and their `<summary>` are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DetailsStyle {
+    /// The summary as a bold line followed by the body. Default.
+    #[default]
+    Markdown,
+    /// Keep the `<details>`/`<summary>` wrapper as HTML, which GitHub renders as a collapsible
+    /// section, and convert the body to Markdown inside it.
+    Html,
+}
+
+impl DetailsStyle {
+    /// Parse a details style from a string.
+    ///
+    /// Accepts "html", defaults to Markdown.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "html" => Self::Html,
+            _ => Self::Markdown,
+        }
+    }
+}
+
+/// How `<abbr>` abbreviations are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AbbrStyle {
+    /// The abbreviation followed by its `title` in parentheses: `HTML (HyperText Markup Language)`.
+    /// Default.
+    #[default]
+    Expand,
+    /// The abbreviation only; the `title` is dropped.
+    Text,
+    /// Keep the `<abbr>` element as inline HTML so renderers show the title as a tooltip.
+    Html,
+}
+
+impl AbbrStyle {
+    /// Parse an abbreviation style from a string.
+    ///
+    /// Accepts "text" or "html", defaults to Expand.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "text" => Self::Text,
+            "html" => Self::Html,
+            _ => Self::Expand,
+        }
+    }
+}
+
+/// How `<time>` elements are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeStyle {
+    /// The human-readable text only. Default.
+    #[default]
+    Text,
+    /// The text followed by the machine-readable `datetime` value in parentheses when the two
+    /// differ: `next Tuesday (2024-05-14)`.
+    Datetime,
+    /// Keep the `<time>` element, including its `datetime` attribute, as inline HTML.
+    Html,
+}
+
+impl TimeStyle {
+    /// Parse a time style from a string.
+    ///
+    /// Accepts "datetime" or "html", defaults to Text.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "datetime" => Self::Datetime,
+            "html" => Self::Html,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// How `<dfn>` defining instances are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DfnStyle {
+    /// Emphasis with `strong_em_symbol`: `*term*`. Default.
+    #[default]
+    Emphasis,
+    /// Plain text.
+    Text,
+    /// Keep the `<dfn>` element as inline HTML.
+    Html,
+}
+
+impl DfnStyle {
+    /// Parse a definition style from a string.
+    ///
+    /// Accepts "text" or "html", defaults to Emphasis.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "text" => Self::Text,
+            "html" => Self::Html,
+            _ => Self::Emphasis,
+        }
+    }
+}
+
+/// How `mailto:` and `tel:` links are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContactLinkStyle {
+    /// A regular link that keeps the scheme: `[ada@example.com](mailto:ada@example.com)`, or an
+    /// autolink when `autolinks` is set and the text is the address. Default.
+    #[default]
+    Link,
+    /// An autolink when the text is the address, ignoring case, the `mailto:` query and phone
+    /// number punctuation: `<ada@example.com>`, `<tel:+1-555-0100>`. Other links stay regular links.
+    Autolink,
+    /// Plain text: the link text, followed by the address in parentheses when they differ.
+    Text,
+}
+
+impl ContactLinkStyle {
+    /// Parse a contact link style from a string.
+    ///
+    /// Accepts "autolink" or "text", defaults to Link.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "autolink" => Self::Autolink,
+            "text" => Self::Text,
+            _ => Self::Link,
+        }
+    }
+}
+
+/// What `<br>` becomes inside a heading or a `<th>` header cell, where a real line break would
+/// end the heading or the table row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeaderBreakStyle {
+    /// Two spaces in headings; header cells follow `br_in_tables` like body cells. Default.
+    #[default]
+    Inherit,
+    /// A single space: `Title Subtitle`.
+    Space,
+    /// A spaced slash: `Title / Subtitle`.
+    Slash,
+    /// An inline `<br>` tag: `Title<br>Subtitle`.
+    Html,
+}
+
+impl HeaderBreakStyle {
+    /// Parse a header break style from a string.
+    ///
+    /// Accepts "space", "slash" or "html", defaults to Inherit.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "space" => Self::Space,
+            "slash" => Self::Slash,
+            "html" => Self::Html,
+            _ => Self::Inherit,
+        }
+    }
+}
+
+/// What to do with content that has no lossless Markdown representation.
+///
+/// Covers `<bdo>`/`<bdi>` direction overrides and isolation, `<wbr>` line break opportunities,
+/// and invisible formatting characters removed by `remove_soft_hyphens` (soft hyphens and
+/// word joiners).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnrepresentablePolicy {
+    /// Drop the information silently. Default.
+    #[default]
+    Drop,
+    /// Keep elements as inline HTML and leave invisible formatting characters in the text.
+    Passthrough,
+    /// Drop the information and record a [`Diagnostic`](crate::Diagnostic) for each occurrence.
+    Diagnose,
+}
+
+impl UnrepresentablePolicy {
+    /// Parse an unrepresentable content policy from a string.
+    ///
+    /// Accepts "passthrough" or "diagnose", defaults to Drop.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match normalize_token(value).as_str() {
+            "passthrough" => Self::Passthrough,
+            "diagnose" => Self::Diagnose,
+            _ => Self::Drop,
+        }
+    }
+}
+
+/// Where to look for a title when an element has none of its own.
+///
+/// Used by `title_fallback`, which lists sources in the order they are tried. A link's own
+/// `title` attribute and the document's `<title>` always take precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TitleSource {
+    /// The document's `og:title` meta tag. Only applies to the front matter title.
+    OpenGraph,
+    /// Text of the first heading: inside the link for links, in the document for front matter.
+    FirstHeading,
+    /// Last path segment of the URL: the link's `href`, or the document's canonical, `og:url`
+    /// or `<base>` URL for front matter.
+    UrlBasename,
+}
+
+impl TitleSource {
+    /// Parse a title source from a string.
+    ///
+    /// Accepts "og-title" (or "open-graph"), "first-heading" (or "heading") and "url-basename"
+    /// (or "url"); returns `None` for anything else.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match normalize_token(value).as_str() {
+            "ogtitle" | "opengraph" => Some(Self::OpenGraph),
+            "firstheading" | "heading" => Some(Self::FirstHeading),
+            "urlbasename" | "url" => Some(Self::UrlBasename),
+            _ => None,
+        }
+    }
+
+    /// Parse a list of title sources, skipping names that are not recognized.
+    #[must_use]
+    pub fn from_names<S: AsRef<str>>(names: &[S]) -> Vec<Self> {
+        names.iter().filter_map(|name| Self::parse(name.as_ref())).collect()
+    }
+}
+
 /// Line break syntax in Markdown output.
 ///
 /// Controls how soft line breaks (from `<br>` or line breaks in source) are rendered.
@@ -182,36 +719,181 @@ pub enum PreprocessingPreset {
     Standard,
     /// Aggressive cleanup. Remove extensive non-content elements and structure.
     Aggressive,
+    /// Standard cleanup plus handling for Confluence storage-format markup.
+    ///
+    /// Drops `<colgroup>`, unwraps `ac:structured-macro`/`ac:rich-text-body`, renders
+    /// info/note/tip/warning macros as blockquotes with an emoji label, turns code macros into
+    /// code blocks and `ac:image` into Markdown images.
+    Confluence,
 }
 
 impl PreprocessingPreset {
     /// Parse a preprocessing preset from a string.
     ///
-    /// Accepts "minimal", "aggressive", or defaults to Standard.
+    /// Accepts "minimal", "aggressive", "confluence", or defaults to Standard.
     /// Input is normalized (lowercased, alphanumeric only).
     #[must_use]
     pub fn parse(value: &str) -> Self {
         match normalize_token(value).as_str() {
             "minimal" => Self::Minimal,
             "aggressive" => Self::Aggressive,
+            "confluence" => Self::Confluence,
             _ => Self::Standard,
         }
     }
 }
 
-/// Main conversion options for HTML to Markdown conversion.
-#[derive(Debug, Clone)]
-pub struct ConversionOptions {
-    /// Heading style (Underlined, Atx, `AtxClosed`)
-    pub heading_style: HeadingStyle,
-
-    /// List indentation type (Spaces or Tabs)
-    pub list_indent_type: ListIndentType,
-
-    /// List indentation width in spaces (applied if using spaces indentation)
-    pub list_indent_width: usize,
+/// Named bundle of conversion options for a common use, as returned by
+/// [`ConversionOptions::preset`].
+///
+/// Each preset starts from the defaults and changes only the fields listed for it; options set
+/// afterwards (config files, command-line flags, [`ConversionOptions::apply_update`]) override it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionPreset {
+    /// Replies and snippets shown in chat interfaces: minimal preprocessing, fenced code with an
+    /// inferred language, `$` math, tight lists, compacted blank lines and no front matter.
+    Chat,
+    /// Documentation sites: standard preprocessing (navigation and forms removed), fenced code with
+    /// an inferred language, `$` math and heading ids kept so `#fragment` links still resolve.
+    Docs,
+    /// Arbitrary web pages: aggressive preprocessing of the main content with print styles
+    /// applied, title fallbacks for front matter, tracking parameters stripped from URLs and empty
+    /// headings, list items and links pruned.
+    Scraping,
+    /// Text for language model prompts: the scraping cleanup, plain tables without HTML line
+    /// breaks, no escaping, no highlight markup, no front matter and lines wrapped at 100 columns.
+    Llm,
+}
 
-    /// Bullet characters for unordered lists (e.g., "-", "*", "+")
+impl ConversionPreset {
+    /// Parse a preset from its name ("chat", "docs", "scraping" or "llm").
+    ///
+    /// Returns `None` for anything else.
+    /// Input is normalized (lowercased, alphanumeric only).
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match normalize_token(value).as_str() {
+            "chat" => Some(Self::Chat),
+            "docs" => Some(Self::Docs),
+            "scraping" => Some(Self::Scraping),
+            "llm" => Some(Self::Llm),
+            _ => None,
+        }
+    }
+
+    /// The conversion options this preset stands for.
+    #[must_use]
+    pub fn options(self) -> ConversionOptions {
+        let defaults = ConversionOptions::default();
+        let scraping_preprocessing = PreprocessingOptions {
+            enabled: true,
+            preset: PreprocessingPreset::Aggressive,
+            apply_print_styles: true,
+            extract_main_content: true,
+            ..PreprocessingOptions::default()
+        };
+        match self {
+            Self::Chat => ConversionOptions {
+                preprocessing: PreprocessingOptions {
+                    enabled: true,
+                    preset: PreprocessingPreset::Minimal,
+                    ..PreprocessingOptions::default()
+                },
+                code_block_style: CodeBlockStyle::Backticks,
+                infer_code_language: true,
+                math_style: MathStyle::Dollar,
+                list_spacing: ListSpacing::Tight,
+                extract_metadata: false,
+                remove_space_before_punctuation: true,
+                post_processors: PostProcessorChain::from_names(&["compact-blank-lines"]),
+                ..defaults
+            },
+            Self::Docs => ConversionOptions {
+                preprocessing: PreprocessingOptions {
+                    enabled: true,
+                    preset: PreprocessingPreset::Standard,
+                    ..PreprocessingOptions::default()
+                },
+                code_block_style: CodeBlockStyle::Backticks,
+                infer_code_language: true,
+                math_style: MathStyle::Dollar,
+                preserve_heading_ids: true,
+                ..defaults
+            },
+            Self::Scraping => ConversionOptions {
+                preprocessing: scraping_preprocessing,
+                code_block_style: CodeBlockStyle::Backticks,
+                title_fallback: vec![TitleSource::OpenGraph, TitleSource::FirstHeading],
+                post_processors: PostProcessorChain::from_names(&[
+                    "normalize-urls",
+                    "prune-empty-elements",
+                    "compact-blank-lines",
+                ]),
+                ..defaults
+            },
+            Self::Llm => ConversionOptions {
+                preprocessing: scraping_preprocessing,
+                code_block_style: CodeBlockStyle::Backticks,
+                infer_code_language: true,
+                math_style: MathStyle::Dollar,
+                highlight_style: HighlightStyle::None,
+                br_in_tables: false,
+                nbsp_in_prose: NbspMode::Space,
+                nbsp_in_tables: NbspMode::Space,
+                escape_asterisks: false,
+                escape_underscores: false,
+                escape_misc: false,
+                escape_ascii: false,
+                extract_metadata: false,
+                wrap: true,
+                wrap_width: 100,
+                post_processors: PostProcessorChain::from_names(&[
+                    "normalize-urls",
+                    "prune-empty-elements",
+                    "compact-blank-lines",
+                ]),
+                ..defaults
+            },
+        }
+    }
+}
+
+/// Main conversion options for HTML to Markdown conversion.
+///
+/// # Thread safety
+///
+/// `ConversionOptions` is `Send + Sync` and holds only owned data without interior
+/// mutability. Conversion never modifies it, so a single value can be shared by reference
+/// across threads converting different documents concurrently.
+///
+/// # Serialization
+///
+/// With the `serde` (or `metadata`) feature the options serialize to the camelCase JSON accepted
+/// by [`conversion_options_from_json`](crate::conversion_options_from_json), and deserialize from
+/// complete or partial objects, filling in defaults. Custom post-processor closures serialize as
+/// their names only and cannot be restored.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    any(feature = "serde", feature = "metadata"),
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(
+    any(feature = "serde", feature = "metadata"),
+    serde(rename_all = "camelCase", from = "ConversionOptionsUpdate")
+)]
+pub struct ConversionOptions {
+    /// Heading style (Underlined, Atx, `AtxClosed`)
+    pub heading_style: HeadingStyle,
+
+    /// List indentation type (Spaces or Tabs)
+    pub list_indent_type: ListIndentType,
+
+    /// List indentation width in spaces (applied if using spaces indentation)
+    pub list_indent_width: usize,
+
+    /// Bullet characters for unordered lists (e.g., "-", "*", "+")
+    ///
+    /// One character per nesting level, cycling when lists nest deeper (see `cycle_bullets`).
     pub bullets: String,
 
     /// Symbol for strong/emphasis emphasis rendering (* or _)
@@ -235,7 +917,9 @@ pub struct ConversionOptions {
     /// Use autolinks syntax for bare URLs (<http://example.com>)
     pub autolinks: bool,
 
-    /// Add default title element to HTML if none exists before conversion
+    /// Use a link's URL as its title when the link text is the URL itself
+    ///
+    /// See `title_fallback` for a configurable chain of title sources.
     pub default_title: bool,
 
     /// Use HTML <br> elements in tables instead of spaces for line breaks
@@ -292,6 +976,9 @@ pub struct ConversionOptions {
     /// HTML tags to strip (extract text content, no markdown conversion)
     pub strip_tags: Vec<String>,
 
+    /// HTML tags to remove together with all of their content (e.g. `aside`, cookie banners)
+    pub remove_tags: Vec<String>,
+
     /// HTML tags to preserve as-is in output (keep original HTML, useful for complex tables)
     pub preserve_tags: Vec<String>,
 
@@ -299,6 +986,169 @@ pub struct ConversionOptions {
     /// When enabled, all `<img>` elements are completely omitted from output.
     /// Useful for text-only extraction or filtering out visual content.
     pub skip_images: bool,
+
+    /// Remove whitespace left between an inline element and following punctuation
+    /// (e.g. `word **bold** .` becomes `word **bold**.`)
+    pub remove_space_before_punctuation: bool,
+
+    /// Non-breaking space handling in paragraphs, headings, lists and other running text
+    /// (Space converts U+00A0 to a regular space, Preserve keeps it)
+    pub nbsp_in_prose: NbspMode,
+
+    /// Non-breaking space handling in table cells (Space converts, Preserve keeps U+00A0)
+    pub nbsp_in_tables: NbspMode,
+
+    /// Serialization format for the metadata block emitted when `extract_metadata` is enabled
+    pub front_matter_format: FrontMatterFormat,
+
+    /// Remove invisible soft hyphens (U+00AD) and word joiners (U+2060, U+FEFF) from text
+    /// (code spans and blocks are left untouched)
+    pub remove_soft_hyphens: bool,
+
+    /// Handling of `dir="rtl"`/`dir="ltr"` direction changes (ignore, direction marks,
+    /// embedding controls, or an HTML `dir` passthrough wrapper)
+    pub bidi_mode: BidiMode,
+
+    /// Use the quotation marks, parentheses and dashes of the element's `lang` when the converter
+    /// inserts punctuation itself (`<q>` quotes, `<abbr>` expansions, blockquote attributions)
+    pub localized_punctuation: bool,
+
+    /// Delimiters for ordered list markers, one per nesting level (`.` or `)`)
+    ///
+    /// Cycles like `bullets`: `".)"` renders `1.` at the top level and `1)` one level down.
+    pub ordered_markers: String,
+
+    /// Cycle through `bullets` by nesting level; when false the first bullet is used at every level
+    pub cycle_bullets: bool,
+
+    /// Spacing between list items: follow the source (auto), or force tight or loose lists
+    pub list_spacing: ListSpacing,
+
+    /// Handling of content without a lossless Markdown form (`<bdo>`, `<bdi>`, `<wbr>`, removed
+    /// soft hyphens): drop it, pass it through, or drop it and record a diagnostic
+    pub unrepresentable_policy: UnrepresentablePolicy,
+
+    /// Post-processors run on the Markdown after conversion and before wrapping, in order
+    pub post_processors: PostProcessorChain,
+
+    /// Collect a resource usage report (timings per phase, nodes parsed, output size, peak memory
+    /// estimate), returned by `convert_with_stats`
+    pub collect_stats: bool,
+
+    /// Title sources tried in order when a link has no `title` attribute or the document has no
+    /// `<title>` (front matter); empty disables the fallback
+    ///
+    /// A link that ends up with a title but no text also uses the title as its text.
+    pub title_fallback: Vec<TitleSource>,
+
+    /// Emit Pandoc attribute blocks (`{#id .class}`) for headings, fenced code blocks, links and
+    /// images so element ids and classes survive conversion. Images also keep `width`/`height`.
+    pub pandoc_attributes: bool,
+
+    /// How the `<title>`, `<desc>` and `<text>` labels of inline SVGs are surfaced: as alt text, as a
+    /// caption below the image, or instead of it
+    pub svg_text_mode: SvgTextMode,
+
+    /// Append a link to the `data`/`src` URL of `<object>` and `<embed>` elements after their
+    /// fallback content, unless the fallback already links to it.
+    pub object_links: bool,
+
+    /// Keep heading `id`s so `#fragment` links still resolve: as an empty `<a id="..."></a>` anchor
+    /// before the heading text, or as `{#id}` when `pandoc_attributes` is on.
+    pub preserve_heading_ids: bool,
+
+    /// Compute a fingerprint of the output's text that ignores whitespace and Markdown syntax,
+    /// returned by [`convert_with_fingerprint`](crate::convert_with_fingerprint).
+    pub content_fingerprint: bool,
+
+    /// How `<math>` and MathJax TeX payloads are written: as an HTML comment with plain text,
+    /// as `$...$`/`$$...$$`, or as ``$`...`$`` and ```` ```math ```` fences
+    pub math_style: MathStyle,
+
+    /// Emit a fence language for code blocks without a `language-*`/`lang-*` class, taken from
+    /// highlighter classes (`brush: js`, `highlight-source-python`, `sourceCode python`), `data-lang`
+    /// attributes or the code itself. Explicit classes are emitted as the fence language too.
+    pub infer_code_language: bool,
+
+    /// Drop blank lines at the start and end of `<pre>` code blocks. Whitespace within the block's
+    /// lines is always kept as written.
+    pub code_block_trim: bool,
+
+    /// Which `srcset` or `<picture><source>` candidate is used for an `<img>` without a `src`:
+    /// the first one, the largest or the smallest
+    pub srcset_policy: SrcsetPolicy,
+
+    /// How `<figure>` captions are written: as an italic paragraph after the figure content, as a
+    /// blockquote, or with the whole figure kept as HTML
+    pub figure_style: FigureStyle,
+
+    /// How `<details>` sections are written: the summary as a bold line followed by the body, or the
+    /// `<details>`/`<summary>` wrapper kept as HTML around the converted body
+    pub details_style: DetailsStyle,
+
+    /// How `<abbr>` is written: the abbreviation followed by its `title` in parentheses, the
+    /// abbreviation alone, or the element kept as inline HTML
+    pub abbr_style: AbbrStyle,
+
+    /// How `<time>` is written: its text, its text followed by the machine-readable `datetime` value
+    /// in parentheses, or the element kept as inline HTML
+    pub time_style: TimeStyle,
+
+    /// How `<dfn>` is written: as emphasis, as plain text, or kept as inline HTML
+    pub dfn_style: DfnStyle,
+
+    /// Query parameters the `normalize-urls` post-processor keeps; when non-empty, every other
+    /// parameter is dropped and `url_param_denylist` is ignored. A trailing `*` matches any suffix
+    pub url_param_allowlist: Vec<String>,
+
+    /// Query parameters the `normalize-urls` post-processor drops from link and image URLs. A trailing
+    /// `*` matches any suffix. Defaults to common tracking parameters (`utm_*`, `fbclid`, `gclid`, ...)
+    pub url_param_denylist: Vec<String>,
+
+    /// How `mailto:` and `tel:` links are written: as regular links, as autolinks when the text is the
+    /// address, or as plain text
+    pub contact_link_style: ContactLinkStyle,
+
+    /// Keep a table, form or `<math>` subtree as sanitized HTML when more than this fraction of its
+    /// structural elements would be lost in Markdown. `1.0` turns the guard off
+    pub fidelity_threshold: f64,
+
+    /// Turn `title` attributes on links, abbreviations and spans into footnotes, with the definitions
+    /// written at the end of each section
+    pub title_footnotes: bool,
+
+    /// What `<br>` becomes inside headings and `<th>` header cells
+    pub header_break_style: HeaderBreakStyle,
+
+    /// Record how much text each removal rule (navigation and form removal, `remove_tags`,
+    /// exclusion and inclusion selectors, print styles, main content extraction) dropped, as
+    /// [`DiagnosticKind::DroppedContent`](crate::DiagnosticKind::DroppedContent) diagnostics
+    pub report_dropped_content: bool,
+
+    /// Convert input holding several complete HTML documents (concatenated pages, log exports,
+    /// email digests) one document at a time, separated by thematic breaks
+    ///
+    /// Applies to [`convert`](crate::convert) and the functions built on it, including the diagnostics,
+    /// progress and stats variants; the metadata, inline image and visitor variants still read the
+    /// input as one document.
+    pub split_documents: bool,
+
+    /// Attributes kept on elements written out as HTML (`preserve_tags`, the fidelity guard, HTML
+    /// styles and visitor `PreserveHtml`); when non-empty, every other attribute is dropped. A
+    /// `style:<property>` entry keeps just that declaration of the `style` attribute
+    pub preserved_attributes: Vec<String>,
+
+    /// Cap the output at this many characters, cutting between top-level blocks and ending with
+    /// [`TRUNCATION_MARKER`](crate::TRUNCATION_MARKER); `None` leaves the length unbounded
+    pub max_output_chars: Option<usize>,
+
+    /// Cap the output at this many tokens, as counted by `token_estimator`, cutting the same way
+    /// as `max_output_chars`; `None` leaves the length unbounded
+    pub max_output_tokens: Option<usize>,
+
+    /// Token counter for `max_output_tokens`; defaults to one token per four characters
+    #[cfg_attr(any(feature = "serde", feature = "metadata"), serde(skip))]
+    pub token_estimator: TokenEstimator,
 }
 
 /// Partial update for `ConversionOptions`.
@@ -400,11 +1250,131 @@ pub struct ConversionOptionsUpdate {
     /// Optional HTML tags to strip override (extract text, no conversion)
     pub strip_tags: Option<Vec<String>>,
 
+    /// Optional HTML tags to remove with their content override
+    pub remove_tags: Option<Vec<String>>,
+
     /// Optional HTML tags to preserve as-is override in output
     pub preserve_tags: Option<Vec<String>>,
 
     /// Optional skip images override
     pub skip_images: Option<bool>,
+
+    /// Optional whitespace-before-punctuation cleanup override
+    pub remove_space_before_punctuation: Option<bool>,
+
+    /// Optional non-breaking space handling override for prose
+    pub nbsp_in_prose: Option<NbspMode>,
+
+    /// Optional non-breaking space handling override for table cells
+    pub nbsp_in_tables: Option<NbspMode>,
+
+    /// Optional front matter format override
+    pub front_matter_format: Option<FrontMatterFormat>,
+
+    /// Optional soft hyphen / word joiner removal override
+    pub remove_soft_hyphens: Option<bool>,
+
+    /// Optional BiDi handling override
+    pub bidi_mode: Option<BidiMode>,
+
+    /// Optional localized punctuation override
+    pub localized_punctuation: Option<bool>,
+
+    /// Optional ordered list delimiter override
+    pub ordered_markers: Option<String>,
+
+    /// Optional bullet cycling override
+    pub cycle_bullets: Option<bool>,
+
+    /// Optional list spacing override
+    pub list_spacing: Option<ListSpacing>,
+
+    /// Optional unrepresentable content policy override
+    pub unrepresentable_policy: Option<UnrepresentablePolicy>,
+
+    /// Optional post-processor chain override, as built-in names in order
+    pub post_processors: Option<Vec<String>>,
+
+    /// Optional resource usage report override
+    pub collect_stats: Option<bool>,
+
+    /// Optional title fallback chain override, as source names in order
+    pub title_fallback: Option<Vec<String>>,
+
+    /// Optional Pandoc attributes override
+    pub pandoc_attributes: Option<bool>,
+
+    /// Optional SVG text mode override
+    pub svg_text_mode: Option<SvgTextMode>,
+
+    /// Optional object links override
+    pub object_links: Option<bool>,
+
+    /// Optional heading id preservation override
+    pub preserve_heading_ids: Option<bool>,
+
+    /// Optional content fingerprint override
+    pub content_fingerprint: Option<bool>,
+
+    /// Optional math style override
+    pub math_style: Option<MathStyle>,
+
+    /// Optional code language inference override
+    pub infer_code_language: Option<bool>,
+
+    /// Optional code block trimming override
+    pub code_block_trim: Option<bool>,
+
+    /// Optional srcset policy override
+    pub srcset_policy: Option<SrcsetPolicy>,
+
+    /// Optional figure style override
+    pub figure_style: Option<FigureStyle>,
+
+    /// Optional details style override
+    pub details_style: Option<DetailsStyle>,
+
+    /// Optional abbreviation style override
+    pub abbr_style: Option<AbbrStyle>,
+
+    /// Optional time style override
+    pub time_style: Option<TimeStyle>,
+
+    /// Optional definition style override
+    pub dfn_style: Option<DfnStyle>,
+
+    /// Optional URL query parameter allowlist override
+    pub url_param_allowlist: Option<Vec<String>>,
+
+    /// Optional URL query parameter denylist override
+    pub url_param_denylist: Option<Vec<String>>,
+
+    /// Optional contact link style override
+    pub contact_link_style: Option<ContactLinkStyle>,
+
+    /// Optional fidelity threshold override
+    pub fidelity_threshold: Option<f64>,
+
+    /// Optional title footnotes override
+    pub title_footnotes: Option<bool>,
+
+    /// Optional header break style override
+    pub header_break_style: Option<HeaderBreakStyle>,
+
+    /// Optional dropped content reporting override
+    pub report_dropped_content: Option<bool>,
+
+    /// Optional split documents override
+    pub split_documents: Option<bool>,
+
+    /// Optional preserved HTML attribute allowlist override
+    pub preserved_attributes: Option<Vec<String>>,
+
+    /// Optional output character budget override (0 removes the budget)
+    pub max_output_chars: Option<usize>,
+
+    /// Optional output token budget override (0 removes the budget)
+    pub max_output_tokens: Option<usize>,
 }
 
 impl Default for ConversionOptions {
@@ -440,8 +1410,49 @@ impl Default for ConversionOptions {
             encoding: "utf-8".to_string(),
             debug: false,
             strip_tags: Vec::new(),
+            remove_tags: Vec::new(),
             preserve_tags: Vec::new(),
             skip_images: false,
+            remove_space_before_punctuation: false,
+            nbsp_in_prose: NbspMode::Space,
+            nbsp_in_tables: NbspMode::Space,
+            front_matter_format: FrontMatterFormat::Yaml,
+            remove_soft_hyphens: true,
+            bidi_mode: BidiMode::Ignore,
+            localized_punctuation: false,
+            ordered_markers: ".".to_string(),
+            cycle_bullets: true,
+            list_spacing: ListSpacing::Auto,
+            unrepresentable_policy: UnrepresentablePolicy::Drop,
+            post_processors: PostProcessorChain::default(),
+            collect_stats: false,
+            title_fallback: Vec::new(),
+            pandoc_attributes: false,
+            svg_text_mode: SvgTextMode::Off,
+            object_links: false,
+            preserve_heading_ids: false,
+            content_fingerprint: false,
+            math_style: MathStyle::Comment,
+            infer_code_language: false,
+            code_block_trim: true,
+            srcset_policy: SrcsetPolicy::First,
+            figure_style: FigureStyle::Italic,
+            details_style: DetailsStyle::Markdown,
+            abbr_style: AbbrStyle::Expand,
+            time_style: TimeStyle::Text,
+            dfn_style: DfnStyle::Emphasis,
+            url_param_allowlist: Vec::new(),
+            url_param_denylist: DEFAULT_URL_PARAM_DENYLIST.iter().map(ToString::to_string).collect(),
+            contact_link_style: ContactLinkStyle::Link,
+            fidelity_threshold: 1.0,
+            title_footnotes: false,
+            header_break_style: HeaderBreakStyle::Inherit,
+            report_dropped_content: false,
+            split_documents: false,
+            preserved_attributes: Vec::new(),
+            max_output_chars: None,
+            max_output_tokens: None,
+            token_estimator: TokenEstimator::default(),
         }
     }
 }
@@ -546,12 +1557,132 @@ impl ConversionOptions {
         if let Some(strip_tags) = update.strip_tags {
             self.strip_tags = strip_tags;
         }
+        if let Some(remove_tags) = update.remove_tags {
+            self.remove_tags = remove_tags;
+        }
         if let Some(preserve_tags) = update.preserve_tags {
             self.preserve_tags = preserve_tags;
         }
         if let Some(skip_images) = update.skip_images {
             self.skip_images = skip_images;
         }
+        if let Some(remove_space_before_punctuation) = update.remove_space_before_punctuation {
+            self.remove_space_before_punctuation = remove_space_before_punctuation;
+        }
+        if let Some(nbsp_in_prose) = update.nbsp_in_prose {
+            self.nbsp_in_prose = nbsp_in_prose;
+        }
+        if let Some(nbsp_in_tables) = update.nbsp_in_tables {
+            self.nbsp_in_tables = nbsp_in_tables;
+        }
+        if let Some(front_matter_format) = update.front_matter_format {
+            self.front_matter_format = front_matter_format;
+        }
+        if let Some(remove_soft_hyphens) = update.remove_soft_hyphens {
+            self.remove_soft_hyphens = remove_soft_hyphens;
+        }
+        if let Some(bidi_mode) = update.bidi_mode {
+            self.bidi_mode = bidi_mode;
+        }
+        if let Some(localized_punctuation) = update.localized_punctuation {
+            self.localized_punctuation = localized_punctuation;
+        }
+        if let Some(ordered_markers) = update.ordered_markers {
+            self.ordered_markers = ordered_markers;
+        }
+        if let Some(cycle_bullets) = update.cycle_bullets {
+            self.cycle_bullets = cycle_bullets;
+        }
+        if let Some(list_spacing) = update.list_spacing {
+            self.list_spacing = list_spacing;
+        }
+        if let Some(unrepresentable_policy) = update.unrepresentable_policy {
+            self.unrepresentable_policy = unrepresentable_policy;
+        }
+        if let Some(post_processors) = update.post_processors {
+            self.post_processors = PostProcessorChain::from_names(&post_processors);
+        }
+        if let Some(collect_stats) = update.collect_stats {
+            self.collect_stats = collect_stats;
+        }
+        if let Some(title_fallback) = update.title_fallback {
+            self.title_fallback = TitleSource::from_names(&title_fallback);
+        }
+        if let Some(pandoc_attributes) = update.pandoc_attributes {
+            self.pandoc_attributes = pandoc_attributes;
+        }
+        if let Some(svg_text_mode) = update.svg_text_mode {
+            self.svg_text_mode = svg_text_mode;
+        }
+        if let Some(object_links) = update.object_links {
+            self.object_links = object_links;
+        }
+        if let Some(preserve_heading_ids) = update.preserve_heading_ids {
+            self.preserve_heading_ids = preserve_heading_ids;
+        }
+        if let Some(content_fingerprint) = update.content_fingerprint {
+            self.content_fingerprint = content_fingerprint;
+        }
+        if let Some(math_style) = update.math_style {
+            self.math_style = math_style;
+        }
+        if let Some(infer_code_language) = update.infer_code_language {
+            self.infer_code_language = infer_code_language;
+        }
+        if let Some(code_block_trim) = update.code_block_trim {
+            self.code_block_trim = code_block_trim;
+        }
+        if let Some(srcset_policy) = update.srcset_policy {
+            self.srcset_policy = srcset_policy;
+        }
+        if let Some(figure_style) = update.figure_style {
+            self.figure_style = figure_style;
+        }
+        if let Some(details_style) = update.details_style {
+            self.details_style = details_style;
+        }
+        if let Some(abbr_style) = update.abbr_style {
+            self.abbr_style = abbr_style;
+        }
+        if let Some(time_style) = update.time_style {
+            self.time_style = time_style;
+        }
+        if let Some(dfn_style) = update.dfn_style {
+            self.dfn_style = dfn_style;
+        }
+        if let Some(url_param_allowlist) = update.url_param_allowlist {
+            self.url_param_allowlist = url_param_allowlist;
+        }
+        if let Some(url_param_denylist) = update.url_param_denylist {
+            self.url_param_denylist = url_param_denylist;
+        }
+        if let Some(contact_link_style) = update.contact_link_style {
+            self.contact_link_style = contact_link_style;
+        }
+        if let Some(fidelity_threshold) = update.fidelity_threshold {
+            self.fidelity_threshold = fidelity_threshold;
+        }
+        if let Some(title_footnotes) = update.title_footnotes {
+            self.title_footnotes = title_footnotes;
+        }
+        if let Some(header_break_style) = update.header_break_style {
+            self.header_break_style = header_break_style;
+        }
+        if let Some(report_dropped_content) = update.report_dropped_content {
+            self.report_dropped_content = report_dropped_content;
+        }
+        if let Some(split_documents) = update.split_documents {
+            self.split_documents = split_documents;
+        }
+        if let Some(preserved_attributes) = update.preserved_attributes {
+            self.preserved_attributes = preserved_attributes;
+        }
+        if let Some(max_output_chars) = update.max_output_chars {
+            self.max_output_chars = Some(max_output_chars).filter(|&max| max > 0);
+        }
+        if let Some(max_output_tokens) = update.max_output_tokens {
+            self.max_output_tokens = Some(max_output_tokens).filter(|&max| max > 0);
+        }
     }
 
     /// Create new conversion options from a partial update.
@@ -580,13 +1711,689 @@ impl From<ConversionOptionsUpdate> for ConversionOptions {
     }
 }
 
+/// Fluent builder for [`ConversionOptions`].
+///
+/// Starts from [`ConversionOptions::default`]; each setter overrides one field and
+/// [`build`](Self::build) validates the combination before handing the options back.
+///
+/// ```
+/// use html_to_markdown_rs::{ConversionOptions, HeadingStyle};
+///
+/// let options = ConversionOptions::builder()
+///     .heading_style(HeadingStyle::Underlined)
+///     .bullets("*+-")
+///     .wrap(true)
+///     .wrap_width(72)
+///     .build()
+///     .unwrap();
+/// assert_eq!(options.wrap_width, 72);
+///
+/// assert!(ConversionOptions::builder().bullets("").build().is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct ConversionOptionsBuilder {
+    options: ConversionOptions,
+}
+
+impl ConversionOptions {
+    /// Start building conversion options from the defaults.
+    pub fn builder() -> ConversionOptionsBuilder {
+        ConversionOptionsBuilder::default()
+    }
+
+    /// Options of the named [`ConversionPreset`] ("chat", "docs", "scraping" or "llm").
+    ///
+    /// ```
+    /// use html_to_markdown_rs::ConversionOptions;
+    ///
+    /// let options = ConversionOptions::preset("llm").unwrap();
+    /// assert!(options.wrap);
+    /// assert!(options.preprocessing.extract_main_content);
+    ///
+    /// assert!(ConversionOptions::preset("blog").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::ConfigError`] when `name` is not a preset.
+    pub fn preset(name: &str) -> crate::error::Result<Self> {
+        ConversionPreset::parse(name)
+            .map(ConversionPreset::options)
+            .ok_or_else(|| {
+                ConversionError::ConfigError(format!(
+                    "unknown preset '{name}', expected one of {}",
+                    ConversionPreset::NAMES.join(", ")
+                ))
+            })
+    }
+
+    /// Every value in these options that the converter cannot honour, in field order.
+    ///
+    /// Checks that `bullets` and `ordered_markers` are non-empty and only use valid markers,
+    /// that `strong_em_symbol` is `*` or `_`, that `wrap_width` is not zero while `wrap` is on,
+    /// and that `sub_symbol`/`sup_symbol` have no line breaks and, when they look like a tag,
+    /// are a single opening tag such as `<sub>`.
+    #[must_use]
+    pub fn violations(&self) -> Vec<OptionViolation> {
+        let mut violations = Vec::new();
+        let mut violation = |field: &'static str, message: String| {
+            violations.push(OptionViolation { field, message });
+        };
+
+        if self.bullets.is_empty() {
+            violation("bullets", "bullets cannot be empty".to_string());
+        }
+        if !matches!(self.strong_em_symbol, '*' | '_') {
+            violation(
+                "strong_em_symbol",
+                format!("strong_em_symbol must be '*' or '_', got {:?}", self.strong_em_symbol),
+            );
+        }
+        if self.wrap && self.wrap_width == 0 {
+            violation(
+                "wrap_width",
+                "wrap_width must be at least 1 when wrap is enabled".to_string(),
+            );
+        }
+        for (field, symbol) in [("sub_symbol", &self.sub_symbol), ("sup_symbol", &self.sup_symbol)] {
+            if symbol.contains(['\n', '\r']) {
+                violation(field, format!("{field} cannot contain line breaks"));
+            } else if symbol.starts_with('<')
+                && (symbol.starts_with("</") || !symbol.ends_with('>') || symbol[1..].contains('<'))
+            {
+                violation(
+                    field,
+                    format!(
+                        "{field} must be a single opening tag such as <{}>, got {symbol:?}",
+                        &field[..3]
+                    ),
+                );
+            }
+        }
+        if self.ordered_markers.is_empty() {
+            violation("ordered_markers", "ordered_markers cannot be empty".to_string());
+        } else if !self.ordered_markers.chars().all(|c| matches!(c, '.' | ')')) {
+            violation(
+                "ordered_markers",
+                format!(
+                    "ordered_markers must only contain '.' or ')', got {:?}",
+                    self.ordered_markers
+                ),
+            );
+        }
+        if !(0.0..=1.0).contains(&self.fidelity_threshold) {
+            violation(
+                "fidelity_threshold",
+                format!(
+                    "fidelity_threshold must be between 0.0 and 1.0, got {}",
+                    self.fidelity_threshold
+                ),
+            );
+        }
+        violations
+    }
+
+    /// Check these options before converting with them.
+    ///
+    /// The language bindings call this on every options object they build, so an invalid
+    /// configuration is rejected with the same message everywhere.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::ConfigError`] listing every entry of
+    /// [`violations`](Self::violations), separated by `"; "`.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        violations_result(self.violations())
+    }
+}
+
+/// A value in [`ConversionOptions`] that [`ConversionOptions::validate`] rejects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionViolation {
+    /// The offending field, spelled as in [`ConversionOptions`].
+    pub field: &'static str,
+    /// What is wrong with it, starting with the field name.
+    pub message: String,
+}
+
+impl fmt::Display for OptionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+fn violations_result(violations: Vec<OptionViolation>) -> crate::error::Result<()> {
+    if violations.is_empty() {
+        return Ok(());
+    }
+    let messages: Vec<String> = violations.into_iter().map(|violation| violation.message).collect();
+    Err(config_error(messages.join("; ")))
+}
+
+/// Read a single-character option, such as `strong_em_symbol`, from the string a binding was given.
+///
+/// # Errors
+///
+/// Returns [`ConversionError::ConfigError`] unless `value` is exactly one character.
+pub fn option_char(field: &str, value: &str) -> crate::error::Result<char> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Ok(ch),
+        _ => Err(config_error(format!(
+            "{field} must be a single character, got {value:?}"
+        ))),
+    }
+}
+
+impl ConversionOptionsBuilder {
+    /// Narrowest `wrap_width` accepted by [`build`](Self::build).
+    pub const MIN_WRAP_WIDTH: usize = 20;
+
+    /// Heading style (Underlined, Atx, `AtxClosed`)
+    pub const fn heading_style(mut self, heading_style: HeadingStyle) -> Self {
+        self.options.heading_style = heading_style;
+        self
+    }
+
+    /// List indentation type (Spaces or Tabs)
+    pub const fn list_indent_type(mut self, list_indent_type: ListIndentType) -> Self {
+        self.options.list_indent_type = list_indent_type;
+        self
+    }
+
+    /// List indentation width in spaces (applied if using spaces indentation)
+    pub const fn list_indent_width(mut self, list_indent_width: usize) -> Self {
+        self.options.list_indent_width = list_indent_width;
+        self
+    }
+
+    /// Bullet characters for unordered lists (e.g., "-", "*", "+")
+    pub fn bullets(mut self, bullets: impl Into<String>) -> Self {
+        self.options.bullets = bullets.into();
+        self
+    }
+
+    /// Symbol for strong/emphasis emphasis rendering (* or _)
+    pub const fn strong_em_symbol(mut self, strong_em_symbol: char) -> Self {
+        self.options.strong_em_symbol = strong_em_symbol;
+        self
+    }
+
+    /// Escape asterisks (*) in text to prevent accidental formatting
+    pub const fn escape_asterisks(mut self, escape_asterisks: bool) -> Self {
+        self.options.escape_asterisks = escape_asterisks;
+        self
+    }
+
+    /// Escape underscores (_) in text to prevent accidental formatting
+    pub const fn escape_underscores(mut self, escape_underscores: bool) -> Self {
+        self.options.escape_underscores = escape_underscores;
+        self
+    }
+
+    /// Escape miscellaneous markdown characters (\ & < ` [ > ~ # = + | -)
+    pub const fn escape_misc(mut self, escape_misc: bool) -> Self {
+        self.options.escape_misc = escape_misc;
+        self
+    }
+
+    /// Escape all ASCII punctuation characters (for `CommonMark` spec compliance tests)
+    pub const fn escape_ascii(mut self, escape_ascii: bool) -> Self {
+        self.options.escape_ascii = escape_ascii;
+        self
+    }
+
+    /// Default code language for fenced code blocks when not specified
+    pub fn code_language(mut self, code_language: impl Into<String>) -> Self {
+        self.options.code_language = code_language.into();
+        self
+    }
+
+    /// Use autolinks syntax for bare URLs (<http://example.com>)
+    pub const fn autolinks(mut self, autolinks: bool) -> Self {
+        self.options.autolinks = autolinks;
+        self
+    }
+
+    /// Use a link's URL as its title when the link text is the URL itself
+    pub const fn default_title(mut self, default_title: bool) -> Self {
+        self.options.default_title = default_title;
+        self
+    }
+
+    /// Use HTML <br> elements in tables instead of spaces for line breaks
+    pub const fn br_in_tables(mut self, br_in_tables: bool) -> Self {
+        self.options.br_in_tables = br_in_tables;
+        self
+    }
+
+    /// Enable spatial table reconstruction in hOCR documents (via spatial positioning analysis)
+    pub const fn hocr_spatial_tables(mut self, hocr_spatial_tables: bool) -> Self {
+        self.options.hocr_spatial_tables = hocr_spatial_tables;
+        self
+    }
+
+    /// Highlight style for <mark> elements (`DoubleEqual`, Html, Bold, None)
+    pub const fn highlight_style(mut self, highlight_style: HighlightStyle) -> Self {
+        self.options.highlight_style = highlight_style;
+        self
+    }
+
+    /// Extract metadata from HTML (title, description, images, links, etc.)
+    pub const fn extract_metadata(mut self, extract_metadata: bool) -> Self {
+        self.options.extract_metadata = extract_metadata;
+        self
+    }
+
+    /// Whitespace handling mode (Normalized collapses multiple spaces, Strict preserves)
+    pub const fn whitespace_mode(mut self, whitespace_mode: WhitespaceMode) -> Self {
+        self.options.whitespace_mode = whitespace_mode;
+        self
+    }
+
+    /// Strip newline characters from HTML before processing
+    pub const fn strip_newlines(mut self, strip_newlines: bool) -> Self {
+        self.options.strip_newlines = strip_newlines;
+        self
+    }
+
+    /// Enable automatic text wrapping at `wrap_width`
+    pub const fn wrap(mut self, wrap: bool) -> Self {
+        self.options.wrap = wrap;
+        self
+    }
+
+    /// Text wrapping width in characters (default 80)
+    pub const fn wrap_width(mut self, wrap_width: usize) -> Self {
+        self.options.wrap_width = wrap_width;
+        self
+    }
+
+    /// Treat block-level elements as inline during conversion
+    pub const fn convert_as_inline(mut self, convert_as_inline: bool) -> Self {
+        self.options.convert_as_inline = convert_as_inline;
+        self
+    }
+
+    /// Custom symbol for subscript content (e.g., "~")
+    pub fn sub_symbol(mut self, sub_symbol: impl Into<String>) -> Self {
+        self.options.sub_symbol = sub_symbol.into();
+        self
+    }
+
+    /// Custom symbol for superscript content (e.g., "^")
+    pub fn sup_symbol(mut self, sup_symbol: impl Into<String>) -> Self {
+        self.options.sup_symbol = sup_symbol.into();
+        self
+    }
+
+    /// Newline style in markdown output (Spaces adds two spaces, Backslash adds \)
+    pub const fn newline_style(mut self, newline_style: NewlineStyle) -> Self {
+        self.options.newline_style = newline_style;
+        self
+    }
+
+    /// Code block fence style (Indented, Backticks, Tildes)
+    pub const fn code_block_style(mut self, code_block_style: CodeBlockStyle) -> Self {
+        self.options.code_block_style = code_block_style;
+        self
+    }
+
+    /// HTML elements where images should remain as markdown links (not converted to alt text)
+    pub fn keep_inline_images_in<I, S>(mut self, keep_inline_images_in: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.options.keep_inline_images_in = keep_inline_images_in.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// HTML preprocessing options (remove nav, forms, etc.)
+    pub fn preprocessing(mut self, preprocessing: PreprocessingOptions) -> Self {
+        self.options.preprocessing = preprocessing;
+        self
+    }
+
+    /// Source document encoding (informational, typically "utf-8")
+    pub fn encoding(mut self, encoding: impl Into<String>) -> Self {
+        self.options.encoding = encoding.into();
+        self
+    }
+
+    /// Enable debug mode with diagnostic warnings on conversion issues
+    pub const fn debug(mut self, debug: bool) -> Self {
+        self.options.debug = debug;
+        self
+    }
+
+    /// HTML tags to strip (extract text content, no markdown conversion)
+    pub fn strip_tags<I, S>(mut self, strip_tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.options.strip_tags = strip_tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// HTML tags to remove together with all of their content (e.g. `aside`, cookie banners)
+    pub fn remove_tags<I, S>(mut self, remove_tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.options.remove_tags = remove_tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// HTML tags to preserve as-is in output (keep original HTML, useful for complex tables)
+    pub fn preserve_tags<I, S>(mut self, preserve_tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.options.preserve_tags = preserve_tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Skip all images during conversion.
+    /// When enabled, all `<img>` elements are completely omitted from output.
+    /// Useful for text-only extraction or filtering out visual content.
+    pub const fn skip_images(mut self, skip_images: bool) -> Self {
+        self.options.skip_images = skip_images;
+        self
+    }
+
+    /// Remove whitespace left between an inline element and following punctuation
+    /// (e.g. `word **bold** .` becomes `word **bold**.`)
+    pub const fn remove_space_before_punctuation(mut self, remove_space_before_punctuation: bool) -> Self {
+        self.options.remove_space_before_punctuation = remove_space_before_punctuation;
+        self
+    }
+
+    /// Non-breaking space handling in paragraphs, headings, lists and other running text
+    /// (Space converts U+00A0 to a regular space, Preserve keeps it)
+    pub const fn nbsp_in_prose(mut self, nbsp_in_prose: NbspMode) -> Self {
+        self.options.nbsp_in_prose = nbsp_in_prose;
+        self
+    }
+
+    /// Non-breaking space handling in table cells (Space converts, Preserve keeps U+00A0)
+    pub const fn nbsp_in_tables(mut self, nbsp_in_tables: NbspMode) -> Self {
+        self.options.nbsp_in_tables = nbsp_in_tables;
+        self
+    }
+
+    /// Serialization format for the metadata block emitted when `extract_metadata` is enabled
+    pub const fn front_matter_format(mut self, front_matter_format: FrontMatterFormat) -> Self {
+        self.options.front_matter_format = front_matter_format;
+        self
+    }
+
+    /// Remove invisible soft hyphens (U+00AD) and word joiners (U+2060, U+FEFF) from text
+    /// (code spans and blocks are left untouched)
+    pub const fn remove_soft_hyphens(mut self, remove_soft_hyphens: bool) -> Self {
+        self.options.remove_soft_hyphens = remove_soft_hyphens;
+        self
+    }
+
+    /// Handling of `dir="rtl"`/`dir="ltr"` direction changes (ignore, direction marks,
+    /// embedding controls, or an HTML `dir` passthrough wrapper)
+    pub const fn bidi_mode(mut self, bidi_mode: BidiMode) -> Self {
+        self.options.bidi_mode = bidi_mode;
+        self
+    }
+
+    /// Use the quotation marks, parentheses and dashes of the element's `lang` when the converter
+    /// inserts punctuation itself (`<q>` quotes, `<abbr>` expansions, blockquote attributions)
+    pub const fn localized_punctuation(mut self, localized_punctuation: bool) -> Self {
+        self.options.localized_punctuation = localized_punctuation;
+        self
+    }
+
+    /// Delimiters for ordered list markers, one per nesting level (`.` or `)`)
+    pub fn ordered_markers(mut self, ordered_markers: impl Into<String>) -> Self {
+        self.options.ordered_markers = ordered_markers.into();
+        self
+    }
+
+    /// Cycle through `bullets` by nesting level; when false the first bullet is used at every level
+    pub const fn cycle_bullets(mut self, cycle_bullets: bool) -> Self {
+        self.options.cycle_bullets = cycle_bullets;
+        self
+    }
+
+    /// Spacing between list items: follow the source (auto), or force tight or loose lists
+    pub const fn list_spacing(mut self, list_spacing: ListSpacing) -> Self {
+        self.options.list_spacing = list_spacing;
+        self
+    }
+
+    /// Handling of content without a lossless Markdown form (`<bdo>`, `<bdi>`, `<wbr>`, removed
+    /// soft hyphens): drop it, pass it through, or drop it and record a diagnostic
+    pub const fn unrepresentable_policy(mut self, unrepresentable_policy: UnrepresentablePolicy) -> Self {
+        self.options.unrepresentable_policy = unrepresentable_policy;
+        self
+    }
+
+    /// Post-processors run on the Markdown after conversion and before wrapping, in order
+    pub fn post_processors(mut self, post_processors: PostProcessorChain) -> Self {
+        self.options.post_processors = post_processors;
+        self
+    }
+
+    /// Collect a resource usage report (timings per phase, nodes parsed, output size, peak memory
+    /// estimate), returned by `convert_with_stats`
+    pub const fn collect_stats(mut self, collect_stats: bool) -> Self {
+        self.options.collect_stats = collect_stats;
+        self
+    }
+
+    /// Title sources tried in order when a link has no `title` attribute or the document has no
+    /// `<title>` (front matter); empty disables the fallback
+    pub fn title_fallback(mut self, title_fallback: impl IntoIterator<Item = TitleSource>) -> Self {
+        self.options.title_fallback = title_fallback.into_iter().collect();
+        self
+    }
+
+    /// Emit Pandoc attribute blocks (`{#id .class}`) for headings, code blocks, links and images
+    pub const fn pandoc_attributes(mut self, pandoc_attributes: bool) -> Self {
+        self.options.pandoc_attributes = pandoc_attributes;
+        self
+    }
+
+    /// How the `<title>`, `<desc>` and `<text>` labels of inline SVGs are surfaced
+    pub const fn svg_text_mode(mut self, svg_text_mode: SvgTextMode) -> Self {
+        self.options.svg_text_mode = svg_text_mode;
+        self
+    }
+
+    /// Link the `data`/`src` URL of `<object>` and `<embed>` elements after their fallback content
+    pub const fn object_links(mut self, object_links: bool) -> Self {
+        self.options.object_links = object_links;
+        self
+    }
+
+    /// Keep heading ids as `<a id>` anchors, or `{#id}` when `pandoc_attributes` is on
+    pub const fn preserve_heading_ids(mut self, preserve_heading_ids: bool) -> Self {
+        self.options.preserve_heading_ids = preserve_heading_ids;
+        self
+    }
+
+    /// Compute a content fingerprint of the output, returned by `convert_with_fingerprint`
+    pub const fn content_fingerprint(mut self, content_fingerprint: bool) -> Self {
+        self.options.content_fingerprint = content_fingerprint;
+        self
+    }
+
+    /// How `<math>` and MathJax TeX payloads are written
+    pub const fn math_style(mut self, math_style: MathStyle) -> Self {
+        self.options.math_style = math_style;
+        self
+    }
+
+    /// Infer the fence language of code blocks that lack a `language-*` class
+    pub const fn infer_code_language(mut self, infer_code_language: bool) -> Self {
+        self.options.infer_code_language = infer_code_language;
+        self
+    }
+
+    /// Drop blank lines at the start and end of `<pre>` code blocks
+    pub const fn code_block_trim(mut self, code_block_trim: bool) -> Self {
+        self.options.code_block_trim = code_block_trim;
+        self
+    }
+
+    /// Which `srcset` or `<picture><source>` candidate is used for an `<img>` without a `src`
+    pub const fn srcset_policy(mut self, srcset_policy: SrcsetPolicy) -> Self {
+        self.options.srcset_policy = srcset_policy;
+        self
+    }
+
+    /// How `<figure>` captions are written: an italic paragraph, a blockquote, or the figure as HTML
+    pub const fn figure_style(mut self, figure_style: FigureStyle) -> Self {
+        self.options.figure_style = figure_style;
+        self
+    }
+
+    /// How `<details>` sections are written: a bold summary line, or kept as collapsible HTML
+    pub const fn details_style(mut self, details_style: DetailsStyle) -> Self {
+        self.options.details_style = details_style;
+        self
+    }
+
+    /// How `<abbr>` is written: with its title in parentheses, as plain text, or as HTML
+    pub const fn abbr_style(mut self, abbr_style: AbbrStyle) -> Self {
+        self.options.abbr_style = abbr_style;
+        self
+    }
+
+    /// How `<time>` is written: its text, its text plus the `datetime` value, or as HTML
+    pub const fn time_style(mut self, time_style: TimeStyle) -> Self {
+        self.options.time_style = time_style;
+        self
+    }
+
+    /// How `<dfn>` is written: as emphasis, as plain text, or as HTML
+    pub const fn dfn_style(mut self, dfn_style: DfnStyle) -> Self {
+        self.options.dfn_style = dfn_style;
+        self
+    }
+
+    /// How `mailto:` and `tel:` links are written: as links, as autolinks, or as plain text
+    pub const fn contact_link_style(mut self, contact_link_style: ContactLinkStyle) -> Self {
+        self.options.contact_link_style = contact_link_style;
+        self
+    }
+
+    /// Fraction of lost structure above which a table, form or `<math>` subtree is kept as HTML
+    pub const fn fidelity_threshold(mut self, fidelity_threshold: f64) -> Self {
+        self.options.fidelity_threshold = fidelity_threshold;
+        self
+    }
+
+    /// Write `title` attributes of links, abbreviations and spans as footnotes
+    pub const fn title_footnotes(mut self, title_footnotes: bool) -> Self {
+        self.options.title_footnotes = title_footnotes;
+        self
+    }
+
+    /// What `<br>` becomes inside headings and header cells
+    pub const fn header_break_style(mut self, header_break_style: HeaderBreakStyle) -> Self {
+        self.options.header_break_style = header_break_style;
+        self
+    }
+
+    /// Report the text dropped by each removal rule as diagnostics
+    pub const fn report_dropped_content(mut self, report_dropped_content: bool) -> Self {
+        self.options.report_dropped_content = report_dropped_content;
+        self
+    }
+
+    /// Convert concatenated HTML documents one at a time
+    pub const fn split_documents(mut self, split_documents: bool) -> Self {
+        self.options.split_documents = split_documents;
+        self
+    }
+
+    /// Attributes kept on elements written out as HTML; empty keeps all of them
+    pub fn preserved_attributes<I, S>(mut self, preserved_attributes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.options.preserved_attributes = preserved_attributes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Cap the output at this many characters, cutting between top-level blocks
+    pub const fn max_output_chars(mut self, max_output_chars: usize) -> Self {
+        self.options.max_output_chars = Some(max_output_chars);
+        self
+    }
+
+    /// Cap the output at this many tokens, as counted by the token estimator
+    pub const fn max_output_tokens(mut self, max_output_tokens: usize) -> Self {
+        self.options.max_output_tokens = Some(max_output_tokens);
+        self
+    }
+
+    /// Count tokens for `max_output_tokens` with the given estimator
+    pub fn token_estimator(mut self, token_estimator: TokenEstimator) -> Self {
+        self.options.token_estimator = token_estimator;
+        self
+    }
+
+    /// Validate the options and return them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::ConfigError`] listing every problem found: the
+    /// [`ConversionOptions::violations`] shared with the language bindings, plus a `wrap_width`
+    /// below [`MIN_WRAP_WIDTH`](Self::MIN_WRAP_WIDTH), which only the builder enforces.
+    pub fn build(self) -> crate::error::Result<ConversionOptions> {
+        let options = self.options;
+        let mut violations = options.violations();
+        if options.wrap_width < Self::MIN_WRAP_WIDTH {
+            violations.retain(|violation| violation.field != "wrap_width");
+            violations.insert(
+                0,
+                OptionViolation {
+                    field: "wrap_width",
+                    message: format!(
+                        "wrap_width must be at least {}, got {}",
+                        Self::MIN_WRAP_WIDTH,
+                        options.wrap_width
+                    ),
+                },
+            );
+        }
+        violations_result(violations)?;
+        Ok(options)
+    }
+}
+
+fn config_error(message: impl Into<String>) -> ConversionError {
+    ConversionError::ConfigError(message.into())
+}
+
 /// HTML preprocessing options for document cleanup before conversion.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    any(feature = "serde", feature = "metadata"),
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[cfg_attr(
+    any(feature = "serde", feature = "metadata"),
+    serde(rename_all = "camelCase", from = "PreprocessingOptionsUpdate")
+)]
 pub struct PreprocessingOptions {
     /// Enable HTML preprocessing globally
     pub enabled: bool,
 
-    /// Preprocessing preset level (Minimal, Standard, Aggressive)
+    /// Preprocessing preset level (Minimal, Standard, Aggressive, Confluence)
     pub preset: PreprocessingPreset,
 
     /// Remove navigation elements (nav, breadcrumbs, menus, sidebars)
@@ -594,6 +2401,28 @@ pub struct PreprocessingOptions {
 
     /// Remove form elements (forms, inputs, buttons, etc.)
     pub remove_forms: bool,
+
+    /// Drop elements that the document's print stylesheet hides (`@media print`,
+    /// `<style media="print">`, or utility classes such as `d-print-none`).
+    pub apply_print_styles: bool,
+
+    /// Convert only the main article, chosen by scoring blocks on text and link density
+    /// the way Readability does. Falls back to the whole document when nothing stands out.
+    pub extract_main_content: bool,
+
+    /// Render declarative shadow roots (`<template shadowrootmode="open">`) in place of
+    /// their host's children, distributing the light DOM into the `<slot>`s the way a
+    /// browser composes them. When disabled, shadow content is kept but not composed.
+    pub flatten_shadow_dom: bool,
+
+    /// CSS selectors whose matching elements are dropped with their content
+    /// (e.g. `.ads, #sidebar`). Supports tag, class, id and attribute selectors
+    /// combined with descendant (` `) and child (`>`) combinators.
+    pub exclude_selectors: Vec<String>,
+
+    /// CSS selector for the only elements to convert (e.g. `article.main`).
+    /// Nothing is emitted when it matches no element.
+    pub include_selector: Option<String>,
 }
 
 /// Partial update for `PreprocessingOptions`.
@@ -608,7 +2437,7 @@ pub struct PreprocessingOptionsUpdate {
     /// Optional global preprocessing enablement override
     pub enabled: Option<bool>,
 
-    /// Optional preprocessing preset level override (Minimal, Standard, Aggressive)
+    /// Optional preprocessing preset level override (Minimal, Standard, Aggressive, Confluence)
     pub preset: Option<PreprocessingPreset>,
 
     /// Optional navigation element removal override (nav, breadcrumbs, menus, sidebars)
@@ -616,9 +2445,24 @@ pub struct PreprocessingOptionsUpdate {
 
     /// Optional form element removal override (forms, inputs, buttons, etc.)
     pub remove_forms: Option<bool>,
+
+    /// Optional print stylesheet override
+    pub apply_print_styles: Option<bool>,
+
+    /// Optional main-content extraction override
+    pub extract_main_content: Option<bool>,
+
+    /// Optional shadow DOM flattening override
+    pub flatten_shadow_dom: Option<bool>,
+
+    /// Optional excluded CSS selectors override
+    pub exclude_selectors: Option<Vec<String>>,
+
+    /// Optional included CSS selector override (an empty selector clears it)
+    pub include_selector: Option<String>,
 }
 
-fn normalize_token(value: &str) -> String {
+pub(crate) fn normalize_token(value: &str) -> String {
     let mut out = String::with_capacity(value.len());
     for ch in value.chars() {
         if ch.is_ascii_alphanumeric() {
@@ -631,9 +2475,11 @@ fn normalize_token(value: &str) -> String {
 #[cfg(any(feature = "serde", feature = "metadata"))]
 mod serde_impls {
     use super::{
-        CodeBlockStyle, HeadingStyle, HighlightStyle, ListIndentType, NewlineStyle, PreprocessingPreset, WhitespaceMode,
+        AbbrStyle, BidiMode, CodeBlockStyle, ContactLinkStyle, DetailsStyle, DfnStyle, FigureStyle, FrontMatterFormat,
+        HeaderBreakStyle, HeadingStyle, HighlightStyle, ListIndentType, ListSpacing, MathStyle, NbspMode, NewlineStyle,
+        PreprocessingPreset, SrcsetPolicy, SvgTextMode, TimeStyle, TitleSource, UnrepresentablePolicy, WhitespaceMode,
     };
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
 
     macro_rules! impl_deserialize_from_parse {
         ($ty:ty, $parser:expr) => {
@@ -652,10 +2498,66 @@ mod serde_impls {
     impl_deserialize_from_parse!(HeadingStyle, HeadingStyle::parse);
     impl_deserialize_from_parse!(ListIndentType, ListIndentType::parse);
     impl_deserialize_from_parse!(WhitespaceMode, WhitespaceMode::parse);
+    impl_deserialize_from_parse!(NbspMode, NbspMode::parse);
+    impl_deserialize_from_parse!(FrontMatterFormat, FrontMatterFormat::parse);
+    impl_deserialize_from_parse!(BidiMode, BidiMode::parse);
+    impl_deserialize_from_parse!(ListSpacing, ListSpacing::parse);
+    impl_deserialize_from_parse!(UnrepresentablePolicy, UnrepresentablePolicy::parse);
     impl_deserialize_from_parse!(NewlineStyle, NewlineStyle::parse);
     impl_deserialize_from_parse!(CodeBlockStyle, CodeBlockStyle::parse);
     impl_deserialize_from_parse!(HighlightStyle, HighlightStyle::parse);
     impl_deserialize_from_parse!(PreprocessingPreset, PreprocessingPreset::parse);
+    impl_deserialize_from_parse!(SvgTextMode, SvgTextMode::parse);
+    impl_deserialize_from_parse!(MathStyle, MathStyle::parse);
+    impl_deserialize_from_parse!(SrcsetPolicy, SrcsetPolicy::parse);
+    impl_deserialize_from_parse!(FigureStyle, FigureStyle::parse);
+    impl_deserialize_from_parse!(DetailsStyle, DetailsStyle::parse);
+    impl_deserialize_from_parse!(AbbrStyle, AbbrStyle::parse);
+    impl_deserialize_from_parse!(TimeStyle, TimeStyle::parse);
+    impl_deserialize_from_parse!(DfnStyle, DfnStyle::parse);
+    impl_deserialize_from_parse!(ContactLinkStyle, ContactLinkStyle::parse);
+    impl_deserialize_from_parse!(HeaderBreakStyle, HeaderBreakStyle::parse);
+
+    macro_rules! impl_serialize_as_name {
+        ($($ty:ty),+ $(,)?) => {
+            $(
+                impl Serialize for $ty {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: serde::Serializer,
+                    {
+                        serializer.serialize_str(self.name())
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_serialize_as_name!(
+        HeadingStyle,
+        ListIndentType,
+        WhitespaceMode,
+        NbspMode,
+        FrontMatterFormat,
+        BidiMode,
+        ListSpacing,
+        UnrepresentablePolicy,
+        TitleSource,
+        NewlineStyle,
+        CodeBlockStyle,
+        HighlightStyle,
+        PreprocessingPreset,
+        SvgTextMode,
+        MathStyle,
+        SrcsetPolicy,
+        FigureStyle,
+        DetailsStyle,
+        AbbrStyle,
+        TimeStyle,
+        DfnStyle,
+        ContactLinkStyle,
+        HeaderBreakStyle,
+    );
 }
 
 impl Default for PreprocessingOptions {
@@ -665,6 +2567,11 @@ impl Default for PreprocessingOptions {
             preset: PreprocessingPreset::default(),
             remove_navigation: true,
             remove_forms: true,
+            apply_print_styles: false,
+            extract_main_content: false,
+            flatten_shadow_dom: false,
+            exclude_selectors: Vec::new(),
+            include_selector: None,
         }
     }
 }
@@ -678,8 +2585,7 @@ impl PreprocessingOptions {
     /// # Arguments
     ///
     /// * `update` - Partial preprocessing options update
-    #[allow(clippy::needless_pass_by_value)]
-    pub const fn apply_update(&mut self, update: PreprocessingOptionsUpdate) {
+    pub fn apply_update(&mut self, update: PreprocessingOptionsUpdate) {
         if let Some(enabled) = update.enabled {
             self.enabled = enabled;
         }
@@ -692,6 +2598,21 @@ impl PreprocessingOptions {
         if let Some(remove_forms) = update.remove_forms {
             self.remove_forms = remove_forms;
         }
+        if let Some(apply_print_styles) = update.apply_print_styles {
+            self.apply_print_styles = apply_print_styles;
+        }
+        if let Some(extract_main_content) = update.extract_main_content {
+            self.extract_main_content = extract_main_content;
+        }
+        if let Some(flatten_shadow_dom) = update.flatten_shadow_dom {
+            self.flatten_shadow_dom = flatten_shadow_dom;
+        }
+        if let Some(exclude_selectors) = update.exclude_selectors {
+            self.exclude_selectors = exclude_selectors;
+        }
+        if let Some(include_selector) = update.include_selector {
+            self.include_selector = Some(include_selector).filter(|selector| !selector.trim().is_empty());
+        }
     }
 
     /// Create new preprocessing options from a partial update.
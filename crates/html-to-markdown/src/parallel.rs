@@ -0,0 +1,76 @@
+//! Multi-threaded batch conversion (requires the `parallel` feature).
+//!
+//! Documents are converted on a dedicated rayon thread pool owned by this crate, so a large
+//! batch does not compete with, or deadlock on, an application's own use of the global rayon
+//! pool. The pool is created on first use with one worker per available CPU; set
+//! `HTML_TO_MARKDOWN_THREADS` to a positive integer to size it explicitly.
+//!
+//! Workers share nothing but a borrowed [`ConversionOptions`]; see its thread-safety notes.
+
+use std::sync::OnceLock;
+
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::options::ConversionOptions;
+use crate::{Result, convert_with_options, safety};
+
+const THREADS_ENV: &str = "HTML_TO_MARKDOWN_THREADS";
+
+static POOL: OnceLock<Option<ThreadPool>> = OnceLock::new();
+
+fn configured_threads() -> usize {
+    std::env::var(THREADS_ENV)
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|&threads| threads > 0)
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, std::num::NonZero::get))
+}
+
+/// The crate's conversion pool, or `None` if it could not be created.
+fn pool() -> Option<&'static ThreadPool> {
+    POOL.get_or_init(|| {
+        ThreadPoolBuilder::new()
+            .num_threads(configured_threads())
+            .thread_name(|index| format!("html-to-markdown-{index}"))
+            .build()
+            .ok()
+    })
+    .as_ref()
+}
+
+/// Convert a batch of HTML documents in parallel.
+///
+/// Behaves like [`convert_many`](crate::convert_many) — results are in input order and each
+/// document fails or panics on its own — but spreads the work over the crate's thread pool.
+/// If that pool cannot be created, the global rayon pool is used instead.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::convert_parallel;
+///
+/// let documents: Vec<String> = (1..=3).map(|i| format!("<h2>Part {i}</h2>")).collect();
+/// let results = convert_parallel(&documents, None);
+/// assert_eq!(results[2].as_deref().unwrap(), "## Part 3\n");
+/// ```
+pub fn convert_parallel<S: AsRef<str> + Sync>(
+    documents: &[S],
+    options: Option<ConversionOptions>,
+) -> Vec<Result<String>> {
+    let options = options.unwrap_or_default();
+    let run = || {
+        documents
+            .par_iter()
+            .map(|html| {
+                let html = html.as_ref();
+                safety::guard_panic(|| convert_with_options(html, &options))
+            })
+            .collect()
+    };
+
+    match pool() {
+        Some(pool) => pool.install(run),
+        None => run(),
+    }
+}
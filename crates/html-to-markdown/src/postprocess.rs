@@ -0,0 +1,915 @@
+//! Post-processors applied to the generated Markdown.
+//!
+//! A [`PostProcessorChain`] is an ordered list of named steps that run after the document has
+//! been serialized to Markdown and before line wrapping. Steps are either built-in
+//! ([`BuiltinPostProcessor`]) or closures supplied by the caller, and each can be enabled or
+//! disabled by name.
+//!
+//! ```
+//! use html_to_markdown_rs::{BuiltinPostProcessor, ConversionOptions, PostProcessorChain, convert};
+//!
+//! let mut chain = PostProcessorChain::new();
+//! chain
+//!     .push_builtin(BuiltinPostProcessor::ReferenceLinks)
+//!     .push_fn("shout", |markdown: &str| markdown.to_uppercase());
+//!
+//! let options = ConversionOptions {
+//!     post_processors: chain,
+//!     ..Default::default()
+//! };
+//! let markdown = convert(r#"<p>See <a href="https://example.com">docs</a></p>"#, Some(options)).unwrap();
+//! assert_eq!(markdown, "SEE [DOCS][1]\n\n[1]: HTTPS://EXAMPLE.COM\n");
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+use std::sync::Arc;
+
+use crate::error::{ConversionError, Result};
+use crate::options::ConversionOptions;
+
+/// Query parameters dropped by the `normalize-urls` post-processor by default: campaign and
+/// click-tracking parameters that never change what a link points to. A trailing `*` matches
+/// any suffix.
+pub const DEFAULT_URL_PARAM_DENYLIST: &[&str] = &[
+    "utm_*", "fbclid", "gclid", "dclid", "gbraid", "wbraid", "msclkid", "mc_cid", "mc_eid", "_hsenc", "_hsmi",
+    "igshid", "yclid",
+];
+
+/// Signature of a custom post-processing step.
+pub type PostProcessorFn = dyn Fn(&str) -> String + Send + Sync;
+
+/// Post-processors shipped with the crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuiltinPostProcessor {
+    /// Collapse runs of blank lines outside code blocks into a single blank line.
+    CompactBlankLines,
+    /// Tidy link and image URLs: drop query parameters on
+    /// [`url_param_denylist`](ConversionOptions::url_param_denylist) (or not on a non-empty
+    /// [`url_param_allowlist`](ConversionOptions::url_param_allowlist)), lowercase the scheme and
+    /// host of web URLs and remove their trailing slash. Run it before `reference-links` so links
+    /// that differ only in tracking parameters share one reference.
+    NormalizeUrls,
+    /// Remove headings, list items and links left without any text.
+    PruneEmptyElements,
+    /// Turn inline links into numbered reference links listed at the end of the document.
+    ReferenceLinks,
+    /// Insert a linked table of contents built from the document's headings.
+    TableOfContents,
+}
+
+impl BuiltinPostProcessor {
+    /// All built-in post-processors.
+    pub const ALL: [Self; 5] = [
+        Self::CompactBlankLines,
+        Self::NormalizeUrls,
+        Self::PruneEmptyElements,
+        Self::ReferenceLinks,
+        Self::TableOfContents,
+    ];
+
+    /// Canonical name, as accepted by [`PostProcessorChain::from_names`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::CompactBlankLines => "compact-blank-lines",
+            Self::NormalizeUrls => "normalize-urls",
+            Self::PruneEmptyElements => "prune-empty-elements",
+            Self::ReferenceLinks => "reference-links",
+            Self::TableOfContents => "toc",
+        }
+    }
+
+    /// Look up a built-in by name.
+    ///
+    /// Matching ignores case and punctuation, so `reference_links` and `referenceLinks` work too.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let wanted = normalize_name(name);
+        if wanted == "tableofcontents" {
+            return Some(Self::TableOfContents);
+        }
+        Self::ALL
+            .into_iter()
+            .find(|builtin| normalize_name(builtin.name()) == wanted)
+    }
+
+    fn apply(self, markdown: &str, options: &ConversionOptions) -> String {
+        match self {
+            Self::CompactBlankLines => compact_blank_lines(markdown),
+            Self::NormalizeUrls => normalize_urls(markdown, options),
+            Self::PruneEmptyElements => prune_empty_elements(markdown),
+            Self::ReferenceLinks => reference_links(markdown),
+            Self::TableOfContents => table_of_contents(markdown, options),
+        }
+    }
+}
+
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[derive(Clone)]
+enum StepAction {
+    Builtin(BuiltinPostProcessor),
+    Custom(Arc<PostProcessorFn>),
+    /// A name that matched no built-in; running the chain reports it.
+    Unknown,
+}
+
+#[derive(Clone)]
+struct Step {
+    name: String,
+    enabled: bool,
+    action: StepAction,
+}
+
+/// Ordered, named post-processing steps run on the Markdown output before wrapping.
+///
+/// The default chain is empty, leaving the output untouched.
+#[derive(Clone, Default)]
+pub struct PostProcessorChain {
+    steps: Vec<Step>,
+}
+
+/// Serializes as the names of the enabled steps, the form [`PostProcessorChain::from_names`] reads.
+#[cfg(any(feature = "serde", feature = "metadata"))]
+impl serde::Serialize for PostProcessorChain {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.steps.iter().filter(|step| step.enabled).map(|step| &step.name))
+    }
+}
+
+impl PostProcessorChain {
+    /// Create an empty chain.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a chain of built-ins from their names, in order.
+    ///
+    /// Unknown names are kept and make the conversion fail with
+    /// [`ConversionError::ConfigError`], so a typo never passes silently.
+    #[must_use]
+    pub fn from_names<S: AsRef<str>>(names: &[S]) -> Self {
+        let steps = names
+            .iter()
+            .map(|name| {
+                let name = name.as_ref();
+                match BuiltinPostProcessor::from_name(name) {
+                    Some(builtin) => Step {
+                        name: builtin.name().to_string(),
+                        enabled: true,
+                        action: StepAction::Builtin(builtin),
+                    },
+                    None => Step {
+                        name: name.to_string(),
+                        enabled: true,
+                        action: StepAction::Unknown,
+                    },
+                }
+            })
+            .collect();
+        Self { steps }
+    }
+
+    /// Append a built-in post-processor.
+    pub fn push_builtin(&mut self, builtin: BuiltinPostProcessor) -> &mut Self {
+        self.steps.push(Step {
+            name: builtin.name().to_string(),
+            enabled: true,
+            action: StepAction::Builtin(builtin),
+        });
+        self
+    }
+
+    /// Append a custom post-processor under `name`.
+    pub fn push_fn<F>(&mut self, name: impl Into<String>, processor: F) -> &mut Self
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.steps.push(Step {
+            name: name.into(),
+            enabled: true,
+            action: StepAction::Custom(Arc::new(processor)),
+        });
+        self
+    }
+
+    /// Enable or disable every step called `name`. Returns `false` if there is none.
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        let mut found = false;
+        for step in self.steps.iter_mut().filter(|step| step.name == name) {
+            step.enabled = enabled;
+            found = true;
+        }
+        found
+    }
+
+    /// Names of the steps in order, including disabled ones.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.steps.iter().map(|step| step.name.as_str())
+    }
+
+    /// Whether running the chain would leave the output unchanged.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self.steps.iter().any(|step| step.enabled)
+    }
+
+    /// Run every enabled step in order.
+    pub(crate) fn run(&self, markdown: String, options: &ConversionOptions) -> Result<String> {
+        let mut markdown = markdown;
+        for step in self.steps.iter().filter(|step| step.enabled) {
+            markdown = match &step.action {
+                StepAction::Builtin(builtin) => builtin.apply(&markdown, options),
+                StepAction::Custom(processor) => processor(&markdown),
+                StepAction::Unknown => {
+                    return Err(ConversionError::ConfigError(format!(
+                        "unknown post-processor '{}'",
+                        step.name
+                    )));
+                }
+            };
+        }
+        Ok(markdown)
+    }
+}
+
+// Conversions run inside `catch_unwind`. Steps are shared `Fn`s that the chain never mutates,
+// so a panicking step cannot leave the chain itself in a broken state.
+impl UnwindSafe for PostProcessorChain {}
+impl RefUnwindSafe for PostProcessorChain {}
+
+impl fmt::Debug for PostProcessorChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for step in &self.steps {
+            if step.enabled {
+                list.entry(&step.name);
+            } else {
+                list.entry(&format_args!("{} (disabled)", step.name));
+            }
+        }
+        list.finish()
+    }
+}
+
+/// A Markdown line and whether it belongs to a code block.
+pub(crate) struct Line<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) code: bool,
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+pub(crate) fn fence_marker(line: &str) -> Option<(char, usize)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let marker = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = trimmed.chars().take_while(|&c| c == marker).count();
+    (len >= 3).then_some((marker, len))
+}
+
+fn is_list_item(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    if let Some(rest) = trimmed.strip_prefix(['-', '*', '+']) {
+        return rest.is_empty() || rest.starts_with(' ');
+    }
+    let digits = trimmed.bytes().take_while(u8::is_ascii_digit).count();
+    digits > 0
+        && trimmed[digits..]
+            .strip_prefix(['.', ')'])
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+}
+
+/// Split Markdown into lines, marking fenced and indented code.
+pub(crate) fn classify_lines(markdown: &str) -> Vec<Line<'_>> {
+    let mut lines = Vec::new();
+    let mut fence: Option<(char, usize)> = None;
+    let mut in_indented_code = false;
+    let mut in_list = false;
+    let mut previous_blank = true;
+
+    for text in markdown.split('\n') {
+        if let Some((marker, len)) = fence {
+            if fence_marker(text).is_some_and(|(m, l)| m == marker && l >= len && text.trim().len() == l) {
+                fence = None;
+            }
+            lines.push(Line { text, code: true });
+            continue;
+        }
+        if let Some(opened) = fence_marker(text) {
+            fence = Some(opened);
+            lines.push(Line { text, code: true });
+            previous_blank = false;
+            continue;
+        }
+
+        let blank = is_blank(text);
+        let indented = text.starts_with('\t') || text.starts_with("    ");
+        let code = !blank && indented && (in_indented_code || (previous_blank && !in_list));
+        if !blank {
+            in_indented_code = code;
+            if !indented {
+                in_list = is_list_item(text);
+            }
+        }
+        lines.push(Line { text, code });
+        previous_blank = blank;
+    }
+
+    // Blank lines between two chunks of an indented code block belong to the block.
+    let mut last_code: Option<usize> = None;
+    for i in 0..lines.len() {
+        if is_blank(lines[i].text) {
+            continue;
+        }
+        if lines[i].code && fence_marker(lines[i].text).is_none() {
+            if let Some(previous) = last_code.filter(|&previous| previous + 1 < i) {
+                for line in &mut lines[previous + 1..i] {
+                    line.code = true;
+                }
+            }
+            last_code = Some(i);
+        } else {
+            last_code = None;
+        }
+    }
+    lines
+}
+
+fn compact_blank_lines(markdown: &str) -> String {
+    let mut output = String::with_capacity(markdown.len());
+    let mut blank_run = 0;
+    for line in classify_lines(markdown) {
+        if !line.code && is_blank(line.text) {
+            blank_run += 1;
+            if blank_run > 1 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        output.push_str(line.text);
+        output.push('\n');
+    }
+    output.pop();
+    output
+}
+
+/// Whether a line is a heading or list item with no content.
+fn is_empty_block(line: &str) -> bool {
+    let trimmed = line.trim();
+    let without_hashes = trimmed.trim_start_matches('#');
+    let hashes = trimmed.len() - without_hashes.len();
+    if (1..=6).contains(&hashes) && without_hashes.trim_matches(|c: char| c == '#' || c == ' ').is_empty() {
+        return true;
+    }
+    if !is_list_item(trimmed) {
+        return false;
+    }
+    let body = trimmed
+        .trim_start_matches(|c: char| c.is_ascii_digit())
+        .get(1..)
+        .unwrap_or_default()
+        .trim();
+    body.is_empty() || body == "[ ]" || body == "[x]"
+}
+
+/// Remove `[](url)` links (not images) outside inline code.
+fn remove_empty_links(line: &str) -> String {
+    let mut output = String::with_capacity(line.len());
+    for (segment, code) in inline_segments(line) {
+        if code {
+            output.push_str(segment);
+            continue;
+        }
+        let mut rest = segment;
+        while let Some(pos) = rest.find("[](") {
+            let is_image = rest[..pos].ends_with('!');
+            match rest[pos + 3..].find(')') {
+                Some(close) if !is_image => {
+                    output.push_str(rest[..pos].trim_end_matches(' '));
+                    if !output.is_empty() && !rest[pos + 3 + close + 1..].starts_with([' ', '.', ',', ';', ':']) {
+                        output.push(' ');
+                    }
+                    rest = &rest[pos + 3 + close + 1..];
+                }
+                _ => {
+                    output.push_str(&rest[..pos + 3]);
+                    rest = &rest[pos + 3..];
+                }
+            }
+        }
+        output.push_str(rest);
+    }
+    output
+}
+
+fn prune_empty_elements(markdown: &str) -> String {
+    let lines = classify_lines(markdown);
+    let mut kept: Vec<String> = Vec::with_capacity(lines.len());
+    for line in &lines {
+        if line.code {
+            kept.push(line.text.to_string());
+            continue;
+        }
+        let text = if line.text.contains("[](") {
+            remove_empty_links(line.text)
+        } else {
+            line.text.to_string()
+        };
+        if is_empty_block(&text) || (text.trim().is_empty() && !line.text.trim().is_empty()) {
+            if kept.last().is_some_and(|previous| is_blank(previous)) {
+                continue;
+            }
+            kept.push(String::new());
+            continue;
+        }
+        if is_blank(&text) && kept.last().is_some_and(|previous| is_blank(previous)) {
+            continue;
+        }
+        kept.push(text);
+    }
+    while kept.first().is_some_and(|first| is_blank(first)) {
+        kept.remove(0);
+    }
+    let mut output = kept.join("\n");
+    if markdown.ends_with('\n') && !output.ends_with('\n') {
+        output.push('\n');
+    }
+    output
+}
+
+/// Split a line into alternating prose and inline-code segments (`true` = code).
+fn inline_segments(line: &str) -> Vec<(&str, bool)> {
+    let mut segments = Vec::new();
+    let mut rest = line;
+    while let Some(open) = rest.find('`') {
+        let ticks = rest[open..].chars().take_while(|&c| c == '`').count();
+        let delimiter = &rest[open..open + ticks];
+        let Some(close) = rest[open + ticks..].find(delimiter) else {
+            break;
+        };
+        let end = open + ticks + close + ticks;
+        segments.push((&rest[..open], false));
+        segments.push((&rest[open..end], true));
+        rest = &rest[end..];
+    }
+    segments.push((rest, false));
+    segments
+}
+
+/// Parse an inline link destination starting right after `](`.
+///
+/// Returns the URL, optional title and the length consumed including the closing `)`.
+fn parse_destination(input: &str) -> Option<(&str, Option<&str>, usize)> {
+    let (url, mut pos) = if let Some(rest) = input.strip_prefix('<') {
+        let end = rest.find('>')?;
+        (&input[..end + 2], end + 2)
+    } else {
+        let mut depth = 0usize;
+        let mut end = input.len();
+        for (idx, c) in input.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' if depth == 0 => {
+                    end = idx;
+                    break;
+                }
+                ')' => depth -= 1,
+                ' ' => {
+                    end = idx;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        (&input[..end], end)
+    };
+    if url.is_empty() {
+        return None;
+    }
+
+    let mut title = None;
+    let rest = &input[pos..];
+    let trimmed = rest.trim_start_matches(' ');
+    if let Some(quoted) = trimmed.strip_prefix('"') {
+        let close = quoted.find('"')?;
+        title = Some(&quoted[..close]);
+        pos += rest.len() - trimmed.len() + close + 2;
+    }
+    let after = &input[pos..];
+    let after_trimmed = after.trim_start_matches(' ');
+    after_trimmed
+        .starts_with(')')
+        .then(|| (url, title, pos + after.len() - after_trimmed.len() + 1))
+}
+
+/// Find the `]` closing a link text that starts after `[`, allowing nested brackets.
+fn link_text_end(input: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (idx, c) in input.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => depth += 1,
+            ']' if depth == 0 => return Some(idx),
+            ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn normalize_urls(markdown: &str, options: &ConversionOptions) -> String {
+    let mut output = String::with_capacity(markdown.len());
+    for line in classify_lines(markdown) {
+        if line.code {
+            output.push_str(line.text);
+        } else if let Some((label, rest)) = reference_definition(line.text) {
+            let url_len = if rest.starts_with('<') {
+                rest.find('>').map_or(rest.len(), |close| close + 1)
+            } else {
+                rest.find(char::is_whitespace).unwrap_or(rest.len())
+            };
+            output.push_str(label);
+            push_normalized_destination(&mut output, &rest[..url_len], options);
+            output.push_str(&rest[url_len..]);
+        } else {
+            for (segment, code) in inline_segments(line.text) {
+                if code {
+                    output.push_str(segment);
+                    continue;
+                }
+                let mut rest = segment;
+                while let Some(open) = rest.find("](") {
+                    output.push_str(&rest[..open + 2]);
+                    rest = &rest[open + 2..];
+                    if let Some((url, _, _)) = parse_destination(rest) {
+                        push_normalized_destination(&mut output, url, options);
+                        rest = &rest[url.len()..];
+                    }
+                }
+                output.push_str(rest);
+            }
+        }
+        output.push('\n');
+    }
+    output.pop();
+    output
+}
+
+/// Split a `[label]: destination` line into the part up to the destination and the rest.
+fn reference_definition(line: &str) -> Option<(&str, &str)> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 || !line[indent..].starts_with('[') {
+        return None;
+    }
+    let label_end = indent + 1 + link_text_end(&line[indent + 1..])?;
+    let after = line[label_end + 1..].strip_prefix(':')?;
+    let spaces = after.len() - after.trim_start().len();
+    let start = label_end + 2 + spaces;
+    (spaces > 0 && start < line.len()).then(|| line.split_at(start))
+}
+
+/// Write a link destination, normalized, keeping `<...>` brackets around it if it had them.
+fn push_normalized_destination(output: &mut String, destination: &str, options: &ConversionOptions) {
+    match destination.strip_prefix('<').and_then(|inner| inner.strip_suffix('>')) {
+        Some(inner) => {
+            output.push('<');
+            output.push_str(&normalize_url(inner, options));
+            output.push('>');
+        }
+        None => output.push_str(&normalize_url(destination, options)),
+    }
+}
+
+/// Normalize one URL as described on [`BuiltinPostProcessor::NormalizeUrls`].
+fn normalize_url<'a>(url: &'a str, options: &ConversionOptions) -> Cow<'a, str> {
+    let (without_fragment, fragment) = url
+        .split_once('#')
+        .map_or((url, None), |(head, tail)| (head, Some(tail)));
+    let (base, query) = without_fragment
+        .split_once('?')
+        .map_or((without_fragment, None), |(head, tail)| (head, Some(tail)));
+
+    let mut base = Cow::Borrowed(base);
+    if let Some(scheme_end) = base.find("://") {
+        let scheme = &base[..scheme_end];
+        if scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https") {
+            let authority_end = base[scheme_end + 3..]
+                .find('/')
+                .map_or(base.len(), |i| scheme_end + 3 + i);
+            if base[..authority_end].bytes().any(|b| b.is_ascii_uppercase()) {
+                base = Cow::Owned(base[..authority_end].to_ascii_lowercase() + &base[authority_end..]);
+            }
+            if base.len() > authority_end + 1 && base.ends_with('/') {
+                let trimmed = base.trim_end_matches('/').len().max(authority_end + 1);
+                base = Cow::Owned(base[..trimmed].to_string());
+            }
+        }
+    }
+
+    let kept_query = query.map(|query| {
+        query
+            .split('&')
+            .filter(|param| !param.is_empty() && keep_query_param(param, options))
+            .collect::<Vec<_>>()
+            .join("&")
+    });
+    let query_changed = match (query, kept_query.as_deref()) {
+        (Some(original), Some(kept)) => original != kept,
+        _ => false,
+    };
+
+    if matches!(base, Cow::Borrowed(_)) && !query_changed {
+        return Cow::Borrowed(url);
+    }
+    let mut normalized = base.into_owned();
+    if let Some(kept) = kept_query.filter(|kept| !kept.is_empty()) {
+        normalized.push('?');
+        normalized.push_str(&kept);
+    }
+    if let Some(fragment) = fragment {
+        normalized.push('#');
+        normalized.push_str(fragment);
+    }
+    Cow::Owned(normalized)
+}
+
+fn keep_query_param(param: &str, options: &ConversionOptions) -> bool {
+    let name = param.split_once('=').map_or(param, |(name, _)| name);
+    let listed = |patterns: &[String]| patterns.iter().any(|pattern| param_matches(pattern, name));
+    if options.url_param_allowlist.is_empty() {
+        !listed(&options.url_param_denylist)
+    } else {
+        listed(&options.url_param_allowlist)
+    }
+}
+
+/// Case-insensitive match of a parameter name against a pattern with an optional trailing `*`.
+fn param_matches(pattern: &str, name: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => name
+            .get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix)),
+        None => name.eq_ignore_ascii_case(pattern),
+    }
+}
+
+fn reference_links(markdown: &str) -> String {
+    let mut references: Vec<(String, Option<String>)> = Vec::new();
+    let mut index: HashMap<(String, Option<String>), usize> = HashMap::new();
+    let mut output = String::with_capacity(markdown.len());
+
+    for line in classify_lines(markdown) {
+        if line.code {
+            output.push_str(line.text);
+            output.push('\n');
+            continue;
+        }
+        for (segment, code) in inline_segments(line.text) {
+            if code {
+                output.push_str(segment);
+                continue;
+            }
+            let mut rest = segment;
+            while let Some(open) = rest.find('[') {
+                let is_image = rest[..open].ends_with('!');
+                let parsed = link_text_end(&rest[open + 1..]).and_then(|text_len| {
+                    let after_text = open + 1 + text_len + 1;
+                    let destination = rest[after_text..].strip_prefix('(')?;
+                    let (url, title, consumed) = parse_destination(destination)?;
+                    Some((text_len, url, title, after_text + 1 + consumed))
+                });
+                match parsed {
+                    Some((text_len, url, title, end)) if !is_image && !url.starts_with('#') => {
+                        let key = (url.to_string(), title.map(str::to_string));
+                        let number = *index.entry(key.clone()).or_insert_with(|| {
+                            references.push(key);
+                            references.len()
+                        });
+                        output.push_str(&rest[..=open + 1 + text_len]);
+                        output.push('[');
+                        output.push_str(&number.to_string());
+                        output.push(']');
+                        rest = &rest[end..];
+                    }
+                    _ => {
+                        output.push_str(&rest[..=open]);
+                        rest = &rest[open + 1..];
+                    }
+                }
+            }
+            output.push_str(rest);
+        }
+        output.push('\n');
+    }
+    output.pop();
+
+    if references.is_empty() {
+        return output;
+    }
+
+    let had_trailing_newline = output.ends_with('\n');
+    output.truncate(output.trim_end_matches('\n').len());
+    output.push_str("\n\n");
+    for (number, (url, title)) in references.iter().enumerate() {
+        output.push('[');
+        output.push_str(&(number + 1).to_string());
+        output.push_str("]: ");
+        output.push_str(url);
+        if let Some(title) = title {
+            output.push_str(" \"");
+            output.push_str(title);
+            output.push('"');
+        }
+        output.push('\n');
+    }
+    if !had_trailing_newline {
+        output.pop();
+    }
+    output
+}
+
+/// Visible text of a heading, with links, emphasis and code markers removed.
+pub(crate) fn heading_plain_text(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = rest.find('[') {
+        plain.push_str(&rest[..open]);
+        let Some(text_len) = link_text_end(&rest[open + 1..]) else {
+            plain.push('[');
+            rest = &rest[open + 1..];
+            continue;
+        };
+        plain.push_str(&rest[open + 1..open + 1 + text_len]);
+        rest = &rest[open + 1 + text_len + 1..];
+        if let Some(destination) = rest.strip_prefix('(') {
+            if let Some((_, _, consumed)) = parse_destination(destination) {
+                rest = &destination[consumed..];
+            }
+        }
+    }
+    plain.push_str(rest);
+    plain.retain(|c| !matches!(c, '*' | '_' | '`' | '\\'));
+    plain.trim().to_string()
+}
+
+/// GitHub-style anchor slug: lowercase, punctuation removed, spaces as hyphens.
+fn heading_slug(text: &str) -> String {
+    text.chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                Some(c.to_lowercase().next().unwrap_or(c))
+            } else if c == ' ' {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Length of a front matter block at the start of the document, if any.
+pub(crate) fn front_matter_len(markdown: &str) -> usize {
+    let fences: [(&str, &str); 3] = [("---\n", "\n---\n"), ("+++\n", "\n+++\n"), ("{\n", "\n}\n")];
+    for (open, close) in fences {
+        if markdown.starts_with(open) {
+            if let Some(end) = markdown[open.len() - 1..].find(close) {
+                let mut len = open.len() - 1 + end + close.len();
+                while markdown[len..].starts_with('\n') {
+                    len += 1;
+                }
+                return len;
+            }
+        }
+    }
+    0
+}
+
+fn table_of_contents(markdown: &str, options: &ConversionOptions) -> String {
+    let start = if options.extract_metadata {
+        front_matter_len(markdown)
+    } else {
+        0
+    };
+    let body = &markdown[start..];
+
+    let lines = classify_lines(body);
+    let mut headings: Vec<(usize, String)> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if line.code {
+            continue;
+        }
+        let trimmed = line.text.trim_start();
+        let hashes = trimmed.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+            let text = trimmed[hashes..].trim().trim_end_matches('#').trim_end();
+            headings.push((hashes, heading_plain_text(text)));
+            continue;
+        }
+        let next = lines.get(i + 1).filter(|next| !next.code).map(|next| next.text.trim());
+        if !is_blank(line.text) && !is_list_item(line.text) {
+            match next {
+                Some(underline) if !underline.is_empty() && underline.chars().all(|c| c == '=') => {
+                    headings.push((1, heading_plain_text(line.text)));
+                }
+                Some(underline) if underline.len() >= 3 && underline.chars().all(|c| c == '-') => {
+                    headings.push((2, heading_plain_text(line.text)));
+                }
+                _ => {}
+            }
+        }
+    }
+    headings.retain(|(_, text)| !text.is_empty());
+    if headings.is_empty() {
+        return markdown.to_string();
+    }
+
+    let bullet = options.bullets.chars().next().unwrap_or('-');
+    let indent_width = options.list_indent_width.max(2);
+    let top_level = headings.iter().map(|(level, _)| *level).min().unwrap_or(1);
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut toc = String::new();
+    for (level, text) in &headings {
+        let base = heading_slug(text);
+        let count = seen.entry(base.clone()).or_insert(0);
+        let slug = if *count == 0 { base } else { format!("{base}-{count}") };
+        *count += 1;
+
+        toc.push_str(&" ".repeat((level - top_level) * indent_width));
+        toc.push(bullet);
+        toc.push_str(" [");
+        toc.push_str(text);
+        toc.push_str("](#");
+        toc.push_str(&slug);
+        toc.push_str(")\n");
+    }
+
+    let mut output = String::with_capacity(markdown.len() + toc.len() + 1);
+    output.push_str(&markdown[..start]);
+    output.push_str(&toc);
+    if !body.is_empty() {
+        output.push('\n');
+        output.push_str(body);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_names_round_trip() {
+        for builtin in BuiltinPostProcessor::ALL {
+            assert_eq!(BuiltinPostProcessor::from_name(builtin.name()), Some(builtin));
+        }
+        assert_eq!(
+            BuiltinPostProcessor::from_name("Reference_Links"),
+            Some(BuiltinPostProcessor::ReferenceLinks)
+        );
+        assert_eq!(
+            BuiltinPostProcessor::from_name("tableOfContents"),
+            Some(BuiltinPostProcessor::TableOfContents)
+        );
+        assert_eq!(BuiltinPostProcessor::from_name("nope"), None);
+    }
+
+    #[test]
+    fn test_classify_lines_code() {
+        let lines = classify_lines("text\n\n    code\n\n    more\n- item\n\n    continuation\n```\n[a](b)\n```");
+        let code: Vec<bool> = lines.iter().map(|line| line.code).collect();
+        assert_eq!(
+            code,
+            [false, false, true, true, true, false, false, false, true, true, true]
+        );
+    }
+
+    #[test]
+    fn test_heading_slug() {
+        assert_eq!(heading_slug("Hello, World!"), "hello-world");
+        assert_eq!(heading_slug("API v2.0 – Über"), "api-v20--über");
+    }
+
+    #[test]
+    fn test_parse_destination() {
+        assert_eq!(parse_destination("a(b)c) tail"), Some(("a(b)c", None, 6)));
+        assert_eq!(parse_destination("<a b> \"T\")"), Some(("<a b>", Some("T"), 10)));
+        assert_eq!(parse_destination("url \"unterminated)"), None);
+    }
+}
@@ -0,0 +1,344 @@
+//! Print-media visibility hints used by `PreprocessingOptions::apply_print_styles`.
+//!
+//! Many sites ship a print stylesheet that hides chrome (navigation, share buttons, ads)
+//! and reveals a cleaner article layout. [`PrintStyles::extract`] reads the inline
+//! `<style media="print">` sheets and `@media print { ... }` blocks of a document and keeps
+//! the `display` rules whose selectors are simple compounds (`tag`, `.class`, `#id` and
+//! combinations such as `div.share#top`). Selectors with combinators, attribute or pseudo
+//! parts are ignored rather than approximated.
+//!
+//! When no stylesheet rule applies to an element, the utility classes of common CSS
+//! frameworks (`d-print-none`, `hidden-print`, `print:hidden`, `no-print`, ...) are used as
+//! a fallback, since those sheets are usually linked rather than inlined.
+
+/// Class names that hide an element when printing.
+const PRINT_HIDDEN_CLASSES: &[&str] = &[
+    "no-print",
+    "noprint",
+    "not-print",
+    "not-printable",
+    "hidden-print",
+    "hide-for-print",
+    "print-hidden",
+    "print-hide",
+    "d-print-none",
+    "print:hidden",
+    "screen-only",
+];
+
+/// Class names that show an element when printing, overriding the hidden classes above.
+const PRINT_VISIBLE_CLASSES: &[&str] = &[
+    "print-only",
+    "visible-print",
+    "visible-print-block",
+    "visible-print-inline",
+    "visible-print-inline-block",
+    "show-for-print",
+    "d-print-block",
+    "d-print-inline",
+    "d-print-inline-block",
+    "d-print-flex",
+    "d-print-table",
+    "print:block",
+    "print:inline",
+    "print:flex",
+];
+
+/// A compound selector such as `div.share#top`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SimpleSelector {
+    tag: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+}
+
+impl SimpleSelector {
+    fn parse(selector: &str) -> Option<Self> {
+        let mut chars = selector.trim().chars().peekable();
+        let mut parsed = Self {
+            tag: None,
+            id: None,
+            classes: Vec::new(),
+        };
+
+        if chars.peek() == Some(&'*') {
+            chars.next();
+        } else {
+            let tag = read_identifier(&mut chars);
+            if !tag.is_empty() {
+                parsed.tag = Some(tag.to_ascii_lowercase());
+            }
+        }
+
+        while let Some(marker) = chars.next() {
+            let name = read_identifier(&mut chars);
+            if name.is_empty() {
+                return None;
+            }
+            match marker {
+                '.' => parsed.classes.push(name),
+                '#' if parsed.id.is_none() => parsed.id = Some(name),
+                _ => return None,
+            }
+        }
+
+        let constrained = parsed.tag.is_some() || parsed.id.is_some() || !parsed.classes.is_empty();
+        constrained.then_some(parsed)
+    }
+
+    fn specificity(&self) -> (usize, usize, usize) {
+        (
+            usize::from(self.id.is_some()),
+            self.classes.len(),
+            usize::from(self.tag.is_some()),
+        )
+    }
+
+    fn matches(&self, tag_name: &str, id: Option<&str>, classes: &[&str]) -> bool {
+        self.tag.as_deref().is_none_or(|tag| tag == tag_name)
+            && self.id.as_deref().is_none_or(|expected| id == Some(expected))
+            && self.classes.iter().all(|class| classes.contains(&class.as_str()))
+    }
+}
+
+/// Read a CSS identifier, resolving backslash escapes such as `print\:hidden`.
+fn read_identifier(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut ident = String::new();
+    while let Some(&ch) = chars.peek() {
+        if ch == '\\' {
+            chars.next();
+            if let Some(escaped) = chars.next() {
+                ident.push(escaped);
+            }
+        } else if ch.is_alphanumeric() || ch == '-' || ch == '_' {
+            ident.push(ch);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    ident
+}
+
+#[derive(Debug, Clone)]
+struct PrintRule {
+    selector: SimpleSelector,
+    hidden: bool,
+}
+
+/// Print-media `display` rules collected from a document's inline stylesheets.
+#[derive(Debug, Clone, Default)]
+pub struct PrintStyles {
+    rules: Vec<PrintRule>,
+}
+
+impl PrintStyles {
+    /// Collect print rules from every `<style>` element in `html`.
+    pub fn extract(html: &str) -> Self {
+        let mut styles = Self::default();
+        let lowered = html.to_ascii_lowercase();
+        let mut cursor = 0;
+
+        while let Some(offset) = lowered[cursor..].find("<style") {
+            let start = cursor + offset;
+            let Some(open_len) = lowered[start..].find('>') else {
+                break;
+            };
+            let open_end = start + open_len + 1;
+            let Some(close_offset) = lowered[open_end..].find("</style") else {
+                break;
+            };
+            let close = open_end + close_offset;
+
+            let whole_sheet_is_print = media_attribute(&html[start..open_end]).is_some_and(targets_print);
+            styles.parse_sheet(&html[open_end..close], whole_sheet_is_print);
+            cursor = close;
+        }
+
+        styles
+    }
+
+    /// Whether an element with the given tag name, `id` and `class` attribute is hidden in print.
+    pub fn hides(&self, tag_name: &str, id: Option<&str>, class: Option<&str>) -> bool {
+        if matches!(tag_name, "html" | "head" | "body") {
+            return false;
+        }
+
+        let classes: Vec<&str> = class
+            .map(|value| value.split_whitespace().collect())
+            .unwrap_or_default();
+        let winner = self
+            .rules
+            .iter()
+            .enumerate()
+            .filter(|(_, rule)| rule.selector.matches(tag_name, id, &classes))
+            .max_by_key(|(order, rule)| (rule.selector.specificity(), *order));
+        if let Some((_, rule)) = winner {
+            return rule.hidden;
+        }
+
+        let has_class = |names: &[&str]| {
+            classes
+                .iter()
+                .any(|class| names.contains(&class.to_ascii_lowercase().as_str()))
+        };
+        has_class(PRINT_HIDDEN_CLASSES) && !has_class(PRINT_VISIBLE_CLASSES)
+    }
+
+    fn parse_sheet(&mut self, css: &str, in_print: bool) {
+        let css = strip_comments(css);
+        let mut rest = css.as_str();
+
+        while let Some(open) = rest.find('{') {
+            // Statement at-rules such as `@import ...;` end at a semicolon, not a block.
+            let prelude = rest[..open].rsplit(';').next().unwrap_or_default().trim();
+            let Some(close) = matching_brace(rest, open) else {
+                break;
+            };
+            let body = &rest[open + 1..close];
+
+            if let Some(query) = prelude.strip_prefix("@media") {
+                if targets_print(query) {
+                    self.parse_sheet(body, true);
+                }
+            } else if !prelude.starts_with('@') && in_print {
+                self.add_rule(prelude, body);
+            }
+
+            rest = &rest[close + 1..];
+        }
+    }
+
+    fn add_rule(&mut self, selectors: &str, declarations: &str) {
+        let Some(display) = declarations.split(';').rev().find_map(|declaration| {
+            let (property, value) = declaration.split_once(':')?;
+            property
+                .trim()
+                .eq_ignore_ascii_case("display")
+                .then(|| value.replace("!important", "").trim().to_ascii_lowercase())
+        }) else {
+            return;
+        };
+
+        let hidden = display == "none";
+        for selector in selectors.split(',') {
+            if let Some(selector) = SimpleSelector::parse(selector) {
+                self.rules.push(PrintRule { selector, hidden });
+            }
+        }
+    }
+}
+
+/// Extract the value of the `media` attribute from a `<style ...>` open tag.
+fn media_attribute(open_tag: &str) -> Option<&str> {
+    let lowered = open_tag.to_ascii_lowercase();
+    let mut search = 0;
+    while let Some(offset) = lowered[search..].find("media") {
+        let start = search + offset;
+        search = start + "media".len();
+        if !lowered[..start].ends_with(|c: char| c.is_ascii_whitespace()) {
+            continue;
+        }
+        let after = open_tag[search..].trim_start();
+        let Some(value) = after.strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        return Some(match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+            _ => value
+                .split(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+                .next()
+                .unwrap_or_default(),
+        });
+    }
+    None
+}
+
+/// Whether a media query list applies to print (`print`, `only print and (...)`).
+fn targets_print(query: &str) -> bool {
+    query.split(',').any(|query| {
+        let mut words = query
+            .split(|c: char| c.is_ascii_whitespace() || c == '(')
+            .filter(|w| !w.is_empty());
+        match words.next().map(str::to_ascii_lowercase).as_deref() {
+            Some("print") => true,
+            Some("only") => words.next().is_some_and(|word| word.eq_ignore_ascii_case("print")),
+            _ => false,
+        }
+    })
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        match rest[start + 2..].find("*/") {
+            Some(end) => rest = &rest[start + 2 + end + 2..],
+            None => return out,
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn matching_brace(css: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (idx, byte) in css.bytes().enumerate().skip(open) {
+        match byte {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print_rules_and_cascade() {
+        let styles = PrintStyles::extract(
+            r#"<style>
+                .share { color: red }
+                @media screen { .sidebar { display: none } }
+                @media only print and (min-width: 1px) {
+                    /* hide chrome */
+                    nav, .share, #comments, div.ad > a { display: none !important }
+                    .share.keep { display: block }
+                    .print\:hidden { display:none }
+                }
+            </style>
+            <style media="print">aside.related { display: none; }</style>"#,
+        );
+
+        assert!(styles.hides("nav", None, None));
+        assert!(styles.hides("span", None, Some("share")));
+        assert!(!styles.hides("span", None, Some("share keep")));
+        assert!(styles.hides("div", Some("comments"), None));
+        assert!(styles.hides("p", None, Some("print:hidden")));
+        assert!(styles.hides("aside", None, Some("related")));
+        assert!(!styles.hides("div", None, Some("related")));
+        assert!(!styles.hides("div", None, Some("sidebar")));
+        assert!(!styles.hides("a", None, None));
+    }
+
+    #[test]
+    fn test_framework_classes_without_stylesheet() {
+        let styles = PrintStyles::extract("<p>No styles</p>");
+
+        assert!(styles.rules.is_empty());
+        assert!(styles.hides("div", None, Some("btn d-print-none")));
+        assert!(styles.hides("div", None, Some("NoPrint")));
+        assert!(!styles.hides("div", None, Some("no-print print-only")));
+        assert!(!styles.hides("body", None, Some("no-print")));
+    }
+}
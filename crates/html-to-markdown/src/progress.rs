@@ -0,0 +1,205 @@
+//! Progress reporting for long conversions.
+//!
+//! [`convert_with_progress`](crate::convert_with_progress) calls back with a [`ProgressEvent`]
+//! once the document is parsed, then every [`ProgressInterval`] while the DOM is walked, and a
+//! last time when the Markdown is finished. Byte counts are positions in the parsed document, which
+//! is the input after `<script>`/`<style>` stripping and repairs, so [`ProgressEvent::fraction`]
+//! tracks how far through the document the walk is rather than how much of the output exists.
+
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+/// A snapshot of how far a conversion has got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressEvent {
+    /// DOM nodes visited so far.
+    pub nodes_processed: usize,
+    /// DOM nodes in the parsed document.
+    pub total_nodes: usize,
+    /// Offset of the furthest element start reached in the parsed document.
+    pub bytes_processed: usize,
+    /// Size of the parsed document in bytes.
+    pub total_bytes: usize,
+}
+
+impl ProgressEvent {
+    /// Approximate share of the document converted so far, from `0.0` to `1.0`.
+    #[must_use]
+    pub fn fraction(&self) -> f64 {
+        if self.total_bytes == 0 {
+            return if self.is_done() { 1.0 } else { 0.0 };
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let fraction = self.bytes_processed as f64 / self.total_bytes as f64;
+        fraction.min(1.0)
+    }
+
+    /// Whether this is the final event of the conversion.
+    #[must_use]
+    pub const fn is_done(&self) -> bool {
+        self.nodes_processed == self.total_nodes && self.bytes_processed == self.total_bytes
+    }
+}
+
+/// How often progress is reported while the DOM is walked.
+///
+/// A plain `usize` converts to [`ProgressInterval::Nodes`]. Zero is treated as one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressInterval {
+    /// Every this many visited DOM nodes.
+    Nodes(usize),
+    /// Every time the walk moves this many bytes further through the parsed document. Suits
+    /// large files better than a node count, since the number of events no longer depends on
+    /// how densely the markup is nested.
+    Bytes(usize),
+}
+
+impl ProgressInterval {
+    const fn step(self) -> usize {
+        match self {
+            Self::Nodes(step) | Self::Bytes(step) => {
+                if step == 0 {
+                    1
+                } else {
+                    step
+                }
+            }
+        }
+    }
+}
+
+impl From<usize> for ProgressInterval {
+    fn from(nodes: usize) -> Self {
+        Self::Nodes(nodes)
+    }
+}
+
+/// Counts visited nodes and calls the callback at each [`ProgressInterval`].
+pub(crate) struct ProgressTracker {
+    callback: Rc<dyn Fn(ProgressEvent)>,
+    interval: ProgressInterval,
+    next_report: Cell<usize>,
+    nodes: Cell<usize>,
+    bytes: Cell<usize>,
+    total_nodes: Cell<usize>,
+    total_bytes: Cell<usize>,
+}
+
+impl fmt::Debug for ProgressTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressTracker")
+            .field("interval", &self.interval)
+            .field("nodes", &self.nodes.get())
+            .field("bytes", &self.bytes.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ProgressTracker {
+    pub(crate) fn new(callback: Rc<dyn Fn(ProgressEvent)>, interval: ProgressInterval) -> Self {
+        Self {
+            callback,
+            interval,
+            next_report: Cell::new(interval.step()),
+            nodes: Cell::new(0),
+            bytes: Cell::new(0),
+            total_nodes: Cell::new(0),
+            total_bytes: Cell::new(0),
+        }
+    }
+
+    /// Record the size of the parsed document and report the starting point.
+    pub(crate) fn begin(&self, total_nodes: usize, total_bytes: usize) {
+        self.total_nodes.set(total_nodes);
+        self.total_bytes.set(total_bytes);
+        self.report();
+    }
+
+    /// Count one visited node that starts at `position`, if it is an element.
+    pub(crate) fn node(&self, position: Option<usize>) {
+        let nodes = self.nodes.get() + 1;
+        self.nodes.set(nodes);
+        if let Some(position) = position {
+            self.bytes
+                .set(self.bytes.get().max(position.min(self.total_bytes.get())));
+        }
+        let reached = match self.interval {
+            ProgressInterval::Nodes(_) => nodes,
+            ProgressInterval::Bytes(_) => self.bytes.get(),
+        };
+        if reached >= self.next_report.get() {
+            self.next_report.set(reached.saturating_add(self.interval.step()));
+            self.report();
+        }
+    }
+
+    /// Report completion.
+    pub(crate) fn finish(&self) {
+        self.nodes.set(self.total_nodes.get());
+        self.bytes.set(self.total_bytes.get());
+        self.report();
+    }
+
+    fn report(&self) {
+        (self.callback)(ProgressEvent {
+            nodes_processed: self.nodes.get().min(self.total_nodes.get()),
+            total_nodes: self.total_nodes.get(),
+            bytes_processed: self.bytes.get(),
+            total_bytes: self.total_bytes.get(),
+        });
+    }
+}
+
+/// Progress across the documents of a `split_documents` input.
+///
+/// Each document is converted with its own [`ProgressTracker`]. Its events are rescaled onto the
+/// document's share of the input, so the callback sees a single conversion that is done once the
+/// last document is.
+pub(crate) struct SplitProgress {
+    callback: Rc<dyn Fn(ProgressEvent)>,
+    interval: ProgressInterval,
+    input_len: usize,
+    bytes_before: Cell<usize>,
+    nodes_before: Rc<Cell<usize>>,
+}
+
+impl SplitProgress {
+    pub(crate) fn new(callback: Rc<dyn Fn(ProgressEvent)>, interval: ProgressInterval, input_len: usize) -> Self {
+        Self {
+            callback,
+            interval,
+            input_len,
+            bytes_before: Cell::new(0),
+            nodes_before: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Tracker for the next document, which is `len` bytes of the input.
+    pub(crate) fn document(&self, len: usize) -> Rc<ProgressTracker> {
+        let callback = Rc::clone(&self.callback);
+        let input_len = self.input_len;
+        let bytes_before = self.bytes_before.replace(self.bytes_before.get() + len);
+        let nodes_before = Rc::clone(&self.nodes_before);
+        let base_nodes = nodes_before.get();
+        let rescale = move |event: ProgressEvent| {
+            let bytes = if event.is_done() {
+                nodes_before.set(base_nodes + event.total_nodes);
+                len
+            } else {
+                event
+                    .bytes_processed
+                    .saturating_mul(len)
+                    .checked_div(event.total_bytes)
+                    .unwrap_or(0)
+            };
+            callback(ProgressEvent {
+                nodes_processed: base_nodes + event.nodes_processed,
+                total_nodes: base_nodes + event.total_nodes,
+                bytes_processed: bytes_before + bytes,
+                total_bytes: input_len,
+            });
+        };
+        Rc::new(ProgressTracker::new(Rc::new(rescale), self.interval))
+    }
+}
@@ -0,0 +1,230 @@
+//! Replay bundles for bug reports (requires the `repro` feature).
+//!
+//! A [`ReproBundle`] records everything needed to reproduce one conversion: the exact input
+//! bytes and the charset they were read with, the effective options, the crate version and
+//! enabled features that produced the result, and the Markdown (or error) that came out. Bundles
+//! are written as a zstd-compressed tar archive holding
+//!
+//! - `manifest.json` — bundle format, crate version, features, input charset, outcome
+//! - `input.html` — the input bytes, unmodified
+//! - `options.json` — the options as camelCase JSON
+//! - `output.md` or `error.txt` — the captured result
+//!
+//! so a report can be inspected with standard tools as well as replayed:
+//!
+//! ```
+//! use html_to_markdown_rs::ConversionOptions;
+//! use html_to_markdown_rs::repro::ReproBundle;
+//!
+//! let bundle = ReproBundle::capture(b"<h1>Broken?</h1>", None, &ConversionOptions::default());
+//! let mut archive = Vec::new();
+//! bundle.write_to(&mut archive).unwrap();
+//!
+//! let received = ReproBundle::read_from(archive.as_slice()).unwrap();
+//! let replay = received.replay();
+//! assert!(replay.matches);
+//! assert_eq!(replay.outcome, Ok("# Broken?\n".to_string()));
+//! ```
+
+use std::io::{Read, Write};
+
+use serde_json::{Value, json};
+
+use crate::encoding::decode_html;
+use crate::error::{ConversionError, Result};
+use crate::options::ConversionOptions;
+
+/// Version of the archive layout written by [`ReproBundle::write_to`].
+const FORMAT_VERSION: u64 = 1;
+
+/// zstd level for bundles: inputs are small enough that a high level costs little.
+const COMPRESSION_LEVEL: i32 = 19;
+
+/// Largest archive entry [`ReproBundle::read_from`] accepts.
+const MAX_ENTRY_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Cargo features of this crate that change what a conversion can do.
+const FEATURES: &[(&str, bool)] = &[
+    ("metadata", cfg!(feature = "metadata")),
+    ("inline-images", cfg!(feature = "inline-images")),
+    ("visitor", cfg!(feature = "visitor")),
+    ("async-visitor", cfg!(feature = "async-visitor")),
+    ("serde", cfg!(feature = "serde")),
+    ("parallel", cfg!(feature = "parallel")),
+    ("encoding", cfg!(feature = "encoding")),
+    ("warc", cfg!(feature = "warc")),
+    ("fetch", cfg!(feature = "fetch")),
+    ("repro", cfg!(feature = "repro")),
+];
+
+/// One conversion captured for replay.
+#[derive(Debug, Clone)]
+pub struct ReproBundle {
+    /// Input exactly as it was read, before charset decoding
+    pub input: Vec<u8>,
+    /// Charset label the input was declared with; `None` lets [`decode_html`] detect it
+    pub encoding: Option<String>,
+    /// Options the conversion ran with
+    pub options: ConversionOptions,
+    /// Version of `html-to-markdown-rs` that captured the bundle
+    pub version: String,
+    /// Enabled Cargo features of `html-to-markdown-rs` at capture time
+    pub features: Vec<String>,
+    /// Converted Markdown, or the error message of a failed conversion
+    pub outcome: std::result::Result<String, String>,
+}
+
+/// Result of replaying a [`ReproBundle`] with the running version of the crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReproReplay {
+    /// Markdown, or error message, produced now
+    pub outcome: std::result::Result<String, String>,
+    /// Whether `outcome` is identical to the captured one
+    pub matches: bool,
+    /// Whether the bundle was captured by this crate version
+    pub same_version: bool,
+}
+
+impl ReproBundle {
+    /// Run a conversion and record it.
+    ///
+    /// `input` is decoded with [`decode_html`] using `encoding` as the declared charset, then
+    /// converted with [`convert`](crate::convert). Failures are recorded in
+    /// [`outcome`](Self::outcome) rather than returned.
+    #[must_use]
+    pub fn capture(input: &[u8], encoding: Option<&str>, options: &ConversionOptions) -> Self {
+        let bundle = Self {
+            input: input.to_vec(),
+            encoding: encoding.map(str::to_string),
+            options: options.clone(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            features: FEATURES
+                .iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(name, _)| (*name).to_string())
+                .collect(),
+            outcome: Ok(String::new()),
+        };
+        let outcome = bundle.run();
+        Self { outcome, ..bundle }
+    }
+
+    /// Convert the recorded input again and compare the result with the captured one.
+    #[must_use]
+    pub fn replay(&self) -> ReproReplay {
+        let outcome = self.run();
+        ReproReplay {
+            matches: outcome == self.outcome,
+            same_version: self.version == env!("CARGO_PKG_VERSION"),
+            outcome,
+        }
+    }
+
+    fn run(&self) -> std::result::Result<String, String> {
+        let html = decode_html(&self.input, self.encoding.as_deref());
+        crate::convert(&html, Some(self.options.clone())).map_err(|err| err.to_string())
+    }
+
+    /// Write the bundle as a zstd-compressed tar archive.
+    ///
+    /// Entries carry fixed timestamps and permissions, so the same bundle always produces the
+    /// same bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::IoError`] if writing fails, or [`ConversionError::Other`] if
+    /// the options cannot be serialized.
+    pub fn write_to<W: Write>(&self, writer: W) -> Result<()> {
+        let (result_name, result) = match &self.outcome {
+            Ok(markdown) => ("output.md", markdown),
+            Err(message) => ("error.txt", message),
+        };
+        let manifest = json!({
+            "format": FORMAT_VERSION,
+            "version": self.version,
+            "features": self.features,
+            "encoding": self.encoding,
+            "outcome": if self.outcome.is_ok() { "ok" } else { "error" },
+        });
+        let options = serde_json::to_vec_pretty(&self.options)
+            .map_err(|err| ConversionError::Other(format!("failed to serialize options: {err}")))?;
+        let manifest = serde_json::to_vec_pretty(&manifest)
+            .map_err(|err| ConversionError::Other(format!("failed to serialize manifest: {err}")))?;
+
+        let encoder = zstd::Encoder::new(writer, COMPRESSION_LEVEL)?;
+        let mut archive = tar::Builder::new(encoder);
+        for (name, data) in [
+            ("manifest.json", manifest.as_slice()),
+            ("input.html", self.input.as_slice()),
+            ("options.json", options.as_slice()),
+            (result_name, result.as_bytes()),
+        ] {
+            let mut header = tar::Header::new_ustar();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(0);
+            archive.append_data(&mut header, name, data)?;
+        }
+        archive.into_inner()?.finish()?.flush()?;
+        Ok(())
+    }
+
+    /// Read a bundle written by [`write_to`](Self::write_to).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::IoError`] if reading or decompressing fails, and
+    /// [`ConversionError::InvalidInput`] if the archive is not a bundle this version understands.
+    pub fn read_from<R: Read>(reader: R) -> Result<Self> {
+        let mut archive = tar::Archive::new(zstd::Decoder::new(reader)?);
+        let (mut manifest, mut input, mut options, mut outcome) = (None, None, None, None);
+        for entry in archive.entries()? {
+            let entry = entry?;
+            let name = entry.path()?.to_string_lossy().into_owned();
+            if entry.size() > MAX_ENTRY_SIZE {
+                return Err(invalid(format!("{name} is larger than {MAX_ENTRY_SIZE} bytes")));
+            }
+            let mut data = Vec::new();
+            entry.take(MAX_ENTRY_SIZE).read_to_end(&mut data)?;
+            match name.as_str() {
+                "manifest.json" => manifest = Some(parse_json::<Value>(&name, &data)?),
+                "input.html" => input = Some(data),
+                "options.json" => options = Some(parse_json::<ConversionOptions>(&name, &data)?),
+                "output.md" => outcome = Some(Ok(utf8(&name, data)?)),
+                "error.txt" => outcome = Some(Err(utf8(&name, data)?)),
+                _ => {}
+            }
+        }
+
+        let manifest = manifest.ok_or_else(|| invalid("missing manifest.json"))?;
+        let format = manifest.get("format").and_then(Value::as_u64);
+        if format != Some(FORMAT_VERSION) {
+            return Err(invalid(format!("unsupported bundle format {format:?}")));
+        }
+        let text = |key: &str| manifest.get(key).and_then(Value::as_str).map(str::to_string);
+        Ok(Self {
+            input: input.ok_or_else(|| invalid("missing input.html"))?,
+            encoding: text("encoding"),
+            options: options.ok_or_else(|| invalid("missing options.json"))?,
+            version: text("version").unwrap_or_default(),
+            features: manifest
+                .get("features")
+                .and_then(Value::as_array)
+                .map(|features| features.iter().filter_map(Value::as_str).map(str::to_string).collect())
+                .unwrap_or_default(),
+            outcome: outcome.ok_or_else(|| invalid("missing output.md or error.txt"))?,
+        })
+    }
+}
+
+fn invalid(message: impl std::fmt::Display) -> ConversionError {
+    ConversionError::InvalidInput(format!("invalid repro bundle: {message}"))
+}
+
+fn parse_json<T: serde::de::DeserializeOwned>(name: &str, data: &[u8]) -> Result<T> {
+    serde_json::from_slice(data).map_err(|err| invalid(format!("{name}: {err}")))
+}
+
+fn utf8(name: &str, data: Vec<u8>) -> Result<String> {
+    String::from_utf8(data).map_err(|_| invalid(format!("{name} is not UTF-8")))
+}
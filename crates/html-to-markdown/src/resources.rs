@@ -0,0 +1,356 @@
+//! External resource collection for `MetadataConfig::extract_resources`.
+//!
+//! Scripts and stylesheets are stripped before the document is parsed, so
+//! [`collect_resources`] scans the raw HTML instead: it tokenizes start tags just far enough to
+//! read their attributes, skips comments and the raw text of `<script>`/`<style>`, and reads
+//! `url()`s and `@import`s from inline CSS. URLs are kept as written and reported once each, in
+//! document order. `data:`, `javascript:` and fragment-only URLs are never fetched and are left
+//! out.
+
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::path::Path;
+
+use crate::metadata::{ResourceMetadata, ResourceType};
+use crate::text;
+
+#[derive(Default)]
+struct Resources {
+    seen: HashSet<String>,
+    list: Vec<ResourceMetadata>,
+}
+
+impl Resources {
+    fn add(&mut self, url: &str, resource_type: ResourceType) {
+        let url = url.trim();
+        if !is_fetchable(url) || !self.seen.insert(url.to_string()) {
+            return;
+        }
+        self.list.push(ResourceMetadata {
+            url: url.to_string(),
+            resource_type,
+        });
+    }
+}
+
+/// Collect the URLs of the scripts, stylesheets, fonts, images, media and embedded documents
+/// referenced by `html`.
+pub fn collect_resources(html: &str) -> Vec<ResourceMetadata> {
+    let mut resources = Resources::default();
+    let bytes = html.as_bytes();
+    let mut cursor = 0;
+
+    while let Some(offset) = html[cursor..].find('<') {
+        let start = cursor + offset;
+        if html[start..].starts_with("<!--") {
+            cursor = html[start..].find("-->").map_or(html.len(), |end| start + end + 3);
+            continue;
+        }
+        if !bytes.get(start + 1).is_some_and(u8::is_ascii_alphabetic) {
+            cursor = start + 1;
+            continue;
+        }
+
+        let (name, attributes, tag_end) = parse_start_tag(html, start + 1);
+        add_element_resources(&name, &attributes, &mut resources);
+        if let Some((_, style)) = attributes.iter().find(|(key, _)| key == "style") {
+            add_css_resources(style, &mut resources);
+        }
+
+        cursor = tag_end;
+        if matches!(name.as_str(), "script" | "style") {
+            let close = find_ascii_case_insensitive(&html[tag_end..], &format!("</{name}"))
+                .map_or(html.len(), |offset| tag_end + offset);
+            if name == "style" {
+                add_css_resources(&html[tag_end..close], &mut resources);
+            }
+            cursor = close;
+        }
+    }
+
+    resources.list
+}
+
+fn add_element_resources(name: &str, attributes: &[(String, String)], resources: &mut Resources) {
+    let attribute = |key: &str| {
+        attributes
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    };
+    let srcset = || srcset_urls(attribute("srcset").unwrap_or_default());
+
+    let mut urls: Vec<(&str, ResourceType)> = Vec::new();
+    let mut add = |key: &str, resource_type: ResourceType| {
+        if let Some(url) = attribute(key) {
+            urls.push((url, resource_type));
+        }
+    };
+    match name {
+        "script" => add("src", ResourceType::Script),
+        "link" => {
+            if let Some(resource_type) = link_resource_type(attribute("rel").unwrap_or_default(), attribute("as")) {
+                add("href", resource_type);
+            }
+        }
+        "img" => add("src", ResourceType::Image),
+        "source" => add("src", ResourceType::Media),
+        "video" => {
+            add("poster", ResourceType::Image);
+            add("src", ResourceType::Media);
+        }
+        "audio" | "track" => add("src", ResourceType::Media),
+        "input" if attribute("type").is_some_and(|kind| kind.eq_ignore_ascii_case("image")) => {
+            add("src", ResourceType::Image);
+        }
+        "image" => {
+            add("href", ResourceType::Image);
+            add("xlink:href", ResourceType::Image);
+        }
+        "iframe" | "frame" | "embed" => add("src", ResourceType::Embed),
+        "object" => add("data", ResourceType::Embed),
+        _ => {}
+    }
+    add("background", ResourceType::Image);
+    if matches!(name, "img" | "source") {
+        urls.extend(srcset().into_iter().map(|url| (url, ResourceType::Image)));
+    }
+
+    for (url, resource_type) in urls {
+        resources.add(url, resource_type);
+    }
+}
+
+/// Resource type of a `<link>` by its `rel` (and `as` for preloads), or `None` for content
+/// links such as `canonical` or `alternate`.
+fn link_resource_type(rel: &str, destination: Option<&str>) -> Option<ResourceType> {
+    let rel = rel.to_ascii_lowercase();
+    let tokens: Vec<&str> = rel.split_ascii_whitespace().collect();
+    let has = |token: &str| tokens.contains(&token);
+
+    if has("stylesheet") {
+        return Some(ResourceType::Stylesheet);
+    }
+    if tokens.iter().any(|token| token.ends_with("icon")) {
+        return Some(ResourceType::Image);
+    }
+    if has("manifest") {
+        return Some(ResourceType::Other);
+    }
+    if has("modulepreload") {
+        return Some(ResourceType::Script);
+    }
+    if !has("preload") && !has("prefetch") {
+        return None;
+    }
+    let resource_type = match destination.map(str::to_ascii_lowercase).as_deref() {
+        Some("script" | "worker" | "sharedworker" | "serviceworker") => ResourceType::Script,
+        Some("style") => ResourceType::Stylesheet,
+        Some("font") => ResourceType::Font,
+        Some("image") => ResourceType::Image,
+        Some("audio" | "video" | "track") => ResourceType::Media,
+        Some("document" | "embed" | "object") => ResourceType::Embed,
+        _ => ResourceType::Other,
+    };
+    Some(resource_type)
+}
+
+/// Add the `@import`s, `@font-face` sources and other `url()`s of a stylesheet, in that order
+/// of precedence.
+fn add_css_resources(css: &str, resources: &mut Resources) {
+    let lowered = css.to_ascii_lowercase();
+
+    let mut cursor = 0;
+    while let Some(offset) = lowered[cursor..].find("@import") {
+        let start = cursor + offset + "@import".len();
+        if let Some(url) = css_url_or_string(&css[start..]) {
+            resources.add(url, ResourceType::Stylesheet);
+        }
+        cursor = start;
+    }
+
+    let mut cursor = 0;
+    while let Some(offset) = lowered[cursor..].find("@font-face") {
+        let start = cursor + offset;
+        let end = lowered[start..].find('}').map_or(css.len(), |end| start + end);
+        for url in css_urls(&css[start..end]) {
+            resources.add(url, ResourceType::Font);
+        }
+        cursor = end;
+    }
+
+    for url in css_urls(css) {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let extension = Path::new(path)
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(str::to_ascii_lowercase);
+        let resource_type = match extension.as_deref() {
+            Some("woff2" | "woff" | "ttf" | "otf" | "eot") => ResourceType::Font,
+            Some("css") => ResourceType::Stylesheet,
+            _ => ResourceType::Image,
+        };
+        resources.add(url, resource_type);
+    }
+}
+
+/// The arguments of every `url(...)` in `css`, without quotes.
+fn css_urls(css: &str) -> Vec<&str> {
+    let mut urls = Vec::new();
+    let mut cursor = 0;
+    while let Some(offset) = find_ascii_case_insensitive(&css[cursor..], "url(") {
+        let start = cursor + offset + "url(".len();
+        let Some(end) = css[start..].find(')').map(|end| start + end) else {
+            break;
+        };
+        urls.push(unquote(css[start..end].trim()));
+        cursor = end;
+    }
+    urls
+}
+
+/// The URL at the start of `css`, written either as `url(...)` or as a quoted string.
+fn css_url_or_string(css: &str) -> Option<&str> {
+    let css = css.trim_start();
+    if css.len() >= 4 && css[..4].eq_ignore_ascii_case("url(") {
+        return css_urls(css).into_iter().next();
+    }
+    let quote = css.chars().next().filter(|quote| matches!(quote, '"' | '\''))?;
+    let end = css[1..].find(quote)?;
+    Some(&css[1..=end])
+}
+
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| value.strip_prefix(*quote)?.strip_suffix(*quote))
+        .unwrap_or(value)
+}
+
+/// The image URLs of a `srcset` attribute. URLs may contain commas, so candidates are split on
+/// the commas that follow whitespace-separated descriptors.
+fn srcset_urls(srcset: &str) -> Vec<&str> {
+    let mut urls = Vec::new();
+    let mut rest = srcset;
+    loop {
+        rest = rest.trim_start_matches(|ch: char| ch.is_ascii_whitespace() || ch == ',');
+        if rest.is_empty() {
+            break;
+        }
+        let end = rest.find(|ch: char| ch.is_ascii_whitespace()).unwrap_or(rest.len());
+        let candidate = &rest[..end];
+        let url = candidate.trim_end_matches(',');
+        urls.push(url);
+        rest = &rest[end..];
+        if url.len() == candidate.len() {
+            rest = rest.find(',').map_or("", |comma| &rest[comma + 1..]);
+        }
+    }
+    urls
+}
+
+fn is_fetchable(url: &str) -> bool {
+    if url.is_empty() || url.starts_with('#') {
+        return false;
+    }
+    let scheme = url.split(':').next().unwrap_or_default().to_ascii_lowercase();
+    !matches!(
+        scheme.as_str(),
+        "data" | "javascript" | "about" | "blob" | "mailto" | "tel"
+    )
+}
+
+/// Parse the start tag whose name begins at `start`, returning the lowercased name, the
+/// attributes (lowercased names, entity-decoded values) and the offset after the closing `>`.
+fn parse_start_tag(html: &str, start: usize) -> (String, Vec<(String, String)>, usize) {
+    let bytes = html.as_bytes();
+    let is_name_end = |byte: u8| byte.is_ascii_whitespace() || matches!(byte, b'>' | b'/');
+
+    let mut idx = start;
+    while idx < bytes.len() && !is_name_end(bytes[idx]) {
+        idx += 1;
+    }
+    let name = html[start..idx].to_ascii_lowercase();
+
+    let mut attributes = Vec::new();
+    loop {
+        while idx < bytes.len() && (bytes[idx].is_ascii_whitespace() || bytes[idx] == b'/') {
+            idx += 1;
+        }
+        if idx >= bytes.len() {
+            return (name, attributes, bytes.len());
+        }
+        if bytes[idx] == b'>' {
+            return (name, attributes, idx + 1);
+        }
+
+        let name_start = idx;
+        while idx < bytes.len() && !is_name_end(bytes[idx]) && bytes[idx] != b'=' {
+            idx += 1;
+        }
+        let key = html[name_start..idx].to_ascii_lowercase();
+        while idx < bytes.len() && bytes[idx].is_ascii_whitespace() {
+            idx += 1;
+        }
+        if bytes.get(idx) != Some(&b'=') {
+            attributes.push((key, String::new()));
+            continue;
+        }
+        idx += 1;
+        while idx < bytes.len() && bytes[idx].is_ascii_whitespace() {
+            idx += 1;
+        }
+
+        let value = if let Some(&quote @ (b'"' | b'\'')) = bytes.get(idx) {
+            let value_start = idx + 1;
+            let value_end = html[value_start..]
+                .find(quote as char)
+                .map_or(bytes.len(), |end| value_start + end);
+            idx = (value_end + 1).min(bytes.len());
+            &html[value_start..value_end]
+        } else {
+            let value_start = idx;
+            while idx < bytes.len() && !bytes[idx].is_ascii_whitespace() && bytes[idx] != b'>' {
+                idx += 1;
+            }
+            &html[value_start..idx]
+        };
+        attributes.push((key, text::decode_html_entities(value)));
+    }
+}
+
+fn find_ascii_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srcset_urls() {
+        assert_eq!(srcset_urls("a.jpg 1x, b,c.jpg 2x,d.jpg"), ["a.jpg", "b,c.jpg", "d.jpg"]);
+        assert_eq!(srcset_urls(" small.png, large.png 800w "), ["small.png", "large.png"]);
+    }
+
+    #[test]
+    fn test_parse_start_tag() {
+        let html = r#"<link rel=preload as="font" href='/f.woff2?a=1&amp;b=2' crossorigin>rest"#;
+        let (name, attributes, end) = parse_start_tag(html, 1);
+
+        assert_eq!(name, "link");
+        assert_eq!(
+            attributes,
+            [
+                ("rel".to_string(), "preload".to_string()),
+                ("as".to_string(), "font".to_string()),
+                ("href".to_string(), "/f.woff2?a=1&b=2".to_string()),
+                ("crossorigin".to_string(), String::new()),
+            ]
+        );
+        assert_eq!(&html[end..], "rest");
+    }
+}
@@ -0,0 +1,461 @@
+//! JSON Schema describing the serialized form of [`ConversionOptions`].
+//!
+//! The schema is built from the same canonical value names the options serialize to, and the
+//! defaults come from serializing [`ConversionOptions::default`], so it stays in step with the
+//! `Serialize` implementation. Validation rules enforced by [`ConversionOptionsBuilder::build`]
+//! appear as `minimum`, `minLength` and `pattern` constraints.
+
+use serde_json::{Map, Value, json};
+
+use crate::options::{
+    AbbrStyle, BidiMode, CodeBlockStyle, ContactLinkStyle, ConversionOptions, ConversionOptionsBuilder, DetailsStyle,
+    DfnStyle, FigureStyle, FrontMatterFormat, HeaderBreakStyle, HeadingStyle, HighlightStyle, ListIndentType,
+    ListSpacing, MathStyle, NbspMode, NewlineStyle, PreprocessingPreset, SrcsetPolicy, SvgTextMode, TimeStyle,
+    TitleSource, UnrepresentablePolicy, WhitespaceMode,
+};
+use crate::postprocess::BuiltinPostProcessor;
+
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Build the JSON Schema for [`ConversionOptions`].
+pub fn conversion_options_schema() -> Value {
+    let defaults = serde_json::to_value(ConversionOptions::default()).unwrap_or_default();
+    let post_processor_names: Vec<&str> = BuiltinPostProcessor::ALL.iter().map(|builtin| builtin.name()).collect();
+
+    let properties = [
+        ("headingStyle", "Heading style", string_enum(HeadingStyle::NAMES)),
+        (
+            "listIndentType",
+            "List indentation type",
+            string_enum(ListIndentType::NAMES),
+        ),
+        (
+            "listIndentWidth",
+            "List indentation width in spaces (applied if using spaces indentation)",
+            json!({"type": "integer", "minimum": 0}),
+        ),
+        (
+            "bullets",
+            "Bullet characters for unordered lists (e.g., \"-\", \"*\", \"+\")",
+            json!({"type": "string", "minLength": 1}),
+        ),
+        (
+            "strongEmSymbol",
+            "Symbol for strong/emphasis emphasis rendering (* or _)",
+            string_enum(&["*", "_"]),
+        ),
+        (
+            "escapeAsterisks",
+            "Escape asterisks (*) in text to prevent accidental formatting",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "escapeUnderscores",
+            "Escape underscores (_) in text to prevent accidental formatting",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "escapeMisc",
+            "Escape miscellaneous markdown characters (\\ & < ` [ > ~ # = + | -)",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "escapeAscii",
+            "Escape all ASCII punctuation characters (for `CommonMark` spec compliance tests)",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "codeLanguage",
+            "Default code language for fenced code blocks when not specified",
+            json!({"type": "string"}),
+        ),
+        (
+            "autolinks",
+            "Use autolinks syntax for bare URLs (<http://example.com>)",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "defaultTitle",
+            "Use a link's URL as its title when the link text is the URL itself",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "brInTables",
+            "Use HTML <br> elements in tables instead of spaces for line breaks",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "hocrSpatialTables",
+            "Enable spatial table reconstruction in hOCR documents (via spatial positioning analysis)",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "highlightStyle",
+            "Highlight style for <mark> elements",
+            string_enum(HighlightStyle::NAMES),
+        ),
+        (
+            "extractMetadata",
+            "Extract metadata from HTML (title, description, images, links, etc.)",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "whitespaceMode",
+            "Whitespace handling mode (normalized collapses multiple spaces, strict preserves)",
+            string_enum(WhitespaceMode::NAMES),
+        ),
+        (
+            "stripNewlines",
+            "Strip newline characters from HTML before processing",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "wrap",
+            "Enable automatic text wrapping at `wrapWidth`",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "wrapWidth",
+            "Text wrapping width in characters (default 80)",
+            json!({"type": "integer", "minimum": ConversionOptionsBuilder::MIN_WRAP_WIDTH}),
+        ),
+        (
+            "convertAsInline",
+            "Treat block-level elements as inline during conversion",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "subSymbol",
+            "Custom symbol for subscript content (e.g., \"~\")",
+            json!({"type": "string"}),
+        ),
+        (
+            "supSymbol",
+            "Custom symbol for superscript content (e.g., \"^\")",
+            json!({"type": "string"}),
+        ),
+        (
+            "newlineStyle",
+            "Newline style in markdown output (spaces adds two spaces, backslash adds \\)",
+            string_enum(NewlineStyle::NAMES),
+        ),
+        ("codeBlockStyle", "Code block style", string_enum(CodeBlockStyle::NAMES)),
+        (
+            "keepInlineImagesIn",
+            "HTML elements where images should remain as markdown links (not converted to alt text)",
+            json!({"type": "array", "items": {"type": "string"}}),
+        ),
+        (
+            "preprocessing",
+            "HTML preprocessing options (remove nav, forms, etc.)",
+            preprocessing_schema(&defaults["preprocessing"]),
+        ),
+        (
+            "encoding",
+            "Source document encoding (informational, typically \"utf-8\")",
+            json!({"type": "string"}),
+        ),
+        (
+            "debug",
+            "Enable debug mode with diagnostic warnings on conversion issues",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "stripTags",
+            "HTML tags to strip (extract text content, no markdown conversion)",
+            json!({"type": "array", "items": {"type": "string"}}),
+        ),
+        (
+            "removeTags",
+            "HTML tags to remove together with all of their content",
+            json!({"type": "array", "items": {"type": "string"}}),
+        ),
+        (
+            "preserveTags",
+            "HTML tags to preserve as-is in output (keep original HTML, useful for complex tables)",
+            json!({"type": "array", "items": {"type": "string"}}),
+        ),
+        (
+            "skipImages",
+            "Skip all images during conversion. When enabled, all `<img>` elements are completely omitted from output. Useful for text-only extraction or filtering out visual content.",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "removeSpaceBeforePunctuation",
+            "Remove whitespace left between an inline element and following punctuation (e.g. `word **bold** .` becomes `word **bold**.`)",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "nbspInProse",
+            "Non-breaking space handling in paragraphs, headings, lists and other running text (space converts U+00A0 to a regular space, preserve keeps it)",
+            string_enum(NbspMode::NAMES),
+        ),
+        (
+            "nbspInTables",
+            "Non-breaking space handling in table cells (space converts, preserve keeps U+00A0)",
+            string_enum(NbspMode::NAMES),
+        ),
+        (
+            "frontMatterFormat",
+            "Serialization format for the metadata block emitted when `extractMetadata` is enabled",
+            string_enum(FrontMatterFormat::NAMES),
+        ),
+        (
+            "removeSoftHyphens",
+            "Remove invisible soft hyphens (U+00AD) and word joiners (U+2060, U+FEFF) from text (code spans and blocks are left untouched)",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "bidiMode",
+            "Handling of `dir=\"rtl\"`/`dir=\"ltr\"` direction changes (ignore, direction marks, embedding controls, or an HTML `dir` passthrough wrapper)",
+            string_enum(BidiMode::NAMES),
+        ),
+        (
+            "localizedPunctuation",
+            "Use the quotation marks, parentheses and dashes of the element's `lang` when the converter inserts punctuation itself (`<q>` quotes, `<abbr>` expansions, blockquote attributions)",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "orderedMarkers",
+            "Delimiters for ordered list markers, one per nesting level (`.` or `)`)",
+            json!({"type": "string", "pattern": "^[.)]+$"}),
+        ),
+        (
+            "cycleBullets",
+            "Cycle through `bullets` by nesting level; when false the first bullet is used at every level",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "listSpacing",
+            "Spacing between list items: follow the source (auto), or force tight or loose lists",
+            string_enum(ListSpacing::NAMES),
+        ),
+        (
+            "unrepresentablePolicy",
+            "Handling of content without a lossless Markdown form (`<bdo>`, `<bdi>`, `<wbr>`, removed soft hyphens): drop it, pass it through, or drop it and record a diagnostic",
+            string_enum(UnrepresentablePolicy::NAMES),
+        ),
+        (
+            "postProcessors",
+            "Post-processors run on the Markdown after conversion and before wrapping, in order",
+            json!({"type": "array", "items": string_enum(&post_processor_names)}),
+        ),
+        (
+            "urlParamAllowlist",
+            "Query parameters the `normalize-urls` post-processor keeps; when non-empty, every other parameter is dropped and `urlParamDenylist` is ignored. A trailing `*` matches any suffix",
+            json!({"type": "array", "items": {"type": "string"}}),
+        ),
+        (
+            "urlParamDenylist",
+            "Query parameters the `normalize-urls` post-processor drops from link and image URLs. A trailing `*` matches any suffix",
+            json!({"type": "array", "items": {"type": "string"}}),
+        ),
+        (
+            "collectStats",
+            "Collect a resource usage report (timings per phase, nodes parsed, output size, peak memory estimate), returned by `convert_with_stats`",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "titleFallback",
+            "Title sources tried in order when a link has no `title` attribute or the document has no `<title>` (front matter); empty disables the fallback",
+            json!({"type": "array", "items": string_enum(TitleSource::NAMES)}),
+        ),
+        (
+            "pandocAttributes",
+            "Emit Pandoc attribute blocks (`{#id .class}`) for headings, fenced code blocks, links and images",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "svgTextMode",
+            "How the `<title>`, `<desc>` and `<text>` labels of inline SVGs are surfaced: as alt text, as a caption below the image, or instead of it",
+            string_enum(SvgTextMode::NAMES),
+        ),
+        (
+            "objectLinks",
+            "Append a link to the `data`/`src` URL of `<object>` and `<embed>` elements after their fallback content, unless the fallback already links to it",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "preserveHeadingIds",
+            "Keep heading ids so `#fragment` links still resolve: as an empty `<a id>` anchor before the heading text, or as `{#id}` when `pandocAttributes` is on",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "contentFingerprint",
+            "Compute a hash of the output's text that ignores whitespace and Markdown syntax, returned by `convert_with_fingerprint`",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "mathStyle",
+            "How `<math>` elements and MathJax TeX payloads are written: as an HTML comment followed by the plain text, as `$...$`/`$$...$$`, or as ``$`...`$`` inline math and ```` ```math ```` blocks",
+            string_enum(MathStyle::NAMES),
+        ),
+        (
+            "inferCodeLanguage",
+            "Emit a fence language for code blocks without a `language-*`/`lang-*` class, taken from highlighter classes (`brush: js`, `highlight-source-python`, `sourceCode python`), `data-lang` attributes or the code itself",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "codeBlockTrim",
+            "Drop blank lines at the start and end of `<pre>` code blocks; whitespace within the block's lines is always kept",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "srcsetPolicy",
+            "Which candidate of an `<img>` `srcset`, or of the `<source>` elements of its `<picture>`, is used when the image has no `src`: the first in document order, the largest or the smallest",
+            string_enum(SrcsetPolicy::NAMES),
+        ),
+        (
+            "figureStyle",
+            "How `<figure>` captions are written: as an italic paragraph after the figure content, as a blockquote, or with the whole figure kept as HTML",
+            string_enum(FigureStyle::NAMES),
+        ),
+        (
+            "detailsStyle",
+            "How `<details>` sections are written: the summary as a bold line followed by the body, or the `<details>`/`<summary>` wrapper kept as HTML around the converted body",
+            string_enum(DetailsStyle::NAMES),
+        ),
+        (
+            "abbrStyle",
+            "How `<abbr>` is written: the abbreviation followed by its `title` in parentheses, the abbreviation alone, or the element kept as inline HTML",
+            string_enum(AbbrStyle::NAMES),
+        ),
+        (
+            "timeStyle",
+            "How `<time>` is written: its text, its text followed by the machine-readable `datetime` value in parentheses, or the element kept as inline HTML",
+            string_enum(TimeStyle::NAMES),
+        ),
+        (
+            "dfnStyle",
+            "How `<dfn>` is written: as emphasis, as plain text, or kept as inline HTML",
+            string_enum(DfnStyle::NAMES),
+        ),
+        (
+            "contactLinkStyle",
+            "How `mailto:` and `tel:` links are written: as regular links, as autolinks when the text is the address, or as plain text with the address in parentheses when the two differ",
+            string_enum(ContactLinkStyle::NAMES),
+        ),
+        (
+            "fidelityThreshold",
+            "Keep a table, form or `<math>` subtree as sanitized HTML when more than this fraction of its structural elements (nested tables, merged cells, form controls, MathML under the comment math style) would be lost in Markdown. `1.0` turns the guard off",
+            json!({"type": "number", "minimum": 0.0, "maximum": 1.0}),
+        ),
+        (
+            "titleFootnotes",
+            "Write the `title` attribute of links, `<abbr>` and `<span>` elements as a `[^n]` footnote, with the definitions collected before the next heading and at the end of the document",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "headerBreakStyle",
+            "What `<br>` becomes inside headings and `<th>` cells: `inherit` keeps two spaces in headings and `brInTables` for cells, `space` and `slash` join the lines with a space or ` / `, `html` writes an inline `<br>`",
+            string_enum(HeaderBreakStyle::NAMES),
+        ),
+        (
+            "reportDroppedContent",
+            "Record, for each rule that removes content (navigation and form removal, `removeTags`, exclusion and inclusion selectors, print styles, main content extraction), how many text characters it dropped, returned as diagnostics",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "splitDocuments",
+            "Detect input made of several complete HTML documents glued together (repeated doctypes, `<html>` or `<body>` elements) and convert each one separately, joined by `---` thematic breaks",
+            json!({"type": "boolean"}),
+        ),
+        (
+            "preservedAttributes",
+            "Attributes kept on elements written out as HTML (`preserveTags`, the fidelity guard, HTML styles, visitor `PreserveHtml`); when non-empty, every other attribute is dropped. A `style:<property>` entry keeps just that declaration of the `style` attribute",
+            json!({"type": "array", "items": {"type": "string"}}),
+        ),
+        (
+            "maxOutputChars",
+            "Cap the output at this many characters, cutting between top-level blocks and ending with an `<!-- truncated -->` line; `null` or `0` leaves the length unbounded",
+            json!({"type": ["integer", "null"], "minimum": 0}),
+        ),
+        (
+            "maxOutputTokens",
+            "Cap the output at this many tokens (estimated as one per four characters by default), cutting the same way as `maxOutputChars`; `null` or `0` leaves the length unbounded",
+            json!({"type": ["integer", "null"], "minimum": 0}),
+        ),
+    ];
+
+    let mut schema = object_schema(properties, &defaults);
+    schema["$schema"] = json!(SCHEMA_DIALECT);
+    schema["title"] = json!("ConversionOptions");
+    schema["description"] = json!("Options for converting HTML to Markdown. Every property is optional.");
+    schema
+}
+
+fn preprocessing_schema(defaults: &Value) -> Value {
+    object_schema(
+        [
+            (
+                "enabled",
+                "Enable HTML preprocessing globally",
+                json!({"type": "boolean"}),
+            ),
+            (
+                "preset",
+                "Preprocessing preset level",
+                string_enum(PreprocessingPreset::NAMES),
+            ),
+            (
+                "removeNavigation",
+                "Remove navigation elements (nav, breadcrumbs, menus, sidebars)",
+                json!({"type": "boolean"}),
+            ),
+            (
+                "removeForms",
+                "Remove form elements (forms, inputs, buttons, etc.)",
+                json!({"type": "boolean"}),
+            ),
+            (
+                "applyPrintStyles",
+                "Drop elements hidden by the document's print stylesheet",
+                json!({"type": "boolean"}),
+            ),
+            (
+                "extractMainContent",
+                "Convert only the main article, detected by text and link density",
+                json!({"type": "boolean"}),
+            ),
+            (
+                "flattenShadowDom",
+                "Compose declarative shadow roots with their host's light DOM",
+                json!({"type": "boolean"}),
+            ),
+            (
+                "excludeSelectors",
+                "CSS selectors whose matching elements are dropped with their content",
+                json!({"type": "array", "items": {"type": "string"}}),
+            ),
+            (
+                "includeSelector",
+                "CSS selector for the only elements to convert",
+                json!({"type": ["string", "null"]}),
+            ),
+        ],
+        defaults,
+    )
+}
+
+/// An object schema with one property per entry, each annotated with its default.
+fn object_schema<const N: usize>(properties: [(&str, &str, Value); N], defaults: &Value) -> Value {
+    let properties: Map<String, Value> = properties
+        .into_iter()
+        .map(|(name, description, mut schema)| {
+            schema["description"] = json!(description);
+            if let Some(default) = defaults.get(name) {
+                schema["default"] = default.clone();
+            }
+            (name.to_string(), schema)
+        })
+        .collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    })
+}
+
+fn string_enum(names: &[&str]) -> Value {
+    json!({ "type": "string", "enum": names })
+}
@@ -0,0 +1,170 @@
+//! Heading lookup for [`crate::convert_section`].
+//!
+//! A section starts at a heading and runs up to the next heading of the same or a higher
+//! level (`<h2>` stops at the next `<h1>` or `<h2>`), or to the end of the document. The
+//! section is cut out of the source by byte offset, so it may start inside a container and
+//! end inside another one; the converter copes with the unbalanced tags that leaves behind.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::converter::strip_script_and_style_tags;
+use crate::selector::SelectorList;
+
+struct Heading {
+    id: u32,
+    level: u8,
+    start: usize,
+}
+
+/// Return the HTML of the section introduced by the heading matching `heading`.
+///
+/// `heading` is tried as an element id (with or without a leading `#`), then as the
+/// heading's text (case-insensitive, whitespace-collapsed) and finally as a CSS selector.
+/// Returns `None` if no heading matches.
+pub fn extract_section<'a>(html: &'a str, heading: &str) -> Option<Cow<'a, str>> {
+    let source = strip_script_and_style_tags(html, false);
+    let range = section_range(&source, heading)?;
+
+    Some(match source {
+        Cow::Borrowed(source) => Cow::Borrowed(&source[range]),
+        Cow::Owned(source) => Cow::Owned(source[range].to_string()),
+    })
+}
+
+fn section_range(source: &str, heading: &str) -> Option<Range<usize>> {
+    let dom = tl::parse(source, tl::ParserOptions::default()).ok()?;
+    let parser = dom.parser();
+
+    let mut headings = Vec::new();
+    let mut parents = HashMap::new();
+    let mut stack: Vec<tl::NodeHandle> = dom.children().to_vec();
+    while let Some(handle) = stack.pop() {
+        let Some(tl::Node::Tag(tag)) = handle.get(parser) else {
+            continue;
+        };
+        if let Some(level) = heading_level(&tag.name().as_utf8_str()) {
+            headings.push(Heading {
+                id: handle.get_inner(),
+                level,
+                start: tag.boundaries(parser).0,
+            });
+        }
+        for child in tag.children().top().iter() {
+            parents.insert(child.get_inner(), handle.get_inner());
+            stack.push(*child);
+        }
+    }
+    headings.sort_by_key(|heading| heading.start);
+
+    let target = find_heading(&headings, heading, parser, &parents)?;
+    let end = headings
+        .iter()
+        .find(|next| next.start > target.start && next.level <= target.level)
+        .map_or(source.len(), |next| next.start);
+    Some(target.start..end)
+}
+
+fn find_heading<'h>(
+    headings: &'h [Heading],
+    query: &str,
+    parser: &tl::Parser<'_>,
+    parents: &HashMap<u32, u32>,
+) -> Option<&'h Heading> {
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+    let tag_of = |heading: &Heading| match tl::NodeHandle::new(heading.id).get(parser) {
+        Some(tl::Node::Tag(tag)) => Some(tag),
+        _ => None,
+    };
+
+    let id = query.strip_prefix('#').unwrap_or(query);
+    let by_id = headings.iter().find(|heading| {
+        tag_of(heading)
+            .and_then(|tag| tag.attributes().id())
+            .is_some_and(|value| value.as_utf8_str() == id)
+    });
+    if by_id.is_some() {
+        return by_id;
+    }
+
+    let text = collapse_whitespace(query).to_lowercase();
+    let by_text = headings.iter().find(|heading| {
+        tag_of(heading).is_some_and(|tag| {
+            let inner = crate::text::decode_html_entities(&tag.inner_text(parser));
+            collapse_whitespace(&inner).to_lowercase() == text
+        })
+    });
+    if by_text.is_some() {
+        return by_text;
+    }
+
+    let selectors = SelectorList::parse(query)?;
+    let parent_of = |id: u32| parents.get(&id).copied();
+    headings
+        .iter()
+        .find(|heading| selectors.matches(heading.id, parser, &parent_of))
+}
+
+fn heading_level(name: &str) -> Option<u8> {
+    match name.to_ascii_lowercase().as_str() {
+        "h1" => Some(1),
+        "h2" => Some(2),
+        "h3" => Some(3),
+        "h4" => Some(4),
+        "h5" => Some(5),
+        "h6" => Some(6),
+        _ => None,
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = "<h1>Guide</h1><p>intro</p>\
+        <h2 id=\"install\">Install</h2><p>run it</p><h3>From source</h3><p>build</p>\
+        <h2 class=\"usage\">Usage &amp; tips</h2><p>call it</p>";
+
+    #[test]
+    fn test_section_by_id_runs_to_next_same_level_heading() {
+        let section = extract_section(DOC, "#install").unwrap();
+
+        assert_eq!(
+            section,
+            "<h2 id=\"install\">Install</h2><p>run it</p><h3>From source</h3><p>build</p>"
+        );
+    }
+
+    #[test]
+    fn test_section_by_text_decodes_entities() {
+        let section = extract_section(DOC, "usage  & TIPS").unwrap();
+
+        assert_eq!(section, "<h2 class=\"usage\">Usage &amp; tips</h2><p>call it</p>");
+    }
+
+    #[test]
+    fn test_section_by_selector() {
+        assert_eq!(extract_section(DOC, "h3").unwrap(), "<h3>From source</h3><p>build</p>");
+        assert!(extract_section(DOC, "h2.usage").unwrap().starts_with("<h2 class"));
+    }
+
+    #[test]
+    fn test_top_level_section_spans_the_document() {
+        assert_eq!(extract_section(DOC, "guide").unwrap(), DOC);
+    }
+
+    #[test]
+    fn test_unknown_heading() {
+        assert!(extract_section(DOC, "missing").is_none());
+        assert!(extract_section(DOC, "p").is_none());
+        assert!(extract_section(DOC, " ").is_none());
+    }
+}
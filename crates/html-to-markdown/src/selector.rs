@@ -0,0 +1,196 @@
+//! CSS selector matching for `PreprocessingOptions::exclude_selectors` and `include_selector`.
+//!
+//! `tl` parses selectors but only evaluates compound ones: its descendant (`a b`) and child
+//! (`a > b`) combinators never match, and a selector list nests inside the last compound
+//! (`a b, c` parses as `a (b, c)`). [`SelectorList`] therefore splits lists itself, lets `tl`
+//! parse each complex selector, flattens the result into compounds joined by combinators and
+//! matches those right to left. Each simple part is still evaluated by `tl`.
+
+use tl::queryselector::Selector;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+/// `compounds[i]` and `compounds[i + 1]` are joined by `combinators[i]`.
+#[derive(Debug)]
+struct ComplexSelector<'a> {
+    compounds: Vec<Vec<Selector<'a>>>,
+    combinators: Vec<Combinator>,
+}
+
+impl<'a> ComplexSelector<'a> {
+    fn parse(input: &'a str) -> Option<Self> {
+        let mut node = tl::parse_query_selector(input)?;
+        let mut compounds = vec![Vec::new()];
+        let mut combinators = Vec::new();
+
+        loop {
+            let (left, right, combinator) = match node {
+                Selector::And(left, right) => (left, right, None),
+                Selector::Descendant(left, right) => (left, right, Some(Combinator::Descendant)),
+                Selector::Parent(left, right) => (left, right, Some(Combinator::Child)),
+                Selector::Or(..) => return None,
+                simple => {
+                    compounds.last_mut()?.push(simple);
+                    break;
+                }
+            };
+            if !is_simple(&left) {
+                return None;
+            }
+            compounds.last_mut()?.push(*left);
+            if let Some(combinator) = combinator {
+                combinators.push(combinator);
+                compounds.push(Vec::new());
+            }
+            node = *right;
+        }
+
+        Some(Self { compounds, combinators })
+    }
+
+    fn matches(&self, id: u32, parser: &tl::Parser<'_>, parent_of: &dyn Fn(u32) -> Option<u32>) -> bool {
+        self.matches_from(self.compounds.len() - 1, id, parser, parent_of)
+    }
+
+    /// Whether `compounds[..=index]` matches with `compounds[index]` anchored at `id`.
+    fn matches_from(
+        &self,
+        index: usize,
+        id: u32,
+        parser: &tl::Parser<'_>,
+        parent_of: &dyn Fn(u32) -> Option<u32>,
+    ) -> bool {
+        if !compound_matches(&self.compounds[index], id, parser) {
+            return false;
+        }
+        let Some(previous) = index.checked_sub(1) else {
+            return true;
+        };
+
+        let mut ancestor = parent_of(id);
+        while let Some(candidate) = ancestor {
+            if self.matches_from(previous, candidate, parser, parent_of) {
+                return true;
+            }
+            if self.combinators[previous] == Combinator::Child {
+                return false;
+            }
+            ancestor = parent_of(candidate);
+        }
+        false
+    }
+}
+
+/// A comma-separated list of complex selectors such as `.ads, #sidebar > ul`.
+#[derive(Debug)]
+pub struct SelectorList<'a> {
+    selectors: Vec<ComplexSelector<'a>>,
+}
+
+impl<'a> SelectorList<'a> {
+    /// Parse a selector list, returning `None` if any entry is empty or unsupported.
+    pub fn parse(input: &'a str) -> Option<Self> {
+        let selectors = split_list(input)
+            .into_iter()
+            .map(|selector| ComplexSelector::parse(selector.trim()))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self { selectors })
+    }
+
+    /// Whether the element with node id `id` matches any selector in the list.
+    pub fn matches(&self, id: u32, parser: &tl::Parser<'_>, parent_of: &dyn Fn(u32) -> Option<u32>) -> bool {
+        self.selectors
+            .iter()
+            .any(|selector| selector.matches(id, parser, parent_of))
+    }
+}
+
+const fn is_simple(selector: &Selector<'_>) -> bool {
+    !matches!(
+        selector,
+        Selector::And(..) | Selector::Or(..) | Selector::Descendant(..) | Selector::Parent(..)
+    )
+}
+
+fn compound_matches(compound: &[Selector<'_>], id: u32, parser: &tl::Parser<'_>) -> bool {
+    let Some(node @ tl::Node::Tag(_)) = tl::NodeHandle::new(id).get(parser) else {
+        return false;
+    };
+    compound.iter().all(|selector| selector.matches(node))
+}
+
+/// Split a selector list on the commas that are not inside `[...]` or quotes.
+fn split_list(input: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut quote = None;
+    for (idx, ch) in input.char_indices() {
+        match (quote, ch) {
+            (Some(open), _) if ch == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '[') => depth += 1,
+            (None, ']') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                parts.push(&input[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&input[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn matching_ids(html: &str, selector: &str) -> Vec<String> {
+        let dom = tl::parse(html, tl::ParserOptions::default()).unwrap();
+        let parser = dom.parser();
+        let mut parents = vec![None; dom.nodes().len()];
+        for (id, node) in dom.nodes().iter().enumerate() {
+            if let Some(children) = node.children() {
+                for child in children.top().iter() {
+                    parents[child.get_inner() as usize] = Some(id as u32);
+                }
+            }
+        }
+        let parent_of = |id: u32| parents.get(id as usize).copied().flatten();
+
+        let list = SelectorList::parse(selector).unwrap();
+        (0..dom.nodes().len() as u32)
+            .filter(|id| list.matches(*id, parser, &parent_of))
+            .filter_map(|id| {
+                let tag = tl::NodeHandle::new(id).get(parser)?.as_tag()?;
+                Some(tag.attributes().id()?.as_utf8_str().into_owned())
+            })
+            .collect()
+    }
+
+    const HTML: &str = r#"<div id="outer" class="box"><p id="direct"><span id="nested" class="x"></span></p><section id="s"><p id="deep" class="x"></p></section></div><p id="top" class="x"></p>"#;
+
+    #[test]
+    fn test_combinators() {
+        assert_eq!(matching_ids(HTML, "div p"), ["direct", "deep"]);
+        assert_eq!(matching_ids(HTML, "div > p"), ["direct"]);
+        assert_eq!(matching_ids(HTML, "div.box > section .x"), ["deep"]);
+        assert_eq!(matching_ids(HTML, "#outer p > .x"), ["nested"]);
+        assert_eq!(matching_ids(HTML, "section p, p > span"), ["nested", "deep"]);
+    }
+
+    #[test]
+    fn test_lists_and_attributes() {
+        assert_eq!(matching_ids(HTML, ".x"), ["nested", "deep", "top"]);
+        assert_eq!(matching_ids(HTML, "[id=\"s\"], #top"), ["s", "top"]);
+        assert!(SelectorList::parse("div[").is_none());
+        assert!(SelectorList::parse(".a,").is_none());
+    }
+}
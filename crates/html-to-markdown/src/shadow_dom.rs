@@ -0,0 +1,151 @@
+//! Declarative shadow DOM handling for html5ever-parsed documents.
+//!
+//! html5ever keeps the content of every `<template>` in a separate fragment that the
+//! serializer never writes, so documents routed through it would silently lose server-rendered
+//! web components. [`inline_template_contents`] moves that content back under its template,
+//! matching what `tl` produces for the same markup.
+//!
+//! [`flatten_shadow_roots`] goes further for `PreprocessingOptions::flatten_shadow_dom`: each
+//! host with a `<template shadowrootmode="open|closed">` child has its children replaced by the
+//! shadow tree, with light DOM children distributed into the matching `<slot>`s (by their
+//! `slot` attribute, the rest into the default slot). Slots nobody is assigned to render their
+//! fallback content, and light children without a matching slot are dropped, as in a browser.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use html5ever::ParseOpts;
+use html5ever::serialize::{SerializeOpts, serialize};
+use html5ever::tendril::TendrilSink;
+use markup5ever_rcdom::{Handle, NodeData, RcDom, SerializableHandle};
+
+/// Whether `html` may contain a declarative shadow root, as a cheap pre-check.
+pub fn has_declarative_shadow_root(html: &str) -> bool {
+    html.as_bytes()
+        .windows(b"shadowroot".len())
+        .any(|window| window.eq_ignore_ascii_case(b"shadowroot"))
+}
+
+/// Parse `html`, compose its declarative shadow roots into the light DOM and serialize it back.
+pub fn flatten_shadow_roots(html: &str) -> Option<String> {
+    let dom = html5ever::parse_document(RcDom::default(), ParseOpts::default())
+        .from_utf8()
+        .read_from(&mut html.as_bytes())
+        .ok()?;
+    compose(&dom.document);
+
+    let mut buf = Vec::with_capacity(html.len());
+    let handle = SerializableHandle::from(dom.document);
+    serialize(&mut buf, &handle, SerializeOpts::default()).ok()?;
+    String::from_utf8(buf).ok()
+}
+
+/// Move the content of every `<template>` below `node` into the template's children.
+pub fn inline_template_contents(node: &Handle) {
+    move_template_contents(node);
+    for child in node.children.borrow().iter() {
+        inline_template_contents(child);
+    }
+}
+
+fn compose(node: &Handle) {
+    if let Some(shadow_root) = take_shadow_root(node) {
+        let mut assigned = assign_slots(node.children.take());
+        let composed = fill_slots(shadow_root.children.take(), &mut assigned);
+        set_children(node, composed);
+    }
+    move_template_contents(node);
+
+    let children = node.children.borrow().clone();
+    for child in &children {
+        compose(child);
+    }
+}
+
+/// Detach the first declarative shadow root template of `host` and return its content.
+fn take_shadow_root(host: &Handle) -> Option<Handle> {
+    let mut children = host.children.borrow_mut();
+    let index = children.iter().position(is_shadow_root_template)?;
+    let template = children.remove(index);
+    let NodeData::Element { template_contents, .. } = &template.data else {
+        return None;
+    };
+    template_contents.borrow_mut().take()
+}
+
+fn is_shadow_root_template(node: &Handle) -> bool {
+    element_name(node) == Some("template")
+        && ["shadowrootmode", "shadowroot"].iter().any(|name| {
+            attribute(node, name)
+                .is_some_and(|mode| mode.eq_ignore_ascii_case("open") || mode.eq_ignore_ascii_case("closed"))
+        })
+}
+
+/// Group light DOM children by the slot they are assigned to (`""` for the default slot).
+fn assign_slots(light: Vec<Handle>) -> HashMap<String, Vec<Handle>> {
+    let mut assigned: HashMap<String, Vec<Handle>> = HashMap::new();
+    for child in light {
+        let slot = match child.data {
+            NodeData::Element { .. } => attribute(&child, "slot").unwrap_or_default(),
+            _ => String::new(),
+        };
+        assigned.entry(slot).or_default().push(child);
+    }
+    assigned
+}
+
+/// Replace the `<slot>`s in `nodes` with their assigned light DOM nodes or fallback content.
+fn fill_slots(nodes: Vec<Handle>, assigned: &mut HashMap<String, Vec<Handle>>) -> Vec<Handle> {
+    let mut filled = Vec::with_capacity(nodes.len());
+    for node in nodes {
+        if element_name(&node) == Some("slot") {
+            let name = attribute(&node, "name").unwrap_or_default();
+            match assigned.remove(&name) {
+                Some(slotted) => filled.extend(slotted),
+                None => filled.extend(fill_slots(node.children.take(), assigned)),
+            }
+        } else {
+            let children = fill_slots(node.children.take(), assigned);
+            set_children(&node, children);
+            filled.push(node);
+        }
+    }
+    filled
+}
+
+fn move_template_contents(node: &Handle) {
+    let NodeData::Element { template_contents, .. } = &node.data else {
+        return;
+    };
+    let Some(contents) = template_contents.borrow_mut().take() else {
+        return;
+    };
+    let mut children = node.children.take();
+    children.extend(contents.children.take());
+    set_children(node, children);
+}
+
+fn set_children(parent: &Handle, children: Vec<Handle>) {
+    for child in &children {
+        child.parent.set(Some(Rc::downgrade(parent)));
+    }
+    *parent.children.borrow_mut() = children;
+}
+
+fn element_name(node: &Handle) -> Option<&str> {
+    match &node.data {
+        NodeData::Element { name, .. } => Some(&name.local),
+        _ => None,
+    }
+}
+
+fn attribute(node: &Handle, name: &str) -> Option<String> {
+    let NodeData::Element { attrs, .. } = &node.data else {
+        return None;
+    };
+    attrs
+        .borrow()
+        .iter()
+        .find(|attr| attr.name.local.as_ref().eq_ignore_ascii_case(name))
+        .map(|attr| attr.value.to_string())
+}
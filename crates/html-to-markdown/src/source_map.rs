@@ -0,0 +1,226 @@
+//! Offset bookkeeping for `MetadataConfig::extract_source_map`.
+//!
+//! The converter parses a rewritten copy of the input: line endings are normalized, `<script>`
+//! and `<style>` elements are cut out and a few constructs are respelled before parsing. Element
+//! positions reported by the parser are therefore offsets into that copy. [`OffsetMap`] lines the
+//! copy up with the input again, so the source map can point into the HTML the caller passed in.
+//! Markdown ranges are found afterwards by [`locate_block`], once post-processors have run.
+
+use std::ops::Range;
+
+/// Bytes compared when looking for the place where the two texts agree again.
+const RESYNC_LEN: usize = 32;
+
+/// Positions tried near a mismatch before searching the rest of the text.
+const LOCAL_WINDOW: usize = 64;
+
+/// Search steps allowed per byte of input before giving up on exact alignment.
+const SEARCH_BUDGET_PER_BYTE: usize = 32;
+
+/// Maps byte offsets in a rewritten copy of a text to offsets in the original.
+///
+/// Built from the runs the two texts share: an offset inside a shared run maps exactly, an
+/// offset inside text that only the copy has maps to where the original continues. Heavily
+/// rewritten input (markup repaired by a full re-parse) is aligned on a best-effort basis.
+#[derive(Debug)]
+pub struct OffsetMap {
+    /// Start of each shared run, as `(rewritten, original)` offsets, in increasing order.
+    anchors: Vec<(usize, usize)>,
+    original_len: usize,
+}
+
+impl OffsetMap {
+    pub(crate) fn new(rewritten: &str, original: &str) -> Self {
+        let (rewritten, original) = (rewritten.as_bytes(), original.as_bytes());
+        let mut anchors: Vec<(usize, usize)> = Vec::new();
+        let mut budget = SEARCH_BUDGET_PER_BYTE * (rewritten.len() + original.len());
+        let (mut i, mut j) = (0, 0);
+
+        while i < rewritten.len() && j < original.len() {
+            match anchors.last_mut() {
+                Some(last) if last.0 == i => *last = (i, j),
+                _ => anchors.push((i, j)),
+            }
+            let shared = rewritten[i..]
+                .iter()
+                .zip(&original[j..])
+                .take_while(|(a, b)| a == b)
+                .count();
+            i += shared;
+            j += shared;
+            if i == rewritten.len() || j == original.len() {
+                break;
+            }
+
+            // Look for text removed from the original (a stripped `<script>`) or added to the copy
+            // (a respelled comment), trying short gaps before long ones.
+            let removed_needle = &rewritten[i..rewritten.len().min(i + RESYNC_LEN)];
+            let added_needle = &original[j..original.len().min(j + RESYNC_LEN)];
+            let resync = [LOCAL_WINDOW, usize::MAX].into_iter().find_map(|window| {
+                find(&original[j..], removed_needle, window, &mut budget)
+                    .map(|removed| (0, removed))
+                    .or_else(|| find(&rewritten[i..], added_needle, window, &mut budget).map(|added| (added, 0)))
+            });
+            if let Some((added, removed)) = resync {
+                i += added;
+                j += removed;
+                continue;
+            }
+            if budget == 0 {
+                break;
+            }
+            i += 1;
+            j += 1;
+        }
+
+        Self {
+            anchors,
+            original_len: original.len(),
+        }
+    }
+
+    /// Map for `original` with its line endings normalized to `\n`, as done before conversion.
+    pub(crate) fn for_line_endings(original: &str) -> Self {
+        let mut anchors = vec![(0, 0)];
+        let mut removed = 0;
+        for (index, _) in original.match_indices("\r\n") {
+            removed += 1;
+            anchors.push((index + 1 - removed, index + 1));
+        }
+        Self {
+            anchors,
+            original_len: original.len(),
+        }
+    }
+
+    /// The original offset for `offset` in the rewritten text.
+    pub(crate) fn map(&self, offset: usize) -> usize {
+        let index = self.anchors.partition_point(|&(rewritten, _)| rewritten <= offset);
+        let Some(&(rewritten, original)) = index.checked_sub(1).and_then(|index| self.anchors.get(index)) else {
+            return offset.min(self.original_len);
+        };
+        let limit = self.anchors.get(index).map_or(self.original_len, |&(_, next)| next);
+        (original + (offset - rewritten)).min(limit)
+    }
+}
+
+/// Offset of the first occurrence of `needle` among the first `window` positions of `haystack`,
+/// spending one unit of `budget` per position tried.
+fn find(haystack: &[u8], needle: &[u8], window: usize, budget: &mut usize) -> Option<usize> {
+    if needle.len() > haystack.len() {
+        return None;
+    }
+    for start in 0..=(haystack.len() - needle.len()).min(window) {
+        if *budget == 0 {
+            return None;
+        }
+        *budget -= 1;
+        if haystack[start..].starts_with(needle) {
+            return Some(start);
+        }
+    }
+    None
+}
+
+/// Byte range of `block` in `markdown`, searching from `from`.
+///
+/// Only the first and last lines are matched, so a block whose inner lines lost trailing
+/// whitespace is still found. Indentation and container markers (`>`, list bullets and numbers)
+/// in front of the first line belong to the block.
+pub fn locate_block(markdown: &str, block: &str, from: usize) -> Option<Range<usize>> {
+    let mut lines = block.lines();
+    let first = lines.next()?.trim_end();
+    let last = lines.next_back().map(str::trim);
+
+    let start = from + markdown.get(from..)?.find(first)?;
+    let end = match last {
+        Some(last) => {
+            let after_first = start + first.len();
+            after_first + markdown[after_first..].find(last)? + last.len()
+        }
+        None => start + first.len(),
+    };
+
+    let line_start = markdown[..start].rfind('\n').map_or(0, |newline| newline + 1);
+    let start = if is_container_prefix(&markdown[line_start..start]) {
+        line_start
+    } else {
+        start
+    };
+    Some(start..end)
+}
+
+/// Whether `prefix` holds nothing but indentation, blockquote markers and list markers.
+fn is_container_prefix(mut prefix: &str) -> bool {
+    loop {
+        prefix = prefix.trim_start();
+        if prefix.is_empty() {
+            return true;
+        }
+        let digits = prefix.bytes().take_while(u8::is_ascii_digit).count();
+        let marker = match prefix.as_bytes()[0] {
+            b'>' | b'-' | b'*' | b'+' => 1,
+            _ if digits > 0 && matches!(prefix.as_bytes().get(digits), Some(b'.' | b')')) => digits + 1,
+            _ => return false,
+        };
+        let rest = &prefix[marker..];
+        if prefix.as_bytes()[0] != b'>' && !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+            return false;
+        }
+        prefix = rest;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_map_skips_removed_and_added_text() {
+        let original = "<p>a</p>\r\n<script>x()</script><p>b</p>";
+        let rewritten = "<p>a</p>\n<p>b</p>";
+        let map = OffsetMap::new(rewritten, original);
+        let second = rewritten.rfind("<p>").unwrap();
+        assert_eq!(&original[map.map(second)..map.map(rewritten.len())], "<p>b</p>");
+        assert_eq!(map.map(0), 0);
+
+        let map = OffsetMap::new("<!-- --><hr>", "<!----><hr>");
+        assert_eq!(map.map(8), 7);
+    }
+
+    #[test]
+    fn test_line_ending_map_is_exact() {
+        let original = "a\r\nb\r\n\r\nc\rd";
+        let normalized = "a\nb\n\nc\nd";
+        let map = OffsetMap::for_line_endings(original);
+        for (offset, byte) in normalized.bytes().enumerate() {
+            let mapped = original.as_bytes()[map.map(offset)];
+            assert!(mapped == byte || (byte == b'\n' && mapped == b'\r'), "{offset}");
+        }
+        assert_eq!(map.map(normalized.len()), original.len());
+    }
+
+    #[test]
+    fn test_locate_block_tolerates_trimmed_lines_and_indentation() {
+        let markdown = "intro\n\n    code line  \n    more\n\nintro\n";
+        assert_eq!(locate_block(markdown, "code line  \n    more", 0), Some(7..31));
+        assert_eq!(locate_block(markdown, "intro", 1), Some(33..38));
+        assert_eq!(locate_block(markdown, "missing", 0), None);
+    }
+
+    #[test]
+    fn test_locate_block_includes_container_markers() {
+        let markdown = "> quoted
+
+1. first
+
+- > nested
+
+see *this*
+";
+        assert_eq!(locate_block(markdown, "quoted", 0), Some(0..8));
+        assert_eq!(locate_block(markdown, "first", 0), Some(10..18));
+        assert_eq!(locate_block(markdown, "nested", 0), Some(20..30));
+        assert_eq!(locate_block(markdown, "this*", 0), Some(37..42));
+    }
+}
@@ -0,0 +1,140 @@
+//! Candidate selection from `srcset` attributes for `srcset_policy`.
+//!
+//! Responsive images often carry their only URLs in `srcset`, either on the `<img>` itself or on
+//! the `<source>` elements of an enclosing `<picture>`. Parsing follows the HTML candidate
+//! grammar closely enough for real pages: URLs may contain commas (`data:` URIs, CDN
+//! transformations), and a URL ending in a comma has no descriptor.
+
+use crate::options::SrcsetPolicy;
+
+/// One image candidate: its URL and the number of its `w` or `x` descriptor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub url: String,
+    pub size: f64,
+}
+
+/// Append the candidates of a `srcset` attribute value to `out`.
+pub fn parse_into(srcset: &str, out: &mut Vec<Candidate>) {
+    let mut rest = srcset;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+        if rest.is_empty() {
+            return;
+        }
+
+        let url_end = rest.find(|c: char| c.is_ascii_whitespace()).unwrap_or(rest.len());
+        let url = &rest[..url_end];
+        rest = &rest[url_end..];
+
+        let (url, descriptors) = if let Some(url) = url.strip_suffix(',') {
+            (url.trim_end_matches(','), "")
+        } else {
+            let end = descriptors_end(rest);
+            let descriptors = &rest[..end];
+            rest = &rest[end..];
+            (url, descriptors)
+        };
+
+        if !url.is_empty() {
+            out.push(Candidate {
+                url: url.to_string(),
+                size: descriptor_size(descriptors),
+            });
+        }
+    }
+}
+
+/// Pick the URL `policy` selects from `candidates`. Ties keep the earlier candidate.
+pub fn select(candidates: &[Candidate], policy: SrcsetPolicy) -> Option<&str> {
+    let chosen = match policy {
+        SrcsetPolicy::First => candidates.first(),
+        SrcsetPolicy::Largest => candidates
+            .iter()
+            .reduce(|best, candidate| if candidate.size > best.size { candidate } else { best }),
+        SrcsetPolicy::Smallest => candidates
+            .iter()
+            .reduce(|best, candidate| if candidate.size < best.size { candidate } else { best }),
+    };
+    chosen.map(|candidate| candidate.url.as_str())
+}
+
+/// Byte offset of the comma ending a candidate's descriptors, ignoring commas in parentheses.
+fn descriptors_end(rest: &str) -> usize {
+    let mut depth = 0usize;
+    for (index, c) in rest.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => return index,
+            _ => {}
+        }
+    }
+    rest.len()
+}
+
+fn descriptor_size(descriptors: &str) -> f64 {
+    descriptors
+        .split_ascii_whitespace()
+        .find_map(|descriptor| {
+            let number = descriptor.strip_suffix(['w', 'x'])?;
+            number
+                .parse::<f64>()
+                .ok()
+                .filter(|size| size.is_finite() && *size > 0.0)
+        })
+        .unwrap_or(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(srcset: &str) -> Vec<(String, f64)> {
+        let mut candidates = Vec::new();
+        parse_into(srcset, &mut candidates);
+        candidates.into_iter().map(|c| (c.url, c.size)).collect()
+    }
+
+    #[test]
+    fn test_parse_descriptors() {
+        assert_eq!(
+            parse("a.jpg 480w, b.jpg 1080w,c.jpg"),
+            vec![
+                ("a.jpg".to_string(), 480.0),
+                ("b.jpg".to_string(), 1080.0),
+                ("c.jpg".to_string(), 1.0)
+            ]
+        );
+        assert_eq!(
+            parse(" small.png 1x , large.png 2.5x "),
+            vec![("small.png".to_string(), 1.0), ("large.png".to_string(), 2.5)]
+        );
+    }
+
+    #[test]
+    fn test_parse_urls_with_commas() {
+        assert_eq!(
+            parse("https://cdn.test/w_200,h_100/a.jpg 200w, data:image/png;base64,AAAA 2x"),
+            vec![
+                ("https://cdn.test/w_200,h_100/a.jpg".to_string(), 200.0),
+                ("data:image/png;base64,AAAA".to_string(), 2.0)
+            ]
+        );
+        assert_eq!(
+            parse("a.jpg, b.jpg 2x"),
+            vec![("a.jpg".to_string(), 1.0), ("b.jpg".to_string(), 2.0)]
+        );
+    }
+
+    #[test]
+    fn test_select_by_policy() {
+        let mut candidates = Vec::new();
+        parse_into("m.jpg 800w, s.jpg 320w, l.jpg 1600w, l2.jpg 1600w", &mut candidates);
+
+        assert_eq!(select(&candidates, SrcsetPolicy::First), Some("m.jpg"));
+        assert_eq!(select(&candidates, SrcsetPolicy::Largest), Some("l.jpg"));
+        assert_eq!(select(&candidates, SrcsetPolicy::Smallest), Some("s.jpg"));
+        assert_eq!(select(&[], SrcsetPolicy::Largest), None);
+    }
+}
@@ -0,0 +1,90 @@
+//! Resource usage reports for single conversions.
+//!
+//! Set [`ConversionOptions::collect_stats`](crate::ConversionOptions::collect_stats) and call
+//! [`convert_with_stats`](crate::convert_with_stats) to get a [`ConversionStats`] alongside the
+//! Markdown. The report is meant for capacity planning: it shows where time goes and roughly how
+//! much memory a document of a given size needs, without attaching a profiler.
+
+use std::time::Duration;
+
+/// Resource usage of one conversion.
+///
+/// Timings are wall-clock durations of the individual phases. On `wasm32-unknown-unknown`, where
+/// the standard library has no clock, all timings are zero and only the size counters are filled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConversionStats {
+    /// Size of the HTML input in bytes.
+    pub input_bytes: usize,
+    /// Size of the Markdown output in bytes.
+    pub output_bytes: usize,
+    /// Number of nodes (elements, text and comments) in the parsed DOM; zero when the input had
+    /// no markup and skipped parsing.
+    pub nodes_parsed: usize,
+    /// Estimate of the largest amount of working memory held at once, in bytes.
+    ///
+    /// Counts the input, the preprocessed copy the parser reads, the DOM nodes and the output
+    /// buffers. Allocator overhead and small per-element allocations are not included, so treat
+    /// this as a lower bound.
+    pub peak_memory_estimate: usize,
+    /// Time spent cleaning up the HTML before parsing (script/style stripping, repairs).
+    pub preprocess_time: Duration,
+    /// Time spent parsing the HTML into a DOM.
+    pub parse_time: Duration,
+    /// Time spent walking the DOM and writing Markdown.
+    pub convert_time: Duration,
+    /// Time spent in post-processors and line wrapping.
+    pub postprocess_time: Duration,
+}
+
+impl ConversionStats {
+    /// Sum of all phase timings.
+    #[must_use]
+    pub fn total_time(&self) -> Duration {
+        self.preprocess_time + self.parse_time + self.convert_time + self.postprocess_time
+    }
+
+    pub(crate) fn note_memory(&mut self, bytes: usize) {
+        self.peak_memory_estimate = self.peak_memory_estimate.max(bytes);
+    }
+
+    /// Fold in the report for one document of a `split_documents` input: node counts and
+    /// timings add up, memory is the largest any document needed.
+    pub(crate) fn add_document(&mut self, document: &Self) {
+        self.nodes_parsed += document.nodes_parsed;
+        self.note_memory(document.peak_memory_estimate);
+        self.preprocess_time += document.preprocess_time;
+        self.parse_time += document.parse_time;
+        self.convert_time += document.convert_time;
+        self.postprocess_time += document.postprocess_time;
+    }
+}
+
+/// Measures one phase; a no-op on targets without a clock.
+pub(crate) struct Stopwatch {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    start: std::time::Instant,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    /// Time since the last call (or since `start`), restarting the measurement.
+    pub(crate) fn lap(&mut self) -> Duration {
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        {
+            let now = std::time::Instant::now();
+            let elapsed = now - self.start;
+            self.start = now;
+            elapsed
+        }
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        {
+            Duration::ZERO
+        }
+    }
+}
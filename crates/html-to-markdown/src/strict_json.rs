@@ -0,0 +1,232 @@
+//! Strict parsing of the JSON form of [`ConversionOptionsUpdate`].
+//!
+//! [`conversion_options_from_json`](crate::conversion_options_from_json) follows serde's default
+//! of skipping fields it does not know, so a misspelled option such as `headingStlye` is silently
+//! dropped. The parser here checks every field against the names the derived `Deserialize`
+//! implementations accept, checks named values such as `headingStyle` against the options JSON
+//! Schema (the enums themselves fall back to a default for names they do not know), and reports
+//! each problem it finds, instead of stopping at the first.
+
+use serde::de::{self, DeserializeOwned, Visitor};
+use serde_json::{Map, Value};
+
+use crate::error::{ConversionError, Result};
+use crate::options::{ConversionOptionsUpdate, PreprocessingOptionsUpdate, normalize_token};
+use crate::schema::conversion_options_schema;
+
+/// Field that switches strict checking off for a JSON options object when set to `false`.
+const STRICT_OPTIONS_FIELD: &str = "strictOptions";
+
+/// Nested options object, checked field by field like the top level.
+const PREPROCESSING_FIELD: &str = "preprocessing";
+
+impl ConversionOptionsUpdate {
+    /// Names of the JSON fields an options object may contain, in their camelCase spelling.
+    #[must_use]
+    pub fn field_names() -> &'static [&'static str] {
+        field_names::<Self>()
+    }
+
+    /// Parse camelCase options JSON, rejecting fields that are not options.
+    ///
+    /// Every unknown field is reported, with the closest option name when one is near (so
+    /// `headingStlye` points at `headingStyle`), along with every value of the wrong type and
+    /// every name a style option does not define; fields of `preprocessing` are checked the same
+    /// way. A top-level `"strictOptions": false` turns the
+    /// unknown-field check off, for callers that deliberately send extra fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::ConfigError`] with the JSON parse error, or with every problem
+    /// found, separated by `"; "`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use html_to_markdown_rs::ConversionOptionsUpdate;
+    ///
+    /// let err = ConversionOptionsUpdate::from_json_strict(r#"{"headingStlye": "atx"}"#).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Invalid configuration: unknown option headingStlye (did you mean headingStyle?)"
+    /// );
+    /// ```
+    pub fn from_json_strict(json: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(json).map_err(|err| config_error(err.to_string()))?;
+        Self::from_json_value_strict(value)
+    }
+
+    /// Strict counterpart of deserializing an already parsed JSON value; see
+    /// [`from_json_strict`](Self::from_json_strict).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConversionError::ConfigError`] with every problem found, separated by `"; "`.
+    pub fn from_json_value_strict(mut value: Value) -> Result<Self> {
+        let mut problems = Vec::new();
+        let mut strict = true;
+        if let Some(flag) = value
+            .as_object_mut()
+            .and_then(|fields| fields.remove(STRICT_OPTIONS_FIELD))
+        {
+            match flag {
+                Value::Bool(flag) => strict = flag,
+                other => problems.push(format!("{STRICT_OPTIONS_FIELD}: expected a boolean, got {other}")),
+            }
+        }
+
+        let schema = conversion_options_schema();
+        let properties = &schema["properties"];
+        check_fields::<Self>(&value, properties, "", strict, &mut problems);
+        if let Some(preprocessing) = value.get(PREPROCESSING_FIELD) {
+            let nested = &properties[PREPROCESSING_FIELD]["properties"];
+            check_fields::<PreprocessingOptionsUpdate>(preprocessing, nested, "preprocessing.", strict, &mut problems);
+        }
+
+        if !problems.is_empty() {
+            return Err(config_error(problems.join("; ")));
+        }
+        serde_json::from_value(value).map_err(|err| config_error(err.to_string()))
+    }
+}
+
+/// Record unknown fields of `value`, fields whose value `T` does not accept, and names missing
+/// from the `enum` of the field's schema in `properties`.
+fn check_fields<T: DeserializeOwned>(
+    value: &Value,
+    properties: &Value,
+    prefix: &str,
+    strict: bool,
+    problems: &mut Vec<String>,
+) {
+    let Some(fields) = value.as_object() else {
+        return;
+    };
+    let known = field_names::<T>();
+
+    for (key, field) in fields {
+        if !known.contains(&key.as_str()) {
+            if strict {
+                problems.push(match closest_name(key, known) {
+                    Some(name) => format!("unknown option {prefix}{key} (did you mean {prefix}{name}?)"),
+                    None => format!("unknown option {prefix}{key}"),
+                });
+            }
+            continue;
+        }
+        // `preprocessing` reports its own fields; checking it here would repeat those errors.
+        if prefix.is_empty() && key == PREPROCESSING_FIELD && field.is_object() {
+            continue;
+        }
+        let single: Map<String, Value> = std::iter::once((key.clone(), field.clone())).collect();
+        if let Err(err) = serde_json::from_value::<T>(Value::Object(single)) {
+            problems.push(format!("{prefix}{key}: {err}"));
+            continue;
+        }
+        if let (Some(allowed), Some(name)) = (properties[key.as_str()]["enum"].as_array(), field.as_str()) {
+            let token = normalize_token(name);
+            let names: Vec<&str> = allowed.iter().filter_map(Value::as_str).collect();
+            if !names.iter().any(|allowed| normalize_token(allowed) == token) {
+                problems.push(format!(
+                    "{prefix}{key}: unknown value {field}, expected one of {}",
+                    names.join(", ")
+                ));
+            }
+        }
+    }
+}
+
+/// The known name within two edits of `key`, if any, ignoring case.
+fn closest_name(key: &str, known: &[&'static str]) -> Option<&'static str> {
+    let key = key.to_ascii_lowercase();
+    known
+        .iter()
+        .map(|name| (edit_distance(&key, &name.to_ascii_lowercase()), *name))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name)
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions and swaps of
+/// adjacent characters each count as one edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Field names of a struct with a derived `Deserialize`, read from the list it hands to
+/// `deserialize_struct`.
+fn field_names<T: DeserializeOwned>() -> &'static [&'static str] {
+    struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> de::Deserializer<'de> for FieldNames<'_> {
+        type Error = de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+            Err(de::Error::custom("expected a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> std::result::Result<V::Value, Self::Error> {
+            *self.0 = fields;
+            Err(de::Error::custom("field names captured"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+            unit_struct newtype_struct seq tuple tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldNames(&mut fields));
+    fields
+}
+
+const fn config_error(message: String) -> ConversionError {
+    ConversionError::ConfigError(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance_counts_swaps_as_one_edit() {
+        assert_eq!(edit_distance("headingstlye", "headingstyle"), 1);
+        assert_eq!(edit_distance("wrap", "wrapwidth"), 5);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_field_names_cover_the_update() {
+        let names = ConversionOptionsUpdate::field_names();
+        assert!(names.contains(&"headingStyle"));
+        assert!(names.contains(&"preprocessing"));
+        assert!(field_names::<PreprocessingOptionsUpdate>().contains(&"removeNavigation"));
+    }
+}
@@ -0,0 +1,291 @@
+//! Golden-corpus snapshot testing (requires the `test-util` feature).
+//!
+//! Point a [`GoldenCorpus`] at a directory of `.html` fixtures and it converts each one and
+//! compares the result with the committed `.md` snapshot next to it (or in a separate snapshot
+//! directory). Comparison ignores line-ending style, trailing whitespace and trailing blank
+//! lines, so snapshots survive editors and `git` autocrlf settings.
+//!
+//! Set `HTML_TO_MARKDOWN_UPDATE_SNAPSHOTS=1` (or call [`GoldenCorpus::update`]) to rewrite
+//! snapshots from the current output instead of comparing.
+//!
+//! ```no_run
+//! use html_to_markdown_rs::test_util::GoldenCorpus;
+//!
+//! GoldenCorpus::new("tests/fixtures").run().unwrap().assert_ok();
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::convert;
+use crate::options::ConversionOptions;
+
+/// Environment variable that switches [`GoldenCorpus`] into update mode.
+pub const UPDATE_ENV: &str = "HTML_TO_MARKDOWN_UPDATE_SNAPSHOTS";
+
+/// A directory of HTML fixtures checked against Markdown snapshots.
+#[derive(Debug, Clone)]
+pub struct GoldenCorpus {
+    fixtures_dir: PathBuf,
+    snapshot_dir: Option<PathBuf>,
+    options: ConversionOptions,
+    update: bool,
+}
+
+impl GoldenCorpus {
+    /// Check every `.html` file under `fixtures_dir`, recursively.
+    ///
+    /// Update mode starts enabled when [`UPDATE_ENV`] is set to `1`, `true` or `yes`.
+    #[must_use]
+    pub fn new(fixtures_dir: impl Into<PathBuf>) -> Self {
+        let update = std::env::var(UPDATE_ENV)
+            .ok()
+            .is_some_and(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes"));
+        Self {
+            fixtures_dir: fixtures_dir.into(),
+            snapshot_dir: None,
+            options: ConversionOptions::default(),
+            update,
+        }
+    }
+
+    /// Keep snapshots in a separate directory that mirrors the fixture layout.
+    #[must_use]
+    pub fn snapshot_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.snapshot_dir = Some(dir.into());
+        self
+    }
+
+    /// Options used to convert every fixture.
+    #[must_use]
+    pub fn options(mut self, options: ConversionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Write the current output as the new snapshots instead of comparing.
+    #[must_use]
+    pub const fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// Snapshot path for a fixture.
+    fn snapshot_path(&self, fixture: &Path) -> PathBuf {
+        let relative = fixture.strip_prefix(&self.fixtures_dir).unwrap_or(fixture);
+        self.snapshot_dir
+            .as_deref()
+            .unwrap_or(&self.fixtures_dir)
+            .join(relative)
+            .with_extension("md")
+    }
+
+    /// Convert every fixture and compare it with (or write) its snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fixture directory cannot be read or a snapshot cannot be
+    /// written. Conversion failures and mismatches are reported in the [`GoldenReport`].
+    pub fn run(&self) -> io::Result<GoldenReport> {
+        let mut fixtures = Vec::new();
+        collect_fixtures(&self.fixtures_dir, &mut fixtures)?;
+        fixtures.sort();
+
+        let mut report = GoldenReport::default();
+        for fixture in fixtures {
+            let html = String::from_utf8_lossy(&fs::read(&fixture)?).into_owned();
+            let actual = match convert(&html, Some(self.options.clone())) {
+                Ok(markdown) => markdown,
+                Err(err) => {
+                    report.failures.push(GoldenFailure::Conversion {
+                        fixture,
+                        error: err.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let snapshot = self.snapshot_path(&fixture);
+            if self.update {
+                if let Some(parent) = snapshot.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&snapshot, normalize_markdown(&actual))?;
+                report.updated += 1;
+                continue;
+            }
+
+            match fs::read_to_string(&snapshot) {
+                Ok(expected) if normalize_markdown(&expected) == normalize_markdown(&actual) => report.passed += 1,
+                Ok(expected) => report.failures.push(GoldenFailure::Mismatch {
+                    fixture,
+                    snapshot,
+                    expected: normalize_markdown(&expected),
+                    actual: normalize_markdown(&actual),
+                }),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    report
+                        .failures
+                        .push(GoldenFailure::MissingSnapshot { fixture, snapshot });
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(report)
+    }
+}
+
+fn collect_fixtures(dir: &Path, fixtures: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_fixtures(&path, fixtures)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm"))
+        {
+            fixtures.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Normalize Markdown for snapshot comparison.
+///
+/// Converts CRLF and CR line endings to LF, strips trailing whitespace from every line and
+/// ends the text with exactly one newline (or none if it is empty).
+#[must_use]
+pub fn normalize_markdown(markdown: &str) -> String {
+    let unified = markdown.replace("\r\n", "\n").replace('\r', "\n");
+    let mut normalized = String::with_capacity(unified.len());
+    for line in unified.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    let trimmed = normalized.trim_end_matches('\n').len();
+    normalized.truncate(trimmed);
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Why a fixture did not pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoldenFailure {
+    /// The converter returned an error.
+    Conversion {
+        /// Fixture that failed to convert.
+        fixture: PathBuf,
+        /// The conversion error message.
+        error: String,
+    },
+    /// No snapshot exists yet for the fixture.
+    MissingSnapshot {
+        /// Fixture without a snapshot.
+        fixture: PathBuf,
+        /// Where the snapshot was expected.
+        snapshot: PathBuf,
+    },
+    /// The output differs from the snapshot (both normalized).
+    Mismatch {
+        /// Fixture whose output changed.
+        fixture: PathBuf,
+        /// Snapshot it was compared with.
+        snapshot: PathBuf,
+        /// Normalized snapshot contents.
+        expected: String,
+        /// Normalized conversion output.
+        actual: String,
+    },
+}
+
+impl GoldenFailure {
+    /// The fixture this failure is about.
+    #[must_use]
+    pub fn fixture(&self) -> &Path {
+        match self {
+            Self::Conversion { fixture, .. }
+            | Self::MissingSnapshot { fixture, .. }
+            | Self::Mismatch { fixture, .. } => fixture,
+        }
+    }
+}
+
+impl fmt::Display for GoldenFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Conversion { fixture, error } => {
+                write!(f, "{}: conversion failed: {error}", fixture.display())
+            }
+            Self::MissingSnapshot { fixture, snapshot } => write!(
+                f,
+                "{}: missing snapshot {} (run with {UPDATE_ENV}=1 to create it)",
+                fixture.display(),
+                snapshot.display()
+            ),
+            Self::Mismatch {
+                fixture,
+                snapshot,
+                expected,
+                actual,
+            } => {
+                writeln!(f, "{}: output differs from {}", fixture.display(), snapshot.display())?;
+                let mut expected_lines = expected.lines();
+                let mut actual_lines = actual.lines();
+                let mut line = 1;
+                loop {
+                    match (expected_lines.next(), actual_lines.next()) {
+                        (Some(e), Some(a)) if e == a => line += 1,
+                        (None, None) => return Ok(()),
+                        (e, a) => {
+                            writeln!(f, "  first difference at line {line}:")?;
+                            writeln!(f, "  - {}", e.unwrap_or("<end of snapshot>"))?;
+                            return write!(f, "  + {}", a.unwrap_or("<end of output>"));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Outcome of [`GoldenCorpus::run`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GoldenReport {
+    /// Fixtures whose output matched their snapshot.
+    pub passed: usize,
+    /// Snapshots written in update mode.
+    pub updated: usize,
+    /// Fixtures that failed, in path order.
+    pub failures: Vec<GoldenFailure>,
+}
+
+impl GoldenReport {
+    /// Whether every fixture passed (or was updated).
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Panic with a readable summary if any fixture failed.
+    ///
+    /// # Panics
+    ///
+    /// Panics when [`failures`](Self::failures) is not empty.
+    #[track_caller]
+    pub fn assert_ok(&self) {
+        if self.is_ok() {
+            return;
+        }
+        let details: Vec<String> = self.failures.iter().map(ToString::to_string).collect();
+        panic!(
+            "{} of {} golden fixture(s) failed:\n{}",
+            self.failures.len(),
+            self.failures.len() + self.passed + self.updated,
+            details.join("\n")
+        );
+    }
+}
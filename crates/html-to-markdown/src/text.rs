@@ -1,10 +1,18 @@
 #![allow(clippy::cast_precision_loss, clippy::cast_sign_loss, clippy::unused_self)]
 //! Text processing utilities for Markdown conversion.
+//!
+//! The items re-exported from the crate root ([`Escaping`], [`escape_with`], [`normalize_text`],
+//! [`normalize_whitespace`] and [`decode_html_entities`]) are part of the stable API and apply the
+//! same rules as the converter, so callers can normalize strings outside a conversion (e.g.
+//! metadata text they want to compare against the Markdown). The remaining helpers here follow
+//! the converter's internals and may change between minor releases.
 
 use regex::Regex;
 use std::borrow::Cow;
 use std::sync::LazyLock;
 
+use crate::options::{ConversionOptions, NbspMode};
+
 /// Regex for escaping miscellaneous characters
 static ESCAPE_MISC_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"([\\&<`\[\]>~#=+|\-])").unwrap());
 
@@ -123,6 +131,89 @@ pub fn escape(
     result
 }
 
+/// The Markdown characters [`escape_with`] escapes.
+///
+/// Mirrors the `escape_*` fields of [`ConversionOptions`]; build it from options with
+/// `Escaping::from(&options)` to escape exactly like a conversion does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Escaping {
+    /// Escape miscellaneous characters (`\` `&` `<` `` ` `` `[` `>` `~` `#` `=` `+` `|` `-`) and
+    /// numbered-list markers
+    pub misc: bool,
+    /// Escape asterisks (`*`)
+    pub asterisks: bool,
+    /// Escape underscores (`_`)
+    pub underscores: bool,
+    /// Escape all ASCII punctuation (for `CommonMark` spec compliance); the other flags are
+    /// then ignored
+    pub ascii: bool,
+}
+
+impl From<&ConversionOptions> for Escaping {
+    fn from(options: &ConversionOptions) -> Self {
+        Self {
+            misc: options.escape_misc,
+            asterisks: options.escape_asterisks,
+            underscores: options.escape_underscores,
+            ascii: options.escape_ascii,
+        }
+    }
+}
+
+/// Escape Markdown special characters in text as selected by `escaping`.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::{ConversionOptions, Escaping, escape_with};
+///
+/// let options = ConversionOptions {
+///     escape_asterisks: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(escape_with("*bold*", Escaping::from(&options)), r"\*bold\*");
+/// ```
+#[must_use]
+pub fn escape_with(text: &str, escaping: Escaping) -> String {
+    escape(
+        text,
+        escaping.misc,
+        escaping.asterisks,
+        escaping.underscores,
+        escaping.ascii,
+    )
+}
+
+/// Normalize text the way the converter normalizes prose before escaping it.
+///
+/// Decodes HTML entities, strips soft hyphens and word joiners when `remove_soft_hyphens` is
+/// set, collapses runs of whitespace into single spaces (keeping U+00A0 if `nbsp_in_prose` is
+/// [`NbspMode::Preserve`]) and trims the result. Newlines are kept, as in text nodes; the result
+/// is not escaped, see [`escape_with`] for that.
+///
+/// # Example
+///
+/// ```
+/// use html_to_markdown_rs::{ConversionOptions, normalize_text};
+///
+/// let text = normalize_text("  Fish &amp;\t chips ", &ConversionOptions::default());
+/// assert_eq!(text, "Fish & chips");
+/// ```
+#[must_use]
+pub fn normalize_text(text: &str, options: &ConversionOptions) -> String {
+    let mut text = decode_html_entities_cow(text);
+    if crate::converter::strips_invisible_formatting(options) {
+        if let Cow::Owned(stripped) = remove_soft_hyphens_cow(&text) {
+            text = Cow::Owned(stripped);
+        }
+    }
+    match options.nbsp_in_prose {
+        NbspMode::Space => normalize_whitespace_cow(&text).trim().to_string(),
+        NbspMode::Preserve => trim_keep_nbsp(&normalize_whitespace_keep_nbsp_cow(&text)).to_string(),
+    }
+}
+
 /// Extract boundary whitespace from text (chomp).
 ///
 /// Returns (prefix, suffix, `trimmed_text`) tuple.
@@ -132,15 +223,23 @@ pub fn escape(
 /// The trimmed text has all leading/trailing whitespace removed.
 #[must_use]
 pub fn chomp(text: &str) -> (&str, &str, &str) {
+    chomp_matching(text, char::is_whitespace)
+}
+
+/// Extract boundary whitespace like [`chomp`], treating U+00A0 as content.
+///
+/// Leading and trailing non-breaking spaces stay part of the trimmed text.
+#[must_use]
+pub fn chomp_keep_nbsp(text: &str) -> (&str, &str, &str) {
+    chomp_matching(text, is_whitespace_except_nbsp)
+}
+
+fn chomp_matching(text: &str, is_space: fn(char) -> bool) -> (&str, &str, &str) {
     if text.is_empty() {
         return ("", "", "");
     }
 
-    let prefix = if text.starts_with(|c: char| c.is_whitespace()) {
-        " "
-    } else {
-        ""
-    };
+    let prefix = if text.starts_with(is_space) { " " } else { "" };
 
     let suffix = if text.ends_with("\n\n") || text.ends_with("\r\n\r\n") {
         "\n\n"
@@ -151,14 +250,26 @@ pub fn chomp(text: &str) -> (&str, &str, &str) {
     };
 
     let trimmed = if suffix == "\n\n" {
-        text.trim_end_matches("\n\n").trim_end_matches("\r\n\r\n").trim()
+        text.trim_end_matches("\n\n")
+            .trim_end_matches("\r\n\r\n")
+            .trim_matches(is_space)
     } else {
-        text.trim()
+        text.trim_matches(is_space)
     };
 
     (prefix, suffix, trimmed)
 }
 
+/// Trim leading and trailing whitespace, keeping U+00A0 non-breaking spaces.
+#[must_use]
+pub fn trim_keep_nbsp(text: &str) -> &str {
+    text.trim_matches(is_whitespace_except_nbsp)
+}
+
+fn is_whitespace_except_nbsp(ch: char) -> bool {
+    ch != '\u{00A0}' && ch.is_whitespace()
+}
+
 /// Normalize whitespace by collapsing consecutive spaces and tabs.
 ///
 /// Multiple spaces and tabs are replaced with a single space.
@@ -174,24 +285,7 @@ pub fn chomp(text: &str) -> (&str, &str, &str) {
 /// Normalized text with collapsed spaces/tabs but preserved newlines
 #[must_use]
 pub fn normalize_whitespace(text: &str) -> String {
-    let mut result = String::with_capacity(text.len());
-    let mut prev_was_space = false;
-
-    for ch in text.chars() {
-        let is_space = ch == ' ' || ch == '\t' || is_unicode_space(ch);
-
-        if is_space {
-            if !prev_was_space {
-                result.push(' ');
-                prev_was_space = true;
-            }
-        } else {
-            result.push(ch);
-            prev_was_space = false;
-        }
-    }
-
-    result
+    normalize_whitespace_impl(text, false)
 }
 
 /// Normalize whitespace in text, returning borrowed or owned result as needed.
@@ -211,13 +305,44 @@ pub fn normalize_whitespace(text: &str) -> String {
 /// `Cow::Borrowed` if text is already normalized, or `Cow::Owned` with normalized text
 #[must_use]
 pub fn normalize_whitespace_cow(text: &str) -> Cow<'_, str> {
+    normalize_whitespace_cow_impl(text, false)
+}
+
+/// Normalize whitespace like [`normalize_whitespace_cow`], leaving U+00A0 untouched.
+///
+/// Non-breaking spaces are treated as regular characters: they are neither collapsed
+/// nor merged into neighbouring spaces.
+#[must_use]
+pub fn normalize_whitespace_keep_nbsp_cow(text: &str) -> Cow<'_, str> {
+    normalize_whitespace_cow_impl(text, true)
+}
+
+fn normalize_whitespace_impl(text: &str, keep_nbsp: bool) -> String {
+    let mut result = String::with_capacity(text.len());
     let mut prev_was_space = false;
 
     for ch in text.chars() {
-        let is_space = ch == ' ' || ch == '\t' || is_unicode_space(ch);
-        if is_space {
+        if is_collapsible_space(ch, keep_nbsp) {
+            if !prev_was_space {
+                result.push(' ');
+                prev_was_space = true;
+            }
+        } else {
+            result.push(ch);
+            prev_was_space = false;
+        }
+    }
+
+    result
+}
+
+fn normalize_whitespace_cow_impl(text: &str, keep_nbsp: bool) -> Cow<'_, str> {
+    let mut prev_was_space = false;
+
+    for ch in text.chars() {
+        if is_collapsible_space(ch, keep_nbsp) {
             if prev_was_space || ch != ' ' {
-                return Cow::Owned(normalize_whitespace(text));
+                return Cow::Owned(normalize_whitespace_impl(text, keep_nbsp));
             }
             prev_was_space = true;
         } else {
@@ -228,6 +353,36 @@ pub fn normalize_whitespace_cow(text: &str) -> Cow<'_, str> {
     Cow::Borrowed(text)
 }
 
+#[inline]
+const fn is_collapsible_space(ch: char, keep_nbsp: bool) -> bool {
+    match ch {
+        ' ' | '\t' => true,
+        '\u{00A0}' => !keep_nbsp,
+        _ => is_unicode_space(ch),
+    }
+}
+
+/// Remove invisible soft hyphens and word joiners from text.
+///
+/// Strips U+00AD (soft hyphen), U+2060 (word joiner) and U+FEFF (zero width
+/// no-break space), which render as nothing in HTML but survive into Markdown.
+///
+/// # Returns
+///
+/// `Cow::Borrowed` if none were present, or `Cow::Owned` with them removed
+#[must_use]
+pub fn remove_soft_hyphens_cow(text: &str) -> Cow<'_, str> {
+    if text.contains(is_soft_hyphen_or_joiner) {
+        Cow::Owned(text.replace(is_soft_hyphen_or_joiner, ""))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+const fn is_soft_hyphen_or_joiner(ch: char) -> bool {
+    matches!(ch, '\u{00AD}' | '\u{2060}' | '\u{FEFF}')
+}
+
 /// Decode common HTML entities.
 ///
 /// Decodes the most common HTML entities to their character equivalents:
@@ -375,6 +530,26 @@ mod tests {
         assert_eq!(chomp(""), ("", "", ""));
     }
 
+    #[test]
+    fn test_remove_soft_hyphens() {
+        assert!(matches!(remove_soft_hyphens_cow("plain"), Cow::Borrowed("plain")));
+        assert_eq!(
+            remove_soft_hyphens_cow("hyph\u{ad}en\u{2060}ated\u{feff}"),
+            "hyphenated"
+        );
+    }
+
+    #[test]
+    fn test_keep_nbsp() {
+        assert_eq!(normalize_whitespace_cow("a\u{a0}\u{a0}b"), "a b");
+        assert_eq!(
+            normalize_whitespace_keep_nbsp_cow("a\u{a0}\u{a0}b  c"),
+            "a\u{a0}\u{a0}b c"
+        );
+        assert_eq!(chomp_keep_nbsp(" \u{a0}x "), (" ", " ", "\u{a0}x"));
+        assert_eq!(trim_keep_nbsp("\n\u{a0}x\u{a0} "), "\u{a0}x\u{a0}");
+    }
+
     #[test]
     fn test_underline() {
         assert_eq!(underline("Title", '='), "Title\n=====\n\n");
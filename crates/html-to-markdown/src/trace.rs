@@ -0,0 +1,256 @@
+//! Chrome `trace_event` recording of conversions.
+//!
+//! [`start`] turns recording on for the whole process. Every conversion then records its phases
+//! (preprocess, parse, convert, postprocess) and one span per top-level element of the document
+//! body until [`stop`] hands back the collected [`Trace`]. [`Trace::write_chrome_json`] writes the
+//! JSON object format that Perfetto and `chrome://tracing` open directly, which is often easier to
+//! read than a sampled flamegraph when the question is "which part of this page is slow".
+//!
+//! While recording is off, the instrumentation costs one relaxed atomic load per span site.
+
+use std::borrow::Cow;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::error::{ConversionError, Result};
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static RECORDING: Mutex<Option<Recording>> = Mutex::new(None);
+static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+/// One completed span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// Phase name (`"parse"`) or element label (`"div#content"`).
+    pub name: String,
+    /// `"phase"` or `"element"`.
+    pub category: &'static str,
+    /// Offset from the call to [`start`].
+    pub start: Duration,
+    /// Wall-clock time between opening and closing the span.
+    pub duration: Duration,
+    /// Small per-thread number, stable for the life of the process.
+    pub thread_id: u64,
+}
+
+/// Spans collected between [`start`] and [`stop`], in completion order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    /// The recorded spans; a parent span follows the spans nested inside it.
+    pub events: Vec<TraceEvent>,
+}
+
+impl Trace {
+    /// Write the trace as a Chrome `trace_event` JSON object of complete (`"X"`) events.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `writer`.
+    pub fn write_chrome_json<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.to_chrome_json().as_bytes())?;
+        writer.flush()
+    }
+
+    /// The trace as a Chrome `trace_event` JSON string.
+    #[must_use]
+    pub fn to_chrome_json(&self) -> String {
+        let mut json = String::with_capacity(self.events.len() * 96 + 48);
+        json.push_str("{\"traceEvents\":[");
+        for (index, event) in self.events.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            let _ = write!(
+                json,
+                "{{\"name\":\"{}\",\"cat\":\"{}\",\"ph\":\"X\",\"ts\":{:.3},\"dur\":{:.3},\"pid\":1,\"tid\":{}}}",
+                escape_json(&event.name),
+                event.category,
+                event.start.as_secs_f64() * 1_000_000.0,
+                event.duration.as_secs_f64() * 1_000_000.0,
+                event.thread_id
+            );
+        }
+        json.push_str("],\"displayTimeUnit\":\"ms\"}");
+        json
+    }
+}
+
+/// Start recording conversions on every thread.
+///
+/// # Errors
+///
+/// Returns [`ConversionError::Other`] if a recording is already active, or on
+/// `wasm32-unknown-unknown`, which has no clock to time spans with.
+pub fn start() -> Result<()> {
+    if cfg!(all(target_arch = "wasm32", target_os = "unknown")) {
+        return Err(ConversionError::Other(
+            "tracing is not supported on this target".to_string(),
+        ));
+    }
+    let mut recording = lock_recording();
+    if recording.is_some() {
+        return Err(ConversionError::Other("tracing already active".to_string()));
+    }
+    *recording = Some(Recording::new());
+    drop(recording);
+    ACTIVE.store(true, Ordering::Release);
+    Ok(())
+}
+
+/// Stop recording and return the collected spans.
+///
+/// Spans still open on other threads when this is called are dropped.
+///
+/// # Errors
+///
+/// Returns [`ConversionError::Other`] if no recording is active.
+pub fn stop() -> Result<Trace> {
+    ACTIVE.store(false, Ordering::Release);
+    let recording = lock_recording().take();
+    recording
+        .map(|recording| Trace {
+            events: recording.events,
+        })
+        .ok_or_else(|| ConversionError::Other("tracing not active".to_string()))
+}
+
+/// Whether a recording is active.
+#[must_use]
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+}
+
+/// Open a span that is recorded when dropped. `name` is only evaluated while recording.
+pub(crate) fn span<'a>(category: &'static str, name: impl FnOnce() -> Cow<'a, str>) -> Option<Span> {
+    if !is_active() {
+        return None;
+    }
+    Some(Span {
+        name: name().into_owned(),
+        category,
+        start: clock::now(),
+    })
+}
+
+/// An open span; see [`span`].
+pub(crate) struct Span {
+    name: String,
+    category: &'static str,
+    start: clock::Instant,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if !is_active() {
+            return;
+        }
+        let end = clock::now();
+        let thread_id = THREAD_ID.with(|id| *id);
+        let mut recording = lock_recording();
+        if let Some(recording) = recording.as_mut() {
+            recording.events.push(TraceEvent {
+                name: std::mem::take(&mut self.name),
+                category: self.category,
+                start: clock::since(recording.origin, self.start),
+                duration: clock::since(self.start, end),
+                thread_id,
+            });
+        }
+    }
+}
+
+struct Recording {
+    origin: clock::Instant,
+    events: Vec<TraceEvent>,
+}
+
+impl Recording {
+    fn new() -> Self {
+        Self {
+            origin: clock::now(),
+            events: Vec::new(),
+        }
+    }
+}
+
+fn lock_recording() -> std::sync::MutexGuard<'static, Option<Recording>> {
+    RECORDING.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+fn escape_json(value: &str) -> Cow<'_, str> {
+    if !value.chars().any(|c| c == '"' || c == '\\' || c.is_control()) {
+        return Cow::Borrowed(value);
+    }
+    let mut escaped = String::with_capacity(value.len() + 8);
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", u32::from(c));
+            }
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+mod clock {
+    use std::time::Duration;
+    pub use std::time::Instant;
+
+    pub fn now() -> Instant {
+        Instant::now()
+    }
+
+    pub fn since(earlier: Instant, later: Instant) -> Duration {
+        later.saturating_duration_since(earlier)
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+mod clock {
+    use std::time::Duration;
+
+    #[derive(Clone, Copy)]
+    pub struct Instant;
+
+    pub const fn now() -> Instant {
+        Instant
+    }
+
+    pub const fn since(_earlier: Instant, _later: Instant) -> Duration {
+        Duration::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chrome_json_escapes_names() {
+        let trace = Trace {
+            events: vec![TraceEvent {
+                name: "div\"x\"".to_string(),
+                category: "element",
+                start: Duration::from_micros(5),
+                duration: Duration::from_micros(10),
+                thread_id: 1,
+            }],
+        };
+
+        assert_eq!(
+            trace.to_chrome_json(),
+            "{\"traceEvents\":[{\"name\":\"div\\\"x\\\"\",\"cat\":\"element\",\"ph\":\"X\",\"ts\":5.000,\"dur\":10.000,\"pid\":1,\"tid\":1}],\"displayTimeUnit\":\"ms\"}"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Output budgets: `max_output_chars` and `max_output_tokens`.
+//!
+//! Truncation runs on the finished Markdown, after post-processors and wrapping. It cuts at the
+//! last top-level block boundary (a blank line outside code) that keeps the output, together with
+//! [`TRUNCATION_MARKER`], within every budget, so a paragraph, list item, table or code block is
+//! either kept whole or dropped. Token counts come from a [`TokenEstimator`], which callers can
+//! replace with their model's tokenizer.
+//!
+//! ```
+//! use html_to_markdown_rs::{ConversionOptions, convert};
+//!
+//! let options = ConversionOptions {
+//!     max_output_chars: Some(40),
+//!     ..Default::default()
+//! };
+//! let markdown = convert("<h1>Title</h1><p>First paragraph.</p><p>Second paragraph.</p>", Some(options)).unwrap();
+//! assert_eq!(markdown, "# Title\n\n<!-- truncated -->\n");
+//! ```
+
+use std::fmt;
+use std::panic::{RefUnwindSafe, UnwindSafe};
+use std::sync::Arc;
+
+use crate::options::ConversionOptions;
+use crate::postprocess::classify_lines;
+
+/// Line appended to output cut short by `max_output_chars` or `max_output_tokens`.
+pub const TRUNCATION_MARKER: &str = "<!-- truncated -->";
+
+/// Signature of a custom token counter.
+pub type TokenEstimatorFn = dyn Fn(&str) -> usize + Send + Sync;
+
+/// Counts the tokens `max_output_tokens` is measured in.
+///
+/// The default estimate is one token per four characters, rounded up, which is close for
+/// English prose under common BPE tokenizers. Use [`TokenEstimator::new`] to count with the
+/// tokenizer of the model the output is meant for. The count must not shrink when text is added.
+#[derive(Clone, Default)]
+pub struct TokenEstimator {
+    count: Option<Arc<TokenEstimatorFn>>,
+}
+
+impl TokenEstimator {
+    /// Count tokens with `count` instead of the default estimate.
+    pub fn new<F>(count: F) -> Self
+    where
+        F: Fn(&str) -> usize + Send + Sync + 'static,
+    {
+        Self {
+            count: Some(Arc::new(count)),
+        }
+    }
+
+    /// Number of tokens in `text`.
+    #[must_use]
+    pub fn estimate(&self, text: &str) -> usize {
+        match &self.count {
+            Some(count) => count(text),
+            None => text.chars().count().div_ceil(4),
+        }
+    }
+}
+
+impl fmt::Debug for TokenEstimator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.count.is_some() {
+            "TokenEstimator(custom)"
+        } else {
+            "TokenEstimator(chars / 4)"
+        })
+    }
+}
+
+// Custom counters are only ever called with a fresh `&str`; a panic in one cannot leave shared
+// state half-updated.
+impl UnwindSafe for TokenEstimator {}
+impl RefUnwindSafe for TokenEstimator {}
+
+/// Whether `text` is within every budget set in `options`.
+fn fits(text: &str, options: &ConversionOptions) -> bool {
+    options.max_output_chars.is_none_or(|max| text.chars().count() <= max)
+        && options
+            .max_output_tokens
+            .is_none_or(|max| options.token_estimator.estimate(text) <= max)
+}
+
+/// Cut `markdown` at a block boundary so it fits the budgets in `options`, appending
+/// [`TRUNCATION_MARKER`]. Output that already fits is returned unchanged; when not even the
+/// marker fits, the output is empty.
+pub(crate) fn truncate_markdown(markdown: String, options: &ConversionOptions) -> String {
+    if (options.max_output_chars.is_none() && options.max_output_tokens.is_none()) || fits(&markdown, options) {
+        return markdown;
+    }
+
+    // Byte offsets where a top-level block ends: the start of each blank line outside code.
+    let mut boundaries = Vec::new();
+    let mut offset = 0;
+    let mut previous_blank = true;
+    for line in classify_lines(&markdown) {
+        let blank = line.text.trim().is_empty();
+        if blank && !line.code && !previous_blank {
+            boundaries.push(offset);
+        }
+        previous_blank = blank && !line.code;
+        offset += line.text.len() + 1;
+    }
+
+    let with_marker = |end: usize| {
+        let kept = markdown[..end].trim_end();
+        if kept.is_empty() {
+            format!("{TRUNCATION_MARKER}\n")
+        } else {
+            format!("{kept}\n\n{TRUNCATION_MARKER}\n")
+        }
+    };
+    // Longer prefixes never fit when a shorter one does not, so search for the last one that does.
+    let kept = boundaries.partition_point(|&end| fits(&with_marker(end), options));
+    let truncated = with_marker(kept.checked_sub(1).map_or(0, |index| boundaries[index]));
+    if fits(&truncated, options) {
+        truncated
+    } else {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget(chars: usize) -> ConversionOptions {
+        ConversionOptions {
+            max_output_chars: Some(chars),
+            ..ConversionOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_truncate_keeps_fenced_code_whole() {
+        let markdown = "Intro\n\n```\nline one\n\nline two\n```\n\nA closing paragraph that runs on\n".to_string();
+        assert_eq!(
+            truncate_markdown(markdown.clone(), &budget(40)),
+            "Intro\n\n<!-- truncated -->\n"
+        );
+        assert_eq!(
+            truncate_markdown(markdown, &budget(60)),
+            "Intro\n\n```\nline one\n\nline two\n```\n\n<!-- truncated -->\n"
+        );
+    }
+
+    #[test]
+    fn test_truncate_leaves_output_within_budget_alone() {
+        let markdown = "Short\n".to_string();
+        assert_eq!(truncate_markdown(markdown.clone(), &budget(6)), markdown);
+        assert_eq!(truncate_markdown("Too long for this\n".to_string(), &budget(5)), "");
+    }
+
+    #[test]
+    fn test_default_estimate_rounds_up() {
+        let estimator = TokenEstimator::default();
+        assert_eq!(estimator.estimate(""), 0);
+        assert_eq!(estimator.estimate("abcde"), 2);
+        assert_eq!(
+            TokenEstimator::new(|text| text.split_whitespace().count()).estimate("a b c"),
+            3
+        );
+    }
+}
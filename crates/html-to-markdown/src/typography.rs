@@ -0,0 +1,146 @@
+//! Language-aware punctuation for text the converter inserts itself.
+//!
+//! Quotation marks around `<q>`, the parentheses around `<abbr>` expansions and the dash
+//! before blockquote attributions are generated rather than copied from the source. With
+//! `localized_punctuation` enabled they follow the conventions of the element's language
+//! (its nearest `lang` attribute) instead of ASCII.
+
+/// Punctuation used for generated text in one language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Typography {
+    /// Opening and closing quotation marks for `<q>`.
+    pub quotes: (&'static str, &'static str),
+    /// Quotation marks for a `<q>` nested inside another `<q>`.
+    pub inner_quotes: (&'static str, &'static str),
+    /// Separator and opening parenthesis placed before an abbreviation's expansion.
+    pub paren_open: &'static str,
+    /// Closing parenthesis after an abbreviation's expansion.
+    pub paren_close: &'static str,
+    /// Prefix of the attribution line emitted for `<blockquote cite>`.
+    pub attribution: &'static str,
+}
+
+impl Typography {
+    /// Punctuation used when localization is disabled or the language is unknown.
+    pub const ASCII: Self = Self {
+        quotes: ("\"", "\""),
+        inner_quotes: ("\"", "\""),
+        paren_open: " (",
+        paren_close: ")",
+        attribution: "— ",
+    };
+
+    const ENGLISH: Self = Self {
+        quotes: ("\u{201C}", "\u{201D}"),
+        inner_quotes: ("\u{2018}", "\u{2019}"),
+        ..Self::ASCII
+    };
+
+    const GERMAN: Self = Self {
+        quotes: ("\u{201E}", "\u{201C}"),
+        inner_quotes: ("\u{201A}", "\u{2018}"),
+        ..Self::ASCII
+    };
+
+    const FRENCH: Self = Self {
+        quotes: ("\u{AB}\u{202F}", "\u{202F}\u{BB}"),
+        inner_quotes: ("\u{201C}", "\u{201D}"),
+        ..Self::ASCII
+    };
+
+    const GUILLEMETS: Self = Self {
+        quotes: ("\u{AB}", "\u{BB}"),
+        inner_quotes: ("\u{201C}", "\u{201D}"),
+        ..Self::ASCII
+    };
+
+    const SLAVIC_GUILLEMETS: Self = Self {
+        quotes: ("\u{AB}", "\u{BB}"),
+        inner_quotes: ("\u{201E}", "\u{201C}"),
+        ..Self::ASCII
+    };
+
+    const LOW_HIGH: Self = Self {
+        quotes: ("\u{201E}", "\u{201D}"),
+        inner_quotes: ("\u{AB}", "\u{BB}"),
+        ..Self::ASCII
+    };
+
+    const DANISH: Self = Self {
+        quotes: ("\u{BB}", "\u{AB}"),
+        inner_quotes: ("\u{203A}", "\u{2039}"),
+        ..Self::ASCII
+    };
+
+    const NORDIC: Self = Self {
+        quotes: ("\u{201D}", "\u{201D}"),
+        inner_quotes: ("\u{2019}", "\u{2019}"),
+        ..Self::ASCII
+    };
+
+    const JAPANESE: Self = Self {
+        quotes: ("\u{300C}", "\u{300D}"),
+        inner_quotes: ("\u{300E}", "\u{300F}"),
+        paren_open: "\u{FF08}",
+        paren_close: "\u{FF09}",
+        attribution: "\u{2015}\u{2015} ",
+    };
+
+    const CHINESE_SIMPLIFIED: Self = Self {
+        quotes: ("\u{201C}", "\u{201D}"),
+        inner_quotes: ("\u{2018}", "\u{2019}"),
+        paren_open: "\u{FF08}",
+        paren_close: "\u{FF09}",
+        attribution: "\u{2014}\u{2014} ",
+    };
+
+    const CHINESE_TRADITIONAL: Self = Self {
+        quotes: ("\u{300C}", "\u{300D}"),
+        inner_quotes: ("\u{300E}", "\u{300F}"),
+        ..Self::CHINESE_SIMPLIFIED
+    };
+
+    /// Look up the punctuation for a BCP 47 language tag such as `de`, `fr-CA` or `zh-Hant`.
+    ///
+    /// Unknown languages fall back to English typographic quotes.
+    pub fn for_language(lang: &str) -> Self {
+        let lang = lang.trim().to_ascii_lowercase().replace('_', "-");
+        let mut subtags = lang.split('-');
+        let primary = subtags.next().unwrap_or_default();
+
+        match primary {
+            "" => Self::ASCII,
+            "de" | "cs" | "sk" | "sl" | "lt" | "is" => Self::GERMAN,
+            "fr" => Self::FRENCH,
+            "es" | "it" | "pt" | "ca" | "el" | "ar" | "fa" | "no" | "nb" | "nn" => Self::GUILLEMETS,
+            "ru" | "uk" | "be" => Self::SLAVIC_GUILLEMETS,
+            "pl" | "hu" | "ro" | "hr" | "bg" | "he" => Self::LOW_HIGH,
+            "da" => Self::DANISH,
+            "sv" | "fi" => Self::NORDIC,
+            "ja" => Self::JAPANESE,
+            "zh" => {
+                if subtags.any(|subtag| matches!(subtag, "hant" | "tw" | "hk" | "mo")) {
+                    Self::CHINESE_TRADITIONAL
+                } else {
+                    Self::CHINESE_SIMPLIFIED
+                }
+            }
+            _ => Self::ENGLISH,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_lookup() {
+        assert_eq!(Typography::for_language("en-US").quotes, ("\u{201C}", "\u{201D}"));
+        assert_eq!(Typography::for_language("DE").quotes, ("\u{201E}", "\u{201C}"));
+        assert_eq!(Typography::for_language("zh_TW"), Typography::CHINESE_TRADITIONAL);
+        assert_eq!(Typography::for_language("zh-Hans-CN"), Typography::CHINESE_SIMPLIFIED);
+        assert_eq!(Typography::for_language("tlh"), Typography::ENGLISH);
+        assert_eq!(Typography::for_language(" "), Typography::ASCII);
+    }
+}
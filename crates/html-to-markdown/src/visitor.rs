@@ -36,6 +36,9 @@ use std::collections::BTreeMap;
 #[cfg(feature = "async-visitor")]
 use async_trait::async_trait;
 
+#[cfg(feature = "async-visitor")]
+pub use crate::visitor_async::convert_with_visitor_async;
+
 /// Node type enumeration covering all HTML element types.
 ///
 /// This enum categorizes all HTML elements that the converter recognizes,
@@ -235,7 +238,7 @@ pub enum NodeType {
 ///
 /// Provides comprehensive metadata about the current node being visited,
 /// including its type, attributes, position in the DOM tree, and parent context.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NodeContext {
     /// Coarse-grained node type classification
     pub node_type: NodeType,
@@ -264,7 +267,7 @@ pub struct NodeContext {
 /// Allows visitors to control the conversion flow by either proceeding
 /// with default behavior, providing custom output, skipping elements,
 /// preserving HTML, or signaling errors.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VisitResult {
     /// Continue with default conversion behavior
     Continue,
@@ -0,0 +1,624 @@
+//! Async visitor dispatch for HTML to Markdown conversion.
+//!
+//! The converter walks the DOM synchronously, so an [`AsyncHtmlVisitor`] cannot be awaited
+//! from inside the traversal. Instead, conversion runs in passes: each pass replays the
+//! visitor results gathered so far and buffers every event it has no answer for, treating it
+//! as `VisitResult::Continue`. The buffered events are then awaited on the visitor in document
+//! order. As long as the visitor keeps answering `Continue`, the speculative pass was correct;
+//! the first other answer changes the traversal, so the remaining buffered events are dropped
+//! and the document is converted again.
+//!
+//! A visitor that only observes (always returns `Continue`) therefore costs one conversion
+//! pass. Each non-`Continue` result adds one more pass, and every callback is awaited exactly
+//! once.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Result;
+use crate::options::ConversionOptions;
+use crate::visitor::{AsyncHtmlVisitor, HtmlVisitor, NodeContext, VisitResult};
+
+/// A visitor callback captured during a conversion pass, with owned arguments.
+#[derive(Debug, Clone, PartialEq)]
+enum BufferedEvent {
+    ElementStart {
+        ctx: NodeContext,
+    },
+    ElementEnd {
+        ctx: NodeContext,
+        output: String,
+    },
+    Text {
+        ctx: NodeContext,
+        text: String,
+    },
+    Link {
+        ctx: NodeContext,
+        href: String,
+        text: String,
+        title: Option<String>,
+    },
+    Image {
+        ctx: NodeContext,
+        src: String,
+        alt: String,
+        title: Option<String>,
+    },
+    Heading {
+        ctx: NodeContext,
+        level: u32,
+        text: String,
+        id: Option<String>,
+    },
+    CodeBlock {
+        ctx: NodeContext,
+        lang: Option<String>,
+        code: String,
+    },
+    CodeInline {
+        ctx: NodeContext,
+        code: String,
+    },
+    ListItem {
+        ctx: NodeContext,
+        ordered: bool,
+        marker: String,
+        text: String,
+    },
+    ListStart {
+        ctx: NodeContext,
+        ordered: bool,
+    },
+    ListEnd {
+        ctx: NodeContext,
+        ordered: bool,
+        output: String,
+    },
+    TableStart {
+        ctx: NodeContext,
+    },
+    TableRow {
+        ctx: NodeContext,
+        cells: Vec<String>,
+        is_header: bool,
+    },
+    TableEnd {
+        ctx: NodeContext,
+        output: String,
+    },
+    Blockquote {
+        ctx: NodeContext,
+        content: String,
+        depth: usize,
+    },
+    Strong {
+        ctx: NodeContext,
+        text: String,
+    },
+    Emphasis {
+        ctx: NodeContext,
+        text: String,
+    },
+    Strikethrough {
+        ctx: NodeContext,
+        text: String,
+    },
+    Underline {
+        ctx: NodeContext,
+        text: String,
+    },
+    Subscript {
+        ctx: NodeContext,
+        text: String,
+    },
+    Superscript {
+        ctx: NodeContext,
+        text: String,
+    },
+    Mark {
+        ctx: NodeContext,
+        text: String,
+    },
+    LineBreak {
+        ctx: NodeContext,
+    },
+    HorizontalRule {
+        ctx: NodeContext,
+    },
+    CustomElement {
+        ctx: NodeContext,
+        tag_name: String,
+        html: String,
+    },
+    DefinitionListStart {
+        ctx: NodeContext,
+    },
+    DefinitionTerm {
+        ctx: NodeContext,
+        text: String,
+    },
+    DefinitionDescription {
+        ctx: NodeContext,
+        text: String,
+    },
+    DefinitionListEnd {
+        ctx: NodeContext,
+        output: String,
+    },
+    Form {
+        ctx: NodeContext,
+        action: Option<String>,
+        method: Option<String>,
+    },
+    Input {
+        ctx: NodeContext,
+        input_type: String,
+        name: Option<String>,
+        value: Option<String>,
+    },
+    Button {
+        ctx: NodeContext,
+        text: String,
+    },
+    Audio {
+        ctx: NodeContext,
+        src: Option<String>,
+    },
+    Video {
+        ctx: NodeContext,
+        src: Option<String>,
+    },
+    Iframe {
+        ctx: NodeContext,
+        src: Option<String>,
+    },
+    Details {
+        ctx: NodeContext,
+        open: bool,
+    },
+    Summary {
+        ctx: NodeContext,
+        text: String,
+    },
+    FigureStart {
+        ctx: NodeContext,
+    },
+    Figcaption {
+        ctx: NodeContext,
+        text: String,
+    },
+    FigureEnd {
+        ctx: NodeContext,
+        output: String,
+    },
+}
+
+impl BufferedEvent {
+    /// Invoke the matching async visitor method for this event.
+    async fn dispatch<V: AsyncHtmlVisitor + ?Sized>(&self, visitor: &mut V) -> VisitResult {
+        match self {
+            Self::ElementStart { ctx } => visitor.visit_element_start(ctx).await,
+            Self::ElementEnd { ctx, output } => visitor.visit_element_end(ctx, output).await,
+            Self::Text { ctx, text } => visitor.visit_text(ctx, text).await,
+            Self::Link { ctx, href, text, title } => visitor.visit_link(ctx, href, text, title.as_deref()).await,
+            Self::Image { ctx, src, alt, title } => visitor.visit_image(ctx, src, alt, title.as_deref()).await,
+            Self::Heading { ctx, level, text, id } => visitor.visit_heading(ctx, *level, text, id.as_deref()).await,
+            Self::CodeBlock { ctx, lang, code } => visitor.visit_code_block(ctx, lang.as_deref(), code).await,
+            Self::CodeInline { ctx, code } => visitor.visit_code_inline(ctx, code).await,
+            Self::ListItem {
+                ctx,
+                ordered,
+                marker,
+                text,
+            } => visitor.visit_list_item(ctx, *ordered, marker, text).await,
+            Self::ListStart { ctx, ordered } => visitor.visit_list_start(ctx, *ordered).await,
+            Self::ListEnd { ctx, ordered, output } => visitor.visit_list_end(ctx, *ordered, output).await,
+            Self::TableStart { ctx } => visitor.visit_table_start(ctx).await,
+            Self::TableRow { ctx, cells, is_header } => visitor.visit_table_row(ctx, cells, *is_header).await,
+            Self::TableEnd { ctx, output } => visitor.visit_table_end(ctx, output).await,
+            Self::Blockquote { ctx, content, depth } => visitor.visit_blockquote(ctx, content, *depth).await,
+            Self::Strong { ctx, text } => visitor.visit_strong(ctx, text).await,
+            Self::Emphasis { ctx, text } => visitor.visit_emphasis(ctx, text).await,
+            Self::Strikethrough { ctx, text } => visitor.visit_strikethrough(ctx, text).await,
+            Self::Underline { ctx, text } => visitor.visit_underline(ctx, text).await,
+            Self::Subscript { ctx, text } => visitor.visit_subscript(ctx, text).await,
+            Self::Superscript { ctx, text } => visitor.visit_superscript(ctx, text).await,
+            Self::Mark { ctx, text } => visitor.visit_mark(ctx, text).await,
+            Self::LineBreak { ctx } => visitor.visit_line_break(ctx).await,
+            Self::HorizontalRule { ctx } => visitor.visit_horizontal_rule(ctx).await,
+            Self::CustomElement { ctx, tag_name, html } => visitor.visit_custom_element(ctx, tag_name, html).await,
+            Self::DefinitionListStart { ctx } => visitor.visit_definition_list_start(ctx).await,
+            Self::DefinitionTerm { ctx, text } => visitor.visit_definition_term(ctx, text).await,
+            Self::DefinitionDescription { ctx, text } => visitor.visit_definition_description(ctx, text).await,
+            Self::DefinitionListEnd { ctx, output } => visitor.visit_definition_list_end(ctx, output).await,
+            Self::Form { ctx, action, method } => visitor.visit_form(ctx, action.as_deref(), method.as_deref()).await,
+            Self::Input {
+                ctx,
+                input_type,
+                name,
+                value,
+            } => {
+                visitor
+                    .visit_input(ctx, input_type, name.as_deref(), value.as_deref())
+                    .await
+            }
+            Self::Button { ctx, text } => visitor.visit_button(ctx, text).await,
+            Self::Audio { ctx, src } => visitor.visit_audio(ctx, src.as_deref()).await,
+            Self::Video { ctx, src } => visitor.visit_video(ctx, src.as_deref()).await,
+            Self::Iframe { ctx, src } => visitor.visit_iframe(ctx, src.as_deref()).await,
+            Self::Details { ctx, open } => visitor.visit_details(ctx, *open).await,
+            Self::Summary { ctx, text } => visitor.visit_summary(ctx, text).await,
+            Self::FigureStart { ctx } => visitor.visit_figure_start(ctx).await,
+            Self::Figcaption { ctx, text } => visitor.visit_figcaption(ctx, text).await,
+            Self::FigureEnd { ctx, output } => visitor.visit_figure_end(ctx, output).await,
+        }
+    }
+}
+
+/// Synchronous visitor that replays known answers and buffers unanswered events.
+#[derive(Debug, Default)]
+struct ReplayVisitor {
+    answered: Vec<(BufferedEvent, VisitResult)>,
+    cursor: usize,
+    pending: Vec<BufferedEvent>,
+}
+
+impl ReplayVisitor {
+    fn replay(&mut self, event: BufferedEvent) -> VisitResult {
+        if self.pending.is_empty() {
+            if let Some((answered, result)) = self.answered.get(self.cursor) {
+                if *answered == event {
+                    self.cursor += 1;
+                    return result.clone();
+                }
+            }
+        }
+        self.pending.push(event);
+        VisitResult::Continue
+    }
+}
+
+impl HtmlVisitor for ReplayVisitor {
+    fn visit_element_start(&mut self, ctx: &NodeContext) -> VisitResult {
+        self.replay(BufferedEvent::ElementStart { ctx: ctx.clone() })
+    }
+
+    fn visit_element_end(&mut self, ctx: &NodeContext, output: &str) -> VisitResult {
+        self.replay(BufferedEvent::ElementEnd {
+            ctx: ctx.clone(),
+            output: output.to_string(),
+        })
+    }
+
+    fn visit_text(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Text {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_link(&mut self, ctx: &NodeContext, href: &str, text: &str, title: Option<&str>) -> VisitResult {
+        self.replay(BufferedEvent::Link {
+            ctx: ctx.clone(),
+            href: href.to_string(),
+            text: text.to_string(),
+            title: title.map(str::to_string),
+        })
+    }
+
+    fn visit_image(&mut self, ctx: &NodeContext, src: &str, alt: &str, title: Option<&str>) -> VisitResult {
+        self.replay(BufferedEvent::Image {
+            ctx: ctx.clone(),
+            src: src.to_string(),
+            alt: alt.to_string(),
+            title: title.map(str::to_string),
+        })
+    }
+
+    fn visit_heading(&mut self, ctx: &NodeContext, level: u32, text: &str, id: Option<&str>) -> VisitResult {
+        self.replay(BufferedEvent::Heading {
+            ctx: ctx.clone(),
+            level,
+            text: text.to_string(),
+            id: id.map(str::to_string),
+        })
+    }
+
+    fn visit_code_block(&mut self, ctx: &NodeContext, lang: Option<&str>, code: &str) -> VisitResult {
+        self.replay(BufferedEvent::CodeBlock {
+            ctx: ctx.clone(),
+            lang: lang.map(str::to_string),
+            code: code.to_string(),
+        })
+    }
+
+    fn visit_code_inline(&mut self, ctx: &NodeContext, code: &str) -> VisitResult {
+        self.replay(BufferedEvent::CodeInline {
+            ctx: ctx.clone(),
+            code: code.to_string(),
+        })
+    }
+
+    fn visit_list_item(&mut self, ctx: &NodeContext, ordered: bool, marker: &str, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::ListItem {
+            ctx: ctx.clone(),
+            ordered,
+            marker: marker.to_string(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_list_start(&mut self, ctx: &NodeContext, ordered: bool) -> VisitResult {
+        self.replay(BufferedEvent::ListStart {
+            ctx: ctx.clone(),
+            ordered,
+        })
+    }
+
+    fn visit_list_end(&mut self, ctx: &NodeContext, ordered: bool, output: &str) -> VisitResult {
+        self.replay(BufferedEvent::ListEnd {
+            ctx: ctx.clone(),
+            ordered,
+            output: output.to_string(),
+        })
+    }
+
+    fn visit_table_start(&mut self, ctx: &NodeContext) -> VisitResult {
+        self.replay(BufferedEvent::TableStart { ctx: ctx.clone() })
+    }
+
+    fn visit_table_row(&mut self, ctx: &NodeContext, cells: &[String], is_header: bool) -> VisitResult {
+        self.replay(BufferedEvent::TableRow {
+            ctx: ctx.clone(),
+            cells: cells.to_vec(),
+            is_header,
+        })
+    }
+
+    fn visit_table_end(&mut self, ctx: &NodeContext, output: &str) -> VisitResult {
+        self.replay(BufferedEvent::TableEnd {
+            ctx: ctx.clone(),
+            output: output.to_string(),
+        })
+    }
+
+    fn visit_blockquote(&mut self, ctx: &NodeContext, content: &str, depth: usize) -> VisitResult {
+        self.replay(BufferedEvent::Blockquote {
+            ctx: ctx.clone(),
+            content: content.to_string(),
+            depth,
+        })
+    }
+
+    fn visit_strong(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Strong {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_emphasis(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Emphasis {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_strikethrough(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Strikethrough {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_underline(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Underline {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_subscript(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Subscript {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_superscript(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Superscript {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_mark(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Mark {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_line_break(&mut self, ctx: &NodeContext) -> VisitResult {
+        self.replay(BufferedEvent::LineBreak { ctx: ctx.clone() })
+    }
+
+    fn visit_horizontal_rule(&mut self, ctx: &NodeContext) -> VisitResult {
+        self.replay(BufferedEvent::HorizontalRule { ctx: ctx.clone() })
+    }
+
+    fn visit_custom_element(&mut self, ctx: &NodeContext, tag_name: &str, html: &str) -> VisitResult {
+        self.replay(BufferedEvent::CustomElement {
+            ctx: ctx.clone(),
+            tag_name: tag_name.to_string(),
+            html: html.to_string(),
+        })
+    }
+
+    fn visit_definition_list_start(&mut self, ctx: &NodeContext) -> VisitResult {
+        self.replay(BufferedEvent::DefinitionListStart { ctx: ctx.clone() })
+    }
+
+    fn visit_definition_term(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::DefinitionTerm {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_definition_description(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::DefinitionDescription {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_definition_list_end(&mut self, ctx: &NodeContext, output: &str) -> VisitResult {
+        self.replay(BufferedEvent::DefinitionListEnd {
+            ctx: ctx.clone(),
+            output: output.to_string(),
+        })
+    }
+
+    fn visit_form(&mut self, ctx: &NodeContext, action: Option<&str>, method: Option<&str>) -> VisitResult {
+        self.replay(BufferedEvent::Form {
+            ctx: ctx.clone(),
+            action: action.map(str::to_string),
+            method: method.map(str::to_string),
+        })
+    }
+
+    fn visit_input(
+        &mut self,
+        ctx: &NodeContext,
+        input_type: &str,
+        name: Option<&str>,
+        value: Option<&str>,
+    ) -> VisitResult {
+        self.replay(BufferedEvent::Input {
+            ctx: ctx.clone(),
+            input_type: input_type.to_string(),
+            name: name.map(str::to_string),
+            value: value.map(str::to_string),
+        })
+    }
+
+    fn visit_button(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Button {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_audio(&mut self, ctx: &NodeContext, src: Option<&str>) -> VisitResult {
+        self.replay(BufferedEvent::Audio {
+            ctx: ctx.clone(),
+            src: src.map(str::to_string),
+        })
+    }
+
+    fn visit_video(&mut self, ctx: &NodeContext, src: Option<&str>) -> VisitResult {
+        self.replay(BufferedEvent::Video {
+            ctx: ctx.clone(),
+            src: src.map(str::to_string),
+        })
+    }
+
+    fn visit_iframe(&mut self, ctx: &NodeContext, src: Option<&str>) -> VisitResult {
+        self.replay(BufferedEvent::Iframe {
+            ctx: ctx.clone(),
+            src: src.map(str::to_string),
+        })
+    }
+
+    fn visit_details(&mut self, ctx: &NodeContext, open: bool) -> VisitResult {
+        self.replay(BufferedEvent::Details { ctx: ctx.clone(), open })
+    }
+
+    fn visit_summary(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Summary {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_figure_start(&mut self, ctx: &NodeContext) -> VisitResult {
+        self.replay(BufferedEvent::FigureStart { ctx: ctx.clone() })
+    }
+
+    fn visit_figcaption(&mut self, ctx: &NodeContext, text: &str) -> VisitResult {
+        self.replay(BufferedEvent::Figcaption {
+            ctx: ctx.clone(),
+            text: text.to_string(),
+        })
+    }
+
+    fn visit_figure_end(&mut self, ctx: &NodeContext, output: &str) -> VisitResult {
+        self.replay(BufferedEvent::FigureEnd {
+            ctx: ctx.clone(),
+            output: output.to_string(),
+        })
+    }
+}
+
+/// Run one synchronous conversion pass, returning its output and the unanswered events.
+fn convert_pass(
+    html: &str,
+    options: &ConversionOptions,
+    answered: Vec<(BufferedEvent, VisitResult)>,
+) -> (Result<String>, Vec<(BufferedEvent, VisitResult)>, Vec<BufferedEvent>) {
+    let replay = Rc::new(RefCell::new(ReplayVisitor {
+        answered,
+        ..ReplayVisitor::default()
+    }));
+    let result = crate::convert_with_visitor(html, Some(options.clone()), Some(replay.clone()));
+    let mut replay = replay.borrow_mut();
+    let cursor = replay.cursor;
+    replay.answered.truncate(cursor);
+    let answered = std::mem::take(&mut replay.answered);
+    let pending = std::mem::take(&mut replay.pending);
+    (result, answered, pending)
+}
+
+/// Convert HTML to Markdown, awaiting an [`AsyncHtmlVisitor`] for every visitor callback.
+///
+/// Visitor results have the same meaning as in [`crate::convert_with_visitor`]. Callbacks are
+/// awaited one at a time in document order, so the visitor never observes concurrent calls.
+///
+/// Observing visitors run in a single conversion pass; every result other than
+/// `VisitResult::Continue` costs one additional pass over the document (see the module docs).
+///
+/// # Errors
+///
+/// Returns an error if HTML parsing fails, the input is invalid, or a visitor callback
+/// returns `VisitResult::Error`.
+pub async fn convert_with_visitor_async<V: AsyncHtmlVisitor + ?Sized>(
+    html: &str,
+    options: Option<ConversionOptions>,
+    visitor: &mut V,
+) -> Result<String> {
+    let options = options.unwrap_or_default();
+    let mut answered = Vec::new();
+
+    loop {
+        let (result, replayed, pending) = convert_pass(html, &options, answered);
+        answered = replayed;
+        if pending.is_empty() || result.is_err() {
+            return result;
+        }
+
+        let mut diverged = false;
+        for event in pending {
+            let answer = event.dispatch(visitor).await;
+            diverged = !matches!(answer, VisitResult::Continue);
+            answered.push((event, answer));
+            if diverged {
+                break;
+            }
+        }
+        if !diverged {
+            return result;
+        }
+    }
+}
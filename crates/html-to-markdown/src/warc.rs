@@ -0,0 +1,366 @@
+//! WARC archive input (requires the `warc` feature).
+//!
+//! [`WarcReader`] walks the records of a WARC 1.0/1.1 file and yields the HTML documents it
+//! holds: successful (2xx) `response` records whose HTTP `Content-Type` is HTML, and `resource`
+//! records stored directly as HTML. Other records — requests, metadata, images, redirects — are
+//! skipped without being buffered. Gzip-compressed archives (`.warc.gz`, one gzip member per
+//! record) are detected and decompressed transparently, as are chunked and gzip/deflate encoded
+//! HTTP payloads. Bodies are decoded using the HTTP charset, then a `<meta charset>`, then UTF-8.
+//!
+//! [`convert_warc`] converts each document in turn, attaching the target URI and capture date:
+//!
+//! ```
+//! use html_to_markdown_rs::warc::convert_warc;
+//!
+//! let html = "<h1>Archived</h1>";
+//! let http = format!("HTTP/1.1 200 OK\r\nContent-Type: text/html\r\n\r\n{html}");
+//! let archive = format!(
+//!     "WARC/1.1\r\nWARC-Type: response\r\nWARC-Target-URI: https://example.com/\r\n\
+//!      WARC-Date: 2024-05-01T12:00:00Z\r\nContent-Type: application/http; msgtype=response\r\n\
+//!      Content-Length: {}\r\n\r\n{http}\r\n\r\n",
+//!     http.len()
+//! );
+//!
+//! let documents: Vec<_> = convert_warc(archive.as_bytes(), None).collect::<Result<_, _>>().unwrap();
+//! assert_eq!(documents[0].target_uri, "https://example.com/");
+//! assert_eq!(documents[0].markdown, "# Archived\n");
+//! ```
+
+use std::io::{self, BufRead, BufReader, Read};
+
+use flate2::read::{MultiGzDecoder, ZlibDecoder};
+
+use crate::encoding::decode_html;
+use crate::error::{ConversionError, Result};
+use crate::options::ConversionOptions;
+
+const GZIP_MAGIC: &[u8] = b"\x1F\x8B";
+
+/// Longest WARC or HTTP header line accepted before the record is considered malformed.
+const MAX_HEADER_LINE: usize = 64 * 1024;
+
+/// An HTML document extracted from a WARC record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarcRecord {
+    /// `WARC-Target-URI` of the record
+    pub target_uri: String,
+    /// `WARC-Date` of the capture, as written in the archive (ISO 8601)
+    pub date: Option<String>,
+    /// `WARC-Record-ID`, including the angle brackets
+    pub record_id: Option<String>,
+    /// Decoded HTML payload
+    pub html: String,
+}
+
+/// Markdown converted from one WARC record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarcDocument {
+    /// `WARC-Target-URI` of the record
+    pub target_uri: String,
+    /// `WARC-Date` of the capture, as written in the archive (ISO 8601)
+    pub date: Option<String>,
+    /// `WARC-Record-ID`, including the angle brackets
+    pub record_id: Option<String>,
+    /// Converted Markdown
+    pub markdown: String,
+}
+
+enum Source<R: Read> {
+    Plain(BufReader<R>),
+    Gzip(Box<BufReader<MultiGzDecoder<BufReader<R>>>>),
+}
+
+impl<R: Read> Source<R> {
+    fn as_buf_read(&mut self) -> &mut dyn BufRead {
+        match self {
+            Self::Plain(reader) => reader,
+            Self::Gzip(reader) => reader.as_mut(),
+        }
+    }
+}
+
+/// Outcome of reading one record.
+enum Step {
+    Record(WarcRecord),
+    /// Not an HTML document; already consumed.
+    Skipped,
+    /// An HTML response whose payload could not be decoded; the next record is still readable.
+    Unreadable(ConversionError),
+    End,
+}
+
+/// Iterator over the HTML documents in a WARC archive.
+///
+/// A record whose HTTP payload cannot be decoded yields an error and iteration continues with
+/// the next record. Malformed WARC framing or an I/O error also yields an error, after which
+/// the iterator ends, since the position of the next record is unknown.
+pub struct WarcReader<R: Read> {
+    source: Option<Source<R>>,
+}
+
+impl<R: Read> WarcReader<R> {
+    /// Read an archive, decompressing it first if it starts with a gzip header.
+    pub fn new(reader: R) -> Self {
+        let mut buffered = BufReader::new(reader);
+        let is_gzip = buffered.fill_buf().is_ok_and(|head| head.starts_with(GZIP_MAGIC));
+        let source = if is_gzip {
+            Source::Gzip(Box::new(BufReader::new(MultiGzDecoder::new(buffered))))
+        } else {
+            Source::Plain(buffered)
+        };
+        Self { source: Some(source) }
+    }
+
+    fn next_record(source: &mut dyn BufRead) -> Result<Step> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if read_line(source, &mut line)? == 0 {
+                return Ok(Step::End);
+            }
+            if line.starts_with("WARC/") {
+                break;
+            }
+            if !line.is_empty() {
+                return Err(malformed(format!("expected a WARC version line, found {line:?}")));
+            }
+        }
+
+        let headers = read_headers(source)?;
+        let length: u64 = header(&headers, "Content-Length")
+            .ok_or_else(|| malformed("record without Content-Length".to_string()))?
+            .parse()
+            .map_err(|_| malformed("invalid Content-Length".to_string()))?;
+
+        let record_type = header(&headers, "WARC-Type").unwrap_or_default();
+        let content_type = header(&headers, "Content-Type").unwrap_or_default();
+        let wanted = match record_type.to_ascii_lowercase().as_str() {
+            "response" => content_type.to_ascii_lowercase().starts_with("application/http"),
+            "resource" => is_html_type(content_type),
+            _ => false,
+        };
+        if !wanted {
+            let skipped = io::copy(&mut source.take(length), &mut io::sink())?;
+            if skipped != length {
+                return Err(malformed("record truncated".to_string()));
+            }
+            return Ok(Step::Skipped);
+        }
+
+        let mut block = Vec::new();
+        source.take(length).read_to_end(&mut block)?;
+        if block.len() as u64 != length {
+            return Err(malformed("record truncated".to_string()));
+        }
+
+        let target_uri = header(&headers, "WARC-Target-URI").unwrap_or_default().to_string();
+        let html = if record_type.eq_ignore_ascii_case("response") {
+            match http_html(&block) {
+                Ok(Some(html)) => html,
+                Ok(None) => return Ok(Step::Skipped),
+                Err(message) => {
+                    return Ok(Step::Unreadable(ConversionError::InvalidInput(format!(
+                        "unreadable WARC payload for {target_uri}: {message}"
+                    ))));
+                }
+            }
+        } else {
+            decode_html(&block, charset(content_type))
+        };
+
+        Ok(Step::Record(WarcRecord {
+            target_uri,
+            date: header(&headers, "WARC-Date").map(str::to_string),
+            record_id: header(&headers, "WARC-Record-ID").map(str::to_string),
+            html,
+        }))
+    }
+}
+
+impl<R: Read> Iterator for WarcReader<R> {
+    type Item = Result<WarcRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let source = self.source.as_mut()?.as_buf_read();
+            match Self::next_record(source) {
+                Ok(Step::Record(record)) => return Some(Ok(record)),
+                Ok(Step::Skipped) => {}
+                Ok(Step::Unreadable(err)) => return Some(Err(err)),
+                Ok(Step::End) => {
+                    self.source = None;
+                    return None;
+                }
+                Err(err) => {
+                    self.source = None;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Iterator converting each HTML document in a WARC archive; see [`convert_warc`].
+pub struct WarcDocuments<R: Read> {
+    records: WarcReader<R>,
+    options: ConversionOptions,
+}
+
+impl<R: Read> Iterator for WarcDocuments<R> {
+    type Item = Result<WarcDocument>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.records.next()? {
+            Ok(record) => record,
+            Err(err) => return Some(Err(err)),
+        };
+        Some(
+            crate::convert(&record.html, Some(self.options.clone())).map(|markdown| WarcDocument {
+                target_uri: record.target_uri,
+                date: record.date,
+                record_id: record.record_id,
+                markdown,
+            }),
+        )
+    }
+}
+
+/// Convert every HTML document in a WARC archive, one record at a time.
+///
+/// Records are read lazily, so arbitrarily large archives use memory proportional to the
+/// largest document. A document that fails to convert yields an error and iteration continues.
+pub fn convert_warc<R: Read>(reader: R, options: Option<ConversionOptions>) -> WarcDocuments<R> {
+    WarcDocuments {
+        records: WarcReader::new(reader),
+        options: options.unwrap_or_default(),
+    }
+}
+
+fn malformed(message: String) -> ConversionError {
+    ConversionError::InvalidInput(format!("malformed WARC record: {message}"))
+}
+
+/// Read one line without its terminator, bounded by [`MAX_HEADER_LINE`].
+fn read_line(source: &mut dyn BufRead, line: &mut String) -> Result<usize> {
+    let mut bytes = Vec::new();
+    let read = source.take(MAX_HEADER_LINE as u64 + 1).read_until(b'\n', &mut bytes)?;
+    if bytes.len() > MAX_HEADER_LINE {
+        return Err(malformed("header line too long".to_string()));
+    }
+    while bytes.last().is_some_and(|&byte| byte == b'\n' || byte == b'\r') {
+        bytes.pop();
+    }
+    line.push_str(&String::from_utf8_lossy(&bytes));
+    Ok(read)
+}
+
+fn read_headers(source: &mut dyn BufRead) -> Result<Vec<(String, String)>> {
+    let mut headers = Vec::new();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if read_line(source, &mut line)? == 0 {
+            return Err(malformed("unexpected end of headers".to_string()));
+        }
+        if line.is_empty() {
+            return Ok(headers);
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+fn is_html_type(content_type: &str) -> bool {
+    let mime = content_type.split(';').next().unwrap_or_default().trim();
+    mime.eq_ignore_ascii_case("text/html") || mime.eq_ignore_ascii_case("application/xhtml+xml")
+}
+
+fn charset(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"'))
+    })
+}
+
+/// Extract the HTML body of an HTTP response block, or `None` if it is not a 2xx HTML response.
+fn http_html(block: &[u8]) -> std::result::Result<Option<String>, String> {
+    let (head, body) = match find(block, b"\r\n\r\n") {
+        Some(end) => (&block[..end], &block[end + 4..]),
+        None => match find(block, b"\n\n") {
+            Some(end) => (&block[..end], &block[end + 2..]),
+            None => return Err("HTTP headers not terminated".to_string()),
+        },
+    };
+    let head = String::from_utf8_lossy(head);
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| "invalid HTTP status line".to_string())?;
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+
+    let content_type = header(&headers, "Content-Type").unwrap_or_default();
+    if !(200..300).contains(&status) || !is_html_type(content_type) {
+        return Ok(None);
+    }
+
+    let mut body = body.to_vec();
+    if header(&headers, "Transfer-Encoding").is_some_and(|value| value.to_ascii_lowercase().contains("chunked")) {
+        body = dechunk(&body)?;
+    }
+    if let Some(encoding) = header(&headers, "Content-Encoding") {
+        body = content_decode(body, encoding)?;
+    }
+    Ok(Some(decode_html(&body, charset(content_type))))
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+fn dechunk(mut body: &[u8]) -> std::result::Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(body.len());
+    loop {
+        let line_end = find(body, b"\n").ok_or_else(|| "truncated chunk header".to_string())?;
+        let size_text = String::from_utf8_lossy(&body[..line_end]);
+        let size_text = size_text.trim().split(';').next().unwrap_or_default();
+        let size = usize::from_str_radix(size_text, 16).map_err(|_| format!("invalid chunk size {size_text:?}"))?;
+        body = &body[line_end + 1..];
+        if size == 0 {
+            return Ok(out);
+        }
+        let chunk = body.get(..size).ok_or_else(|| "truncated chunk".to_string())?;
+        out.extend_from_slice(chunk);
+        body = &body[size..];
+        body = body
+            .strip_prefix(b"\r\n")
+            .or_else(|| body.strip_prefix(b"\n"))
+            .unwrap_or(body);
+    }
+}
+
+fn content_decode(body: Vec<u8>, encoding: &str) -> std::result::Result<Vec<u8>, String> {
+    let mut out = Vec::new();
+    match encoding.trim().to_ascii_lowercase().as_str() {
+        "" | "identity" => return Ok(body),
+        "gzip" | "x-gzip" => MultiGzDecoder::new(body.as_slice()).read_to_end(&mut out),
+        "deflate" => ZlibDecoder::new(body.as_slice()).read_to_end(&mut out),
+        other => return Err(format!("unsupported Content-Encoding '{other}'")),
+    }
+    .map_err(|err| format!("cannot decode {encoding} body: {err}"))?;
+    Ok(out)
+}
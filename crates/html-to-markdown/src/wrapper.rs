@@ -177,9 +177,14 @@ pub fn wrap_markdown(markdown: &str, options: &ConversionOptions) -> String {
 
     if in_paragraph && !paragraph_buffer.is_empty() {
         result.push_str(&wrap_line(&paragraph_buffer, options.wrap_width));
-        result.push_str("\n\n");
+        result.push('\n');
     }
 
+    // End the way the input did, without the separator left after a final paragraph.
+    result.truncate(result.trim_end_matches('\n').len());
+    if markdown.ends_with('\n') {
+        result.push('\n');
+    }
     result
 }
 
@@ -480,6 +485,21 @@ mod tests {
         assert!(result.lines().all(|line| line.len() <= 40 || line.trim().is_empty()));
     }
 
+    #[test]
+    fn test_wrap_markdown_ends_with_single_newline() {
+        let options = ConversionOptions {
+            wrap: true,
+            wrap_width: 20,
+            ..Default::default()
+        };
+        let markdown = "# Title\n\nword word word word word word\n";
+        assert_eq!(
+            wrap_markdown(markdown, &options),
+            "# Title\n\nword word word word\nword word\n"
+        );
+        assert_eq!(wrap_markdown("word word", &options), "word word");
+    }
+
     #[test]
     fn test_wrap_markdown_blockquote_paragraph() {
         let markdown = "> This is a very long blockquote line that should wrap at 30 characters\n";
@@ -0,0 +1,129 @@
+//! Tests for collecting author and organization profile URLs into document metadata.
+
+use html_to_markdown_rs::metadata::{AuthorProfile, MetadataConfig, ProfileSource};
+
+fn profiles(html: &str, config: MetadataConfig) -> Vec<AuthorProfile> {
+    let (_markdown, metadata) =
+        html_to_markdown_rs::convert_with_metadata(html, None, config, None).expect("convert_with_metadata failed");
+    metadata.document.author_profiles
+}
+
+fn profile(url: &str, name: Option<&str>, source: ProfileSource) -> AuthorProfile {
+    AuthorProfile {
+        url: url.to_string(),
+        name: name.map(str::to_string),
+        source,
+    }
+}
+
+#[test]
+fn test_rel_author_and_me_links() {
+    let html = r#"
+        <html>
+          <head>
+            <title>Post</title>
+            <link rel="author" href="https://example.com/about/jane">
+            <link rel="me" href="https://mastodon.social/@jane" title="Mastodon">
+            <link rel="stylesheet" href="/site.css">
+          </head>
+          <body>
+            <p>By <a rel="author" href="https://example.com/about/jane">Jane Doe</a></p>
+            <p><a rel="me noopener" href="https://github.com/jane">GitHub</a>
+               <a href="https://example.com/other">Other</a></p>
+          </body>
+        </html>
+    "#;
+
+    assert_eq!(
+        profiles(html, MetadataConfig::default()),
+        [
+            profile("https://example.com/about/jane", None, ProfileSource::RelAuthor),
+            profile("https://mastodon.social/@jane", Some("Mastodon"), ProfileSource::RelMe),
+            profile("https://github.com/jane", Some("GitHub"), ProfileSource::RelMe),
+        ]
+    );
+}
+
+#[test]
+fn test_schema_org_author_and_same_as() {
+    let html = r#"
+        <html>
+          <head>
+            <script type="application/ld+json">
+              {
+                "@context": "https://schema.org",
+                "@graph": [
+                  {
+                    "@type": "Article",
+                    "headline": "Post",
+                    "url": "https://example.com/post",
+                    "author": [
+                      { "@type": "Person", "name": "Jane Doe", "url": "https://example.com/about/jane",
+                        "sameAs": ["https://twitter.com/jane", "https://github.com/jane"] },
+                      "https://example.com/about/john"
+                    ]
+                  },
+                  { "@type": ["Organization"], "name": "Example Inc", "sameAs": "https://www.linkedin.com/company/example" }
+                ]
+              }
+            </script>
+          </head>
+          <body><p>Hello</p></body>
+        </html>
+    "#;
+
+    assert_eq!(
+        profiles(html, MetadataConfig::default()),
+        [
+            profile(
+                "https://example.com/about/jane",
+                Some("Jane Doe"),
+                ProfileSource::SchemaAuthor
+            ),
+            profile(
+                "https://twitter.com/jane",
+                Some("Jane Doe"),
+                ProfileSource::SchemaSameAs
+            ),
+            profile("https://github.com/jane", Some("Jane Doe"), ProfileSource::SchemaSameAs),
+            profile("https://example.com/about/john", None, ProfileSource::SchemaAuthor),
+            profile(
+                "https://www.linkedin.com/company/example",
+                Some("Example Inc"),
+                ProfileSource::SchemaSameAs
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_profiles_do_not_depend_on_link_or_structured_data_extraction() {
+    let html = r#"
+        <head>
+          <script type="application/ld+json">{"@type": "Person", "sameAs": "https://github.com/jane"}</script>
+        </head>
+        <body><a rel="author" href="/about/jane">Jane</a></body>
+    "#;
+    let config = MetadataConfig {
+        extract_links: false,
+        extract_structured_data: false,
+        ..MetadataConfig::default()
+    };
+
+    let (_markdown, metadata) = html_to_markdown_rs::convert_with_metadata(html, None, config, None).unwrap();
+    assert!(metadata.links.is_empty());
+    assert!(metadata.structured_data.is_empty());
+    assert_eq!(
+        metadata.document.author_profiles,
+        [
+            profile("https://github.com/jane", None, ProfileSource::SchemaSameAs),
+            profile("/about/jane", Some("Jane"), ProfileSource::RelAuthor),
+        ]
+    );
+
+    let config = MetadataConfig {
+        extract_document: false,
+        ..MetadataConfig::default()
+    };
+    assert!(profiles(html, config).is_empty());
+}
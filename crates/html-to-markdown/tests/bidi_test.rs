@@ -0,0 +1,82 @@
+//! Tests for the `bidi_mode` option (right-to-left sections).
+
+use html_to_markdown_rs::{BidiMode, ConversionOptions, convert};
+
+const RLM: char = '\u{200F}';
+const RLE: char = '\u{202B}';
+const PDF: char = '\u{202C}';
+
+fn convert_bidi(html: &str, bidi_mode: BidiMode) -> String {
+    let options = ConversionOptions {
+        bidi_mode,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_dir_ignored_by_default() {
+    let html = "<div dir=\"rtl\"><p>שלום</p></div>";
+    assert_eq!(convert(html, None).unwrap(), "שלום\n");
+    assert_eq!(convert_bidi(html, BidiMode::Ignore), "שלום\n");
+}
+
+#[test]
+fn test_existing_direction_marks_kept() {
+    let result = convert("<p>&rlm;שלום abc</p>", None).unwrap();
+    assert_eq!(result, format!("{RLM}שלום abc\n"));
+}
+
+#[test]
+fn test_marks_prefix_each_block_line() {
+    let html = "<div dir=\"rtl\"><h2>כותרת</h2><p>שלום <b>עולם</b> 123.</p><ul><li>אחד</li></ul></div>";
+    let result = convert_bidi(html, BidiMode::Marks);
+
+    assert!(result.contains(&format!("## {RLM}כותרת")), "{result:?}");
+    assert!(result.contains(&format!("{RLM}שלום **עולם** 123.")), "{result:?}");
+    assert!(result.contains(&format!("- {RLM}אחד")), "{result:?}");
+}
+
+#[test]
+fn test_embedding_wraps_lines_and_inline_spans() {
+    let result = convert_bidi(
+        "<p dir=\"rtl\">שלום עולם</p><p>Hello <span dir=\"rtl\">مرحبا</span> there</p>",
+        BidiMode::Embedding,
+    );
+
+    assert!(result.starts_with(&format!("{RLE}שלום עולם{PDF}\n")), "{result:?}");
+    assert!(result.contains(&format!("Hello {RLE}مرحبا{PDF} there")), "{result:?}");
+}
+
+#[test]
+fn test_html_passthrough_wrapper() {
+    let result = convert_bidi(
+        "<div dir=\"rtl\"><p>שלום</p><ul><li dir=\"ltr\">two</li></ul></div>",
+        BidiMode::Html,
+    );
+
+    assert!(result.starts_with("<div dir=\"rtl\">\n\nשלום\n\n"), "{result:?}");
+    assert!(result.contains("- <span dir=\"ltr\">two</span>"), "{result:?}");
+    assert!(result.trim_end().ends_with("</div>"), "{result:?}");
+}
+
+#[test]
+fn test_code_blocks_and_tables_untouched() {
+    let html = "<div dir=\"rtl\"><pre><code>x = 1</code></pre>\
+                <table><tr><th>א</th></tr><tr><td>ב</td></tr></table></div>";
+    let result = convert_bidi(html, BidiMode::Marks);
+
+    assert!(result.contains("    x = 1"), "{result:?}");
+    for line in result.lines().filter(|line| line.contains('|')) {
+        assert!(line.starts_with('|'), "table row must not be prefixed: {line:?}");
+    }
+}
+
+#[test]
+fn test_inherited_direction_not_repeated() {
+    let result = convert_bidi("<div dir=\"rtl\"><p dir=\"rtl\">שלום</p></div>", BidiMode::Marks);
+    assert_eq!(result.matches(RLM).count(), 1, "{result:?}");
+
+    let result = convert_bidi("<p dir=\"ltr\">plain</p>", BidiMode::Marks);
+    assert_eq!(result, "plain\n");
+}
@@ -0,0 +1,59 @@
+//! Tests for batch boilerplate removal.
+
+use html_to_markdown_rs::{BoilerplateConfig, convert_many, convert_many_deduplicated};
+
+const FOOTER: &str = "<footer><p>Sign up for our newsletter</p><ul><li><a href=\"/about\">About</a></li><li><a href=\"/jobs\">Jobs</a></li></ul></footer>";
+
+fn page(title: &str, body: &str) -> String {
+    format!("<h1>{title}</h1><p>{body}</p>{FOOTER}")
+}
+
+#[test]
+fn test_shared_footer_is_removed_from_every_page() {
+    let pages = [page("One", "First"), page("Two", "Second"), page("Three", "Third")];
+    let (results, report) = convert_many_deduplicated(&pages, None, &BoilerplateConfig::default());
+
+    assert_eq!(results[1].as_deref().unwrap(), "# Two\n\nSecond\n");
+    assert_eq!(report.documents, 3);
+    let removed: Vec<&str> = report.blocks.iter().map(|block| block.text.as_str()).collect();
+    assert_eq!(
+        removed,
+        ["Sign up for our newsletter", "- [About](/about)\n- [Jobs](/jobs)"]
+    );
+    assert!(report.blocks.iter().all(|block| block.documents == 3));
+}
+
+#[test]
+fn test_min_share_controls_what_counts_as_boilerplate() {
+    let pages = [
+        page("One", "First"),
+        page("Two", "Second"),
+        "<h1>Three</h1><p>Third</p>".to_string(),
+        "<h1>Four</h1><p>Fourth</p>".to_string(),
+    ];
+
+    let (results, report) = convert_many_deduplicated(&pages, None, &BoilerplateConfig::default());
+    assert!(report.is_empty());
+    let plain: Vec<String> = convert_many(&pages, None).into_iter().map(Result::unwrap).collect();
+    let deduplicated: Vec<String> = results.into_iter().map(Result::unwrap).collect();
+    assert_eq!(deduplicated, plain);
+
+    let config = BoilerplateConfig {
+        min_share: 0.5,
+        ..Default::default()
+    };
+    let (results, report) = convert_many_deduplicated(&pages, None, &config);
+    assert_eq!(report.blocks.len(), 2);
+    assert_eq!(results[0].as_deref().unwrap(), "# One\n\nFirst\n");
+}
+
+#[test]
+fn test_unrelated_documents_are_untouched() {
+    let mut pages = vec![page("One", "First"), page("Two", "Second"), page("Three", "Third")];
+    pages.push("<p>Nothing shared</p>".to_string());
+    let (results, report) = convert_many_deduplicated(&pages, None, &BoilerplateConfig::default());
+
+    assert_eq!(report.documents, 4);
+    assert_eq!(results[3].as_deref().unwrap(), "Nothing shared\n");
+    assert_eq!(results[0].as_deref().unwrap(), "# One\n\nFirst\n");
+}
@@ -0,0 +1,56 @@
+//! Tests for cached conversions.
+
+use html_to_markdown_rs::cache::{CacheStats, CachedConverter, ConversionCache, FsCache, MemoryCache, cache_key};
+use html_to_markdown_rs::{ConversionOptions, HeadingStyle};
+
+#[test]
+fn test_key_depends_on_input_and_options() {
+    let defaults = ConversionOptions::default();
+    let underlined = ConversionOptions {
+        heading_style: HeadingStyle::Underlined,
+        ..Default::default()
+    };
+
+    assert_eq!(cache_key("<p>a</p>", &defaults), cache_key("<p>a</p>", &defaults));
+    assert_ne!(cache_key("<p>a</p>", &defaults), cache_key("<p>b</p>", &defaults));
+    assert_ne!(cache_key("<h1>a</h1>", &defaults), cache_key("<h1>a</h1>", &underlined));
+    assert!(cache_key("", &defaults).ends_with(env!("CARGO_PKG_VERSION")));
+}
+
+#[test]
+fn test_memory_cache_hits_and_misses() {
+    let converter = CachedConverter::new(MemoryCache::default());
+
+    converter.convert("<p>one</p>", None).unwrap();
+    converter.convert("<p>two</p>", None).unwrap();
+    assert_eq!(converter.convert("<p>one</p>", None).unwrap(), "one\n");
+
+    assert_eq!(converter.stats(), CacheStats { hits: 1, misses: 2 });
+    assert_eq!(converter.cache().len(), 2);
+
+    converter.reset_stats();
+    assert_eq!(converter.stats(), CacheStats::default());
+}
+
+#[test]
+fn test_fs_cache_persists_across_converters() {
+    let dir = tempfile::tempdir().unwrap();
+    let html = "<h1>Title</h1><p>Body</p>";
+
+    let first = CachedConverter::new(FsCache::new(dir.path().join("cache")).unwrap());
+    let markdown = first.convert(html, None).unwrap();
+    assert_eq!(first.stats().misses, 1);
+
+    let second = CachedConverter::new(FsCache::new(dir.path().join("cache")).unwrap());
+    assert_eq!(second.convert(html, None).unwrap(), markdown);
+    assert_eq!(second.stats(), CacheStats { hits: 1, misses: 0 });
+}
+
+#[test]
+fn test_cached_entries_are_returned_verbatim() {
+    let cache = MemoryCache::default();
+    cache.put(&cache_key("<p>x</p>", &ConversionOptions::default()), "cached\n");
+    let converter = CachedConverter::new(cache);
+
+    assert_eq!(converter.convert("<p>x</p>", None).unwrap(), "cached\n");
+}
@@ -0,0 +1,75 @@
+//! Tests for `convert_chunked`.
+
+use html_to_markdown_rs::{ChunkConfig, ConversionError, ConversionOptions, MarkdownChunk, convert_chunked};
+
+const GUIDE: &str = "<h1>Guide</h1><p>Intro.</p>\
+    <h2><a href=\"/install\">Install</a> steps</h2><p>Download it.</p><p>Run it.</p>\
+    <h3>Linux</h3><pre><code>apt install tool</code></pre>\
+    <h2>Usage</h2><p>Call it.</p>";
+
+fn options() -> ConversionOptions {
+    ConversionOptions {
+        extract_metadata: false,
+        ..Default::default()
+    }
+}
+
+fn chunk(content: &str, heading_path: &[&str]) -> MarkdownChunk {
+    MarkdownChunk {
+        content: content.to_string(),
+        heading_path: heading_path.iter().map(ToString::to_string).collect(),
+    }
+}
+
+#[test]
+fn test_chunks_follow_sections_with_plain_text_breadcrumbs() {
+    let chunks = convert_chunked(GUIDE, Some(options()), ChunkConfig::default()).unwrap();
+    assert_eq!(
+        chunks,
+        [
+            chunk("# Guide\n\nIntro.", &["Guide"]),
+            chunk(
+                "## [Install](/install) steps\n\nDownload it.\n\nRun it.",
+                &["Guide", "Install steps"]
+            ),
+            chunk(
+                "### Linux\n\n    apt install tool",
+                &["Guide", "Install steps", "Linux"]
+            ),
+            chunk("## Usage\n\nCall it.", &["Guide", "Usage"]),
+        ]
+    );
+}
+
+#[test]
+fn test_blocks_are_packed_up_to_max_chars() {
+    let config = ChunkConfig {
+        max_chars: 60,
+        split_on_headings: false,
+    };
+    let chunks = convert_chunked(GUIDE, Some(options()), config).unwrap();
+    assert!(chunks.len() > 1);
+    for chunk in &chunks {
+        assert!(chunk.content.chars().count() <= 60, "{chunk:?}");
+        assert!(!chunk.content.starts_with('\n') && !chunk.content.ends_with('\n'));
+    }
+    let rejoined: Vec<_> = chunks.iter().map(|chunk| chunk.content.as_str()).collect();
+    assert_eq!(
+        rejoined.join("\n\n") + "\n",
+        html_to_markdown_rs::convert(GUIDE, Some(options())).unwrap()
+    );
+    assert_eq!(chunks[1].heading_path, ["Guide", "Install steps"]);
+}
+
+#[test]
+fn test_zero_max_chars_is_rejected() {
+    let config = ChunkConfig {
+        max_chars: 0,
+        ..Default::default()
+    };
+    assert!(matches!(
+        convert_chunked(GUIDE, None, config),
+        Err(ConversionError::ConfigError(_))
+    ));
+    assert!(convert_chunked("", None, ChunkConfig::default()).unwrap().is_empty());
+}
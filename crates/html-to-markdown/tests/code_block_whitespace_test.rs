@@ -0,0 +1,76 @@
+//! Whitespace inside `<pre>` survives conversion exactly, in every code block style.
+
+use html_to_markdown_rs::{CodeBlockStyle, ConversionOptions, WhitespaceMode, convert};
+
+fn convert_with(code_block_style: CodeBlockStyle, code_block_trim: bool, html: &str) -> String {
+    let options = ConversionOptions {
+        code_block_style,
+        code_block_trim,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_indentation_is_not_dedented() {
+    let html = "<pre>\n    fn main() {\n        run();\n    }\n</pre>";
+
+    assert_eq!(
+        convert_with(CodeBlockStyle::Backticks, true, html),
+        "```\n    fn main() {\n        run();\n    }\n```\n"
+    );
+    assert_eq!(
+        convert_with(CodeBlockStyle::Indented, true, html),
+        "        fn main() {\n            run();\n        }\n"
+    );
+}
+
+#[test]
+fn test_trailing_and_interior_whitespace_is_kept() {
+    let html = "<pre><code>a  \n   \n\tb\t\n<b>c  </b>  <i> d </i>\n</code></pre>";
+
+    assert_eq!(
+        convert_with(CodeBlockStyle::Tildes, true, html),
+        "~~~\na  \n   \n\tb\t\nc     d \n~~~\n"
+    );
+}
+
+#[test]
+fn test_code_block_trim_controls_blank_lines() {
+    let html = "<pre>\n\n  x\n\n</pre>";
+
+    assert_eq!(convert_with(CodeBlockStyle::Backticks, true, html), "```\n  x\n```\n");
+    assert_eq!(
+        convert_with(CodeBlockStyle::Backticks, false, html),
+        "```\n\n  x\n\n```\n"
+    );
+}
+
+#[test]
+fn test_code_in_list_items_keeps_trailing_whitespace() {
+    let html = "<ul><li><p>Run:</p><pre>cmd --flag  \n  next</pre></li></ul>";
+    let markdown = convert_with(CodeBlockStyle::Backticks, true, html);
+
+    assert!(markdown.contains("cmd --flag  \n"), "{markdown:?}");
+    assert!(markdown.contains("  next\n"), "{markdown:?}");
+}
+
+#[test]
+fn test_normalized_and_strict_modes_agree_on_code() {
+    let html = "<p>Text   with   spaces</p><pre>  keep   these  </pre>";
+    let strict = ConversionOptions {
+        code_block_style: CodeBlockStyle::Backticks,
+        whitespace_mode: WhitespaceMode::Strict,
+        extract_metadata: false,
+        ..Default::default()
+    };
+
+    let normalized = convert_with(CodeBlockStyle::Backticks, true, html);
+    assert!(normalized.contains("```\n  keep   these  \n```"), "{normalized:?}");
+    assert!(
+        convert(html, Some(strict))
+            .unwrap()
+            .contains("```\n  keep   these  \n```")
+    );
+}
@@ -0,0 +1,69 @@
+//! Tests for `infer_code_language`.
+
+use html_to_markdown_rs::{CodeBlockStyle, ConversionOptions, convert};
+
+fn convert_inferring(infer_code_language: bool, html: &str) -> String {
+    let options = ConversionOptions {
+        code_block_style: CodeBlockStyle::Backticks,
+        infer_code_language,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_language_class_emitted_only_when_inferring() {
+    let html = r#"<pre><code class="language-rust">let x = 1;</code></pre>"#;
+
+    assert_eq!(convert_inferring(false, html), "```\nlet x = 1;\n```\n");
+    assert_eq!(convert_inferring(true, html), "```rust\nlet x = 1;\n```\n");
+}
+
+#[test]
+fn test_highlighter_classes() {
+    assert_eq!(
+        convert_inferring(true, r#"<pre class="brush: js; gutter: false">var a = 1;</pre>"#),
+        "```js\nvar a = 1;\n```\n"
+    );
+    assert_eq!(
+        convert_inferring(
+            true,
+            r#"<pre class="highlight highlight-source-python"><code>x = 1</code></pre>"#
+        ),
+        "```python\nx = 1\n```\n"
+    );
+    assert_eq!(
+        convert_inferring(
+            true,
+            r#"<pre class="sourceCode haskell"><code>main = pure ()</code></pre>"#
+        ),
+        "```haskell\nmain = pure ()\n```\n"
+    );
+}
+
+#[test]
+fn test_data_lang_attribute() {
+    let html = r#"<pre><code data-lang="Ruby">puts 1</code></pre>"#;
+
+    assert_eq!(convert_inferring(true, html), "```ruby\nputs 1\n```\n");
+}
+
+#[test]
+fn test_heuristics_and_fallback_to_code_language() {
+    let python = "<pre><code>def greet(name):\n    return name</code></pre>";
+    assert_eq!(
+        convert_inferring(true, python),
+        "```python\ndef greet(name):\n    return name\n```\n"
+    );
+
+    let plain = "<pre>just some text</pre>";
+    let options = ConversionOptions {
+        code_block_style: CodeBlockStyle::Backticks,
+        code_language: "text".to_string(),
+        infer_code_language: true,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    assert_eq!(convert(plain, Some(options)).unwrap(), "```text\njust some text\n```\n");
+}
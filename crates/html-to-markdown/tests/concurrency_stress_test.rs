@@ -0,0 +1,170 @@
+//! Stress test: thousands of conversions across threads sharing one set of options.
+//!
+//! Every thread converts the same mixed corpus through every entry point enabled for this
+//! build and checks each result against a single-threaded reference. Any state leaking between
+//! concurrent conversions shows up as a mismatch. Set `HTML_TO_MARKDOWN_STRESS_ROUNDS` to run
+//! more rounds per thread.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use html_to_markdown_rs::{
+    ConversionOptions, PostProcessorChain, UnrepresentablePolicy, convert, convert_with_diagnostics,
+};
+
+const THREADS: usize = 8;
+const DEFAULT_ROUNDS: usize = 40;
+
+const CORPUS: &[&str] = &[
+    "<h1>Title</h1><p>Intro with <a href=\"https://example.com\" title=\"Home\">a link</a> and <em>emphasis</em>.</p>",
+    "<table><caption>Totals</caption><tr><th colspan=\"2\">Name</th></tr><tr><td rowspan=\"2\">a</td><td>1</td></tr><tr><td>2</td></tr></table>",
+    "<ol start=\"7\"><li><p>Loose</p></li><li>Nested<ul><li><input type=\"checkbox\" checked> done</li></ul></li></ol>",
+    "<pre><code class=\"language-rust\">fn main() {\n    println!(\"hi\");\n}</code></pre><blockquote cite=\"x\"><p>Quoted</p></blockquote>",
+    "<p>Soft\u{AD}hyphen and long<wbr>word, <bdo dir=\"rtl\">olleh</bdo>, <q>quote</q>, H<sub>2</sub>O.</p>",
+    "<html><head><title>Meta</title><meta name=\"description\" content=\"Described\"></head><body><h2 id=\"s\">Section</h2><img src=\"a.png\" alt=\"A\"></body></html>",
+    "<html><head><meta name=\"ocr-system\" content=\"tesseract 5\"></head><body><div class=\"ocr_page\" title=\"bbox 0 0 500 500\"><p class=\"ocr_par\"><span class=\"ocr_line\" title=\"bbox 10 10 200 30\"><span class=\"ocrx_word\" title=\"bbox 10 10 60 30; x_wconf 95\">Scanned</span> <span class=\"ocrx_word\" title=\"bbox 70 10 140 30; x_wconf 93\">words</span></span></p></div></body></html>",
+    "<dl><dt>Term</dt><dd>Definition</dd></dl><p><img src=\"data:image/png;base64,iVBORw0KGgo=\" alt=\"inline\"></p>",
+    "plain text &amp; entities &lt;not a tag&gt;",
+];
+
+fn rounds() -> usize {
+    std::env::var("HTML_TO_MARKDOWN_STRESS_ROUNDS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_ROUNDS)
+}
+
+/// Options with a post-processor that counts its calls through shared state.
+fn counting_options(calls: &Arc<AtomicUsize>) -> ConversionOptions {
+    let mut post_processors = PostProcessorChain::from_names(&["reference-links", "toc"]);
+    let calls = Arc::clone(calls);
+    post_processors.push_fn("count", move |markdown: &str| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        markdown.to_string()
+    });
+    ConversionOptions {
+        post_processors,
+        unrepresentable_policy: UnrepresentablePolicy::Diagnose,
+        ..Default::default()
+    }
+}
+
+/// Run `convert_one` for every document on `THREADS` threads and compare with `reference`.
+fn stress<T, F>(reference: &[T], convert_one: F)
+where
+    T: PartialEq + std::fmt::Debug + Sync,
+    F: Fn(&str) -> T + Sync,
+{
+    let rounds = rounds();
+    thread::scope(|scope| {
+        for worker in 0..THREADS {
+            let convert_one = &convert_one;
+            scope.spawn(move || {
+                for round in 0..rounds {
+                    // Stagger the starting document so threads hit different code paths at once.
+                    for offset in 0..CORPUS.len() {
+                        let index = (worker + round + offset) % CORPUS.len();
+                        assert_eq!(
+                            convert_one(CORPUS[index]),
+                            reference[index],
+                            "thread {worker} round {round} document {index}"
+                        );
+                    }
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn test_concurrent_convert_matches_sequential() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let options = counting_options(&calls);
+    let reference: Vec<_> = CORPUS
+        .iter()
+        .map(|html| convert(html, Some(options.clone())).unwrap())
+        .collect();
+
+    stress(&reference, |html| convert(html, Some(options.clone())).unwrap());
+
+    assert_eq!(calls.load(Ordering::Relaxed), CORPUS.len() * (1 + THREADS * rounds()));
+}
+
+#[test]
+fn test_concurrent_diagnostics_stay_per_conversion() {
+    let options = counting_options(&Arc::new(AtomicUsize::new(0)));
+    let reference: Vec<_> = CORPUS
+        .iter()
+        .map(|html| convert_with_diagnostics(html, Some(options.clone())).unwrap())
+        .collect();
+
+    stress(&reference, |html| {
+        convert_with_diagnostics(html, Some(options.clone())).unwrap()
+    });
+}
+
+#[cfg(feature = "metadata")]
+#[test]
+fn test_concurrent_metadata_extraction() {
+    use html_to_markdown_rs::{MetadataConfig, convert_with_metadata};
+
+    let extract = |html: &str| {
+        let (markdown, metadata) = convert_with_metadata(html, None, MetadataConfig::default(), None).unwrap();
+        (markdown, format!("{metadata:?}"))
+    };
+    let reference: Vec<_> = CORPUS.iter().map(|html| extract(html)).collect();
+
+    stress(&reference, extract);
+}
+
+#[cfg(feature = "inline-images")]
+#[test]
+fn test_concurrent_inline_image_extraction() {
+    use html_to_markdown_rs::{InlineImageConfig, convert_with_inline_images};
+
+    let extract = |html: &str| {
+        let extraction = convert_with_inline_images(html, None, InlineImageConfig::new(1 << 20), None).unwrap();
+        (
+            extraction.markdown,
+            extraction.inline_images.len(),
+            extraction.warnings.len(),
+        )
+    };
+    let reference: Vec<_> = CORPUS.iter().map(|html| extract(html)).collect();
+
+    stress(&reference, extract);
+}
+
+#[cfg(feature = "visitor")]
+#[test]
+fn test_concurrent_visitors_are_per_thread() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use html_to_markdown_rs::convert_with_visitor;
+    use html_to_markdown_rs::visitor::{HtmlVisitor, NodeContext, VisitResult};
+
+    #[derive(Debug, Default)]
+    struct CountLinks {
+        links: usize,
+    }
+
+    impl HtmlVisitor for CountLinks {
+        fn visit_link(&mut self, _ctx: &NodeContext, _href: &str, _text: &str, _title: Option<&str>) -> VisitResult {
+            self.links += 1;
+            VisitResult::Continue
+        }
+    }
+
+    // Visitor handles are `Rc<RefCell<_>>`, so each conversion gets its own.
+    let visit = |html: &str| {
+        let visitor = Rc::new(RefCell::new(CountLinks::default()));
+        let markdown = convert_with_visitor(html, None, Some(visitor.clone())).unwrap();
+        let links = visitor.borrow().links;
+        (markdown, links)
+    };
+    let reference: Vec<_> = CORPUS.iter().map(|html| visit(html)).collect();
+
+    stress(&reference, visit);
+}
@@ -0,0 +1,77 @@
+//! Tests for the Confluence preprocessing preset.
+
+use html_to_markdown_rs::{CodeBlockStyle, ConversionOptions, PreprocessingPreset, convert};
+
+fn confluence_options() -> ConversionOptions {
+    let mut options = ConversionOptions::default();
+    options.preprocessing.enabled = true;
+    options.preprocessing.preset = PreprocessingPreset::Confluence;
+    options
+}
+
+fn convert_confluence(html: &str) -> String {
+    convert(html, Some(confluence_options())).unwrap()
+}
+
+#[test]
+fn test_panel_macros_become_labelled_blockquotes() {
+    let html = r#"<p>Intro</p>
+<ac:structured-macro ac:name="info" ac:schema-version="1"><ac:parameter ac:name="title">Heads up</ac:parameter><ac:rich-text-body><p>Be <strong>careful</strong>.</p><p>Second</p></ac:rich-text-body></ac:structured-macro>
+<p>Between</p>
+<ac:structured-macro ac:name="warning"><ac:rich-text-body><p>Danger</p></ac:rich-text-body></ac:structured-macro>"#;
+
+    assert_eq!(
+        convert_confluence(html),
+        "Intro\n> \u{2139}\u{fe0f} **Heads up**\n>\n> Be **careful**.\n>\n> Second\n\nBetween\n> \u{26a0}\u{fe0f} **Warning**\n>\n> Danger\n"
+    );
+}
+
+#[test]
+fn test_code_macro_keeps_cdata_verbatim() {
+    let html = r#"<ac:structured-macro ac:name="code"><ac:parameter ac:name="language">rust</ac:parameter><ac:plain-text-body><![CDATA[fn main() {
+    println!("<hi> & bye");
+}]]></ac:plain-text-body></ac:structured-macro>"#;
+
+    assert_eq!(
+        convert_confluence(html),
+        "    fn main() {\n        println!(\"<hi> & bye\");\n    }\n"
+    );
+
+    let options = ConversionOptions {
+        code_block_style: CodeBlockStyle::Backticks,
+        ..confluence_options()
+    };
+    assert_eq!(
+        convert(html, Some(options)).unwrap(),
+        "```rust\nfn main() {\n    println!(\"<hi> & bye\");\n}\n```\n"
+    );
+}
+
+#[test]
+fn test_other_macros_are_unwrapped() {
+    let html = r#"<ac:structured-macro ac:name="expand"><ac:parameter ac:name="title">More</ac:parameter><ac:rich-text-body><p>Hidden <em>details</em></p></ac:rich-text-body></ac:structured-macro>
+<ac:structured-macro ac:name="toc"><ac:parameter ac:name="maxLevel">2</ac:parameter></ac:structured-macro>"#;
+
+    assert_eq!(convert_confluence(html), "Hidden *details*\n");
+}
+
+#[test]
+fn test_images_and_colgroups() {
+    let html = r#"<p><ac:image ac:alt="Flow diagram"><ri:attachment ri:filename="flow chart.png" /></ac:image></p>
+<p><ac:image ac:title="Logo"><ri:url ri:value="https://example.com/logo.png" /></ac:image></p>
+<table><colgroup><col style="width: 50px"/><col/></colgroup><tbody><tr><th>A</th><th>B</th></tr><tr><td>1</td><td>2</td></tr></tbody></table>"#;
+
+    assert_eq!(
+        convert_confluence(html),
+        "![Flow diagram](<flow chart.png>)\n\n![](https://example.com/logo.png \"Logo\")\n\n| A | B |\n| --- | --- |\n| 1 | 2 |\n"
+    );
+}
+
+#[test]
+fn test_confluence_markup_untouched_without_preset() {
+    let html = r#"<ac:structured-macro ac:name="info"><ac:parameter ac:name="title">Heads up</ac:parameter><ac:rich-text-body><p>Body</p></ac:rich-text-body></ac:structured-macro>"#;
+
+    let markdown = convert(html, None).unwrap();
+    assert!(!markdown.contains('>'), "{markdown}");
+    assert!(markdown.contains("Body"));
+}
@@ -0,0 +1,111 @@
+//! Tests for `contact_link_style`.
+
+use html_to_markdown_rs::{ContactLinkStyle, ConversionOptions, convert};
+
+fn convert_with(style: ContactLinkStyle, html: &str) -> String {
+    let options = ConversionOptions {
+        contact_link_style: style,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_link_style_is_unchanged() {
+    assert_eq!(
+        convert_with(
+            ContactLinkStyle::Link,
+            r#"<p><a href="mailto:ada@example.com?subject=Hi">ada@example.com</a></p>"#
+        ),
+        "[ada@example.com](mailto:ada@example.com?subject=Hi)\n"
+    );
+    assert_eq!(
+        convert_with(
+            ContactLinkStyle::Link,
+            r#"<p><a href="tel:+1-555-0100">Call us</a></p>"#
+        ),
+        "[Call us](tel:+1-555-0100)\n"
+    );
+}
+
+#[test]
+fn test_autolink_when_text_is_the_address() {
+    let cases = [
+        (
+            r#"<a href="mailto:ada@example.com">ada@example.com</a>"#,
+            "<ada@example.com>",
+        ),
+        (
+            r#"<a href="MAILTO:Ada@Example.com?subject=Hi">ada@example.com</a>"#,
+            "<Ada@Example.com>",
+        ),
+        (
+            r#"<a href="mailto:ada@example.com">mailto:ada@example.com</a>"#,
+            "<ada@example.com>",
+        ),
+        (r#"<a href="tel:+1-555-0100">+1 (555) 0100</a>"#, "<tel:+1-555-0100>"),
+        (r#"<a href="tel:+1 555 0100">+1 555 0100</a>"#, "<tel:+15550100>"),
+    ];
+    for (html, expected) in cases {
+        assert_eq!(
+            convert_with(ContactLinkStyle::Autolink, html).trim_end(),
+            expected,
+            "{html}"
+        );
+    }
+}
+
+#[test]
+fn test_autolink_keeps_other_text_as_link() {
+    assert_eq!(
+        convert_with(
+            ContactLinkStyle::Autolink,
+            r#"<p><a href="mailto:ada@example.com">Email Ada</a> or <a href="https://example.com">https://example.com</a></p>"#
+        ),
+        "[Email Ada](mailto:ada@example.com) or <https://example.com>\n"
+    );
+}
+
+#[test]
+fn test_text_style() {
+    assert_eq!(
+        convert_with(
+            ContactLinkStyle::Text,
+            r#"<p>Write to <a href="mailto:ada@example.com?subject=Hi">ada@example.com</a>.</p>"#
+        ),
+        "Write to ada@example.com.\n"
+    );
+    assert_eq!(
+        convert_with(
+            ContactLinkStyle::Text,
+            r#"<p><a href="tel:+15550100"><strong>Call</strong> us</a> today</p>"#
+        ),
+        "**Call** us (+15550100) today\n"
+    );
+    assert_eq!(
+        convert_with(
+            ContactLinkStyle::Text,
+            r#"<p>Mail: <a href="mailto:ada@example.com"></a></p>"#
+        ),
+        "Mail: ada@example.com\n"
+    );
+}
+
+#[test]
+fn test_text_style_ignores_autolinks_option() {
+    let options = ConversionOptions {
+        contact_link_style: ContactLinkStyle::Text,
+        autolinks: true,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        convert(
+            r#"<p><a href="mailto:ada@example.com">ada@example.com</a></p>"#,
+            Some(options)
+        )
+        .unwrap(),
+        "ada@example.com\n"
+    );
+}
@@ -0,0 +1,54 @@
+//! Tests for `details_style`.
+
+use html_to_markdown_rs::{ConversionOptions, DetailsStyle, convert};
+
+const DETAILS: &str = "<p>Intro</p><details><summary>Install <code>npm</code></summary><p>Run <strong>this</strong>:</p><pre>npm i</pre></details><p>Outro</p>";
+
+fn convert_with(details_style: DetailsStyle, html: &str) -> String {
+    let options = ConversionOptions {
+        details_style,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_markdown_is_default() {
+    assert_eq!(
+        convert(DETAILS, None).unwrap(),
+        convert_with(DetailsStyle::Markdown, DETAILS)
+    );
+    assert_eq!(
+        convert_with(DetailsStyle::Markdown, DETAILS),
+        "Intro\n\n**Install `npm`**\n\nRun **this**:\n\n    npm i\n\nOutro\n"
+    );
+}
+
+#[test]
+fn test_html_keeps_wrapper_and_converts_body() {
+    assert_eq!(
+        convert_with(DetailsStyle::Html, DETAILS),
+        "Intro\n\n<details>\n<summary>Install `npm`</summary>\n\nRun **this**:\n\n    npm i\n\n</details>\n\nOutro\n"
+    );
+}
+
+#[test]
+fn test_html_keeps_open_and_handles_missing_summary() {
+    let html = "<details open><p>Body</p></details>";
+
+    assert_eq!(
+        convert_with(DetailsStyle::Html, html),
+        "<details open>\n\nBody\n\n</details>\n"
+    );
+}
+
+#[test]
+fn test_html_body_keeps_leading_code_indentation() {
+    let html = "<details><summary>Log</summary><pre>line</pre></details>";
+
+    assert_eq!(
+        convert_with(DetailsStyle::Html, html),
+        "<details>\n<summary>Log</summary>\n\n    line\n\n</details>\n"
+    );
+}
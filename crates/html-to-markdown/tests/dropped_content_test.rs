@@ -0,0 +1,84 @@
+//! Tests for the `report_dropped_content` option.
+
+use html_to_markdown_rs::{ConversionOptions, DiagnosticKind, PreprocessingOptions, convert, convert_with_diagnostics};
+
+const PAGE: &str = r#"<html><head><title>Ignored title</title></head><body>
+<nav><a href="/">Home</a> <a href="/about">About</a></nav>
+<main><h1>Article</h1><p>Body text here.</p><aside class="ad">Buy now</aside></main>
+<form><label>Email</label><button>Go</button></form>
+</body></html>"#;
+
+fn options() -> ConversionOptions {
+    ConversionOptions {
+        preprocessing: PreprocessingOptions {
+            enabled: true,
+            remove_navigation: true,
+            remove_forms: true,
+            ..Default::default()
+        },
+        remove_tags: vec!["aside".to_string()],
+        report_dropped_content: true,
+        extract_metadata: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_dropped_text_is_totalled_per_rule() {
+    let (markdown, diagnostics) = convert_with_diagnostics(PAGE, Some(options())).unwrap();
+    assert_eq!(markdown, "# Article\n\nBody text here.\n");
+
+    let dropped: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            assert_eq!(diagnostic.kind, DiagnosticKind::DroppedContent);
+            let dropped = diagnostic.dropped.as_ref().unwrap();
+            (dropped.rule.as_str(), dropped.characters, dropped.document_characters)
+        })
+        .collect();
+    // "HomeAbout", "Buynow" and "EmailGo" without whitespace; the <head> title is not counted.
+    assert_eq!(
+        dropped,
+        [
+            ("remove_navigation", 9, 42),
+            ("remove_tags", 6, 42),
+            ("remove_forms", 7, 42)
+        ]
+    );
+    assert_eq!(
+        diagnostics[0].message,
+        "remove_navigation removed 9 of 42 text characters (21.4%)"
+    );
+}
+
+#[test]
+fn test_main_content_selection_is_reported() {
+    let options = ConversionOptions {
+        preprocessing: PreprocessingOptions {
+            enabled: true,
+            include_selector: Some("p".to_string()),
+            ..Default::default()
+        },
+        report_dropped_content: true,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    let (markdown, diagnostics) = convert_with_diagnostics(PAGE, Some(options)).unwrap();
+    assert_eq!(markdown, "Body text here.\n");
+
+    let dropped = diagnostics[0].dropped.as_ref().unwrap();
+    assert_eq!(dropped.rule, "include_selector");
+    assert_eq!(dropped.characters, 42 - 13);
+    assert!((dropped.share() - 29.0 / 42.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_reporting_is_off_by_default() {
+    let options = ConversionOptions {
+        report_dropped_content: false,
+        ..options()
+    };
+    let (markdown, diagnostics) = convert_with_diagnostics(PAGE, Some(options.clone())).unwrap();
+    assert!(diagnostics.is_empty());
+    assert_eq!(markdown, convert(PAGE, Some(options)).unwrap());
+}
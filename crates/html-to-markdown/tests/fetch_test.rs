@@ -0,0 +1,169 @@
+#![cfg(feature = "fetch")]
+//! Tests for the `fetch` module against a scripted local HTTP server.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use html_to_markdown_rs::fetch::{Cookie, FetchConfig, FetchError, Fetcher};
+
+/// Serve `responses` in order, one per connection, and record each request head.
+fn serve(responses: Vec<&'static str>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&requests);
+    thread::spawn(move || {
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            recorded.lock().unwrap().push(head);
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    (base, requests)
+}
+
+fn config() -> FetchConfig {
+    FetchConfig {
+        initial_backoff: Duration::from_millis(1),
+        ..FetchConfig::default()
+    }
+}
+
+#[test]
+fn test_retries_transient_status() {
+    let (base, requests) = serve(vec![
+        "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=\"utf-8\"\r\nContent-Length: 12\r\nConnection: close\r\n\r\n<h1>Hi</h1>\n",
+    ]);
+    let fetcher = Fetcher::new(config()).unwrap();
+
+    let response = fetcher.fetch(&format!("{base}/page")).unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, b"<h1>Hi</h1>\n");
+    assert_eq!(response.charset(), Some("utf-8"));
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_client_errors_are_not_retried() {
+    let (base, requests) = serve(vec![
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+    ]);
+    let fetcher = Fetcher::new(config()).unwrap();
+
+    let err = fetcher.fetch(&format!("{base}/missing")).unwrap_err();
+
+    assert!(matches!(err, FetchError::Status { status: 404, .. }), "{err}");
+    assert_eq!(requests.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_redirects_carry_cookies() {
+    let (base, requests) = serve(vec![
+        "HTTP/1.1 302 Found\r\nLocation: /final\r\nSet-Cookie: session=abc; Path=/; HttpOnly\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+    ]);
+    let fetcher = Fetcher::new(config()).unwrap();
+
+    let response = fetcher.fetch(&format!("{base}/start")).unwrap();
+
+    assert_eq!(response.url, format!("{base}/final"));
+    let requests = requests.lock().unwrap().clone();
+    assert!(requests[1].starts_with("GET /final "));
+    assert!(requests[1].to_ascii_lowercase().contains("cookie: session=abc\r\n"));
+}
+
+#[test]
+fn test_secure_cookies_stay_off_plain_http() {
+    let (base, requests) = serve(vec![
+        "HTTP/1.1 302 Found\r\nLocation: /final\r\nSet-Cookie: token=xyz; Secure\r\nSet-Cookie: session=abc\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+    ]);
+    let fetcher = Fetcher::new(config()).unwrap();
+    fetcher.fetch(&format!("{base}/start")).unwrap();
+    let mut state = fetcher.state();
+    let jar = state.cookies.get_mut("127.0.0.1").unwrap();
+    assert_eq!(jar.keys().collect::<Vec<_>>(), ["session"]);
+
+    // A secure cookie saved from an https visit is not sent over http either.
+    jar.insert(
+        "token".to_string(),
+        Cookie {
+            value: "xyz".to_string(),
+            secure: true,
+        },
+    );
+    let fetcher = Fetcher::with_state(config(), state).unwrap();
+    fetcher.fetch(&format!("{base}/again")).unwrap();
+
+    let requests = requests.lock().unwrap().clone();
+    assert!(requests[1].to_ascii_lowercase().contains("cookie: session=abc\r\n"));
+    assert!(requests[2].to_ascii_lowercase().contains("cookie: session=abc\r\n"));
+}
+
+#[test]
+fn test_saved_state_enables_conditional_requests() {
+    let (base, requests) = serve(vec![
+        "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 4\r\nConnection: close\r\n\r\nbody",
+        "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n",
+    ]);
+    let url = format!("{base}/doc");
+    let state = {
+        let fetcher = Fetcher::new(config()).unwrap();
+        assert!(!fetcher.fetch(&url).unwrap().not_modified);
+        fetcher.state()
+    };
+    assert_eq!(state.validators[&url].etag.as_deref(), Some("\"v1\""));
+
+    let resumed = Fetcher::with_state(config(), state).unwrap();
+    let response = resumed.fetch(&url).unwrap();
+
+    assert!(response.not_modified);
+    assert!(response.body.is_empty());
+    assert!(
+        requests.lock().unwrap()[1]
+            .to_ascii_lowercase()
+            .contains("if-none-match: \"v1\"\r\n")
+    );
+}
+
+#[test]
+fn test_per_host_delay_spaces_requests() {
+    let ok = "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    let (base, _) = serve(vec![ok, ok]);
+    let fetcher = Fetcher::new(FetchConfig {
+        per_host_delay: Duration::from_millis(150),
+        ..config()
+    })
+    .unwrap();
+
+    let started = Instant::now();
+    fetcher.fetch(&format!("{base}/a")).unwrap();
+    fetcher.fetch(&format!("{base}/b")).unwrap();
+
+    assert!(started.elapsed() >= Duration::from_millis(150));
+}
+
+#[test]
+fn test_rejects_non_http_urls() {
+    let fetcher = Fetcher::new(config()).unwrap();
+
+    assert!(matches!(
+        fetcher.fetch("file:///etc/passwd"),
+        Err(FetchError::InvalidUrl { .. })
+    ));
+}
@@ -0,0 +1,86 @@
+//! Tests for `fidelity_threshold`.
+
+use html_to_markdown_rs::{ConversionOptions, DiagnosticKind, MathStyle, convert, convert_with_diagnostics};
+
+fn guarded(threshold: f64) -> ConversionOptions {
+    ConversionOptions {
+        fidelity_threshold: threshold,
+        extract_metadata: false,
+        ..Default::default()
+    }
+}
+
+const NESTED_TABLE: &str =
+    "<table><tr><td><table><tr><td>a</td><td>b</td></tr><tr><td>c</td><td>d</td></tr></table></td></tr></table>";
+
+#[test]
+fn test_disabled_by_default() {
+    let (markdown, diagnostics) = convert_with_diagnostics(NESTED_TABLE, None).unwrap();
+    assert!(!markdown.contains("<table>"), "{markdown}");
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_nested_table_kept_as_html() {
+    let html = format!("<p>Before</p>{NESTED_TABLE}<p>After</p>");
+    let (markdown, diagnostics) = convert_with_diagnostics(&html, Some(guarded(0.5))).unwrap();
+
+    assert_eq!(markdown, format!("Before\n\n{NESTED_TABLE}\n\nAfter\n"));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::KeptAsHtml);
+    assert_eq!(diagnostics[0].element.as_deref(), Some("table"));
+    assert!(diagnostics[0].message.contains("7 of 10"), "{}", diagnostics[0].message);
+}
+
+#[test]
+fn test_simple_table_still_converted() {
+    let html = "<table><tr><th>A</th><th>B</th></tr><tr><td>1</td><td>2</td></tr></table>";
+    assert_eq!(convert(html, Some(guarded(0.0))).unwrap(), convert(html, None).unwrap());
+}
+
+#[test]
+fn test_threshold_is_exclusive() {
+    // form, label, input and button: three of the four are lost.
+    let form = "<form><label>Name</label><input name=\"n\"><button>Go</button></form>";
+    assert!(!convert(form, Some(guarded(0.75))).unwrap().contains("<form>"));
+    assert!(
+        convert(form, Some(guarded(0.7)))
+            .unwrap()
+            .contains("<form><label>Name</label><input name=\"n\">")
+    );
+}
+
+#[test]
+fn test_kept_html_is_sanitized() {
+    let html = r#"<form action="javascript:go()" onsubmit="steal()"><script>alert(1)</script><input type="text" onfocus="x()" value="a&quot;b"></form>"#;
+    let markdown = convert(html, Some(guarded(0.0))).unwrap();
+    assert!(
+        markdown.starts_with("<form>") && markdown.ends_with("</form>\n"),
+        "{markdown}"
+    );
+    assert!(markdown.contains(r#"value="a&quot;b""#), "{markdown}");
+    for removed in ["javascript", "onsubmit", "onfocus", "alert"] {
+        assert!(!markdown.contains(removed), "{markdown}");
+    }
+}
+
+#[test]
+fn test_math_kept_only_without_math_conversion() {
+    let html = "<p>Area <math><mi>x</mi><mo>=</mo><mn>2</mn></math> units</p>";
+    assert_eq!(
+        convert(html, Some(guarded(0.0))).unwrap(),
+        "Area <math><mi>x</mi><mo>=</mo><mn>2</mn></math> units\n"
+    );
+
+    let options = ConversionOptions {
+        math_style: MathStyle::Dollar,
+        ..guarded(0.0)
+    };
+    assert!(!convert(html, Some(options)).unwrap().contains("<math>"));
+}
+
+#[test]
+fn test_threshold_out_of_range_is_rejected() {
+    let err = guarded(1.5).validate().unwrap_err().to_string();
+    assert!(err.contains("fidelity_threshold must be between 0.0 and 1.0"), "{err}");
+}
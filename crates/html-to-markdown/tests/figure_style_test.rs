@@ -0,0 +1,52 @@
+//! Tests for `figure_style`.
+
+use html_to_markdown_rs::{ConversionOptions, FigureStyle, convert};
+
+const FIGURE: &str = r#"<p>Before</p><figure id="f1"><img src="chart.png" alt="Chart"><figcaption>Sales by <em>year</em></figcaption></figure><p>After</p>"#;
+
+fn convert_with(figure_style: FigureStyle, html: &str) -> String {
+    let options = ConversionOptions {
+        figure_style,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_italic_caption_is_default() {
+    assert_eq!(
+        convert(FIGURE, None).unwrap(),
+        convert_with(FigureStyle::Italic, FIGURE)
+    );
+    assert_eq!(
+        convert_with(FigureStyle::Italic, FIGURE),
+        "Before\n\n![Chart](chart.png)\n\n*Sales by *year**\n\nAfter\n"
+    );
+}
+
+#[test]
+fn test_blockquote_caption() {
+    assert_eq!(
+        convert_with(FigureStyle::Blockquote, FIGURE),
+        "Before\n\n![Chart](chart.png)\n\n> Sales by *year*\n\nAfter\n"
+    );
+}
+
+#[test]
+fn test_blockquote_caption_keeps_line_breaks() {
+    let html = r#"<figure><img src="a.png" alt=""><figcaption>Figure 1<br>Revenue</figcaption></figure>"#;
+
+    assert_eq!(
+        convert_with(FigureStyle::Blockquote, html),
+        "![](a.png)\n\n> Figure 1  \n> Revenue\n"
+    );
+}
+
+#[test]
+fn test_html_keeps_figure() {
+    assert_eq!(
+        convert_with(FigureStyle::Html, FIGURE),
+        "Before\n\n<figure id=\"f1\"><img src=\"chart.png\" alt=\"Chart\"><figcaption>Sales by <em>year</em></figcaption></figure>\n\nAfter\n"
+    );
+}
@@ -0,0 +1,74 @@
+//! Tests for `content_fingerprint` and `convert_with_fingerprint`.
+
+use html_to_markdown_rs::{ConversionOptions, content_fingerprint, convert, convert_with_fingerprint};
+
+const ORIGINAL: &str = r#"<html><head><title>Release notes</title></head><body>
+<h1>Version 2.0</h1>
+<p>This release adds <em>streaming</em> output and fixes <a href="/issues/12">issue 12</a>.</p>
+<ul><li>Faster parsing</li><li>Smaller binaries</li></ul>
+</body></html>"#;
+
+const MIRROR: &str = r#"<html><head><title>Mirror: Release notes</title></head><body>
+<div><h1 id="v2">Version   2.0</h1></div>
+<div>This release adds <i>streaming</i>
+output and fixes <a href="https://mirror.example/issues/12">issue 12</a>.</div>
+<ol><li>Faster parsing</li><li>Smaller binaries</li></ol>
+</body></html>"#;
+
+fn fingerprint(html: &str, options: ConversionOptions) -> String {
+    let options = ConversionOptions {
+        content_fingerprint: true,
+        ..options
+    };
+    convert_with_fingerprint(html, Some(options)).unwrap().1.unwrap()
+}
+
+#[test]
+fn test_fingerprint_is_off_by_default() {
+    let (markdown, fingerprint) = convert_with_fingerprint(ORIGINAL, None).unwrap();
+
+    assert_eq!(markdown, convert(ORIGINAL, None).unwrap());
+    assert!(fingerprint.is_none());
+}
+
+#[test]
+fn test_mirrors_share_a_fingerprint() {
+    let original = fingerprint(ORIGINAL, ConversionOptions::default());
+
+    assert_eq!(original.len(), 32);
+    assert_eq!(fingerprint(MIRROR, ConversionOptions::default()), original);
+    assert_eq!(
+        fingerprint(
+            MIRROR,
+            ConversionOptions {
+                extract_metadata: false,
+                wrap: true,
+                wrap_width: 20,
+                preserve_heading_ids: true,
+                ..Default::default()
+            }
+        ),
+        original
+    );
+}
+
+#[test]
+fn test_fingerprint_matches_markdown_body() {
+    let options = ConversionOptions {
+        extract_metadata: false,
+        ..Default::default()
+    };
+    let markdown = convert(ORIGINAL, Some(options.clone())).unwrap();
+
+    assert_eq!(fingerprint(ORIGINAL, options), content_fingerprint(&markdown));
+}
+
+#[test]
+fn test_changed_text_changes_the_fingerprint() {
+    let edited = ORIGINAL.replace("issue 12", "issue 13");
+
+    assert_ne!(
+        fingerprint(&edited, ConversionOptions::default()),
+        fingerprint(ORIGINAL, ConversionOptions::default())
+    );
+}
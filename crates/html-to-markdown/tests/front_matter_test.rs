@@ -0,0 +1,59 @@
+//! Tests for the `front_matter_format` option.
+
+use html_to_markdown_rs::{ConversionOptions, FrontMatterFormat, convert};
+
+const HTML: &str = r#"<html><head>
+<title>Release: "v2"</title>
+<meta name="description" content="Notes\with backslash">
+<meta name="dc.creator" content="Ada">
+</head><body><p>Body</p></body></html>"#;
+
+fn convert_as(format: FrontMatterFormat) -> String {
+    let options = ConversionOptions {
+        extract_metadata: true,
+        front_matter_format: format,
+        ..Default::default()
+    };
+    convert(HTML, Some(options)).unwrap()
+}
+
+#[test]
+fn test_yaml_front_matter_is_default() {
+    let result = convert_as(FrontMatterFormat::Yaml);
+    assert!(result.starts_with("---\n"), "{result}");
+    assert!(result.contains("title: \"Release: \\\"v2\\\"\"\n"), "{result}");
+    assert!(result.ends_with("---\n\nBody\n"), "{result}");
+}
+
+#[test]
+fn test_toml_front_matter() {
+    let result = convert_as(FrontMatterFormat::Toml);
+    assert_eq!(
+        result,
+        "+++\n\
+         \"meta-dc.creator\" = \"Ada\"\n\
+         meta-description = \"Notes\\\\with backslash\"\n\
+         title = \"Release: \\\"v2\\\"\"\n\
+         +++\n\nBody\n"
+    );
+}
+
+#[test]
+fn test_json_front_matter() {
+    let result = convert_as(FrontMatterFormat::Json);
+    let (front_matter, body) = result.split_once("\n\n").unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(front_matter).unwrap();
+    assert_eq!(parsed["title"], "Release: \"v2\"");
+    assert_eq!(parsed["meta-description"], "Notes\\with backslash");
+    assert_eq!(body, "Body\n");
+}
+
+#[test]
+fn test_front_matter_format_ignored_without_metadata() {
+    let options = ConversionOptions {
+        extract_metadata: false,
+        front_matter_format: FrontMatterFormat::Json,
+        ..Default::default()
+    };
+    assert_eq!(convert(HTML, Some(options)).unwrap(), "Body\n");
+}
@@ -0,0 +1,84 @@
+//! Tests for the `test-util` golden-corpus runner.
+#![cfg(feature = "test-util")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use html_to_markdown_rs::ConversionOptions;
+use html_to_markdown_rs::test_util::{GoldenCorpus, GoldenFailure, normalize_markdown};
+
+fn golden_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden")
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("html-to-markdown-golden-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_committed_corpus_matches() {
+    let report = GoldenCorpus::new(golden_dir()).update(false).run().unwrap();
+    report.assert_ok();
+    assert_eq!(report.passed, 2);
+}
+
+#[test]
+fn test_normalize_markdown() {
+    assert_eq!(normalize_markdown("a  \r\nb\t\r\n\r\n\n"), "a\nb\n");
+    assert_eq!(normalize_markdown("\n\n"), "");
+}
+
+#[test]
+fn test_mismatch_and_missing_snapshot_are_reported() {
+    let dir = scratch_dir("mismatch");
+    fs::write(dir.join("a.html"), "<p>actual</p>").unwrap();
+    fs::write(dir.join("a.md"), "expected\n").unwrap();
+    fs::write(dir.join("b.html"), "<p>new</p>").unwrap();
+
+    let report = GoldenCorpus::new(&dir).update(false).run().unwrap();
+    assert!(!report.is_ok());
+    assert_eq!(report.failures.len(), 2);
+    assert!(matches!(
+        &report.failures[0],
+        GoldenFailure::Mismatch { expected, actual, .. } if expected == "expected\n" && actual == "actual\n"
+    ));
+    assert!(matches!(&report.failures[1], GoldenFailure::MissingSnapshot { .. }));
+    assert!(report.failures[0].to_string().contains("- expected\n  + actual"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_update_mode_writes_snapshots_to_separate_dir() {
+    let dir = scratch_dir("update");
+    let fixtures = dir.join("fixtures");
+    let snapshots = dir.join("snapshots");
+    fs::create_dir_all(fixtures.join("nested")).unwrap();
+    fs::write(fixtures.join("nested/page.html"), "<ul><li>x</li></ul>").unwrap();
+
+    let options = ConversionOptions {
+        bullets: "*".to_string(),
+        ..Default::default()
+    };
+    let corpus = GoldenCorpus::new(&fixtures).snapshot_dir(&snapshots).options(options);
+    let report = corpus.clone().update(true).run().unwrap();
+    assert_eq!(report.updated, 1);
+    assert_eq!(fs::read_to_string(snapshots.join("nested/page.md")).unwrap(), "* x\n");
+
+    let report = corpus.update(false).run().unwrap();
+    report.assert_ok();
+    assert_eq!(report.passed, 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "1 of 1 golden fixture(s) failed")]
+fn test_assert_ok_panics_with_summary() {
+    let dir = scratch_dir("panic");
+    fs::write(dir.join("only.html"), "<p>x</p>").unwrap();
+    GoldenCorpus::new(&dir).update(false).run().unwrap().assert_ok();
+}
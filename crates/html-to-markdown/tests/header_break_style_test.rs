@@ -0,0 +1,77 @@
+//! Tests for `<br>` inside headings and header cells.
+
+use html_to_markdown_rs::{ConversionOptions, HeaderBreakStyle, HeadingStyle, NewlineStyle, convert};
+
+fn convert_with(html: &str, header_break_style: HeaderBreakStyle) -> String {
+    let options = ConversionOptions {
+        header_break_style,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+const TABLE: &str = "<table><tr><th>Price<br>per unit</th><th>Qty</th></tr><tr><td>1<br>2</td><td>3</td></tr></table>";
+
+#[test]
+fn test_inherit_keeps_existing_output() {
+    assert_eq!(
+        convert_with("<h2>Title<br>Sub</h2>", HeaderBreakStyle::Inherit),
+        "## Title  Sub\n"
+    );
+    assert_eq!(
+        convert_with(TABLE, HeaderBreakStyle::Inherit),
+        convert(TABLE, None).unwrap()
+    );
+}
+
+#[test]
+fn test_headings() {
+    let html = "<h2>Release notes <br/> version 2</h2>";
+    assert_eq!(
+        convert_with(html, HeaderBreakStyle::Space),
+        "## Release notes version 2\n"
+    );
+    assert_eq!(
+        convert_with(html, HeaderBreakStyle::Slash),
+        "## Release notes / version 2\n"
+    );
+    assert_eq!(
+        convert_with(html, HeaderBreakStyle::Html),
+        "## Release notes<br>version 2\n"
+    );
+
+    let options = ConversionOptions {
+        header_break_style: HeaderBreakStyle::Slash,
+        heading_style: HeadingStyle::Underlined,
+        ..Default::default()
+    };
+    assert_eq!(convert("<h1>A<br>B</h1>", Some(options)).unwrap(), "A / B\n=====\n");
+}
+
+#[test]
+fn test_leading_and_trailing_breaks_add_nothing() {
+    let html = "<h1><br>Title<br><br></h1><p>Body</p>";
+    assert_eq!(convert_with(html, HeaderBreakStyle::Slash), "# Title\n\nBody\n");
+}
+
+#[test]
+fn test_header_cells() {
+    let markdown = convert_with(TABLE, HeaderBreakStyle::Slash);
+    assert!(markdown.contains("| Price / per unit | Qty |"), "{markdown}");
+
+    let markdown = convert_with(TABLE, HeaderBreakStyle::Html);
+    assert!(markdown.contains("| Price<br>per unit | Qty |"), "{markdown}");
+    // Body cells still follow `br_in_tables`.
+    assert!(!markdown.contains("1<br>2"), "{markdown}");
+}
+
+#[test]
+fn test_header_cells_with_backslash_breaks() {
+    let options = ConversionOptions {
+        header_break_style: HeaderBreakStyle::Space,
+        newline_style: NewlineStyle::Backslash,
+        ..Default::default()
+    };
+    let markdown = convert(TABLE, Some(options)).unwrap();
+    assert!(markdown.contains("| Price per unit | Qty |"), "{markdown}");
+}
@@ -0,0 +1,61 @@
+//! Tests for `preserve_heading_ids`.
+
+use html_to_markdown_rs::{ConversionOptions, HeadingStyle, convert};
+
+const HTML: &str = r##"<h2 id="getting-started">Getting started</h2><p>See <a href="#getting-started">above</a>.</p>"##;
+
+fn convert_with(options: ConversionOptions, html: &str) -> String {
+    let options = ConversionOptions {
+        extract_metadata: false,
+        ..options
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_ids_are_dropped_by_default() {
+    let markdown = convert_with(ConversionOptions::default(), HTML);
+
+    assert!(markdown.starts_with("## Getting started\n"), "{markdown}");
+}
+
+#[test]
+fn test_anchor_precedes_heading_text() {
+    let options = ConversionOptions {
+        preserve_heading_ids: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        convert_with(options, HTML),
+        "## <a id=\"getting-started\"></a>Getting started\n\nSee [above](#getting-started).\n"
+    );
+}
+
+#[test]
+fn test_anchor_with_underlined_headings_and_escaping() {
+    let options = ConversionOptions {
+        preserve_heading_ids: true,
+        heading_style: HeadingStyle::Underlined,
+        ..Default::default()
+    };
+    let markdown = convert_with(options, r#"<h1 id="a&quot;b">Title</h1><h3>No id</h3>"#);
+
+    assert!(markdown.starts_with("<a id=\"a&quot;b\"></a>Title\n="), "{markdown}");
+    assert!(markdown.contains("### No id\n"), "{markdown}");
+}
+
+#[test]
+fn test_pandoc_attributes_use_id_block() {
+    let options = ConversionOptions {
+        preserve_heading_ids: true,
+        pandoc_attributes: true,
+        ..Default::default()
+    };
+    let markdown = convert_with(options, HTML);
+
+    assert!(
+        markdown.starts_with("## Getting started {#getting-started}\n"),
+        "{markdown}"
+    );
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "inline-images")]
+//! Tests for resolving remote `<img>` sources through an `ImageFetcher`.
+
+use std::sync::Mutex;
+
+use html_to_markdown_rs::{
+    DEFAULT_INLINE_IMAGE_LIMIT, FetchedImage, InlineImageConfig, InlineImageFormat, InlineImageSource,
+    convert_with_inline_images,
+};
+
+const PNG_1X1: &[u8] = &[
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F, 0x15, 0xC4, 0x89, 0x00, 0x00, 0x00, 0x0D, 0x49,
+    0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0xF8, 0xCF, 0xC0, 0xF0, 0x1F, 0x00, 0x05, 0x00, 0x01, 0xFF, 0x89, 0x99, 0x3D,
+    0x1D, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82,
+];
+
+#[test]
+fn test_remote_images_ignored_without_fetcher() {
+    let html = r#"<p><img src="https://example.com/logo.png" alt="Logo"></p>"#;
+    let extraction =
+        convert_with_inline_images(html, None, InlineImageConfig::new(DEFAULT_INLINE_IMAGE_LIMIT), None).unwrap();
+
+    assert!(extraction.inline_images.is_empty());
+    assert!(extraction.markdown.contains("![Logo](https://example.com/logo.png)"));
+}
+
+#[test]
+fn test_remote_image_collected_via_fetcher() {
+    let requested = std::sync::Arc::new(Mutex::new(Vec::new()));
+    let seen = requested.clone();
+    let mut config = InlineImageConfig::new(DEFAULT_INLINE_IMAGE_LIMIT).with_fetcher(move |url: &str| {
+        seen.lock().unwrap().push(url.to_string());
+        Ok(Some(FetchedImage {
+            data: PNG_1X1.to_vec(),
+            mime_type: None,
+        }))
+    });
+    config.infer_dimensions = true;
+
+    let html = r#"<p><img src="https://example.com/assets/logo.png?v=2" alt="Logo"> <img src="/local.png"></p>"#;
+    let extraction = convert_with_inline_images(html, None, config, None).unwrap();
+
+    assert_eq!(
+        *requested.lock().unwrap(),
+        vec!["https://example.com/assets/logo.png?v=2"]
+    );
+    assert_eq!(extraction.inline_images.len(), 1);
+
+    let image = &extraction.inline_images[0];
+    assert_eq!(image.source, InlineImageSource::ImgRemote);
+    assert_eq!(image.format, InlineImageFormat::Png);
+    assert_eq!(image.filename.as_deref(), Some("logo.png"));
+    assert_eq!(image.description.as_deref(), Some("Logo"));
+    assert_eq!(image.dimensions, Some((1, 1)));
+    assert!(
+        extraction
+            .markdown
+            .contains("![Logo](https://example.com/assets/logo.png?v=2)")
+    );
+}
+
+#[test]
+fn test_fetcher_mime_type_takes_precedence() {
+    let config = InlineImageConfig::new(DEFAULT_INLINE_IMAGE_LIMIT).with_fetcher(|_: &str| {
+        Ok(Some(FetchedImage {
+            data: b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>".to_vec(),
+            mime_type: Some("image/svg+xml; charset=utf-8".to_string()),
+        }))
+    });
+
+    let html = r#"<img src="https://cdn.example.com/icon" data-filename="icon.svg">"#;
+    let extraction = convert_with_inline_images(html, None, config, None).unwrap();
+
+    assert_eq!(extraction.inline_images.len(), 1);
+    assert_eq!(extraction.inline_images[0].format, InlineImageFormat::Svg);
+    assert_eq!(extraction.inline_images[0].filename.as_deref(), Some("icon.svg"));
+}
+
+#[test]
+fn test_fetcher_errors_and_misses_become_warnings() {
+    let config = InlineImageConfig::new(DEFAULT_INLINE_IMAGE_LIMIT).with_fetcher(|url: &str| {
+        if url.ends_with("missing.png") {
+            Ok(None)
+        } else {
+            Err("connection refused".to_string())
+        }
+    });
+
+    let html = r#"<img src="https://example.com/missing.png"><img src="http://example.com/broken.png">"#;
+    let extraction = convert_with_inline_images(html, None, config, None).unwrap();
+
+    assert!(extraction.inline_images.is_empty());
+    assert_eq!(extraction.warnings.len(), 1);
+    assert!(extraction.warnings[0].message.contains("connection refused"));
+}
+
+#[test]
+fn test_fetched_payload_respects_size_limit() {
+    let config = InlineImageConfig::new(16).with_fetcher(|_: &str| {
+        Ok(Some(FetchedImage {
+            data: PNG_1X1.to_vec(),
+            mime_type: Some("image/png".to_string()),
+        }))
+    });
+
+    let extraction =
+        convert_with_inline_images(r#"<img src="https://example.com/a.png">"#, None, config, None).unwrap();
+
+    assert!(extraction.inline_images.is_empty());
+    assert!(extraction.warnings[0].message.contains("exceeds configured max"));
+}
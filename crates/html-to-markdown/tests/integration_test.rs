@@ -3,7 +3,7 @@
 //! These tests verify end-to-end conversion of various HTML elements
 //! to ensure correct Markdown output.
 
-use html_to_markdown_rs::{ConversionOptions, convert};
+use html_to_markdown_rs::{ConversionError, ConversionOptions, convert, convert_many};
 
 #[test]
 fn test_basic_paragraph() {
@@ -474,3 +474,15 @@ fn test_nested_bold_issue_111() {
     let result = convert(html, None).unwrap();
     assert_eq!(result, "**bolder**\n");
 }
+
+#[test]
+fn test_convert_many_keeps_order_and_isolates_failures() {
+    let documents = ["<h1>One</h1>", "%PDF-1.7 not html", "<p>Three</p>"];
+
+    let results = convert_many(&documents, None);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap(), &convert(documents[0], None).unwrap());
+    assert!(matches!(results[1], Err(ConversionError::InvalidInput(_))));
+    assert_eq!(results[2].as_ref().unwrap(), "Three\n");
+}
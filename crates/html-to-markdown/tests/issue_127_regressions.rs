@@ -31,6 +31,11 @@ fn issue_127_options() -> ConversionOptions {
             preset: PreprocessingPreset::Minimal,
             remove_navigation: true,
             remove_forms: true,
+            apply_print_styles: false,
+            flatten_shadow_dom: false,
+            extract_main_content: false,
+            exclude_selectors: Vec::new(),
+            include_selector: None,
         },
         ..Default::default()
     }
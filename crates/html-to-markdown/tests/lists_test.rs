@@ -1,4 +1,4 @@
-use html_to_markdown_rs::{ConversionOptions, convert};
+use html_to_markdown_rs::{ConversionOptions, ListSpacing, convert};
 
 #[test]
 fn test_basic_unordered_list() {
@@ -159,6 +159,51 @@ fn test_custom_bullet_symbols() {
     assert!(result.contains("* Item 1") || result.contains("* Item 2"));
 }
 
+#[test]
+fn test_same_bullet_at_every_level() {
+    let html = "<ul><li>One<ul><li>Two<ul><li>Three</li></ul></li></ul></li></ul>";
+
+    let options = ConversionOptions {
+        bullets: "*+-".to_string(),
+        cycle_bullets: false,
+        ..Default::default()
+    };
+
+    let result = convert(html, Some(options)).unwrap();
+    assert_eq!(result, "* One\n  * Two\n    * Three\n");
+}
+
+#[test]
+fn test_ordered_markers_per_level() {
+    let html = "<ol><li>One<ol><li>Nested</li><li>Again</li></ol></li><li>Two</li></ol>";
+
+    let options = ConversionOptions {
+        ordered_markers: ".)".to_string(),
+        ..Default::default()
+    };
+
+    let result = convert(html, Some(options)).unwrap();
+    assert!(result.contains("1. One"), "{result}");
+    assert!(result.contains("1) Nested"), "{result}");
+    assert!(result.contains("2) Again"), "{result}");
+    assert!(result.contains("2. Two"), "{result}");
+}
+
+#[test]
+fn test_ordered_markers_independent_of_bullet_depth() {
+    let html = "<ul><li>Item<ol><li>Step</li></ol></li></ul>";
+
+    let options = ConversionOptions {
+        bullets: "*-".to_string(),
+        ordered_markers: ")".to_string(),
+        ..Default::default()
+    };
+
+    let result = convert(html, Some(options)).unwrap();
+    assert!(result.contains("* Item"), "{result}");
+    assert!(result.contains("1) Step"), "{result}");
+}
+
 #[test]
 fn test_empty_list_item() {
     let html = r#"<ul>
@@ -188,3 +233,65 @@ fn test_list_with_code_block() {
     assert!(result.contains("- Item with code:"));
     assert!(result.contains("fn main()"));
 }
+
+fn convert_with_spacing(html: &str, list_spacing: ListSpacing) -> String {
+    let options = ConversionOptions {
+        list_spacing,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_list_spacing_auto_follows_source() {
+    let html = "<ul><li><p>One</p></li><li>Two</li></ul><ul><li>Three</li><li>Four</li></ul>";
+    let result = convert_with_spacing(html, ListSpacing::Auto);
+    assert!(result.contains("- One\n\n- Two\n"), "{result:?}");
+    assert!(result.contains("- Three\n- Four\n"), "{result:?}");
+}
+
+#[test]
+fn test_list_spacing_tight() {
+    let html = "<ul><li><p>One</p></li><li><p>Two</p></li></ul><ol><li><p>Three</p></li><li>Four</li></ol>";
+    let result = convert_with_spacing(html, ListSpacing::Tight);
+    assert!(result.starts_with("- One\n- Two\n"), "{result:?}");
+    assert!(result.ends_with("1. Three\n2. Four\n"), "{result:?}");
+}
+
+#[test]
+fn test_list_spacing_loose() {
+    let html = "<ul><li>One</li><li>Two</li></ul><ol><li><p>Three</p></li><li>Four</li></ol>";
+    assert_eq!(
+        convert_with_spacing(html, ListSpacing::Loose),
+        "- One\n\n- Two\n\n1. Three\n\n2. Four\n"
+    );
+}
+
+#[test]
+fn test_list_spacing_tight_nested() {
+    let html = "<ul><li><p>Parent</p><ul><li><p>Child A</p></li><li><p>Child B</p></li></ul></li><li>Next</li></ul>";
+    let result = convert_with_spacing(html, ListSpacing::Tight);
+    assert!(!result.trim_end().contains("\n\n"), "{result:?}");
+    assert!(result.contains("  - Child A\n  - Child B\n"), "{result:?}");
+}
+
+#[test]
+fn test_paragraph_items_with_alternate_markers() {
+    let options = ConversionOptions {
+        bullets: "+".to_string(),
+        ordered_markers: ")".to_string(),
+        ..Default::default()
+    };
+    let html = "<ul><li><p>Bullet</p></li></ul><ol><li><p>First</p></li><li><p>Second</p></li></ol>";
+    let result = convert(html, Some(options)).unwrap();
+
+    assert!(result.contains("+ Bullet\n"), "{result:?}");
+    assert!(result.contains("1) First\n\n2) Second\n"), "{result:?}");
+}
+
+#[test]
+fn test_single_blank_line_between_list_and_paragraph() {
+    let html = "<p>Intro</p><ul><li>one</li><li>two</li></ul><p>After</p><ol><li>three</li></ol><p>End</p>";
+    let result = convert(html, None).unwrap();
+    assert_eq!(result, "Intro\n\n- one\n- two\n\nAfter\n\n1. three\n\nEnd\n");
+}
@@ -0,0 +1,76 @@
+//! Tests for Readability-style main-content extraction.
+
+use html_to_markdown_rs::{ConversionOptions, convert};
+
+fn main_content_options() -> ConversionOptions {
+    let mut options = ConversionOptions::default();
+    options.preprocessing.enabled = true;
+    options.preprocessing.extract_main_content = true;
+    options
+}
+
+const NEWS_PAGE: &str = r#"<html>
+<head><title>Example News</title></head>
+<body>
+<div class="top">
+  <a href="/">Home</a> <a href="/world">World</a> <a href="/tech">Tech</a> <a href="/sport">Sport</a>
+</div>
+<div class="layout">
+  <div class="story-body">
+    <h1>Rivers rise after a week of rain</h1>
+    <p>Rivers across the region rose sharply on Tuesday, after a week of heavy rain saturated the ground, filled reservoirs, and pushed several tributaries over their banks.</p>
+    <p>Officials said the water level at the main gauge, which is monitored hourly, was the highest recorded since 1998, and warned residents in low-lying streets to prepare.</p>
+    <p>Forecasters expect drier weather from Thursday, although they cautioned that levels downstream could keep rising for another two days.</p>
+  </div>
+  <div class="promo-links">
+    <p><a href="/a">Ten photos of cute dogs you need to see today</a></p>
+    <p><a href="/b">This one trick will change how you cook pasta forever</a></p>
+  </div>
+</div>
+<div id="comments">
+  <p>Great article, thanks for sharing, really informative and well written.</p>
+</div>
+<div class="copyright">Copyright Example News. All rights reserved.</div>
+</body>
+</html>"#;
+
+#[test]
+fn test_only_the_article_is_converted() {
+    let markdown = convert(NEWS_PAGE, Some(main_content_options())).unwrap();
+
+    assert!(markdown.contains("# Rivers rise after a week of rain\n\nRivers across the region"));
+    assert!(markdown.contains("keep rising for another two days."));
+    for chrome in ["Home", "cute dogs", "Great article", "Copyright"] {
+        assert!(!markdown.contains(chrome), "{chrome} leaked into:\n{markdown}");
+    }
+}
+
+#[test]
+fn test_split_article_keeps_scoring_siblings() {
+    let paragraph = "<p>The committee met on Monday, reviewed the budget, and agreed, after a long debate, to fund the new library, the pool, and the park.</p>";
+    let html = format!(
+        r#"<body><div class="nav-links"><a href="/">Home</a></div>
+        <div class="part">{paragraph}{paragraph}{paragraph}</div>
+        <div class="part">{paragraph}{paragraph}</div>
+        <div class="ad"><a href="/buy">Buy now</a></div></body>"#
+    );
+
+    let markdown = convert(&html, Some(main_content_options())).unwrap();
+    assert_eq!(markdown.matches("The committee met").count(), 5);
+    assert!(!markdown.contains("Buy now"));
+    assert!(!markdown.contains("Home"));
+}
+
+#[test]
+fn test_falls_back_to_whole_document() {
+    let html = "<p>Short note.</p><ul><li>One</li><li>Two</li></ul>";
+    assert_eq!(
+        convert(html, Some(main_content_options())).unwrap(),
+        convert(html, None).unwrap()
+    );
+
+    let mut options = main_content_options();
+    options.preprocessing.enabled = false;
+    let markdown = convert(NEWS_PAGE, Some(options)).unwrap();
+    assert!(markdown.contains("Great article"));
+}
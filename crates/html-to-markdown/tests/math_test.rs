@@ -0,0 +1,61 @@
+//! Tests for `math_style`.
+
+use html_to_markdown_rs::{ConversionOptions, MathStyle, convert};
+
+fn convert_with(style: MathStyle, html: &str) -> String {
+    let options = ConversionOptions {
+        math_style: style,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+const MATHML: &str = r#"<p>Let <math><msup><mi>x</mi><mn>2</mn></msup></math> be positive.</p><math display="block"><mfrac><mi>a</mi><mi>b</mi></mfrac></math><p>Done.</p>"#;
+
+#[test]
+fn test_default_keeps_mathml_comment() {
+    let markdown = convert_with(MathStyle::Comment, MATHML);
+
+    assert!(markdown.contains("<!-- MathML: <math>"), "{markdown}");
+    assert!(!markdown.contains('$'), "{markdown}");
+}
+
+#[test]
+fn test_dollar_style() {
+    assert_eq!(
+        convert_with(MathStyle::Dollar, MATHML),
+        "Let $x^{2}$ be positive.\n\n$$\n\\frac{a}{b}\n$$\n\nDone.\n"
+    );
+}
+
+#[test]
+fn test_fenced_style() {
+    assert_eq!(
+        convert_with(MathStyle::Fenced, MATHML),
+        "Let $`x^{2}`$ be positive.\n\n```math\n\\frac{a}{b}\n```\n\nDone.\n"
+    );
+}
+
+#[test]
+fn test_mathjax_scripts_and_delimiters() {
+    let html = r#"<p>Energy <script type="math/tex">E = mc^2</script> and \(p = mv\).</p>
+<script type="math/tex; mode=display">\int_0^1 x\,dx</script>
+<p>Shown as \[a^2 + b^2\] inline.</p>"#;
+
+    assert_eq!(
+        convert_with(MathStyle::Dollar, html),
+        "Energy $E = mc^2$ and $p = mv$.\n\n$$\n\\int_0^1 x\\,dx\n$$\n\nShown as $$ a^2 + b^2 $$ inline.\n"
+    );
+    assert_eq!(
+        convert_with(MathStyle::Comment, html),
+        "Energy and \\(p = mv\\).\n\nShown as \\[a^2 + b^2\\] inline.\n"
+    );
+}
+
+#[test]
+fn test_katex_uses_annotation_and_skips_rendering() {
+    let html = r#"<p>Area <span class="katex"><span class="katex-mathml"><math><semantics><mrow><mi>π</mi><msup><mi>r</mi><mn>2</mn></msup></mrow><annotation encoding="application/x-tex">\pi r^2</annotation></semantics></math></span><span class="katex-html" aria-hidden="true"><span class="mord mathnormal">π</span><span class="mord">r²</span></span></span>.</p>"#;
+
+    assert_eq!(convert_with(MathStyle::Dollar, html), "Area $\\pi r^2$.\n");
+}
@@ -0,0 +1,50 @@
+//! Tests for the `nbsp_in_prose` / `nbsp_in_tables` options.
+
+use html_to_markdown_rs::{ConversionOptions, NbspMode, convert};
+
+const NBSP: char = '\u{a0}';
+
+fn convert_with(html: &str, prose: NbspMode, tables: NbspMode) -> String {
+    let options = ConversionOptions {
+        nbsp_in_prose: prose,
+        nbsp_in_tables: tables,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_nbsp_converted_everywhere_by_default() {
+    let result = convert(
+        "<p>a&nbsp;&nbsp;b</p><table><tr><th>H&nbsp;1</th></tr><tr><td>x&nbsp;y</td></tr></table>",
+        None,
+    )
+    .unwrap();
+    assert!(!result.contains(NBSP), "{result:?}");
+    assert!(result.starts_with("a b\n"));
+    assert!(result.contains("| H 1 |"));
+}
+
+#[test]
+fn test_nbsp_preserved_in_tables_only() {
+    let html = "<p>a&nbsp;b</p><table><tr><th>Name</th></tr><tr><td>&nbsp;&nbsp;indented</td></tr></table>";
+    let result = convert_with(html, NbspMode::Space, NbspMode::Preserve);
+    assert!(result.starts_with("a b\n"), "{result:?}");
+    assert!(result.contains(&format!("| {NBSP}{NBSP}indented |")), "{result:?}");
+}
+
+#[test]
+fn test_nbsp_preserved_in_prose() {
+    let result = convert_with(
+        "<p>10&nbsp;km and&nbsp;&nbsp;more</p>",
+        NbspMode::Preserve,
+        NbspMode::Space,
+    );
+    assert_eq!(result, format!("10{NBSP}km and{NBSP}{NBSP}more\n"));
+}
+
+#[test]
+fn test_nbsp_in_code_untouched() {
+    let result = convert("<p><code>a&nbsp;b</code></p>", None).unwrap();
+    assert!(result.contains(&format!("`a{NBSP}b`")), "{result:?}");
+}
@@ -0,0 +1,64 @@
+//! Tests for `<canvas>`, `<object>` and `<embed>` fallback content and `object_links`.
+
+use html_to_markdown_rs::{ConversionOptions, convert};
+
+fn convert_with(object_links: bool, html: &str) -> String {
+    let options = ConversionOptions {
+        object_links,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_canvas_fallback_is_converted() {
+    let html = r#"<canvas width="300" height="150"><p>Your browser lacks <a href="/chart.png">canvas</a> support.</p></canvas>"#;
+
+    assert_eq!(
+        convert_with(false, html),
+        "Your browser lacks [canvas](/chart.png) support.\n"
+    );
+}
+
+#[test]
+fn test_object_fallback_without_links_by_default() {
+    let html = r#"<object data="/report.pdf" type="application/pdf"><p>Quarterly <strong>report</strong></p></object>"#;
+
+    assert_eq!(convert_with(false, html), "Quarterly **report**\n");
+}
+
+#[test]
+fn test_object_link_follows_block_fallback() {
+    let html = r#"<object data="/files/report.pdf?v=2" type="application/pdf"><p>Quarterly <strong>report</strong></p></object>"#;
+
+    assert_eq!(
+        convert_with(true, html),
+        "Quarterly **report**\n\n[report.pdf](/files/report.pdf?v=2)\n"
+    );
+}
+
+#[test]
+fn test_object_link_follows_inline_fallback() {
+    let html = r#"<p>See <object data="/intro.mp4" title="Intro video">a video</object>.</p>"#;
+
+    assert_eq!(convert_with(true, html), "See a video [Intro video](/intro.mp4).\n");
+}
+
+#[test]
+fn test_embed_without_fallback_becomes_link() {
+    let html = r#"<p>Map: <embed src="https://maps.example.com/" type="text/html"></p>"#;
+
+    assert_eq!(
+        convert_with(true, html),
+        "Map: [maps.example.com](https://maps.example.com/)\n"
+    );
+    assert_eq!(convert_with(false, html), "Map:\n");
+}
+
+#[test]
+fn test_fallback_linking_the_same_url_is_not_duplicated() {
+    let html = r#"<object data="/doc.pdf"><p>Download <a href="/doc.pdf">the PDF</a></p></object>"#;
+
+    assert_eq!(convert_with(true, html), "Download [the PDF](/doc.pdf)\n");
+}
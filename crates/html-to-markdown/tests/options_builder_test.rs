@@ -0,0 +1,120 @@
+//! Tests for `ConversionOptions::builder()` and option validation.
+
+use html_to_markdown_rs::{
+    BuiltinPostProcessor, CodeBlockStyle, ConversionError, ConversionOptions, ConversionOptionsBuilder, HeadingStyle,
+    PostProcessorChain, convert, option_char,
+};
+
+fn config_error(builder: ConversionOptionsBuilder) -> String {
+    match builder.build() {
+        Err(ConversionError::ConfigError(message)) => message,
+        other => panic!("expected a config error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_builder_defaults_match_default_options() {
+    let built = ConversionOptions::builder().build().unwrap();
+    assert_eq!(format!("{built:?}"), format!("{:?}", ConversionOptions::default()));
+}
+
+#[test]
+fn test_builder_sets_fields() {
+    let mut chain = PostProcessorChain::new();
+    chain.push_builtin(BuiltinPostProcessor::CompactBlankLines);
+    let options = ConversionOptions::builder()
+        .heading_style(HeadingStyle::Underlined)
+        .code_block_style(CodeBlockStyle::Tildes)
+        .code_language("rust")
+        .strip_tags(["span", "font"])
+        .preserve_tags(vec![String::from("table")])
+        .ordered_markers(")")
+        .post_processors(chain)
+        .build()
+        .unwrap();
+
+    assert_eq!(options.heading_style, HeadingStyle::Underlined);
+    assert_eq!(options.code_language, "rust");
+    assert_eq!(options.strip_tags, ["span", "font"]);
+    assert_eq!(options.preserve_tags, ["table"]);
+    assert_eq!(
+        options.post_processors.names().collect::<Vec<_>>(),
+        ["compact-blank-lines"]
+    );
+
+    let markdown = convert("<h1>Title</h1><ol><li>one</li></ol>", Some(options)).unwrap();
+    assert_eq!(markdown, "Title\n=====\n\n1) one\n");
+}
+
+#[test]
+fn test_builder_rejects_invalid_values() {
+    let message = config_error(ConversionOptions::builder().wrap(true).wrap_width(10));
+    assert_eq!(message, "wrap_width must be at least 20, got 10");
+    assert!(config_error(ConversionOptions::builder().bullets("")).contains("bullets"));
+    assert!(config_error(ConversionOptions::builder().strong_em_symbol('~')).contains("strong_em_symbol"));
+    assert!(config_error(ConversionOptions::builder().ordered_markers("")).contains("ordered_markers"));
+    assert!(config_error(ConversionOptions::builder().ordered_markers(".-")).contains("ordered_markers"));
+
+    assert!(
+        ConversionOptions::builder()
+            .wrap_width(ConversionOptionsBuilder::MIN_WRAP_WIDTH)
+            .build()
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_validate_reports_every_violation() {
+    assert!(ConversionOptions::default().validate().is_ok());
+
+    let options = ConversionOptions {
+        bullets: String::new(),
+        strong_em_symbol: '~',
+        wrap: true,
+        wrap_width: 0,
+        sup_symbol: "</sup>".to_string(),
+        ..Default::default()
+    };
+    let fields: Vec<_> = options.violations().iter().map(|violation| violation.field).collect();
+    assert_eq!(fields, ["bullets", "strong_em_symbol", "wrap_width", "sup_symbol"]);
+
+    match options.validate() {
+        Err(ConversionError::ConfigError(message)) => assert_eq!(
+            message,
+            "bullets cannot be empty; strong_em_symbol must be '*' or '_', got '~'; \
+             wrap_width must be at least 1 when wrap is enabled; \
+             sup_symbol must be a single opening tag such as <sup>, got \"</sup>\""
+        ),
+        other => panic!("expected a config error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_validate_accepts_symbols_and_narrow_wraps() {
+    let options = ConversionOptions {
+        wrap: true,
+        wrap_width: 10,
+        sub_symbol: "<sub>".to_string(),
+        sup_symbol: "^".to_string(),
+        ..Default::default()
+    };
+    assert!(options.validate().is_ok());
+
+    let options = ConversionOptions {
+        sub_symbol: "~\n".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(options.violations()[0].message, "sub_symbol cannot contain line breaks");
+}
+
+#[test]
+fn test_option_char() {
+    assert_eq!(option_char("strong_em_symbol", "_").unwrap(), '_');
+    for value in ["", "**"] {
+        let err = option_char("strong_em_symbol", value).unwrap_err();
+        assert!(
+            err.to_string().contains("strong_em_symbol must be a single character"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! Tests for serializing `ConversionOptions` and the options JSON Schema.
+
+use html_to_markdown_rs::{
+    ConversionOptions, ConversionOptionsUpdate, HeadingStyle, PostProcessorChain, PreprocessingPreset, TitleSource,
+    conversion_options_from_json, conversion_options_json_schema,
+};
+use serde_json::Value;
+
+fn round_trip(options: &ConversionOptions) -> ConversionOptions {
+    let json = serde_json::to_string(options).unwrap();
+    conversion_options_from_json(&json).unwrap()
+}
+
+#[test]
+fn test_default_options_round_trip() {
+    let options = ConversionOptions::default();
+    assert_eq!(format!("{:?}", round_trip(&options)), format!("{options:?}"));
+}
+
+#[test]
+fn test_customized_options_round_trip() {
+    let mut options = ConversionOptions::builder()
+        .heading_style(HeadingStyle::AtxClosed)
+        .strong_em_symbol('_')
+        .wrap(true)
+        .wrap_width(60)
+        .strip_tags(["font"])
+        .ordered_markers(".)")
+        .title_fallback([TitleSource::UrlBasename])
+        .post_processors(PostProcessorChain::from_names(&["toc", "compact-blank-lines"]))
+        .build()
+        .unwrap();
+    options.preprocessing.enabled = true;
+    options.preprocessing.preset = PreprocessingPreset::Aggressive;
+
+    let restored = round_trip(&options);
+    assert_eq!(format!("{restored:?}"), format!("{options:?}"));
+
+    let value = serde_json::to_value(&options).unwrap();
+    assert_eq!(value["headingStyle"], "atx_closed");
+    assert_eq!(
+        value["postProcessors"],
+        serde_json::json!(["toc", "compact-blank-lines"])
+    );
+    assert_eq!(value["preprocessing"]["preset"], "aggressive");
+}
+
+#[test]
+fn test_deserialize_partial_options_uses_defaults() {
+    let options: ConversionOptions =
+        serde_json::from_str(r#"{"bullets": "+", "preprocessing": {"enabled": true}}"#).unwrap();
+    assert_eq!(options.bullets, "+");
+    assert!(options.preprocessing.enabled);
+    assert!(options.preprocessing.remove_forms);
+    assert_eq!(options.heading_style, HeadingStyle::Atx);
+}
+
+/// Checks that every serialized option has a described schema property with a matching default.
+fn check_schema(value: &Value, schema: &Value, path: &str) {
+    let properties = schema["properties"].as_object().unwrap();
+    let fields = value.as_object().unwrap();
+    assert_eq!(properties.len(), fields.len(), "{path}: schema and options disagree");
+    for (key, field) in fields {
+        let property = properties
+            .get(key)
+            .unwrap_or_else(|| panic!("{path}{key} missing from schema"));
+        assert_eq!(property.get("default"), Some(field), "{path}{key}");
+        assert!(property["description"].is_string(), "{path}{key}");
+        if let Some(allowed) = property["enum"].as_array() {
+            assert!(allowed.contains(field), "{path}{key}: default not among {allowed:?}");
+        }
+        if property["type"] == "object" {
+            check_schema(field, property, &format!("{path}{key}."));
+        }
+    }
+}
+
+#[test]
+fn test_schema_describes_every_serialized_option() {
+    let schema = conversion_options_json_schema();
+    assert_eq!(schema["$schema"], "https://json-schema.org/draft/2020-12/schema");
+    check_schema(
+        &serde_json::to_value(ConversionOptions::default()).unwrap(),
+        &schema,
+        "",
+    );
+}
+
+#[test]
+fn test_schema_enum_values_are_accepted() {
+    let schema = conversion_options_json_schema();
+    for (key, property) in schema["properties"].as_object().unwrap() {
+        let Some(allowed) = property["enum"].as_array() else {
+            continue;
+        };
+        for value in allowed {
+            let json = serde_json::json!({ key.as_str(): value }).to_string();
+            let options = conversion_options_from_json(&json).unwrap();
+            assert_eq!(&serde_json::to_value(&options).unwrap()[key], value, "{key}");
+        }
+    }
+}
+
+#[test]
+fn test_strict_json_reports_unknown_fields_with_suggestions() {
+    let err = ConversionOptionsUpdate::from_json_strict(
+        r#"{"headingStlye": "atx", "wrap": true, "frobnicate": 1, "preprocessing": {"removeNavigaton": false}}"#,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.starts_with("Invalid configuration: "), "{err}");
+    assert!(
+        err.contains("unknown option headingStlye (did you mean headingStyle?)"),
+        "{err}"
+    );
+    assert!(
+        err.contains("unknown option frobnicate;") || err.ends_with("unknown option frobnicate"),
+        "{err}"
+    );
+    assert!(
+        err.contains("unknown option preprocessing.removeNavigaton (did you mean preprocessing.removeNavigation?)"),
+        "{err}"
+    );
+    assert_eq!(err.matches("; ").count(), 2, "{err}");
+}
+
+#[test]
+fn test_strict_json_reports_every_type_error() {
+    let err = ConversionOptionsUpdate::from_json_strict(
+        r#"{"wrapWidth": "80", "headingStyle": "banner", "preprocessing": {"enabled": "yes"}}"#,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("wrapWidth: invalid type: string \"80\""), "{err}");
+    assert!(
+        err.contains("headingStyle: unknown value \"banner\", expected one of "),
+        "{err}"
+    );
+    assert!(
+        err.contains("preprocessing.enabled: invalid type: string \"yes\""),
+        "{err}"
+    );
+    assert_eq!(err.matches("; ").count(), 2, "{err}");
+}
+
+#[test]
+fn test_strict_json_accepts_known_fields() {
+    let update = ConversionOptionsUpdate::from_json_strict(
+        r#"{"headingStyle": "atx_closed", "wrapWidth": 60, "preprocessing": {"enabled": true}}"#,
+    )
+    .unwrap();
+    let options = ConversionOptions::from(update);
+    assert_eq!(options.heading_style, HeadingStyle::AtxClosed);
+    assert_eq!(options.wrap_width, 60);
+    assert!(options.preprocessing.enabled);
+}
+
+#[test]
+fn test_strict_json_can_be_relaxed_per_object() {
+    let update =
+        ConversionOptionsUpdate::from_json_strict(r#"{"strictOptions": false, "headingStlye": "atx", "wrap": true}"#)
+            .unwrap();
+    assert_eq!(update.wrap, Some(true));
+
+    let err = ConversionOptionsUpdate::from_json_strict(r#"{"strictOptions": false, "wrap": "yes"}"#).unwrap_err();
+    assert!(err.to_string().contains("wrap: invalid type"), "{err}");
+}
+
+#[test]
+fn test_strict_json_field_names_match_schema() {
+    let schema = conversion_options_json_schema();
+    let mut properties: Vec<&str> = schema["properties"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    let mut names = ConversionOptionsUpdate::field_names().to_vec();
+    properties.sort_unstable();
+    names.sort_unstable();
+    assert_eq!(names, properties);
+}
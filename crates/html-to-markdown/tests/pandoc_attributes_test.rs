@@ -0,0 +1,81 @@
+//! Tests for Pandoc attribute output.
+
+use html_to_markdown_rs::{CodeBlockStyle, ConversionOptions, HeadingStyle, convert};
+
+fn pandoc_options() -> ConversionOptions {
+    ConversionOptions {
+        pandoc_attributes: true,
+        extract_metadata: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_heading_attributes() {
+    let html = r#"<h1 id="intro" class="lead title">Introduction</h1><h3 class="note">Aside</h3><h2>Plain</h2>"#;
+
+    assert_eq!(
+        convert(html, Some(pandoc_options())).unwrap(),
+        "# Introduction {#intro .lead .title}\n\n### Aside {.note}\n\n## Plain\n"
+    );
+
+    let closed = ConversionOptions {
+        heading_style: HeadingStyle::AtxClosed,
+        ..pandoc_options()
+    };
+    assert_eq!(
+        convert(r#"<h2 id="setup">Setup</h2>"#, Some(closed)).unwrap(),
+        "## Setup ## {#setup}\n"
+    );
+
+    let underlined = ConversionOptions {
+        heading_style: HeadingStyle::Underlined,
+        ..pandoc_options()
+    };
+    assert_eq!(
+        convert(r#"<h1 id="top">Top</h1>"#, Some(underlined)).unwrap(),
+        "Top {#top}\n===\n"
+    );
+}
+
+#[test]
+fn test_link_and_image_attributes() {
+    let html = r#"<p><a href="/docs" id="docs-link" class="external">Docs</a> <img src="chart.png" alt="Chart" class="wide" width="50%" height="200"> <img src="a.png" alt="A" data-x="1"></p>"#;
+
+    assert_eq!(
+        convert(html, Some(pandoc_options())).unwrap(),
+        "[Docs](/docs){#docs-link .external} ![Chart](chart.png){.wide width=50% height=200} ![A](a.png)\n"
+    );
+}
+
+#[test]
+fn test_code_block_attributes() {
+    let options = ConversionOptions {
+        code_block_style: CodeBlockStyle::Backticks,
+        ..pandoc_options()
+    };
+
+    assert_eq!(
+        convert(
+            r#"<pre id="main" class="numberLines"><code class="language-rust">fn main() {}</code></pre>"#,
+            Some(options.clone())
+        )
+        .unwrap(),
+        "```{#main .rust .numberLines}\nfn main() {}\n```\n"
+    );
+    assert_eq!(
+        convert(r#"<pre><code class="lang-python">pass</code></pre>"#, Some(options)).unwrap(),
+        "```python\npass\n```\n"
+    );
+}
+
+#[test]
+fn test_attributes_are_off_by_default() {
+    let html = r#"<h1 id="intro">Introduction</h1><p><a href="/x" class="c">X</a></p>"#;
+    let options = ConversionOptions {
+        extract_metadata: false,
+        ..Default::default()
+    };
+
+    assert_eq!(convert(html, Some(options)).unwrap(), "# Introduction\n\n[X](/x)\n");
+}
@@ -0,0 +1,54 @@
+//! Tests for `convert_parallel` (the `parallel` feature).
+
+#![cfg(feature = "parallel")]
+
+use html_to_markdown_rs::{ConversionError, ConversionOptions, HeadingStyle, convert, convert_many, convert_parallel};
+
+fn documents(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|i| format!("<h1>Doc {i}</h1><p>Paragraph <strong>{i}</strong></p><ul><li>a</li><li>b</li></ul>"))
+        .collect()
+}
+
+#[test]
+fn test_matches_sequential_conversion() {
+    let docs = documents(64);
+    let options = ConversionOptions {
+        heading_style: HeadingStyle::Underlined,
+        ..Default::default()
+    };
+
+    let parallel = convert_parallel(&docs, Some(options.clone()));
+    let sequential = convert_many(&docs, Some(options));
+
+    assert_eq!(parallel.len(), docs.len());
+    for (parallel, sequential) in parallel.iter().zip(&sequential) {
+        assert_eq!(parallel.as_ref().unwrap(), sequential.as_ref().unwrap());
+    }
+}
+
+#[test]
+fn test_results_keep_input_order() {
+    let docs = documents(200);
+    let results = convert_parallel(&docs, None);
+
+    for (i, result) in results.iter().enumerate() {
+        assert!(result.as_ref().unwrap().starts_with(&format!("# Doc {i}\n")));
+    }
+}
+
+#[test]
+fn test_failures_are_per_document() {
+    let docs = ["<p>ok</p>", "PK\u{3}\u{4}zip", "<p>also ok</p>"];
+    let results = convert_parallel(&docs, None);
+
+    assert_eq!(results[0].as_deref().unwrap(), convert(docs[0], None).unwrap());
+    assert!(matches!(results[1], Err(ConversionError::InvalidInput(_))));
+    assert_eq!(results[2].as_deref().unwrap(), "also ok\n");
+}
+
+#[test]
+fn test_empty_batch() {
+    let docs: [&str; 0] = [];
+    assert!(convert_parallel(&docs, None).is_empty());
+}
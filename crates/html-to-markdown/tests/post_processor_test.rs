@@ -0,0 +1,192 @@
+//! Tests for the `post_processors` chain.
+
+use html_to_markdown_rs::{
+    BuiltinPostProcessor, ConversionError, ConversionOptions, ConversionOptionsUpdate, PostProcessorChain, convert,
+};
+
+fn convert_with(html: &str, post_processors: PostProcessorChain) -> String {
+    let options = ConversionOptions {
+        post_processors,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+fn builtin(names: &[&str]) -> PostProcessorChain {
+    PostProcessorChain::from_names(names)
+}
+
+#[test]
+fn test_default_chain_leaves_output_unchanged() {
+    let html = "<h1>Title</h1><p><a href=\"https://example.com\">link</a></p>";
+    assert_eq!(
+        convert(html, None).unwrap(),
+        convert_with(html, PostProcessorChain::default())
+    );
+}
+
+#[test]
+fn test_reference_links_numbers_and_deduplicates() {
+    let html = concat!(
+        "<p>See <a href=\"https://a.example\">one</a>, <a href=\"https://b.example\" title=\"B\">two</a> ",
+        "and <a href=\"https://a.example\">one again</a>.</p>",
+        "<p><img src=\"pic.png\" alt=\"pic\"> <code>[x](y)</code> <a href=\"#top\">top</a></p>",
+        "<pre><code>[in](code)</code></pre>"
+    );
+    let markdown = convert_with(html, builtin(&["reference-links"]));
+    assert_eq!(
+        markdown,
+        concat!(
+            "See [one][1], [two][2] and [one again][1].\n\n",
+            "![pic](pic.png) `[x](y)` [top](#top)\n\n",
+            "    [in](code)\n\n",
+            "[1]: https://a.example\n",
+            "[2]: https://b.example \"B\"\n"
+        )
+    );
+}
+
+#[test]
+fn test_toc_lists_headings_with_unique_slugs() {
+    let html = "<h1>Guide</h1><h2>Install</h2><p>x</p><h2>Usage &amp; Tips</h2><h3>Install</h3>";
+    let markdown = convert_with(html, builtin(&["toc"]));
+    assert_eq!(
+        markdown,
+        concat!(
+            "- [Guide](#guide)\n",
+            "  - [Install](#install)\n",
+            "  - [Usage & Tips](#usage--tips)\n",
+            "    - [Install](#install-1)\n\n",
+            "# Guide\n\n## Install\n\nx\n\n## Usage & Tips\n\n### Install\n"
+        )
+    );
+}
+
+#[test]
+fn test_toc_goes_after_front_matter() {
+    let html = "<html><head><title>Doc</title></head><body><h2>Intro</h2></body></html>";
+    let markdown = convert_with(html, builtin(&["toc"]));
+    assert!(
+        markdown.starts_with("---\ntitle: Doc\n---\n\n- [Intro](#intro)\n\n## Intro"),
+        "{markdown}"
+    );
+}
+
+#[test]
+fn test_prune_empty_elements() {
+    let html = "<h2> </h2><p>Keep <a href=\"https://x.example\"></a>this.</p><ul><li></li><li>item</li></ul>";
+    let markdown = convert_with(html, builtin(&["prune-empty-elements"]));
+    assert_eq!(markdown, "Keep this.\n\n- item\n");
+}
+
+#[test]
+fn test_compact_blank_lines_keeps_code_blocks() {
+    let chain = {
+        let mut chain = PostProcessorChain::new();
+        chain
+            .push_fn("pad", |markdown: &str| markdown.replace("\n\n", "\n\n\n\n"))
+            .push_builtin(BuiltinPostProcessor::CompactBlankLines);
+        chain
+    };
+    let html = "<p>a</p><pre><code>x\n\n\n\ny</code></pre><p>b</p>";
+    let markdown = convert_with(html, chain);
+    assert_eq!(markdown, "a\n\n    x\n\n\n\n\n\n\n\n    y\n\nb\n");
+}
+
+#[test]
+fn test_custom_steps_run_in_order_and_can_be_disabled() {
+    let mut chain = PostProcessorChain::new();
+    chain
+        .push_fn("exclaim", |markdown: &str| markdown.replace('.', "!"))
+        .push_fn("shout", |markdown: &str| markdown.to_uppercase())
+        .push_builtin(BuiltinPostProcessor::TableOfContents);
+    assert_eq!(chain.names().collect::<Vec<_>>(), ["exclaim", "shout", "toc"]);
+
+    assert!(chain.set_enabled("toc", false));
+    assert!(!chain.set_enabled("missing", false));
+    assert_eq!(convert_with("<h1>Hi.</h1>", chain.clone()), "# HI!\n");
+
+    chain.set_enabled("shout", false);
+    assert_eq!(convert_with("<h1>Hi.</h1>", chain), "# Hi!\n");
+}
+
+#[test]
+fn test_post_processors_run_before_wrapping() {
+    let mut chain = PostProcessorChain::new();
+    chain.push_fn("long", |markdown: &str| markdown.repeat(12).replace('\n', " "));
+    let options = ConversionOptions {
+        post_processors: chain,
+        wrap: true,
+        wrap_width: 20,
+        ..Default::default()
+    };
+    let markdown = convert("word", Some(options)).unwrap();
+    assert!(markdown.lines().all(|line| line.len() <= 20), "{markdown}");
+}
+
+#[test]
+fn test_unknown_name_is_a_config_error() {
+    let options = ConversionOptions::from_update(ConversionOptionsUpdate {
+        post_processors: Some(vec!["toc".to_string(), "spellcheck".to_string()]),
+        ..Default::default()
+    });
+    let err = convert("<p>x</p>", Some(options)).unwrap_err();
+    assert!(matches!(err, ConversionError::ConfigError(ref message) if message.contains("spellcheck")));
+}
+
+#[test]
+fn test_normalize_urls_strips_tracking_params_and_trailing_slashes() {
+    let html = concat!(
+        r#"<p><a href="HTTPS://Example.COM/docs/?utm_source=x&amp;page=2&amp;fbclid=abc#intro">Docs</a> "#,
+        r#"<a href="https://example.com/?utm_medium=mail">Home</a> "#,
+        r#"<a href="/local/?gclid=1">Local</a> "#,
+        r#"<img src="https://cdn.example.com/a.png?UTM_Campaign=1&w=200" alt="A"></p>"#,
+    );
+    let markdown = convert_with(html, builtin(&["normalize-urls"]));
+    assert_eq!(
+        markdown,
+        "[Docs](https://example.com/docs?page=2#intro) [Home](https://example.com/) [Local](/local/) ![A](https://cdn.example.com/a.png?w=200)\n"
+    );
+}
+
+#[test]
+fn test_normalize_urls_before_reference_links_deduplicates() {
+    let html = r#"<p><a href="https://example.com/a?utm_source=one">One</a> and <a href="https://example.com/a/?utm_source=two">Two</a></p>"#;
+    let markdown = convert_with(html, builtin(&["normalize-urls", "reference-links"]));
+    assert_eq!(markdown, "[One][1] and [Two][1]\n\n[1]: https://example.com/a\n");
+}
+
+#[test]
+fn test_normalize_urls_allow_and_deny_lists() {
+    let html = r#"<p><a href="https://example.com/search?q=rust&amp;ref=nav&amp;sessionid=9">Search</a></p>"#;
+
+    let options = ConversionOptions {
+        post_processors: builtin(&["normalize-urls"]),
+        url_param_denylist: vec!["ref".to_string(), "session*".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        convert(html, Some(options)).unwrap(),
+        "[Search](https://example.com/search?q=rust)\n"
+    );
+
+    let options = ConversionOptions {
+        post_processors: builtin(&["normalize-urls"]),
+        url_param_allowlist: vec!["sessionid".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        convert(html, Some(options)).unwrap(),
+        "[Search](https://example.com/search?sessionid=9)\n"
+    );
+}
+
+#[test]
+fn test_normalize_urls_leaves_code_alone() {
+    let html =
+        "<p><code>[x](https://example.com/?utm_source=a)</code></p><pre>[y](https://example.com/?utm_source=b)</pre>";
+    assert_eq!(
+        convert_with(html, builtin(&["normalize-urls"])),
+        convert_with(html, PostProcessorChain::default())
+    );
+}
@@ -0,0 +1,53 @@
+//! Tests for the `preserved_attributes` allowlist on HTML kept in the output.
+
+use html_to_markdown_rs::{ConversionOptions, convert};
+
+fn preserving_tables(preserved_attributes: &[&str]) -> ConversionOptions {
+    ConversionOptions::builder()
+        .preserve_tags(["table"])
+        .preserved_attributes(preserved_attributes.iter().copied())
+        .build()
+        .unwrap()
+}
+
+const TABLE: &str = r#"<table class="grid" data-row="1" style="color: red; text-align: center; width: 50%"><tr><td colspan="2" onclick="go()" style="background: url(x.png)">Cell</td></tr></table>"#;
+
+#[test]
+fn test_all_attributes_kept_by_default() {
+    let markdown = convert(TABLE, Some(preserving_tables(&[]))).unwrap();
+
+    assert!(markdown.contains(r#"class="grid""#), "{markdown}");
+    assert!(markdown.contains(r#"data-row="1""#), "{markdown}");
+    assert!(markdown.contains("width: 50%"), "{markdown}");
+}
+
+#[test]
+fn test_only_listed_attributes_kept() {
+    let markdown = convert(TABLE, Some(preserving_tables(&["colspan", "ROWSPAN"]))).unwrap();
+
+    assert!(markdown.contains(r#"<td colspan="2">Cell</td>"#), "{markdown}");
+    assert!(markdown.contains("<table><tr>"), "{markdown}");
+    assert!(!markdown.contains("style"), "{markdown}");
+}
+
+#[test]
+fn test_style_properties_filtered() {
+    let markdown = convert(TABLE, Some(preserving_tables(&["colspan", "style:text-align"]))).unwrap();
+
+    assert!(markdown.contains(r#"<table style="text-align: center">"#), "{markdown}");
+    assert!(markdown.contains(r#"<td colspan="2">Cell</td>"#), "{markdown}");
+}
+
+#[test]
+fn test_fidelity_guard_output_filtered() {
+    let html = r#"<table class="layout" align="center"><tr><td rowspan="2" class="a">A</td><td>B</td></tr><tr><td>C</td></tr></table>"#;
+    let options = ConversionOptions::builder()
+        .fidelity_threshold(0.0)
+        .preserved_attributes(["rowspan", "align"])
+        .build()
+        .unwrap();
+    let markdown = convert(html, Some(options)).unwrap();
+
+    assert!(markdown.contains(r#"<table align="center">"#), "{markdown}");
+    assert!(markdown.contains(r#"<td rowspan="2">A</td>"#), "{markdown}");
+}
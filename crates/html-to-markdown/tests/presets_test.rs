@@ -0,0 +1,76 @@
+//! Tests for the named option presets.
+
+use html_to_markdown_rs::{
+    CodeBlockStyle, ConversionError, ConversionOptions, ConversionPreset, HighlightStyle, PreprocessingPreset, convert,
+};
+
+#[test]
+fn test_preset_names_round_trip() {
+    for preset in [
+        ConversionPreset::Chat,
+        ConversionPreset::Docs,
+        ConversionPreset::Scraping,
+        ConversionPreset::Llm,
+    ] {
+        assert_eq!(ConversionPreset::parse(preset.name()), Some(preset));
+    }
+    assert_eq!(ConversionPreset::parse("LLM"), Some(ConversionPreset::Llm));
+    assert_eq!(ConversionPreset::parse("blog"), None);
+}
+
+#[test]
+fn test_every_preset_validates() {
+    for name in ["chat", "docs", "scraping", "llm"] {
+        let options = ConversionOptions::preset(name).unwrap();
+        assert!(options.validate().is_ok(), "{name} preset is invalid");
+    }
+}
+
+#[test]
+fn test_unknown_preset_lists_the_names() {
+    match ConversionOptions::preset("blog") {
+        Err(ConversionError::ConfigError(message)) => {
+            assert_eq!(
+                message,
+                "unknown preset 'blog', expected one of chat, docs, scraping, llm"
+            );
+        }
+        other => panic!("expected a config error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_llm_preset_bundles_its_flags() {
+    let options = ConversionOptions::preset("llm").unwrap();
+
+    assert!(options.preprocessing.enabled);
+    assert_eq!(options.preprocessing.preset, PreprocessingPreset::Aggressive);
+    assert!(options.preprocessing.extract_main_content);
+    assert!(!options.br_in_tables);
+    assert!(!options.escape_asterisks && !options.escape_underscores && !options.escape_misc);
+    assert_eq!(options.highlight_style, HighlightStyle::None);
+    assert!(options.wrap);
+    assert_eq!(options.wrap_width, 100);
+    assert!(!options.extract_metadata);
+}
+
+#[test]
+fn test_preset_fields_can_be_overridden() {
+    let mut options = ConversionPreset::Docs.options();
+    assert_eq!(options.code_block_style, CodeBlockStyle::Backticks);
+    assert!(options.preserve_heading_ids);
+
+    options.code_block_style = CodeBlockStyle::Tildes;
+    let markdown = convert("<pre><code>let x = 1;</code></pre>", Some(options)).unwrap();
+    assert!(markdown.starts_with("~~~"), "{markdown}");
+}
+
+#[test]
+fn test_llm_preset_converts_without_front_matter_or_escapes() {
+    let html = "<html><head><title>Guide</title></head><body><main><h1>Guide</h1>\
+                <p>Use <mark>snake_case</mark> names like my_var * 2.</p></main></body></html>";
+    let markdown = convert(html, Some(ConversionOptions::preset("llm").unwrap())).unwrap();
+
+    assert!(!markdown.starts_with("---"), "{markdown}");
+    assert!(markdown.contains("snake_case names like my_var * 2."), "{markdown}");
+}
@@ -0,0 +1,58 @@
+//! Tests for applying print stylesheet hints during preprocessing.
+
+use html_to_markdown_rs::{ConversionOptions, convert};
+
+fn print_options() -> ConversionOptions {
+    let mut options = ConversionOptions::default();
+    options.preprocessing.enabled = true;
+    options.preprocessing.apply_print_styles = true;
+    options
+}
+
+const PAGE: &str = r#"<html>
+<head>
+<style>
+  .byline { color: gray }
+  @media print {
+    .share, #comments { display: none }
+    .promo.keep { display: block }
+  }
+</style>
+<style media="print">.promo { display: none }</style>
+</head>
+<body>
+<h1>Title</h1>
+<p class="byline">By Jane</p>
+<div class="share">Share on social</div>
+<div class="promo">Print promo</div>
+<div class="promo keep">Kept promo</div>
+<p>Body text</p>
+<div class="d-none d-print-block">Printed on 2024-01-01</div>
+<span class="no-print">Click to subscribe</span>
+<section id="comments"><p>First!</p></section>
+</body>
+</html>"#;
+
+#[test]
+fn test_print_hidden_elements_are_dropped() {
+    let markdown = convert(PAGE, Some(print_options())).unwrap();
+
+    assert_eq!(
+        markdown,
+        "# Title\n\nBy Jane\n\nKept promo\n\nBody text\n\nPrinted on 2024-01-01\n"
+    );
+}
+
+#[test]
+fn test_print_styles_require_the_option() {
+    let mut options = print_options();
+    options.preprocessing.apply_print_styles = false;
+    let markdown = convert(PAGE, Some(options)).unwrap();
+    assert!(markdown.contains("Share on social"));
+    assert!(markdown.contains("First!"));
+
+    let mut options = print_options();
+    options.preprocessing.enabled = false;
+    let markdown = convert(PAGE, Some(options)).unwrap();
+    assert!(markdown.contains("Click to subscribe"));
+}
@@ -0,0 +1,80 @@
+//! Tests for `convert_with_progress`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use html_to_markdown_rs::{ProgressEvent, ProgressInterval, convert, convert_with_progress};
+
+fn record(html: &str, interval: usize) -> (String, Vec<ProgressEvent>) {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&events);
+    let markdown = convert_with_progress(html, None, interval, move |event| sink.borrow_mut().push(event)).unwrap();
+    let events = events.borrow().clone();
+    (markdown, events)
+}
+
+#[test]
+fn test_output_matches_convert() {
+    let html = "<h1>Title</h1><ul><li>one</li><li>two</li></ul><p>Text with <a href=\"/x\">link</a></p>";
+
+    assert_eq!(record(html, 2).0, convert(html, None).unwrap());
+}
+
+#[test]
+fn test_events_start_at_zero_and_end_done() {
+    let (_, events) = record("<div><p>a</p><p>b</p><p>c</p></div>", 1);
+
+    let first = events.first().unwrap();
+    assert_eq!((first.nodes_processed, first.bytes_processed), (0, 0));
+    assert!(first.total_nodes > 0 && first.total_bytes > 0);
+
+    let last = events.last().unwrap();
+    assert!(last.is_done());
+    assert!((last.fraction() - 1.0).abs() < f64::EPSILON);
+    assert!(events[1..events.len() - 1].iter().all(|event| !event.is_done()));
+}
+
+#[test]
+fn test_progress_is_monotonic() {
+    let html = "<p>x</p>".repeat(200);
+    let (_, events) = record(&html, 10);
+
+    assert!(events.len() > 10, "{}", events.len());
+    for pair in events.windows(2) {
+        assert!(pair[0].nodes_processed <= pair[1].nodes_processed);
+        assert!(pair[0].bytes_processed <= pair[1].bytes_processed);
+    }
+}
+
+#[test]
+fn test_interval_limits_callbacks() {
+    let html = "<p>x</p>".repeat(100);
+
+    let (_, every_node) = record(&html, 1);
+    let (_, sparse) = record(&html, 50);
+    assert!(
+        sparse.len() < every_node.len() / 10,
+        "{} vs {}",
+        sparse.len(),
+        every_node.len()
+    );
+}
+
+#[test]
+fn test_byte_interval_reports_by_document_position() {
+    let html = "<p>x</p>".repeat(400);
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&events);
+    convert_with_progress(&html, None, ProgressInterval::Bytes(800), move |event| {
+        sink.borrow_mut().push(event);
+    })
+    .unwrap();
+    let events = events.borrow();
+
+    let walk = &events[1..events.len() - 1];
+    assert!(!walk.is_empty());
+    for pair in walk.windows(2) {
+        assert!(pair[1].bytes_processed - pair[0].bytes_processed >= 800);
+    }
+    assert!(walk.len() <= html.len() / 800);
+}
@@ -0,0 +1,189 @@
+//! Property-based tests: malformed markup and attribute combinations must never panic.
+//!
+//! The generators splice together tags, hostile attribute values and Markdown-significant text,
+//! aiming at the table, list and hOCR code paths where production panics have been reported.
+//! Those inputs are clamped and converted rather than rejected, so the properties require a
+//! successful conversion, not merely the absence of `ConversionError::Panic`.
+
+use html_to_markdown_rs::{
+    CodeBlockStyle, ConversionError, ConversionOptions, ListIndentType, ListSpacing, convert, convert_many,
+};
+use proptest::prelude::*;
+
+const TAGS: &[&str] = &[
+    "table",
+    "caption",
+    "thead",
+    "tbody",
+    "tfoot",
+    "tr",
+    "td",
+    "th",
+    "colgroup",
+    "col",
+    "ol",
+    "ul",
+    "li",
+    "dl",
+    "dt",
+    "dd",
+    "p",
+    "div",
+    "pre",
+    "code",
+    "blockquote",
+    "h2",
+    "a",
+    "img",
+    "br",
+    "input",
+    "details",
+    "summary",
+];
+
+const ATTRIBUTES: &[&str] = &[
+    "",
+    " colspan=\"0\"",
+    " colspan=\"18446744073709551615\"",
+    " rowspan=\"0\"",
+    " rowspan=\"18446744073709551615\"",
+    " start=\"0\"",
+    " start=\"18446744073709551615\"",
+    " start=\"-3\"",
+    " reversed",
+    " span=\"99999\"",
+    " type=\"checkbox\" checked",
+    " class=\"language-\"",
+    " href=\"\"",
+    " alt=\"]\"",
+];
+
+fn fragment() -> impl Strategy<Value = String> {
+    prop_oneof![
+        (
+            prop::sample::select(TAGS),
+            prop::sample::select(ATTRIBUTES),
+            any::<bool>()
+        )
+            .prop_map(|(tag, attributes, close)| {
+                if close {
+                    format!("</{tag}>")
+                } else {
+                    format!("<{tag}{attributes}>")
+                }
+            }),
+        "[a-z0-9|*_`#>\\[\\]() \n-]{0,8}",
+    ]
+}
+
+fn document() -> impl Strategy<Value = String> {
+    prop::collection::vec(fragment(), 0..40).prop_map(|fragments| fragments.concat())
+}
+
+fn coordinate() -> impl Strategy<Value = String> {
+    prop_oneof![
+        (0u32..400).prop_map(|value| value.to_string()),
+        Just(u32::MAX.to_string()),
+        Just((u32::MAX - 4).to_string()),
+        Just("-1".to_string()),
+    ]
+}
+
+fn hocr_document() -> impl Strategy<Value = String> {
+    let word = (
+        [coordinate(), coordinate(), coordinate(), coordinate()],
+        prop::sample::select(&["ocrx_word", "ocr_line", "ocr_par", "ocr_carea"][..]),
+        prop::sample::select(&["let x = 1;", "{", "}", "x += 1", "Total", "42", "3.5", ""][..]),
+    )
+        .prop_map(|([x1, y1, x2, y2], class, text)| {
+            format!("<span class=\"{class}\" title=\"bbox {x1} {y1} {x2} {y2}; x_wconf 90\">{text}</span>")
+        });
+    prop::collection::vec(word, 0..40).prop_map(|words| {
+        format!(
+            "<html><head><meta name=\"ocr-system\" content=\"tesseract 5\"></head><body>\
+             <div class=\"ocr_page\" title=\"bbox 0 0 1000 1000\">{}</div></body></html>",
+            words.concat()
+        )
+    })
+}
+
+fn options() -> impl Strategy<Value = ConversionOptions> {
+    (any::<bool>(), any::<bool>(), 0usize..3, 0usize..3, 1usize..8).prop_map(
+        |(wrap, br_in_tables, spacing, code_style, list_indent_width)| ConversionOptions {
+            wrap,
+            wrap_width: 20,
+            br_in_tables,
+            list_spacing: [ListSpacing::Auto, ListSpacing::Tight, ListSpacing::Loose][spacing],
+            code_block_style: [
+                CodeBlockStyle::Indented,
+                CodeBlockStyle::Backticks,
+                CodeBlockStyle::Tildes,
+            ][code_style],
+            list_indent_type: if br_in_tables {
+                ListIndentType::Tabs
+            } else {
+                ListIndentType::Spaces
+            },
+            list_indent_width,
+            ..Default::default()
+        },
+    )
+}
+
+fn assert_converts(html: &str, options: ConversionOptions) -> Result<(), TestCaseError> {
+    match convert_many(&[html], Some(options)).remove(0) {
+        Ok(_) => Ok(()),
+        Err(ConversionError::Panic(message)) => {
+            Err(TestCaseError::fail(format!("panicked with {message:?} on {html:?}")))
+        }
+        Err(err) => Err(TestCaseError::fail(format!("rejected {html:?}: {err}"))),
+    }
+}
+
+proptest! {
+    #[test]
+    fn prop_markup_never_panics(html in document(), options in options()) {
+        assert_converts(&html, options)?;
+    }
+
+    #[test]
+    fn prop_hocr_never_panics(html in hocr_document(), spatial in any::<bool>()) {
+        let options = ConversionOptions {
+            hocr_spatial_tables: spatial,
+            ..Default::default()
+        };
+        assert_converts(&html, options)?;
+    }
+
+    #[test]
+    fn prop_conversion_is_deterministic(html in document()) {
+        prop_assert_eq!(convert(&html, None).ok(), convert(&html, None).ok());
+    }
+}
+
+#[test]
+fn test_ordered_list_start_is_clamped() {
+    let html = "<ol start=\"18446744073709551615\"><li>a</li><li>b</li></ol>";
+    assert_eq!(convert(html, None).unwrap(), "999999998. a\n999999999. b\n");
+}
+
+#[test]
+fn test_hocr_bbox_at_coordinate_limit() {
+    let line = |x: u32, y: u32, text: &str| {
+        format!(
+            "<span class=\"ocr_line\" title=\"bbox {x} {y} {} {}\"><span class=\"ocrx_word\" title=\"bbox {x} {y} {} {}; x_wconf 90\">{text}</span></span>",
+            x.saturating_add(40),
+            y.saturating_add(10),
+            x.saturating_add(40),
+            y.saturating_add(10)
+        )
+    };
+    let html = format!(
+        "<html><head><meta name=\"ocr-system\" content=\"tesseract 5\"></head><body><div class=\"ocr_page\">\
+         <p class=\"ocr_par\">{}{}{}</p></div></body></html>",
+        line(u32::MAX - 4, 0, "fn main() {"),
+        line(u32::MAX - 2, 20, "let x = 1;"),
+        line(u32::MAX - 4, 40, "}")
+    );
+    assert!(convert(&html, None).is_ok());
+}
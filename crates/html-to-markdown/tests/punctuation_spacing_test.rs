@@ -0,0 +1,61 @@
+//! Tests for the `remove_space_before_punctuation` option.
+//!
+//! Span-heavy editors often leave whitespace between an inline element and the
+//! punctuation that follows it, which renders as `word **bold** .` in Markdown.
+
+use html_to_markdown_rs::{ConversionOptions, convert};
+
+fn convert_tight(html: &str) -> String {
+    let options = ConversionOptions {
+        remove_space_before_punctuation: true,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_space_before_punctuation_kept_by_default() {
+    let result = convert("<p>word <b>bold </b>. next</p>", None).unwrap();
+    assert_eq!(result, "word **bold** . next\n");
+}
+
+#[test]
+fn test_space_removed_after_strong() {
+    assert_eq!(convert_tight("<p>word <b>bold </b>. next</p>"), "word **bold**. next\n");
+}
+
+#[test]
+fn test_space_removed_between_spans() {
+    assert_eq!(
+        convert_tight("<p>word <span>bold</span> , and <em>it</em> !</p>"),
+        "word bold, and *it*!\n"
+    );
+}
+
+#[test]
+fn test_space_removed_after_link_and_code() {
+    assert_eq!(
+        convert_tight(r#"<p>See <a href="/x">link</a> ; then <code>x</code> ?</p>"#),
+        "See [link](/x); then `x`?\n"
+    );
+}
+
+#[test]
+fn test_space_after_list_marker_preserved() {
+    let result = convert_tight("<ul><li>. dot</li></ul><ol><li><span></span> , comma</li></ol>");
+    assert!(
+        result.contains("- . dot"),
+        "list marker spacing must be kept: {result:?}"
+    );
+    assert!(
+        result.contains("1. , comma"),
+        "ordered marker spacing must be kept: {result:?}"
+    );
+}
+
+#[test]
+fn test_code_blocks_untouched() {
+    let result = convert_tight("<pre>value ;\nother .</pre>");
+    assert!(result.contains("value ;"));
+    assert!(result.contains("other ."));
+}
@@ -0,0 +1,71 @@
+//! Tests for capturing and replaying conversion bundles.
+#![cfg(feature = "repro")]
+
+use html_to_markdown_rs::repro::ReproBundle;
+use html_to_markdown_rs::{ConversionError, ConversionOptions, HeadingStyle};
+
+fn round_trip(bundle: &ReproBundle) -> ReproBundle {
+    let mut archive = Vec::new();
+    bundle.write_to(&mut archive).unwrap();
+    ReproBundle::read_from(archive.as_slice()).unwrap()
+}
+
+#[test]
+fn test_bundle_round_trips_input_options_and_output() {
+    let options = ConversionOptions {
+        heading_style: HeadingStyle::Underlined,
+        ..Default::default()
+    };
+    let input = b"<meta charset=\"iso-8859-1\"><h1>Caf\xe9</h1>";
+    let bundle = ReproBundle::capture(input, None, &options);
+    assert!(
+        bundle
+            .outcome
+            .as_deref()
+            .is_ok_and(|markdown| markdown.starts_with("Café\n===="))
+    );
+
+    let received = round_trip(&bundle);
+    assert_eq!(received.input, input);
+    assert_eq!(received.encoding, None);
+    assert_eq!(received.options.heading_style, HeadingStyle::Underlined);
+    assert_eq!(received.version, env!("CARGO_PKG_VERSION"));
+    assert!(received.features.iter().any(|feature| feature == "repro"));
+
+    let replay = received.replay();
+    assert!(replay.matches);
+    assert!(replay.same_version);
+}
+
+#[test]
+fn test_bundle_records_errors_and_declared_encoding() {
+    let bundle = ReproBundle::capture(b"%PDF-1.7 not html", Some("utf-8"), &ConversionOptions::default());
+    assert!(bundle.outcome.is_err());
+
+    let received = round_trip(&bundle);
+    assert_eq!(received.encoding.as_deref(), Some("utf-8"));
+    assert_eq!(received.outcome, bundle.outcome);
+    assert!(received.replay().matches);
+}
+
+#[test]
+fn test_bundles_are_deterministic() {
+    let bundle = ReproBundle::capture(b"<p>same</p>", None, &ConversionOptions::default());
+    let (mut first, mut second) = (Vec::new(), Vec::new());
+    bundle.write_to(&mut first).unwrap();
+    bundle.write_to(&mut second).unwrap();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_replay_detects_changed_output() {
+    let mut bundle = ReproBundle::capture(b"<p>text</p>", None, &ConversionOptions::default());
+    bundle.outcome = Ok("something else\n".to_string());
+    assert!(!round_trip(&bundle).replay().matches);
+}
+
+#[test]
+fn test_rejects_non_bundles() {
+    let result = ReproBundle::read_from(&b"not an archive"[..]);
+    assert!(matches!(result, Err(ConversionError::IoError(_))));
+}
@@ -0,0 +1,91 @@
+//! Tests for collecting external resource URLs into the extraction result.
+
+use html_to_markdown_rs::metadata::{MetadataConfig, ResourceMetadata, ResourceType};
+
+fn resources(html: &str) -> Vec<ResourceMetadata> {
+    let config = MetadataConfig {
+        extract_resources: true,
+        ..MetadataConfig::default()
+    };
+    let (_markdown, metadata) =
+        html_to_markdown_rs::convert_with_metadata(html, None, config, None).expect("convert_with_metadata failed");
+    metadata.resources
+}
+
+fn resource(url: &str, resource_type: ResourceType) -> ResourceMetadata {
+    ResourceMetadata {
+        url: url.to_string(),
+        resource_type,
+    }
+}
+
+#[test]
+fn test_head_and_body_resources() {
+    let html = r#"
+        <html>
+          <head>
+            <link rel="stylesheet" href="/site.css">
+            <link rel="canonical" href="https://example.com/post">
+            <link rel="preload" as="font" href="/fonts/inter.woff2" crossorigin>
+            <link rel="icon" href="/favicon.ico">
+            <script src="/app.js?v=1&amp;x=2"></script>
+            <script>document.write('<img src="/not-a-resource.png">');</script>
+          </head>
+          <body>
+            <p><a href="/next">Next</a> <img src="/a.png" srcset="/a.png 1x, /a@2x.png 2x"></p>
+            <video src="/clip.mp4" poster="/poster.jpg"><track src="/clip.vtt"></video>
+            <iframe src="https://player.example.com/embed/1"></iframe>
+            <img src="data:image/png;base64,AAAA">
+          </body>
+        </html>
+    "#;
+
+    assert_eq!(
+        resources(html),
+        [
+            resource("/site.css", ResourceType::Stylesheet),
+            resource("/fonts/inter.woff2", ResourceType::Font),
+            resource("/favicon.ico", ResourceType::Image),
+            resource("/app.js?v=1&x=2", ResourceType::Script),
+            resource("/a.png", ResourceType::Image),
+            resource("/a@2x.png", ResourceType::Image),
+            resource("/poster.jpg", ResourceType::Image),
+            resource("/clip.mp4", ResourceType::Media),
+            resource("/clip.vtt", ResourceType::Media),
+            resource("https://player.example.com/embed/1", ResourceType::Embed),
+        ]
+    );
+}
+
+#[test]
+fn test_css_resources() {
+    let html = r#"
+        <style>
+          @import url("/print.css");
+          @font-face { font-family: Inter; src: url(/fonts/inter.woff2) format("woff2"), url('/fonts/inter.ttf'); }
+          .hero { background: url(/hero.jpg) no-repeat; }
+        </style>
+        <div style="background-image: url('/bg.png')">Hi</div>
+    "#;
+
+    assert_eq!(
+        resources(html),
+        [
+            resource("/print.css", ResourceType::Stylesheet),
+            resource("/fonts/inter.woff2", ResourceType::Font),
+            resource("/fonts/inter.ttf", ResourceType::Font),
+            resource("/hero.jpg", ResourceType::Image),
+            resource("/bg.png", ResourceType::Image),
+        ]
+    );
+}
+
+#[test]
+fn test_resources_are_opt_in() {
+    let html = r#"<head><script src="/app.js"></script></head><body><img src="/a.png"></body>"#;
+    let (_markdown, metadata) =
+        html_to_markdown_rs::convert_with_metadata(html, None, MetadataConfig::default(), None).unwrap();
+
+    assert!(metadata.resources.is_empty());
+    assert_eq!(metadata.images.len(), 1);
+}
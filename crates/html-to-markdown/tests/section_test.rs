@@ -0,0 +1,48 @@
+//! Tests for `convert_section`.
+
+use html_to_markdown_rs::{ConversionError, ConversionOptions, convert_section};
+
+const PAGE: &str = r#"<html><head><title>Docs</title><script>var h = "<h2>Fake</h2>";</script></head>
+<body><main>
+<section><h2 id="setup">Setup</h2><p>Install the package.</p>
+<h3>Windows</h3><p>Use the installer.</p></section>
+<section><h2>API <em>reference</em></h2><ul><li>convert</li></ul></section>
+</main></body></html>"#;
+
+fn options() -> ConversionOptions {
+    ConversionOptions {
+        extract_metadata: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_section_stops_at_next_sibling_heading_across_containers() {
+    let markdown = convert_section(PAGE, "setup", Some(options())).unwrap();
+
+    assert_eq!(
+        markdown,
+        "## Setup\n\nInstall the package.\n\n### Windows\n\nUse the installer.\n"
+    );
+}
+
+#[test]
+fn test_section_by_heading_text_ignores_markup() {
+    let markdown = convert_section(PAGE, "API reference", Some(options())).unwrap();
+
+    assert_eq!(markdown, "## API *reference*\n\n- convert\n");
+}
+
+#[test]
+fn test_section_by_selector() {
+    let markdown = convert_section(PAGE, "section > h3", Some(options())).unwrap();
+
+    assert_eq!(markdown, "### Windows\n\nUse the installer.\n");
+}
+
+#[test]
+fn test_missing_heading_is_an_error() {
+    let err = convert_section(PAGE, "Fake", Some(options())).unwrap_err();
+
+    assert!(matches!(err, ConversionError::InvalidInput(message) if message.contains("Fake")));
+}
@@ -0,0 +1,66 @@
+//! Tests for CSS selector based exclusion and inclusion during preprocessing.
+
+use html_to_markdown_rs::{ConversionError, ConversionOptions, convert};
+
+fn selector_options(exclude: &[&str], include: Option<&str>) -> ConversionOptions {
+    let mut options = ConversionOptions::default();
+    options.preprocessing.enabled = true;
+    options.preprocessing.exclude_selectors = exclude.iter().map(ToString::to_string).collect();
+    options.preprocessing.include_selector = include.map(str::to_string);
+    options
+}
+
+const PAGE: &str = r#"<body>
+<div class="ads">Buy now</div>
+<article class="main">
+  <h2>Story</h2>
+  <p>First paragraph.</p>
+  <div class="inline-ad" data-slot="1">Sponsored</div>
+  <p>Second paragraph.</p>
+</article>
+<div id="sidebar"><p>Popular posts</p></div>
+<article class="teaser"><p>Another story</p></article>
+</body>"#;
+
+#[test]
+fn test_exclude_selectors_drop_matching_elements() {
+    let markdown = convert(PAGE, Some(selector_options(&[".ads, #sidebar", "[data-slot]"], None))).unwrap();
+
+    assert_eq!(
+        markdown,
+        "## Story\n\nFirst paragraph.\n\nSecond paragraph.\n\nAnother story\n"
+    );
+}
+
+#[test]
+fn test_include_selector_limits_output() {
+    let markdown = convert(
+        PAGE,
+        Some(selector_options(&["article .inline-ad"], Some("article.main"))),
+    )
+    .unwrap();
+    assert_eq!(markdown, "## Story\n\nFirst paragraph.\n\nSecond paragraph.\n");
+
+    let markdown = convert(PAGE, Some(selector_options(&[], Some("body > article")))).unwrap();
+    assert!(markdown.contains("Sponsored"));
+    assert!(markdown.contains("Another story"));
+    assert!(!markdown.contains("Buy now"));
+
+    let markdown = convert(PAGE, Some(selector_options(&[], Some("section")))).unwrap();
+    assert_eq!(markdown, "");
+}
+
+#[test]
+fn test_selectors_require_preprocessing() {
+    let mut options = selector_options(&[".ads"], Some("article.main"));
+    options.preprocessing.enabled = false;
+    let markdown = convert(PAGE, Some(options)).unwrap();
+    assert!(markdown.contains("Buy now"));
+    assert!(markdown.contains("Popular posts"));
+}
+
+#[test]
+fn test_invalid_selector_is_a_config_error() {
+    let err = convert(PAGE, Some(selector_options(&["div["], None))).unwrap_err();
+    assert!(matches!(err, ConversionError::ConfigError(_)), "{err:?}");
+}
@@ -0,0 +1,95 @@
+//! Tests for `abbr_style`, `time_style` and `dfn_style`.
+
+use html_to_markdown_rs::{AbbrStyle, ConversionOptions, DfnStyle, TimeStyle, convert};
+
+const ABBR: &str = r#"<p>Written in <abbr title="HyperText Markup Language">HTML</abbr>.</p>"#;
+const TIME: &str = r#"<p>Due <time datetime="2024-05-14">next Tuesday</time>, sharp.</p>"#;
+const DFN: &str = "<p>A <dfn>widget</dfn> is a gadget.</p>";
+
+fn convert_with(options: ConversionOptions, html: &str) -> String {
+    let options = ConversionOptions {
+        extract_metadata: false,
+        ..options
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_defaults_are_unchanged() {
+    let options = ConversionOptions::default();
+    assert_eq!(
+        convert_with(options.clone(), ABBR),
+        "Written in HTML (HyperText Markup Language).\n"
+    );
+    assert_eq!(convert_with(options.clone(), TIME), "Due next Tuesday, sharp.\n");
+    assert_eq!(convert_with(options, DFN), "A *widget* is a gadget.\n");
+}
+
+#[test]
+fn test_abbr_style() {
+    let text = ConversionOptions {
+        abbr_style: AbbrStyle::Text,
+        ..Default::default()
+    };
+    assert_eq!(convert_with(text, ABBR), "Written in HTML.\n");
+
+    let html = ConversionOptions {
+        abbr_style: AbbrStyle::Html,
+        ..Default::default()
+    };
+    assert_eq!(
+        convert_with(html, ABBR),
+        "Written in <abbr title=\"HyperText Markup Language\">HTML</abbr>.\n"
+    );
+}
+
+#[test]
+fn test_time_style_datetime() {
+    let options = ConversionOptions {
+        time_style: TimeStyle::Datetime,
+        ..Default::default()
+    };
+    assert_eq!(
+        convert_with(options.clone(), TIME),
+        "Due next Tuesday (2024-05-14), sharp.\n"
+    );
+    assert_eq!(
+        convert_with(
+            options.clone(),
+            r#"<p>On <time datetime="2024-05-14">2024-05-14</time>.</p>"#
+        ),
+        "On 2024-05-14.\n"
+    );
+    assert_eq!(
+        convert_with(options.clone(), r#"<p>On <time datetime="2024-05-14"></time>.</p>"#),
+        "On 2024-05-14.\n"
+    );
+    assert_eq!(convert_with(options, "<p>At <time>noon</time>.</p>"), "At noon.\n");
+}
+
+#[test]
+fn test_time_style_html() {
+    let options = ConversionOptions {
+        time_style: TimeStyle::Html,
+        ..Default::default()
+    };
+    assert_eq!(
+        convert_with(options, TIME),
+        "Due <time datetime=\"2024-05-14\">next Tuesday</time>, sharp.\n"
+    );
+}
+
+#[test]
+fn test_dfn_style() {
+    let text = ConversionOptions {
+        dfn_style: DfnStyle::Text,
+        ..Default::default()
+    };
+    assert_eq!(convert_with(text, DFN), "A widget is a gadget.\n");
+
+    let html = ConversionOptions {
+        dfn_style: DfnStyle::Html,
+        ..Default::default()
+    };
+    assert_eq!(convert_with(html, DFN), "A <dfn>widget</dfn> is a gadget.\n");
+}
@@ -0,0 +1,42 @@
+//! Tests for declarative shadow DOM handling.
+
+use html_to_markdown_rs::{ConversionOptions, convert};
+
+const CARD: &str = r#"<my-card>
+<template shadowrootmode="open"><h2><slot name="title">Untitled</slot></h2><div class="body"><slot></slot></div><footer><slot name="footer">No footer</slot></footer></template>
+<span slot="title">Release notes</span>
+<p>Light paragraph.</p>
+<em slot="missing">Unassigned</em>
+</my-card>"#;
+
+fn flatten_options() -> ConversionOptions {
+    let mut options = ConversionOptions::default();
+    options.preprocessing.enabled = true;
+    options.preprocessing.flatten_shadow_dom = true;
+    options
+}
+
+#[test]
+fn test_shadow_root_is_composed_with_slots() {
+    let markdown = convert(CARD, Some(flatten_options())).unwrap();
+
+    assert_eq!(markdown, "## Release notes\n\nLight paragraph.\n\nNo footer\n");
+}
+
+#[test]
+fn test_nested_shadow_hosts_are_flattened() {
+    let html = r#"<outer-el><template shadowrootmode="closed"><section><inner-el><template shadowrootmode="open"><strong><slot></slot></strong></template>Deep</inner-el></section><slot></slot></template><p>Outer light</p></outer-el>"#;
+
+    let markdown = convert(html, Some(flatten_options())).unwrap();
+
+    assert_eq!(markdown, "**Deep**\n\nOuter light\n");
+}
+
+#[test]
+fn test_shadow_content_is_kept_without_flattening() {
+    let markdown = convert(CARD, None).unwrap();
+
+    assert!(markdown.contains("Untitled"), "{markdown}");
+    assert!(markdown.contains("Light paragraph."), "{markdown}");
+    assert!(markdown.contains("Unassigned"), "{markdown}");
+}
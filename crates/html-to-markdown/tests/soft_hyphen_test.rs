@@ -0,0 +1,38 @@
+//! Tests for the `remove_soft_hyphens` option.
+
+use html_to_markdown_rs::{ConversionOptions, convert};
+
+#[test]
+fn test_soft_hyphens_removed_by_default() {
+    let result = convert("<p>Donau&shy;dampf&shy;schiff and word&#x2060;joiner</p>", None).unwrap();
+    assert_eq!(result, "Donaudampfschiff and wordjoiner\n");
+}
+
+#[test]
+fn test_soft_hyphens_removed_in_tables_and_plain_text() {
+    let table = convert(
+        "<table><tr><th>Head&shy;er</th></tr><tr><td>ce&shy;ll</td></tr></table>",
+        None,
+    )
+    .unwrap();
+    assert!(table.contains("| Header |"), "{table}");
+    assert!(table.contains("| cell |"), "{table}");
+
+    assert_eq!(convert("plain&shy;text", None).unwrap(), "plaintext\n");
+}
+
+#[test]
+fn test_soft_hyphens_kept_when_disabled() {
+    let options = ConversionOptions {
+        remove_soft_hyphens: false,
+        ..Default::default()
+    };
+    let result = convert("<p>Donau&shy;dampf</p>", Some(options)).unwrap();
+    assert_eq!(result, "Donau\u{ad}dampf\n");
+}
+
+#[test]
+fn test_soft_hyphens_in_code_untouched() {
+    let result = convert("<p><code>a&shy;b</code></p>", None).unwrap();
+    assert_eq!(result, "`a\u{ad}b`\n");
+}
@@ -0,0 +1,64 @@
+//! Tests for recording block source ranges with `MetadataConfig::extract_source_map`.
+
+use html_to_markdown_rs::metadata::{MetadataConfig, SourceMapEntry};
+
+fn convert(html: &str) -> (String, Vec<SourceMapEntry>) {
+    let config = MetadataConfig {
+        extract_source_map: true,
+        ..MetadataConfig::default()
+    };
+    let (markdown, metadata) =
+        html_to_markdown_rs::convert_with_metadata(html, None, config, None).expect("convert_with_metadata failed");
+    (markdown, metadata.source_map)
+}
+
+fn spans<'a>(html: &'a str, markdown: &'a str, source_map: &[SourceMapEntry]) -> Vec<(&'a str, &'a str)> {
+    source_map
+        .iter()
+        .map(|entry| {
+            (
+                &html[entry.html_start..entry.html_end],
+                &markdown[entry.markdown_start..entry.markdown_end],
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_blocks_map_to_their_html_and_markdown() {
+    let html = "<h1>Title</h1><p>Some <em>text</em>.</p><ul><li>one</li><li>two</li></ul>";
+    let (markdown, source_map) = convert(html);
+    assert_eq!(markdown, "# Title\n\nSome *text*.\n\n- one\n- two\n");
+
+    let tags: Vec<_> = source_map.iter().map(|entry| entry.tag.as_str()).collect();
+    assert_eq!(tags, ["h1", "p", "ul"]);
+    assert_eq!(
+        spans(html, &markdown, &source_map),
+        [
+            ("<h1>Title</h1>", "# Title"),
+            ("<p>Some <em>text</em>.</p>", "Some *text*."),
+            ("<ul><li>one</li><li>two</li></ul>", "- one\n- two"),
+        ]
+    );
+}
+
+#[test]
+fn test_offsets_point_into_the_original_input() {
+    let html = "<p>first</p>\r\n<script>track()</script>\r\n<blockquote><p>quoted</p></blockquote>";
+    let (markdown, source_map) = convert(html);
+    assert_eq!(
+        spans(html, &markdown, &source_map),
+        [
+            ("<p>first</p>", "first"),
+            ("<blockquote><p>quoted</p></blockquote>", "> quoted"),
+        ]
+    );
+}
+
+#[test]
+fn test_source_map_is_opt_in() {
+    let (_markdown, metadata) =
+        html_to_markdown_rs::convert_with_metadata("<p>text</p>", None, MetadataConfig::default(), None)
+            .expect("convert_with_metadata failed");
+    assert!(metadata.source_map.is_empty());
+}
@@ -0,0 +1,119 @@
+//! Tests for the `split_documents` option.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use html_to_markdown_rs::{
+    ConversionOptions, PreprocessingOptions, ProgressEvent, UnrepresentablePolicy, convert, convert_to_writer,
+    convert_with_diagnostics, convert_with_progress, convert_with_stats,
+};
+
+const DIGEST: &str = "<!DOCTYPE html><html><head><title>One</title></head><body><nav>menu</nav><main><h1>First</h1><p>a</p></main></body></html>\n<!DOCTYPE html><html><head><title>Two</title></head><body><nav>menu</nav><main><h1>Second</h1><p>b <my-el>x</my-el></p></main></body></html>";
+
+fn split(options: ConversionOptions) -> ConversionOptions {
+    ConversionOptions {
+        split_documents: true,
+        ..options
+    }
+}
+
+#[test]
+fn test_each_document_is_converted_on_its_own() {
+    let markdown = convert(DIGEST, Some(split(ConversionOptions::default()))).unwrap();
+    assert_eq!(
+        markdown,
+        "---\ntitle: One\n---\n\nmenu\n\n# First\n\na\n\n---\n\n---\ntitle: Two\n---\n\nmenu\n\n# Second\n\nb x\n"
+    );
+
+    // Without splitting, the second <title> is read as body text.
+    let joined = convert(DIGEST, None).unwrap();
+    assert!(joined.contains("\n\nTwo\n\n"), "{joined}");
+}
+
+#[test]
+fn test_preprocessing_applies_to_every_document() {
+    let options = ConversionOptions {
+        extract_metadata: false,
+        preprocessing: PreprocessingOptions {
+            enabled: true,
+            remove_navigation: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let markdown = convert(DIGEST, Some(split(options))).unwrap();
+    assert_eq!(markdown, "# First\n\na\n\n---\n\n# Second\n\nb x\n");
+}
+
+#[test]
+fn test_empty_documents_and_single_documents() {
+    let options = split(ConversionOptions::default());
+    assert_eq!(
+        convert(
+            "<body><p>a</p></body><body> </body><body><p>b</p></body>",
+            Some(options.clone())
+        )
+        .unwrap(),
+        "a\n\n---\n\nb\n"
+    );
+    let single = "<html><body><p>a</p></body></html>\n<p>trailing</p>";
+    assert_eq!(
+        convert(single, Some(options.clone())).unwrap(),
+        convert(single, None).unwrap()
+    );
+
+    let mut out = Vec::new();
+    convert_to_writer("<body>a</body><body>b</body>", Some(options), &mut out).unwrap();
+    assert_eq!(out, b"a\n\n---\n\nb\n");
+}
+
+#[test]
+fn test_diagnostics_and_progress_split_documents() {
+    let html = "<body><p>a<wbr>b</p></body><body><p>c<wbr>d</p></body>";
+    let options = split(ConversionOptions {
+        unrepresentable_policy: UnrepresentablePolicy::Diagnose,
+        ..Default::default()
+    });
+    let (markdown, diagnostics) = convert_with_diagnostics(html, Some(options.clone())).unwrap();
+    assert_eq!(markdown, "ab\n\n---\n\ncd\n");
+    assert_eq!(diagnostics.len(), 2);
+
+    let events: Rc<RefCell<Vec<ProgressEvent>>> = Rc::default();
+    let seen = Rc::clone(&events);
+    let markdown = convert_with_progress(html, Some(options), 1, move |event| seen.borrow_mut().push(event)).unwrap();
+    assert_eq!(markdown, "ab\n\n---\n\ncd\n");
+    let events = events.borrow();
+    assert!(events.iter().all(|event| event.total_bytes == html.len()));
+    assert!(
+        events
+            .windows(2)
+            .all(|pair| pair[0].bytes_processed <= pair[1].bytes_processed)
+    );
+    assert_eq!(events.iter().filter(|event| event.is_done()).count(), 1);
+    assert!(events.last().unwrap().is_done());
+}
+
+#[test]
+fn test_stats_split_documents() {
+    let html = "<body><p>a</p></body><body><p>b</p></body>";
+    let options = split(ConversionOptions {
+        collect_stats: true,
+        ..Default::default()
+    });
+    let (markdown, stats) = convert_with_stats(html, Some(options.clone())).unwrap();
+    assert_eq!(markdown, convert(html, Some(options)).unwrap());
+    assert_eq!(markdown, "a\n\n---\n\nb\n");
+
+    let stats = stats.unwrap();
+    assert_eq!(stats.input_bytes, html.len());
+    assert_eq!(stats.output_bytes, markdown.len());
+    let (_, single) = convert_with_stats(
+        "<body><p>a</p></body>",
+        Some(ConversionOptions {
+            collect_stats: true,
+            ..Default::default()
+        }),
+    )
+    .unwrap();
+    assert_eq!(stats.nodes_parsed, 2 * single.unwrap().nodes_parsed);
+}
@@ -0,0 +1,47 @@
+//! Tests for `srcset_policy` on images without a `src`.
+
+use html_to_markdown_rs::{ConversionOptions, SrcsetPolicy, convert};
+
+fn convert_with(srcset_policy: SrcsetPolicy, html: &str) -> String {
+    let options = ConversionOptions {
+        srcset_policy,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_img_srcset_replaces_missing_src() {
+    let html = r#"<img srcset="m.jpg 800w, s.jpg 320w, l.jpg 1600w" alt="Cat">"#;
+
+    assert_eq!(convert_with(SrcsetPolicy::First, html), "![Cat](m.jpg)\n");
+    assert_eq!(convert_with(SrcsetPolicy::Largest, html), "![Cat](l.jpg)\n");
+    assert_eq!(convert_with(SrcsetPolicy::Smallest, html), "![Cat](s.jpg)\n");
+}
+
+#[test]
+fn test_plain_src_wins_over_srcset() {
+    let html = r#"<img src="plain.jpg" srcset="big.jpg 2x" alt="Cat">"#;
+
+    assert_eq!(convert_with(SrcsetPolicy::Largest, html), "![Cat](plain.jpg)\n");
+}
+
+#[test]
+fn test_picture_sources_are_candidates() {
+    let html = r#"<picture>
+        <source type="image/avif" srcset="photo.avif 1x, photo@2x.avif 2x">
+        <source type="image/webp" srcset="photo.webp">
+        <img alt="Photo">
+    </picture>"#;
+
+    assert_eq!(convert_with(SrcsetPolicy::First, html), "![Photo](photo.avif)\n");
+    assert_eq!(convert_with(SrcsetPolicy::Largest, html), "![Photo](photo@2x.avif)\n");
+}
+
+#[test]
+fn test_sources_after_img_are_ignored() {
+    let html = r#"<picture><img alt="x" srcset="a.png"><source srcset="late.png 3x"></picture>"#;
+
+    assert_eq!(convert_with(SrcsetPolicy::Largest, html), "![x](a.png)\n");
+}
@@ -0,0 +1,55 @@
+//! Tests for the per-conversion resource usage report.
+
+use html_to_markdown_rs::{ConversionOptions, PostProcessorChain, convert, convert_with_stats};
+
+fn collecting() -> ConversionOptions {
+    ConversionOptions {
+        collect_stats: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_stats_are_opt_in() {
+    let (markdown, stats) = convert_with_stats("<p>hi</p>", None).unwrap();
+    assert_eq!(markdown, "hi\n");
+    assert!(stats.is_none());
+}
+
+#[test]
+fn test_stats_describe_the_conversion() {
+    let html = "<html><body><h1>Title</h1><ul><li>one</li><li>two</li></ul></body></html>";
+    let (markdown, stats) = convert_with_stats(html, Some(collecting())).unwrap();
+    let stats = stats.unwrap();
+
+    assert_eq!(markdown, convert(html, None).unwrap());
+    assert_eq!(stats.input_bytes, html.len());
+    assert_eq!(stats.output_bytes, markdown.len());
+    assert!(stats.nodes_parsed >= 9, "{stats:?}");
+    assert!(stats.peak_memory_estimate >= html.len() + markdown.len(), "{stats:?}");
+    assert_eq!(
+        stats.total_time(),
+        stats.preprocess_time + stats.parse_time + stats.convert_time + stats.postprocess_time
+    );
+}
+
+#[test]
+fn test_stats_for_text_without_markup() {
+    let (markdown, stats) = convert_with_stats("plain &amp; simple", Some(collecting())).unwrap();
+    let stats = stats.unwrap();
+    assert_eq!(markdown, "plain & simple\n");
+    assert_eq!(stats.nodes_parsed, 0);
+    assert_eq!(stats.output_bytes, markdown.len());
+}
+
+#[test]
+fn test_stats_output_size_includes_post_processing() {
+    let options = ConversionOptions {
+        post_processors: PostProcessorChain::from_names(&["toc"]),
+        ..collecting()
+    };
+    let html = "<h1>One</h1><h2>Two</h2>";
+    let (markdown, stats) = convert_with_stats(html, Some(options)).unwrap();
+    assert!(markdown.len() > convert(html, None).unwrap().len());
+    assert_eq!(stats.unwrap().output_bytes, markdown.len());
+}
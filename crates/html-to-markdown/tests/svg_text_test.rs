@@ -0,0 +1,65 @@
+//! Tests for `svg_text_mode`.
+
+use html_to_markdown_rs::{ConversionOptions, SvgTextMode, convert};
+
+const CHART: &str = r#"<div><svg width="200" height="100"><title>Revenue by quarter</title><desc>Bar chart &amp; trend</desc><g><text x="0" y="10">Q1</text><text x="20" y="10">Q2</text><text><tspan>Q3</tspan> total</text><text>Q1</text></g></svg></div>"#;
+
+fn convert_with(mode: SvgTextMode, html: &str) -> String {
+    let options = ConversionOptions {
+        svg_text_mode: mode,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+fn alt_text(markdown: &str) -> &str {
+    let start = markdown.find("![").expect("image") + 2;
+    let end = markdown[start..]
+        .find("](data:image/svg+xml;base64,")
+        .expect("data uri")
+        + start;
+    &markdown[start..end]
+}
+
+#[test]
+fn test_default_uses_title_only() {
+    let markdown = convert_with(SvgTextMode::Off, CHART);
+
+    assert_eq!(alt_text(&markdown), "Revenue by quarter");
+}
+
+#[test]
+fn test_alt_mode_uses_all_labels() {
+    let markdown = convert_with(SvgTextMode::Alt, CHART);
+
+    assert_eq!(
+        alt_text(&markdown),
+        "Revenue by quarter; Bar chart & trend; Q1; Q2; Q3 total"
+    );
+}
+
+#[test]
+fn test_caption_mode_keeps_image_and_adds_caption() {
+    let markdown = convert_with(SvgTextMode::Caption, CHART);
+
+    assert_eq!(alt_text(&markdown), "Revenue by quarter");
+    assert!(
+        markdown.ends_with(")\n\n*Revenue by quarter; Bar chart & trend; Q1; Q2; Q3 total*\n"),
+        "{markdown}"
+    );
+}
+
+#[test]
+fn test_replace_mode_emits_text_only() {
+    let markdown = convert_with(SvgTextMode::Replace, CHART);
+
+    assert_eq!(markdown, "Revenue by quarter; Bar chart & trend; Q1; Q2; Q3 total\n");
+}
+
+#[test]
+fn test_replace_mode_keeps_image_without_text() {
+    let markdown = convert_with(SvgTextMode::Replace, r#"<svg><rect width="10" height="10"/></svg>"#);
+
+    assert_eq!(alt_text(&markdown), "SVG Image");
+}
@@ -0,0 +1,41 @@
+//! Tests for the public text normalization and escaping helpers.
+
+use html_to_markdown_rs::{ConversionOptions, Escaping, NbspMode, convert, escape_with, normalize_text};
+
+#[test]
+fn test_normalize_text_matches_paragraph_conversion() {
+    let raw = "  Caf&eacute;\t\tprices &amp; opening\u{00AD} hours  ";
+    let options = ConversionOptions::default();
+
+    let markdown = convert(&format!("<p>{raw}</p>"), Some(options.clone())).unwrap();
+    assert_eq!(normalize_text(raw, &options), markdown.trim());
+    assert_eq!(normalize_text(raw, &options), "Café prices & opening hours");
+}
+
+#[test]
+fn test_normalize_text_follows_nbsp_mode() {
+    let options = ConversionOptions {
+        nbsp_in_prose: NbspMode::Preserve,
+        ..Default::default()
+    };
+
+    assert_eq!(normalize_text("10&nbsp;km  away", &options), "10\u{00A0}km away");
+    assert_eq!(
+        normalize_text("10&nbsp;km  away", &ConversionOptions::default()),
+        "10 km away"
+    );
+}
+
+#[test]
+fn test_escape_with_matches_conversion() {
+    let options = ConversionOptions {
+        escape_asterisks: true,
+        escape_underscores: true,
+        ..Default::default()
+    };
+    let text = "a*b*c snake_case";
+
+    let markdown = convert(&format!("<p>{text}</p>"), Some(options.clone())).unwrap();
+    assert_eq!(escape_with(text, Escaping::from(&options)), markdown.trim());
+    assert_eq!(escape_with(text, Escaping::default()), text);
+}
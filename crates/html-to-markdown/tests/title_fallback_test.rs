@@ -0,0 +1,97 @@
+//! Tests for the `title_fallback` chain on links and front matter.
+
+use html_to_markdown_rs::{ConversionOptions, TitleSource, convert};
+
+fn with_fallback(sources: &[TitleSource]) -> ConversionOptions {
+    ConversionOptions {
+        title_fallback: sources.to_vec(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_fallback_is_off_by_default() {
+    let html = r#"<a href="https://a.test/docs/intro.html">Intro</a>"#;
+    assert_eq!(
+        convert(html, None).unwrap(),
+        "[Intro](https://a.test/docs/intro.html)\n"
+    );
+}
+
+#[test]
+fn test_link_title_from_url_basename() {
+    let options = with_fallback(&[TitleSource::UrlBasename]);
+    let html = r#"<p><a href="https://a.test/docs/intro.html?x=1#top">Intro</a> <a href="https://a.test/">Home</a> <a href="mailto:me@a.test">Mail</a></p>"#;
+    assert_eq!(
+        convert(html, Some(options)).unwrap(),
+        "[Intro](https://a.test/docs/intro.html?x=1#top \"intro.html\") [Home](https://a.test/ \"a.test\") [Mail](mailto:me@a.test)\n"
+    );
+}
+
+#[test]
+fn test_link_title_attribute_wins() {
+    let options = with_fallback(&[TitleSource::UrlBasename]);
+    let html = r#"<a href="https://a.test/page" title="Explicit">Page</a>"#;
+    assert_eq!(
+        convert(html, Some(options)).unwrap(),
+        "[Page](https://a.test/page \"Explicit\")\n"
+    );
+}
+
+#[test]
+fn test_empty_bookmark_gets_title_as_text() {
+    let options = with_fallback(&[TitleSource::FirstHeading, TitleSource::UrlBasename]);
+    let html = r#"<a href="https://a.test/notes/rust-tips"></a>"#;
+    assert_eq!(
+        convert(html, Some(options)).unwrap(),
+        "[rust-tips](https://a.test/notes/rust-tips \"rust-tips\")\n"
+    );
+}
+
+#[test]
+fn test_link_title_from_heading_inside_link() {
+    let options = with_fallback(&[TitleSource::OpenGraph, TitleSource::FirstHeading]);
+    let html = r#"<a href="/post"><h3>Post title</h3><p>Summary</p></a>"#;
+    let markdown = convert(html, Some(options)).unwrap();
+    assert!(markdown.contains("(/post \"Post title\")"), "{markdown}");
+}
+
+#[test]
+fn test_front_matter_title_chain() {
+    let html = r#"<html><head><meta property="og:title" content="From OG"><link rel="canonical" href="https://a.test/articles/launch-day"></head><body><h1>From Heading</h1></body></html>"#;
+    let front_matter = |sources: &[TitleSource]| {
+        let options = ConversionOptions {
+            extract_metadata: true,
+            ..with_fallback(sources)
+        };
+        convert(html, Some(options)).unwrap()
+    };
+
+    assert!(front_matter(&[TitleSource::OpenGraph]).contains("title: From OG\n"));
+    assert!(front_matter(&[TitleSource::FirstHeading, TitleSource::OpenGraph]).contains("title: From Heading\n"));
+    assert!(front_matter(&[TitleSource::UrlBasename]).contains("title: launch-day\n"));
+    assert!(!front_matter(&[]).contains("\ntitle:"));
+}
+
+#[test]
+fn test_document_title_element_wins() {
+    let options = ConversionOptions {
+        extract_metadata: true,
+        ..with_fallback(&[TitleSource::FirstHeading])
+    };
+    let html = "<html><head><title>Real</title></head><body><h1>Heading</h1></body></html>";
+    let markdown = convert(html, Some(options)).unwrap();
+    assert!(markdown.contains("title: Real\n"), "{markdown}");
+}
+
+#[test]
+fn test_parse_title_sources() {
+    assert_eq!(
+        TitleSource::from_names(&["og-title", "first_heading", "URL", "bogus"]),
+        [
+            TitleSource::OpenGraph,
+            TitleSource::FirstHeading,
+            TitleSource::UrlBasename
+        ]
+    );
+}
@@ -0,0 +1,58 @@
+//! Tests for `title_footnotes`.
+
+use html_to_markdown_rs::{AbbrStyle, ConversionOptions, convert};
+
+fn convert_with_footnotes(html: &str) -> String {
+    let options = ConversionOptions {
+        title_footnotes: true,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_disabled_by_default() {
+    let html = r#"<p><a href="/x" title="More">Link</a> and <span title="Hover">text</span></p>"#;
+    assert_eq!(convert(html, None).unwrap(), "[Link](/x \"More\") and text\n");
+}
+
+#[test]
+fn test_titles_become_footnotes() {
+    let html = r#"<p><a href="/x" title="More info">Link</a>, <abbr title="HyperText Markup Language">HTML</abbr> and <span title="Shown on hover">text</span>.</p>"#;
+    assert_eq!(
+        convert_with_footnotes(html),
+        "[Link](/x)[^1], HTML[^2] and text[^3].\n\n[^1]: More info\n[^2]: HyperText Markup Language\n[^3]: Shown on hover\n"
+    );
+}
+
+#[test]
+fn test_definitions_close_each_section() {
+    let html = r#"<h1>One</h1><p><abbr title="World Wide Web">WWW</abbr></p><h2>Two</h2><p><span title="Second">b</span> and <abbr title="World Wide Web">WWW</abbr></p>"#;
+    assert_eq!(
+        convert_with_footnotes(html),
+        "# One\n\nWWW[^1]\n\n[^1]: World Wide Web\n\n## Two\n\nb[^2] and WWW[^1]\n\n[^2]: Second\n"
+    );
+}
+
+#[test]
+fn test_elements_without_titles_are_unchanged() {
+    let html = r#"<p><a href="/x">Link</a> <span title="  ">text</span></p>"#;
+    assert_eq!(convert_with_footnotes(html), convert(html, None).unwrap());
+}
+
+#[test]
+fn test_abbr_html_style_keeps_title() {
+    let options = ConversionOptions {
+        title_footnotes: true,
+        abbr_style: AbbrStyle::Html,
+        extract_metadata: false,
+        ..Default::default()
+    };
+    let markdown = convert(
+        r#"<p><abbr title="Cascading Style Sheets">CSS</abbr></p>"#,
+        Some(options),
+    )
+    .unwrap();
+    assert!(!markdown.contains("[^1]"), "{markdown}");
+}
@@ -0,0 +1,37 @@
+//! Tests for Chrome trace recording. Recording is process-wide, so everything runs in one test.
+
+use html_to_markdown_rs::{convert, trace};
+
+#[test]
+fn test_trace_records_phases_and_top_level_elements() {
+    assert!(trace::stop().is_err());
+
+    trace::start().unwrap();
+    assert!(trace::start().is_err());
+    let html = r#"<html><head><title>T</title></head><body><div id="main"><p>Text</p></div><ul><li>item</li></ul></body></html>"#;
+    convert(html, None).unwrap();
+    let recorded = trace::stop().unwrap();
+
+    let names = |category: &str| {
+        recorded
+            .events
+            .iter()
+            .filter(|event| event.category == category)
+            .map(|event| event.name.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names("phase"), ["preprocess", "parse", "convert", "postprocess"]);
+    // Spans are listed as they complete, so the root comes after its children.
+    assert_eq!(names("element"), ["div#main", "ul", "html"]);
+
+    let json = recorded.to_chrome_json();
+    assert!(json.starts_with("{\"traceEvents\":[{\"name\":\"preprocess\""), "{json}");
+    assert!(
+        json.contains("\"name\":\"div#main\",\"cat\":\"element\",\"ph\":\"X\""),
+        "{json}"
+    );
+
+    convert("<p>not recorded</p>", None).unwrap();
+    trace::start().unwrap();
+    assert!(trace::stop().unwrap().events.is_empty());
+}
@@ -0,0 +1,61 @@
+//! Tests for the `max_output_chars` and `max_output_tokens` budgets.
+
+use html_to_markdown_rs::{ConversionOptions, TRUNCATION_MARKER, TokenEstimator, convert, convert_to_writer};
+
+const ARTICLE: &str = "<h1>Title</h1><p>First paragraph.</p><ul><li>one</li><li>two</li></ul><p>Last paragraph.</p>";
+
+#[test]
+fn test_chars_budget_cuts_between_blocks() {
+    let full = convert(ARTICLE, None).unwrap();
+    assert_eq!(full, "# Title\n\nFirst paragraph.\n\n- one\n- two\n\nLast paragraph.\n");
+
+    let options = ConversionOptions {
+        max_output_chars: Some(50),
+        ..Default::default()
+    };
+    let markdown = convert(ARTICLE, Some(options.clone())).unwrap();
+    assert_eq!(markdown, "# Title\n\nFirst paragraph.\n\n<!-- truncated -->\n");
+
+    let mut streamed = Vec::new();
+    convert_to_writer(ARTICLE, Some(options), &mut streamed).unwrap();
+    assert_eq!(String::from_utf8(streamed).unwrap(), markdown);
+}
+
+#[test]
+fn test_output_within_budget_is_untouched() {
+    let options = ConversionOptions {
+        max_output_chars: Some(1000),
+        max_output_tokens: Some(1000),
+        ..Default::default()
+    };
+    let markdown = convert(ARTICLE, Some(options)).unwrap();
+    assert!(!markdown.contains(TRUNCATION_MARKER), "{markdown}");
+}
+
+#[test]
+fn test_tokens_budget_uses_the_estimator() {
+    let words = TokenEstimator::new(|text| text.split_whitespace().count());
+    let options = ConversionOptions {
+        max_output_tokens: Some(8),
+        token_estimator: words,
+        ..Default::default()
+    };
+    let markdown = convert(ARTICLE, Some(options)).unwrap();
+    assert_eq!(markdown, "# Title\n\nFirst paragraph.\n\n<!-- truncated -->\n");
+}
+
+#[test]
+fn test_split_documents_budget_applies_to_joined_output() {
+    let options = ConversionOptions {
+        split_documents: true,
+        extract_metadata: false,
+        max_output_chars: Some(45),
+        ..Default::default()
+    };
+    let markdown = convert(
+        "<body><p>alpha beta gamma delta</p></body><body><p>epsilon zeta eta theta</p></body>",
+        Some(options),
+    )
+    .unwrap();
+    assert_eq!(markdown, "alpha beta gamma delta\n\n<!-- truncated -->\n");
+}
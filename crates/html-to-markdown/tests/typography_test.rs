@@ -0,0 +1,53 @@
+//! Tests for the `localized_punctuation` option.
+
+use html_to_markdown_rs::{ConversionOptions, convert};
+
+fn convert_localized(html: &str) -> String {
+    let options = ConversionOptions {
+        localized_punctuation: true,
+        ..Default::default()
+    };
+    convert(html, Some(options)).unwrap()
+}
+
+#[test]
+fn test_ascii_quotes_by_default() {
+    let html = r#"<html lang="de"><body><p><q>Hallo</q></p></body></html>"#;
+    assert_eq!(convert(html, None).unwrap(), "\"Hallo\"\n");
+}
+
+#[test]
+fn test_document_language_quotes() {
+    let html = r#"<html lang="de"><body><p>Er sagte <q>Hallo <q>du</q></q>.</p></body></html>"#;
+    assert_eq!(
+        convert_localized(html),
+        "Er sagte \u{201E}Hallo \u{201A}du\u{2018}\u{201C}.\n"
+    );
+}
+
+#[test]
+fn test_nearest_lang_attribute_wins() {
+    let html = r#"<html lang="en"><body><p><q>hi</q> <span lang="fr"><q>salut</q></span></p></body></html>"#;
+    assert_eq!(
+        convert_localized(html),
+        "\u{201C}hi\u{201D} \u{AB}\u{202F}salut\u{202F}\u{BB}\n"
+    );
+}
+
+#[test]
+fn test_cjk_abbreviation_parentheses() {
+    let html = r#"<p lang="ja"><abbr title="日本">JP</abbr></p>"#;
+    assert_eq!(convert_localized(html), "JP\u{FF08}日本\u{FF09}\n");
+}
+
+#[test]
+fn test_blockquote_attribution_dash() {
+    let html = r#"<blockquote lang="zh-CN" cite="https://example.cn"><p>引用</p></blockquote>"#;
+    let result = convert_localized(html);
+    assert!(result.contains("\u{2014}\u{2014} <https://example.cn>"), "{result:?}");
+}
+
+#[test]
+fn test_no_language_keeps_ascii() {
+    assert_eq!(convert_localized("<p><q>say \"x\"</q></p>"), "\"say \\\"x\\\"\"\n");
+}
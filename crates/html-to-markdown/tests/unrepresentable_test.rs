@@ -0,0 +1,85 @@
+//! Tests for the `unrepresentable_policy` option and `convert_with_diagnostics`.
+
+use html_to_markdown_rs::{
+    BidiMode, ConversionOptions, DiagnosticKind, UnrepresentablePolicy, convert, convert_with_diagnostics,
+};
+
+const HTML: &str = "<p>Read <bdo dir=\"rtl\">olleh</bdo> in a very\u{AD}long<wbr>word.</p>";
+
+fn options(unrepresentable_policy: UnrepresentablePolicy) -> ConversionOptions {
+    ConversionOptions {
+        unrepresentable_policy,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_drop_is_default_and_silent() {
+    let (markdown, diagnostics) = convert_with_diagnostics(HTML, None).unwrap();
+    assert_eq!(markdown, "Read olleh in a verylongword.\n");
+    assert!(diagnostics.is_empty());
+    assert_eq!(
+        markdown,
+        convert(HTML, Some(options(UnrepresentablePolicy::Drop))).unwrap()
+    );
+}
+
+#[test]
+fn test_passthrough_keeps_html_and_characters() {
+    let result = convert(HTML, Some(options(UnrepresentablePolicy::Passthrough))).unwrap();
+    assert_eq!(
+        result,
+        "Read <bdo dir=\"rtl\">olleh</bdo> in a very\u{AD}long<wbr>word.\n"
+    );
+}
+
+#[test]
+fn test_diagnose_drops_and_records() {
+    let (markdown, diagnostics) =
+        convert_with_diagnostics(HTML, Some(options(UnrepresentablePolicy::Diagnose))).unwrap();
+
+    assert_eq!(markdown, "Read olleh in a verylongword.\n");
+    assert_eq!(diagnostics.len(), 3, "{diagnostics:?}");
+    assert!(
+        diagnostics
+            .iter()
+            .all(|d| d.kind == DiagnosticKind::UnrepresentableContent)
+    );
+
+    let elements: Vec<_> = diagnostics.iter().map(|d| d.element.as_deref()).collect();
+    assert!(elements.contains(&Some("bdo")));
+    assert!(elements.contains(&Some("wbr")));
+    assert!(elements.contains(&None));
+    assert!(diagnostics.iter().any(|d| d.message.contains("dir=\"rtl\"")));
+}
+
+#[test]
+fn test_bdi_without_dir() {
+    let result = convert(
+        "<p>User <bdi>إيان</bdi> posted</p>",
+        Some(options(UnrepresentablePolicy::Passthrough)),
+    )
+    .unwrap();
+    assert_eq!(result, "User <bdi>إيان</bdi> posted\n");
+}
+
+#[test]
+fn test_bidi_html_mode_is_not_double_wrapped() {
+    let options = ConversionOptions {
+        bidi_mode: BidiMode::Html,
+        ..options(UnrepresentablePolicy::Passthrough)
+    };
+    let result = convert("<p>a <bdo dir=\"rtl\">b</bdo></p>", Some(options)).unwrap();
+    assert_eq!(result.matches("dir=\"rtl\"").count(), 1, "{result:?}");
+}
+
+#[test]
+fn test_code_is_untouched() {
+    let (markdown, diagnostics) = convert_with_diagnostics(
+        "<pre><code>a\u{AD}b</code></pre>",
+        Some(options(UnrepresentablePolicy::Diagnose)),
+    )
+    .unwrap();
+    assert!(markdown.contains("a\u{AD}b"));
+    assert!(diagnostics.is_empty());
+}
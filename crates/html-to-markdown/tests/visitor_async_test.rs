@@ -0,0 +1,165 @@
+//! Integration tests for async visitor dispatch.
+//!
+//! Async visitors must see the same callbacks, and produce the same output, as an
+//! equivalent synchronous visitor.
+
+#![cfg(feature = "async-visitor")]
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use async_trait::async_trait;
+use html_to_markdown_rs::visitor::{
+    AsyncHtmlVisitor, HtmlVisitor, NodeContext, VisitResult, convert_with_visitor_async,
+};
+use html_to_markdown_rs::{ConversionError, convert, convert_with_async_visitor, convert_with_visitor};
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+/// Future that returns `Pending` once before completing, like a host-language callback would.
+async fn yield_once() {
+    let mut yielded = false;
+    std::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await;
+}
+
+#[derive(Debug, Default)]
+struct RecordingVisitor {
+    events: Vec<String>,
+}
+
+impl HtmlVisitor for RecordingVisitor {
+    fn visit_text(&mut self, _ctx: &NodeContext, text: &str) -> VisitResult {
+        self.events.push(format!("text:{text}"));
+        VisitResult::Continue
+    }
+
+    fn visit_link(&mut self, _ctx: &NodeContext, href: &str, text: &str, _title: Option<&str>) -> VisitResult {
+        self.events.push(format!("link:{text}->{href}"));
+        VisitResult::Continue
+    }
+}
+
+#[derive(Debug, Default)]
+struct AsyncRecordingVisitor {
+    events: Vec<String>,
+}
+
+#[async_trait]
+impl AsyncHtmlVisitor for AsyncRecordingVisitor {
+    async fn visit_text(&mut self, _ctx: &NodeContext, text: &str) -> VisitResult {
+        yield_once().await;
+        self.events.push(format!("text:{text}"));
+        VisitResult::Continue
+    }
+
+    async fn visit_link(&mut self, _ctx: &NodeContext, href: &str, text: &str, _title: Option<&str>) -> VisitResult {
+        yield_once().await;
+        self.events.push(format!("link:{text}->{href}"));
+        VisitResult::Continue
+    }
+}
+
+#[derive(Debug, Default)]
+struct AsyncRewritingVisitor {
+    calls: usize,
+}
+
+#[async_trait]
+impl AsyncHtmlVisitor for AsyncRewritingVisitor {
+    async fn visit_link(&mut self, _ctx: &NodeContext, href: &str, text: &str, _title: Option<&str>) -> VisitResult {
+        yield_once().await;
+        self.calls += 1;
+        VisitResult::Custom(format!("{text} <{href}>"))
+    }
+
+    async fn visit_image(&mut self, _ctx: &NodeContext, _src: &str, _alt: &str, _title: Option<&str>) -> VisitResult {
+        VisitResult::Skip
+    }
+
+    async fn visit_code_inline(&mut self, _ctx: &NodeContext, code: &str) -> VisitResult {
+        if code == "boom" {
+            VisitResult::Error("refusing to convert boom".to_string())
+        } else {
+            VisitResult::Continue
+        }
+    }
+}
+
+const HTML: &str = r#"<h1>Title</h1><p>Read <a href="/a">the docs</a> and <a href="/b">more</a>.</p><p><img src="x.png" alt="x">Done</p>"#;
+
+#[test]
+fn test_observing_visitor_matches_sync_visitor() {
+    let sync_visitor = Rc::new(RefCell::new(RecordingVisitor::default()));
+    let expected = convert_with_visitor(HTML, None, Some(sync_visitor.clone())).unwrap();
+
+    let mut visitor = AsyncRecordingVisitor::default();
+    let markdown = block_on(convert_with_visitor_async(HTML, None, &mut visitor)).unwrap();
+
+    assert_eq!(markdown, expected);
+    assert_eq!(markdown, convert(HTML, None).unwrap());
+    assert_eq!(visitor.events, sync_visitor.borrow().events);
+    assert!(visitor.events.contains(&"link:the docs->/a".to_string()));
+}
+
+#[test]
+fn test_custom_and_skip_results_applied() {
+    let mut visitor = AsyncRewritingVisitor::default();
+    let markdown = block_on(convert_with_visitor_async(HTML, None, &mut visitor)).unwrap();
+
+    assert!(markdown.contains("Read the docs </a> and more </b>."), "{markdown}");
+    assert!(!markdown.contains("x.png"), "{markdown}");
+    assert!(markdown.contains("Done"), "{markdown}");
+    assert_eq!(visitor.calls, 2, "each callback should be awaited exactly once");
+}
+
+#[test]
+fn test_visitor_error_stops_conversion() {
+    let mut visitor = AsyncRewritingVisitor::default();
+    let result = block_on(convert_with_visitor_async(
+        "<p><code>ok</code> then <code>boom</code></p>",
+        None,
+        &mut visitor,
+    ));
+
+    match result {
+        Err(ConversionError::Visitor(message)) => assert!(message.contains("refusing to convert boom")),
+        other => panic!("expected visitor error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_convert_with_async_visitor_uses_handle() {
+    let handle: Rc<RefCell<dyn AsyncHtmlVisitor>> = Rc::new(RefCell::new(AsyncRewritingVisitor::default()));
+    let markdown = block_on(convert_with_async_visitor(
+        r#"<a href="https://example.com">site</a>"#,
+        None,
+        Some(handle),
+    ))
+    .unwrap();
+
+    assert_eq!(markdown, "site <https://example.com>\n");
+    assert_eq!(
+        block_on(convert_with_async_visitor("<p>plain</p>", None, None)).unwrap(),
+        "plain\n"
+    );
+}
@@ -0,0 +1,169 @@
+//! Tests for reading and converting WARC archives.
+#![cfg(feature = "warc")]
+
+use std::io::Write;
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use html_to_markdown_rs::warc::{WarcReader, convert_warc};
+
+fn record(warc_type: &str, uri: &str, content_type: &str, block: &[u8]) -> Vec<u8> {
+    let mut out = format!(
+        "WARC/1.1\r\nWARC-Type: {warc_type}\r\nWARC-Target-URI: {uri}\r\nWARC-Date: 2024-05-01T12:00:00Z\r\n\
+         WARC-Record-ID: <urn:uuid:{uri}>\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\r\n",
+        block.len()
+    )
+    .into_bytes();
+    out.extend_from_slice(block);
+    out.extend_from_slice(b"\r\n\r\n");
+    out
+}
+
+fn response(uri: &str, http_head: &str, body: &[u8]) -> Vec<u8> {
+    let mut block = format!("{http_head}\r\n\r\n").into_bytes();
+    block.extend_from_slice(body);
+    record("response", uri, "application/http; msgtype=response", &block)
+}
+
+fn html_response(uri: &str, body: &str) -> Vec<u8> {
+    response(
+        uri,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8",
+        body.as_bytes(),
+    )
+}
+
+fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn test_only_html_responses_are_converted() {
+    let archive = [
+        record("warcinfo", "", "application/warc-fields", b"software: test\r\n"),
+        record(
+            "request",
+            "https://a.test/",
+            "application/http; msgtype=request",
+            b"GET / HTTP/1.1\r\n\r\n",
+        ),
+        html_response("https://a.test/", "<h1>A</h1>"),
+        response(
+            "https://a.test/logo.png",
+            "HTTP/1.1 200 OK\r\nContent-Type: image/png",
+            b"\x89PNG",
+        ),
+        response(
+            "https://a.test/missing",
+            "HTTP/1.1 404 Not Found\r\nContent-Type: text/html",
+            b"<p>nope</p>",
+        ),
+        record("resource", "file:///b.html", "text/html", b"<p><em>B</em></p>"),
+    ]
+    .concat();
+
+    let documents: Vec<_> = convert_warc(archive.as_slice(), None)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].target_uri, "https://a.test/");
+    assert_eq!(documents[0].date.as_deref(), Some("2024-05-01T12:00:00Z"));
+    assert_eq!(documents[0].record_id.as_deref(), Some("<urn:uuid:https://a.test/>"));
+    assert_eq!(documents[0].markdown, "# A\n");
+    assert_eq!(documents[1].target_uri, "file:///b.html");
+    assert_eq!(documents[1].markdown, "*B*\n");
+}
+
+#[test]
+fn test_gzip_archive_with_member_per_record() {
+    let archive = [
+        gzip(&html_response("https://a.test/1", "<p>one</p>")),
+        gzip(&html_response("https://a.test/2", "<p>two</p>")),
+    ]
+    .concat();
+
+    let markdown: Vec<_> = convert_warc(archive.as_slice(), None)
+        .map(|document| document.unwrap().markdown)
+        .collect();
+    assert_eq!(markdown, ["one\n", "two\n"]);
+}
+
+#[test]
+fn test_chunked_gzip_payload_and_charset() {
+    let compressed = gzip("<p>caf\u{e9}</p>".as_bytes());
+    let mut chunked = format!("{:x}\r\n", compressed.len()).into_bytes();
+    chunked.extend_from_slice(&compressed);
+    chunked.extend_from_slice(b"\r\n0\r\n\r\n");
+    let archive = [
+        response(
+            "https://a.test/chunked",
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nTransfer-Encoding: chunked\r\nContent-Encoding: gzip",
+            &chunked,
+        ),
+        response(
+            "https://a.test/latin1",
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=iso-8859-1",
+            b"<p>na\xefve</p>",
+        ),
+        response(
+            "https://a.test/meta",
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html",
+            b"<meta charset=\"windows-1252\"><p>\x93quoted\x94</p>",
+        ),
+    ]
+    .concat();
+
+    let records: Vec<_> = WarcReader::new(archive.as_slice()).map(Result::unwrap).collect();
+    assert_eq!(records[0].html, "<p>caf\u{e9}</p>");
+    assert_eq!(records[1].html, "<p>na\u{ef}ve</p>");
+    assert!(records[2].html.contains("\u{201c}quoted\u{201d}"));
+}
+
+#[test]
+fn test_unreadable_payload_does_not_stop_iteration() {
+    let archive = [
+        response(
+            "https://a.test/br",
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Encoding: br",
+            b"\x0b\x02\x80",
+        ),
+        html_response("https://a.test/ok", "<p>ok</p>"),
+    ]
+    .concat();
+
+    let results: Vec<_> = convert_warc(archive.as_slice(), None).collect();
+    assert_eq!(results.len(), 2);
+    let err = results[0].as_ref().unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("https://a.test/br: unsupported Content-Encoding 'br'"),
+        "{err}"
+    );
+    assert_eq!(results[1].as_ref().unwrap().markdown, "ok\n");
+}
+
+#[test]
+fn test_malformed_framing_ends_iteration() {
+    let mut archive = html_response("https://a.test/", "<p>first</p>");
+    archive.extend_from_slice(b"WARC/1.1\r\nWARC-Type: response\r\n\r\n");
+    archive.extend_from_slice(&html_response("https://a.test/unreached", "<p>x</p>"));
+
+    let results: Vec<_> = convert_warc(archive.as_slice(), None).collect();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    let err = results[1].as_ref().unwrap_err();
+    assert!(err.to_string().contains("record without Content-Length"), "{err}");
+
+    let full = html_response("https://a.test/", "<p>cut</p>");
+    let results: Vec<_> = WarcReader::new(&full[..full.len() - 10]).collect();
+    assert_eq!(results.len(), 1);
+    assert!(
+        results[0]
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .contains("record truncated")
+    );
+}
@@ -0,0 +1,148 @@
+//! Tests for converting into `io::Write` and `fmt::Write` sinks.
+
+use html_to_markdown_rs::{ConversionError, ConversionOptions, convert, convert_to_fmt, convert_to_writer};
+use std::io::{self, Write};
+use std::path::Path;
+
+fn via_writer(html: &str, options: Option<ConversionOptions>) -> String {
+    let mut out = Vec::new();
+    convert_to_writer(html, options, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn assert_same(html: &str, options: Option<ConversionOptions>) {
+    let expected = without_svg_data(&convert(html, options.clone()).unwrap());
+    assert_eq!(
+        without_svg_data(&via_writer(html, options.clone())),
+        expected,
+        "io::Write output differs for {html:?}"
+    );
+    let mut text = String::new();
+    convert_to_fmt(html, options, &mut text).unwrap();
+    assert_eq!(
+        without_svg_data(&text),
+        expected,
+        "fmt::Write output differs for {html:?}"
+    );
+}
+
+/// Inline SVGs are serialized with their attributes in no fixed order, so drop their payload.
+fn without_svg_data(markdown: &str) -> String {
+    const PREFIX: &str = "data:image/svg+xml;base64,";
+    let mut out = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+    while let Some(start) = rest.find(PREFIX) {
+        out.push_str(&rest[..start + PREFIX.len()]);
+        rest = &rest[start + PREFIX.len()..];
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '=')))
+            .unwrap_or(rest.len());
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Records every chunk handed to it.
+#[derive(Default)]
+struct Chunks(Vec<String>);
+
+impl Write for Chunks {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.push(String::from_utf8_lossy(buf).into_owned());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_writer_matches_convert_on_documents() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test_documents/html");
+    let mut documents = Vec::new();
+    for dir in ["issues", "visitor"] {
+        for entry in std::fs::read_dir(root.join(dir)).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "html") {
+                documents.push(std::fs::read_to_string(&path).unwrap());
+            }
+        }
+    }
+    assert!(!documents.is_empty());
+
+    // The captured pages nest deeply enough to need more than the default test thread stack
+    // in debug builds.
+    std::thread::Builder::new()
+        .stack_size(16 * 1024 * 1024)
+        .spawn(move || {
+            for html in &documents {
+                assert_same(html, None);
+            }
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn test_writer_matches_convert_on_edge_cases() {
+    for html in [
+        "",
+        "   ",
+        "plain text &amp; entities",
+        "<p>one</p>",
+        "<html><head><title>T</title></head><body><h1>A</h1><p>x</p><pre>a\n\n\nb</pre><ul><li>i</li></ul></body></html>",
+        "<body><p>first</p>\n\n<p>second  </p><br><br></body>",
+        "<div><body><p>nested</p><p>body</p></body></div><p>after</p>",
+        "<html dir=\"rtl\"><body><p>שלום</p><p>עולם</p></body></html>",
+        "<p>a</p><div><p>b</p></div><p></p><p> </p>",
+    ] {
+        assert_same(html, None);
+    }
+
+    let html = "<html><head><title>Doc</title></head><body><h2>S</h2><p><abbr title=\"x\">X</abbr></p><h2>T</h2><p>y</p></body></html>";
+    assert_same(
+        html,
+        Some(ConversionOptions {
+            extract_metadata: true,
+            title_footnotes: true,
+            ..Default::default()
+        }),
+    );
+    assert_same(
+        "<p>a long paragraph that is wrapped at a narrow width</p>",
+        Some(ConversionOptions {
+            wrap: true,
+            wrap_width: 20,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+fn test_blocks_are_written_as_they_finish() {
+    let html = (0..5).fold(String::new(), |html, i| html + &format!("<p>paragraph {i}</p>"));
+    let mut chunks = Chunks::default();
+    convert_to_writer(&format!("<html><body>{html}</body></html>"), None, &mut chunks).unwrap();
+
+    assert!(chunks.0.len() >= 5, "{:?}", chunks.0);
+    assert_eq!(chunks.0.concat(), convert(&html, None).unwrap());
+}
+
+#[test]
+fn test_write_errors_are_returned() {
+    struct Broken;
+    impl Write for Broken {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let err = convert_to_writer("<p>a</p><p>b</p>", None, &mut Broken).unwrap_err();
+    assert!(matches!(err, ConversionError::IoError(ref io) if io.kind() == io::ErrorKind::BrokenPipe));
+}
@@ -5,7 +5,10 @@ use std::{
     path::{Path, PathBuf},
     process::{Command, Stdio},
 };
-use wasmtime::{Engine, Instance, Memory, Module, Store, TypedFunc};
+use wasmtime::{Engine, Instance, Linker, Memory, Module, Store, TypedFunc};
+use wasmtime_wasi::p1::{self, WasiP1Ctx};
+use wasmtime_wasi::p2::pipe::{MemoryInputPipe, MemoryOutputPipe};
+use wasmtime_wasi::{I32Exit, WasiCtxBuilder};
 
 fn workspace_root() -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -66,14 +69,14 @@ fn cargo_invocation() -> Command {
     }
 }
 
-fn wasm_target_installed() -> bool {
+fn wasm_target_installed(target: &str) -> bool {
     let mut rustc_cmd = Command::new("rustc");
     if let Some(rustc) = rustup_rustc() {
         rustc_cmd = Command::new(rustc);
     }
 
     let output = rustc_cmd
-        .args(["--print", "target-libdir", "--target", "wasm32-unknown-unknown"])
+        .args(["--print", "target-libdir", "--target", target])
         .stdout(Stdio::piped())
         .stderr(Stdio::null())
         .output();
@@ -103,7 +106,7 @@ fn wasm_target_installed() -> bool {
 }
 
 fn build_wasm_module() -> Result<Option<PathBuf>> {
-    if !wasm_target_installed() {
+    if !wasm_target_installed("wasm32-unknown-unknown") {
         eprintln!("Skipping WASM wasmtime tests: rust target wasm32-unknown-unknown not installed");
         return Ok(None);
     }
@@ -138,6 +141,88 @@ fn build_wasm_module() -> Result<Option<PathBuf>> {
     Ok(Some(artefact))
 }
 
+fn build_wasi_command() -> Result<Option<PathBuf>> {
+    if !wasm_target_installed("wasm32-wasip1") {
+        eprintln!("Skipping WASI command tests: rust target wasm32-wasip1 not installed");
+        return Ok(None);
+    }
+
+    let mut command = cargo_invocation();
+    command.args([
+        "build",
+        "-p",
+        "html-to-markdown-wasm",
+        "--bin",
+        "html-to-markdown-wasi",
+        "--target",
+        "wasm32-wasip1",
+        "--release",
+        "--no-default-features",
+        "--features",
+        "wasi",
+    ]);
+    if let Some(rustc) = rustup_rustc() {
+        command.env("RUSTC", rustc);
+    }
+
+    let status = command
+        .current_dir(workspace_root())
+        .status()
+        .context("unable to spawn cargo build for wasi command")?;
+    if !status.success() {
+        anyhow::bail!("building html-to-markdown-wasi failed");
+    }
+    let artefact = workspace_root().join("target/wasm32-wasip1/release/html-to-markdown-wasi.wasm");
+    if !artefact.exists() {
+        anyhow::bail!("expected wasi command at {}", artefact.display());
+    }
+    Ok(Some(artefact))
+}
+
+/// Output of one run of the WASI command.
+struct WasiRun {
+    exit_code: i32,
+    stdout: String,
+    stderr: String,
+}
+
+/// Runs the WASI command with the given stdin, argv and environment.
+fn run_wasi_command(wasm_path: &Path, stdin: &str, args: &[&str], env: &[(&str, &str)]) -> Result<WasiRun> {
+    let engine = Engine::default();
+    let module = Module::from_file(&engine, wasm_path)?;
+    let mut linker: Linker<WasiP1Ctx> = Linker::new(&engine);
+    p1::add_to_linker_sync(&mut linker, |ctx| ctx)?;
+
+    let stdout = MemoryOutputPipe::new(1 << 20);
+    let stderr = MemoryOutputPipe::new(1 << 16);
+    let mut builder = WasiCtxBuilder::new();
+    builder
+        .stdin(MemoryInputPipe::new(stdin.to_string()))
+        .stdout(stdout.clone())
+        .stderr(stderr.clone())
+        .arg("html-to-markdown-wasi")
+        .args(args)
+        .envs(env);
+    let mut store = Store::new(&engine, builder.build_p1());
+
+    let instance = linker.instantiate(&mut store, &module)?;
+    let start = instance.get_typed_func::<(), ()>(&mut store, "_start")?;
+    let exit_code = match start.call(&mut store, ()) {
+        Ok(()) => 0,
+        Err(err) => match err.downcast_ref::<I32Exit>() {
+            Some(exit) => exit.0,
+            None => return Err(err),
+        },
+    };
+    drop(store);
+
+    Ok(WasiRun {
+        exit_code,
+        stdout: String::from_utf8(stdout.contents().to_vec())?,
+        stderr: String::from_utf8(stderr.contents().to_vec())?,
+    })
+}
+
 struct WasmHarness {
     store: Store<()>,
     memory: Memory,
@@ -279,3 +364,45 @@ fn respects_conversion_options() -> Result<()> {
     assert_eq!(output.trim(), expected.trim());
     Ok(())
 }
+
+#[test]
+fn wasi_command_converts_stdin_with_argv_and_env_options() -> Result<()> {
+    let Some(wasm_path) = build_wasi_command()? else {
+        return Ok(());
+    };
+    let html = "<h1>Title</h1><p>content here</p>";
+    let options = ConversionOptions {
+        heading_style: HeadingStyle::Underlined,
+        ..Default::default()
+    };
+    #[cfg(not(feature = "visitor"))]
+    let expected = html_to_markdown_rs::convert(html, Some(options))?;
+    #[cfg(feature = "visitor")]
+    let expected = html_to_markdown_rs::convert(html, Some(options), None)?;
+
+    let from_argv = run_wasi_command(&wasm_path, html, &["--options", r#"{"headingStyle":"underlined"}"#], &[])?;
+    assert_eq!(from_argv.exit_code, 0, "stderr: {}", from_argv.stderr);
+    assert_eq!(from_argv.stdout, expected);
+
+    let from_env = run_wasi_command(
+        &wasm_path,
+        html,
+        &[],
+        &[("HTML_TO_MARKDOWN_OPTIONS", r#"{"headingStyle":"underlined"}"#)],
+    )?;
+    assert_eq!(from_env.exit_code, 0, "stderr: {}", from_env.stderr);
+    assert_eq!(from_env.stdout, expected);
+    Ok(())
+}
+
+#[test]
+fn wasi_command_rejects_invalid_options() -> Result<()> {
+    let Some(wasm_path) = build_wasi_command()? else {
+        return Ok(());
+    };
+    let run = run_wasi_command(&wasm_path, "<p>text</p>", &["--options", "{not json"], &[])?;
+    assert_eq!(run.exit_code, 2);
+    assert!(run.stdout.is_empty());
+    assert!(run.stderr.contains("invalid options JSON"), "stderr: {}", run.stderr);
+    Ok(())
+}
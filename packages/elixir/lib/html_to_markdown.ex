@@ -22,6 +22,13 @@ defmodule HtmlToMarkdown do
 
   The `options` argument accepts an `%HtmlToMarkdown.Options{}` struct,
   a map/keyword list with option keys, or `nil` (defaults).
+
+  Unknown keys in a map or keyword list are ignored. Add `strict_options: true`
+  to reject them instead with `{:error, {:unknown_option, key}}`, where `key` is the
+  offending option name as a string:
+
+      iex> HtmlToMarkdown.convert("<p>Hi</p>", heading_stlye: :atx, strict_options: true)
+      {:error, {:unknown_option, "heading_stlye"}}
   """
   @spec convert(String.t(), options_input()) :: {:ok, String.t()} | {:error, term()}
   def convert(html, options \\ nil) when is_binary(html) do
@@ -44,6 +51,19 @@ defmodule HtmlToMarkdown do
     end
   end
 
+  @doc """
+  Convert a list of HTML documents with shared options in a single native call.
+
+  Returns one `{:ok, markdown}` or `{:error, reason}` tuple per document, in input order,
+  so a single malformed page does not fail the whole batch. Invalid options fail the
+  batch as a whole with `{:error, reason}`.
+  """
+  @spec convert_batch([String.t()], options_input()) ::
+          [{:ok, String.t()} | {:error, term()}] | {:error, term()}
+  def convert_batch(htmls, options \\ nil) when is_list(htmls) do
+    Native.convert_batch(htmls, normalize_options(options))
+  end
+
   @doc """
   Convert HTML using a reusable options handle.
   """
@@ -63,6 +83,27 @@ defmodule HtmlToMarkdown do
     end
   end
 
+  @doc """
+  Convert HTML in a separate process that reports to `pid` (the caller by default).
+
+  Returns `{:ok, task_pid}` right away, so a GenServer can keep serving calls while a large
+  page converts on a dirty CPU scheduler. `pid` receives:
+
+    * `{:progress, percent}` - how far through the document the conversion is, from 0 to 100
+    * `{:chunk, binary}` - a piece of the Markdown, cut between blocks; in order, the chunks
+      join to the output of `convert/2`
+    * `:done` - after the last chunk
+    * `{:error, reason}` - instead of chunks, if the conversion fails
+
+  Progress arrives during the conversion and the chunks once it is complete. `options` may
+  also be a handle from `options/1`.
+  """
+  @spec convert_stream(String.t(), options_input() | reference(), pid()) :: {:ok, pid()}
+  def convert_stream(html, options \\ nil, pid \\ self()) when is_binary(html) and is_pid(pid) do
+    options = if is_reference(options), do: options, else: normalize_options(options) || %{}
+    Task.start(fn -> Native.convert_stream(html, options, pid) end)
+  end
+
   @doc """
   Convert HTML and collect inline image assets.
 
@@ -146,6 +187,14 @@ defmodule HtmlToMarkdown do
     end
   end
 
+  @doc """
+  List the option keys accepted in an options map or keyword list.
+  """
+  @spec known_options() :: [atom()]
+  def known_options do
+    Enum.map(Native.known_options(), &String.to_atom/1)
+  end
+
   @doc """
   Start Rust-side profiling and write a flamegraph to the given output path.
   """
@@ -170,12 +219,42 @@ defmodule HtmlToMarkdown do
 
   defp normalize_options(nil), do: nil
 
+  defp normalize_options(%Options{} = options), do: Options.to_map(options)
+
   defp normalize_options(options) do
-    options
-    |> Options.new()
-    |> Options.to_map()
+    attrs = Map.new(options)
+    {strict, attrs} = Map.pop(attrs, :strict_options, false)
+    {strict_string, attrs} = Map.pop(attrs, "strict_options", false)
+    options_map = attrs |> Options.new() |> Options.to_map()
+
+    if strict == true or strict_string == true do
+      # Options.new/1 drops keys it does not know, so hand them to the native
+      # layer, which reports the first one as {:unknown_option, key}.
+      options_map
+      |> Map.merge(unknown_options(attrs))
+      |> Map.put("strict_options", true)
+    else
+      options_map
+    end
   end
 
+  defp unknown_options(attrs) do
+    known = Native.known_options()
+
+    for {key, value} <- attrs,
+        name = option_name(key),
+        name not in known,
+        into: %{},
+        do: {name, value}
+  end
+
+  defp option_name(key) when is_atom(key), do: Atom.to_string(key)
+
+  defp option_name(key) when is_binary(key),
+    do: key |> String.trim() |> String.replace("-", "_")
+
+  defp option_name(key), do: inspect(key)
+
   defp into_inline_image(map) do
     data = fetch(map, :data)
     format = fetch(map, :format)
@@ -12,6 +12,8 @@ defmodule HtmlToMarkdown.MetadataConfig do
             extract_links: true,
             extract_images: true,
             extract_structured_data: true,
+            extract_resources: false,
+            extract_source_map: false,
             max_structured_data_size: @default_max_structured_data_size
 
   @type t :: %__MODULE__{
@@ -20,6 +22,8 @@ defmodule HtmlToMarkdown.MetadataConfig do
           extract_links: boolean(),
           extract_images: boolean(),
           extract_structured_data: boolean(),
+          extract_resources: boolean(),
+          extract_source_map: boolean(),
           max_structured_data_size: pos_integer()
         }
 
@@ -48,6 +52,8 @@ defmodule HtmlToMarkdown.MetadataConfig do
          :extract_links,
          :extract_images,
          :extract_structured_data,
+         :extract_resources,
+         :extract_source_map,
          :max_structured_data_size
        ],
        do: key,
@@ -64,6 +70,8 @@ defmodule HtmlToMarkdown.MetadataConfig do
       "extract_links" -> :extract_links
       "extract_images" -> :extract_images
       "extract_structured_data" -> :extract_structured_data
+      "extract_resources" -> :extract_resources
+      "extract_source_map" -> :extract_source_map
       "max_structured_data_size" -> :max_structured_data_size
       _ -> nil
     end
@@ -86,6 +94,8 @@ defmodule HtmlToMarkdown.MetadataConfig do
          {:ok, extract_images} <- ensure_boolean(cfg.extract_images, :extract_images),
          {:ok, extract_structured_data} <-
            ensure_boolean(cfg.extract_structured_data, :extract_structured_data),
+         {:ok, extract_resources} <- ensure_boolean(cfg.extract_resources, :extract_resources),
+         {:ok, extract_source_map} <- ensure_boolean(cfg.extract_source_map, :extract_source_map),
          {:ok, max_size} <-
            positive_integer(cfg.max_structured_data_size, :max_structured_data_size) do
       {:ok,
@@ -95,6 +105,8 @@ defmodule HtmlToMarkdown.MetadataConfig do
          "extract_links" => extract_links,
          "extract_images" => extract_images,
          "extract_structured_data" => extract_structured_data,
+         "extract_resources" => extract_resources,
+         "extract_source_map" => extract_source_map,
          "max_structured_data_size" => max_size
        }}
     end
@@ -13,6 +13,7 @@ defmodule HtmlToMarkdown.Native do
 
   def convert(_html), do: :erlang.nif_error(:nif_not_loaded)
   def convert_with_options_map(_html, _options), do: :erlang.nif_error(:nif_not_loaded)
+  def convert_batch(_htmls, _options), do: :erlang.nif_error(:nif_not_loaded)
   def convert_with_handle(_html, _handle), do: :erlang.nif_error(:nif_not_loaded)
   def create_options_handle(_options), do: :erlang.nif_error(:nif_not_loaded)
   def convert_with_inline_images(_html, _options, _config), do: :erlang.nif_error(:nif_not_loaded)
@@ -20,4 +21,6 @@ defmodule HtmlToMarkdown.Native do
   def start_profiling(_output, _frequency), do: :erlang.nif_error(:nif_not_loaded)
   def stop_profiling, do: :erlang.nif_error(:nif_not_loaded)
   def convert_with_visitor(_html, _options, _visitor_pid), do: :erlang.nif_error(:nif_not_loaded)
+  def convert_stream(_html, _options, _pid), do: :erlang.nif_error(:nif_not_loaded)
+  def known_options, do: :erlang.nif_error(:nif_not_loaded)
 end
@@ -13,6 +13,21 @@ defmodule HtmlToMarkdown.Options do
   @list_indent_types [:spaces, :tabs]
   @highlight_styles [:double_equal, :html, :bold, :none]
   @whitespace_modes [:normalized, :strict]
+  @header_break_styles [:inherit, :space, :slash, :html]
+  @contact_link_styles [:link, :autolink, :text]
+  @dfn_styles [:emphasis, :text, :html]
+  @time_styles [:text, :datetime, :html]
+  @abbr_styles [:expand, :text, :html]
+  @details_styles [:markdown, :html]
+  @figure_styles [:italic, :blockquote, :html]
+  @srcset_policys [:first, :largest, :smallest]
+  @math_styles [:comment, :dollar, :fenced]
+  @svg_text_modes [:off, :alt, :caption, :replace]
+  @unrepresentable_policys [:drop, :passthrough, :diagnose]
+  @list_spacings [:auto, :tight, :loose]
+  @bidi_modes [:ignore, :marks, :embedding, :html]
+  @front_matter_formats [:yaml, :toml, :json]
+  @nbsp_modes [:space, :preserve]
   @newline_styles [:spaces, :backslash]
   @code_block_styles [:indented, :backticks, :tildes]
 
@@ -40,12 +55,52 @@ defmodule HtmlToMarkdown.Options do
     :wrap,
     :wrap_width,
     :strip_tags,
+    :remove_tags,
     :preserve_tags,
     :convert_as_inline,
     :sub_symbol,
     :sup_symbol,
     :newline_style,
     :code_block_style,
+    :remove_space_before_punctuation,
+    :nbsp_in_prose,
+    :nbsp_in_tables,
+    :front_matter_format,
+    :remove_soft_hyphens,
+    :bidi_mode,
+    :localized_punctuation,
+    :ordered_markers,
+    :cycle_bullets,
+    :list_spacing,
+    :unrepresentable_policy,
+    :post_processors,
+    :collect_stats,
+    :title_fallback,
+    :pandoc_attributes,
+    :svg_text_mode,
+    :object_links,
+    :preserve_heading_ids,
+    :content_fingerprint,
+    :math_style,
+    :infer_code_language,
+    :code_block_trim,
+    :srcset_policy,
+    :figure_style,
+    :details_style,
+    :abbr_style,
+    :time_style,
+    :dfn_style,
+    :url_param_allowlist,
+    :url_param_denylist,
+    :contact_link_style,
+    :fidelity_threshold,
+    :title_footnotes,
+    :header_break_style,
+    :report_dropped_content,
+    :split_documents,
+    :preserved_attributes,
+    :max_output_chars,
+    :max_output_tokens,
     :preprocessing,
     :debug
   ]
@@ -73,12 +128,52 @@ defmodule HtmlToMarkdown.Options do
             wrap: false,
             wrap_width: 80,
             strip_tags: MapSet.new(),
+            remove_tags: MapSet.new(),
             preserve_tags: MapSet.new(),
             convert_as_inline: false,
             sub_symbol: "",
             sup_symbol: "",
             newline_style: :spaces,
             code_block_style: :backticks,
+            remove_space_before_punctuation: false,
+            nbsp_in_prose: :space,
+            nbsp_in_tables: :space,
+            front_matter_format: :yaml,
+            remove_soft_hyphens: true,
+            bidi_mode: :ignore,
+            localized_punctuation: false,
+            ordered_markers: ".",
+            cycle_bullets: true,
+            list_spacing: :auto,
+            unrepresentable_policy: :drop,
+            post_processors: [],
+            collect_stats: false,
+            title_fallback: [],
+            pandoc_attributes: false,
+            svg_text_mode: :off,
+            object_links: false,
+            preserve_heading_ids: false,
+            content_fingerprint: false,
+            math_style: :comment,
+            infer_code_language: false,
+            code_block_trim: true,
+            srcset_policy: :first,
+            figure_style: :italic,
+            details_style: :markdown,
+            abbr_style: :expand,
+            time_style: :text,
+            dfn_style: :emphasis,
+            url_param_allowlist: [],
+            url_param_denylist: ~w(utm_* fbclid gclid dclid gbraid wbraid msclkid mc_cid mc_eid _hsenc _hsmi igshid yclid),
+            contact_link_style: :link,
+            fidelity_threshold: 1.0,
+            title_footnotes: false,
+            header_break_style: :inherit,
+            report_dropped_content: false,
+            split_documents: false,
+            preserved_attributes: [],
+            max_output_chars: nil,
+            max_output_tokens: nil,
             preprocessing: %PreprocessingOptions{},
             debug: false
 
@@ -86,6 +181,21 @@ defmodule HtmlToMarkdown.Options do
   @type list_indent_type :: :spaces | :tabs
   @type highlight_style :: :double_equal | :html | :bold | :none
   @type whitespace_mode :: :normalized | :strict
+  @type header_break_style :: :inherit | :space | :slash | :html
+  @type contact_link_style :: :link | :autolink | :text
+  @type dfn_style :: :emphasis | :text | :html
+  @type time_style :: :text | :datetime | :html
+  @type abbr_style :: :expand | :text | :html
+  @type details_style :: :markdown | :html
+  @type figure_style :: :italic | :blockquote | :html
+  @type srcset_policy :: :first | :largest | :smallest
+  @type math_style :: :comment | :dollar | :fenced
+  @type svg_text_mode :: :off | :alt | :caption | :replace
+  @type unrepresentable_policy :: :drop | :passthrough | :diagnose
+  @type list_spacing :: :auto | :tight | :loose
+  @type bidi_mode :: :ignore | :marks | :embedding | :html
+  @type front_matter_format :: :yaml | :toml | :json
+  @type nbsp_mode :: :space | :preserve
   @type newline_style :: :spaces | :backslash
   @type code_block_style :: :indented | :backticks | :tildes
 
@@ -113,12 +223,52 @@ defmodule HtmlToMarkdown.Options do
           wrap: boolean(),
           wrap_width: pos_integer(),
           strip_tags: MapSet.t(),
+          remove_tags: MapSet.t(),
           preserve_tags: MapSet.t(),
           convert_as_inline: boolean(),
           sub_symbol: String.t(),
           sup_symbol: String.t(),
           newline_style: newline_style(),
           code_block_style: code_block_style(),
+          remove_space_before_punctuation: boolean(),
+          nbsp_in_prose: nbsp_mode(),
+          nbsp_in_tables: nbsp_mode(),
+          front_matter_format: front_matter_format(),
+          remove_soft_hyphens: boolean(),
+          bidi_mode: bidi_mode(),
+          localized_punctuation: boolean(),
+          ordered_markers: String.t(),
+          cycle_bullets: boolean(),
+          list_spacing: list_spacing(),
+          unrepresentable_policy: unrepresentable_policy(),
+          post_processors: [String.t()],
+          collect_stats: boolean(),
+          title_fallback: [String.t()],
+          pandoc_attributes: boolean(),
+          svg_text_mode: svg_text_mode(),
+          object_links: boolean(),
+          preserve_heading_ids: boolean(),
+          content_fingerprint: boolean(),
+          math_style: math_style(),
+          infer_code_language: boolean(),
+          code_block_trim: boolean(),
+          srcset_policy: srcset_policy(),
+          figure_style: figure_style(),
+          details_style: details_style(),
+          abbr_style: abbr_style(),
+          time_style: time_style(),
+          dfn_style: dfn_style(),
+          url_param_allowlist: [String.t()],
+          url_param_denylist: [String.t()],
+          contact_link_style: contact_link_style(),
+          fidelity_threshold: float(),
+          title_footnotes: boolean(),
+          header_break_style: header_break_style(),
+          report_dropped_content: boolean(),
+          split_documents: boolean(),
+          preserved_attributes: [String.t()],
+          max_output_chars: non_neg_integer() | nil,
+          max_output_tokens: non_neg_integer() | nil,
           preprocessing: PreprocessingOptions.t(),
           debug: boolean()
         }
@@ -180,6 +330,51 @@ defmodule HtmlToMarkdown.Options do
   defp normalize_value(:whitespace_mode, value),
     do: normalize_enum(value, @whitespace_modes, :normalized)
 
+  defp normalize_value(:header_break_style, value),
+    do: normalize_enum(value, @header_break_styles, :inherit)
+
+  defp normalize_value(:contact_link_style, value),
+    do: normalize_enum(value, @contact_link_styles, :link)
+
+  defp normalize_value(:dfn_style, value),
+    do: normalize_enum(value, @dfn_styles, :emphasis)
+
+  defp normalize_value(:time_style, value),
+    do: normalize_enum(value, @time_styles, :text)
+
+  defp normalize_value(:abbr_style, value),
+    do: normalize_enum(value, @abbr_styles, :expand)
+
+  defp normalize_value(:details_style, value),
+    do: normalize_enum(value, @details_styles, :markdown)
+
+  defp normalize_value(:figure_style, value),
+    do: normalize_enum(value, @figure_styles, :italic)
+
+  defp normalize_value(:srcset_policy, value),
+    do: normalize_enum(value, @srcset_policys, :first)
+
+  defp normalize_value(:math_style, value),
+    do: normalize_enum(value, @math_styles, :comment)
+
+  defp normalize_value(:svg_text_mode, value),
+    do: normalize_enum(value, @svg_text_modes, :off)
+
+  defp normalize_value(:unrepresentable_policy, value),
+    do: normalize_enum(value, @unrepresentable_policys, :drop)
+
+  defp normalize_value(:list_spacing, value),
+    do: normalize_enum(value, @list_spacings, :auto)
+
+  defp normalize_value(:bidi_mode, value),
+    do: normalize_enum(value, @bidi_modes, :ignore)
+
+  defp normalize_value(:front_matter_format, value),
+    do: normalize_enum(value, @front_matter_formats, :yaml)
+
+  defp normalize_value(:nbsp_in_prose, value), do: normalize_enum(value, @nbsp_modes, :space)
+  defp normalize_value(:nbsp_in_tables, value), do: normalize_enum(value, @nbsp_modes, :space)
+
   defp normalize_value(:newline_style, value), do: normalize_enum(value, @newline_styles, :spaces)
 
   defp normalize_value(:code_block_style, value),
@@ -201,10 +396,15 @@ defmodule HtmlToMarkdown.Options do
 
   defp normalize_value(:keep_inline_images_in, value), do: normalize_set(value)
   defp normalize_value(:strip_tags, value), do: normalize_set(value)
+  defp normalize_value(:remove_tags, value), do: normalize_set(value)
   defp normalize_value(:preserve_tags, value), do: normalize_set(value)
+  defp normalize_value(:post_processors, value), do: normalize_list(value)
+  defp normalize_value(:title_fallback, value), do: normalize_list(value)
 
   defp normalize_value(:wrap_width, value) when is_integer(value) and value > 0, do: value
   defp normalize_value(:list_indent_width, value) when is_integer(value) and value > 0, do: value
+  defp normalize_value(:max_output_chars, 0), do: nil
+  defp normalize_value(:max_output_tokens, 0), do: nil
   defp normalize_value(_, value) when is_boolean(value), do: value
   defp normalize_value(_, value) when is_binary(value), do: value
   defp normalize_value(_, value) when is_integer(value), do: value
@@ -238,6 +438,11 @@ defmodule HtmlToMarkdown.Options do
   defp normalize_set(value) when is_atom(value), do: MapSet.new([normalize_string!(value)])
   defp normalize_set(_), do: MapSet.new()
 
+  defp normalize_list(value) when is_list(value), do: Enum.map(value, &normalize_string!/1)
+  defp normalize_list(nil), do: []
+  defp normalize_list(value) when is_binary(value) or is_atom(value), do: [normalize_string!(value)]
+  defp normalize_list(_), do: []
+
   defp normalize_string!(value) when is_binary(value), do: value
   defp normalize_string!(value) when is_atom(value), do: Atom.to_string(value)
   defp normalize_string!(value), do: to_string(value)
@@ -269,12 +474,52 @@ defmodule HtmlToMarkdown.Options do
       "wrap" => opts.wrap,
       "wrap_width" => opts.wrap_width,
       "strip_tags" => mapset_to_list(opts.strip_tags),
+      "remove_tags" => mapset_to_list(opts.remove_tags),
       "preserve_tags" => mapset_to_list(opts.preserve_tags),
       "convert_as_inline" => opts.convert_as_inline,
       "sub_symbol" => opts.sub_symbol,
       "sup_symbol" => opts.sup_symbol,
       "newline_style" => Atom.to_string(opts.newline_style),
       "code_block_style" => Atom.to_string(opts.code_block_style),
+      "remove_space_before_punctuation" => opts.remove_space_before_punctuation,
+      "nbsp_in_prose" => Atom.to_string(opts.nbsp_in_prose),
+      "nbsp_in_tables" => Atom.to_string(opts.nbsp_in_tables),
+      "front_matter_format" => Atom.to_string(opts.front_matter_format),
+      "remove_soft_hyphens" => opts.remove_soft_hyphens,
+      "bidi_mode" => Atom.to_string(opts.bidi_mode),
+      "localized_punctuation" => opts.localized_punctuation,
+      "ordered_markers" => opts.ordered_markers,
+      "cycle_bullets" => opts.cycle_bullets,
+      "list_spacing" => Atom.to_string(opts.list_spacing),
+      "unrepresentable_policy" => Atom.to_string(opts.unrepresentable_policy),
+      "post_processors" => opts.post_processors,
+      "collect_stats" => opts.collect_stats,
+      "title_fallback" => opts.title_fallback,
+      "pandoc_attributes" => opts.pandoc_attributes,
+      "svg_text_mode" => Atom.to_string(opts.svg_text_mode),
+      "object_links" => opts.object_links,
+      "preserve_heading_ids" => opts.preserve_heading_ids,
+      "content_fingerprint" => opts.content_fingerprint,
+      "math_style" => Atom.to_string(opts.math_style),
+      "infer_code_language" => opts.infer_code_language,
+      "code_block_trim" => opts.code_block_trim,
+      "srcset_policy" => Atom.to_string(opts.srcset_policy),
+      "figure_style" => Atom.to_string(opts.figure_style),
+      "details_style" => Atom.to_string(opts.details_style),
+      "abbr_style" => Atom.to_string(opts.abbr_style),
+      "time_style" => Atom.to_string(opts.time_style),
+      "dfn_style" => Atom.to_string(opts.dfn_style),
+      "url_param_allowlist" => opts.url_param_allowlist,
+      "url_param_denylist" => opts.url_param_denylist,
+      "contact_link_style" => Atom.to_string(opts.contact_link_style),
+      "fidelity_threshold" => opts.fidelity_threshold,
+      "title_footnotes" => opts.title_footnotes,
+      "header_break_style" => Atom.to_string(opts.header_break_style),
+      "report_dropped_content" => opts.report_dropped_content,
+      "split_documents" => opts.split_documents,
+      "preserved_attributes" => opts.preserved_attributes,
+      "max_output_chars" => opts.max_output_chars,
+      "max_output_tokens" => opts.max_output_tokens,
       "preprocessing" => PreprocessingOptions.to_map(opts.preprocessing),
       "debug" => opts.debug
     }
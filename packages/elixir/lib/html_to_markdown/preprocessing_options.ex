@@ -3,26 +3,49 @@ defmodule HtmlToMarkdown.PreprocessingOptions do
   Configuration for HTML preprocessing before conversion.
   """
 
-  @type preset :: :minimal | :standard | :aggressive
+  @type preset :: :minimal | :standard | :aggressive | :confluence
 
   @preset_strings %{
     minimal: "minimal",
     standard: "standard",
-    aggressive: "aggressive"
+    aggressive: "aggressive",
+    confluence: "confluence"
   }
 
   @preset_atoms Map.new(@preset_strings, fn {k, v} -> {v, k} end)
 
+  @keys [
+    :enabled,
+    :preset,
+    :remove_navigation,
+    :remove_forms,
+    :apply_print_styles,
+    :extract_main_content,
+    :flatten_shadow_dom,
+    :exclude_selectors,
+    :include_selector
+  ]
+
   defstruct enabled: true,
             preset: :standard,
             remove_navigation: true,
-            remove_forms: true
+            remove_forms: true,
+            apply_print_styles: false,
+            extract_main_content: false,
+            flatten_shadow_dom: false,
+            exclude_selectors: [],
+            include_selector: nil
 
   @type t :: %__MODULE__{
           enabled: boolean(),
           preset: preset(),
           remove_navigation: boolean(),
-          remove_forms: boolean()
+          remove_forms: boolean(),
+          apply_print_styles: boolean(),
+          extract_main_content: boolean(),
+          flatten_shadow_dom: boolean(),
+          exclude_selectors: [String.t()],
+          include_selector: String.t() | nil
         }
 
   @doc """
@@ -48,7 +71,7 @@ defmodule HtmlToMarkdown.PreprocessingOptions do
   end
 
   defp normalize_key(key) when is_atom(key) do
-    if key in [:enabled, :preset, :remove_navigation, :remove_forms], do: key, else: nil
+    if key in @keys, do: key, else: nil
   end
 
   defp normalize_key(key) when is_binary(key) do
@@ -62,7 +85,7 @@ defmodule HtmlToMarkdown.PreprocessingOptions do
 
   defp normalize_key(_), do: nil
 
-  defp normalize_value(:preset, value) when value in [:minimal, :standard, :aggressive], do: value
+  defp normalize_value(:preset, value) when value in [:minimal, :standard, :aggressive, :confluence], do: value
 
   defp normalize_value(:preset, value) when is_binary(value) do
     normalized =
@@ -76,6 +99,13 @@ defmodule HtmlToMarkdown.PreprocessingOptions do
 
   defp normalize_value(:preset, value), do: value
 
+  defp normalize_value(:exclude_selectors, value) when is_list(value), do: Enum.map(value, &to_string/1)
+  defp normalize_value(:exclude_selectors, value) when is_binary(value), do: [value]
+  defp normalize_value(:exclude_selectors, _value), do: []
+
+  defp normalize_value(:include_selector, value) when is_binary(value) or is_nil(value), do: value
+  defp normalize_value(:include_selector, value), do: to_string(value)
+
   defp normalize_value(_, value) when is_boolean(value), do: value
   defp normalize_value(_, value) when value in [0, 1], do: value == 1
   defp normalize_value(_, _value), do: true
@@ -99,7 +129,12 @@ defmodule HtmlToMarkdown.PreprocessingOptions do
       "enabled" => opts.enabled,
       "preset" => preset,
       "remove_navigation" => opts.remove_navigation,
-      "remove_forms" => opts.remove_forms
+      "remove_forms" => opts.remove_forms,
+      "apply_print_styles" => opts.apply_print_styles,
+      "extract_main_content" => opts.extract_main_content,
+      "flatten_shadow_dom" => opts.flatten_shadow_dom,
+      "exclude_selectors" => opts.exclude_selectors,
+      "include_selector" => opts.include_selector
     }
   end
 end
@@ -1,25 +1,107 @@
 #![allow(clippy::let_unit_value, deprecated)]
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 
 use html_to_markdown_rs::convert_with_metadata as convert_with_metadata_inner;
 use html_to_markdown_rs::metadata::{
-    DocumentMetadata, ExtendedMetadata, HeaderMetadata, ImageMetadata, LinkMetadata, MetadataConfig, StructuredData,
+    AuthorProfile, DocumentMetadata, ExtendedMetadata, HeaderMetadata, ImageMetadata, LinkMetadata, MetadataConfig,
+    ResourceMetadata, SourceMapEntry, StructuredData,
 };
 use html_to_markdown_rs::{
-    CodeBlockStyle, ConversionOptions, ConversionOptionsUpdate, DEFAULT_INLINE_IMAGE_LIMIT, HeadingStyle,
-    HighlightStyle, HtmlExtraction, InlineImage, InlineImageConfig, InlineImageConfigUpdate, ListIndentType,
-    MetadataConfigUpdate, NewlineStyle, PreprocessingOptionsUpdate, PreprocessingPreset, WhitespaceMode,
-    convert as convert_inner, convert_with_inline_images as convert_with_inline_images_inner,
+    BidiMode, CodeBlockStyle, ConversionError, ConversionOptions, ConversionOptionsUpdate, DEFAULT_INLINE_IMAGE_LIMIT,
+    FrontMatterFormat, HeadingStyle, HighlightStyle, HtmlExtraction, InlineImage, InlineImageConfig,
+    InlineImageConfigUpdate, ListIndentType, ListSpacing, MetadataConfigUpdate, NbspMode, NewlineStyle,
+    PreprocessingOptionsUpdate, PreprocessingPreset, UnrepresentablePolicy, SvgTextMode, MathStyle, SrcsetPolicy, FigureStyle, DetailsStyle, AbbrStyle, TimeStyle, DfnStyle, ContactLinkStyle, HeaderBreakStyle, WhitespaceMode, convert as convert_inner,
+    convert_with_inline_images as convert_with_inline_images_inner, convert_with_progress, option_char,
+    ProgressInterval, safety::guard_panic,
 };
 mod profiling;
 mod visitor;
 
 use rustler::types::binary::{Binary, OwnedBinary};
-use rustler::{Encoder, Env, Error, NifMap, NifResult, ResourceArc, Term};
+use rustler::{Encoder, Env, Error, LocalPid, NifMap, NifResult, OwnedEnv, ResourceArc, Term};
+use std::panic::AssertUnwindSafe;
 use std::path::PathBuf;
 
+/// Largest Markdown piece sent in one `{:chunk, binary}` message by `convert_stream`.
+const STREAM_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Option keys understood by `apply_options`, returned by `known_options/0`.
+const KNOWN_OPTIONS: &[&str] = &[
+    "heading_style",
+    "list_indent_type",
+    "list_indent_width",
+    "bullets",
+    "strong_em_symbol",
+    "escape_asterisks",
+    "escape_underscores",
+    "escape_misc",
+    "escape_ascii",
+    "code_language",
+    "encoding",
+    "autolinks",
+    "default_title",
+    "keep_inline_images_in",
+    "br_in_tables",
+    "hocr_spatial_tables",
+    "highlight_style",
+    "extract_metadata",
+    "whitespace_mode",
+    "strip_newlines",
+    "wrap",
+    "wrap_width",
+    "strip_tags",
+    "remove_tags",
+    "preserve_tags",
+    "convert_as_inline",
+    "sub_symbol",
+    "sup_symbol",
+    "newline_style",
+    "code_block_style",
+    "remove_space_before_punctuation",
+    "nbsp_in_prose",
+    "nbsp_in_tables",
+    "front_matter_format",
+    "remove_soft_hyphens",
+    "bidi_mode",
+    "localized_punctuation",
+    "ordered_markers",
+    "cycle_bullets",
+    "list_spacing",
+    "unrepresentable_policy",
+    "post_processors",
+    "collect_stats",
+    "title_fallback",
+    "pandoc_attributes",
+    "svg_text_mode",
+    "object_links",
+    "preserve_heading_ids",
+    "content_fingerprint",
+    "math_style",
+    "infer_code_language",
+    "code_block_trim",
+    "srcset_policy",
+    "figure_style",
+    "details_style",
+    "abbr_style",
+    "time_style",
+    "dfn_style",
+    "url_param_allowlist",
+    "url_param_denylist",
+    "contact_link_style",
+    "fidelity_threshold",
+    "title_footnotes",
+    "header_break_style",
+    "report_dropped_content",
+    "split_documents",
+    "preserved_attributes",
+    "max_output_chars",
+    "max_output_tokens",
+    "preprocessing",
+    "debug",
+];
+
 struct OptionsHandleResource(ConversionOptions);
 
 #[derive(NifMap)]
@@ -39,12 +121,20 @@ struct InlineImageTerm<'a> {
     attributes: HashMap<String, String>,
 }
 
+#[derive(NifMap)]
+struct AuthorProfileTerm {
+    url: String,
+    name: Option<String>,
+    source: String,
+}
+
 #[derive(NifMap)]
 struct DocumentMetadataTerm {
     title: Option<String>,
     description: Option<String>,
     keywords: Vec<String>,
     author: Option<String>,
+    author_profiles: Vec<AuthorProfileTerm>,
     canonical_url: Option<String>,
     base_href: Option<String>,
     language: Option<String>,
@@ -90,6 +180,21 @@ struct StructuredDataTerm {
     schema_type: Option<String>,
 }
 
+#[derive(NifMap)]
+struct ResourceMetadataTerm {
+    url: String,
+    resource_type: String,
+}
+
+#[derive(NifMap)]
+struct SourceMapEntryTerm {
+    tag: String,
+    html_start: u64,
+    html_end: u64,
+    markdown_start: u64,
+    markdown_end: u64,
+}
+
 #[derive(NifMap)]
 struct ExtendedMetadataTerm {
     document: DocumentMetadataTerm,
@@ -97,6 +202,8 @@ struct ExtendedMetadataTerm {
     links: Vec<LinkMetadataTerm>,
     images: Vec<ImageMetadataTerm>,
     structured_data: Vec<StructuredDataTerm>,
+    resources: Vec<ResourceMetadataTerm>,
+    source_map: Vec<SourceMapEntryTerm>,
 }
 
 rustler::init!(
@@ -104,13 +211,16 @@ rustler::init!(
     [
         convert,
         convert_with_options_map,
+        convert_batch,
         convert_with_handle,
         create_options_handle,
         convert_with_inline_images,
         convert_with_metadata,
         start_profiling,
         stop_profiling,
-        convert_with_visitor
+        convert_with_visitor,
+        convert_stream,
+        known_options
     ],
     load = on_load
 );
@@ -126,6 +236,7 @@ mod atoms {
         ok,
         error,
         invalid_option,
+        unknown_option,
         conversion_failed,
         atx,
         atx_closed,
@@ -143,6 +254,9 @@ mod atoms {
         tildes,
         img_data_uri,
         svg_element,
+        chunk,
+        progress,
+        done,
     }
 }
 
@@ -167,6 +281,24 @@ fn convert_with_options_map<'a>(env: Env<'a>, html: String, options_term: Term<'
     }
 }
 
+#[rustler::nif(schedule = "DirtyCpu")]
+fn convert_batch<'a>(env: Env<'a>, htmls: Vec<String>, options_term: Term<'a>) -> NifResult<Term<'a>> {
+    let options = match decode_options_term(options_term) {
+        Ok(options) => options,
+        Err(err) => return handle_invalid_option_error(env, err),
+    };
+
+    let results: Vec<Term<'a>> = html_to_markdown_rs::convert_many(&htmls, Some(options))
+        .into_iter()
+        .map(|result| match result {
+            Ok(markdown) => (atoms::ok(), markdown).encode(env),
+            Err(err) => (atoms::error(), err.to_string()).encode(env),
+        })
+        .collect();
+
+    Ok(results.encode(env))
+}
+
 #[rustler::nif(schedule = "DirtyCpu")]
 fn convert_with_handle<'a>(
     env: Env<'a>,
@@ -218,6 +350,88 @@ fn convert_with_visitor<'a>(
     }
 }
 
+/// Convert on a dirty scheduler, messaging `pid` with `{:progress, percent}` while converting,
+/// then `{:chunk, binary}` pieces of the Markdown and finally `:done` or `{:error, reason}`.
+#[rustler::nif(schedule = "DirtyCpu")]
+fn convert_stream<'a>(env: Env<'a>, html: String, options_term: Term<'a>, pid: LocalPid) -> NifResult<Term<'a>> {
+    let options = if let Ok(handle) = options_term.decode::<ResourceArc<OptionsHandleResource>>() {
+        handle.0.clone()
+    } else {
+        match decode_options_term(options_term) {
+            Ok(options) => options,
+            Err(err) => {
+                let result = handle_invalid_option_error(env, err)?;
+                let _ = env.send(&pid, result);
+                return Ok(result);
+            }
+        }
+    };
+
+    // Messages can only be sent mid-conversion from an owned environment, which the VM does not
+    // allow on its own threads, so the conversion runs on a helper thread this scheduler waits for.
+    let result = std::thread::scope(|scope| {
+        scope
+            .spawn(|| stream_conversion(&html, options, pid))
+            .join()
+            .unwrap_or_else(|_| Err("html-to-markdown panic during conversion".to_string()))
+    });
+
+    match result {
+        Ok(()) => Ok(atoms::ok().encode(env)),
+        Err(reason) => Ok((atoms::error(), reason).encode(env)),
+    }
+}
+
+fn stream_conversion(html: &str, options: ConversionOptions, pid: LocalPid) -> Result<(), String> {
+    let last_percent = Cell::new(None);
+    let report = move |event: html_to_markdown_rs::ProgressEvent| {
+        let percent = (event.fraction() * 100.0) as u8;
+        if last_percent.replace(Some(percent)) != Some(percent) {
+            send_message(&pid, |env| (atoms::progress(), percent).encode(env));
+        }
+    };
+    let interval = ProgressInterval::Bytes((html.len() / 100).max(1));
+
+    match guard_panic(AssertUnwindSafe(move || convert_with_progress(html, Some(options), interval, report))) {
+        Ok(markdown) => {
+            for chunk in block_chunks(&markdown, STREAM_CHUNK_BYTES) {
+                send_message(&pid, |env| (atoms::chunk(), chunk).encode(env));
+            }
+            send_message(&pid, |env| atoms::done().encode(env));
+            Ok(())
+        }
+        Err(err) => {
+            let reason = err.to_string();
+            send_message(&pid, |env| (atoms::error(), reason.as_str()).encode(env));
+            Err(reason)
+        }
+    }
+}
+
+fn send_message(pid: &LocalPid, build: impl for<'e> FnOnce(Env<'e>) -> Term<'e>) {
+    // A dead recipient is not an error for the conversion.
+    let _ = OwnedEnv::new().send_and_clear(pid, build);
+}
+
+/// Split Markdown between blocks into pieces of at most `max_bytes`, except where one block is
+/// longer than that on its own.
+fn block_chunks(markdown: &str, max_bytes: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+    for block in markdown.split_inclusive("\n\n") {
+        if end > start && end - start + block.len() > max_bytes {
+            chunks.push(&markdown[start..end]);
+            start = end;
+        }
+        end += block.len();
+    }
+    if end > start {
+        chunks.push(&markdown[start..end]);
+    }
+    chunks
+}
+
 #[rustler::nif(schedule = "DirtyCpu")]
 fn create_options_handle<'a>(env: Env<'a>, options_term: Term<'a>) -> NifResult<Term<'a>> {
     match decode_options_term(options_term) {
@@ -229,6 +443,11 @@ fn create_options_handle<'a>(env: Env<'a>, options_term: Term<'a>) -> NifResult<
     }
 }
 
+#[rustler::nif]
+fn known_options() -> Vec<&'static str> {
+    KNOWN_OPTIONS.to_vec()
+}
+
 #[rustler::nif(schedule = "DirtyCpu")]
 fn convert_with_inline_images<'a>(
     env: Env<'a>,
@@ -327,6 +546,8 @@ fn decode_metadata_config(term: Term) -> NifResult<MetadataConfig> {
             "extract_structured_data" => {
                 update.extract_structured_data = Some(decode_bool(value, "extract_structured_data")?)
             }
+            "extract_resources" => update.extract_resources = Some(decode_bool(value, "extract_resources")?),
+            "extract_source_map" => update.extract_source_map = Some(decode_bool(value, "extract_source_map")?),
             "max_structured_data_size" => {
                 update.max_structured_data_size = Some(decode_positive_integer(value, "max_structured_data_size")?)
             }
@@ -348,6 +569,8 @@ fn build_metadata(metadata: ExtendedMetadata) -> ExtendedMetadataTerm {
             .into_iter()
             .map(build_structured_data)
             .collect(),
+        resources: metadata.resources.into_iter().map(build_resource_metadata).collect(),
+        source_map: metadata.source_map.into_iter().map(build_source_map_entry).collect(),
     }
 }
 
@@ -357,6 +580,7 @@ fn build_document_metadata(metadata: DocumentMetadata) -> DocumentMetadataTerm {
         description: metadata.description,
         keywords: metadata.keywords,
         author: metadata.author,
+        author_profiles: metadata.author_profiles.into_iter().map(build_author_profile).collect(),
         canonical_url: metadata.canonical_url,
         base_href: metadata.base_href,
         language: metadata.language,
@@ -367,6 +591,14 @@ fn build_document_metadata(metadata: DocumentMetadata) -> DocumentMetadataTerm {
     }
 }
 
+fn build_author_profile(profile: AuthorProfile) -> AuthorProfileTerm {
+    AuthorProfileTerm {
+        url: profile.url,
+        name: profile.name,
+        source: profile.source.to_string(),
+    }
+}
+
 fn build_header_metadata(metadata: HeaderMetadata) -> HeaderMetadataTerm {
     HeaderMetadataTerm {
         level: metadata.level,
@@ -407,7 +639,36 @@ fn build_structured_data(metadata: StructuredData) -> StructuredDataTerm {
     }
 }
 
+fn build_resource_metadata(metadata: ResourceMetadata) -> ResourceMetadataTerm {
+    ResourceMetadataTerm {
+        url: metadata.url,
+        resource_type: metadata.resource_type.to_string(),
+    }
+}
+
+fn build_source_map_entry(entry: SourceMapEntry) -> SourceMapEntryTerm {
+    SourceMapEntryTerm {
+        tag: entry.tag,
+        html_start: entry.html_start as u64,
+        html_end: entry.html_end as u64,
+        markdown_start: entry.markdown_start as u64,
+        markdown_end: entry.markdown_end as u64,
+    }
+}
+
 fn apply_options(map: HashMap<String, Term>) -> NifResult<ConversionOptions> {
+    if let Some(value) = map.get("strict_options") {
+        if decode_bool(*value, "strict_options")? {
+            let unknown = map
+                .keys()
+                .filter(|key| key.as_str() != "strict_options" && !KNOWN_OPTIONS.contains(&key.as_str()))
+                .min();
+            if let Some(key) = unknown {
+                return Err(unknown_option(key));
+            }
+        }
+    }
+
     let mut update = ConversionOptionsUpdate::default();
 
     for (key, value) in map.into_iter() {
@@ -420,9 +681,7 @@ fn apply_options(map: HashMap<String, Term>) -> NifResult<ConversionOptions> {
             "bullets" => update.bullets = Some(decode_string(value, "bullets")?),
             "strong_em_symbol" => {
                 let symbol = decode_string(value, "strong_em_symbol")?;
-                let mut chars = symbol.chars();
-                let ch = chars.next().ok_or_else(|| bad_option("strong_em_symbol"))?;
-                update.strong_em_symbol = Some(ch);
+                update.strong_em_symbol = Some(option_char("strong_em_symbol", &symbol).map_err(invalid_options)?);
             }
             "escape_asterisks" => update.escape_asterisks = Some(decode_bool(value, "escape_asterisks")?),
             "escape_underscores" => update.escape_underscores = Some(decode_bool(value, "escape_underscores")?),
@@ -444,19 +703,71 @@ fn apply_options(map: HashMap<String, Term>) -> NifResult<ConversionOptions> {
             "wrap" => update.wrap = Some(decode_bool(value, "wrap")?),
             "wrap_width" => update.wrap_width = Some(decode_positive_integer(value, "wrap_width")?),
             "strip_tags" => update.strip_tags = Some(decode_string_list(value, "strip_tags")?),
+            "remove_tags" => update.remove_tags = Some(decode_string_list(value, "remove_tags")?),
             "preserve_tags" => update.preserve_tags = Some(decode_string_list(value, "preserve_tags")?),
             "convert_as_inline" => update.convert_as_inline = Some(decode_bool(value, "convert_as_inline")?),
             "sub_symbol" => update.sub_symbol = Some(decode_string(value, "sub_symbol")?),
             "sup_symbol" => update.sup_symbol = Some(decode_string(value, "sup_symbol")?),
             "newline_style" => update.newline_style = Some(parse_newline_style(value)?),
             "code_block_style" => update.code_block_style = Some(parse_code_block_style(value)?),
+            "remove_space_before_punctuation" => {
+                update.remove_space_before_punctuation = Some(decode_bool(value, "remove_space_before_punctuation")?)
+            }
+            "nbsp_in_prose" => update.nbsp_in_prose = Some(parse_nbsp_mode(value, "nbsp_in_prose")?),
+            "nbsp_in_tables" => update.nbsp_in_tables = Some(parse_nbsp_mode(value, "nbsp_in_tables")?),
+            "front_matter_format" => update.front_matter_format = Some(parse_front_matter_format(value)?),
+            "remove_soft_hyphens" => update.remove_soft_hyphens = Some(decode_bool(value, "remove_soft_hyphens")?),
+            "bidi_mode" => update.bidi_mode = Some(parse_bidi_mode(value)?),
+            "localized_punctuation" => {
+                update.localized_punctuation = Some(decode_bool(value, "localized_punctuation")?)
+            }
+            "ordered_markers" => update.ordered_markers = Some(decode_string(value, "ordered_markers")?),
+            "cycle_bullets" => update.cycle_bullets = Some(decode_bool(value, "cycle_bullets")?),
+            "list_spacing" => update.list_spacing = Some(parse_list_spacing(value)?),
+            "unrepresentable_policy" => update.unrepresentable_policy = Some(parse_unrepresentable_policy(value)?),
+            "post_processors" => update.post_processors = Some(decode_string_list(value, "post_processors")?),
+            "collect_stats" => update.collect_stats = Some(decode_bool(value, "collect_stats")?),
+            "title_fallback" => update.title_fallback = Some(decode_string_list(value, "title_fallback")?),
+            "pandoc_attributes" => update.pandoc_attributes = Some(decode_bool(value, "pandoc_attributes")?),
+            "svg_text_mode" => update.svg_text_mode = Some(parse_svg_text_mode(value)?),
+            "object_links" => update.object_links = Some(decode_bool(value, "object_links")?),
+            "preserve_heading_ids" => update.preserve_heading_ids = Some(decode_bool(value, "preserve_heading_ids")?),
+            "content_fingerprint" => update.content_fingerprint = Some(decode_bool(value, "content_fingerprint")?),
+            "math_style" => update.math_style = Some(parse_math_style(value)?),
+            "infer_code_language" => update.infer_code_language = Some(decode_bool(value, "infer_code_language")?),
+            "code_block_trim" => update.code_block_trim = Some(decode_bool(value, "code_block_trim")?),
+            "srcset_policy" => update.srcset_policy = Some(parse_srcset_policy(value)?),
+            "figure_style" => update.figure_style = Some(parse_figure_style(value)?),
+            "details_style" => update.details_style = Some(parse_details_style(value)?),
+            "abbr_style" => update.abbr_style = Some(parse_abbr_style(value)?),
+            "time_style" => update.time_style = Some(parse_time_style(value)?),
+            "dfn_style" => update.dfn_style = Some(parse_dfn_style(value)?),
+            "url_param_allowlist" => {
+                update.url_param_allowlist = Some(decode_string_list(value, "url_param_allowlist")?)
+            }
+            "url_param_denylist" => update.url_param_denylist = Some(decode_string_list(value, "url_param_denylist")?),
+            "contact_link_style" => update.contact_link_style = Some(parse_contact_link_style(value)?),
+            "fidelity_threshold" => update.fidelity_threshold = Some(decode_fraction(value, "fidelity_threshold")?),
+            "title_footnotes" => update.title_footnotes = Some(decode_bool(value, "title_footnotes")?),
+            "header_break_style" => update.header_break_style = Some(parse_header_break_style(value)?),
+            "report_dropped_content" => {
+                update.report_dropped_content = Some(decode_bool(value, "report_dropped_content")?)
+            }
+            "split_documents" => update.split_documents = Some(decode_bool(value, "split_documents")?),
+            "preserved_attributes" => {
+                update.preserved_attributes = Some(decode_string_list(value, "preserved_attributes")?)
+            }
+            "max_output_chars" => update.max_output_chars = Some(decode_output_limit(value, "max_output_chars")?),
+            "max_output_tokens" => update.max_output_tokens = Some(decode_output_limit(value, "max_output_tokens")?),
             "preprocessing" => update.preprocessing = Some(decode_preprocessing(value)?),
             "debug" => update.debug = Some(decode_bool(value, "debug")?),
             _ => {}
         }
     }
 
-    Ok(ConversionOptions::from(update))
+    let options = ConversionOptions::from(update);
+    options.validate().map_err(invalid_options)?;
+    Ok(options)
 }
 
 fn decode_preprocessing(term: Term) -> NifResult<PreprocessingOptionsUpdate> {
@@ -474,6 +785,23 @@ fn decode_preprocessing(term: Term) -> NifResult<PreprocessingOptionsUpdate> {
                 update.remove_navigation = Some(decode_bool(value, "preprocessing.remove_navigation")?)
             }
             "remove_forms" => update.remove_forms = Some(decode_bool(value, "preprocessing.remove_forms")?),
+            "apply_print_styles" => {
+                update.apply_print_styles = Some(decode_bool(value, "preprocessing.apply_print_styles")?)
+            }
+            "extract_main_content" => {
+                update.extract_main_content = Some(decode_bool(value, "preprocessing.extract_main_content")?)
+            }
+            "flatten_shadow_dom" => {
+                update.flatten_shadow_dom = Some(decode_bool(value, "preprocessing.flatten_shadow_dom")?)
+            }
+            "exclude_selectors" => {
+                update.exclude_selectors = Some(decode_string_list(value, "preprocessing.exclude_selectors")?)
+            }
+            "include_selector" => {
+                update.include_selector = value
+                    .decode::<Option<String>>()
+                    .map_err(|_| bad_option_msg("preprocessing.include_selector", "must be a string or nil"))?
+            }
             _ => {}
         }
     }
@@ -564,6 +892,23 @@ fn decode_positive_integer(term: Term, field: &'static str) -> NifResult<usize>
     }
 }
 
+/// Output budget; `nil` and `0` both mean no limit.
+fn decode_output_limit(term: Term, field: &'static str) -> NifResult<usize> {
+    if matches!(term.atom_to_string(), Ok(name) if name == "nil") {
+        return Ok(0);
+    }
+    let value = term
+        .decode::<i64>()
+        .map_err(|_| bad_option_msg(field, format!("{field} must be a non-negative integer or nil")))?;
+    usize::try_from(value).map_err(|_| bad_option_msg(field, format!("{field} must not be negative")))
+}
+
+fn decode_fraction(term: Term, field: &'static str) -> NifResult<f64> {
+    term.decode::<f64>()
+        .or_else(|_| term.decode::<i64>().map(|value| value as f64))
+        .map_err(|_| bad_option_msg(field, format!("{field} must be a number between 0.0 and 1.0")))
+}
+
 fn parse_heading_style(term: Term) -> NifResult<HeadingStyle> {
     let value = decode_atom_or_string(term)?;
     match value.as_str() {
@@ -594,6 +939,151 @@ fn parse_highlight_style(term: Term) -> NifResult<HighlightStyle> {
     }
 }
 
+fn parse_front_matter_format(term: Term) -> NifResult<FrontMatterFormat> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "yaml" => Ok(FrontMatterFormat::Yaml),
+        "toml" => Ok(FrontMatterFormat::Toml),
+        "json" => Ok(FrontMatterFormat::Json),
+        _ => Err(bad_option_msg("front_matter_format", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_bidi_mode(term: Term) -> NifResult<BidiMode> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "ignore" => Ok(BidiMode::Ignore),
+        "marks" => Ok(BidiMode::Marks),
+        "embedding" => Ok(BidiMode::Embedding),
+        "html" => Ok(BidiMode::Html),
+        _ => Err(bad_option_msg("bidi_mode", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_list_spacing(term: Term) -> NifResult<ListSpacing> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "auto" => Ok(ListSpacing::Auto),
+        "tight" => Ok(ListSpacing::Tight),
+        "loose" => Ok(ListSpacing::Loose),
+        _ => Err(bad_option_msg("list_spacing", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_unrepresentable_policy(term: Term) -> NifResult<UnrepresentablePolicy> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "drop" => Ok(UnrepresentablePolicy::Drop),
+        "passthrough" => Ok(UnrepresentablePolicy::Passthrough),
+        "diagnose" => Ok(UnrepresentablePolicy::Diagnose),
+        _ => Err(bad_option_msg(
+            "unrepresentable_policy",
+            format!("invalid value: {value}"),
+        )),
+    }
+}
+
+fn parse_svg_text_mode(term: Term) -> NifResult<SvgTextMode> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "off" => Ok(SvgTextMode::Off),
+        "alt" => Ok(SvgTextMode::Alt),
+        "caption" => Ok(SvgTextMode::Caption),
+        "replace" => Ok(SvgTextMode::Replace),
+        _ => Err(bad_option_msg("svg_text_mode", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_math_style(term: Term) -> NifResult<MathStyle> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "comment" => Ok(MathStyle::Comment),
+        "dollar" => Ok(MathStyle::Dollar),
+        "fenced" => Ok(MathStyle::Fenced),
+        _ => Err(bad_option_msg("math_style", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_srcset_policy(term: Term) -> NifResult<SrcsetPolicy> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "first" => Ok(SrcsetPolicy::First),
+        "largest" => Ok(SrcsetPolicy::Largest),
+        "smallest" => Ok(SrcsetPolicy::Smallest),
+        _ => Err(bad_option_msg("srcset_policy", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_figure_style(term: Term) -> NifResult<FigureStyle> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "italic" => Ok(FigureStyle::Italic),
+        "blockquote" => Ok(FigureStyle::Blockquote),
+        "html" => Ok(FigureStyle::Html),
+        _ => Err(bad_option_msg("figure_style", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_details_style(term: Term) -> NifResult<DetailsStyle> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "markdown" => Ok(DetailsStyle::Markdown),
+        "html" => Ok(DetailsStyle::Html),
+        _ => Err(bad_option_msg("details_style", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_abbr_style(term: Term) -> NifResult<AbbrStyle> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "expand" => Ok(AbbrStyle::Expand),
+        "text" => Ok(AbbrStyle::Text),
+        "html" => Ok(AbbrStyle::Html),
+        _ => Err(bad_option_msg("abbr_style", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_time_style(term: Term) -> NifResult<TimeStyle> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "text" => Ok(TimeStyle::Text),
+        "datetime" => Ok(TimeStyle::Datetime),
+        "html" => Ok(TimeStyle::Html),
+        _ => Err(bad_option_msg("time_style", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_dfn_style(term: Term) -> NifResult<DfnStyle> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "emphasis" => Ok(DfnStyle::Emphasis),
+        "text" => Ok(DfnStyle::Text),
+        "html" => Ok(DfnStyle::Html),
+        _ => Err(bad_option_msg("dfn_style", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_contact_link_style(term: Term) -> NifResult<ContactLinkStyle> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "link" => Ok(ContactLinkStyle::Link),
+        "autolink" => Ok(ContactLinkStyle::Autolink),
+        "text" => Ok(ContactLinkStyle::Text),
+        _ => Err(bad_option_msg("contact_link_style", format!("invalid value: {value}"))),
+    }
+}
+
+fn parse_header_break_style(term: Term) -> NifResult<HeaderBreakStyle> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "inherit" => Ok(HeaderBreakStyle::Inherit),
+        "space" => Ok(HeaderBreakStyle::Space),
+        "slash" => Ok(HeaderBreakStyle::Slash),
+        "html" => Ok(HeaderBreakStyle::Html),
+        _ => Err(bad_option_msg("header_break_style", format!("invalid value: {value}"))),
+    }
+}
+
 fn parse_whitespace_mode(term: Term) -> NifResult<WhitespaceMode> {
     let value = decode_atom_or_string(term)?;
     match value.as_str() {
@@ -603,6 +1093,15 @@ fn parse_whitespace_mode(term: Term) -> NifResult<WhitespaceMode> {
     }
 }
 
+fn parse_nbsp_mode(term: Term, key: &'static str) -> NifResult<NbspMode> {
+    let value = decode_atom_or_string(term)?;
+    match value.as_str() {
+        "space" => Ok(NbspMode::Space),
+        "preserve" => Ok(NbspMode::Preserve),
+        _ => Err(bad_option_msg(key, format!("invalid value: {value}"))),
+    }
+}
+
 fn parse_newline_style(term: Term) -> NifResult<NewlineStyle> {
     let value = decode_atom_or_string(term)?;
     match value.as_str() {
@@ -627,6 +1126,7 @@ fn parse_preset(term: Term) -> NifResult<PreprocessingPreset> {
     match value.as_str() {
         "minimal" => Ok(PreprocessingPreset::Minimal),
         "aggressive" => Ok(PreprocessingPreset::Aggressive),
+        "confluence" => Ok(PreprocessingPreset::Confluence),
         "standard" => Ok(PreprocessingPreset::Standard),
         _ => Err(bad_option_msg(
             "preprocessing.preset",
@@ -690,11 +1190,16 @@ fn handle_invalid_option_error<'a>(env: Env<'a>, err: Error) -> NifResult<Term<'
             let reason = take_invalid_option_message().unwrap_or_else(|| "invalid option".to_string());
             Ok((atoms::error(), reason).encode(env))
         }
+        Error::Atom(atom) if atom == UNKNOWN_OPTION_ERROR => {
+            let key = take_invalid_option_message().unwrap_or_default();
+            Ok((atoms::error(), (atoms::unknown_option(), key)).encode(env))
+        }
         other => Err(other),
     }
 }
 
 const INVALID_OPTION_ERROR: &str = "invalid_option";
+const UNKNOWN_OPTION_ERROR: &str = "unknown_option";
 
 thread_local! {
     static LAST_INVALID_OPTION: RefCell<Option<String>> = const { RefCell::new(None) };
@@ -719,3 +1224,18 @@ fn bad_option_msg(field: &'static str, message: impl Into<String>) -> Error {
     set_invalid_option_message(format!("{field}: {message}"));
     Error::Atom(INVALID_OPTION_ERROR)
 }
+
+/// Reject a key that `strict_options: true` does not allow; the key becomes the error reason.
+fn unknown_option(key: &str) -> Error {
+    set_invalid_option_message(key);
+    Error::Atom(UNKNOWN_OPTION_ERROR)
+}
+
+/// Report options rejected by the core's validation with its message unchanged.
+fn invalid_options(err: ConversionError) -> Error {
+    match err {
+        ConversionError::ConfigError(message) => set_invalid_option_message(message),
+        other => set_invalid_option_message(other.to_string()),
+    }
+    Error::Atom(INVALID_OPTION_ERROR)
+}
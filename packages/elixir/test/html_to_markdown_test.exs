@@ -21,6 +21,11 @@ defmodule HtmlToMarkdownTest do
     assert is_binary(reason)
   end
 
+  test "convert/2 reports every option the core rejects" do
+    assert {:error, reason} = HtmlToMarkdown.convert("<p>x</p>", bullets: "", ordered_markers: "-")
+    assert reason == "bullets cannot be empty; ordered_markers must only contain '.' or ')', got \"-\""
+  end
+
   test "convert/2 accepts keyword options" do
     assert {:ok, markdown} =
              HtmlToMarkdown.convert("<p>Example</p>",
@@ -32,6 +37,14 @@ defmodule HtmlToMarkdownTest do
     assert markdown =~ "Example"
   end
 
+  test "convert_batch/2 converts each document in order" do
+    assert [{:ok, first}, {:ok, second}] =
+             HtmlToMarkdown.convert_batch(["<h1>One</h1>", "<p>Two</p>"], heading_style: :atx)
+
+    assert first =~ "# One"
+    assert second =~ "Two"
+  end
+
   test "options/1 raises on invalid configuration" do
     assert_raise HtmlToMarkdown.Error, fn ->
       HtmlToMarkdown.options(heading_style: :invalid)
@@ -51,6 +64,38 @@ defmodule HtmlToMarkdownTest do
     assert String.contains?(reason, "wrap")
   end
 
+  test "convert/2 ignores unknown options by default" do
+    assert {:ok, markdown} = HtmlToMarkdown.convert("<h1>Title</h1>", heading_stlye: :atx)
+    assert markdown =~ "Title"
+  end
+
+  test "convert/2 rejects unknown options with strict_options" do
+    assert {:error, {:unknown_option, "heading_stlye"}} =
+             HtmlToMarkdown.convert("<h1>Title</h1>", heading_stlye: :atx, strict_options: true)
+
+    assert {:error, {:unknown_option, "wrap_colums"}} =
+             HtmlToMarkdown.convert("<p>Body</p>", %{"wrap_colums" => 40, "strict_options" => true})
+
+    assert {:ok, markdown} =
+             HtmlToMarkdown.convert("<h1>Title</h1>", heading_style: :atx, strict_options: true)
+
+    assert markdown =~ "# Title"
+  end
+
+  test "options/1 raises on unknown options with strict_options" do
+    assert_raise HtmlToMarkdown.Error, ~r/unknown_option/, fn ->
+      HtmlToMarkdown.options(wrapp: true, strict_options: true)
+    end
+  end
+
+  test "known_options/0 lists the accepted option keys" do
+    known = HtmlToMarkdown.known_options()
+
+    assert :heading_style in known
+    assert :preprocessing in known
+    refute :strict_options in known
+  end
+
   test "convert_with_inline_images/3 extracts image payloads" do
     html = """
     <p>
@@ -104,4 +149,30 @@ defmodule HtmlToMarkdownTest do
     assert [%{"src" => "https://example.com/image.jpg", "image_type" => "external"} | _] =
              metadata["images"]
   end
+
+  test "convert_stream/3 sends progress, chunks and done to the caller" do
+    html = String.duplicate("<h2>Section</h2><p>Body text</p>", 200)
+
+    assert {:ok, _task} = HtmlToMarkdown.convert_stream(html, heading_style: :atx)
+
+    {progress, chunks} = collect_stream([], [])
+    assert List.last(progress) == 100
+    assert progress == Enum.sort(progress)
+    assert IO.iodata_to_binary(chunks) == HtmlToMarkdown.convert!(html, heading_style: :atx)
+  end
+
+  test "convert_stream/3 reports invalid options to the recipient" do
+    assert {:ok, _task} = HtmlToMarkdown.convert_stream("<p>x</p>", %{heading_style: :bogus})
+    assert_receive {:error, _reason}
+  end
+
+  defp collect_stream(progress, chunks) do
+    receive do
+      {:progress, percent} -> collect_stream([percent | progress], chunks)
+      {:chunk, chunk} -> collect_stream(progress, [chunk | chunks])
+      :done -> {Enum.reverse(progress), Enum.reverse(chunks)}
+    after
+      5_000 -> flunk("convert_stream did not finish")
+    end
+  end
 end
@@ -32,6 +32,7 @@ import "C"
 import (
 	"encoding/json"
 	"errors"
+	"runtime"
 	"unsafe"
 )
 
@@ -60,6 +61,11 @@ func Convert(html string) (string, error) {
 	cHTML := C.CString(html)
 	defer C.free(unsafe.Pointer(cHTML))
 
+	// The last error is kept per OS thread, so the call and the error lookup
+	// must not be split across threads.
+	runtime.LockOSThread()
+	defer runtime.UnlockOSThread()
+
 	result := C.html_to_markdown_convert_proxy(cHTML)
 	if result == nil {
 		errMsg := C.html_to_markdown_last_error_proxy()
@@ -122,6 +128,9 @@ func StartProfiling(outputPath string, frequency int) error {
 	cOutput := C.CString(outputPath)
 	defer C.free(unsafe.Pointer(cOutput))
 
+	runtime.LockOSThread()
+	defer runtime.UnlockOSThread()
+
 	ok := C.html_to_markdown_profile_start_proxy(cOutput, C.int32_t(frequency))
 	if !bool(ok) {
 		errMsg := C.html_to_markdown_last_error_proxy()
@@ -138,6 +147,9 @@ func StopProfiling() error {
 	if err := ensureFFILoaded(); err != nil {
 		return err
 	}
+	runtime.LockOSThread()
+	defer runtime.UnlockOSThread()
+
 	ok := C.html_to_markdown_profile_stop_proxy()
 	if !bool(ok) {
 		errMsg := C.html_to_markdown_last_error_proxy()
@@ -368,6 +380,9 @@ func ConvertWithMetadata(html string) (MetadataExtraction, error) {
 	// Allocate output pointer for metadata JSON
 	var metadataPtr *C.char
 
+	runtime.LockOSThread()
+	defer runtime.UnlockOSThread()
+
 	result := C.html_to_markdown_convert_with_metadata_proxy(cHTML, &metadataPtr) // nolint:gocritic
 	if result == nil {
 		errMsg := C.html_to_markdown_last_error_proxy()
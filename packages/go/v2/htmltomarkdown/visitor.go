@@ -307,6 +307,7 @@ import "C"
 import (
 	"errors"
 	"regexp"
+	"runtime"
 	"strings"
 	"sync"
 	"unsafe"
@@ -536,6 +537,9 @@ func ConvertWithVisitor(html string, visitor *Visitor) (string, error) {
 	cHTML := C.CString(html)
 	defer C.free(unsafe.Pointer(cHTML))
 
+	runtime.LockOSThread()
+	defer runtime.UnlockOSThread()
+
 	result := C.html_to_markdown_convert_proxy(cHTML)
 	if result == nil {
 		errMsg := C.html_to_markdown_last_error_proxy()
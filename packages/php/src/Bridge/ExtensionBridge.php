@@ -18,6 +18,10 @@ use HtmlToMarkdown\Visitor\HtmlVisitor;
 final class ExtensionBridge implements ExtensionBridgeContract
 {
     private const CONVERT_FUNCTION = 'html_to_markdown_convert';
+    private const CONVERT_BATCH_FUNCTION = 'html_to_markdown_convert_batch';
+    private const CONVERT_FILE_FUNCTION = 'html_to_markdown_convert_file';
+    private const OPTIONS_CREATE_FUNCTION = 'html_to_markdown_options_create';
+    private const CONVERT_OPTIONS_HANDLE_FUNCTION = 'html_to_markdown_convert_with_options_handle';
     private const CONVERT_INLINE_FUNCTION = 'html_to_markdown_convert_with_inline_images';
     private const CONVERT_METADATA_FUNCTION = 'html_to_markdown_convert_with_metadata';
     private const CONVERT_VISITOR_FUNCTION = 'html_to_markdown_convert_with_visitor';
@@ -43,6 +47,96 @@ final class ExtensionBridge implements ExtensionBridgeContract
         return $result;
     }
 
+    /**
+     * @param list<string> $htmls
+     * @param ConversionOptionsInput|null $options
+     *
+     * @return list<string>
+     */
+    public function convertBatch(array $htmls, ?array $options = null): array
+    {
+        /**
+     * @param ConversionOptionsInput|null $options
+     */
+    public function convertFile(string $path, ?array $options = null): string
+    {
+        /** @var callable-string $callable */
+        $callable = self::CONVERT_FILE_FUNCTION;
+        if (!\function_exists($callable)) {
+            throw ExtensionNotLoaded::create();
+        }
+
+        try {
+            /** @var string $result */
+            $result = $callable($path, $options);
+        } catch (\Throwable $exception) {
+            throw ConversionFailed::withMessage($exception->getMessage());
+        }
+
+        return $result;
+    }
+
+    /** @var callable-string $callable */
+        $callable = self::CONVERT_BATCH_FUNCTION;
+        if (!\function_exists($callable)) {
+            throw ExtensionNotLoaded::create();
+        }
+
+        try {
+            /** @var list<string> $result */
+            $result = $callable($htmls, $options);
+        } catch (\Throwable $exception) {
+            throw ConversionFailed::withMessage($exception->getMessage());
+        }
+
+        return $result;
+    }
+
+    /**
+     * @param ConversionOptionsInput|null $options
+     */
+    public function createOptionsHandle(?array $options = null): object
+    {
+        /** @var callable-string $callable */
+        $callable = self::OPTIONS_CREATE_FUNCTION;
+        if (!\function_exists($callable)) {
+            throw ExtensionNotLoaded::create();
+        }
+
+        try {
+            $handle = $callable($options);
+        } catch (\Throwable $exception) {
+            throw ConversionFailed::withMessage($exception->getMessage());
+        }
+
+        if (!\is_object($handle)) {
+            throw InvalidOption::because(
+                'options_create',
+                'extension returned unexpected payload',
+            );
+        }
+
+        return $handle;
+    }
+
+    public function convertWithOptionsHandle(string $html, object $handle): string
+    {
+        /** @var callable-string $callable */
+        $callable = self::CONVERT_OPTIONS_HANDLE_FUNCTION;
+        if (!\function_exists($callable)) {
+            throw ExtensionNotLoaded::create();
+        }
+
+        try {
+            /** @var string $result */
+            $result = $callable($html, $handle);
+        } catch (\Throwable $exception) {
+            throw ConversionFailed::withMessage($exception->getMessage());
+        }
+
+        return $result;
+    }
+
     /**
      * @param ConversionOptionsInput|null $options
      * @param InlineImageConfigInput|null $config
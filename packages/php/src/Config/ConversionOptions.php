@@ -5,9 +5,24 @@ declare(strict_types=1);
 namespace HtmlToMarkdown\Config;
 
 use HtmlToMarkdown\Enum\CodeBlockStyle;
+use HtmlToMarkdown\Enum\FrontMatterFormat;
+use HtmlToMarkdown\Enum\BidiMode;
+use HtmlToMarkdown\Enum\ListSpacing;
+use HtmlToMarkdown\Enum\UnrepresentablePolicy;
+use HtmlToMarkdown\Enum\SvgTextMode;
+use HtmlToMarkdown\Enum\MathStyle;
+use HtmlToMarkdown\Enum\SrcsetPolicy;
+use HtmlToMarkdown\Enum\FigureStyle;
+use HtmlToMarkdown\Enum\DetailsStyle;
+use HtmlToMarkdown\Enum\AbbrStyle;
+use HtmlToMarkdown\Enum\TimeStyle;
+use HtmlToMarkdown\Enum\DfnStyle;
+use HtmlToMarkdown\Enum\ContactLinkStyle;
+use HtmlToMarkdown\Enum\HeaderBreakStyle;
 use HtmlToMarkdown\Enum\HeadingStyle;
 use HtmlToMarkdown\Enum\HighlightStyle;
 use HtmlToMarkdown\Enum\ListIndentType;
+use HtmlToMarkdown\Enum\NbspMode;
 use HtmlToMarkdown\Enum\NewlineStyle;
 use HtmlToMarkdown\Enum\WhitespaceMode;
 use HtmlToMarkdown\Exception\InvalidOption;
@@ -44,20 +59,138 @@ use HtmlToMarkdown\Internal\TypeAssertions;
  *     keep_inline_images_in?: list<string>,
  *     encoding?: string,
  *     debug?: bool,
+ *     remove_space_before_punctuation?: bool,
+ *     nbsp_in_prose?: value-of<NbspMode>,
+ *     nbsp_in_tables?: value-of<NbspMode>,
+ *     front_matter_format?: value-of<FrontMatterFormat>,
+ *     remove_soft_hyphens?: bool,
+ *     bidi_mode?: value-of<BidiMode>,
+ *     localized_punctuation?: bool,
+ *     ordered_markers?: string,
+ *     cycle_bullets?: bool,
+ *     list_spacing?: value-of<ListSpacing>,
+ *     unrepresentable_policy?: value-of<UnrepresentablePolicy>,
+ *     post_processors?: list<string>,
+ *     collect_stats?: bool,
+ *     title_fallback?: list<string>,
+ *     pandoc_attributes?: bool,
+ *     svg_text_mode?: value-of<SvgTextMode>,
+ *     object_links?: bool,
+ *     preserve_heading_ids?: bool,
+ *     content_fingerprint?: bool,
+ *     math_style?: value-of<MathStyle>,
+ *     infer_code_language?: bool,
+ *     code_block_trim?: bool,
+ *     srcset_policy?: value-of<SrcsetPolicy>,
+ *     figure_style?: value-of<FigureStyle>,
+ *     details_style?: value-of<DetailsStyle>,
+ *     abbr_style?: value-of<AbbrStyle>,
+ *     time_style?: value-of<TimeStyle>,
+ *     dfn_style?: value-of<DfnStyle>,
+ *     url_param_allowlist?: list<string>,
+ *     url_param_denylist?: list<string>,
+ *     contact_link_style?: value-of<ContactLinkStyle>,
+ *     fidelity_threshold?: float,
+ *     title_footnotes?: bool,
+ *     header_break_style?: value-of<HeaderBreakStyle>,
+ *     report_dropped_content?: bool,
+ *     split_documents?: bool,
+ *     preserved_attributes?: list<string>,
+ *     max_output_chars?: int|null,
+ *     max_output_tokens?: int|null,
  *     skip_images?: bool,
  *     strip_tags?: list<string>,
+ *     remove_tags?: list<string>,
  *     preserve_tags?: list<string>,
- *     preprocessing?: PreprocessingOptionsInput
+ *     preprocessing?: PreprocessingOptionsInput,
+ *     strict_options?: bool
  * }
  */
 final readonly class ConversionOptions
 {
+    private const OPTION_KEYS = [
+        'heading_style',
+        'list_indent_type',
+        'list_indent_width',
+        'bullets',
+        'strong_em_symbol',
+        'escape_asterisks',
+        'escape_underscores',
+        'escape_misc',
+        'escape_ascii',
+        'code_language',
+        'autolinks',
+        'default_title',
+        'br_in_tables',
+        'hocr_spatial_tables',
+        'highlight_style',
+        'extract_metadata',
+        'whitespace_mode',
+        'strip_newlines',
+        'wrap',
+        'wrap_width',
+        'convert_as_inline',
+        'sub_symbol',
+        'sup_symbol',
+        'newline_style',
+        'code_block_style',
+        'keep_inline_images_in',
+        'encoding',
+        'debug',
+        'remove_space_before_punctuation',
+        'nbsp_in_prose',
+        'nbsp_in_tables',
+        'front_matter_format',
+        'remove_soft_hyphens',
+        'bidi_mode',
+        'localized_punctuation',
+        'ordered_markers',
+        'cycle_bullets',
+        'list_spacing',
+        'unrepresentable_policy',
+        'post_processors',
+        'collect_stats',
+        'title_fallback',
+        'pandoc_attributes',
+        'svg_text_mode',
+        'object_links',
+        'preserve_heading_ids',
+        'content_fingerprint',
+        'math_style',
+        'infer_code_language',
+        'code_block_trim',
+        'srcset_policy',
+        'figure_style',
+        'details_style',
+        'abbr_style',
+        'time_style',
+        'dfn_style',
+        'url_param_allowlist',
+        'url_param_denylist',
+        'contact_link_style',
+        'fidelity_threshold',
+        'title_footnotes',
+        'header_break_style',
+        'report_dropped_content',
+        'split_documents',
+        'preserved_attributes',
+        'max_output_chars',
+        'max_output_tokens',
+        'skip_images',
+        'strip_tags',
+        'remove_tags',
+        'preserve_tags',
+        'preprocessing',
+    ];
+
     public PreprocessingOptions $preprocessing;
 
     /**
      * @param list<string> $keepInlineImagesIn
      * @param list<string> $stripTags
+     * @param list<string> $removeTags
      * @param list<string> $preserveTags
+     * @param list<string> $postProcessors
      */
     public function __construct(
         public HeadingStyle $headingStyle = HeadingStyle::ATX,
@@ -88,8 +221,62 @@ final readonly class ConversionOptions
         public array $keepInlineImagesIn = [],
         public string $encoding = 'utf-8',
         public bool $debug = false,
+        public bool $removeSpaceBeforePunctuation = false,
+        public NbspMode $nbspInProse = NbspMode::SPACE,
+        public NbspMode $nbspInTables = NbspMode::SPACE,
+        public FrontMatterFormat $frontMatterFormat = FrontMatterFormat::YAML,
+        public bool $removeSoftHyphens = true,
+        public BidiMode $bidiMode = BidiMode::IGNORE,
+        public bool $localizedPunctuation = false,
+        public string $orderedMarkers = '.',
+        public bool $cycleBullets = true,
+        public ListSpacing $listSpacing = ListSpacing::AUTO,
+        public UnrepresentablePolicy $unrepresentablePolicy = UnrepresentablePolicy::DROP,
+        public array $postProcessors = [],
+        public bool $collectStats = false,
+        public array $titleFallback = [],
+        public bool $pandocAttributes = false,
+        public SvgTextMode $svgTextMode = SvgTextMode::OFF,
+        public bool $objectLinks = false,
+        public bool $preserveHeadingIds = false,
+        public bool $contentFingerprint = false,
+        public MathStyle $mathStyle = MathStyle::COMMENT,
+        public bool $inferCodeLanguage = false,
+        public bool $codeBlockTrim = true,
+        public SrcsetPolicy $srcsetPolicy = SrcsetPolicy::FIRST,
+        public FigureStyle $figureStyle = FigureStyle::ITALIC,
+        public DetailsStyle $detailsStyle = DetailsStyle::MARKDOWN,
+        public AbbrStyle $abbrStyle = AbbrStyle::EXPAND,
+        public TimeStyle $timeStyle = TimeStyle::TEXT,
+        public DfnStyle $dfnStyle = DfnStyle::EMPHASIS,
+        public array $urlParamAllowlist = [],
+        public array $urlParamDenylist = [
+            'utm_*',
+            'fbclid',
+            'gclid',
+            'dclid',
+            'gbraid',
+            'wbraid',
+            'msclkid',
+            'mc_cid',
+            'mc_eid',
+            '_hsenc',
+            '_hsmi',
+            'igshid',
+            'yclid',
+        ],
+        public ContactLinkStyle $contactLinkStyle = ContactLinkStyle::LINK,
+        public float $fidelityThreshold = 1.0,
+        public bool $titleFootnotes = false,
+        public HeaderBreakStyle $headerBreakStyle = HeaderBreakStyle::INHERIT,
+        public bool $reportDroppedContent = false,
+        public bool $splitDocuments = false,
+        public array $preservedAttributes = [],
+        public ?int $maxOutputChars = null,
+        public ?int $maxOutputTokens = null,
         public bool $skipImages = false,
         public array $stripTags = [],
+        public array $removeTags = [],
         public array $preserveTags = [],
         ?PreprocessingOptions $preprocessing = null,
     ) {
@@ -106,6 +293,10 @@ final readonly class ConversionOptions
      */
     public static function fromArray(array $input): self
     {
+        if (($input['strict_options'] ?? false) === true) {
+            self::assertKnownKeys($input);
+        }
+
         $defaults = new self();
 
         return new self(
@@ -193,12 +384,128 @@ final readonly class ConversionOptions
             debug: \array_key_exists('debug', $input)
                 ? TypeAssertions::bool($input['debug'], 'debug')
                 : $defaults->debug,
+            removeSpaceBeforePunctuation: \array_key_exists('remove_space_before_punctuation', $input)
+                ? TypeAssertions::bool($input['remove_space_before_punctuation'], 'remove_space_before_punctuation')
+                : $defaults->removeSpaceBeforePunctuation,
+            nbspInProse: \array_key_exists('nbsp_in_prose', $input)
+                ? NbspMode::fromString(TypeAssertions::string($input['nbsp_in_prose'], 'nbsp_in_prose'))
+                : $defaults->nbspInProse,
+            nbspInTables: \array_key_exists('nbsp_in_tables', $input)
+                ? NbspMode::fromString(TypeAssertions::string($input['nbsp_in_tables'], 'nbsp_in_tables'))
+                : $defaults->nbspInTables,
+            frontMatterFormat: \array_key_exists('front_matter_format', $input)
+                ? FrontMatterFormat::fromString(TypeAssertions::string($input['front_matter_format'], 'front_matter_format'))
+                : $defaults->frontMatterFormat,
+            removeSoftHyphens: \array_key_exists('remove_soft_hyphens', $input)
+                ? TypeAssertions::bool($input['remove_soft_hyphens'], 'remove_soft_hyphens')
+                : $defaults->removeSoftHyphens,
+            bidiMode: \array_key_exists('bidi_mode', $input)
+                ? BidiMode::fromString(TypeAssertions::string($input['bidi_mode'], 'bidi_mode'))
+                : $defaults->bidiMode,
+            localizedPunctuation: \array_key_exists('localized_punctuation', $input)
+                ? TypeAssertions::bool($input['localized_punctuation'], 'localized_punctuation')
+                : $defaults->localizedPunctuation,
+            orderedMarkers: \array_key_exists('ordered_markers', $input)
+                ? TypeAssertions::string($input['ordered_markers'], 'ordered_markers')
+                : $defaults->orderedMarkers,
+            cycleBullets: \array_key_exists('cycle_bullets', $input)
+                ? TypeAssertions::bool($input['cycle_bullets'], 'cycle_bullets')
+                : $defaults->cycleBullets,
+            listSpacing: \array_key_exists('list_spacing', $input)
+                ? ListSpacing::fromString(TypeAssertions::string($input['list_spacing'], 'list_spacing'))
+                : $defaults->listSpacing,
+            unrepresentablePolicy: \array_key_exists('unrepresentable_policy', $input)
+                ? UnrepresentablePolicy::fromString(TypeAssertions::string($input['unrepresentable_policy'], 'unrepresentable_policy'))
+                : $defaults->unrepresentablePolicy,
+            postProcessors: \array_key_exists('post_processors', $input)
+                ? TypeAssertions::stringList($input['post_processors'], 'post_processors')
+                : $defaults->postProcessors,
+            collectStats: \array_key_exists('collect_stats', $input)
+                ? TypeAssertions::bool($input['collect_stats'], 'collect_stats')
+                : $defaults->collectStats,
+            titleFallback: \array_key_exists('title_fallback', $input)
+                ? TypeAssertions::stringList($input['title_fallback'], 'title_fallback')
+                : $defaults->titleFallback,
+            pandocAttributes: \array_key_exists('pandoc_attributes', $input)
+                ? TypeAssertions::bool($input['pandoc_attributes'], 'pandoc_attributes')
+                : $defaults->pandocAttributes,
+            svgTextMode: \array_key_exists('svg_text_mode', $input)
+                ? SvgTextMode::fromString(TypeAssertions::string($input['svg_text_mode'], 'svg_text_mode'))
+                : $defaults->svgTextMode,
+            objectLinks: \array_key_exists('object_links', $input)
+                ? TypeAssertions::bool($input['object_links'], 'object_links')
+                : $defaults->objectLinks,
+            preserveHeadingIds: \array_key_exists('preserve_heading_ids', $input)
+                ? TypeAssertions::bool($input['preserve_heading_ids'], 'preserve_heading_ids')
+                : $defaults->preserveHeadingIds,
+            contentFingerprint: \array_key_exists('content_fingerprint', $input)
+                ? TypeAssertions::bool($input['content_fingerprint'], 'content_fingerprint')
+                : $defaults->contentFingerprint,
+            mathStyle: \array_key_exists('math_style', $input)
+                ? MathStyle::fromString(TypeAssertions::string($input['math_style'], 'math_style'))
+                : $defaults->mathStyle,
+            inferCodeLanguage: \array_key_exists('infer_code_language', $input)
+                ? TypeAssertions::bool($input['infer_code_language'], 'infer_code_language')
+                : $defaults->inferCodeLanguage,
+            codeBlockTrim: \array_key_exists('code_block_trim', $input)
+                ? TypeAssertions::bool($input['code_block_trim'], 'code_block_trim')
+                : $defaults->codeBlockTrim,
+            srcsetPolicy: \array_key_exists('srcset_policy', $input)
+                ? SrcsetPolicy::fromString(TypeAssertions::string($input['srcset_policy'], 'srcset_policy'))
+                : $defaults->srcsetPolicy,
+            figureStyle: \array_key_exists('figure_style', $input)
+                ? FigureStyle::fromString(TypeAssertions::string($input['figure_style'], 'figure_style'))
+                : $defaults->figureStyle,
+            detailsStyle: \array_key_exists('details_style', $input)
+                ? DetailsStyle::fromString(TypeAssertions::string($input['details_style'], 'details_style'))
+                : $defaults->detailsStyle,
+            abbrStyle: \array_key_exists('abbr_style', $input)
+                ? AbbrStyle::fromString(TypeAssertions::string($input['abbr_style'], 'abbr_style'))
+                : $defaults->abbrStyle,
+            timeStyle: \array_key_exists('time_style', $input)
+                ? TimeStyle::fromString(TypeAssertions::string($input['time_style'], 'time_style'))
+                : $defaults->timeStyle,
+            dfnStyle: \array_key_exists('dfn_style', $input)
+                ? DfnStyle::fromString(TypeAssertions::string($input['dfn_style'], 'dfn_style'))
+                : $defaults->dfnStyle,
+            urlParamAllowlist: \array_key_exists('url_param_allowlist', $input)
+                ? TypeAssertions::stringList($input['url_param_allowlist'], 'url_param_allowlist')
+                : $defaults->urlParamAllowlist,
+            urlParamDenylist: \array_key_exists('url_param_denylist', $input)
+                ? TypeAssertions::stringList($input['url_param_denylist'], 'url_param_denylist')
+                : $defaults->urlParamDenylist,
+            contactLinkStyle: \array_key_exists('contact_link_style', $input)
+                ? ContactLinkStyle::fromString(TypeAssertions::string($input['contact_link_style'], 'contact_link_style'))
+                : $defaults->contactLinkStyle,
+            fidelityThreshold: \array_key_exists('fidelity_threshold', $input)
+                ? TypeAssertions::fraction($input['fidelity_threshold'], 'fidelity_threshold')
+                : $defaults->fidelityThreshold,
+            titleFootnotes: \array_key_exists('title_footnotes', $input)
+                ? TypeAssertions::bool($input['title_footnotes'], 'title_footnotes')
+                : $defaults->titleFootnotes,
+            headerBreakStyle: \array_key_exists('header_break_style', $input)
+                ? HeaderBreakStyle::fromString(TypeAssertions::string($input['header_break_style'], 'header_break_style'))
+                : $defaults->headerBreakStyle,
+            reportDroppedContent: \array_key_exists('report_dropped_content', $input)
+                ? TypeAssertions::bool($input['report_dropped_content'], 'report_dropped_content')
+                : $defaults->reportDroppedContent,
+            splitDocuments: \array_key_exists('split_documents', $input)
+                ? TypeAssertions::bool($input['split_documents'], 'split_documents')
+                : $defaults->splitDocuments,
+            preservedAttributes: \array_key_exists('preserved_attributes', $input)
+                ? TypeAssertions::stringList($input['preserved_attributes'], 'preserved_attributes')
+                : $defaults->preservedAttributes,
+            maxOutputChars: self::optionalLimit($input, 'max_output_chars', $defaults->maxOutputChars),
+            maxOutputTokens: self::optionalLimit($input, 'max_output_tokens', $defaults->maxOutputTokens),
             skipImages: \array_key_exists('skip_images', $input)
                 ? TypeAssertions::bool($input['skip_images'], 'skip_images')
                 : $defaults->skipImages,
             stripTags: \array_key_exists('strip_tags', $input)
                 ? TypeAssertions::stringList($input['strip_tags'], 'strip_tags')
                 : $defaults->stripTags,
+            removeTags: \array_key_exists('remove_tags', $input)
+                ? TypeAssertions::stringList($input['remove_tags'], 'remove_tags')
+                : $defaults->removeTags,
             preserveTags: \array_key_exists('preserve_tags', $input)
                 ? TypeAssertions::stringList($input['preserve_tags'], 'preserve_tags')
                 : $defaults->preserveTags,
@@ -303,12 +610,132 @@ final readonly class ConversionOptions
         if ($this->debug !== $defaults->debug) {
             $payload['debug'] = $this->debug;
         }
+        if ($this->removeSpaceBeforePunctuation !== $defaults->removeSpaceBeforePunctuation) {
+            $payload['remove_space_before_punctuation'] = $this->removeSpaceBeforePunctuation;
+        }
+        if ($this->nbspInProse !== $defaults->nbspInProse) {
+            $payload['nbsp_in_prose'] = $this->nbspInProse->value;
+        }
+        if ($this->nbspInTables !== $defaults->nbspInTables) {
+            $payload['nbsp_in_tables'] = $this->nbspInTables->value;
+        }
+        if ($this->frontMatterFormat !== $defaults->frontMatterFormat) {
+            $payload['front_matter_format'] = $this->frontMatterFormat->value;
+        }
+        if ($this->removeSoftHyphens !== $defaults->removeSoftHyphens) {
+            $payload['remove_soft_hyphens'] = $this->removeSoftHyphens;
+        }
+        if ($this->bidiMode !== $defaults->bidiMode) {
+            $payload['bidi_mode'] = $this->bidiMode->value;
+        }
+        if ($this->localizedPunctuation !== $defaults->localizedPunctuation) {
+            $payload['localized_punctuation'] = $this->localizedPunctuation;
+        }
+        if ($this->orderedMarkers !== $defaults->orderedMarkers) {
+            $payload['ordered_markers'] = $this->orderedMarkers;
+        }
+        if ($this->cycleBullets !== $defaults->cycleBullets) {
+            $payload['cycle_bullets'] = $this->cycleBullets;
+        }
+        if ($this->listSpacing !== $defaults->listSpacing) {
+            $payload['list_spacing'] = $this->listSpacing->value;
+        }
+        if ($this->unrepresentablePolicy !== $defaults->unrepresentablePolicy) {
+            $payload['unrepresentable_policy'] = $this->unrepresentablePolicy->value;
+        }
+        if ($this->postProcessors !== $defaults->postProcessors) {
+            $payload['post_processors'] = \array_values($this->postProcessors);
+        }
+        if ($this->collectStats !== $defaults->collectStats) {
+            $payload['collect_stats'] = $this->collectStats;
+        }
+        if ($this->titleFallback !== $defaults->titleFallback) {
+            $payload['title_fallback'] = \array_values($this->titleFallback);
+        }
+        if ($this->pandocAttributes !== $defaults->pandocAttributes) {
+            $payload['pandoc_attributes'] = $this->pandocAttributes;
+        }
+        if ($this->svgTextMode !== $defaults->svgTextMode) {
+            $payload['svg_text_mode'] = $this->svgTextMode->value;
+        }
+        if ($this->objectLinks !== $defaults->objectLinks) {
+            $payload['object_links'] = $this->objectLinks;
+        }
+        if ($this->preserveHeadingIds !== $defaults->preserveHeadingIds) {
+            $payload['preserve_heading_ids'] = $this->preserveHeadingIds;
+        }
+        if ($this->contentFingerprint !== $defaults->contentFingerprint) {
+            $payload['content_fingerprint'] = $this->contentFingerprint;
+        }
+        if ($this->mathStyle !== $defaults->mathStyle) {
+            $payload['math_style'] = $this->mathStyle->value;
+        }
+        if ($this->inferCodeLanguage !== $defaults->inferCodeLanguage) {
+            $payload['infer_code_language'] = $this->inferCodeLanguage;
+        }
+        if ($this->codeBlockTrim !== $defaults->codeBlockTrim) {
+            $payload['code_block_trim'] = $this->codeBlockTrim;
+        }
+        if ($this->srcsetPolicy !== $defaults->srcsetPolicy) {
+            $payload['srcset_policy'] = $this->srcsetPolicy->value;
+        }
+        if ($this->figureStyle !== $defaults->figureStyle) {
+            $payload['figure_style'] = $this->figureStyle->value;
+        }
+        if ($this->detailsStyle !== $defaults->detailsStyle) {
+            $payload['details_style'] = $this->detailsStyle->value;
+        }
+        if ($this->abbrStyle !== $defaults->abbrStyle) {
+            $payload['abbr_style'] = $this->abbrStyle->value;
+        }
+        if ($this->timeStyle !== $defaults->timeStyle) {
+            $payload['time_style'] = $this->timeStyle->value;
+        }
+        if ($this->dfnStyle !== $defaults->dfnStyle) {
+            $payload['dfn_style'] = $this->dfnStyle->value;
+        }
+        if ($this->urlParamAllowlist !== $defaults->urlParamAllowlist) {
+            $payload['url_param_allowlist'] = $this->urlParamAllowlist;
+        }
+        if ($this->urlParamDenylist !== $defaults->urlParamDenylist) {
+            $payload['url_param_denylist'] = $this->urlParamDenylist;
+        }
+        if ($this->contactLinkStyle !== $defaults->contactLinkStyle) {
+            $payload['contact_link_style'] = $this->contactLinkStyle->value;
+        }
+        if ($this->fidelityThreshold !== $defaults->fidelityThreshold) {
+            $payload['fidelity_threshold'] = $this->fidelityThreshold;
+        }
+        if ($this->titleFootnotes !== $defaults->titleFootnotes) {
+            $payload['title_footnotes'] = $this->titleFootnotes;
+        }
+        if ($this->headerBreakStyle !== $defaults->headerBreakStyle) {
+            $payload['header_break_style'] = $this->headerBreakStyle->value;
+        }
+        if ($this->reportDroppedContent !== $defaults->reportDroppedContent) {
+            $payload['report_dropped_content'] = $this->reportDroppedContent;
+        }
+        if ($this->splitDocuments !== $defaults->splitDocuments) {
+            $payload['split_documents'] = $this->splitDocuments;
+        }
+        if ($this->preservedAttributes !== $defaults->preservedAttributes) {
+            $payload['preserved_attributes'] = $this->preservedAttributes;
+        }
+        if ($this->maxOutputChars !== $defaults->maxOutputChars) {
+            $payload['max_output_chars'] = $this->maxOutputChars;
+        }
+        if ($this->maxOutputTokens !== $defaults->maxOutputTokens) {
+            $payload['max_output_tokens'] = $this->maxOutputTokens;
+        }
         if ($this->skipImages !== $defaults->skipImages) {
             $payload['skip_images'] = $this->skipImages;
         }
         if ($this->stripTags !== $defaults->stripTags && $this->stripTags !== []) {
             $payload['strip_tags'] = \array_values($this->stripTags);
         }
+        if ($this->removeTags !== $defaults->removeTags && $this->removeTags !== []) {
+            $payload['remove_tags'] = \array_values($this->removeTags);
+        }
         if ($this->preserveTags !== $defaults->preserveTags && $this->preserveTags !== []) {
             $payload['preserve_tags'] = \array_values($this->preserveTags);
         }
@@ -316,6 +743,37 @@ final readonly class ConversionOptions
         return $payload;
     }
 
+    /**
+     * @param array<string, mixed> $input
+     */
+    private static function assertKnownKeys(array $input): void
+    {
+        foreach (\array_keys($input) as $key) {
+            if ($key !== 'strict_options' && !\in_array($key, self::OPTION_KEYS, true)) {
+                throw InvalidOption::unknown((string) $key);
+            }
+        }
+    }
+
+    /**
+     * An output budget: `null` or `0` leaves the output unbounded.
+     *
+     * @param array<string, mixed> $input
+     */
+    private static function optionalLimit(array $input, string $key, ?int $default): ?int
+    {
+        if (!\array_key_exists($key, $input)) {
+            return $default;
+        }
+        if ($input[$key] === null) {
+            return null;
+        }
+
+        $limit = TypeAssertions::positiveInt($input[$key], $key);
+
+        return $limit === 0 ? null : $limit;
+    }
+
     /**
      * @return PreprocessingOptionsInput
      */
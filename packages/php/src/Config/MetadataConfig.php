@@ -0,0 +1,92 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Config;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+use HtmlToMarkdown\Internal\TypeAssertions;
+
+/**
+ * @phpstan-type MetadataConfigInput array{
+ *     extract_document?: bool,
+ *     extract_headers?: bool,
+ *     extract_links?: bool,
+ *     extract_images?: bool,
+ *     extract_structured_data?: bool,
+ *     extract_resources?: bool,
+ *     max_structured_data_size?: positive-int
+ * }
+ */
+final readonly class MetadataConfig
+{
+    public function __construct(
+        public bool $extractDocument = true,
+        public bool $extractHeaders = true,
+        public bool $extractLinks = true,
+        public bool $extractImages = true,
+        public bool $extractStructuredData = true,
+        public bool $extractResources = false,
+        /** @phpstan-param positive-int $maxStructuredDataSize */
+        public int $maxStructuredDataSize = 1_000_000,
+    ) {
+        if ($this->maxStructuredDataSize <= 0) {
+            throw InvalidOption::because(
+                'metadata_config.max_structured_data_size',
+                'must be greater than zero',
+            );
+        }
+    }
+
+    /**
+     * @param array<string, mixed> $input
+     */
+    public static function fromArray(array $input): self
+    {
+        $defaults = new self();
+
+        return new self(
+            extractDocument: self::flag($input, 'extract_document', $defaults->extractDocument),
+            extractHeaders: self::flag($input, 'extract_headers', $defaults->extractHeaders),
+            extractLinks: self::flag($input, 'extract_links', $defaults->extractLinks),
+            extractImages: self::flag($input, 'extract_images', $defaults->extractImages),
+            extractStructuredData: self::flag($input, 'extract_structured_data', $defaults->extractStructuredData),
+            extractResources: self::flag($input, 'extract_resources', $defaults->extractResources),
+            maxStructuredDataSize: \array_key_exists('max_structured_data_size', $input)
+                ? TypeAssertions::positiveInt(
+                    $input['max_structured_data_size'],
+                    'metadata_config.max_structured_data_size',
+                )
+                : $defaults->maxStructuredDataSize,
+        );
+    }
+
+    /**
+     * @return MetadataConfigInput
+     */
+    public function toArray(): array
+    {
+        /** @var positive-int $size */
+        $size = $this->maxStructuredDataSize;
+
+        return [
+            'extract_document' => $this->extractDocument,
+            'extract_headers' => $this->extractHeaders,
+            'extract_links' => $this->extractLinks,
+            'extract_images' => $this->extractImages,
+            'extract_structured_data' => $this->extractStructuredData,
+            'extract_resources' => $this->extractResources,
+            'max_structured_data_size' => $size,
+        ];
+    }
+
+    /**
+     * @param array<string, mixed> $input
+     */
+    private static function flag(array $input, string $key, bool $default): bool
+    {
+        return \array_key_exists($key, $input)
+            ? TypeAssertions::bool($input[$key], 'metadata_config.' . $key)
+            : $default;
+    }
+}
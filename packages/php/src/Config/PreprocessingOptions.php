@@ -9,15 +9,23 @@ use HtmlToMarkdown\Exception\InvalidOption;
 use HtmlToMarkdown\Internal\TypeAssertions;
 
 /**
- * @phpstan-type PreprocessingOptionsInput array<string, bool|string>
+ * @phpstan-type PreprocessingOptionsInput array<string, bool|string|list<string>|null>
  */
 final readonly class PreprocessingOptions
 {
+    /**
+     * @param list<string> $excludeSelectors
+     */
     public function __construct(
         public bool $enabled = true,
         public PreprocessingPreset $preset = PreprocessingPreset::STANDARD,
         public bool $removeNavigation = true,
         public bool $removeForms = true,
+        public bool $applyPrintStyles = false,
+        public bool $extractMainContent = false,
+        public bool $flattenShadowDom = false,
+        public array $excludeSelectors = [],
+        public ?string $includeSelector = null,
     ) {
         if (($removeNavigation || $removeForms) && !$enabled) {
             throw InvalidOption::because(
@@ -47,6 +55,21 @@ final readonly class PreprocessingOptions
             removeForms: \array_key_exists('remove_forms', $input)
                 ? TypeAssertions::bool($input['remove_forms'], 'preprocessing.remove_forms')
                 : $defaults->removeForms,
+            applyPrintStyles: \array_key_exists('apply_print_styles', $input)
+                ? TypeAssertions::bool($input['apply_print_styles'], 'preprocessing.apply_print_styles')
+                : $defaults->applyPrintStyles,
+            extractMainContent: \array_key_exists('extract_main_content', $input)
+                ? TypeAssertions::bool($input['extract_main_content'], 'preprocessing.extract_main_content')
+                : $defaults->extractMainContent,
+            flattenShadowDom: \array_key_exists('flatten_shadow_dom', $input)
+                ? TypeAssertions::bool($input['flatten_shadow_dom'], 'preprocessing.flatten_shadow_dom')
+                : $defaults->flattenShadowDom,
+            excludeSelectors: \array_key_exists('exclude_selectors', $input)
+                ? TypeAssertions::stringList($input['exclude_selectors'], 'preprocessing.exclude_selectors')
+                : $defaults->excludeSelectors,
+            includeSelector: \array_key_exists('include_selector', $input)
+                ? TypeAssertions::stringOrNull($input['include_selector'], 'preprocessing.include_selector')
+                : $defaults->includeSelector,
         );
     }
 
@@ -57,6 +80,11 @@ final readonly class PreprocessingOptions
             preset: $preset,
             removeNavigation: $this->removeNavigation,
             removeForms: $this->removeForms,
+            applyPrintStyles: $this->applyPrintStyles,
+            extractMainContent: $this->extractMainContent,
+            flattenShadowDom: $this->flattenShadowDom,
+            excludeSelectors: $this->excludeSelectors,
+            includeSelector: $this->includeSelector,
         );
     }
 
@@ -70,6 +98,11 @@ final readonly class PreprocessingOptions
             'preset' => $this->preset->value,
             'remove_navigation' => $this->removeNavigation,
             'remove_forms' => $this->removeForms,
+            'apply_print_styles' => $this->applyPrintStyles,
+            'extract_main_content' => $this->extractMainContent,
+            'flatten_shadow_dom' => $this->flattenShadowDom,
+            'exclude_selectors' => $this->excludeSelectors,
+            'include_selector' => $this->includeSelector,
         ];
     }
 
@@ -81,6 +114,11 @@ final readonly class PreprocessingOptions
         return $this->enabled === $defaults->enabled
             && $this->preset === $defaults->preset
             && $this->removeNavigation === $defaults->removeNavigation
-            && $this->removeForms === $defaults->removeForms;
+            && $this->removeForms === $defaults->removeForms
+            && $this->applyPrintStyles === $defaults->applyPrintStyles
+            && $this->extractMainContent === $defaults->extractMainContent
+            && $this->flattenShadowDom === $defaults->flattenShadowDom
+            && $this->excludeSelectors === $defaults->excludeSelectors
+            && $this->includeSelector === $defaults->includeSelector;
     }
 }
@@ -18,6 +18,26 @@ interface ExtensionBridge
      */
     public function convert(string $html, ?array $options = null): string;
 
+    /**
+     * @param list<string> $htmls
+     * @param ConversionOptionsInput|null $options
+     *
+     * @return list<string>
+     */
+    public function convertBatch(array $htmls, ?array $options = null): array;
+
+    /**
+     * @param ConversionOptionsInput|null $options
+     */
+    public function convertFile(string $path, ?array $options = null): string;
+
+    /**
+     * @param ConversionOptionsInput|null $options
+     */
+    public function createOptionsHandle(?array $options = null): object;
+
+    public function convertWithOptionsHandle(string $html, object $handle): string;
+
     /**
      * @param ConversionOptionsInput|null $options
      * @param InlineImageConfigInput|null $config
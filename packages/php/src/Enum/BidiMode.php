@@ -0,0 +1,24 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum BidiMode: string
+{
+    case IGNORE = 'ignore';
+    case MARKS = 'marks';
+    case EMBEDDING = 'embedding';
+    case HTML = 'html';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('bidi_mode', $value, self::cases());
+        }
+    }
+}
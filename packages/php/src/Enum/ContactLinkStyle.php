@@ -0,0 +1,23 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum ContactLinkStyle: string
+{
+    case LINK = 'link';
+    case AUTOLINK = 'autolink';
+    case TEXT = 'text';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('contact_link_style', $value, self::cases());
+        }
+    }
+}
@@ -0,0 +1,22 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum DetailsStyle: string
+{
+    case MARKDOWN = 'markdown';
+    case HTML = 'html';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('details_style', $value, self::cases());
+        }
+    }
+}
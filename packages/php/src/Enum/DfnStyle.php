@@ -0,0 +1,23 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum DfnStyle: string
+{
+    case EMPHASIS = 'emphasis';
+    case TEXT = 'text';
+    case HTML = 'html';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('dfn_style', $value, self::cases());
+        }
+    }
+}
@@ -0,0 +1,23 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum FigureStyle: string
+{
+    case ITALIC = 'italic';
+    case BLOCKQUOTE = 'blockquote';
+    case HTML = 'html';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('figure_style', $value, self::cases());
+        }
+    }
+}
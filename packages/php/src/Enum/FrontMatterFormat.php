@@ -0,0 +1,23 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum FrontMatterFormat: string
+{
+    case YAML = 'yaml';
+    case TOML = 'toml';
+    case JSON = 'json';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('front_matter_format', $value, self::cases());
+        }
+    }
+}
@@ -0,0 +1,24 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum HeaderBreakStyle: string
+{
+    case INHERIT = 'inherit';
+    case SPACE = 'space';
+    case SLASH = 'slash';
+    case HTML = 'html';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('header_break_style', $value, self::cases());
+        }
+    }
+}
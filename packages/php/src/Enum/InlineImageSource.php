@@ -10,6 +10,7 @@ enum InlineImageSource: string
 {
     case IMG_DATA_URI = 'img_data_uri';
     case SVG_ELEMENT = 'svg_element';
+    case IMG_REMOTE = 'img_remote';
 
     public static function fromString(string $value): self
     {
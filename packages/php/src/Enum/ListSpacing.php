@@ -0,0 +1,23 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum ListSpacing: string
+{
+    case AUTO = 'auto';
+    case TIGHT = 'tight';
+    case LOOSE = 'loose';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('list_spacing', $value, self::cases());
+        }
+    }
+}
@@ -0,0 +1,23 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum MathStyle: string
+{
+    case COMMENT = 'comment';
+    case DOLLAR = 'dollar';
+    case FENCED = 'fenced';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('math_style', $value, self::cases());
+        }
+    }
+}
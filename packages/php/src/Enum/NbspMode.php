@@ -0,0 +1,22 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum NbspMode: string
+{
+    case SPACE = 'space';
+    case PRESERVE = 'preserve';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('nbsp_mode', $value, self::cases());
+        }
+    }
+}
@@ -11,6 +11,7 @@ enum PreprocessingPreset: string
     case MINIMAL = 'minimal';
     case STANDARD = 'standard';
     case AGGRESSIVE = 'aggressive';
+    case CONFLUENCE = 'confluence';
 
     public static function fromString(string $value): self
     {
@@ -0,0 +1,23 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum SrcsetPolicy: string
+{
+    case FIRST = 'first';
+    case LARGEST = 'largest';
+    case SMALLEST = 'smallest';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('srcset_policy', $value, self::cases());
+        }
+    }
+}
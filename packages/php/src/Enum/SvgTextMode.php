@@ -0,0 +1,24 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum SvgTextMode: string
+{
+    case OFF = 'off';
+    case ALT = 'alt';
+    case CAPTION = 'caption';
+    case REPLACE = 'replace';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('svg_text_mode', $value, self::cases());
+        }
+    }
+}
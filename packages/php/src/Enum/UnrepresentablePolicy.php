@@ -0,0 +1,23 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Enum;
+
+use HtmlToMarkdown\Exception\InvalidOption;
+
+enum UnrepresentablePolicy: string
+{
+    case DROP = 'drop';
+    case PASSTHROUGH = 'passthrough';
+    case DIAGNOSE = 'diagnose';
+
+    public static function fromString(string $value): self
+    {
+        try {
+            return self::from($value);
+        } catch (\ValueError) {
+            throw InvalidOption::forEnum('unrepresentable_policy', $value, self::cases());
+        }
+    }
+}
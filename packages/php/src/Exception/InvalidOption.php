@@ -31,4 +31,9 @@ final class InvalidOption extends InvalidArgumentException
     {
         return new self(\sprintf("Invalid value for option '%s': %s", $option, $message));
     }
+
+    public static function unknown(string $option): self
+    {
+        return new self(\sprintf("Unknown option '%s'", $option));
+    }
 }
@@ -6,14 +6,17 @@ namespace HtmlToMarkdown;
 
 use HtmlToMarkdown\Config\ConversionOptions;
 use HtmlToMarkdown\Config\InlineImageConfig;
+use HtmlToMarkdown\Config\MetadataConfig;
 use HtmlToMarkdown\Service\Converter as ConverterService;
 use HtmlToMarkdown\Value\ExtendedMetadata;
 use HtmlToMarkdown\Value\InlineImageExtraction;
+use HtmlToMarkdown\Value\OptionsHandle;
 use HtmlToMarkdown\Visitor\HtmlVisitor;
 
 /**
  * @phpstan-import-type ConversionOptionsInput from \HtmlToMarkdown\Config\ConversionOptions
  * @phpstan-import-type InlineImageConfigInput from \HtmlToMarkdown\Config\InlineImageConfig
+ * @phpstan-import-type MetadataConfigInput from \HtmlToMarkdown\Config\MetadataConfig
  */
 
 final class HtmlToMarkdown
@@ -27,6 +30,51 @@ final class HtmlToMarkdown
         return ConverterService::create()->convert($html, $options);
     }
 
+    /**
+     * Convert several HTML documents with shared options in a single extension call.
+     *
+     * @param list<string> $htmls
+     * @param ConversionOptions|ConversionOptionsInput|null $options
+     * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+     *
+     * @return list<string>
+     */
+    public static function convertBatch(array $htmls, ConversionOptions|array|null $options = null): array
+    {
+        return ConverterService::create()->convertBatch($htmls, $options);
+    }
+
+    /**
+     * Read and convert an HTML file.
+     *
+     * The extension reads the file itself and picks the character set from a byte order mark or
+     * `<meta charset>`, falling back to UTF-8 and then Windows-1252, so there is no need to run
+     * `mb_convert_encoding` on the contents first.
+     *
+     * @param ConversionOptions|ConversionOptionsInput|null $options
+     * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+     */
+    public static function convertFile(string $path, ConversionOptions|array|null $options = null): string
+    {
+        return ConverterService::create()->convertFile($path, $options);
+    }
+
+    /**
+     * Parse and validate options once for reuse across conversions.
+     *
+     * @param ConversionOptions|ConversionOptionsInput|null $options
+     * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+     */
+    public static function createOptionsHandle(ConversionOptions|array|null $options = null): OptionsHandle
+    {
+        return ConverterService::create()->createOptionsHandle($options);
+    }
+
+    public static function convertWithOptionsHandle(string $html, OptionsHandle $handle): string
+    {
+        return ConverterService::create()->convertWithOptionsHandle($html, $handle);
+    }
+
     /**
      * @param ConversionOptions|ConversionOptionsInput|null $options
      * @param InlineImageConfig|InlineImageConfigInput|null $config
@@ -43,14 +91,15 @@ final class HtmlToMarkdown
 
     /**
      * @param ConversionOptions|ConversionOptionsInput|null $options
-     * @param array<string, mixed>|null $metadataConfig
+     * @param MetadataConfig|MetadataConfigInput|null $metadataConfig
      * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+     * @phpstan-param MetadataConfig|array<string, mixed>|null $metadataConfig
      * @phpstan-return array{markdown: string, metadata: ExtendedMetadata}
      */
     public static function convertWithMetadata(
         string $html,
         ConversionOptions|array|null $options = null,
-        ?array $metadataConfig = null,
+        MetadataConfig|array|null $metadataConfig = null,
     ): array {
         return ConverterService::create()->convertWithMetadata($html, $options, $metadataConfig);
     }
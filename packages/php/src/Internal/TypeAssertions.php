@@ -54,6 +54,18 @@ final class TypeAssertions
         );
     }
 
+    public static function fraction(mixed $value, string $option): float
+    {
+        if ((\is_float($value) || \is_int($value)) && $value >= 0 && $value <= 1) {
+            return (float) $value;
+        }
+
+        throw InvalidOption::because(
+            $option,
+            \sprintf('expected a number between 0.0 and 1.0, got %s', \get_debug_type($value)),
+        );
+    }
+
     /**
      * @return list<string>
      */
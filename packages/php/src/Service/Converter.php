@@ -7,15 +7,18 @@ namespace HtmlToMarkdown\Service;
 use HtmlToMarkdown\Bridge\ExtensionBridge;
 use HtmlToMarkdown\Config\ConversionOptions;
 use HtmlToMarkdown\Config\InlineImageConfig;
+use HtmlToMarkdown\Config\MetadataConfig;
 use HtmlToMarkdown\Contract\ExtensionBridge as ExtensionBridgeContract;
 use HtmlToMarkdown\Internal\TypeAssertions;
 use HtmlToMarkdown\Value\ExtendedMetadata;
 use HtmlToMarkdown\Value\InlineImageExtraction;
+use HtmlToMarkdown\Value\OptionsHandle;
 use HtmlToMarkdown\Visitor\HtmlVisitor;
 
 /**
  * @phpstan-import-type ConversionOptionsInput from \HtmlToMarkdown\Config\ConversionOptions
  * @phpstan-import-type InlineImageConfigInput from \HtmlToMarkdown\Config\InlineImageConfig
+ * @phpstan-import-type MetadataConfigInput from \HtmlToMarkdown\Config\MetadataConfig
  */
 
 final class Converter
@@ -39,6 +42,43 @@ final class Converter
         return $this->bridge->convert($html, $this->normalizeOptions($options));
     }
 
+    /**
+     * @param list<string> $htmls
+     * @param ConversionOptions|ConversionOptionsInput|null $options
+     * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+     *
+     * @return list<string>
+     */
+    public function convertBatch(array $htmls, ConversionOptions|array|null $options = null): array
+    {
+        return $this->bridge->convertBatch(array_values($htmls), $this->normalizeOptions($options));
+    }
+
+    /**
+     * @param ConversionOptions|ConversionOptionsInput|null $options
+     * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+     */
+    public function convertFile(string $path, ConversionOptions|array|null $options = null): string
+    {
+        return $this->bridge->convertFile($path, $this->normalizeOptions($options));
+    }
+
+    /**
+     * Parse and validate options once for reuse across conversions.
+     *
+     * @param ConversionOptions|ConversionOptionsInput|null $options
+     * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+     */
+    public function createOptionsHandle(ConversionOptions|array|null $options = null): OptionsHandle
+    {
+        return new OptionsHandle($this->bridge->createOptionsHandle($this->normalizeOptions($options)));
+    }
+
+    public function convertWithOptionsHandle(string $html, OptionsHandle $handle): string
+    {
+        return $this->bridge->convertWithOptionsHandle($html, $handle->native);
+    }
+
     /**
      * @param ConversionOptions|ConversionOptionsInput|null $options
      * @param InlineImageConfig|InlineImageConfigInput|null $config
@@ -61,19 +101,20 @@ final class Converter
 
     /**
      * @param ConversionOptions|array<string, mixed>|null $options
-     * @param array<string, mixed>|null $metadataConfig
+     * @param MetadataConfig|MetadataConfigInput|null $metadataConfig
      * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+     * @phpstan-param MetadataConfig|array<string, mixed>|null $metadataConfig
      * @phpstan-return array{markdown: string, metadata: ExtendedMetadata}
      */
     public function convertWithMetadata(
         string $html,
         ConversionOptions|array|null $options = null,
-        ?array $metadataConfig = null,
+        MetadataConfig|array|null $metadataConfig = null,
     ): array {
         $payload = $this->bridge->convertWithMetadata(
             $html,
             $this->normalizeOptions($options),
-            $metadataConfig,
+            $this->normalizeMetadataConfig($metadataConfig),
         );
 
         $markdown = TypeAssertions::string($payload['markdown'] ?? '', 'convert_with_metadata.markdown');
@@ -141,4 +182,21 @@ final class Converter
 
         return InlineImageConfig::fromArray($config)->toArray();
     }
+
+    /**
+     * @phpstan-param MetadataConfig|array<string, mixed>|null $config
+     * @phpstan-return MetadataConfigInput|null
+     */
+    private function normalizeMetadataConfig(MetadataConfig|array|null $config): ?array
+    {
+        if ($config === null) {
+            return null;
+        }
+
+        if ($config instanceof MetadataConfig) {
+            return $config->toArray();
+        }
+
+        return MetadataConfig::fromArray($config)->toArray();
+    }
 }
@@ -0,0 +1,41 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Value;
+
+use HtmlToMarkdown\Internal\TypeAssertions;
+
+final readonly class AuthorProfile
+{
+    /**
+     * @param 'rel_author'|'rel_me'|'schema_author'|'schema_same_as'|string $source
+     */
+    public function __construct(
+        public string $url,
+        public ?string $name,
+        public string $source,
+    ) {
+    }
+
+    /**
+     * @param array<string, mixed> $payload
+     */
+    public static function fromExtensionPayload(array $payload): self
+    {
+        foreach (['url', 'source'] as $required) {
+            if (!\array_key_exists($required, $payload)) {
+                throw \HtmlToMarkdown\Exception\InvalidOption::because(
+                    "author_profile.$required",
+                    'missing field in extension payload',
+                );
+            }
+        }
+
+        return new self(
+            url: TypeAssertions::string($payload['url'], 'author_profile.url'),
+            name: TypeAssertions::stringOrNull($payload['name'] ?? null, 'author_profile.name'),
+            source: TypeAssertions::string($payload['source'], 'author_profile.source'),
+        );
+    }
+}
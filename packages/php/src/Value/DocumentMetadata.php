@@ -13,12 +13,14 @@ final readonly class DocumentMetadata
      * @param array<string, string> $twitterCard
      * @param array<string, string> $metaTags
      * @param list<string> $keywords
+     * @param list<AuthorProfile> $authorProfiles
      */
     public function __construct(
         public ?string $title,
         public ?string $description,
         public array $keywords,
         public ?string $author,
+        public array $authorProfiles,
         public ?string $canonicalUrl,
         public ?string $baseHref,
         public ?string $language,
@@ -41,6 +43,7 @@ final readonly class DocumentMetadata
                 ? TypeAssertions::stringList($payload['keywords'], 'document_metadata.keywords')
                 : [],
             author: TypeAssertions::stringOrNull($payload['author'] ?? null, 'document_metadata.author'),
+            authorProfiles: self::authorProfiles($payload['author_profiles'] ?? []),
             canonicalUrl: TypeAssertions::stringOrNull(
                 $payload['canonical_url'] ?? null,
                 'document_metadata.canonical_url',
@@ -56,4 +59,31 @@ final readonly class DocumentMetadata
             metaTags: TypeAssertions::stringMap($payload['meta_tags'] ?? [], 'document_metadata.meta_tags'),
         );
     }
+
+    /**
+     * @return list<AuthorProfile>
+     */
+    private static function authorProfiles(mixed $payload): array
+    {
+        if (!\is_array($payload)) {
+            throw \HtmlToMarkdown\Exception\InvalidOption::because(
+                'document_metadata.author_profiles',
+                'expected array, got ' . \get_debug_type($payload),
+            );
+        }
+
+        $profiles = [];
+        foreach ($payload as $profile) {
+            if (!\is_array($profile)) {
+                throw \HtmlToMarkdown\Exception\InvalidOption::because(
+                    'document_metadata.author_profiles[]',
+                    'expected array, got ' . \get_debug_type($profile),
+                );
+            }
+
+            $profiles[] = AuthorProfile::fromExtensionPayload($profile);
+        }
+
+        return $profiles;
+    }
 }
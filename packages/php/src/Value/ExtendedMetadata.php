@@ -11,6 +11,8 @@ final readonly class ExtendedMetadata
      * @param list<LinkMetadata> $links
      * @param list<ImageMetadata> $images
      * @param list<StructuredData> $structuredData
+     * @param list<ResourceMetadata> $resources
+     * @param list<SourceMapEntry> $sourceMap
      */
     public function __construct(
         public DocumentMetadata $document,
@@ -18,6 +20,8 @@ final readonly class ExtendedMetadata
         public array $links,
         public array $images,
         public array $structuredData,
+        public array $resources = [],
+        public array $sourceMap = [],
     ) {
     }
 
@@ -84,12 +88,42 @@ final readonly class ExtendedMetadata
             $structuredData[] = StructuredData::fromExtensionPayload($data);
         }
 
+        $resourcesPayload = \is_array($payload['resources'] ?? null) ? $payload['resources'] : [];
+
+        $resources = [];
+        foreach ($resourcesPayload as $resource) {
+            if (!\is_array($resource)) {
+                throw \HtmlToMarkdown\Exception\InvalidOption::because(
+                    'extended_metadata.resources[]',
+                    'expected array, got ' . \get_debug_type($resource),
+                );
+            }
+
+            $resources[] = ResourceMetadata::fromExtensionPayload($resource);
+        }
+
+        $sourceMapPayload = \is_array($payload['source_map'] ?? null) ? $payload['source_map'] : [];
+
+        $sourceMap = [];
+        foreach ($sourceMapPayload as $entry) {
+            if (!\is_array($entry)) {
+                throw \HtmlToMarkdown\Exception\InvalidOption::because(
+                    'extended_metadata.source_map[]',
+                    'expected array, got ' . \get_debug_type($entry),
+                );
+            }
+
+            $sourceMap[] = SourceMapEntry::fromExtensionPayload($entry);
+        }
+
         return new self(
             document: DocumentMetadata::fromExtensionPayload($documentPayload),
             headers: $headers,
             links: $links,
             images: $images,
             structuredData: $structuredData,
+            resources: $resources,
+            sourceMap: $sourceMap,
         );
     }
 
@@ -104,6 +138,14 @@ final readonly class ExtendedMetadata
                 'description' => $this->document->description,
                 'keywords' => $this->document->keywords,
                 'author' => $this->document->author,
+                'author_profiles' => \array_map(
+                    static fn (AuthorProfile $p): array => [
+                        'url' => $p->url,
+                        'name' => $p->name,
+                        'source' => $p->source,
+                    ],
+                    $this->document->authorProfiles,
+                ),
                 'canonical_url' => $this->document->canonicalUrl,
                 'base_href' => $this->document->baseHref,
                 'language' => $this->document->language,
@@ -152,6 +194,23 @@ final readonly class ExtendedMetadata
                 ],
                 $this->structuredData,
             ),
+            'resources' => \array_map(
+                static fn (ResourceMetadata $r): array => [
+                    'url' => $r->url,
+                    'resource_type' => $r->resourceType,
+                ],
+                $this->resources,
+            ),
+            'source_map' => \array_map(
+                static fn (SourceMapEntry $e): array => [
+                    'tag' => $e->tag,
+                    'html_start' => $e->htmlStart,
+                    'html_end' => $e->htmlEnd,
+                    'markdown_start' => $e->markdownStart,
+                    'markdown_end' => $e->markdownEnd,
+                ],
+                $this->sourceMap,
+            ),
         ];
     }
 }
@@ -0,0 +1,19 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Value;
+
+/**
+ * Conversion options parsed and validated once by the extension.
+ *
+ * Reuse one handle across conversions (for example in a long-running queue worker)
+ * to skip re-parsing the options array on every call.
+ */
+final readonly class OptionsHandle
+{
+    public function __construct(
+        public object $native,
+    ) {
+    }
+}
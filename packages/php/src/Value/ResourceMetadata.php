@@ -0,0 +1,36 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Value;
+
+use HtmlToMarkdown\Internal\TypeAssertions;
+
+final readonly class ResourceMetadata
+{
+    public function __construct(
+        public string $url,
+        public string $resourceType,
+    ) {
+    }
+
+    /**
+     * @param array<string, mixed> $payload
+     */
+    public static function fromExtensionPayload(array $payload): self
+    {
+        foreach (['url', 'resource_type'] as $required) {
+            if (!\array_key_exists($required, $payload)) {
+                throw \HtmlToMarkdown\Exception\InvalidOption::because(
+                    "resources.$required",
+                    'missing field in extension payload',
+                );
+            }
+        }
+
+        return new self(
+            url: TypeAssertions::string($payload['url'], 'resources.url'),
+            resourceType: TypeAssertions::string($payload['resource_type'], 'resources.resource_type'),
+        );
+    }
+}
@@ -0,0 +1,42 @@
+<?php
+
+declare(strict_types=1);
+
+namespace HtmlToMarkdown\Value;
+
+use HtmlToMarkdown\Internal\TypeAssertions;
+
+final readonly class SourceMapEntry
+{
+    public function __construct(
+        public string $tag,
+        public int $htmlStart,
+        public int $htmlEnd,
+        public int $markdownStart,
+        public int $markdownEnd,
+    ) {
+    }
+
+    /**
+     * @param array<string, mixed> $payload
+     */
+    public static function fromExtensionPayload(array $payload): self
+    {
+        foreach (['tag', 'html_start', 'html_end', 'markdown_start', 'markdown_end'] as $required) {
+            if (!\array_key_exists($required, $payload)) {
+                throw \HtmlToMarkdown\Exception\InvalidOption::because(
+                    "source_map.$required",
+                    'missing field in extension payload',
+                );
+            }
+        }
+
+        return new self(
+            tag: TypeAssertions::string($payload['tag'], 'source_map.tag'),
+            htmlStart: TypeAssertions::positiveInt($payload['html_start'], 'source_map.html_start'),
+            htmlEnd: TypeAssertions::positiveInt($payload['html_end'], 'source_map.html_end'),
+            markdownStart: TypeAssertions::positiveInt($payload['markdown_start'], 'source_map.markdown_start'),
+            markdownEnd: TypeAssertions::positiveInt($payload['markdown_end'], 'source_map.markdown_end'),
+        );
+    }
+}
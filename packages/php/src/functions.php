@@ -6,12 +6,15 @@ namespace HtmlToMarkdown;
 
 use HtmlToMarkdown\Config\ConversionOptions;
 use HtmlToMarkdown\Config\InlineImageConfig;
+use HtmlToMarkdown\Config\MetadataConfig;
 use HtmlToMarkdown\Value\ExtendedMetadata;
 use HtmlToMarkdown\Value\InlineImageExtraction;
+use HtmlToMarkdown\Value\OptionsHandle;
 
 /**
  * @phpstan-import-type ConversionOptionsInput from \HtmlToMarkdown\Config\ConversionOptions
  * @phpstan-import-type InlineImageConfigInput from \HtmlToMarkdown\Config\InlineImageConfig
+ * @phpstan-import-type MetadataConfigInput from \HtmlToMarkdown\Config\MetadataConfig
  */
 
 /**
@@ -23,6 +26,43 @@ function convert(string $html, ConversionOptions|array|null $options = null): st
     return HtmlToMarkdown::convert($html, $options);
 }
 
+/**
+ * @param list<string> $htmls
+ * @param ConversionOptions|array<string, mixed>|null $options
+ * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+ *
+ * @return list<string>
+ */
+function convert_batch(array $htmls, ConversionOptions|array|null $options = null): array
+{
+    return HtmlToMarkdown::convertBatch($htmls, $options);
+}
+
+/**
+ * @param ConversionOptions|array<string, mixed>|null $options
+ * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+ */
+function convert_file(string $path, ConversionOptions|array|null $options = null): string
+{
+    return HtmlToMarkdown::convertFile($path, $options);
+}
+
+/**
+ * Parse and validate options once, e.g. when a queue worker starts, for reuse across conversions.
+ *
+ * @param ConversionOptions|array<string, mixed>|null $options
+ * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+ */
+function create_options_handle(ConversionOptions|array|null $options = null): OptionsHandle
+{
+    return HtmlToMarkdown::createOptionsHandle($options);
+}
+
+function convert_with_options_handle(string $html, OptionsHandle $handle): string
+{
+    return HtmlToMarkdown::convertWithOptionsHandle($html, $handle);
+}
+
 /**
  * @param ConversionOptions|array<string, mixed>|null $options
  * @param InlineImageConfig|array<string, mixed>|null $config
@@ -62,13 +102,15 @@ function convert_with_inline_images(
  *   - escape_ascii: bool (default: false)
  *   - And many more - see ConversionOptions documentation
  *
- * @param array<string, bool|int|string>|null $metadataConfig Optional metadata extraction configuration.
- *   Associative array controlling which metadata to extract:
+ * @param MetadataConfig|array<string, bool|int>|null $metadataConfig Optional metadata extraction configuration.
+ *   Can be a MetadataConfig instance or associative array controlling which metadata to extract:
  *   - extract_headers: bool (Extract h1-h6 elements, default: true)
  *   - extract_links: bool (Extract hyperlinks, default: true)
  *   - extract_images: bool (Extract image elements, default: true)
  *   - extract_structured_data: bool (Extract JSON-LD/Microdata/RDFa, default: true)
  *   - max_structured_data_size: int (Size limit in bytes, default: 1000000)
+ *   - extract_resources: bool (Collect script/stylesheet/font/media URLs, default: false)
+ *   - extract_source_map: bool (Record HTML/Markdown byte ranges of blocks, default: false)
  *
  * @return array{markdown: string, metadata: ExtendedMetadata} Associative array with keys:
  *   - "markdown": string - The converted Markdown output
@@ -79,6 +121,7 @@ function convert_with_inline_images(
  *       - description?: string - From <meta name="description">
  *       - keywords: string[] - From <meta name="keywords">
  *       - author?: string - From <meta name="author">
+ *       - author_profiles: AuthorProfile[] - rel="author"/rel="me" links and schema.org author/sameAs URLs
  *       - language?: string - From lang attribute (e.g., "en")
  *       - text_direction?: string - "ltr", "rtl", or "auto"
  *       - canonical_url?: string - From <link rel="canonical">
@@ -115,6 +158,15 @@ function convert_with_inline_images(
  *       - raw_json: string - Raw JSON content
  *       - schema_type?: string - Schema type (e.g., "Article", "Event")
  *
+ *     * resources: ResourceMetadata[] - External resources the page loads (with extract_resources):
+ *       - url: string - Resource URL as written in the document
+ *       - resource_type: string - "script", "stylesheet", "font", "image", "media", "embed", or "other"
+ *
+ *     * source_map: SourceMapEntry[] - Where each block came from (with extract_source_map):
+ *       - tag: string - Tag name of the block element
+ *       - html_start, html_end: int - Byte range of the element in the input HTML
+ *       - markdown_start, markdown_end: int - Byte range of the block in the Markdown
+ *
  * @throws \Throwable If HTML parsing fails, configuration is invalid, or conversion fails
  *   (thrown by the native extension)
  *
@@ -184,12 +236,13 @@ function convert_with_inline_images(
  * @see ConversionOptions Configuration options documentation
  *
  * @phpstan-param ConversionOptions|array<string, mixed>|null $options
+ * @phpstan-param MetadataConfig|array<string, mixed>|null $metadataConfig
  * @return array{markdown: string, metadata: ExtendedMetadata}
  */
 function convert_with_metadata(
     string $html,
     ConversionOptions|array|null $options = null,
-    ?array $metadataConfig = null,
+    MetadataConfig|array|null $metadataConfig = null,
 ): array {
     return HtmlToMarkdown::convertWithMetadata($html, $options, $metadataConfig);
 }
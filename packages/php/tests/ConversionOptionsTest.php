@@ -48,4 +48,25 @@ final class ConversionOptionsTest extends TestCase
         $this->expectException(InvalidOption::class);
         ConversionOptions::fromArray(['strong_em_symbol' => 'too-long']);
     }
+
+    public function testUnknownKeysAreIgnoredByDefault(): void
+    {
+        $options = ConversionOptions::fromArray(['heading_stlye' => 'atx']);
+
+        self::assertEquals(new ConversionOptions(), $options);
+    }
+
+    public function testStrictOptionsRejectsUnknownKeys(): void
+    {
+        $this->expectException(InvalidOption::class);
+        $this->expectExceptionMessage("Unknown option 'heading_stlye'");
+        ConversionOptions::fromArray(['heading_stlye' => 'atx', 'strict_options' => true]);
+    }
+
+    public function testStrictOptionsAcceptsKnownKeys(): void
+    {
+        $options = ConversionOptions::fromArray(['heading_style' => 'atx', 'strict_options' => true]);
+
+        self::assertSame(HeadingStyle::ATX, $options->headingStyle);
+    }
 }
@@ -6,6 +6,7 @@ namespace HtmlToMarkdown\Tests;
 
 use HtmlToMarkdown\Config\ConversionOptions;
 use HtmlToMarkdown\Config\InlineImageConfig;
+use HtmlToMarkdown\Exception\ConversionFailed;
 use HtmlToMarkdown\Service\Converter;
 use HtmlToMarkdown\Value\InlineImageExtraction;
 use HtmlToMarkdown\Value\InlineImageFormat;
@@ -47,6 +48,35 @@ final class ConverterTest extends TestCase
         self::assertSame("Lorem\nipsum\ndolor sit\namet\n\n", $markdown);
     }
 
+    public function testConvertReportsEveryInvalidOption(): void
+    {
+        $this->expectException(ConversionFailed::class);
+        $this->expectExceptionMessage(
+            "bullets cannot be empty; ordered_markers must only contain '.' or ')', got \"-\"",
+        );
+
+        $this->converter->convert('<p>x</p>', ['bullets' => '', 'ordered_markers' => '-']);
+    }
+
+    public function testOptionsHandleIsReusable(): void
+    {
+        $handle = $this->converter->createOptionsHandle(new ConversionOptions(wrap: true, wrapWidth: 10));
+
+        self::assertSame(
+            "Lorem\nipsum\ndolor sit\namet\n\n",
+            $this->converter->convertWithOptionsHandle('<p>Lorem ipsum dolor sit amet</p>', $handle),
+        );
+        self::assertSame("Hello\n", $this->converter->convertWithOptionsHandle('<p>Hello</p>', $handle));
+    }
+
+    public function testOptionsHandleRejectsInvalidOptions(): void
+    {
+        $this->expectException(ConversionFailed::class);
+        $this->expectExceptionMessage('bullets cannot be empty');
+
+        $this->converter->createOptionsHandle(['bullets' => '']);
+    }
+
     public function testConvertWithInlineImagesReturnsExtraction(): void
     {
         $html = '<p><img src="data:image/png;base64,Zm9v" alt="test"></p>';
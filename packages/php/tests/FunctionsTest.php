@@ -4,10 +4,15 @@ declare(strict_types=1);
 
 namespace HtmlToMarkdown\Tests;
 
+use HtmlToMarkdown\Exception\ConversionFailed;
 use HtmlToMarkdown\HtmlToMarkdown;
 use HtmlToMarkdown\Value\InlineImageExtraction;
 
 use function HtmlToMarkdown\convert;
+use function HtmlToMarkdown\convert_batch;
+use function HtmlToMarkdown\convert_file;
+use function HtmlToMarkdown\convert_with_options_handle;
+use function HtmlToMarkdown\create_options_handle;
 use function HtmlToMarkdown\convert_with_inline_images;
 
 final class FunctionsTest extends TestCase
@@ -29,6 +34,42 @@ final class FunctionsTest extends TestCase
         self::assertSame("![x](data:image/png;base64,Zm9v)\n", $result->markdown);
     }
 
+    public function testOptionsHandleHelpers(): void
+    {
+        $handle = create_options_handle(['heading_style' => 'atx']);
+
+        self::assertSame("# Title\n", convert_with_options_handle('<h1>Title</h1>', $handle));
+    }
+
+    public function testConvertBatchHelperKeepsOrder(): void
+    {
+        $markdown = convert_batch(['<h1>One</h1>', '<p>Two</p>'], ['heading_style' => 'atx']);
+
+        self::assertSame(["# One\n", "Two\n"], $markdown);
+    }
+
+    public function testConvertFileHelperDecodesDeclaredCharset(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'h2m');
+        self::assertIsString($path);
+        file_put_contents($path, "<meta charset=\"iso-8859-1\"><h1>Caf\xE9</h1>");
+
+        try {
+            $markdown = convert_file($path, ['heading_style' => 'atx']);
+        } finally {
+            unlink($path);
+        }
+
+        self::assertSame("# Café\n", $markdown);
+    }
+
+    public function testConvertFileHelperReportsMissingFile(): void
+    {
+        $this->expectException(ConversionFailed::class);
+
+        convert_file(sys_get_temp_dir() . '/h2m-missing-' . uniqid() . '.html');
+    }
+
     public function testFacadeAcceptsArray(): void
     {
         $markdown = HtmlToMarkdown::convert('<p>Hello</p>', ['heading_style' => 'atx']);
@@ -4,6 +4,8 @@ declare(strict_types=1);
 
 namespace HtmlToMarkdown\Tests;
 
+use HtmlToMarkdown\Config\MetadataConfig;
+use HtmlToMarkdown\Exception\InvalidOption;
 use HtmlToMarkdown\HtmlToMarkdown;
 use HtmlToMarkdown\Value\ExtendedMetadata;
 
@@ -151,6 +153,35 @@ final class MetadataExtractionTest extends TestCase
         self::assertIsArray($result['metadata']->images);
     }
 
+    public function testMetadataExtractionWithConfigObject(): void
+    {
+        $html = '<html><body><h1>Header</h1><a href="https://example.com">Link</a></body></html>';
+        $config = new MetadataConfig(extractHeaders: false);
+        $result = convert_with_metadata($html, null, $config);
+
+        self::assertSame([], $result['metadata']->headers);
+        self::assertCount(1, $result['metadata']->links);
+    }
+
+    public function testMetadataConfigFromArrayRoundTrips(): void
+    {
+        $config = MetadataConfig::fromArray([
+            'extract_resources' => true,
+            'max_structured_data_size' => 2048,
+        ]);
+
+        self::assertTrue($config->extractResources);
+        self::assertTrue($config->extractHeaders);
+        self::assertSame(2048, $config->toArray()['max_structured_data_size']);
+    }
+
+    public function testMetadataConfigRejectsInvalidValues(): void
+    {
+        $this->expectException(InvalidOption::class);
+
+        MetadataConfig::fromArray(['extract_links' => 'yes']);
+    }
+
     public function testMetadataExtractionViaFacade(): void
     {
         $html = '<html><head><title>Test</title></head><body><p>Content</p></body></html>';
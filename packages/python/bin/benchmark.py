@@ -6,6 +6,7 @@ import json
 import os
 import sys
 import time
+from concurrent.futures import ThreadPoolExecutor
 from pathlib import Path
 from typing import TYPE_CHECKING
 
@@ -59,6 +60,12 @@ def parse_args() -> argparse.Namespace:
         default=None,
         help="Visitor type to use during conversion",
     )
+    parser.add_argument(
+        "--threads",
+        type=int,
+        default=1,
+        help="Worker threads, each running every iteration (for scaling benchmarks)",
+    )
     return parser.parse_args()
 
 
@@ -303,16 +310,26 @@ def main() -> None:
         freq = int(profile_frequency) if profile_frequency and profile_frequency.isdigit() else 1000
         start_profiling(profile_output, freq)
 
+    def run_iterations() -> None:
+        for _ in range(iterations):
+            run_scenario(html, args.scenario, handle, metadata_config, visitor)
+
+    threads = max(1, args.threads)
     start = time.perf_counter()
-    for _ in range(iterations):
-        run_scenario(html, args.scenario, handle, metadata_config, visitor)
+    if threads == 1:
+        run_iterations()
+    else:
+        with ThreadPoolExecutor(max_workers=threads) as pool:
+            for future in [pool.submit(run_iterations) for _ in range(threads)]:
+                future.result()
     elapsed = time.perf_counter() - start
 
     if profile_output:
         stop_profiling()
 
-    bytes_processed = len(html.encode("utf-8")) * iterations
-    ops_per_sec = iterations / elapsed
+    total_iterations = iterations * threads
+    bytes_processed = len(html.encode("utf-8")) * total_iterations
+    ops_per_sec = total_iterations / elapsed
     mb_per_sec = (bytes_processed / (1024 * 1024)) / elapsed
 
     result = {
@@ -320,7 +337,8 @@ def main() -> None:
         "fixture": fixture.name,
         "fixture_path": str(fixture),
         "scenario": args.scenario,
-        "iterations": iterations,
+        "threads": threads,
+        "iterations": total_iterations,
         "elapsed_seconds": elapsed,
         "ops_per_sec": ops_per_sec,
         "mb_per_sec": mb_per_sec,
@@ -20,17 +20,22 @@ from html_to_markdown.api import (
     InlineImage,
     InlineImageConfig,
     InlineImageWarning,
+    InputFormat,
     MetadataConfig,
     OptionsHandle,
     convert,
+    convert_batch,
     convert_with_async_visitor,
     convert_with_handle,
+    convert_with_fingerprint,
     convert_with_inline_images,
     convert_with_inline_images_handle,
     convert_with_metadata,
     convert_with_metadata_handle,
+    convert_with_stats,
     convert_with_visitor,
     create_options_handle,
+    detect_input_format,
     start_profiling,
     stop_profiling,
 )
@@ -53,21 +58,26 @@ __all__ = [
     "InlineImage",
     "InlineImageConfig",
     "InlineImageWarning",
+    "InputFormat",
     "InvalidParserError",
     "MetadataConfig",
     "MissingDependencyError",
     "OptionsHandle",
     "PreprocessingOptions",
     "convert",
+    "convert_batch",
     "convert_to_markdown",
     "convert_with_async_visitor",
     "convert_with_handle",
+    "convert_with_fingerprint",
     "convert_with_inline_images",
     "convert_with_inline_images_handle",
     "convert_with_metadata",
     "convert_with_metadata_handle",
+    "convert_with_stats",
     "convert_with_visitor",
     "create_options_handle",
+    "detect_input_format",
     "markdownify",
     "start_profiling",
     "stop_profiling",
@@ -1,18 +1,30 @@
 from typing import Literal, TypeAlias, TypedDict
 
+InputFormat: TypeAlias = Literal["html", "hocr", "alto", "mhtml", "warc", "xml", "text"]
+
 class PreprocessingOptions:
     enabled: bool
-    preset: Literal["minimal", "standard", "aggressive"]
+    preset: Literal["minimal", "standard", "aggressive", "confluence"]
     remove_navigation: bool
     remove_forms: bool
+    apply_print_styles: bool
+    extract_main_content: bool
+    flatten_shadow_dom: bool
+    exclude_selectors: list[str]
+    include_selector: str | None
 
     def __init__(
         self,
         *,
         enabled: bool = False,
-        preset: Literal["minimal", "standard", "aggressive"] = "standard",
+        preset: Literal["minimal", "standard", "aggressive", "confluence"] = "standard",
         remove_navigation: bool = True,
         remove_forms: bool = True,
+        apply_print_styles: bool = False,
+        extract_main_content: bool = False,
+        flatten_shadow_dom: bool = False,
+        exclude_selectors: list[str] = [],
+        include_selector: str | None = None,
     ) -> None: ...
 
 class ConversionOptions:
@@ -46,7 +58,47 @@ class ConversionOptions:
     encoding: str
     debug: bool
     strip_tags: list[str]
+    remove_tags: list[str]
     preserve_tags: list[str]
+    remove_space_before_punctuation: bool
+    nbsp_in_prose: Literal["space", "preserve"]
+    nbsp_in_tables: Literal["space", "preserve"]
+    front_matter_format: Literal["yaml", "toml", "json"]
+    remove_soft_hyphens: bool
+    bidi_mode: Literal["ignore", "marks", "embedding", "html"]
+    localized_punctuation: bool
+    ordered_markers: str
+    cycle_bullets: bool
+    list_spacing: Literal["auto", "tight", "loose"]
+    unrepresentable_policy: Literal["drop", "passthrough", "diagnose"]
+    post_processors: list[str] | None
+    collect_stats: bool
+    title_fallback: list[str] | None
+    pandoc_attributes: bool
+    svg_text_mode: Literal["off", "alt", "caption", "replace"]
+    object_links: bool
+    preserve_heading_ids: bool
+    content_fingerprint: bool
+    math_style: Literal["comment", "dollar", "fenced"]
+    infer_code_language: bool
+    code_block_trim: bool
+    srcset_policy: Literal["first", "largest", "smallest"]
+    figure_style: Literal["italic", "blockquote", "html"]
+    details_style: Literal["markdown", "html"]
+    abbr_style: Literal["expand", "text", "html"]
+    time_style: Literal["text", "datetime", "html"]
+    dfn_style: Literal["emphasis", "text", "html"]
+    url_param_allowlist: list[str]
+    url_param_denylist: list[str] | None
+    contact_link_style: Literal["link", "autolink", "text"]
+    fidelity_threshold: float
+    title_footnotes: bool
+    header_break_style: Literal["inherit", "space", "slash", "html"]
+    report_dropped_content: bool
+    split_documents: bool
+    preserved_attributes: list[str] | None
+    max_output_chars: int | None
+    max_output_tokens: int | None
 
     def __init__(
         self,
@@ -81,7 +133,47 @@ class ConversionOptions:
         encoding: str = "utf-8",
         debug: bool = False,
         strip_tags: list[str] = [],
+        remove_tags: list[str] = [],
         preserve_tags: list[str] = [],
+        remove_space_before_punctuation: bool = False,
+        nbsp_in_prose: Literal["space", "preserve"] = "space",
+        nbsp_in_tables: Literal["space", "preserve"] = "space",
+        front_matter_format: Literal["yaml", "toml", "json"] = "yaml",
+        remove_soft_hyphens: bool = True,
+        bidi_mode: Literal["ignore", "marks", "embedding", "html"] = "ignore",
+        localized_punctuation: bool = False,
+        ordered_markers: str = ".",
+        cycle_bullets: bool = True,
+        list_spacing: Literal["auto", "tight", "loose"] = "auto",
+        unrepresentable_policy: Literal["drop", "passthrough", "diagnose"] = "drop",
+        post_processors: list[str] | None = None,
+        collect_stats: bool = False,
+        title_fallback: list[str] | None = None,
+        pandoc_attributes: bool = False,
+        svg_text_mode: Literal["off", "alt", "caption", "replace"] = "off",
+        object_links: bool = False,
+        preserve_heading_ids: bool = False,
+        content_fingerprint: bool = False,
+        math_style: Literal["comment", "dollar", "fenced"] = "comment",
+        infer_code_language: bool = False,
+        code_block_trim: bool = True,
+        srcset_policy: Literal["first", "largest", "smallest"] = "first",
+        figure_style: Literal["italic", "blockquote", "html"] = "italic",
+        details_style: Literal["markdown", "html"] = "markdown",
+        abbr_style: Literal["expand", "text", "html"] = "expand",
+        time_style: Literal["text", "datetime", "html"] = "text",
+        dfn_style: Literal["emphasis", "text", "html"] = "emphasis",
+        url_param_allowlist: list[str] = [],
+        url_param_denylist: list[str] | None = None,
+        contact_link_style: Literal["link", "autolink", "text"] = "link",
+        fidelity_threshold: float = 1.0,
+        title_footnotes: bool = False,
+        header_break_style: Literal["inherit", "space", "slash", "html"] = "inherit",
+        report_dropped_content: bool = False,
+        split_documents: bool = False,
+        preserved_attributes: list[str] | None = None,
+        max_output_chars: int | None = None,
+        max_output_tokens: int | None = None,
     ) -> None: ...
 
 class InlineImageConfig:
@@ -107,7 +199,7 @@ class InlineImage(TypedDict):
     filename: str | None
     description: str | None
     dimensions: tuple[int, int] | None
-    source: Literal["img_data_uri", "svg_element"]
+    source: Literal["img_data_uri", "svg_element", "img_remote"]
     attributes: dict[str, str]
 
 class InlineImageWarning(TypedDict):
@@ -121,6 +213,8 @@ class MetadataConfig:
     extract_images: bool
     extract_structured_data: bool
     max_structured_data_size: int
+    extract_resources: bool
+    extract_source_map: bool
 
     def __init__(
         self,
@@ -131,13 +225,21 @@ class MetadataConfig:
         extract_images: bool = True,
         extract_structured_data: bool = True,
         max_structured_data_size: int = 1_000_000,
+        extract_resources: bool = False,
+        extract_source_map: bool = False,
     ) -> None: ...
 
+class AuthorProfile(TypedDict):
+    url: str
+    name: str | None
+    source: Literal["rel_author", "rel_me", "schema_author", "schema_same_as"]
+
 class DocumentMetadata(TypedDict):
     title: str | None
     description: str | None
     keywords: list[str]
     author: str | None
+    author_profiles: list[AuthorProfile]
     canonical_url: str | None
     base_href: str | None
     language: str | None
@@ -174,15 +276,48 @@ class StructuredData(TypedDict):
     raw_json: str
     schema_type: str | None
 
+class ResourceMetadata(TypedDict):
+    url: str
+    resource_type: Literal["script", "stylesheet", "font", "image", "media", "embed", "other"]
+
+class SourceMapEntry(TypedDict):
+    tag: str
+    html_start: int
+    html_end: int
+    markdown_start: int
+    markdown_end: int
+
+class ConversionStats(TypedDict):
+    input_bytes: int
+    output_bytes: int
+    nodes_parsed: int
+    peak_memory_estimate: int
+    preprocess_ms: float
+    parse_ms: float
+    convert_ms: float
+    postprocess_ms: float
+    total_ms: float
+
 class ExtendedMetadata(TypedDict):
     document: DocumentMetadata
     headers: list[HeaderMetadata]
     links: list[LinkMetadata]
     images: list[ImageMetadata]
     structured_data: list[StructuredData]
+    resources: list[ResourceMetadata]
+    source_map: list[SourceMapEntry]
 
 def convert(html: str, options: ConversionOptions | None = None) -> str: ...
 def convert_json(html: str, options_json: str | None = None) -> str: ...
+def convert_with_stats_json(html: str, options_json: str | None = None) -> tuple[str, ConversionStats | None]: ...
+def convert_with_fingerprint_json(html: str, options_json: str | None = None) -> tuple[str, str | None]: ...
+def detect_input_format(html: str) -> InputFormat: ...
+def convert_batch(
+    htmls: list[str], options: ConversionOptions | None = None, max_workers: int | None = None
+) -> list[str]: ...
+def convert_batch_json(
+    htmls: list[str], options_json: str | None = None, max_workers: int | None = None
+) -> list[str]: ...
 def convert_with_inline_images(
     html: str,
     options: ConversionOptions | None = None,
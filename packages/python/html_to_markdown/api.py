@@ -3,7 +3,7 @@
 from __future__ import annotations
 
 import json
-from dataclasses import asdict
+from dataclasses import asdict, replace
 from typing import TYPE_CHECKING, Literal, TypedDict, cast
 
 import html_to_markdown._html_to_markdown as _rust
@@ -17,12 +17,15 @@ from html_to_markdown._html_to_markdown import (
 from html_to_markdown.options import ConversionOptions, PreprocessingOptions
 
 if TYPE_CHECKING:
-    from collections.abc import Mapping
+    from collections.abc import Mapping, Sequence
 
-    from html_to_markdown._html_to_markdown import ExtendedMetadata  # pragma: no cover
+    from html_to_markdown._html_to_markdown import ConversionStats, ExtendedMetadata  # pragma: no cover
 else:
+    ConversionStats = dict[str, object]  # type: ignore[assignment]
     ExtendedMetadata = dict[str, object]  # type: ignore[assignment]
 
+InputFormat = Literal["html", "hocr", "alto", "mhtml", "warc", "xml", "text"]
+
 
 class InlineImage(TypedDict):
     """Inline image extracted during conversion."""
@@ -32,7 +35,7 @@ class InlineImage(TypedDict):
     filename: str | None
     description: str | None
     dimensions: tuple[int, int] | None
-    source: Literal["img_data_uri", "svg_element"]
+    source: Literal["img_data_uri", "svg_element", "img_remote"]
     attributes: dict[str, str]
 
 
@@ -43,6 +46,17 @@ class InlineImageWarning(TypedDict):
     message: str
 
 
+def _with_overrides(options: ConversionOptions | None, overrides: Mapping[str, object]) -> ConversionOptions | None:
+    """Apply keyword overrides such as ``heading_style="atx"`` on top of ``options``.
+
+    Raises:
+        TypeError: If a keyword is not a ``ConversionOptions`` field.
+    """
+    if not overrides:
+        return options
+    return replace(options if options is not None else ConversionOptions(), **overrides)
+
+
 def _to_camel_case(name: str) -> str:
     parts = name.split("_")
     return parts[0] + "".join(part.capitalize() for part in parts[1:])
@@ -95,6 +109,8 @@ def _metadata_config_payload(config: MetadataConfig | dict[str, object]) -> dict
         "extract_images": config.extract_images,
         "extract_structured_data": config.extract_structured_data,
         "max_structured_data_size": config.max_structured_data_size,
+        "extract_resources": config.extract_resources,
+        "extract_source_map": config.extract_source_map,
     }
     return _normalize_payload(payload)
 
@@ -103,8 +119,15 @@ def convert(
     html: str,
     options: ConversionOptions | None = None,
     preprocessing: PreprocessingOptions | None = None,
+    **option_overrides: object,
 ) -> str:
-    """Convert HTML to Markdown using the Rust backend."""
+    """Convert HTML to Markdown using the Rust backend.
+
+    Keyword arguments override the matching ``ConversionOptions`` fields, so
+    ``convert(html, heading_style="atx")`` is shorthand for
+    ``convert(html, ConversionOptions(heading_style="atx"))``.
+    """
+    options = _with_overrides(options, option_overrides)
     if options is None and preprocessing is None:
         return _rust.convert(html, None)
 
@@ -117,13 +140,100 @@ def convert(
     return _rust.convert_json(html, json.dumps(payload))
 
 
+def convert_with_stats(
+    html: str,
+    options: ConversionOptions | None = None,
+    preprocessing: PreprocessingOptions | None = None,
+) -> tuple[str, ConversionStats | None]:
+    """Convert HTML and report resource usage.
+
+    The stats are only collected when ``options.collect_stats`` is set; otherwise the second
+    element is ``None``. Timings are in milliseconds, sizes in bytes.
+    """
+    if options is None:
+        options = ConversionOptions()
+    if preprocessing is None:
+        preprocessing = PreprocessingOptions()
+
+    payload = _options_payload(options, preprocessing)
+    return _rust.convert_with_stats_json(html, json.dumps(payload))
+
+
+def convert_with_fingerprint(
+    html: str,
+    options: ConversionOptions | None = None,
+    preprocessing: PreprocessingOptions | None = None,
+) -> tuple[str, str | None]:
+    """Convert HTML and fingerprint the result for deduplication.
+
+    The fingerprint is only computed when ``options.content_fingerprint`` is set; otherwise the
+    second element is ``None``. It is a 32 hex digit hash of the output's text that ignores
+    whitespace, markup and link targets.
+    """
+    if options is None:
+        options = ConversionOptions()
+    if preprocessing is None:
+        preprocessing = PreprocessingOptions()
+
+    payload = _options_payload(options, preprocessing)
+    return _rust.convert_with_fingerprint_json(html, json.dumps(payload))
+
+
+def detect_input_format(html: str) -> InputFormat:
+    """Classify HTML input before converting it.
+
+    Returns one of ``"html"``, ``"hocr"``, ``"alto"``, ``"mhtml"``, ``"warc"``, ``"xml"`` or
+    ``"text"``, using the same heuristics the converter applies to pick its hOCR pipeline.
+    """
+    return _rust.detect_input_format(html)
+
+
+def convert_batch(
+    htmls: Sequence[str],
+    options: ConversionOptions | None = None,
+    preprocessing: PreprocessingOptions | None = None,
+    *,
+    max_workers: int | None = None,
+    **option_overrides: object,
+) -> list[str]:
+    """Convert several HTML documents with shared options in a single native call.
+
+    The GIL is released for the whole batch, and the documents are spread over
+    ``max_workers`` native threads (default: one per CPU). Results keep the input order.
+    Keyword arguments override ``ConversionOptions`` fields, as in ``convert``.
+
+    Raises:
+        ValueError: If a document fails to convert (the message names its index),
+            or if ``max_workers`` is less than 1.
+    """
+    if max_workers is not None and max_workers < 1:
+        raise ValueError("max_workers must be greater than 0")
+
+    options = _with_overrides(options, option_overrides)
+    if options is None and preprocessing is None:
+        return _rust.convert_batch(list(htmls), None, max_workers)
+
+    if options is None:
+        options = ConversionOptions()
+    if preprocessing is None:
+        preprocessing = PreprocessingOptions()
+
+    payload = _options_payload(options, preprocessing)
+    return _rust.convert_batch_json(list(htmls), json.dumps(payload), max_workers)
+
+
 def convert_with_inline_images(
     html: str,
     options: ConversionOptions | None = None,
     preprocessing: PreprocessingOptions | None = None,
     image_config: InlineImageConfig | None = None,
+    **option_overrides: object,
 ) -> tuple[str, list[InlineImage], list[InlineImageWarning]]:
-    """Convert HTML and extract inline images."""
+    """Convert HTML and extract inline images.
+
+    Keyword arguments override ``ConversionOptions`` fields, as in ``convert``.
+    """
+    options = _with_overrides(options, option_overrides)
     if options is None:
         options = ConversionOptions()
     if preprocessing is None:
@@ -157,8 +267,13 @@ def convert_with_inline_images_handle(
 def create_options_handle(
     options: ConversionOptions | None = None,
     preprocessing: PreprocessingOptions | None = None,
+    **option_overrides: object,
 ) -> OptionsHandle:
-    """Create a reusable ConversionOptions handle backed by Rust."""
+    """Create a reusable ConversionOptions handle backed by Rust.
+
+    Keyword arguments override ``ConversionOptions`` fields, as in ``convert``.
+    """
+    options = _with_overrides(options, option_overrides)
     if options is None:
         options = ConversionOptions()
     if preprocessing is None:
@@ -187,6 +302,7 @@ def convert_with_metadata(
     options: ConversionOptions | None = None,
     preprocessing: PreprocessingOptions | None = None,
     metadata_config: MetadataConfig | None = None,
+    **option_overrides: object,
 ) -> tuple[str, ExtendedMetadata]:
     """Convert HTML and extract comprehensive metadata.
 
@@ -195,6 +311,7 @@ def convert_with_metadata(
         options: Optional conversion configuration
         preprocessing: Optional preprocessing configuration
         metadata_config: Optional metadata extraction configuration
+        **option_overrides: ``ConversionOptions`` fields to override, as in ``convert``
 
     Returns:
         Tuple of (markdown, metadata_dict) where metadata_dict contains:
@@ -203,7 +320,10 @@ def convert_with_metadata(
         - links: List of extracted hyperlinks with classification
         - images: List of extracted images with metadata
         - structured_data: List of JSON-LD, Microdata, or RDFa blocks
+        - resources: External script, stylesheet, font and media URLs (with extract_resources)
+        - source_map: HTML and Markdown byte ranges of each block (with extract_source_map)
     """
+    options = _with_overrides(options, option_overrides)
     if options is None:
         options = ConversionOptions()
     if preprocessing is None:
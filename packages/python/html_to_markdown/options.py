@@ -95,6 +95,9 @@ class ConversionOptions:
     strip_tags: set[str] | None = None
     """HTML tags to strip from output (output only text content, no markdown conversion)."""
 
+    remove_tags: set[str] | None = None
+    """HTML tags to remove from output together with all of their content."""
+
     preserve_tags: set[str] | None = None
     """HTML tags to preserve as-is in the output (keep original HTML). Useful for complex elements like tables."""
 
@@ -113,6 +116,123 @@ class ConversionOptions:
     code_block_style: Literal["indented", "backticks", "tildes"] = "backticks"
     """Style for code blocks: 'backticks' (```, better whitespace preservation), 'indented' (4 spaces), or 'tildes' (~~~). All are CommonMark compliant."""
 
+    remove_space_before_punctuation: bool = False
+    """Remove whitespace left between an inline element and following punctuation."""
+
+    nbsp_in_prose: Literal["space", "preserve"] = "space"
+    """Non-breaking space handling in prose: 'space' converts U+00A0 to a regular space, 'preserve' keeps it."""
+
+    nbsp_in_tables: Literal["space", "preserve"] = "space"
+    """Non-breaking space handling in table cells: 'space' converts U+00A0 to a regular space, 'preserve' keeps it."""
+
+    front_matter_format: Literal["yaml", "toml", "json"] = "yaml"
+    """Front matter format used for extracted metadata: 'yaml', 'toml', or 'json'."""
+
+    remove_soft_hyphens: bool = True
+    """Remove invisible soft hyphens and word joiners from text."""
+
+    bidi_mode: Literal["ignore", "marks", "embedding", "html"] = "ignore"
+    """Handling of dir='rtl' sections: 'ignore', 'marks', 'embedding', or 'html'."""
+
+    localized_punctuation: bool = False
+    """Use language-appropriate quotes and parentheses for punctuation the converter inserts."""
+
+    ordered_markers: str = "."
+    """Delimiters for ordered list markers per nesting level (``.`` or ``)``), cycled like ``bullets``."""
+
+    cycle_bullets: bool = True
+    """Cycle through ``bullets`` by nesting level. Set to False to use the first bullet at every level."""
+
+    list_spacing: Literal["auto", "tight", "loose"] = "auto"
+    """List item spacing: 'auto' follows the source, 'tight' or 'loose' force one style."""
+
+    unrepresentable_policy: Literal["drop", "passthrough", "diagnose"] = "drop"
+    """Content without a Markdown equivalent (bdo, bdi, wbr, soft hyphens): 'drop', 'passthrough', or 'diagnose'."""
+
+    post_processors: list[str] | None = None
+    """Post-processors applied to the Markdown in order: ``compact-blank-lines``, ``normalize-urls``, ``prune-empty-elements``, ``reference-links`` and ``toc``."""
+
+    collect_stats: bool = False
+    """Collect a resource usage report, returned by ``convert_with_stats``."""
+
+    title_fallback: list[str] | None = None
+    """Title sources tried in order when a link has no title or the document has no ``<title>``: ``og-title``, ``first-heading`` and ``url-basename``."""
+
+    pandoc_attributes: bool = False
+    """Emit Pandoc attribute blocks (``{#id .class}``) for headings, code blocks, links and images."""
+
+    svg_text_mode: Literal["off", "alt", "caption", "replace"] = "off"
+    """How inline SVG text is surfaced: 'alt' text, a 'caption' below the image, or 'replace' the image."""
+
+    object_links: bool = False
+    """Link the ``data``/``src`` URL of ``<object>`` and ``<embed>`` elements."""
+
+    preserve_heading_ids: bool = False
+    """Keep heading ids as ``<a id>`` anchors (or ``{#id}`` with ``pandoc_attributes``)."""
+
+    content_fingerprint: bool = False
+    """Compute a content fingerprint, returned by ``convert_with_fingerprint``."""
+
+    math_style: Literal["comment", "dollar", "fenced"] = "comment"
+    """How math is written: 'comment' (MathML comment plus text), 'dollar' ($...$) or 'fenced' (```math blocks)."""
+
+    infer_code_language: bool = False
+    """Infer the fence language of code blocks from highlighter classes, ``data-lang`` or the code."""
+
+    code_block_trim: bool = True
+    """Drop blank lines at the start and end of ``<pre>`` code blocks."""
+
+    srcset_policy: Literal["first", "largest", "smallest"] = "first"
+    """Which srcset candidate replaces a missing img src: 'first', 'largest' or 'smallest'."""
+
+    figure_style: Literal["italic", "blockquote", "html"] = "italic"
+    """How figure captions are written: an 'italic' paragraph, a 'blockquote', or the figure kept as 'html'."""
+
+    details_style: Literal["markdown", "html"] = "markdown"
+    """How <details> sections are written: a bold 'markdown' summary line, or the wrapper kept as 'html'."""
+
+    abbr_style: Literal["expand", "text", "html"] = "expand"
+    """How <abbr> is written: 'expand' appends the title in parentheses, 'text' drops it, 'html' keeps the element."""
+
+    time_style: Literal["text", "datetime", "html"] = "text"
+    """How <time> is written: 'text' only, 'datetime' appends the datetime attribute, 'html' keeps the element."""
+
+    dfn_style: Literal["emphasis", "text", "html"] = "emphasis"
+    """How <dfn> is written: 'emphasis', plain 'text', or kept as 'html'."""
+
+    url_param_allowlist: list[str] | None = None
+    """Query parameters the ``normalize-urls`` post-processor keeps; when set, all others are dropped. A trailing ``*`` matches any suffix."""
+
+    url_param_denylist: list[str] | None = None
+    """Query parameters the ``normalize-urls`` post-processor drops; defaults to tracking parameters such as ``utm_*`` and ``fbclid``."""
+
+    contact_link_style: Literal["link", "autolink", "text"] = "link"
+    """How mailto: and tel: links are written: as a 'link', an 'autolink' when the text is the address, or plain 'text'."""
+
+    fidelity_threshold: float = 1.0
+    """Keep table, form and MathML subtrees as sanitized HTML when more than this fraction of their structural elements would be lost. 1.0 disables the guard."""
+
+    title_footnotes: bool = False
+    """Turn title attributes on links, abbreviations and spans into footnotes collected at the end of each section."""
+
+    header_break_style: Literal["inherit", "space", "slash", "html"] = "inherit"
+    """What <br> becomes inside headings and <th> cells: 'inherit', 'space', 'slash' (' / ') or 'html'."""
+
+    report_dropped_content: bool = False
+    """Report how much text each removal rule dropped, as diagnostics."""
+
+    split_documents: bool = False
+    """Convert concatenated HTML documents one at a time, separated by thematic breaks."""
+
+    preserved_attributes: list[str] | None = None
+    """Attributes kept on elements written out as HTML (``preserve_tags``, the fidelity guard); when set, all others are dropped. ``style:<property>`` keeps a single CSS declaration."""
+
+    max_output_chars: int | None = None
+    """Cap the output at this many characters, cutting between top-level blocks and ending with an ``<!-- truncated -->`` line."""
+
+    max_output_tokens: int | None = None
+    """Cap the output at this many tokens (estimated as one per four characters), cutting the same way as ``max_output_chars``."""
+
     debug: bool = False
     """Enable debug mode with diagnostic warnings about unhandled elements and hOCR processing."""
 
@@ -134,7 +254,7 @@ class PreprocessingOptions:
     enabled: bool = True
     """Whether to enable HTML preprocessing (enabled by default for robust handling of malformed HTML)."""
 
-    preset: Literal["minimal", "standard", "aggressive"] = "standard"
+    preset: Literal["minimal", "standard", "aggressive", "confluence"] = "standard"
     """Preprocessing aggressiveness level."""
 
     remove_navigation: bool = True
@@ -142,3 +262,18 @@ class PreprocessingOptions:
 
     remove_forms: bool = True
     """Remove form elements during preprocessing."""
+
+    apply_print_styles: bool = False
+    """Drop elements hidden by the print stylesheet."""
+
+    extract_main_content: bool = False
+    """Convert only the main article content."""
+
+    flatten_shadow_dom: bool = False
+    """Replace declarative shadow roots (`<template shadowrootmode>`) with their hosts' composed content."""
+
+    exclude_selectors: list[str] | None = None
+    """CSS selectors whose matching elements are dropped with their content, e.g. ``[".ads", "#sidebar"]``."""
+
+    include_selector: str | None = None
+    """CSS selector for the only elements to convert, e.g. ``"article.main"``."""
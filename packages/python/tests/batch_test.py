@@ -0,0 +1,47 @@
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+
+from html_to_markdown import ConversionOptions, convert, convert_batch
+
+
+def test_convert_batch_matches_convert() -> None:
+    documents = ["<h1>One</h1>", "<p>Two <em>words</em></p>", ""]
+    options = ConversionOptions(heading_style="atx")
+
+    assert convert_batch(documents, options) == [convert(html, options) for html in documents]
+
+
+def test_convert_batch_without_options() -> None:
+    assert convert_batch(["<p>a</p>", "<p>b</p>"]) == ["a\n", "b\n"]
+
+
+def test_convert_batch_empty() -> None:
+    assert convert_batch([]) == []
+
+
+def test_convert_batch_rejects_non_strings() -> None:
+    with pytest.raises(TypeError):
+        convert_batch([b"<p>bytes</p>"])  # type: ignore[list-item]
+
+
+@pytest.mark.parametrize("max_workers", [1, 2, 3, 64])
+def test_convert_batch_with_max_workers_keeps_order(max_workers: int) -> None:
+    documents = [f"<h2>Part {i}</h2><p>{'x' * i}</p>" for i in range(25)]
+
+    assert convert_batch(documents, max_workers=max_workers) == [convert(html) for html in documents]
+
+
+def test_convert_batch_rejects_zero_workers() -> None:
+    with pytest.raises(ValueError, match="max_workers"):
+        convert_batch(["<p>a</p>"], max_workers=0)
+
+
+def test_convert_batch_runs_alongside_python_threads() -> None:
+    documents = ["<p>" + "word " * 2000 + "</p>"] * 8
+    expected = convert_batch(documents, max_workers=1)
+
+    with ThreadPoolExecutor(max_workers=4) as pool:
+        results = list(pool.map(lambda _: convert_batch(documents, max_workers=2), range(4)))
+
+    assert all(result == expected for result in results)
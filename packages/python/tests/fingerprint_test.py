@@ -0,0 +1,19 @@
+from html_to_markdown import ConversionOptions, convert, convert_with_fingerprint
+
+
+def test_convert_with_fingerprint_ignores_markup() -> None:
+    options = ConversionOptions(content_fingerprint=True)
+    markdown, original = convert_with_fingerprint("<p>Hello <b>world</b></p>", options)
+    _, mirror = convert_with_fingerprint("<div>Hello\n<strong>world</strong></div>", options)
+
+    assert markdown == "Hello **world**\n"
+    assert original is not None
+    assert len(original) == 32
+    assert original == mirror
+
+
+def test_convert_with_fingerprint_is_opt_in() -> None:
+    markdown, fingerprint = convert_with_fingerprint("<p>hi</p>")
+
+    assert markdown == convert("<p>hi</p>")
+    assert fingerprint is None
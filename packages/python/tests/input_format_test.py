@@ -0,0 +1,12 @@
+from html_to_markdown import detect_input_format
+
+
+def test_detect_input_format_recognises_hocr() -> None:
+    hocr = '<div class="ocr_page"><span class="ocrx_word">Hi</span></div>'
+
+    assert detect_input_format(hocr) == "hocr"
+
+
+def test_detect_input_format_defaults_to_html() -> None:
+    assert detect_input_format("<p>Hello</p>") == "html"
+    assert detect_input_format("just words") == "text"
@@ -0,0 +1,55 @@
+import pytest
+
+from html_to_markdown import (
+    ConversionOptions,
+    MetadataConfig,
+    convert,
+    convert_batch,
+    convert_with_handle,
+    convert_with_inline_images,
+    convert_with_metadata,
+    create_options_handle,
+)
+
+
+def test_convert_accepts_option_kwargs() -> None:
+    html = "<h1>Title</h1>"
+
+    assert convert(html, heading_style="underlined") == convert(html, ConversionOptions(heading_style="underlined"))
+
+
+def test_option_kwargs_override_options() -> None:
+    options = ConversionOptions(heading_style="underlined", bullets="*")
+
+    markdown = convert("<h1>Title</h1><ul><li>item</li></ul>", options, heading_style="atx")
+
+    assert markdown.startswith("# Title")
+    assert "* item" in markdown
+    assert options.heading_style == "underlined"
+
+
+def test_option_kwargs_in_batch_and_handles() -> None:
+    documents = ["<h1>One</h1>", "<h1>Two</h1>"]
+
+    assert convert_batch(documents, heading_style="atx_closed") == ["# One #\n", "# Two #\n"]
+
+    handle = create_options_handle(heading_style="atx_closed")
+    assert convert_with_handle("<h1>One</h1>", handle) == "# One #\n"
+
+
+def test_option_kwargs_with_metadata_and_inline_images() -> None:
+    html = "<html><head><title>Doc</title></head><body><h1>Title</h1></body></html>"
+
+    markdown, metadata = convert_with_metadata(html, metadata_config=MetadataConfig(), heading_style="atx_closed")
+    assert "# Title #" in markdown
+    assert metadata["document"]["title"] == "Doc"
+
+    markdown, images, warnings = convert_with_inline_images("<h1>Title</h1>", heading_style="atx_closed")
+    assert markdown.startswith("# Title #")
+    assert images == []
+    assert warnings == []
+
+
+def test_unknown_option_kwarg_raises() -> None:
+    with pytest.raises(TypeError):
+        convert("<p>text</p>", heading_styel="atx")
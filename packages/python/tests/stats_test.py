@@ -0,0 +1,21 @@
+from html_to_markdown import ConversionOptions, convert, convert_with_stats
+
+
+def test_convert_with_stats_reports_usage() -> None:
+    html = "<h1>Title</h1><p>Some <strong>text</strong></p>"
+    markdown, stats = convert_with_stats(html, ConversionOptions(collect_stats=True))
+
+    assert markdown == convert(html)
+    assert stats is not None
+    assert stats["input_bytes"] == len(html)
+    assert stats["output_bytes"] == len(markdown.encode())
+    assert stats["nodes_parsed"] > 0
+    assert stats["peak_memory_estimate"] >= len(html)
+    assert stats["total_ms"] >= stats["parse_ms"] >= 0
+
+
+def test_convert_with_stats_is_opt_in() -> None:
+    markdown, stats = convert_with_stats("<p>hi</p>")
+
+    assert markdown == "hi\n"
+    assert stats is None
@@ -40,6 +40,24 @@ class TestDocumentMetadataExtraction:
         _markdown, metadata = convert_with_metadata(html)
         assert metadata["document"]["language"] is None
 
+    def test_author_profiles(self) -> None:
+        """Test collection of rel=author/rel=me links and schema.org sameAs URLs."""
+        html = """
+        <html>
+        <head>
+            <script type="application/ld+json">
+                {"@type": "Person", "name": "Jane", "sameAs": ["https://github.com/jane"]}
+            </script>
+        </head>
+        <body><a rel="me" href="https://mastodon.social/@jane">Mastodon</a></body>
+        </html>
+        """
+        _markdown, metadata = convert_with_metadata(html)
+        assert metadata["document"]["author_profiles"] == [
+            {"url": "https://github.com/jane", "name": "Jane", "source": "schema_same_as"},
+            {"url": "https://mastodon.social/@jane", "name": "Mastodon", "source": "rel_me"},
+        ]
+
     def test_text_direction_ltr(self) -> None:
         """Test extraction of left-to-right text direction."""
         html = """
@@ -501,6 +519,27 @@ class TestStructuredDataDetection:
         assert "structured_data" in metadata
         assert isinstance(metadata["structured_data"], list)
 
+    def test_resources_are_opt_in(self) -> None:
+        """Test that external resource URLs are only collected with extract_resources."""
+        html = """
+        <html>
+        <head>
+            <link rel="stylesheet" href="/site.css">
+            <script src="/app.js"></script>
+        </head>
+        <body><video src="/clip.mp4"></video></body>
+        </html>
+        """
+        _markdown, metadata = convert_with_metadata(html)
+        assert metadata["resources"] == []
+
+        _markdown, metadata = convert_with_metadata(html, metadata_config=MetadataConfig(extract_resources=True))
+        assert metadata["resources"] == [
+            {"url": "/site.css", "resource_type": "stylesheet"},
+            {"url": "/app.js", "resource_type": "script"},
+            {"url": "/clip.mp4", "resource_type": "media"},
+        ]
+
 
 class TestMetadataConfigFeatureFlags:
     """Test that MetadataConfig controls what gets extracted."""
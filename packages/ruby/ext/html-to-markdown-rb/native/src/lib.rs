@@ -1,9 +1,15 @@
 #![allow(clippy::all, clippy::pedantic, clippy::nursery, missing_docs)]
 use html_to_markdown_rs::{
-    CodeBlockStyle, ConversionOptions, ConversionOptionsUpdate, DEFAULT_INLINE_IMAGE_LIMIT, HeadingStyle,
+    AbbrStyle, BidiMode, CodeBlockStyle, ContactLinkStyle, ConversionOptions, ConversionOptionsUpdate,
+    DEFAULT_INLINE_IMAGE_LIMIT, DetailsStyle, DfnStyle, FigureStyle, FrontMatterFormat, HeaderBreakStyle, HeadingStyle,
     HighlightStyle, HtmlExtraction, InlineImage, InlineImageConfig, InlineImageConfigUpdate, InlineImageWarning,
-    ListIndentType, NewlineStyle, PreprocessingOptionsUpdate, PreprocessingPreset, WhitespaceMode,
-    convert as convert_inner, convert_with_inline_images as convert_with_inline_images_inner, error::ConversionError,
+    ListIndentType, ListSpacing, MathStyle, NbspMode, NewlineStyle, PreprocessingOptionsUpdate, PreprocessingPreset,
+    SrcsetPolicy, SvgTextMode, TimeStyle, UnrepresentablePolicy, WhitespaceMode, conversion_options_from_json,
+    convert as convert_inner, convert_with_inline_images as convert_with_inline_images_inner,
+    encoding::decode_html,
+    error::ConversionError,
+    fetch::{FetchConfig, Fetcher},
+    inline_image_config_from_json, option_char,
     safety::guard_panic,
 };
 
@@ -14,18 +20,19 @@ use html_to_markdown_rs::{
 };
 
 #[cfg(feature = "metadata")]
-use html_to_markdown_rs::convert_with_metadata as convert_with_metadata_inner;
+use html_to_markdown_rs::{convert_with_metadata as convert_with_metadata_inner, metadata_config_from_json};
 mod profiling;
 #[cfg(feature = "metadata")]
 use html_to_markdown_rs::metadata::{
-    DocumentMetadata as RustDocumentMetadata, ExtendedMetadata as RustExtendedMetadata,
-    HeaderMetadata as RustHeaderMetadata, ImageMetadata as RustImageMetadata, LinkMetadata as RustLinkMetadata,
-    MetadataConfig as RustMetadataConfig, StructuredData as RustStructuredData, TextDirection as RustTextDirection,
+    AuthorProfile as RustAuthorProfile, DocumentMetadata as RustDocumentMetadata,
+    ExtendedMetadata as RustExtendedMetadata, HeaderMetadata as RustHeaderMetadata, ImageMetadata as RustImageMetadata,
+    LinkMetadata as RustLinkMetadata, MetadataConfig as RustMetadataConfig, ResourceMetadata as RustResourceMetadata,
+    SourceMapEntry as RustSourceMapEntry, StructuredData as RustStructuredData, TextDirection as RustTextDirection,
 };
 use magnus::prelude::*;
 use magnus::r_hash::ForEach;
 use magnus::value::ReprValue;
-use magnus::{Error, RArray, RHash, Ruby, Symbol, TryConvert, Value, function, scan_args::scan_args};
+use magnus::{Error, RArray, RHash, RString, Ruby, Symbol, TryConvert, Value, function, scan_args::scan_args};
 #[cfg(feature = "visitor")]
 use std::panic::AssertUnwindSafe;
 #[cfg(feature = "profiling")]
@@ -63,6 +70,11 @@ impl RubyVisitorWrapper {
     fn call_visitor_method(&self, method_name: &str, args: &[Value]) -> Result<VisitResult, Error> {
         let ruby = Ruby::get().expect("Ruby not initialized");
 
+        // Visitors only need to define the callbacks they care about.
+        if !self.ruby_visitor.respond_to(method_name, true)? {
+            return Ok(VisitResult::Continue);
+        }
+
         let result: Value = match args.len() {
             0 => match self.ruby_visitor.funcall::<&str, (), Value>(method_name, ()) {
                 Ok(val) => val,
@@ -119,8 +131,18 @@ impl RubyVisitorWrapper {
             }
         };
 
-        let hash = RHash::from_value(result)
-            .ok_or_else(|| arg_error(format!("visitor method {method_name} must return a Hash")))?;
+        if result.is_nil() {
+            return Ok(VisitResult::Continue);
+        }
+        if let Some(output) = RString::from_value(result) {
+            return Ok(VisitResult::Custom(output.to_string()?));
+        }
+
+        let hash = RHash::from_value(result).ok_or_else(|| {
+            arg_error(format!(
+                "visitor method {method_name} must return a Hash, a String or nil"
+            ))
+        })?;
 
         let type_value: Value = hash
             .get(ruby.intern("type"))
@@ -928,16 +950,36 @@ fn conversion_error(err: ConversionError) -> Error {
     }
 }
 
+fn batch_conversion_error(index: usize, err: ConversionError) -> Error {
+    match err {
+        ConversionError::ConfigError(msg) => arg_error(format!("document {index}: {msg}")),
+        ConversionError::Panic(message) => runtime_error(format!(
+            "document {index}: html-to-markdown panic during conversion: {message}"
+        )),
+        other => runtime_error(format!("document {index}: {other}")),
+    }
+}
+
 fn arg_error(message: impl Into<String>) -> Error {
     let ruby = Ruby::get().expect("Ruby not initialised");
     Error::new(ruby.exception_arg_error(), message.into())
 }
 
+fn io_error(ruby: &Ruby, message: impl Into<String>) -> Error {
+    Error::new(ruby.exception_io_error(), message.into())
+}
+
 fn runtime_error(message: impl Into<String>) -> Error {
     let ruby = Ruby::get().expect("Ruby not initialised");
     Error::new(ruby.exception_runtime_error(), message.into())
 }
 
+/// Parse a configuration given as camelCase JSON, the format shared with the other bindings.
+fn parse_json_config<T>(json: RString, parse: fn(&str) -> html_to_markdown_rs::Result<T>) -> Result<T, Error> {
+    let json = json.to_string()?;
+    parse(&json).map_err(conversion_error)
+}
+
 fn symbol_to_string(value: Value) -> Result<String, Error> {
     if let Some(symbol) = Symbol::from_value(value) {
         Ok(symbol.name()?.to_string())
@@ -973,6 +1015,134 @@ fn parse_highlight_style(value: Value) -> Result<HighlightStyle, Error> {
     }
 }
 
+fn parse_front_matter_format(value: Value) -> Result<FrontMatterFormat, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "yaml" => Ok(FrontMatterFormat::Yaml),
+        "toml" => Ok(FrontMatterFormat::Toml),
+        "json" => Ok(FrontMatterFormat::Json),
+        other => Err(arg_error(format!("invalid front_matter_format: {other}"))),
+    }
+}
+
+fn parse_bidi_mode(value: Value) -> Result<BidiMode, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "ignore" => Ok(BidiMode::Ignore),
+        "marks" => Ok(BidiMode::Marks),
+        "embedding" => Ok(BidiMode::Embedding),
+        "html" => Ok(BidiMode::Html),
+        other => Err(arg_error(format!("invalid bidi_mode: {other}"))),
+    }
+}
+
+fn parse_list_spacing(value: Value) -> Result<ListSpacing, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "auto" => Ok(ListSpacing::Auto),
+        "tight" => Ok(ListSpacing::Tight),
+        "loose" => Ok(ListSpacing::Loose),
+        other => Err(arg_error(format!("invalid list_spacing: {other}"))),
+    }
+}
+
+fn parse_unrepresentable_policy(value: Value) -> Result<UnrepresentablePolicy, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "drop" => Ok(UnrepresentablePolicy::Drop),
+        "passthrough" => Ok(UnrepresentablePolicy::Passthrough),
+        "diagnose" => Ok(UnrepresentablePolicy::Diagnose),
+        other => Err(arg_error(format!("invalid unrepresentable_policy: {other}"))),
+    }
+}
+
+fn parse_svg_text_mode(value: Value) -> Result<SvgTextMode, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "off" => Ok(SvgTextMode::Off),
+        "alt" => Ok(SvgTextMode::Alt),
+        "caption" => Ok(SvgTextMode::Caption),
+        "replace" => Ok(SvgTextMode::Replace),
+        other => Err(arg_error(format!("invalid svg_text_mode: {other}"))),
+    }
+}
+
+fn parse_math_style(value: Value) -> Result<MathStyle, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "comment" => Ok(MathStyle::Comment),
+        "dollar" => Ok(MathStyle::Dollar),
+        "fenced" => Ok(MathStyle::Fenced),
+        other => Err(arg_error(format!("invalid math_style: {other}"))),
+    }
+}
+
+fn parse_srcset_policy(value: Value) -> Result<SrcsetPolicy, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "first" => Ok(SrcsetPolicy::First),
+        "largest" => Ok(SrcsetPolicy::Largest),
+        "smallest" => Ok(SrcsetPolicy::Smallest),
+        other => Err(arg_error(format!("invalid srcset_policy: {other}"))),
+    }
+}
+
+fn parse_figure_style(value: Value) -> Result<FigureStyle, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "italic" => Ok(FigureStyle::Italic),
+        "blockquote" => Ok(FigureStyle::Blockquote),
+        "html" => Ok(FigureStyle::Html),
+        other => Err(arg_error(format!("invalid figure_style: {other}"))),
+    }
+}
+
+fn parse_details_style(value: Value) -> Result<DetailsStyle, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "markdown" => Ok(DetailsStyle::Markdown),
+        "html" => Ok(DetailsStyle::Html),
+        other => Err(arg_error(format!("invalid details_style: {other}"))),
+    }
+}
+
+fn parse_abbr_style(value: Value) -> Result<AbbrStyle, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "expand" => Ok(AbbrStyle::Expand),
+        "text" => Ok(AbbrStyle::Text),
+        "html" => Ok(AbbrStyle::Html),
+        other => Err(arg_error(format!("invalid abbr_style: {other}"))),
+    }
+}
+
+fn parse_time_style(value: Value) -> Result<TimeStyle, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "text" => Ok(TimeStyle::Text),
+        "datetime" => Ok(TimeStyle::Datetime),
+        "html" => Ok(TimeStyle::Html),
+        other => Err(arg_error(format!("invalid time_style: {other}"))),
+    }
+}
+
+fn parse_dfn_style(value: Value) -> Result<DfnStyle, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "emphasis" => Ok(DfnStyle::Emphasis),
+        "text" => Ok(DfnStyle::Text),
+        "html" => Ok(DfnStyle::Html),
+        other => Err(arg_error(format!("invalid dfn_style: {other}"))),
+    }
+}
+
+fn parse_contact_link_style(value: Value) -> Result<ContactLinkStyle, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "link" => Ok(ContactLinkStyle::Link),
+        "autolink" => Ok(ContactLinkStyle::Autolink),
+        "text" => Ok(ContactLinkStyle::Text),
+        other => Err(arg_error(format!("invalid contact_link_style: {other}"))),
+    }
+}
+
+fn parse_header_break_style(value: Value) -> Result<HeaderBreakStyle, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "inherit" => Ok(HeaderBreakStyle::Inherit),
+        "space" => Ok(HeaderBreakStyle::Space),
+        "slash" => Ok(HeaderBreakStyle::Slash),
+        "html" => Ok(HeaderBreakStyle::Html),
+        other => Err(arg_error(format!("invalid header_break_style: {other}"))),
+    }
+}
+
 fn parse_whitespace_mode(value: Value) -> Result<WhitespaceMode, Error> {
     match symbol_to_string(value)?.as_str() {
         "normalized" => Ok(WhitespaceMode::Normalized),
@@ -981,6 +1151,14 @@ fn parse_whitespace_mode(value: Value) -> Result<WhitespaceMode, Error> {
     }
 }
 
+fn parse_nbsp_mode(value: Value) -> Result<NbspMode, Error> {
+    match symbol_to_string(value)?.as_str() {
+        "space" => Ok(NbspMode::Space),
+        "preserve" => Ok(NbspMode::Preserve),
+        other => Err(arg_error(format!("invalid nbsp mode: {other}"))),
+    }
+}
+
 fn parse_newline_style(value: Value) -> Result<NewlineStyle, Error> {
     match symbol_to_string(value)?.as_str() {
         "spaces" => Ok(NewlineStyle::Spaces),
@@ -1003,6 +1181,7 @@ fn parse_preset(value: Value) -> Result<PreprocessingPreset, Error> {
         "minimal" => Ok(PreprocessingPreset::Minimal),
         "standard" => Ok(PreprocessingPreset::Standard),
         "aggressive" => Ok(PreprocessingPreset::Aggressive),
+        "confluence" => Ok(PreprocessingPreset::Confluence),
         other => Err(arg_error(format!("invalid preprocessing preset: {other}"))),
     }
 }
@@ -1033,6 +1212,21 @@ fn parse_preprocessing_options(_ruby: &Ruby, value: Value) -> Result<Preprocessi
             "remove_forms" => {
                 update.remove_forms = Some(bool::try_convert(val)?);
             }
+            "apply_print_styles" => {
+                update.apply_print_styles = Some(bool::try_convert(val)?);
+            }
+            "extract_main_content" => {
+                update.extract_main_content = Some(bool::try_convert(val)?);
+            }
+            "flatten_shadow_dom" => {
+                update.flatten_shadow_dom = Some(bool::try_convert(val)?);
+            }
+            "exclude_selectors" => {
+                update.exclude_selectors = Some(parse_vec_of_strings(val)?);
+            }
+            "include_selector" => {
+                update.include_selector = Option::<String>::try_convert(val)?;
+            }
             _ => {}
         }
         Ok(ForEach::Continue)
@@ -1052,11 +1246,24 @@ fn build_conversion_options(ruby: &Ruby, options: Option<Value>) -> Result<Conve
         return Ok(ConversionOptions::default());
     }
 
-    let hash = RHash::from_value(options).ok_or_else(|| arg_error("options must be provided as a Hash"))?;
+    if let Some(json) = RString::from_value(options) {
+        let options = parse_json_config(json, conversion_options_from_json)?;
+        options.validate().map_err(conversion_error)?;
+        return Ok(options);
+    }
+
+    let hash =
+        RHash::from_value(options).ok_or_else(|| arg_error("options must be provided as a Hash or a JSON String"))?;
+
+    let mut strict = false;
+    let mut unknown_keys = Vec::new();
 
     hash.foreach(|key: Value, val: Value| {
         let key_name = symbol_to_string(key)?;
         match key_name.as_str() {
+            "strict_options" => {
+                strict = bool::try_convert(val)?;
+            }
             "heading_style" => {
                 update.heading_style = Some(parse_heading_style(val)?);
             }
@@ -1071,14 +1278,7 @@ fn build_conversion_options(ruby: &Ruby, options: Option<Value>) -> Result<Conve
             }
             "strong_em_symbol" => {
                 let value = String::try_convert(val)?;
-                let mut chars = value.chars();
-                let ch = chars
-                    .next()
-                    .ok_or_else(|| arg_error("strong_em_symbol must not be empty"))?;
-                if chars.next().is_some() {
-                    return Err(arg_error("strong_em_symbol must be a single character"));
-                }
-                update.strong_em_symbol = Some(ch);
+                update.strong_em_symbol = Some(option_char("strong_em_symbol", &value).map_err(conversion_error)?);
             }
             "escape_asterisks" => {
                 update.escape_asterisks = Some(bool::try_convert(val)?);
@@ -1155,15 +1355,146 @@ fn build_conversion_options(ruby: &Ruby, options: Option<Value>) -> Result<Conve
             "strip_tags" => {
                 update.strip_tags = Some(parse_vec_of_strings(val)?);
             }
+            "remove_tags" => {
+                update.remove_tags = Some(parse_vec_of_strings(val)?);
+            }
             "preserve_tags" => {
                 update.preserve_tags = Some(parse_vec_of_strings(val)?);
             }
-            _ => {}
+            "remove_space_before_punctuation" => {
+                update.remove_space_before_punctuation = Some(bool::try_convert(val)?);
+            }
+            "nbsp_in_prose" => {
+                update.nbsp_in_prose = Some(parse_nbsp_mode(val)?);
+            }
+            "nbsp_in_tables" => {
+                update.nbsp_in_tables = Some(parse_nbsp_mode(val)?);
+            }
+            "front_matter_format" => {
+                update.front_matter_format = Some(parse_front_matter_format(val)?);
+            }
+            "remove_soft_hyphens" => {
+                update.remove_soft_hyphens = Some(bool::try_convert(val)?);
+            }
+            "bidi_mode" => {
+                update.bidi_mode = Some(parse_bidi_mode(val)?);
+            }
+            "localized_punctuation" => {
+                update.localized_punctuation = Some(bool::try_convert(val)?);
+            }
+            "ordered_markers" => {
+                update.ordered_markers = Some(String::try_convert(val)?);
+            }
+            "cycle_bullets" => {
+                update.cycle_bullets = Some(bool::try_convert(val)?);
+            }
+            "list_spacing" => {
+                update.list_spacing = Some(parse_list_spacing(val)?);
+            }
+            "unrepresentable_policy" => {
+                update.unrepresentable_policy = Some(parse_unrepresentable_policy(val)?);
+            }
+            "post_processors" => {
+                update.post_processors = Some(parse_vec_of_strings(val)?);
+            }
+            "collect_stats" => {
+                update.collect_stats = Some(bool::try_convert(val)?);
+            }
+            "title_fallback" => {
+                update.title_fallback = Some(parse_vec_of_strings(val)?);
+            }
+            "pandoc_attributes" => {
+                update.pandoc_attributes = Some(bool::try_convert(val)?);
+            }
+            "svg_text_mode" => {
+                update.svg_text_mode = Some(parse_svg_text_mode(val)?);
+            }
+            "object_links" => {
+                update.object_links = Some(bool::try_convert(val)?);
+            }
+            "preserve_heading_ids" => {
+                update.preserve_heading_ids = Some(bool::try_convert(val)?);
+            }
+            "content_fingerprint" => {
+                update.content_fingerprint = Some(bool::try_convert(val)?);
+            }
+            "math_style" => {
+                update.math_style = Some(parse_math_style(val)?);
+            }
+            "infer_code_language" => {
+                update.infer_code_language = Some(bool::try_convert(val)?);
+            }
+            "code_block_trim" => {
+                update.code_block_trim = Some(bool::try_convert(val)?);
+            }
+            "srcset_policy" => {
+                update.srcset_policy = Some(parse_srcset_policy(val)?);
+            }
+            "figure_style" => {
+                update.figure_style = Some(parse_figure_style(val)?);
+            }
+            "details_style" => {
+                update.details_style = Some(parse_details_style(val)?);
+            }
+            "abbr_style" => {
+                update.abbr_style = Some(parse_abbr_style(val)?);
+            }
+            "time_style" => {
+                update.time_style = Some(parse_time_style(val)?);
+            }
+            "dfn_style" => {
+                update.dfn_style = Some(parse_dfn_style(val)?);
+            }
+            "url_param_allowlist" => {
+                update.url_param_allowlist = Some(parse_vec_of_strings(val)?);
+            }
+            "url_param_denylist" => {
+                update.url_param_denylist = Some(parse_vec_of_strings(val)?);
+            }
+            "contact_link_style" => {
+                update.contact_link_style = Some(parse_contact_link_style(val)?);
+            }
+            "fidelity_threshold" => {
+                update.fidelity_threshold = Some(f64::try_convert(val)?);
+            }
+            "title_footnotes" => {
+                update.title_footnotes = Some(bool::try_convert(val)?);
+            }
+            "header_break_style" => {
+                update.header_break_style = Some(parse_header_break_style(val)?);
+            }
+            "report_dropped_content" => {
+                update.report_dropped_content = Some(bool::try_convert(val)?);
+            }
+            "split_documents" => {
+                update.split_documents = Some(bool::try_convert(val)?);
+            }
+            "preserved_attributes" => {
+                update.preserved_attributes = Some(parse_vec_of_strings(val)?);
+            }
+            "max_output_chars" => {
+                update.max_output_chars = Some(usize::try_convert(val)?);
+            }
+            "max_output_tokens" => {
+                update.max_output_tokens = Some(usize::try_convert(val)?);
+            }
+            "skip_images" => {
+                update.skip_images = Some(bool::try_convert(val)?);
+            }
+            _ => unknown_keys.push(key_name),
         }
         Ok(ForEach::Continue)
     })?;
 
-    Ok(ConversionOptions::from(update))
+    if strict {
+        if let Some(key) = unknown_keys.first() {
+            return Err(arg_error(format!("unknown option: {key}")));
+        }
+    }
+
+    let options = ConversionOptions::from(update);
+    options.validate().map_err(conversion_error)?;
+    Ok(options)
 }
 
 fn build_inline_image_config(_ruby: &Ruby, config: Option<Value>) -> Result<InlineImageConfig, Error> {
@@ -1177,7 +1508,12 @@ fn build_inline_image_config(_ruby: &Ruby, config: Option<Value>) -> Result<Inli
         return Ok(InlineImageConfig::new(DEFAULT_INLINE_IMAGE_LIMIT));
     }
 
-    let hash = RHash::from_value(config).ok_or_else(|| arg_error("inline image config must be provided as a Hash"))?;
+    if let Some(json) = RString::from_value(config) {
+        return parse_json_config(json, inline_image_config_from_json);
+    }
+
+    let hash = RHash::from_value(config)
+        .ok_or_else(|| arg_error("inline image config must be provided as a Hash or a JSON String"))?;
 
     hash.foreach(|key: Value, val: Value| {
         let key_name = symbol_to_string(key)?;
@@ -1289,6 +1625,77 @@ fn convert_fn(ruby: &Ruby, args: &[Value]) -> Result<String, Error> {
     guard_panic(|| profiling::maybe_profile(|| convert_inner(&html, Some(options)))).map_err(conversion_error)
 }
 
+fn convert_batch_fn(ruby: &Ruby, args: &[Value]) -> Result<Vec<String>, Error> {
+    let parsed = scan_args::<(Vec<String>,), (Option<Value>,), (), (), (), ()>(args)?;
+    let htmls = parsed.required.0;
+    let options = build_conversion_options(ruby, parsed.optional.0)?;
+
+    html_to_markdown_rs::convert_many(&htmls, Some(options))
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| result.map_err(|err| batch_conversion_error(index, err)))
+        .collect()
+}
+
+fn convert_file_fn(ruby: &Ruby, args: &[Value]) -> Result<String, Error> {
+    let parsed = scan_args::<(String,), (Option<Value>,), (), (), (), ()>(args)?;
+    let path = parsed.required.0;
+    let options = build_conversion_options(ruby, parsed.optional.0)?;
+
+    let bytes = std::fs::read(&path).map_err(|err| io_error(ruby, format!("Failed to read {path}: {err}")))?;
+    let html = decode_html(&bytes, None);
+    guard_panic(|| profiling::maybe_profile(|| convert_inner(&html, Some(options)))).map_err(conversion_error)
+}
+
+fn convert_url_fn(ruby: &Ruby, args: &[Value]) -> Result<String, Error> {
+    let parsed = scan_args::<(String,), (Option<Value>, Option<Value>), (), (), (), ()>(args)?;
+    let url = parsed.required.0;
+    let options = build_conversion_options(ruby, parsed.optional.0)?;
+    let config = build_fetch_config(parsed.optional.1)?;
+
+    let fetch_error = |err| io_error(ruby, format!("Failed to fetch {url}: {err}"));
+    let response = Fetcher::new(config)
+        .map_err(fetch_error)?
+        .fetch(&url)
+        .map_err(fetch_error)?;
+    let html = decode_html(&response.body, response.charset());
+    guard_panic(|| profiling::maybe_profile(|| convert_inner(&html, Some(options)))).map_err(conversion_error)
+}
+
+fn build_fetch_config(config: Option<Value>) -> Result<FetchConfig, Error> {
+    let mut fetch_config = FetchConfig::default();
+
+    let Some(config) = config.filter(|value| !value.is_nil()) else {
+        return Ok(fetch_config);
+    };
+
+    let hash = RHash::from_value(config).ok_or_else(|| arg_error("fetch options must be provided as a Hash"))?;
+
+    hash.foreach(|key: Value, val: Value| {
+        let key_name = symbol_to_string(key)?;
+        match key_name.as_str() {
+            "user_agent" => {
+                fetch_config.user_agent = String::try_convert(val)?;
+            }
+            "timeout" => {
+                let seconds = f64::try_convert(val)?;
+                fetch_config.timeout = std::time::Duration::try_from_secs_f64(seconds)
+                    .map_err(|_| arg_error(format!("Invalid timeout: {seconds}")))?;
+            }
+            "max_retries" => {
+                fetch_config.max_retries = u32::try_convert(val)?;
+            }
+            "max_redirects" => {
+                fetch_config.max_redirects = usize::try_convert(val)?;
+            }
+            _ => {}
+        }
+        Ok(ForEach::Continue)
+    })?;
+
+    Ok(fetch_config)
+}
+
 fn options_handle_fn(ruby: &Ruby, args: &[Value]) -> Result<OptionsHandle, Error> {
     let parsed = scan_args::<(), (Option<Value>,), (), (), (), ()>(args)?;
     let options = build_conversion_options(ruby, parsed.optional.0)?;
@@ -1343,7 +1750,12 @@ fn build_metadata_config(_ruby: &Ruby, config: Option<Value>) -> Result<RustMeta
         return Ok(cfg);
     }
 
-    let hash = RHash::from_value(config).ok_or_else(|| arg_error("metadata_config must be provided as a Hash"))?;
+    if let Some(json) = RString::from_value(config) {
+        return parse_json_config(json, metadata_config_from_json);
+    }
+
+    let hash = RHash::from_value(config)
+        .ok_or_else(|| arg_error("metadata_config must be provided as a Hash or a JSON String"))?;
 
     hash.foreach(|key: Value, val: Value| {
         let key_name = symbol_to_string(key)?;
@@ -1363,6 +1775,12 @@ fn build_metadata_config(_ruby: &Ruby, config: Option<Value>) -> Result<RustMeta
             "extract_structured_data" => {
                 cfg.extract_structured_data = bool::try_convert(val)?;
             }
+            "extract_resources" => {
+                cfg.extract_resources = bool::try_convert(val)?;
+            }
+            "extract_source_map" => {
+                cfg.extract_source_map = bool::try_convert(val)?;
+            }
             "max_structured_data_size" => {
                 cfg.max_structured_data_size = usize::try_convert(val)?;
             }
@@ -1410,6 +1828,10 @@ fn document_metadata_to_ruby(ruby: &Ruby, doc: RustDocumentMetadata) -> Result<V
     hash.aset(ruby.intern("keywords"), keywords)?;
 
     hash.aset(ruby.intern("author"), opt_string_to_ruby(ruby, doc.author)?)?;
+    hash.aset(
+        ruby.intern("author_profiles"),
+        author_profiles_to_ruby(ruby, doc.author_profiles)?,
+    )?;
     hash.aset(
         ruby.intern("canonical_url"),
         opt_string_to_ruby(ruby, doc.canonical_url)?,
@@ -1432,6 +1854,19 @@ fn document_metadata_to_ruby(ruby: &Ruby, doc: RustDocumentMetadata) -> Result<V
     Ok(hash.as_value())
 }
 
+#[cfg(feature = "metadata")]
+fn author_profiles_to_ruby(ruby: &Ruby, profiles: Vec<RustAuthorProfile>) -> Result<Value, Error> {
+    let array = ruby.ary_new();
+    for profile in profiles {
+        let hash = ruby.hash_new();
+        hash.aset(ruby.intern("url"), profile.url)?;
+        hash.aset(ruby.intern("name"), opt_string_to_ruby(ruby, profile.name)?)?;
+        hash.aset(ruby.intern("source"), profile.source.to_string())?;
+        array.push(hash)?;
+    }
+    Ok(array.as_value())
+}
+
 #[cfg(feature = "metadata")]
 fn headers_to_ruby(ruby: &Ruby, headers: Vec<RustHeaderMetadata>) -> Result<Value, Error> {
     let array = ruby.ary_new();
@@ -1513,6 +1948,33 @@ fn structured_data_to_ruby(ruby: &Ruby, data: Vec<RustStructuredData>) -> Result
     Ok(array.as_value())
 }
 
+#[cfg(feature = "metadata")]
+fn resources_to_ruby(ruby: &Ruby, resources: Vec<RustResourceMetadata>) -> Result<Value, Error> {
+    let array = ruby.ary_new();
+    for resource in resources {
+        let hash = ruby.hash_new();
+        hash.aset(ruby.intern("url"), resource.url)?;
+        hash.aset(ruby.intern("resource_type"), resource.resource_type.to_string())?;
+        array.push(hash)?;
+    }
+    Ok(array.as_value())
+}
+
+#[cfg(feature = "metadata")]
+fn source_map_to_ruby(ruby: &Ruby, source_map: Vec<RustSourceMapEntry>) -> Result<Value, Error> {
+    let array = ruby.ary_new();
+    for entry in source_map {
+        let hash = ruby.hash_new();
+        hash.aset(ruby.intern("tag"), entry.tag)?;
+        hash.aset(ruby.intern("html_start"), entry.html_start as i64)?;
+        hash.aset(ruby.intern("html_end"), entry.html_end as i64)?;
+        hash.aset(ruby.intern("markdown_start"), entry.markdown_start as i64)?;
+        hash.aset(ruby.intern("markdown_end"), entry.markdown_end as i64)?;
+        array.push(hash)?;
+    }
+    Ok(array.as_value())
+}
+
 #[cfg(feature = "metadata")]
 fn extended_metadata_to_ruby(ruby: &Ruby, metadata: RustExtendedMetadata) -> Result<Value, Error> {
     let hash = ruby.hash_new();
@@ -1528,6 +1990,11 @@ fn extended_metadata_to_ruby(ruby: &Ruby, metadata: RustExtendedMetadata) -> Res
         ruby.intern("structured_data"),
         structured_data_to_ruby(ruby, metadata.structured_data)?,
     )?;
+    hash.aset(ruby.intern("resources"), resources_to_ruby(ruby, metadata.resources)?)?;
+    hash.aset(
+        ruby.intern("source_map"),
+        source_map_to_ruby(ruby, metadata.source_map)?,
+    )?;
 
     Ok(hash.as_value())
 }
@@ -1631,6 +2098,9 @@ fn stop_profiling_fn(_ruby: &Ruby, _args: &[Value]) -> Result<bool, Error> {
 fn init(ruby: &Ruby) -> Result<(), Error> {
     let module = ruby.define_module("HtmlToMarkdown")?;
     module.define_singleton_method("convert", function!(convert_fn, -1))?;
+    module.define_singleton_method("convert_batch", function!(convert_batch_fn, -1))?;
+    module.define_singleton_method("convert_file", function!(convert_file_fn, -1))?;
+    module.define_singleton_method("convert_url", function!(convert_url_fn, -1))?;
     module.define_singleton_method("options", function!(options_handle_fn, -1))?;
     module.define_singleton_method("convert_with_options", function!(convert_with_options_handle_fn, -1))?;
     module.define_singleton_method(
@@ -6,29 +6,96 @@ require 'html_to_markdown_rb'
 module HtmlToMarkdown
   autoload :CLI, 'html_to_markdown/cli'
   autoload :CLIProxy, 'html_to_markdown/cli_proxy'
+  autoload :BlockVisitor, 'html_to_markdown/block_visitor'
 
   class Options; end # rubocop:disable Lint/EmptyClass
 
   class << self
     alias native_convert convert
+    alias native_convert_batch convert_batch
+    alias native_convert_file convert_file
+    alias native_convert_url convert_url
     alias native_convert_with_inline_images convert_with_inline_images
     alias native_convert_with_inline_images_handle convert_with_inline_images_handle
     alias native_options options
     alias native_convert_with_options convert_with_options
     alias native_convert_with_metadata convert_with_metadata
     alias native_convert_with_metadata_handle convert_with_metadata_handle
+    alias native_convert_with_visitor convert_with_visitor
   end
 
   module_function
 
-  def convert(html, options = nil, visitor = nil)
-    native_convert(html.to_s, options, visitor)
+  def convert(html, options = nil, visitor = nil, &)
+    native_convert(html.to_s, options, BlockVisitor.resolve(visitor, &))
+  end
+
+  # Convert HTML to Markdown, calling a visitor for the elements it handles.
+  #
+  # @param html [String] HTML to convert
+  # @param options [Hash, String, Options, nil] Conversion options
+  # @param visitor [Object, nil] Object defining any of the visit_* callbacks
+  # @yieldparam builder [BlockVisitor] Registers callbacks as blocks instead of a visitor object
+  # @return [String] Markdown
+  # @raise [ArgumentError] if neither or both of a visitor and a block are given
+  #
+  # @example Rewriting links inline
+  #   HtmlToMarkdown.convert_with_visitor(html) do |v|
+  #     v.visit_link { |_ctx, href, text, _title| "[#{text}](#{URI.join(base_url, href)})" }
+  #   end
+  def convert_with_visitor(html, options = nil, visitor = nil, &)
+    visitor = BlockVisitor.resolve(visitor, &)
+    raise ArgumentError, 'a visitor or a block is required' unless visitor
+
+    native_convert_with_visitor(html.to_s, options, visitor)
   end
 
   def convert_with_options(html, options_handle)
     native_convert_with_options(html.to_s, options_handle)
   end
 
+  # Convert several HTML documents with shared options in a single native call.
+  #
+  # @param htmls [Array<String>] HTML documents to convert
+  # @param options [Hash, String, nil] Conversion options applied to every document
+  # @return [Array<String>] Markdown for each document, in input order
+  # @raise [RuntimeError] naming the index of the first document that fails
+  def convert_batch(htmls, options = nil)
+    native_convert_batch(htmls.map(&:to_s), options)
+  end
+
+  # Read an HTML file and convert it to Markdown.
+  #
+  # The file is read natively and its encoding detected from a byte order mark or a
+  # <meta charset> declaration, falling back to UTF-8 or, for bytes that are not valid
+  # UTF-8, Windows-1252. No File.read or force_encoding is needed.
+  #
+  # @param path [String, Pathname] File to convert
+  # @param options [Hash, String, nil] Conversion options
+  # @return [String] Markdown
+  # @raise [IOError] if the file cannot be read
+  def convert_file(path, options = nil)
+    native_convert_file(path.to_s, options)
+  end
+
+  # Download a page and convert it to Markdown.
+  #
+  # Transient failures (timeouts, 429 and 5xx responses) are retried, and the body is decoded
+  # using the charset from the Content-Type header or the page's <meta charset>.
+  #
+  # @param url [String, URI] Page to fetch
+  # @param options [Hash, String, nil] Conversion options
+  # @param fetch_options [Hash, nil] HTTP settings:
+  #   - :user_agent [String] User-Agent header
+  #   - :timeout [Numeric] Per-request timeout in seconds (default: 15)
+  #   - :max_retries [Integer] Retries after a transient failure (default: 2)
+  #   - :max_redirects [Integer] Redirects to follow (default: 5)
+  # @return [String] Markdown
+  # @raise [IOError] if the page cannot be fetched
+  def convert_url(url, options = nil, fetch_options = nil)
+    native_convert_url(url.to_s, options, fetch_options)
+  end
+
   def convert_with_inline_images(html, options = nil, image_config = nil, visitor = nil)
     native_convert_with_inline_images(html.to_s, options, image_config, visitor)
   end
@@ -48,23 +115,28 @@ module HtmlToMarkdown
   # SEO workflows, and document indexing.
   #
   # @param html [String] HTML string to convert. Line endings are normalized (CRLF -> LF).
-  # @param options [ConversionOptions, Hash, nil] Optional conversion configuration.
+  # @param options [ConversionOptions, Hash, String, nil] Optional conversion configuration.
   #   When a Hash, keys should match ConversionOptions field names (as symbols or strings).
+  #   When a String, it is parsed as camelCase JSON, the format shared with the other bindings.
   #   Common options:
   #   - :heading_style [String] "atx", "atx_closed", or "underlined" (default: "underlined")
   #   - :list_indent_type [String] "spaces" or "tabs" (default: "spaces")
   #   - :list_indent_width [Integer] Spaces per indent level (default: 4)
   #   - :wrap [true, false] Enable text wrapping (default: false)
   #   - :wrap_width [Integer] Wrap at this column width (default: 80)
+  #   - :strict_options [true, false] Raise ArgumentError on unknown keys instead of
+  #     ignoring them (default: false)
   #   See ConversionOptions documentation for complete list.
   #
-  # @param metadata_config [Hash, nil] Optional metadata extraction configuration.
-  #   Keys should be symbols or strings. Supported keys:
+  # @param metadata_config [Hash, String, nil] Optional metadata extraction configuration.
+  #   Keys should be symbols or strings; a String is parsed as camelCase JSON. Supported keys:
   #   - :extract_headers [true, false] Extract h1-h6 heading elements (default: true)
   #   - :extract_links [true, false] Extract hyperlinks with type classification (default: true)
   #   - :extract_images [true, false] Extract image elements (default: true)
   #   - :extract_structured_data [true, false] Extract JSON-LD/Microdata/RDFa (default: true)
   #   - :max_structured_data_size [Integer] Size limit for structured data in bytes (default: 1_000_000)
+  #   - :extract_resources [true, false] Collect script/stylesheet/font/media URLs (default: false)
+  #   - :extract_source_map [true, false] Record HTML/Markdown byte ranges of blocks (default: false)
   #
   # @return [Array<String, Hash>] Tuple of [markdown_string, metadata_hash]
   #   markdown_string: String - The converted Markdown output
@@ -75,6 +147,8 @@ module HtmlToMarkdown
   #     - :description [String, nil] From <meta name="description">
   #     - :keywords [Array<String>] From <meta name="keywords">
   #     - :author [String, nil] From <meta name="author">
+  #     - :author_profiles [Array<Hash>] rel="author"/rel="me" links and schema.org author/sameAs URLs
+  #       (:url, :name, :source)
   #     - :language [String, nil] From lang attribute (e.g., "en")
   #     - :text_direction [String, nil] "ltr", "rtl", or "auto"
   #     - :canonical_url [String, nil] From <link rel="canonical">
@@ -111,6 +185,15 @@ module HtmlToMarkdown
   #     - :raw_json [String] Raw JSON content
   #     - :schema_type [String, nil] Schema type (e.g., "Article", "Event")
   #
+  #   - :resources [Array<Hash>] External resources the page loads (with :extract_resources):
+  #     - :url [String] Resource URL as written in the document
+  #     - :resource_type [String] "script", "stylesheet", "font", "image", "media", "embed", or "other"
+  #
+  #   - :source_map [Array<Hash>] Where each block came from (with :extract_source_map):
+  #     - :tag [String] Tag name of the block element
+  #     - :html_start, :html_end [Integer] Byte range of the element in the input HTML
+  #     - :markdown_start, :markdown_end [Integer] Byte range of the block in the Markdown
+  #
   # @raise [StandardError] If conversion fails or invalid configuration
   #
   # @example Basic usage
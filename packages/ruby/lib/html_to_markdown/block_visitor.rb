@@ -0,0 +1,52 @@
+# frozen_string_literal: true
+
+module HtmlToMarkdown
+  # Builds a visitor from blocks, so callbacks can be written inline:
+  #
+  #   HtmlToMarkdown.convert_with_visitor(html) do |v|
+  #     v.visit_link { |_ctx, href, text, _title| "[#{text}](#{absolute_url(href)})" }
+  #   end
+  #
+  # Each visit_* method registers the handler for that callback; callbacks without a
+  # handler keep the default conversion. Handlers receive the same arguments as visitor
+  # object methods and may return a visitor result Hash, a String (used as the Markdown
+  # output) or nil (continue with the default).
+  class BlockVisitor
+    CALLBACKS = %i[
+      visit_element_start visit_element_end visit_text visit_link visit_image
+      visit_heading visit_code_block visit_code_inline visit_list_item visit_list_start
+      visit_list_end visit_table_start visit_table_row visit_table_end visit_blockquote
+      visit_strong visit_emphasis visit_strikethrough visit_underline visit_subscript
+      visit_superscript visit_mark visit_line_break visit_horizontal_rule visit_custom_element
+      visit_definition_list_start visit_definition_term visit_definition_description
+      visit_definition_list_end visit_form visit_input visit_button visit_audio visit_video
+      visit_iframe visit_details visit_summary visit_figure_start visit_figcaption
+      visit_figure_end
+    ].freeze
+
+    # Pick the visitor for a conversion given an optional visitor object and block.
+    def self.resolve(visitor, &definition)
+      return visitor unless definition
+      raise ArgumentError, 'pass either a visitor or a block, not both' if visitor
+
+      builder = new
+      definition.call(builder)
+      builder.visitor
+    end
+
+    attr_reader :visitor
+
+    def initialize
+      @visitor = Object.new
+    end
+
+    CALLBACKS.each do |name|
+      define_method(name) do |&handler|
+        raise ArgumentError, "#{name} requires a block" unless handler
+
+        @visitor.define_singleton_method(name) { |*args| handler.call(*args) }
+        self
+      end
+    end
+  end
+end
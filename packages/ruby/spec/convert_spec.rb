@@ -1,6 +1,8 @@
 # frozen_string_literal: true
 
 require 'spec_helper'
+require 'socket'
+require 'tmpdir'
 
 RSpec.describe HtmlToMarkdown do
   describe '.convert' do
@@ -16,6 +18,55 @@ RSpec.describe HtmlToMarkdown do
       )
       expect(result).to include('Hello')
     end
+
+    it 'accepts options as a camelCase JSON string' do
+      result = described_class.convert('<h1>Hello</h1>', '{"headingStyle":"atxClosed"}')
+      expect(result).to eq("# Hello #\n")
+    end
+
+    it 'rejects malformed options JSON' do
+      expect { described_class.convert('<p>x</p>', '{"headingStyle":') }.to raise_error(ArgumentError)
+    end
+  end
+
+  describe '.convert_batch' do
+    it 'converts every document in order' do
+      result = described_class.convert_batch(['<h1>One</h1>', '<p>Two</p>'], heading_style: :atx)
+      expect(result).to eq(["# One\n", "Two\n"])
+    end
+  end
+
+  describe '.convert_file' do
+    it 'detects the encoding declared in the file' do
+      Dir.mktmpdir do |dir|
+        path = File.join(dir, 'latin1.html')
+        File.binwrite(path, "<meta charset=\"iso-8859-1\"><h1>Caf\xE9</h1>".b)
+        expect(described_class.convert_file(path, heading_style: :atx)).to eq("# Café\n")
+      end
+    end
+
+    it 'raises IOError for a missing file' do
+      expect { described_class.convert_file('/nonexistent/page.html') }.to raise_error(IOError, /Failed to read/)
+    end
+  end
+
+  describe '.convert_url' do
+    it 'fetches and converts a page' do
+      server = TCPServer.new('127.0.0.1', 0)
+      thread = Thread.new do
+        client = server.accept
+        while (line = client.gets) && line != "\r\n"; end
+        body = '<p>Fetched</p>'
+        client.write("HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: #{body.bytesize}\r\n" \
+                     "Connection: close\r\n\r\n#{body}")
+        client.close
+      end
+      url = "http://127.0.0.1:#{server.addr[1]}/"
+      expect(described_class.convert_url(url, nil, max_retries: 0)).to eq("Fetched\n")
+    ensure
+      thread&.join
+      server&.close
+    end
   end
 
   describe '.convert_with_inline_images' do
@@ -34,6 +85,38 @@ RSpec.describe HtmlToMarkdown do
       result = described_class.convert_with_options('<h1>Hello</h1>', handle)
       expect(result).to include('# Hello #')
     end
+
+    it 'rejects invalid options with every violation' do
+      expect { described_class.options(bullets: '', ordered_markers: '-') }.to raise_error(
+        ArgumentError,
+        "bullets cannot be empty; ordered_markers must only contain '.' or ')', got \"-\""
+      )
+    end
+
+    it 'builds a handle from a JSON string' do
+      handle = described_class.options('{"headingStyle":"atx"}')
+      expect(described_class.convert_with_options('<h1>Hello</h1>', handle)).to eq("# Hello\n")
+    end
+
+    it 'validates options given as JSON' do
+      expect { described_class.options('{"bullets":""}') }.to raise_error(ArgumentError, /bullets cannot be empty/)
+    end
+
+    it 'ignores unknown keys by default' do
+      expect(described_class.convert('<h1>Hello</h1>', heading_stlye: :atx)).to include('Hello')
+    end
+
+    it 'rejects unknown keys with strict_options' do
+      expect { described_class.options(heading_stlye: :atx, strict_options: true) }
+        .to raise_error(ArgumentError, /unknown option: heading_stlye/)
+      expect(described_class.convert('<h1>Hello</h1>', heading_style: :atx, strict_options: true))
+        .to eq("# Hello\n")
+    end
+
+    it 'rejects multi-character symbols' do
+      expect { described_class.convert('<p>x</p>', strong_em_symbol: '**') }
+        .to raise_error(ArgumentError, /strong_em_symbol must be a single character/)
+    end
   end
 
   describe 'panic handling' do
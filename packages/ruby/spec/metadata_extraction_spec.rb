@@ -289,6 +289,13 @@ RSpec.describe HtmlToMarkdown do
         expect(metadata[:headers]).to eq([])
       end
 
+      it 'accepts the configuration as a JSON string' do
+        html = '<html><body><h1>Title</h1><p>Content</p></body></html>'
+        _, metadata = described_class.convert_with_metadata(html, '{"headingStyle":"atx"}', '{"extractHeaders":false}')
+
+        expect(metadata[:headers]).to eq([])
+      end
+
       it 'respects extract_links flag' do
         html = '<html><body><a href="https://example.com">Link</a></body></html>'
         config = { extract_links: false }
@@ -1144,6 +1144,74 @@ RSpec.describe HtmlToMarkdown do
         end.not_to raise_error
       end
     end
+
+    context 'partial visitors' do
+      it 'keeps the default conversion for callbacks the visitor does not define' do
+        visitor = Class.new do
+          def visit_link(_ctx, href, text, _title)
+            { type: :custom, output: "<#{href}|#{text}>" }
+          end
+        end.new
+
+        result = described_class.convert_with_visitor('<h1>Title</h1><p><a href="/a">A</a></p>', nil, visitor)
+        expect(result).to include('Title')
+        expect(result).to include('</a|A>')
+      end
+
+      it 'treats String results as custom output and nil as continue' do
+        visitor = Class.new do
+          def visit_strong(_ctx, text) = "**#{text.upcase}**"
+          def visit_emphasis(_ctx, _text) = nil
+        end.new
+
+        result = described_class.convert_with_visitor('<p><strong>bold</strong> <em>it</em></p>', nil, visitor)
+        expect(result).to eq("**BOLD** *it*\n")
+      end
+    end
+
+    context 'block visitors' do
+      it 'registers callbacks from a block' do
+        html = '<p><a href="/docs">Docs</a></p>'
+
+        result = described_class.convert_with_visitor(html) do |v|
+          v.visit_link { |_ctx, href, text, _title| "[#{text}](https://example.com#{href})" }
+        end
+
+        expect(result).to eq("[Docs](https://example.com/docs)\n")
+      end
+
+      it 'passes options alongside the block' do
+        result = described_class.convert_with_visitor('<h1>Title</h1>', { heading_style: :atx }) do |v|
+          v.visit_link { nil }
+        end
+
+        expect(result).to eq("# Title\n")
+      end
+
+      it 'is accepted by convert' do
+        result = described_class.convert('<p><code>x</code></p>') do |v|
+          v.visit_code_inline { |_ctx, code| { type: :custom, output: "`#{code}!`" } }
+        end
+
+        expect(result).to eq("`x!`\n")
+      end
+
+      it 'rejects a visitor and a block together' do
+        expect do
+          described_class.convert_with_visitor('<p>x</p>', nil, Object.new) { |v| v.visit_text { nil } }
+        end.to raise_error(ArgumentError, /not both/)
+      end
+
+      it 'requires a visitor or a block' do
+        expect { described_class.convert_with_visitor('<p>x</p>') }.to raise_error(ArgumentError)
+      end
+
+      it 'rejects unknown callbacks' do
+        expect do
+          described_class.convert_with_visitor('<p>x</p>') { |v| v.visit_paragraph { nil } }
+        end.to raise_error(NoMethodError)
+      end
+    end
   end
 end
 # rubocop:enable RSpec/ContextWording, RSpec/VerifiedDoubles
@@ -531,7 +531,9 @@ describe("html-to-markdown async visitor (TypeScript)", () => {
 
 			const result = await convertWithVisitor(BASIC_HTML, undefined, visitor);
 
-			expect(result).toBeTruthy();
+			expect(typeof result).toBe("string");
+			expect(result).not.toContain("Hello");
+			expect(result).not.toContain("World");
 		});
 	});
 
@@ -181,7 +181,7 @@ describe("html-to-markdown visitor API (TypeScript)", () => {
 			const html = '<a href="/page">Link</a>';
 			const result = await convertWithVisitor(html, undefined, visitor);
 
-			expect(typeof result).toBe("string");
+			expect(result).toContain("[CUSTOM](/page)");
 		});
 
 		it("should support skip result type", async () => {
@@ -195,7 +195,7 @@ describe("html-to-markdown visitor API (TypeScript)", () => {
 			const html = '<img src="test.jpg" alt="Test">';
 			const result = await convertWithVisitor(html, undefined, visitor);
 
-			expect(typeof result).toBe("string");
+			expect(result).not.toContain("test.jpg");
 		});
 
 		it("should support preserve_html result type", async () => {
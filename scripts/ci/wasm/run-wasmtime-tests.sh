@@ -1,4 +1,7 @@
 #!/usr/bin/env bash
 set -euo pipefail
 
+# The WASI command tests build for wasm32-wasip1 and skip when the target is missing.
+rustup target add wasm32-wasip1
+
 cargo test -p html-to-markdown-wasm-wasmtime-tests
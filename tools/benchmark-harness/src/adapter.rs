@@ -11,5 +11,9 @@ pub trait FrameworkAdapter: Send + Sync {
         let _ = scenario;
         true
     }
+    /// Whether `run` honours `BenchmarkConfig::threads`; only such adapters run in scaling mode.
+    fn supports_threads(&self) -> bool {
+        false
+    }
     fn run(&self, fixture: &Fixture, scenario: BenchmarkScenario, config: &BenchmarkConfig) -> Result<BenchmarkResult>;
 }
@@ -0,0 +1,344 @@
+#![allow(unsafe_code)]
+
+use crate::adapter::FrameworkAdapter;
+use crate::adapters::native::calculate_statistics;
+use crate::adapters::script::ensure_ffi_library;
+use crate::config::{BenchmarkConfig, BenchmarkScenario};
+use crate::fixture::{Fixture, FixtureFormat};
+use crate::monitoring::ResourceMonitor;
+use crate::types::{BenchmarkResult, IterationResult, PerformanceMetrics};
+use crate::{Error, Result};
+use html_to_markdown_rs::metadata::DEFAULT_MAX_STRUCTURED_DATA_SIZE;
+use html_to_markdown_rs::{MetadataConfig, convert, convert_with_metadata};
+use libloading::{Library, Symbol};
+use std::ffi::{CStr, CString, c_char};
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::time::{Duration, Instant};
+
+type ConvertFn = unsafe extern "C" fn(*const c_char) -> *mut c_char;
+type ConvertWithLenFn = unsafe extern "C" fn(*const c_char, *mut usize) -> *mut c_char;
+type ConvertBytesWithLenFn = unsafe extern "C" fn(*const u8, usize, *mut usize) -> *mut c_char;
+type ConvertWithMetadataFn = unsafe extern "C" fn(*const c_char, *mut *mut c_char) -> *mut c_char;
+type ConvertWithMetadataBytesWithLenFn =
+    unsafe extern "C" fn(*const u8, usize, *mut *mut c_char, *mut usize, *mut usize) -> *mut c_char;
+type FreeStringFn = unsafe extern "C" fn(*mut c_char);
+type LastErrorFn = unsafe extern "C" fn() -> *const c_char;
+
+/// Benchmarks the C ABI of `html-to-markdown-ffi` by loading the compiled cdylib.
+///
+/// The library is `HTML_TO_MARKDOWN_FFI_LIB` when set, otherwise the release build in
+/// `target/release` (built on demand). Before timing, every entry point the scenario touches is
+/// checked against the Rust crate linked into the harness: the NUL-terminated and
+/// length-reporting variants must agree with each other and with `convert`, and the metadata
+/// JSON must match the serialized `convert_with_metadata` result.
+pub struct FfiAdapter {
+    repo_root: PathBuf,
+}
+
+impl FfiAdapter {
+    pub const fn new(repo_root: PathBuf) -> Self {
+        Self { repo_root }
+    }
+
+    fn library_path(&self) -> Result<PathBuf> {
+        match std::env::var_os("HTML_TO_MARKDOWN_FFI_LIB") {
+            Some(path) => Ok(PathBuf::from(path)),
+            None => ensure_ffi_library(&self.repo_root),
+        }
+    }
+
+    fn read_fixture(&self, fixture: &Fixture) -> Result<String> {
+        let path = fixture.resolved_path(&self.repo_root);
+        let data = std::fs::read(&path).map_err(Error::Io)?;
+        String::from_utf8(data).map_err(|_| Error::Benchmark(format!("Fixture {} is not valid UTF-8", path.display())))
+    }
+}
+
+impl FrameworkAdapter for FfiAdapter {
+    fn name(&self) -> &str {
+        "ffi"
+    }
+
+    fn supports_format(&self, format: FixtureFormat) -> bool {
+        matches!(format, FixtureFormat::Html | FixtureFormat::Hocr)
+    }
+
+    fn supports_scenario(&self, scenario: BenchmarkScenario) -> bool {
+        matches!(
+            scenario,
+            BenchmarkScenario::ConvertDefault | BenchmarkScenario::MetadataDefault | BenchmarkScenario::MetadataRaw
+        )
+    }
+
+    fn run(&self, fixture: &Fixture, scenario: BenchmarkScenario, config: &BenchmarkConfig) -> Result<BenchmarkResult> {
+        let html = self.read_fixture(fixture)?;
+        let library = FfiLibrary::load(&self.library_path()?)?;
+
+        match scenario {
+            BenchmarkScenario::ConvertDefault => library.verify_convert(&html)?,
+            _ => library.verify_metadata(&html)?,
+        }
+
+        let iterations = fixture.iterations.unwrap_or(config.benchmark_iterations as u32).max(1) as usize;
+        for _ in 0..config.warmup_iterations.max(1) {
+            library.run_scenario(&html, scenario)?;
+        }
+
+        let monitor = ResourceMonitor::start(std::process::id(), Duration::from_millis(config.sample_interval_ms));
+
+        let mut iteration_results = Vec::with_capacity(iterations);
+        let start = Instant::now();
+        for iteration in 0..iterations {
+            let iter_start = Instant::now();
+            library.run_scenario(&html, scenario)?;
+            iteration_results.push(IterationResult {
+                iteration,
+                duration: iter_start.elapsed(),
+            });
+        }
+        let total_duration = start.elapsed();
+
+        let resource_stats = monitor.map(|m| m.stop()).unwrap_or_default();
+
+        let file_size = html.len() as u64;
+        let bytes_processed = file_size as f64 * iterations as f64;
+        let duration_secs = total_duration.as_secs_f64().max(0.000_001);
+
+        Ok(BenchmarkResult {
+            framework: config.framework_label(self.name()),
+            scenario: scenario.as_str().to_string(),
+            fixture_id: fixture.id.clone(),
+            fixture_name: fixture.name.clone(),
+            fixture_path: fixture.resolved_path(&self.repo_root),
+            fixture_format: fixture.format.as_str().to_string(),
+            file_extension: fixture.file_extension(),
+            file_size,
+            iterations,
+            duration: total_duration,
+            metrics: PerformanceMetrics {
+                ops_per_sec: iterations as f64 / duration_secs,
+                mb_per_sec: (bytes_processed / (1024.0 * 1024.0)) / duration_secs,
+                throughput_bytes_per_sec: bytes_processed / duration_secs,
+            },
+            resource_stats,
+            memory_stats: None,
+            flamegraph_path: None,
+            statistics: (iteration_results.len() > 1).then(|| calculate_statistics(&iteration_results)),
+            success: true,
+            error_message: None,
+            threads: None,
+            scaling_efficiency: None,
+        })
+    }
+}
+
+/// Entry points resolved from a loaded `html-to-markdown-ffi` library.
+struct FfiLibrary {
+    convert: ConvertFn,
+    convert_with_len: ConvertWithLenFn,
+    convert_bytes_with_len: ConvertBytesWithLenFn,
+    convert_with_metadata: ConvertWithMetadataFn,
+    convert_with_metadata_bytes_with_len: ConvertWithMetadataBytesWithLenFn,
+    free_string: FreeStringFn,
+    last_error: LastErrorFn,
+    // Keeps the function pointers above valid.
+    _library: Library,
+}
+
+impl FfiLibrary {
+    fn load(path: &Path) -> Result<Self> {
+        // SAFETY: the library is html-to-markdown-ffi, whose initializers have no preconditions.
+        let library = unsafe { Library::new(path) }
+            .map_err(|err| Error::Benchmark(format!("Failed to load FFI library {}: {err}", path.display())))?;
+
+        // SAFETY: each symbol is looked up with the signature it is exported with.
+        unsafe {
+            Ok(Self {
+                convert: symbol(&library, b"html_to_markdown_convert\0")?,
+                convert_with_len: symbol(&library, b"html_to_markdown_convert_with_len\0")?,
+                convert_bytes_with_len: symbol(&library, b"html_to_markdown_convert_bytes_with_len\0")?,
+                convert_with_metadata: symbol(&library, b"html_to_markdown_convert_with_metadata\0")?,
+                convert_with_metadata_bytes_with_len: symbol(
+                    &library,
+                    b"html_to_markdown_convert_with_metadata_bytes_with_len\0",
+                )?,
+                free_string: symbol(&library, b"html_to_markdown_free_string\0")?,
+                last_error: symbol(&library, b"html_to_markdown_last_error\0")?,
+                _library: library,
+            })
+        }
+    }
+
+    fn run_scenario(&self, html: &str, scenario: BenchmarkScenario) -> Result<()> {
+        match scenario {
+            BenchmarkScenario::MetadataDefault => {
+                let (_, metadata) = self.metadata_bytes_with_len(html)?;
+                serde_json::from_str::<serde_json::Value>(&metadata)
+                    .map_err(|err| Error::Benchmark(format!("FFI metadata JSON is invalid: {err}")))?;
+            }
+            BenchmarkScenario::MetadataRaw => {
+                self.metadata_bytes_with_len(html)?;
+            }
+            _ => {
+                self.convert_bytes_with_len(html)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn verify_convert(&self, html: &str) -> Result<()> {
+        let expected = convert(html, None).map_err(|err| Error::Benchmark(format!("Conversion failed: {err}")))?;
+        let bytes_with_len = self.convert_bytes_with_len(html)?;
+        check_equal("html_to_markdown_convert_bytes_with_len", &expected, &bytes_with_len)?;
+
+        // The NUL-terminated entry points cannot take input with interior NUL bytes.
+        if let Ok(c_html) = CString::new(html) {
+            // SAFETY: `c_html` is NUL-terminated and outlives the call.
+            let plain = self.take_string(unsafe { (self.convert)(c_html.as_ptr()) }, None)?;
+            check_equal("html_to_markdown_convert", &expected, &plain)?;
+
+            let mut len = 0usize;
+            // SAFETY: as above; `len` is a valid out-pointer.
+            let result = unsafe { (self.convert_with_len)(c_html.as_ptr(), &mut len) };
+            let with_len = self.take_string(result, Some(len))?;
+            check_equal("html_to_markdown_convert_with_len", &expected, &with_len)?;
+        }
+        Ok(())
+    }
+
+    fn verify_metadata(&self, html: &str) -> Result<()> {
+        let (expected_markdown, expected_metadata) = convert_with_metadata(html, None, ffi_metadata_config(), None)
+            .map_err(|err| Error::Benchmark(format!("Metadata conversion failed: {err}")))?;
+        let expected_metadata = serde_json::to_value(&expected_metadata)
+            .map_err(|err| Error::Serialization(format!("Failed to serialize metadata: {err}")))?;
+
+        let mut outputs = vec![(
+            "html_to_markdown_convert_with_metadata_bytes_with_len",
+            self.metadata_bytes_with_len(html)?,
+        )];
+        if let Ok(c_html) = CString::new(html) {
+            let mut metadata_ptr: *mut c_char = ptr::null_mut();
+            // SAFETY: `c_html` is NUL-terminated and `metadata_ptr` is a valid out-pointer.
+            let result = unsafe { (self.convert_with_metadata)(c_html.as_ptr(), &mut metadata_ptr) };
+            let markdown = self.take_string(result, None)?;
+            let metadata = self.take_string(metadata_ptr, None)?;
+            outputs.push(("html_to_markdown_convert_with_metadata", (markdown, metadata)));
+        }
+
+        for (entry_point, (markdown, metadata)) in outputs {
+            check_equal(entry_point, &expected_markdown, &markdown)?;
+            let metadata: serde_json::Value = serde_json::from_str(&metadata)
+                .map_err(|err| Error::Benchmark(format!("{entry_point} returned invalid metadata JSON: {err}")))?;
+            if metadata != expected_metadata {
+                return Err(Error::Benchmark(format!(
+                    "{entry_point} metadata differs from convert_with_metadata"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn convert_bytes_with_len(&self, html: &str) -> Result<String> {
+        let mut len = 0usize;
+        // SAFETY: `html` is valid for `html.len()` bytes and `len` is a valid out-pointer.
+        let result = unsafe { (self.convert_bytes_with_len)(html.as_ptr(), html.len(), &mut len) };
+        self.take_string(result, Some(len))
+    }
+
+    fn metadata_bytes_with_len(&self, html: &str) -> Result<(String, String)> {
+        let mut metadata_ptr: *mut c_char = ptr::null_mut();
+        let mut markdown_len = 0usize;
+        let mut metadata_len = 0usize;
+        // SAFETY: `html` is valid for `html.len()` bytes and all out-pointers are valid.
+        let result = unsafe {
+            (self.convert_with_metadata_bytes_with_len)(
+                html.as_ptr(),
+                html.len(),
+                &mut metadata_ptr,
+                &mut markdown_len,
+                &mut metadata_len,
+            )
+        };
+        let markdown = self.take_string(result, Some(markdown_len))?;
+        let metadata = self.take_string(metadata_ptr, Some(metadata_len))?;
+        Ok((markdown, metadata))
+    }
+
+    /// Copy and free a string returned by the library, checking its reported length.
+    fn take_string(&self, ptr: *mut c_char, reported_len: Option<usize>) -> Result<String> {
+        if ptr.is_null() {
+            return Err(Error::Benchmark(format!("FFI call failed: {}", self.last_error())));
+        }
+        // SAFETY: non-null results are NUL-terminated strings owned by the library until freed.
+        let bytes = unsafe { CStr::from_ptr(ptr) }.to_bytes().to_vec();
+        // SAFETY: `ptr` came from the library and is freed exactly once.
+        unsafe { (self.free_string)(ptr) };
+
+        if let Some(len) = reported_len {
+            if len != bytes.len() {
+                return Err(Error::Benchmark(format!(
+                    "FFI reported length {len} for a {}-byte string",
+                    bytes.len()
+                )));
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| Error::Benchmark("FFI returned invalid UTF-8".to_string()))
+    }
+
+    fn last_error(&self) -> String {
+        // SAFETY: the error pointer is either null or a NUL-terminated thread-local string.
+        let ptr = unsafe { (self.last_error)() };
+        if ptr.is_null() {
+            "unknown error".to_string()
+        } else {
+            // SAFETY: checked non-null above; the string stays valid until the next FFI call.
+            unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned()
+        }
+    }
+}
+
+/// Look up `name` in `library` as a function pointer of type `T`.
+///
+/// # Safety
+///
+/// `T` must match the exported signature, and the pointer must not outlive `library`.
+unsafe fn symbol<T: Copy>(library: &Library, name: &[u8]) -> Result<T> {
+    let symbol: Symbol<'_, T> = unsafe { library.get(name) }.map_err(|err| {
+        Error::Benchmark(format!(
+            "FFI library is missing {}: {err}",
+            String::from_utf8_lossy(&name[..name.len() - 1])
+        ))
+    })?;
+    Ok(*symbol)
+}
+
+/// The metadata configuration the FFI metadata entry points use.
+fn ffi_metadata_config() -> MetadataConfig {
+    MetadataConfig {
+        extract_document: true,
+        extract_headers: true,
+        extract_links: true,
+        extract_images: true,
+        extract_structured_data: true,
+        extract_resources: false,
+        extract_source_map: false,
+        max_structured_data_size: DEFAULT_MAX_STRUCTURED_DATA_SIZE,
+    }
+}
+
+fn check_equal(entry_point: &str, expected: &str, actual: &str) -> Result<()> {
+    if expected == actual {
+        return Ok(());
+    }
+    let offset = expected
+        .bytes()
+        .zip(actual.bytes())
+        .position(|(a, b)| a != b)
+        .unwrap_or_else(|| expected.len().min(actual.len()));
+    Err(Error::Benchmark(format!(
+        "{entry_point} output differs from convert at byte {offset} ({} vs {} bytes)",
+        actual.len(),
+        expected.len()
+    )))
+}
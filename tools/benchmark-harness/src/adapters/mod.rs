@@ -1,5 +1,7 @@
+pub mod ffi;
 pub mod native;
 pub mod script;
 
+pub use ffi::FfiAdapter;
 pub use native::NativeAdapter;
 pub use script::{ScriptAdapter, ScriptLanguage};
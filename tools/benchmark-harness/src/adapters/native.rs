@@ -55,6 +55,24 @@ impl NativeAdapter {
         }
         Ok(())
     }
+
+    fn run_iterations(
+        html: &str,
+        scenario: BenchmarkScenario,
+        options: &Option<ConversionOptions>,
+        iterations: usize,
+    ) -> Result<Vec<IterationResult>> {
+        let mut iteration_results = Vec::with_capacity(iterations);
+        for iteration in 0..iterations {
+            let iter_start = Instant::now();
+            Self::run_scenario(html, scenario, options.clone())?;
+            iteration_results.push(IterationResult {
+                iteration,
+                duration: iter_start.elapsed(),
+            });
+        }
+        Ok(iteration_results)
+    }
 }
 
 impl FrameworkAdapter for NativeAdapter {
@@ -66,6 +84,10 @@ impl FrameworkAdapter for NativeAdapter {
         matches!(format, FixtureFormat::Html | FixtureFormat::Hocr)
     }
 
+    fn supports_threads(&self) -> bool {
+        true
+    }
+
     fn run(&self, fixture: &Fixture, scenario: BenchmarkScenario, config: &BenchmarkConfig) -> Result<BenchmarkResult> {
         let html = self.read_fixture(fixture)?;
         let base_options = Self::build_options(fixture.format);
@@ -96,17 +118,27 @@ impl FrameworkAdapter for NativeAdapter {
 
         let monitor = ResourceMonitor::start(std::process::id(), Duration::from_millis(config.sample_interval_ms));
 
-        let mut iteration_results = Vec::with_capacity(iterations);
+        let threads = config.threads.max(1);
         let start = Instant::now();
-        for iteration in 0..iterations {
-            let iter_start = Instant::now();
-            Self::run_scenario(&html, scenario, options.clone())?;
-            iteration_results.push(IterationResult {
-                iteration,
-                duration: iter_start.elapsed(),
-            });
-        }
+        let iteration_results = if threads == 1 {
+            Self::run_iterations(&html, scenario, &options, iterations)?
+        } else {
+            std::thread::scope(|scope| {
+                let workers = (0..threads)
+                    .map(|_| scope.spawn(|| Self::run_iterations(&html, scenario, &options, iterations)))
+                    .collect::<Vec<_>>();
+                let mut all = Vec::with_capacity(iterations.saturating_mul(threads));
+                for worker in workers {
+                    let results = worker
+                        .join()
+                        .map_err(|_| Error::Benchmark("Benchmark thread panicked".to_string()))??;
+                    all.extend(results);
+                }
+                Ok::<_, Error>(all)
+            })?
+        };
         let total_duration = start.elapsed();
+        let iterations = iteration_results.len();
 
         let resource_stats = monitor.map(|m| m.stop()).unwrap_or_default();
 
@@ -177,11 +209,13 @@ impl FrameworkAdapter for NativeAdapter {
             statistics,
             success: true,
             error_message: None,
+            threads: None,
+            scaling_efficiency: None,
         })
     }
 }
 
-fn calculate_statistics(iterations: &[IterationResult]) -> DurationStatistics {
+pub(crate) fn calculate_statistics(iterations: &[IterationResult]) -> DurationStatistics {
     let mut durations: Vec<Duration> = iterations.iter().map(|r| r.duration).collect();
     durations.sort();
 
@@ -297,6 +297,10 @@ impl FrameworkAdapter for ScriptAdapter {
         }
     }
 
+    fn supports_threads(&self) -> bool {
+        matches!(self.language, ScriptLanguage::Python | ScriptLanguage::Node)
+    }
+
     fn run(&self, fixture: &Fixture, scenario: BenchmarkScenario, config: &BenchmarkConfig) -> Result<BenchmarkResult> {
         let (mut command, working_dir) = self.build_command()?;
         let fixture_path = fixture.resolved_path(&self.repo_root);
@@ -354,6 +358,10 @@ impl FrameworkAdapter for ScriptAdapter {
             command.arg("--visitor").arg(fixture.visitor.as_str());
         }
 
+        if config.threads > 1 {
+            command.arg("--threads").arg(config.threads.to_string());
+        }
+
         command
             .current_dir(&working_dir)
             .stdout(Stdio::piped())
@@ -397,6 +405,8 @@ impl FrameworkAdapter for ScriptAdapter {
                 statistics: None,
                 success: false,
                 error_message: Some(error_message),
+                threads: None,
+                scaling_efficiency: None,
             });
         }
 
@@ -461,6 +471,8 @@ impl FrameworkAdapter for ScriptAdapter {
             statistics: None,
             success: true,
             error_message: None,
+            threads: None,
+            scaling_efficiency: None,
         })
     }
 }
@@ -948,7 +960,7 @@ fn copy_dir_recursive(source: &Path, destination: &Path) -> Result<()> {
     Ok(())
 }
 
-fn ensure_ffi_library(repo_root: &Path) -> Result<()> {
+pub(crate) fn ensure_ffi_library(repo_root: &Path) -> Result<PathBuf> {
     let file_name = if cfg!(target_os = "windows") {
         "html_to_markdown_ffi.dll"
     } else if cfg!(target_os = "macos") {
@@ -961,7 +973,7 @@ fn ensure_ffi_library(repo_root: &Path) -> Result<()> {
     let candidate = target_dir.join(file_name);
 
     if candidate.exists() && !ffi_needs_rebuild(repo_root, &candidate)? {
-        return Ok(());
+        return Ok(candidate);
     }
 
     let mut cmd = Command::new("cargo");
@@ -982,7 +994,7 @@ fn ensure_ffi_library(repo_root: &Path) -> Result<()> {
     }
 
     if candidate.exists() {
-        Ok(())
+        Ok(candidate)
     } else {
         Err(Error::Benchmark(format!(
             "FFI library not found at {} even after building",
@@ -6,6 +6,8 @@ use std::time::Duration;
 pub enum BenchmarkMode {
     SingleFile,
     Batch,
+    /// Run each fixture on every count in `thread_counts` to measure multi-threaded scaling.
+    Scaling,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -62,6 +64,10 @@ pub struct BenchmarkConfig {
     pub flamegraph_dir: Option<PathBuf>,
     pub include_rust_baseline: bool,
     pub framework_label_override: Option<String>,
+    /// Thread (or worker) counts measured in scaling mode.
+    pub thread_counts: Vec<usize>,
+    /// Threads the current run converts on, each running the full iteration count.
+    pub threads: usize,
 }
 
 impl Default for BenchmarkConfig {
@@ -81,6 +87,8 @@ impl Default for BenchmarkConfig {
             flamegraph_dir: None,
             include_rust_baseline: false,
             framework_label_override: None,
+            thread_counts: vec![1],
+            threads: 1,
         }
     }
 }
@@ -111,6 +119,12 @@ impl BenchmarkConfig {
         if self.profile_repeat == 0 {
             return Err(crate::Error::Config("profile_repeat must be > 0".to_string()));
         }
+        if self.threads == 0 || self.thread_counts.contains(&0) {
+            return Err(crate::Error::Config("thread counts must be > 0".to_string()));
+        }
+        if self.benchmark_mode == BenchmarkMode::Scaling && self.thread_counts.is_empty() {
+            return Err(crate::Error::Config("thread_counts must not be empty".to_string()));
+        }
         Ok(())
     }
 }
@@ -17,6 +17,15 @@ impl FixtureFormat {
             Self::Hocr => "hocr",
         }
     }
+
+    /// Pick the format for a document from its content, for fixtures whose file extension does
+    /// not say.
+    pub fn detect(html: &str) -> Self {
+        match html_to_markdown_rs::detect_input_format(html) {
+            html_to_markdown_rs::InputFormat::Hocr => Self::Hocr,
+            _ => Self::Html,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -162,7 +171,9 @@ fn load_fixtures_from_documents(dir: &Path) -> Result<Vec<Fixture>> {
                 .unwrap_or("")
                 .to_ascii_lowercase();
             let format = match extension.as_str() {
-                "html" | "htm" => FixtureFormat::Html,
+                "html" | "htm" => std::fs::read(&entry_path)
+                    .map(|bytes| FixtureFormat::detect(&String::from_utf8_lossy(&bytes)))
+                    .unwrap_or_default(),
                 "hocr" => FixtureFormat::Hocr,
                 _ => continue,
             };
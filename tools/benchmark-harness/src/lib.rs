@@ -14,6 +14,6 @@ pub mod types;
 pub use crate::config::{BenchmarkConfig, BenchmarkMode, BenchmarkScenario};
 pub use crate::error::{Error, Result};
 pub use crate::fixture::{Fixture, FixtureFormat, FixtureSet};
-pub use crate::output::{write_html_report, write_json_results, write_summary_json};
+pub use crate::output::{HistoricalRun, write_html_report, write_json_results, write_summary_json};
 pub use crate::registry::AdapterRegistry;
 pub use crate::runner::BenchmarkRunner;
@@ -7,10 +7,12 @@
 #[global_allocator]
 static ALLOC: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
-use benchmark_harness::adapters::{NativeAdapter, ScriptAdapter, ScriptLanguage};
+use benchmark_harness::adapters::{FfiAdapter, NativeAdapter, ScriptAdapter, ScriptLanguage};
 use benchmark_harness::fixture::load_fixtures;
 use benchmark_harness::types::BenchmarkResult;
-use benchmark_harness::{AdapterRegistry, BenchmarkConfig, BenchmarkMode, BenchmarkRunner, BenchmarkScenario, Result};
+use benchmark_harness::{
+    AdapterRegistry, BenchmarkConfig, BenchmarkMode, BenchmarkRunner, BenchmarkScenario, HistoricalRun, Result,
+};
 use clap::{Parser, Subcommand, ValueEnum};
 use std::env;
 use std::path::{Path, PathBuf};
@@ -51,6 +53,10 @@ enum Commands {
         input: PathBuf,
         #[arg(short, long, default_value = "tools/benchmark-harness/results-consolidated")]
         output: PathBuf,
+
+        /// Earlier result directories or results.json files to chart as trends, oldest first
+        #[arg(long)]
+        history: Vec<PathBuf>,
     },
 
     /// Run benchmarks
@@ -93,6 +99,14 @@ enum Commands {
 
         #[arg(long, value_enum, default_value = "json")]
         format: OutputFormat,
+
+        /// Thread counts for scaling mode (defaults to powers of two up to the available cores)
+        #[arg(long, value_delimiter = ',')]
+        threads: Vec<usize>,
+
+        /// Earlier result directories or results.json files to chart as trends, oldest first
+        #[arg(long)]
+        history: Vec<PathBuf>,
     },
 }
 
@@ -102,6 +116,8 @@ enum CliMode {
     SingleFile,
     #[value(name = "batch")]
     Batch,
+    #[value(name = "scaling")]
+    Scaling,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -134,6 +150,7 @@ impl From<CliMode> for BenchmarkMode {
         match mode {
             CliMode::SingleFile => Self::SingleFile,
             CliMode::Batch => Self::Batch,
+            CliMode::Scaling => Self::Scaling,
         }
     }
 }
@@ -180,7 +197,7 @@ fn main() -> Result<()> {
             println!("✓ Flamegraph index generated: {}", output.display());
             Ok(())
         }
-        Commands::Consolidate { input, output } => {
+        Commands::Consolidate { input, output, history } => {
             let results = collect_results(&input)?;
             if results.is_empty() {
                 return Err(benchmark_harness::Error::Config(format!(
@@ -194,7 +211,7 @@ fn main() -> Result<()> {
             let summary_path = output.join("summary.json");
 
             benchmark_harness::write_json_results(&results, &json_path)?;
-            benchmark_harness::write_html_report(&results, &html_path)?;
+            benchmark_harness::write_html_report(&results, &load_history(&history)?, &html_path)?;
             benchmark_harness::write_summary_json(&results, &summary_path)?;
 
             println!("✓ Consolidated {} result(s) into {}", results.len(), output.display());
@@ -214,6 +231,8 @@ fn main() -> Result<()> {
             rust_baseline,
             scenarios,
             format,
+            threads,
+            history,
         } => {
             let repo_root = repo_root()?;
             let fixtures = load_fixtures(&fixtures_path)?;
@@ -231,6 +250,12 @@ fn main() -> Result<()> {
                 scenarios.into_iter().map(Into::into).collect()
             };
 
+            let thread_counts = if threads.is_empty() {
+                default_thread_counts()
+            } else {
+                threads
+            };
+
             let config = BenchmarkConfig {
                 fixtures_path,
                 output_dir: output.clone(),
@@ -243,12 +268,14 @@ fn main() -> Result<()> {
                 profile_repeat,
                 flamegraph_dir,
                 include_rust_baseline: rust_baseline,
+                thread_counts,
                 ..Default::default()
             };
             config.validate()?;
 
             let mut registry = AdapterRegistry::new();
             registry.register(std::sync::Arc::new(NativeAdapter::new(repo_root.clone())))?;
+            registry.register(std::sync::Arc::new(FfiAdapter::new(repo_root.clone())))?;
             registry.register(std::sync::Arc::new(ScriptAdapter::new(
                 ScriptLanguage::Python,
                 repo_root.clone(),
@@ -298,11 +325,11 @@ fn main() -> Result<()> {
                     benchmark_harness::write_json_results(&results, &json_path)?;
                 }
                 OutputFormat::Html => {
-                    benchmark_harness::write_html_report(&results, &html_path)?;
+                    benchmark_harness::write_html_report(&results, &load_history(&history)?, &html_path)?;
                 }
                 OutputFormat::Both => {
                     benchmark_harness::write_json_results(&results, &json_path)?;
-                    benchmark_harness::write_html_report(&results, &html_path)?;
+                    benchmark_harness::write_html_report(&results, &load_history(&history)?, &html_path)?;
                 }
             }
             benchmark_harness::write_summary_json(&results, &summary_path)?;
@@ -312,6 +339,17 @@ fn main() -> Result<()> {
     }
 }
 
+fn default_thread_counts() -> Vec<usize> {
+    let available = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+    let mut counts: Vec<usize> = std::iter::successors(Some(1usize), |count| count.checked_mul(2))
+        .take_while(|count| *count <= available)
+        .collect();
+    if counts.last() != Some(&available) {
+        counts.push(available);
+    }
+    counts
+}
+
 fn repo_root() -> Result<PathBuf> {
     let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
     let repo_root = manifest_dir
@@ -386,6 +424,28 @@ fn collect_results(input: &Path) -> Result<Vec<BenchmarkResult>> {
     Ok(results)
 }
 
+/// Load each history path as one trend point, labelled by its directory name.
+fn load_history(paths: &[PathBuf]) -> Result<Vec<HistoricalRun>> {
+    paths
+        .iter()
+        .map(|path| {
+            let dir = if path.is_file() {
+                path.parent()
+            } else {
+                Some(path.as_path())
+            };
+            let label = dir.and_then(Path::file_name).map_or_else(
+                || path.display().to_string(),
+                |name| name.to_string_lossy().into_owned(),
+            );
+            Ok(HistoricalRun {
+                label,
+                results: collect_results(path)?,
+            })
+        })
+        .collect()
+}
+
 fn collect_results_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
     for entry in std::fs::read_dir(dir).map_err(benchmark_harness::Error::Io)? {
         let entry = entry.map_err(benchmark_harness::Error::Io)?;
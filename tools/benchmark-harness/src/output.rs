@@ -1,12 +1,27 @@
 use crate::types::BenchmarkResult;
 use crate::{Error, Result};
 use minijinja::{Environment, context};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 
 const REPORT_TEMPLATE: &str = include_str!("../templates/report.html.jinja");
 
+const TREND_WIDTH: f64 = 640.0;
+const TREND_HEIGHT: f64 = 220.0;
+const TREND_PADDING: f64 = 36.0;
+const TREND_COLORS: &[&str] = &[
+    "#c75c2b", "#2b6cc7", "#2f9e5b", "#8e44ad", "#d4a017", "#16a2b8", "#c0392b", "#5d6d7e",
+];
+
+/// A previous result set, drawn as one point per series on the report's trend charts.
+#[derive(Debug, Clone)]
+pub struct HistoricalRun {
+    /// Version or commit the results were recorded at, used as the x-axis label.
+    pub label: String,
+    pub results: Vec<BenchmarkResult>,
+}
+
 pub fn write_json_results(results: &[BenchmarkResult], output_path: &Path) -> Result<()> {
     if let Some(parent) = output_path.parent() {
         fs::create_dir_all(parent).map_err(Error::Io)?;
@@ -18,7 +33,9 @@ pub fn write_json_results(results: &[BenchmarkResult], output_path: &Path) -> Re
     Ok(())
 }
 
-pub fn write_html_report(results: &[BenchmarkResult], output_path: &Path) -> Result<()> {
+/// Render the HTML report. `history` is ordered oldest first; when it is non-empty the report
+/// gains per-fixture trend charts ending with the current `results`.
+pub fn write_html_report(results: &[BenchmarkResult], history: &[HistoricalRun], output_path: &Path) -> Result<()> {
     if let Some(parent) = output_path.parent() {
         fs::create_dir_all(parent).map_err(Error::Io)?;
     }
@@ -43,7 +60,8 @@ pub fn write_html_report(results: &[BenchmarkResult], output_path: &Path) -> Res
             results => rows,
             summary => summary,
             frameworks => framework_summary,
-            fixtures => fixture_summary
+            fixtures => fixture_summary,
+            trends => build_trends(results, history)
         })
         .map_err(|err| Error::Serialization(format!("Failed to render template: {err}")))?;
 
@@ -60,6 +78,7 @@ pub fn write_summary_json(results: &[BenchmarkResult], output_path: &Path) -> Re
         overall: build_summary(results),
         frameworks: build_framework_summary(results),
         fixtures: build_fixture_summary(results),
+        scaling: build_scaling_summary(results),
     };
 
     let json = serde_json::to_string_pretty(&summary)
@@ -159,6 +178,115 @@ fn build_fixture_summary(results: &[BenchmarkResult]) -> Vec<FixtureSummary> {
     summaries
 }
 
+fn build_scaling_summary(results: &[BenchmarkResult]) -> Vec<ScalingSummary> {
+    let mut summaries = results
+        .iter()
+        .filter(|r| r.success)
+        .filter_map(|r| {
+            Some(ScalingSummary {
+                framework: r.framework.clone(),
+                fixture: r.fixture_name.clone(),
+                scenario: r.scenario.clone(),
+                threads: r.threads?,
+                ops_per_sec: r.metrics.ops_per_sec,
+                scaling_efficiency: r.scaling_efficiency,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    summaries.sort_by(|a, b| {
+        (&a.framework, &a.fixture, &a.scenario, a.threads).cmp(&(&b.framework, &b.fixture, &b.scenario, b.threads))
+    });
+    summaries
+}
+
+fn build_trends(results: &[BenchmarkResult], history: &[HistoricalRun]) -> Vec<TrendChart> {
+    if history.is_empty() {
+        return Vec::new();
+    }
+
+    let runs = history
+        .iter()
+        .map(|run| (run.label.as_str(), run.results.as_slice()))
+        .chain(std::iter::once(("current", results)))
+        .collect::<Vec<_>>();
+
+    // (fixture id, scenario) -> fixture name and framework -> median ops/sec per run.
+    let mut series: BTreeMap<(&str, &str), (&str, BTreeMap<&str, Vec<Option<f64>>>)> = BTreeMap::new();
+    for (index, (_, run_results)) in runs.iter().enumerate() {
+        let mut samples: HashMap<(&str, &str, &str), Vec<f64>> = HashMap::new();
+        for result in run_results.iter().filter(|r| r.success && r.threads.is_none()) {
+            samples
+                .entry((&result.fixture_id, &result.scenario, &result.framework))
+                .or_default()
+                .push(result.metrics.ops_per_sec);
+            series
+                .entry((&result.fixture_id, &result.scenario))
+                .or_insert_with(|| (&result.fixture_name, BTreeMap::new()));
+        }
+        for ((fixture_id, scenario, framework), values) in samples {
+            if let Some((_, frameworks)) = series.get_mut(&(fixture_id, scenario)) {
+                frameworks.entry(framework).or_insert_with(|| vec![None; runs.len()])[index] = Some(median(values));
+            }
+        }
+    }
+
+    let step = (TREND_WIDTH - 2.0 * TREND_PADDING) / (runs.len() - 1) as f64;
+    let x_at = |index: usize| TREND_PADDING + step * index as f64;
+    let ticks = runs
+        .iter()
+        .enumerate()
+        .map(|(index, (label, _))| TrendTick {
+            x: x_at(index),
+            label: (*label).to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    series
+        .into_iter()
+        .filter_map(|((_, scenario), (fixture, frameworks))| {
+            let max_ops = frameworks.values().flatten().flatten().copied().fold(0.0, f64::max);
+            if max_ops <= 0.0 {
+                return None;
+            }
+            let y_at = |ops: f64| TREND_HEIGHT - TREND_PADDING - ops / max_ops * (TREND_HEIGHT - 2.0 * TREND_PADDING);
+
+            let lines = frameworks
+                .into_iter()
+                .enumerate()
+                .map(|(line_index, (framework, values))| {
+                    let points = values
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, ops)| ops.map(|ops| format!("{:.1},{:.1}", x_at(index), y_at(ops))))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    let first = values.iter().flatten().next().copied();
+                    let latest = values.last().copied().flatten();
+                    TrendLine {
+                        framework: framework.to_string(),
+                        color: TREND_COLORS[line_index % TREND_COLORS.len()],
+                        points,
+                        latest_ops: latest,
+                        change_percent: first
+                            .zip(latest)
+                            .filter(|(first, _)| *first > 0.0)
+                            .map(|(first, latest)| (latest - first) / first * 100.0),
+                    }
+                })
+                .collect();
+
+            Some(TrendChart {
+                fixture: fixture.to_string(),
+                scenario: scenario.to_string(),
+                max_ops,
+                ticks: ticks.clone(),
+                lines,
+            })
+        })
+        .collect()
+}
+
 fn average<I>(values: I) -> f64
 where
     I: Iterator<Item = f64>,
@@ -213,11 +341,49 @@ struct FixtureSummary {
     peak_memory_mb: f64,
 }
 
+#[derive(serde::Serialize)]
+struct ScalingSummary {
+    framework: String,
+    fixture: String,
+    scenario: String,
+    threads: usize,
+    ops_per_sec: f64,
+    scaling_efficiency: Option<f64>,
+}
+
+#[derive(serde::Serialize, Clone)]
+struct TrendTick {
+    x: f64,
+    label: String,
+}
+
+#[derive(serde::Serialize)]
+struct TrendLine {
+    framework: String,
+    color: &'static str,
+    /// SVG polyline points; runs without a successful result for this framework are skipped.
+    points: String,
+    latest_ops: Option<f64>,
+    /// Change from the oldest to the current median ops/sec.
+    change_percent: Option<f64>,
+}
+
+#[derive(serde::Serialize)]
+struct TrendChart {
+    fixture: String,
+    scenario: String,
+    max_ops: f64,
+    ticks: Vec<TrendTick>,
+    lines: Vec<TrendLine>,
+}
+
 #[derive(serde::Serialize)]
 struct SummaryReport {
     overall: SummaryStats,
     frameworks: Vec<FrameworkSummary>,
     fixtures: Vec<FixtureSummary>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    scaling: Vec<ScalingSummary>,
 }
 
 #[derive(serde::Serialize)]
@@ -1,6 +1,6 @@
 use crate::adapter::FrameworkAdapter;
 use crate::config::BenchmarkConfig;
-use crate::config::BenchmarkScenario;
+use crate::config::{BenchmarkMode, BenchmarkScenario};
 use crate::fixture::Fixture;
 use crate::registry::AdapterRegistry;
 use crate::types::{BenchmarkResult, PerformanceMetrics, ResourceStats};
@@ -44,6 +44,13 @@ impl BenchmarkRunner {
                         continue;
                     }
 
+                    if self.config.benchmark_mode == BenchmarkMode::Scaling {
+                        if adapter.supports_threads() {
+                            results.extend(self.run_scaling(adapter, fixture, *scenario));
+                        }
+                        continue;
+                    }
+
                     if self.config.include_rust_baseline && adapter.name() == "rust" {
                         let mut baseline_config = self.config.clone();
                         baseline_config.enable_profiling = false;
@@ -67,6 +74,41 @@ impl BenchmarkRunner {
 
         Ok(results)
     }
+
+    /// Run on each configured thread count, always starting from one thread so every result
+    /// can report its efficiency against the single-threaded throughput.
+    fn run_scaling(
+        &self,
+        adapter: &Arc<dyn FrameworkAdapter>,
+        fixture: &Fixture,
+        scenario: BenchmarkScenario,
+    ) -> Vec<BenchmarkResult> {
+        let mut thread_counts = self.config.thread_counts.clone();
+        thread_counts.push(1);
+        thread_counts.sort_unstable();
+        thread_counts.dedup();
+
+        let mut baseline_ops = None;
+        let mut results = Vec::with_capacity(thread_counts.len());
+        for threads in thread_counts {
+            let mut config = self.config.clone();
+            config.threads = threads;
+
+            let mut result = run_adapter(adapter.clone(), fixture, scenario, &config)
+                .unwrap_or_else(|err| failed_result(adapter.clone(), fixture, scenario, &config, err));
+            result.threads = Some(threads);
+            if result.success {
+                if threads == 1 {
+                    baseline_ops = Some(result.metrics.ops_per_sec);
+                }
+                result.scaling_efficiency = baseline_ops
+                    .filter(|ops| *ops > 0.0)
+                    .map(|ops| result.metrics.ops_per_sec / (ops * threads as f64));
+            }
+            results.push(result);
+        }
+        results
+    }
 }
 
 fn run_adapter(
@@ -103,5 +145,7 @@ fn failed_result(
         statistics: None,
         success: false,
         error_message: Some(err.to_string()),
+        threads: None,
+        scaling_efficiency: None,
     }
 }
@@ -27,6 +27,11 @@ pub struct BenchmarkResult {
     pub success: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threads: Option<usize>,
+    /// Throughput relative to `threads` times the single-threaded throughput (1.0 is perfect).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scaling_efficiency: Option<f64>,
 }
 
 fn default_scenario() -> String {